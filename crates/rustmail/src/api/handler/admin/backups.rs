@@ -0,0 +1,135 @@
+use crate::prelude::api::*;
+use crate::prelude::config::*;
+use crate::prelude::modules::*;
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::{StatusCode, header};
+use axum::response::IntoResponse;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+async fn backup_config(bot_state: &Arc<Mutex<BotState>>) -> BackupConfig {
+    bot_state
+        .lock()
+        .await
+        .config()
+        .map(|c| c.backups.clone())
+        .unwrap_or_default()
+}
+
+async fn find(
+    bot_state: &Arc<Mutex<BotState>>,
+    name: &str,
+) -> Result<BackupInfo, (StatusCode, String)> {
+    match find_backup(&backup_config(bot_state).await, name) {
+        Ok(Some(backup)) => Ok(backup),
+        Ok(None) => Err((StatusCode::NOT_FOUND, "Backup not found".to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/backups",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Database backups, newest first", body = [BackupInfo]),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_list_backups(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+) -> Result<Json<Vec<BackupInfo>>, (StatusCode, String)> {
+    list_backups(&backup_config(&bot_state).await)
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/backups",
+    tag = "admin",
+    responses(
+        (status = 201, description = "Backup taken", body = BackupInfo),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_create_backup(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+) -> Result<(StatusCode, Json<BackupInfo>), (StatusCode, String)> {
+    let config = backup_config(&bot_state).await;
+    let pool = bot_state.lock().await.db_pool.clone().ok_or((
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Database not initialized".to_string(),
+    ))?;
+
+    let _guard = BACKUP_LOCK.lock().await;
+    create_backup(&config, &pool)
+        .await
+        .map(|backup| (StatusCode::CREATED, Json(backup)))
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/backups/{name}/download",
+    tag = "admin",
+    params(("name" = String, Path, description = "Backup file name")),
+    responses(
+        (status = 200, description = "The backup file", content_type = "application/octet-stream"),
+        (status = 404, description = "Backup not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_download_backup(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(name): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let backup = find(&bot_state, &name).await?;
+
+    let path = backup.path.clone();
+    let bytes = tokio::task::spawn_blocking(move || std::fs::read(path))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    let content_type = if backup.compressed {
+        "application/gzip"
+    } else {
+        "application/vnd.sqlite3"
+    };
+    Ok((
+        [
+            (header::CONTENT_TYPE, content_type.to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"{}\"", backup.name),
+            ),
+        ],
+        bytes,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/backups/{name}/restore",
+    tag = "admin",
+    params(("name" = String, Path, description = "Backup file name")),
+    responses(
+        (status = 200, description = "Database restored", body = RestoreReport),
+        (status = 404, description = "Backup not found"),
+        (status = 500, description = "Restore failed, the previous database is still in use")
+    )
+)]
+pub async fn handle_restore_backup(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(name): Path<String>,
+) -> Result<Json<RestoreReport>, (StatusCode, String)> {
+    let backup = find(&bot_state, &name).await?;
+
+    restore_backup(bot_state, &backup)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))
+}
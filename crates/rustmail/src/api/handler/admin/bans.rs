@@ -9,7 +9,7 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-#[derive(serde::Serialize)]
+#[derive(serde::Serialize, utoipa::ToSchema)]
 struct PanelBannedUser {
     user_id: String,
     username: String,
@@ -25,10 +25,19 @@ struct PanelBannedUser {
     roles_unknown: bool,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/admin/bans",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Banned users of the community guild", body = [PanelBannedUser]),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_list_bans(State(bot_state): State<Arc<Mutex<BotState>>>) -> impl IntoResponse {
     let (community_guild_id, pool, bot_http) = {
         let state_lock = bot_state.lock().await;
-        let config = match &state_lock.config {
+        let config = match state_lock.config() {
             Some(c) => c,
             None => {
                 return (
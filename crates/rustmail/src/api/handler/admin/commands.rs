@@ -0,0 +1,144 @@
+use crate::prelude::db::*;
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct CommandPermissionDto {
+    pub command: String,
+    pub role_ids: Vec<String>,
+    pub user_ids: Vec<String>,
+}
+
+async fn pool(bot_state: &Arc<Mutex<BotState>>) -> Result<SqlitePool, (StatusCode, String)> {
+    let state_lock = bot_state.lock().await;
+    match &state_lock.db_pool {
+        Some(p) => Ok(p.clone()),
+        None => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Database not initialized".to_string(),
+        )),
+    }
+}
+
+fn internal(e: impl ToString) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+async fn registered_commands(
+    bot_state: &Arc<Mutex<BotState>>,
+) -> Result<Vec<String>, (StatusCode, String)> {
+    let cmd_tx = {
+        let state_lock = bot_state.lock().await;
+        state_lock.command_tx.clone()
+    };
+    let unavailable = || {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "Bot is not running".to_string(),
+        )
+    };
+
+    let (resp_tx, resp_rx) = tokio::sync::oneshot::channel();
+    cmd_tx
+        .send(BotCommand::ListCommands { resp: resp_tx })
+        .await
+        .map_err(|_| unavailable())?;
+    resp_rx.await.map_err(|_| unavailable())
+}
+
+fn validate_ids(raw: &[String], what: &str) -> Result<Vec<String>, (StatusCode, String)> {
+    let mut ids: Vec<String> = Vec::with_capacity(raw.len());
+    for id in raw {
+        let trimmed = id.trim();
+        trimmed
+            .parse::<u64>()
+            .map_err(|_| (StatusCode::BAD_REQUEST, format!("Invalid {what}")))?;
+        if !ids.iter().any(|i| i == trimmed) {
+            ids.push(trimmed.to_string());
+        }
+    }
+    Ok(ids)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/commands",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Every registered command with the roles and users allowed to run it", body = [CommandPermissionDto]),
+        (status = 503, description = "Bot is not running"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_list_command_permissions(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+) -> Result<Json<Vec<CommandPermissionDto>>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let commands = registered_commands(&bot_state).await?;
+    let mut matrix = list_command_permissions(&p).await.map_err(internal)?;
+
+    Ok(Json(
+        commands
+            .into_iter()
+            .map(|command| {
+                let access = matrix.remove(&command).unwrap_or_default();
+                CommandPermissionDto {
+                    command,
+                    role_ids: access.role_ids,
+                    user_ids: access.user_ids,
+                }
+            })
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/commands/{name}",
+    tag = "admin",
+    params(("name" = String, Path, description = "Command name")),
+    request_body = AccessList,
+    responses(
+        (status = 200, description = "Command permissions replaced", body = CommandPermissionDto),
+        (status = 400, description = "Invalid role or user ID"),
+        (status = 404, description = "Unknown command"),
+        (status = 503, description = "Bot is not running"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_set_command_permissions(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(name): Path<String>,
+    Json(req): Json<AccessList>,
+) -> Result<Json<CommandPermissionDto>, (StatusCode, String)> {
+    let access = AccessList {
+        role_ids: validate_ids(&req.role_ids, "role ID")?,
+        user_ids: validate_ids(&req.user_ids, "user ID")?,
+    };
+    let p = pool(&bot_state).await?;
+    if !registered_commands(&bot_state).await?.contains(&name) {
+        return Err((StatusCode::NOT_FOUND, "Unknown command".to_string()));
+    }
+
+    set_command_permissions(&name, &access, &p)
+        .await
+        .map_err(internal)?;
+
+    let cmd_tx = {
+        let state_lock = bot_state.lock().await;
+        state_lock.command_tx.clone()
+    };
+    let _ = cmd_tx.send(BotCommand::SyncCommands).await;
+
+    Ok(Json(CommandPermissionDto {
+        command: name,
+        role_ids: access.role_ids,
+        user_ids: access.user_ids,
+    }))
+}
@@ -0,0 +1,200 @@
+use crate::prelude::api::*;
+use crate::prelude::db::*;
+use crate::prelude::modules::*;
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::{StatusCode, header};
+use axum::response::IntoResponse;
+use axum_extra::extract::CookieJar;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+const DATA_REQUESTS_LIMIT: i64 = 200;
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct DataRequestsQuery {
+    pub user_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct DataSubjectSummary {
+    pub user_id: String,
+    pub counts: BTreeMap<String, u64>,
+    pub open_ticket: bool,
+}
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct EraseUserDataRequest {
+    pub mode: ErasureMode,
+    pub confirm: String,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct EraseUserDataResponse {
+    pub user_id: String,
+    pub mode: ErasureMode,
+    pub counts: BTreeMap<String, u64>,
+}
+
+async fn db_pool(bot_state: &Arc<Mutex<BotState>>) -> Result<SqlitePool, (StatusCode, String)> {
+    bot_state.lock().await.db_pool.clone().ok_or((
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Database not initialized".to_string(),
+    ))
+}
+
+fn data_subject(raw: &str) -> Result<String, (StatusCode, String)> {
+    parse_data_subject(raw).ok_or((StatusCode::BAD_REQUEST, "Invalid user ID".to_string()))
+}
+
+fn internal_error(e: impl ToString) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/gdpr/requests",
+    tag = "admin",
+    params(DataRequestsQuery),
+    responses(
+        (status = 200, description = "Recorded exports and erasures, newest first", body = [DataSubjectRequest]),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_list_data_requests(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Query(params): Query<DataRequestsQuery>,
+) -> Result<Json<Vec<DataSubjectRequest>>, (StatusCode, String)> {
+    let pool = db_pool(&bot_state).await?;
+    let user_id = params.user_id.filter(|id| !id.trim().is_empty());
+    list_data_subject_requests(user_id.as_deref(), DATA_REQUESTS_LIMIT, &pool)
+        .await
+        .map(Json)
+        .map_err(internal_error)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/gdpr/{user_id}/summary",
+    tag = "admin",
+    params(("user_id" = String, Path, description = "Discord user ID")),
+    responses(
+        (status = 200, description = "What is stored about the user", body = DataSubjectSummary),
+        (status = 400, description = "Invalid user ID"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_data_subject_summary(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(user_id): Path<String>,
+) -> Result<Json<DataSubjectSummary>, (StatusCode, String)> {
+    let user_id = data_subject(&user_id)?;
+    let pool = db_pool(&bot_state).await?;
+
+    let data = collect_user_data(&user_id, &pool)
+        .await
+        .map_err(internal_error)?;
+    let open_ticket = user_has_open_thread(&user_id, &pool)
+        .await
+        .map_err(internal_error)?;
+
+    Ok(Json(DataSubjectSummary {
+        user_id,
+        counts: count_user_data(&data),
+        open_ticket,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/gdpr/{user_id}/export",
+    tag = "admin",
+    params(("user_id" = String, Path, description = "Discord user ID")),
+    responses(
+        (status = 200, description = "JSON bundle of everything stored about the user", content_type = "application/json"),
+        (status = 400, description = "Invalid user ID"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_export_user_data(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    jar: CookieJar,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    let user_id = data_subject(&user_id)?;
+    let pool = db_pool(&bot_state).await?;
+    let requested_by = session_user_id(&bot_state, &jar).await.unwrap_or_default();
+
+    let bytes = export_user_data(&user_id, "panel", &requested_by, &pool)
+        .await
+        .map_err(internal_error)?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/json".to_string()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!(
+                    "attachment; filename=\"{}\"",
+                    data_export_file_name(&user_id)
+                ),
+            ),
+        ],
+        bytes,
+    ))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/gdpr/{user_id}/erase",
+    tag = "admin",
+    params(("user_id" = String, Path, description = "Discord user ID")),
+    request_body = EraseUserDataRequest,
+    responses(
+        (status = 200, description = "User data erased", body = EraseUserDataResponse),
+        (status = 400, description = "Invalid user ID or confirmation"),
+        (status = 409, description = "The user has an open ticket"),
+        (status = 500, description = "Internal error, nothing was erased")
+    )
+)]
+pub async fn handle_erase_user_data(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    jar: CookieJar,
+    Path(user_id): Path<String>,
+    Json(request): Json<EraseUserDataRequest>,
+) -> Result<Json<EraseUserDataResponse>, (StatusCode, String)> {
+    let user_id = data_subject(&user_id)?;
+    if request.confirm.trim() != user_id {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Type the user ID to confirm the erasure".to_string(),
+        ));
+    }
+    let pool = db_pool(&bot_state).await?;
+
+    if user_has_open_thread(&user_id, &pool)
+        .await
+        .map_err(internal_error)?
+    {
+        return Err((
+            StatusCode::CONFLICT,
+            "The user has an open ticket, close it before erasing their data".to_string(),
+        ));
+    }
+
+    let requested_by = session_user_id(&bot_state, &jar).await.unwrap_or_default();
+    let counts = erase_data_subject(&user_id, request.mode, "panel", &requested_by, &pool)
+        .await
+        .map_err(internal_error)?;
+
+    Ok(Json(EraseUserDataResponse {
+        user_id,
+        mode: request.mode,
+        counts,
+    }))
+}
@@ -0,0 +1,67 @@
+use crate::prelude::logging::*;
+use axum::Json;
+use axum::extract::Query;
+use axum::http::StatusCode;
+use serde::Deserialize;
+use tracing::Level;
+
+const DEFAULT_LOG_LIMIT: usize = 200;
+const MAX_LOG_LIMIT: usize = 1000;
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LogsQuery {
+    pub level: Option<String>,
+    pub target: Option<String>,
+    pub search: Option<String>,
+    pub after: Option<u64>,
+    pub limit: Option<usize>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/logs",
+    tag = "admin",
+    params(LogsQuery),
+    responses(
+        (status = 200, description = "Buffered log entries, oldest first", body = [LogEntry]),
+        (status = 400, description = "Unknown log level")
+    )
+)]
+pub async fn handle_list_logs(
+    Query(params): Query<LogsQuery>,
+) -> Result<Json<Vec<LogEntry>>, (StatusCode, String)> {
+    let min_level = match params.level.as_deref().filter(|l| !l.is_empty()) {
+        Some(l) => Some(l.parse::<Level>().map_err(|_| {
+            (
+                StatusCode::BAD_REQUEST,
+                format!("Unknown log level '{}'", l),
+            )
+        })?),
+        None => None,
+    };
+    let target = params.target.filter(|t| !t.is_empty());
+    let search = params
+        .search
+        .filter(|s| !s.is_empty())
+        .map(|s| s.to_lowercase());
+    let after = params.after.unwrap_or(0);
+    let limit = params
+        .limit
+        .unwrap_or(DEFAULT_LOG_LIMIT)
+        .clamp(1, MAX_LOG_LIMIT);
+
+    let entries = LOG_BUFFER.snapshot(limit, |entry| {
+        entry.id > after
+            && min_level.is_none_or(|level| entry.level() <= level)
+            && target
+                .as_deref()
+                .is_none_or(|t| entry.target.starts_with(t))
+            && search.as_deref().is_none_or(|s| {
+                entry.message.to_lowercase().contains(s)
+                    || entry.fields.values().any(|v| v.to_lowercase().contains(s))
+            })
+    });
+
+    Ok(Json(entries))
+}
@@ -8,7 +8,7 @@ use serenity::all::GuildId;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct MemberInfo {
     pub user_id: String,
     pub username: String,
@@ -17,12 +17,21 @@ pub struct MemberInfo {
     pub roles: Vec<String>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/admin/members",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Members of the staff guild", body = [MemberInfo]),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_list_members(
     State(bot_state): State<Arc<Mutex<BotState>>>,
 ) -> impl IntoResponse {
     let (guild_id, bot_http) = {
         let state_lock = bot_state.lock().await;
-        let config = match &state_lock.config {
+        let config = match state_lock.config() {
             Some(c) => c,
             None => {
                 return (
@@ -1,9 +1,21 @@
+mod backups;
 mod bans;
+mod commands;
+mod gdpr;
+mod logs;
 mod members;
 mod permissions;
+mod retention;
 mod roles;
+mod sessions;
 
+pub use backups::*;
 pub use bans::*;
+pub use commands::*;
+pub use gdpr::*;
+pub use logs::*;
 pub use members::*;
 pub use permissions::*;
+pub use retention::*;
 pub use roles::*;
+pub use sessions::*;
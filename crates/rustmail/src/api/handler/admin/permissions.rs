@@ -1,4 +1,6 @@
 use crate::prelude::api::*;
+use crate::prelude::config::Config;
+use crate::prelude::db::*;
 use crate::prelude::types::*;
 use axum::Json;
 use axum::extract::{Path, State};
@@ -7,10 +9,22 @@ use axum::response::IntoResponse;
 use axum_extra::extract::CookieJar;
 use chrono::Utc;
 use rustmail_types::api::panel_permissions::*;
-use sqlx::{Row, query};
+use serenity::all::{Context, GuildId, RoleId, UserId};
+use sqlx::{Row, SqlitePool, query};
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::{error, info};
 
+#[utoipa::path(
+    get,
+    path = "/api/admin/permissions",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Granted panel permissions", body = [PanelPermissionEntry]),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_list_permissions(
     State(bot_state): State<Arc<Mutex<BotState>>>,
 ) -> impl IntoResponse {
@@ -76,6 +90,17 @@ pub async fn handle_list_permissions(
     (StatusCode::OK, Json(permissions)).into_response()
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/admin/permissions",
+    tag = "admin",
+    request_body = GrantPermissionRequest,
+    responses(
+        (status = 200, description = "Permission granted", body = Object),
+        (status = 401, description = "No panel session"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_grant_permission(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     jar: CookieJar,
@@ -137,13 +162,23 @@ pub async fn handle_grant_permission(
     }
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/admin/permissions/{id}",
+    tag = "admin",
+    params(("id" = i64, Path, description = "Permission entry ID")),
+    responses(
+        (status = 200, description = "Permission revoked", body = Object),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_revoke_permission(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path(permission_id): Path<i64>,
 ) -> impl IntoResponse {
-    let db_pool = {
+    let (db_pool, config, bot_ctx) = {
         let state_lock = bot_state.lock().await;
-        match &state_lock.db_pool {
+        let pool = match &state_lock.db_pool {
             Some(pool) => pool.clone(),
             None => {
                 return (
@@ -152,16 +187,47 @@ pub async fn handle_revoke_permission(
                 )
                     .into_response();
             }
-        }
+        };
+        let ctx = state_lock.bot_context.read().await.clone();
+        (pool, state_lock.config(), ctx)
     };
 
+    let subject =
+        query("SELECT subject_type, subject_id, permission FROM panel_permissions WHERE id = ?")
+            .bind(permission_id)
+            .fetch_optional(&db_pool)
+            .await
+            .ok()
+            .flatten()
+            .map(|row| {
+                (
+                    row.get::<String, _>("subject_type"),
+                    row.get::<String, _>("subject_id"),
+                    row.get::<String, _>("permission"),
+                )
+            });
+
     let result = query("DELETE FROM panel_permissions WHERE id = ?")
         .bind(permission_id)
         .execute(&db_pool)
         .await;
 
     match result {
-        Ok(_) => (StatusCode::OK, Json(serde_json::json!({"success": true}))).into_response(),
+        Ok(_) => {
+            get_panel_permissions_cache().invalidate_all();
+            if let Some((subject_type, subject_id, permission)) = subject {
+                invalidate_subject_sessions(
+                    &subject_type,
+                    &subject_id,
+                    PanelPermission::from_str(&permission),
+                    config,
+                    bot_ctx,
+                    &db_pool,
+                )
+                .await;
+            }
+            (StatusCode::OK, Json(serde_json::json!({"success": true}))).into_response()
+        }
         Err(e) => (
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({"error": format!("Database error: {}", e)})),
@@ -169,3 +235,81 @@ pub async fn handle_revoke_permission(
             .into_response(),
     }
 }
+
+async fn invalidate_subject_sessions(
+    subject_type: &str,
+    subject_id: &str,
+    permission: Option<PanelPermission>,
+    config: Option<Arc<Config>>,
+    bot_ctx: Option<Context>,
+    db_pool: &SqlitePool,
+) {
+    let guild_id = config.as_ref().map(|c| c.bot.get_staff_guild_id());
+    let user_ids = match subject_type {
+        "user" => vec![subject_id.to_string()],
+        "role" => {
+            let (Some(guild_id), Some(ctx), Ok(role_id)) =
+                (guild_id, bot_ctx.as_ref(), subject_id.parse::<u64>())
+            else {
+                return;
+            };
+            let guild_id = GuildId::new(guild_id);
+            let role_id = RoleId::new(role_id);
+            let session_users: Vec<UserId> = list_session_user_ids(db_pool)
+                .await
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|id| id.parse::<u64>().ok().map(UserId::new))
+                .collect();
+
+            let cached: HashMap<UserId, bool> = ctx
+                .cache
+                .guild(guild_id)
+                .map(|guild| {
+                    session_users
+                        .iter()
+                        .filter_map(|user_id| {
+                            let member = guild.members.get(user_id)?;
+                            Some((*user_id, member.roles.contains(&role_id)))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            let mut affected = Vec::new();
+            for user_id in session_users {
+                let has_role = match cached.get(&user_id) {
+                    Some(has_role) => *has_role,
+                    None => guild_id
+                        .member(&ctx.http, user_id)
+                        .await
+                        .is_ok_and(|member| member.roles.contains(&role_id)),
+                };
+                if has_role {
+                    affected.push(user_id.to_string());
+                }
+            }
+            affected
+        }
+        _ => return,
+    };
+
+    for user_id in user_ids {
+        if let (Some(permission), Some(config), Some(guild_id), Some(ctx)) =
+            (&permission, &config, guild_id, &bot_ctx)
+            && get_user_panel_permissions(&user_id, config, guild_id, ctx.http.clone(), db_pool)
+                .await
+                .contains(permission)
+        {
+            continue;
+        }
+        match delete_sessions_for_user(&user_id, db_pool).await {
+            Ok(0) => {}
+            Ok(n) => info!(
+                user_id,
+                "Revoked {} panel session(s) after permission removal", n
+            ),
+            Err(e) => error!("Failed to revoke panel sessions of {}: {e:?}", user_id),
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use crate::prelude::db::*;
+use crate::prelude::modules::*;
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[derive(Debug, Deserialize, utoipa::ToSchema)]
+pub struct RetentionRuleRequest {
+    pub target: RetentionTarget,
+    pub older_than_days: i64,
+    pub ticket_category_id: Option<String>,
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RetentionPreview {
+    pub cutoff: i64,
+    pub counts: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RetentionRunResponse {
+    pub counts: BTreeMap<String, u64>,
+}
+
+async fn db_pool(bot_state: &Arc<Mutex<BotState>>) -> Result<SqlitePool, (StatusCode, String)> {
+    bot_state.lock().await.db_pool.clone().ok_or((
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Database not initialized".to_string(),
+    ))
+}
+
+fn internal_error(e: impl ToString) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+async fn validate_rule(
+    request: &RetentionRuleRequest,
+    pool: &SqlitePool,
+) -> Result<Option<String>, (StatusCode, String)> {
+    if request.older_than_days < 1 {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "older_than_days must be at least 1".to_string(),
+        ));
+    }
+
+    let Some(category_id) = request
+        .ticket_category_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+    else {
+        return Ok(None);
+    };
+    if !request.target.is_ticket_data() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Only ticket rules can be restricted to a category".to_string(),
+        ));
+    }
+    if get_category_by_id(category_id, pool)
+        .await
+        .map_err(internal_error)?
+        .is_none()
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Unknown ticket category".to_string(),
+        ));
+    }
+
+    Ok(Some(category_id.to_string()))
+}
+
+async fn rule_or_404(id: i64, pool: &SqlitePool) -> Result<RetentionRule, (StatusCode, String)> {
+    get_retention_rule(id, pool)
+        .await
+        .map_err(internal_error)?
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            "Retention rule not found".to_string(),
+        ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/retention/rules",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Configured retention rules", body = [RetentionRule]),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_list_retention_rules(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+) -> Result<Json<Vec<RetentionRule>>, (StatusCode, String)> {
+    let pool = db_pool(&bot_state).await?;
+    list_retention_rules(&pool)
+        .await
+        .map(Json)
+        .map_err(internal_error)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/retention/rules",
+    tag = "admin",
+    request_body = RetentionRuleRequest,
+    responses(
+        (status = 201, description = "Rule created", body = RetentionRule),
+        (status = 400, description = "Invalid rule"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_create_retention_rule(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Json(request): Json<RetentionRuleRequest>,
+) -> Result<(StatusCode, Json<RetentionRule>), (StatusCode, String)> {
+    let pool = db_pool(&bot_state).await?;
+    let category_id = validate_rule(&request, &pool).await?;
+
+    let id = insert_retention_rule(
+        request.target,
+        request.older_than_days,
+        category_id.as_deref(),
+        request.enabled,
+        &pool,
+    )
+    .await
+    .map_err(internal_error)?;
+
+    Ok((StatusCode::CREATED, Json(rule_or_404(id, &pool).await?)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/admin/retention/rules/{id}",
+    tag = "admin",
+    params(("id" = i64, Path, description = "Retention rule ID")),
+    request_body = RetentionRuleRequest,
+    responses(
+        (status = 200, description = "Rule updated", body = RetentionRule),
+        (status = 400, description = "Invalid rule"),
+        (status = 404, description = "Rule not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_update_retention_rule(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+    Json(request): Json<RetentionRuleRequest>,
+) -> Result<Json<RetentionRule>, (StatusCode, String)> {
+    let pool = db_pool(&bot_state).await?;
+    let category_id = validate_rule(&request, &pool).await?;
+
+    let updated = update_retention_rule(
+        id,
+        request.target,
+        request.older_than_days,
+        category_id.as_deref(),
+        request.enabled,
+        &pool,
+    )
+    .await
+    .map_err(internal_error)?;
+    if !updated {
+        return Err((
+            StatusCode::NOT_FOUND,
+            "Retention rule not found".to_string(),
+        ));
+    }
+
+    Ok(Json(rule_or_404(id, &pool).await?))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/retention/rules/{id}",
+    tag = "admin",
+    params(("id" = i64, Path, description = "Retention rule ID")),
+    responses(
+        (status = 204, description = "Rule deleted"),
+        (status = 404, description = "Rule not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_delete_retention_rule(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let pool = db_pool(&bot_state).await?;
+    if delete_retention_rule(id, &pool)
+        .await
+        .map_err(internal_error)?
+    {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err((
+            StatusCode::NOT_FOUND,
+            "Retention rule not found".to_string(),
+        ))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/retention/preview",
+    tag = "admin",
+    request_body = RetentionRuleRequest,
+    responses(
+        (status = 200, description = "Rows the rule would prune, nothing is changed", body = RetentionPreview),
+        (status = 400, description = "Invalid rule"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_preview_retention(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Json(request): Json<RetentionRuleRequest>,
+) -> Result<Json<RetentionPreview>, (StatusCode, String)> {
+    let pool = db_pool(&bot_state).await?;
+    let category_id = validate_rule(&request, &pool).await?;
+
+    let cutoff = retention_cutoff(request.older_than_days, Utc::now().timestamp());
+    let counts = preview_retention(request.target, cutoff, category_id.as_deref(), &pool)
+        .await
+        .map_err(internal_error)?;
+
+    Ok(Json(RetentionPreview { cutoff, counts }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/retention/rules/{id}/run",
+    tag = "admin",
+    params(("id" = i64, Path, description = "Retention rule ID")),
+    responses(
+        (status = 200, description = "Rule applied", body = RetentionRunResponse),
+        (status = 404, description = "Rule not found"),
+        (status = 500, description = "Internal error, nothing was pruned")
+    )
+)]
+pub async fn handle_run_retention_rule(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+) -> Result<Json<RetentionRunResponse>, (StatusCode, String)> {
+    let pool = db_pool(&bot_state).await?;
+    let rule = rule_or_404(id, &pool).await?;
+
+    let counts = run_retention_rule(&rule, &pool)
+        .await
+        .map_err(internal_error)?;
+
+    Ok(Json(RetentionRunResponse { counts }))
+}
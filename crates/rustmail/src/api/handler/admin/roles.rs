@@ -8,7 +8,7 @@ use serenity::all::GuildId;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize, utoipa::ToSchema)]
 pub struct RoleInfo {
     pub role_id: String,
     pub name: String,
@@ -16,10 +16,19 @@ pub struct RoleInfo {
     pub position: u16,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/admin/roles",
+    tag = "admin",
+    responses(
+        (status = 200, description = "Roles of the staff guild", body = [RoleInfo]),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_list_roles(State(bot_state): State<Arc<Mutex<BotState>>>) -> impl IntoResponse {
     let (guild_id, bot_http) = {
         let state_lock = bot_state.lock().await;
-        let config = match &state_lock.config {
+        let config = match state_lock.config() {
             Some(c) => c,
             None => {
                 return (
@@ -0,0 +1,82 @@
+use crate::db::repr::PanelSession;
+use crate::prelude::db::*;
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+async fn db_pool(bot_state: &Arc<Mutex<BotState>>) -> Result<SqlitePool, (StatusCode, String)> {
+    bot_state.lock().await.db_pool.clone().ok_or((
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Database not initialized".to_string(),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/sessions",
+    tag = "admin",
+    responses(
+        (status = 200, description = "All active panel sessions", body = [PanelSession]),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_list_sessions(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+) -> Result<Json<Vec<PanelSession>>, (StatusCode, String)> {
+    let db_pool = db_pool(&bot_state).await?;
+
+    list_sessions(None, &db_pool)
+        .await
+        .map(Json)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/sessions/{id}",
+    tag = "admin",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 204, description = "Session revoked"),
+        (status = 404, description = "Session not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_revoke_session(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let db_pool = db_pool(&bot_state).await?;
+
+    match delete_session_by_public_id(&id, None, &db_pool).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "Session not found".to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/sessions/user/{user_id}",
+    tag = "admin",
+    params(("user_id" = String, Path, description = "Discord user ID")),
+    responses(
+        (status = 204, description = "All sessions of the user revoked"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn handle_revoke_user_sessions(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(user_id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let db_pool = db_pool(&bot_state).await?;
+
+    delete_sessions_for_user(&user_id, &db_pool)
+        .await
+        .map(|_| StatusCode::NO_CONTENT)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
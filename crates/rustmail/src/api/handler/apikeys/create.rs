@@ -1,4 +1,4 @@
-use crate::db::operations::{create_api_key, generate_api_key};
+use crate::db::operations::{ApiKeyRestrictions, create_api_key, generate_api_key, parse_cidr};
 use crate::db::repr::Permission;
 use crate::prelude::types::*;
 use axum::Json;
@@ -8,14 +8,16 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct CreateApiKeyRequest {
     pub name: String,
     pub permissions: Vec<Permission>,
     pub expires_at: Option<i64>,
+    #[serde(flatten)]
+    pub restrictions: ApiKeyRestrictions,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 pub struct CreateApiKeyResponse {
     pub api_key: String,
     pub id: i64,
@@ -23,8 +25,63 @@ pub struct CreateApiKeyResponse {
     pub permissions: Vec<Permission>,
     pub created_at: i64,
     pub expires_at: Option<i64>,
+    #[serde(flatten)]
+    pub restrictions: ApiKeyRestrictions,
 }
 
+pub fn normalize_restrictions(
+    mut restrictions: ApiKeyRestrictions,
+) -> Result<ApiKeyRestrictions, (StatusCode, String)> {
+    for (label, value) in [
+        ("rate_limit_per_minute", restrictions.rate_limit_per_minute),
+        ("rate_limit_burst", restrictions.rate_limit_burst),
+    ] {
+        if value.is_some_and(|v| v <= 0) {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                format!("{} must be greater than 0", label),
+            ));
+        }
+    }
+
+    restrictions.allowed_cidrs = restrictions
+        .allowed_cidrs
+        .iter()
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+        .collect();
+    if let Some(invalid) = restrictions
+        .allowed_cidrs
+        .iter()
+        .find(|c| parse_cidr(c).is_none())
+    {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Invalid CIDR range '{}'", invalid),
+        ));
+    }
+
+    restrictions.allowed_category_ids = restrictions
+        .allowed_category_ids
+        .iter()
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty())
+        .collect();
+
+    Ok(restrictions)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/apikeys",
+    tag = "apikeys",
+    request_body = CreateApiKeyRequest,
+    responses(
+        (status = 200, description = "API key created; the plain key is only returned once", body = CreateApiKeyResponse),
+        (status = 400, description = "Invalid name, permissions or restrictions"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn create_api_key_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Json(req): Json<CreateApiKeyRequest>,
@@ -40,6 +97,8 @@ pub async fn create_api_key_handler(
         ));
     }
 
+    let restrictions = normalize_restrictions(req.restrictions)?;
+
     let db_pool = {
         let state_lock = bot_state.lock().await;
         match &state_lock.db_pool {
@@ -64,6 +123,7 @@ pub async fn create_api_key_handler(
         req.name,
         req.permissions,
         req.expires_at,
+        restrictions,
     )
     .await
     {
@@ -78,5 +138,11 @@ pub async fn create_api_key_handler(
         permissions: api_key.permissions,
         created_at: api_key.created_at,
         expires_at: api_key.expires_at,
+        restrictions: ApiKeyRestrictions {
+            rate_limit_per_minute: api_key.rate_limit_per_minute,
+            rate_limit_burst: api_key.rate_limit_burst,
+            allowed_cidrs: api_key.allowed_cidrs,
+            allowed_category_ids: api_key.allowed_category_ids,
+        },
     }))
 }
@@ -5,6 +5,16 @@ use axum::http::StatusCode;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+#[utoipa::path(
+    delete,
+    path = "/api/apikeys/{id}",
+    tag = "apikeys",
+    params(("id" = i64, Path, description = "API key ID")),
+    responses(
+        (status = 204, description = "API key and its request log deleted"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn delete_api_key_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path(id): Path<i64>,
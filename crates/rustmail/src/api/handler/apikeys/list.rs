@@ -1,4 +1,4 @@
-use crate::db::operations::list_api_keys;
+use crate::db::operations::{ApiKeyRestrictions, list_api_keys};
 use crate::db::repr::{ApiKey, Permission};
 use crate::prelude::types::*;
 use axum::Json;
@@ -8,7 +8,7 @@ use serde::Serialize;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-#[derive(Serialize)]
+#[derive(Serialize, utoipa::ToSchema)]
 pub struct ApiKeyListItem {
     pub id: i64,
     pub name: String,
@@ -18,6 +18,8 @@ pub struct ApiKeyListItem {
     pub last_used_at: Option<i64>,
     pub is_active: bool,
     pub key_preview: String,
+    #[serde(flatten)]
+    pub restrictions: ApiKeyRestrictions,
 }
 
 impl From<ApiKey> for ApiKeyListItem {
@@ -37,10 +39,25 @@ impl From<ApiKey> for ApiKeyListItem {
             last_used_at: key.last_used_at,
             is_active: key.is_active,
             key_preview,
+            restrictions: ApiKeyRestrictions {
+                rate_limit_per_minute: key.rate_limit_per_minute,
+                rate_limit_burst: key.rate_limit_burst,
+                allowed_cidrs: key.allowed_cidrs,
+                allowed_category_ids: key.allowed_category_ids,
+            },
         }
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/apikeys",
+    tag = "apikeys",
+    responses(
+        (status = 200, description = "All API keys", body = [ApiKeyListItem]),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn list_api_keys_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
 ) -> Result<Json<Vec<ApiKeyListItem>>, (StatusCode, String)> {
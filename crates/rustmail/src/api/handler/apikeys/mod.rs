@@ -1,9 +1,13 @@
 mod create;
 mod delete;
 mod list;
+mod requests;
+mod restrictions;
 mod revoke;
 
 pub use create::*;
 pub use delete::*;
 pub use list::*;
+pub use requests::*;
+pub use restrictions::*;
 pub use revoke::*;
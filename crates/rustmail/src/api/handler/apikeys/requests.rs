@@ -0,0 +1,51 @@
+use crate::db::operations::list_api_key_requests;
+use crate::db::repr::ApiKeyRequestLog;
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use serde::Deserialize;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[derive(Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ApiKeyRequestsQuery {
+    pub limit: Option<i64>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/apikeys/{id}/requests",
+    tag = "apikeys",
+    params(("id" = i64, Path, description = "API key ID"), ApiKeyRequestsQuery),
+    responses(
+        (status = 200, description = "Most recent requests made with the key, newest first", body = [ApiKeyRequestLog]),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_api_key_requests_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+    Query(query): Query<ApiKeyRequestsQuery>,
+) -> Result<Json<Vec<ApiKeyRequestLog>>, (StatusCode, String)> {
+    let db_pool = {
+        let state_lock = bot_state.lock().await;
+        match &state_lock.db_pool {
+            Some(pool) => pool.clone(),
+            None => {
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Database not initialized".to_string(),
+                ));
+            }
+        }
+    };
+
+    let limit = query.limit.unwrap_or(100).clamp(1, 1000);
+
+    match list_api_key_requests(&db_pool, id, limit).await {
+        Ok(logs) => Ok(Json(logs)),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e)),
+    }
+}
@@ -0,0 +1,48 @@
+use crate::api::handler::apikeys::normalize_restrictions;
+use crate::db::operations::{ApiKeyRestrictions, update_api_key_restrictions};
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[utoipa::path(
+    put,
+    path = "/api/apikeys/{id}/restrictions",
+    tag = "apikeys",
+    params(("id" = i64, Path, description = "API key ID")),
+    request_body = ApiKeyRestrictions,
+    responses(
+        (status = 204, description = "Restrictions replaced"),
+        (status = 400, description = "Invalid rate limit or CIDR"),
+        (status = 404, description = "API key not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn update_api_key_restrictions_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+    Json(req): Json<ApiKeyRestrictions>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let restrictions = normalize_restrictions(req)?;
+
+    let db_pool = {
+        let state_lock = bot_state.lock().await;
+        match &state_lock.db_pool {
+            Some(pool) => pool.clone(),
+            None => {
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Database not initialized".to_string(),
+                ));
+            }
+        }
+    };
+
+    match update_api_key_restrictions(&db_pool, id, &restrictions).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "API key not found".to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e)),
+    }
+}
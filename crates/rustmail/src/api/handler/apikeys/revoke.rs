@@ -5,6 +5,16 @@ use axum::http::StatusCode;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+#[utoipa::path(
+    post,
+    path = "/api/apikeys/{id}/revoke",
+    tag = "apikeys",
+    params(("id" = i64, Path, description = "API key ID")),
+    responses(
+        (status = 204, description = "API key revoked"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn revoke_api_key_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path(id): Path<i64>,
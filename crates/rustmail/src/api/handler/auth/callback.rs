@@ -1,14 +1,19 @@
+use crate::prelude::db::*;
 use crate::prelude::types::*;
 use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::http::header::USER_AGENT;
 use axum::response::Redirect;
 use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
 use chrono::{Duration, Utc};
 use reqwest::Client;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::error;
 use uuid::Uuid;
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct AuthRequest {
     pub code: String,
     pub state: Option<String>,
@@ -24,13 +29,24 @@ struct DiscordUser {
     avatar: Option<String>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/auth/callback",
+    tag = "auth",
+    security(()),
+    params(AuthRequest),
+    responses(
+        (status = 303, description = "Session cookie set and redirect to the panel, or to the error page")
+    )
+)]
 pub async fn handle_callback(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     jar: CookieJar,
+    headers: HeaderMap,
     Query(params): Query<AuthRequest>,
 ) -> (CookieJar, Redirect) {
     let state_lock = bot_state.lock().await;
-    let Some(config) = &state_lock.config else {
+    let Some(config) = state_lock.config() else {
         return (jar, Redirect::to("/error?message=Missing+configuration"));
     };
 
@@ -54,13 +70,13 @@ pub async fn handle_callback(
     {
         Ok(resp) => {
             if !resp.status().is_success() {
-                eprintln!("⚠️ Token exchange failed with status: {}", resp.status());
+                error!("⚠️ Token exchange failed with status: {}", resp.status());
                 return (jar, Redirect::to("/error?message=Token+exchange+failed"));
             }
             resp
         }
         Err(e) => {
-            eprintln!(
+            error!(
                 "⚠️ Failed to exchange code for token (maybe client_secret or client_id: {}",
                 e
             );
@@ -85,7 +101,7 @@ pub async fn handle_callback(
     {
         Ok(resp) => resp,
         Err(e) => {
-            eprintln!("⚠️ Failed to fetch user info: {}", e);
+            error!("⚠️ Failed to fetch user info: {}", e);
             return (
                 jar,
                 Redirect::to("/error?message=Failed+to+fetch+user+info"),
@@ -96,7 +112,7 @@ pub async fn handle_callback(
     let user: DiscordUser = match user_response.json().await {
         Ok(user) => user,
         Err(e) => {
-            eprintln!("⚠️ Failed to parse user info: {}", e);
+            error!("⚠️ Failed to parse user info: {}", e);
             return (
                 jar,
                 Redirect::to("/error?message=Failed+to+parse+user+info"),
@@ -109,35 +125,27 @@ pub async fn handle_callback(
     let expires_at = Utc::now() + Duration::seconds(expires_in);
     let timestamp = expires_at.timestamp();
 
-    if let Err(e) = sqlx::query!(r#"DELETE FROM sessions_panel WHERE user_id = ?"#, user_id)
-        .execute(db_pool)
-        .await
-    {
-        eprintln!("⚠️ Failed to delete old session: {}", e);
-        return (jar, Redirect::to("/error?message=Database+delete+failed"));
-    }
-
-    if let Err(e) = sqlx::query!(
-        r#"
-        INSERT INTO sessions_panel (session_id, user_id, access_token, refresh_token, expires_at, avatar_hash)
-        VALUES (?, ?, ?, ?, ?, ?)
-        ON CONFLICT(session_id) DO UPDATE SET
-            access_token = excluded.access_token,
-            refresh_token = excluded.refresh_token,
-            expires_at = excluded.expires_at,
-            avatar_hash = excluded.avatar_hash
-        "#,
-        session_id,
-        user_id,
-        access_token,
-        refresh_token,
-        timestamp,
-        user.avatar
+    let user_agent = headers
+        .get(USER_AGENT)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.chars().take(256).collect::<String>());
+
+    if let Err(e) = create_session(
+        NewPanelSession {
+            session_id: &session_id,
+            user_id: &user_id,
+            access_token,
+            refresh_token,
+            expires_at: timestamp,
+            avatar_hash: user.avatar.as_deref(),
+            user_agent: user_agent.as_deref(),
+            now: Utc::now().timestamp(),
+        },
+        db_pool,
     )
-    .execute(db_pool)
     .await
     {
-        eprintln!("⚠️ Failed to store session in database: {}", e);
+        error!("⚠️ Failed to store session in database: {:?}", e);
         return (jar, Redirect::to("/error?message=Database+write+failed"));
     }
 
@@ -8,6 +8,16 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+#[utoipa::path(
+    get,
+    path = "/api/auth/login",
+    tag = "auth",
+    security(()),
+    params(("redirect" = Option<String>, Query, description = "Panel path to return to after login")),
+    responses(
+        (status = 303, description = "Redirect to Discord OAuth2, or to the panel when already logged in")
+    )
+)]
 pub async fn handle_login(
     jar: CookieJar,
     State(bot_state): State<Arc<Mutex<BotState>>>,
@@ -31,7 +41,7 @@ pub async fn handle_login(
     let bot_config = {
         let state_lock = bot_state.lock().await;
 
-        if let Some(config) = &state_lock.config {
+        if let Some(config) = state_lock.config() {
             config.clone()
         } else {
             return Redirect::to("/error?message=Bot+not+configured");
@@ -6,6 +6,14 @@ use axum_extra::extract::CookieJar;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+#[utoipa::path(
+    get,
+    path = "/api/auth/logout",
+    tag = "auth",
+    responses(
+        (status = 303, description = "Session deleted and redirect to the home page")
+    )
+)]
 pub async fn handle_logout(
     jar: CookieJar,
     State(bot_state): State<Arc<Mutex<BotState>>>,
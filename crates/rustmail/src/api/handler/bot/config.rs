@@ -1,77 +1,77 @@
-use crate::config::{Config, load_config, save_config_with_backup, validate_config};
+use crate::bot::save_and_reload_config;
+use crate::config::{Config, ReloadImpact, validate_config};
+use crate::db::repr::{ConfigRevisionOrigin, ConfigRevisionSource};
+use crate::prelude::api::*;
 use crate::prelude::types::*;
 use axum::Json;
 use axum::extract::State;
 use axum::http::StatusCode;
+use axum_extra::extract::CookieJar;
 use rustmail_types::ConfigResponse;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-fn mask_secret(secret: &str) -> String {
-    let len = secret.chars().count();
-    if len <= 8 {
-        "*".repeat(len)
-    } else {
-        let chars: Vec<char> = secret.chars().collect();
-        let start: String = chars.iter().take(4).collect();
-        let end: String = chars.iter().skip(len - 4).collect();
-        format!("{}...{}", start, end)
-    }
-}
-
+#[utoipa::path(
+    get,
+    path = "/api/bot/config",
+    tag = "bot",
+    responses(
+        (status = 200, description = "Current configuration with masked secrets", body = ConfigResponse),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_get_config(
     State(bot_state): State<Arc<Mutex<BotState>>>,
 ) -> Result<Json<ConfigResponse>, StatusCode> {
     let state = bot_state.lock().await;
 
-    let config = match &state.config {
+    let config = match state.config() {
         Some(c) => c,
         None => return Err(StatusCode::INTERNAL_SERVER_ERROR),
     };
 
-    let mut masked_bot = config.bot.clone();
-    masked_bot.token = mask_secret(&config.bot.token);
-    masked_bot.client_secret = mask_secret(&config.bot.client_secret);
-
-    let response = ConfigResponse {
-        bot: masked_bot,
-        command: config.command.clone(),
-        thread: config.thread.clone(),
-        language: config.language.clone(),
-        error_handling: config.error_handling.clone(),
-        notifications: config.notifications.clone(),
-        reminders: config.reminders.clone(),
-        logs: config.logs.clone(),
-    };
-
-    Ok(Json(response))
+    Ok(Json(config.to_masked_response()))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/bot/config",
+    tag = "bot",
+    request_body = ConfigResponse,
+    responses(
+        (status = 200, description = "Configuration saved and applied; lists changed fields that still need a reconnect or a restart", body = Object),
+        (status = 400, description = "Invalid configuration"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_update_config(
     State(bot_state): State<Arc<Mutex<BotState>>>,
+    jar: CookieJar,
     Json(update): Json<ConfigResponse>,
 ) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
-    let current_config = {
-        let state = bot_state.lock().await;
-        match &state.config {
-            Some(c) => c.clone(),
-            None => {
-                return Err((
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    "Configuration not loaded".to_string(),
-                ));
-            }
-        }
+    let origin = ConfigRevisionOrigin {
+        author_id: session_user_id(&bot_state, &jar).await,
+        ..ConfigRevisionOrigin::new(ConfigRevisionSource::Panel)
     };
+    apply_config_update(&bot_state, update, origin).await
+}
+
+pub async fn session_user_id(bot_state: &Arc<Mutex<BotState>>, jar: &CookieJar) -> Option<String> {
+    let session_id = jar.get("session_id")?.value().to_string();
+    let pool = bot_state.lock().await.db_pool.clone()?;
+    let user_id = get_user_id_from_session(&session_id, &pool).await;
+    (!user_id.is_empty()).then_some(user_id)
+}
 
-    let mut new_bot_config = update.bot.clone();
+pub fn build_config(update: ConfigResponse, current: &Config) -> Result<Config, String> {
+    let mut new_bot_config = update.bot;
 
     if new_bot_config.token.contains("...") {
-        new_bot_config.token = current_config.bot.token.clone();
+        new_bot_config.token = current.bot.token.clone();
     }
 
     if new_bot_config.client_secret.contains("...") {
-        new_bot_config.client_secret = current_config.bot.client_secret.clone();
+        new_bot_config.client_secret = current.bot.client_secret.clone();
     }
 
     let new_config = Config {
@@ -83,24 +83,54 @@ pub async fn handle_update_config(
         notifications: update.notifications,
         reminders: update.reminders,
         logs: update.logs,
+        logging: update.logging,
+        backups: update.backups,
         db_pool: None,
         error_handler: None,
         thread_locks: Arc::new(std::sync::Mutex::new(std::collections::HashMap::new())),
     };
 
-    if let Err(e) = validate_config(&new_config) {
-        return Err((StatusCode::BAD_REQUEST, e));
-    }
+    validate_config(&new_config)?;
+    Ok(new_config)
+}
 
-    if let Err(e) = save_config_with_backup(&new_config, "config.toml").await {
-        return Err((StatusCode::INTERNAL_SERVER_ERROR, e));
-    }
+pub async fn apply_config_update(
+    bot_state: &Arc<Mutex<BotState>>,
+    update: ConfigResponse,
+    origin: ConfigRevisionOrigin,
+) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    let current_config = {
+        let state = bot_state.lock().await;
+        match state.config() {
+            Some(c) => c,
+            None => {
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    "Configuration not loaded".to_string(),
+                ));
+            }
+        }
+    };
 
-    let mut state = bot_state.lock().await;
-    state.config = load_config("config.toml");
+    let new_config =
+        build_config(update, &current_config).map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let changes = save_and_reload_config(bot_state, &new_config, origin)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e))?;
+    let fields = |impact: ReloadImpact| -> Vec<&str> {
+        changes
+            .iter()
+            .filter(|c| c.impact == impact)
+            .map(|c| c.field.as_str())
+            .collect()
+    };
 
     Ok(Json(serde_json::json!({
         "success": true,
-        "message": "Configuration saved successfully. Restart the bot to apply changes."
+        "message": "Configuration saved and applied.",
+        "applied": fields(ReloadImpact::Live),
+        "reconnect_required": fields(ReloadImpact::Reconnect),
+        "restart_required": fields(ReloadImpact::Restart),
     })))
 }
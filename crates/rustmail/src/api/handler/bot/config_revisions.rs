@@ -0,0 +1,269 @@
+use crate::config::{Config, ConfigFieldDiff, diff_config_responses};
+use crate::db::operations::{
+    get_config_revision, get_previous_config_revision, list_config_revisions,
+};
+use crate::db::repr::{ConfigRevision, ConfigRevisionOrigin, ConfigRevisionSource};
+use crate::prelude::api::*;
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum_extra::extract::CookieJar;
+use rustmail_types::ConfigResponse;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+async fn pool(bot_state: &Arc<Mutex<BotState>>) -> Result<SqlitePool, (StatusCode, String)> {
+    let state_lock = bot_state.lock().await;
+    match &state_lock.db_pool {
+        Some(p) => Ok(p.clone()),
+        None => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Database not initialized".to_string(),
+        )),
+    }
+}
+
+fn internal(e: impl ToString) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+async fn revision(id: i64, pool: &SqlitePool) -> Result<ConfigRevision, (StatusCode, String)> {
+    get_config_revision(id, pool)
+        .await
+        .map_err(internal)?
+        .ok_or((StatusCode::NOT_FOUND, "Revision not found".to_string()))
+}
+
+// Revisions only hold masked secrets, so a rollback keeps the current ones.
+fn rollback_update(mut snapshot: ConfigResponse, current: &Config) -> ConfigResponse {
+    snapshot.bot.token = current.bot.token.clone();
+    snapshot.bot.client_secret = current.bot.client_secret.clone();
+    snapshot
+}
+
+fn snapshot(revision: &ConfigRevision) -> Result<ConfigResponse, (StatusCode, String)> {
+    serde_json::from_str(&revision.snapshot).map_err(internal)
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct ConfigRevisionDto {
+    pub id: i64,
+    pub source: ConfigRevisionSource,
+    pub author_id: Option<String>,
+    pub rollback_of: Option<i64>,
+    pub created_at: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<ConfigResponse>,
+}
+
+impl From<&ConfigRevision> for ConfigRevisionDto {
+    fn from(r: &ConfigRevision) -> Self {
+        Self {
+            id: r.id,
+            source: r.source,
+            author_id: r.author_id.clone(),
+            rollback_of: r.rollback_of,
+            created_at: r.created_at,
+            snapshot: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ConfigRevisionQuery {
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct PaginatedConfigRevisions {
+    pub revisions: Vec<ConfigRevisionDto>,
+    pub total: i64,
+    pub page: i64,
+    pub page_size: i64,
+    pub total_pages: i64,
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ConfigDiffQuery {
+    pub against: Option<i64>,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct ConfigRevisionDiff {
+    pub from: Option<i64>,
+    pub to: i64,
+    pub changes: Vec<ConfigFieldDiff>,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/bot/config/revisions",
+    tag = "bot",
+    params(ConfigRevisionQuery),
+    responses(
+        (status = 200, description = "Configuration revisions, newest first", body = PaginatedConfigRevisions),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_config_revisions_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Query(params): Query<ConfigRevisionQuery>,
+) -> Result<Json<PaginatedConfigRevisions>, (StatusCode, String)> {
+    let page = params.page.unwrap_or(1).max(1);
+    let page_size = params.page_size.unwrap_or(50).clamp(1, 200);
+
+    let p = pool(&bot_state).await?;
+    let (revisions, total) = list_config_revisions(page_size, (page - 1) * page_size, &p)
+        .await
+        .map_err(internal)?;
+
+    Ok(Json(PaginatedConfigRevisions {
+        revisions: revisions.iter().map(ConfigRevisionDto::from).collect(),
+        total,
+        page,
+        page_size,
+        total_pages: (total + page_size - 1) / page_size,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/bot/config/revisions/{id}",
+    tag = "bot",
+    params(("id" = i64, Path, description = "Revision ID")),
+    responses(
+        (status = 200, description = "Revision with its masked configuration", body = ConfigRevisionDto),
+        (status = 404, description = "Revision not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_config_revision_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+) -> Result<Json<ConfigRevisionDto>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let revision = revision(id, &p).await?;
+    Ok(Json(ConfigRevisionDto {
+        snapshot: Some(snapshot(&revision)?),
+        ..ConfigRevisionDto::from(&revision)
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/bot/config/revisions/{id}/diff",
+    tag = "bot",
+    params(("id" = i64, Path, description = "Revision ID"), ConfigDiffQuery),
+    responses(
+        (status = 200, description = "Settings changed between the two revisions", body = ConfigRevisionDiff),
+        (status = 404, description = "Revision not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn diff_config_revision_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+    Query(params): Query<ConfigDiffQuery>,
+) -> Result<Json<ConfigRevisionDiff>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let to = revision(id, &p).await?;
+    let from = match params.against {
+        Some(against) => Some(revision(against, &p).await?),
+        None => get_previous_config_revision(id, &p)
+            .await
+            .map_err(internal)?,
+    };
+
+    let changes = match &from {
+        Some(from) => diff_config_responses(&snapshot(from)?, &snapshot(&to)?),
+        None => Vec::new(),
+    };
+
+    Ok(Json(ConfigRevisionDiff {
+        from: from.map(|r| r.id),
+        to: to.id,
+        changes,
+    }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/bot/config/revisions/{id}/rollback",
+    tag = "bot",
+    params(("id" = i64, Path, description = "Revision ID")),
+    responses(
+        (status = 200, description = "Configuration restored and applied; the current token and client secret are kept", body = Object),
+        (status = 400, description = "The revision no longer validates"),
+        (status = 404, description = "Revision not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn rollback_config_revision_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+    jar: CookieJar,
+) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let snapshot = snapshot(&revision(id, &p).await?)?;
+    let current = bot_state.lock().await.config().ok_or((
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Configuration not loaded".to_string(),
+    ))?;
+    let update = rollback_update(snapshot, &current);
+
+    let origin = ConfigRevisionOrigin {
+        author_id: session_user_id(&bot_state, &jar).await,
+        rollback_of: Some(id),
+        ..ConfigRevisionOrigin::new(ConfigRevisionSource::Rollback)
+    };
+    apply_config_update(&bot_state, update, origin).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::handler::bot::config::build_config;
+    use crate::config::test_config;
+
+    fn current() -> Config {
+        let mut config = test_config();
+        config.bot.token = "current-bot-token-value".to_string();
+        config.bot.client_secret = "secret".to_string();
+        config
+    }
+
+    #[test]
+    fn rollback_keeps_the_current_secrets() {
+        let mut old = test_config();
+        old.bot.token = "previous-bot-token-value".to_string();
+        old.bot.client_secret = "old".to_string();
+        old.bot.status = "Old status".to_string();
+
+        let current = current();
+        let restored = build_config(
+            rollback_update(old.to_masked_response(), &current),
+            &current,
+        )
+        .unwrap();
+
+        assert_eq!(restored.bot.token, current.bot.token);
+        assert_eq!(restored.bot.client_secret, current.bot.client_secret);
+        assert_eq!(restored.bot.status, "Old status");
+    }
+
+    #[test]
+    fn rollback_rejects_a_revision_that_no_longer_validates() {
+        let mut snapshot = test_config().to_masked_response();
+        snapshot.thread.user_message_color = "not a color".to_string();
+
+        let current = current();
+        let err = build_config(rollback_update(snapshot, &current), &current).unwrap_err();
+        assert!(err.contains("user message color"));
+    }
+}
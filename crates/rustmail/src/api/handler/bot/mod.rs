@@ -1,4 +1,5 @@
 pub mod config;
+pub mod config_revisions;
 pub mod profile;
 pub mod restart;
 pub mod start;
@@ -8,6 +9,7 @@ pub mod stop;
 pub mod tickets;
 
 pub use config::*;
+pub use config_revisions::*;
 pub use profile::*;
 pub use restart::*;
 pub use start::*;
@@ -9,7 +9,7 @@ use serenity::all::EditProfile;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
 pub struct UpdateProfileRequest {
     #[serde(default)]
     pub username: Option<String>,
@@ -19,6 +19,15 @@ pub struct UpdateProfileRequest {
     pub banner: Option<String>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/bot/profile",
+    tag = "bot",
+    responses(
+        (status = 200, description = "Bot user profile", body = Object),
+        (status = 503, description = "Bot is not running")
+    )
+)]
 pub async fn handle_get_profile(
     State(bot_state): State<Arc<Mutex<BotState>>>,
 ) -> impl IntoResponse {
@@ -52,6 +61,18 @@ pub async fn handle_get_profile(
     )
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/bot/profile",
+    tag = "bot",
+    request_body = UpdateProfileRequest,
+    responses(
+        (status = 200, description = "Profile updated", body = Object),
+        (status = 400, description = "Invalid username or image"),
+        (status = 503, description = "Bot is not running"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_update_profile(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Json(payload): Json<UpdateProfileRequest>,
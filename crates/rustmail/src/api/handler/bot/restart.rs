@@ -8,6 +8,14 @@ use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
 
+#[utoipa::path(
+    post,
+    path = "/api/bot/restart",
+    tag = "bot",
+    responses(
+        (status = 200, description = "Bot is restarting", body = String)
+    )
+)]
 pub async fn handle_restart_bot(
     State(bot_state): State<Arc<Mutex<BotState>>>,
 ) -> (StatusCode, Json<&'static str>) {
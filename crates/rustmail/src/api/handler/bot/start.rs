@@ -6,6 +6,15 @@ use axum::http::StatusCode;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+#[utoipa::path(
+    post,
+    path = "/api/bot/start",
+    tag = "bot",
+    responses(
+        (status = 200, description = "Bot started", body = String),
+        (status = 409, description = "Bot is already running", body = String)
+    )
+)]
 pub async fn handle_start_bot(
     State(bot_state): State<Arc<Mutex<BotState>>>,
 ) -> (StatusCode, Json<&'static str>) {
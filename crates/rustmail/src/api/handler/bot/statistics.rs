@@ -7,7 +7,8 @@ use axum::response::IntoResponse;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct StatisticsQuery {
     #[serde(default = "default_days")]
     pub days: i64,
@@ -17,6 +18,17 @@ fn default_days() -> i64 {
     30
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/bot/statistics",
+    tag = "bot",
+    params(StatisticsQuery),
+    responses(
+        (status = 200, description = "Ticket statistics for the period", body = Statistics),
+        (status = 503, description = "Database not initialized"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_statistics(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Query(query): Query<StatisticsQuery>,
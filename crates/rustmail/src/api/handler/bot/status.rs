@@ -11,6 +11,14 @@ use std::sync::Arc;
 use std::sync::atomic::Ordering;
 use tokio::sync::Mutex;
 
+#[utoipa::path(
+    get,
+    path = "/api/bot/status",
+    tag = "bot",
+    responses(
+        (status = 200, description = "Bot status and presence", body = Object)
+    )
+)]
 pub async fn handle_status_bot(State(bot_state): State<Arc<Mutex<BotState>>>) -> impl IntoResponse {
     let state_lock = bot_state.lock().await;
     let presence_status = state_lock.presence_status.read().await.clone();
@@ -33,11 +41,23 @@ pub async fn handle_status_bot(State(bot_state): State<Arc<Mutex<BotState>>>) ->
     }
 }
 
-#[derive(serde::Deserialize)]
+#[derive(serde::Deserialize, utoipa::ToSchema)]
 pub struct PresenceStatusRequest {
     pub status: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/bot/presence",
+    tag = "bot",
+    request_body = PresenceStatusRequest,
+    responses(
+        (status = 200, description = "Presence updated", body = Object),
+        (status = 400, description = "Unknown presence status"),
+        (status = 503, description = "Bot is not running"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_set_presence(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Json(payload): Json<PresenceStatusRequest>,
@@ -65,7 +85,7 @@ pub async fn handle_set_presence(
         }
     };
 
-    let config = match &state_lock.config {
+    let config = match state_lock.config() {
         Some(c) => c.clone(),
         None => {
             return (
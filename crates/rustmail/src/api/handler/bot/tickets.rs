@@ -1,3 +1,9 @@
+use crate::db::operations::{
+    get_thread_form_answers, get_thread_priority, get_thread_ticket_category_id,
+    get_thread_workflow, list_category_access, list_thread_state_history,
+};
+use crate::db::repr::{FormAnswer, ThreadStateChange, TicketPriority, TicketWorkflowState};
+use crate::modules::category_access::{StaffIdentity, inaccessible_category_ids};
 use crate::prelude::api::*;
 use crate::prelude::types::*;
 use axum::{
@@ -7,10 +13,12 @@ use axum::{
 };
 use axum_extra::extract::CookieJar;
 use serde::{Deserialize, Serialize};
+use serenity::all::{GuildId, UserId};
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::error;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
 pub struct ThreadMessage {
     pub id: i64,
     pub thread_id: String,
@@ -25,7 +33,7 @@ pub struct ThreadMessage {
     pub is_internal: bool,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
 pub struct CompleteThread {
     pub id: String,
     pub user_id: i64,
@@ -40,21 +48,31 @@ pub struct CompleteThread {
     pub category_id: Option<String>,
     pub category_name: Option<String>,
     pub required_permissions: Option<String>,
+    pub priority: TicketPriority,
+    pub workflow_state: TicketWorkflowState,
+    pub state_until: Option<i64>,
     pub messages: Vec<ThreadMessage>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub form_answers: Vec<FormAnswer>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub state_history: Vec<ThreadStateChange>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
 pub struct TicketQuery {
     pub id: Option<String>,
     pub page: Option<i64>,
     pub page_size: Option<i64>,
     pub status: Option<i64>,
     pub category_id: Option<String>,
+    pub priority: Option<String>,
+    pub state: Option<String>,
     pub sort_by: Option<String>,
     pub sort_order: Option<String>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, utoipa::ToSchema)]
 pub struct PaginatedThreadsResponse {
     pub threads: Vec<CompleteThread>,
     pub total: i64,
@@ -63,6 +81,19 @@ pub struct PaginatedThreadsResponse {
     pub total_pages: i64,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/bot/tickets",
+    tag = "tickets",
+    params(TicketQuery),
+    responses(
+        (status = 200, description = "Paginated tickets, or a single `CompleteThread` when `id` is set", body = PaginatedThreadsResponse),
+        (status = 401, description = "No panel session"),
+        (status = 403, description = "Missing permission for the ticket category"),
+        (status = 404, description = "Ticket not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_tickets_bot(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     jar: CookieJar,
@@ -97,7 +128,7 @@ pub async fn handle_tickets_bot(
 
     let (guild_id, bot_http) = {
         let state_lock = bot_state.lock().await;
-        let guild_id = match &state_lock.config {
+        let guild_id = match state_lock.config() {
             Some(config) => config.bot.get_staff_guild_id(),
             None => {
                 return (
@@ -124,6 +155,35 @@ pub async fn handle_tickets_bot(
 
     let is_admin = is_admin_or_owner(&user_id, guild_id, bot_http.clone()).await;
 
+    // Ticket categories with an access list that excludes this user.
+    let hidden_categories = if is_admin {
+        Vec::new()
+    } else {
+        let identity = match user_id.parse::<u64>() {
+            Ok(id) => {
+                StaffIdentity::fetch(&bot_http, GuildId::new(guild_id), UserId::new(id)).await
+            }
+            Err(_) => None,
+        };
+        let hidden = match identity {
+            Some(identity) => inaccessible_category_ids(&identity, &db_pool).await,
+            None => list_category_access(&db_pool)
+                .await
+                .map(|lists| lists.into_keys().collect()),
+        };
+        match hidden {
+            Ok(hidden) => hidden,
+            Err(_) => {
+                return (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({
+                        "error": "Failed to check category access"
+                    })),
+                );
+            }
+        }
+    };
+
     if let Some(id) = params.id {
         let thread = match sqlx::query!(
             r#"
@@ -159,7 +219,7 @@ pub async fn handle_tickets_bot(
                 );
             }
             Err(err) => {
-                eprintln!("Erreur SQL thread {}: {:?}", id, err);
+                error!("Erreur SQL thread {}: {:?}", id, err);
                 return (
                     StatusCode::INTERNAL_SERVER_ERROR,
                     Json(serde_json::json!({
@@ -194,7 +254,7 @@ pub async fn handle_tickets_bot(
         {
             Ok(rows) => rows,
             Err(err) => {
-                eprintln!("Erreur SQL messages pour {}: {:?}", thread.id, err);
+                error!("Erreur SQL messages pour {}: {:?}", thread.id, err);
                 Vec::new()
             }
         };
@@ -216,6 +276,26 @@ pub async fn handle_tickets_bot(
             })
             .collect();
 
+        let form_answers = get_thread_form_answers(&thread.id, &db_pool)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+
+        let priority = get_thread_priority(&thread.id, &db_pool)
+            .await
+            .unwrap_or_default();
+
+        let (workflow_state, state_until) = get_thread_workflow(&thread.id, &db_pool)
+            .await
+            .ok()
+            .flatten()
+            .unwrap_or((TicketWorkflowState::Open, None));
+
+        let state_history = list_thread_state_history(&thread.id, &db_pool)
+            .await
+            .unwrap_or_default();
+
         let complete = CompleteThread {
             id: thread.id,
             user_id: thread.user_id,
@@ -237,9 +317,25 @@ pub async fn handle_tickets_bot(
             category_id: thread.category_id,
             category_name: thread.category_name,
             required_permissions: thread.required_permissions.clone(),
+            priority,
+            workflow_state,
+            state_until,
             messages,
+            form_answers,
+            state_history,
         };
 
+        if let Ok(Some(ticket_category_id)) = get_thread_ticket_category_id(&id, &db_pool).await
+            && hidden_categories.contains(&ticket_category_id)
+        {
+            return (
+                StatusCode::FORBIDDEN,
+                Json(serde_json::json!({
+                    "error": "You don't have permission to view this ticket"
+                })),
+            );
+        }
+
         if !is_admin
             && let Some(ref category_id) = complete.category_id
             && !category_id.is_empty()
@@ -288,12 +384,35 @@ pub async fn handle_tickets_bot(
         where_conditions.push(format!("category_id = '{}'", cat_id.replace("'", "''")));
     }
 
+    if let Some(priority) = params.priority.as_deref().and_then(TicketPriority::parse) {
+        where_conditions.push(format!("priority = '{}'", priority.as_str()));
+    }
+
+    if let Some(state) = params.state.as_deref().and_then(TicketWorkflowState::parse) {
+        where_conditions.push(format!("workflow_state = '{}'", state.as_str()));
+    }
+
+    if !hidden_categories.is_empty() {
+        let hidden = hidden_categories
+            .iter()
+            .map(|id| format!("'{}'", id.replace("'", "''")))
+            .collect::<Vec<_>>()
+            .join(",");
+        where_conditions.push(format!(
+            "(ticket_category_id IS NULL OR ticket_category_id NOT IN ({}))",
+            hidden
+        ));
+    }
+
     let where_clause = where_conditions.join(" AND ");
 
     let sort_column = match params.sort_by.as_deref() {
         Some("user_name") => "user_name",
         Some("closed_at") => "closed_at",
         Some("created_at") => "created_at",
+        Some("priority") => {
+            "CASE priority WHEN 'urgent' THEN 3 WHEN 'high' THEN 2 WHEN 'normal' THEN 1 ELSE 0 END"
+        }
         _ => "created_at",
     };
 
@@ -313,7 +432,7 @@ pub async fn handle_tickets_bot(
     {
         Ok(count) => count,
         Err(err) => {
-            eprintln!("Erreur SQL count: {:?}", err);
+            error!("Erreur SQL count: {:?}", err);
             return (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(serde_json::json!({
@@ -338,10 +457,13 @@ pub async fn handle_tickets_bot(
             closed_by,
             category_id,
             category_name,
-            required_permissions
+            required_permissions,
+            priority,
+            workflow_state,
+            state_until
         FROM threads
         WHERE {}
-        ORDER BY {} {}
+        ORDER BY {} {}, created_at DESC
         LIMIT {} OFFSET {}
         "#,
         where_clause, sort_column, sort_order, page_size, offset
@@ -364,6 +486,9 @@ pub async fn handle_tickets_bot(
             Option<String>,
             Option<String>,
             Option<String>,
+            String,
+            String,
+            Option<i64>,
         ),
     >(query_str_static)
     .fetch_all(&db_pool)
@@ -371,7 +496,7 @@ pub async fn handle_tickets_bot(
     {
         Ok(rows) => rows,
         Err(err) => {
-            eprintln!("Erreur SQL threads: {:?}", err);
+            error!("Erreur SQL threads: {:?}", err);
             return (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(serde_json::json!({
@@ -433,7 +558,7 @@ pub async fn handle_tickets_bot(
         .fetch_all(&db_pool)
         .await
         .unwrap_or_else(|err| {
-            eprintln!("Erreur SQL messages batch: {:?}", err);
+            error!("Erreur SQL messages batch: {:?}", err);
             Vec::new()
         });
 
@@ -482,7 +607,13 @@ pub async fn handle_tickets_bot(
             category_id: thread.10,
             category_name: thread.11,
             required_permissions: thread.12,
+            priority: TicketPriority::parse(&thread.13).unwrap_or_default(),
+            workflow_state: TicketWorkflowState::parse(&thread.14)
+                .unwrap_or(TicketWorkflowState::Open),
+            state_until: thread.15,
             messages,
+            form_answers: Vec::new(),
+            state_history: Vec::new(),
         });
     }
 
@@ -1,19 +1,32 @@
-use crate::db::operations::ticket_categories::CATEGORY_BUTTON_HARD_LIMIT;
+use crate::db::operations::ticket_categories::{
+    CATEGORY_BUTTON_HARD_LIMIT, CATEGORY_FORM_MAX_QUESTIONS,
+};
 use crate::db::operations::{
     add_category_role, clear_category_roles, count_enabled_categories, create_category,
-    delete_category, get_category_by_id, get_category_by_name, get_category_settings,
-    list_all_categories, list_category_role_ids, remove_category_role, set_category_roles,
-    update_category, update_category_settings,
+    delete_category, delete_category_form, get_category_access, get_category_by_id,
+    get_category_by_name, get_category_form, get_category_settings, list_all_categories,
+    list_category_message_variants, list_category_role_ids, list_open_channels_in_category,
+    list_priority_rules, remove_category_role, set_category_access, set_category_default_priority,
+    set_category_embed_color, set_category_form, set_category_message_variants,
+    set_category_parent, set_category_roles, set_priority_rules, update_category,
+    update_category_settings,
+};
+use crate::db::repr::{
+    AccessList, CategoryFormQuestion, CategoryMessageVariant, CategorySelectionMode, PriorityRule,
+    TicketCategory, TicketCategorySettings, TicketPriority,
 };
-use crate::db::repr::{TicketCategory, TicketCategorySettings};
+use crate::modules::category_access::apply_category_access;
+use crate::prelude::i18n::*;
 use crate::prelude::types::*;
 use axum::Json;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use serde::{Deserialize, Serialize};
+use serenity::all::ChannelId;
 use sqlx::SqlitePool;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::error;
 
 async fn pool(bot_state: &Arc<Mutex<BotState>>) -> Result<SqlitePool, (StatusCode, String)> {
     let state_lock = bot_state.lock().await;
@@ -30,7 +43,7 @@ fn internal(e: impl ToString) -> (StatusCode, String) {
     (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, utoipa::ToSchema)]
 pub struct CategoryDto {
     pub id: String,
     pub name: String,
@@ -39,6 +52,9 @@ pub struct CategoryDto {
     pub discord_category_id: String,
     pub position: i64,
     pub enabled: bool,
+    pub parent_id: Option<String>,
+    pub embed_color: Option<String>,
+    pub default_priority: Option<TicketPriority>,
     pub created_at: i64,
     pub updated_at: i64,
 }
@@ -53,12 +69,80 @@ impl From<TicketCategory> for CategoryDto {
             discord_category_id: c.discord_category_id,
             position: c.position,
             enabled: c.enabled,
+            parent_id: c.parent_id,
+            embed_color: c.embed_color,
+            default_priority: c.default_priority,
             created_at: c.created_at,
             updated_at: c.updated_at,
         }
     }
 }
 
+async fn validate_parent(
+    p: &SqlitePool,
+    id: Option<&str>,
+    parent_id: &str,
+) -> Result<(), (StatusCode, String)> {
+    if Some(parent_id) == id {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "A category cannot be its own parent".to_string(),
+        ));
+    }
+    let parent = get_category_by_id(parent_id, p)
+        .await
+        .map_err(internal)?
+        .ok_or((
+            StatusCode::BAD_REQUEST,
+            "Parent category not found".to_string(),
+        ))?;
+    if parent.parent_id.is_some() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "Parent category must be a top-level category".to_string(),
+        ));
+    }
+    if let Some(id) = id {
+        let has_children = list_all_categories(p)
+            .await
+            .map_err(internal)?
+            .iter()
+            .any(|c| c.parent_id.as_deref() == Some(id));
+        if has_children {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                "A category with subcategories cannot be nested".to_string(),
+            ));
+        }
+    }
+    Ok(())
+}
+
+async fn ensure_room_for_enabled(
+    p: &SqlitePool,
+    parent_id: Option<&str>,
+) -> Result<(), (StatusCode, String)> {
+    let enabled_count = count_enabled_categories(parent_id, p)
+        .await
+        .map_err(internal)?;
+    if enabled_count as usize >= CATEGORY_BUTTON_HARD_LIMIT {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            format!("Maximum {} enabled categories", CATEGORY_BUTTON_HARD_LIMIT),
+        ));
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/categories",
+    tag = "categories",
+    responses(
+        (status = 200, description = "All ticket categories", body = [CategoryDto]),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn list_categories_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
 ) -> Result<Json<Vec<CategoryDto>>, (StatusCode, String)> {
@@ -67,14 +151,27 @@ pub async fn list_categories_handler(
     Ok(Json(cats.into_iter().map(CategoryDto::from).collect()))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct CreateCategoryRequest {
     pub name: String,
     pub description: Option<String>,
     pub emoji: Option<String>,
     pub discord_category_id: String,
+    pub parent_id: Option<String>,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/categories",
+    tag = "categories",
+    request_body = CreateCategoryRequest,
+    responses(
+        (status = 200, description = "Category created", body = CategoryDto),
+        (status = 400, description = "Invalid name or Discord category ID"),
+        (status = 409, description = "A category with this name already exists"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn create_category_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Json(req): Json<CreateCategoryRequest>,
@@ -91,13 +188,11 @@ pub async fn create_category_handler(
     }
     let p = pool(&bot_state).await?;
 
-    let enabled_count = count_enabled_categories(&p).await.map_err(internal)?;
-    if enabled_count as usize >= CATEGORY_BUTTON_HARD_LIMIT {
-        return Err((
-            StatusCode::BAD_REQUEST,
-            format!("Maximum {} enabled categories", CATEGORY_BUTTON_HARD_LIMIT),
-        ));
+    let parent_id = req.parent_id.as_deref().filter(|s| !s.is_empty());
+    if let Some(parent_id) = parent_id {
+        validate_parent(&p, None, parent_id).await?;
     }
+    ensure_room_for_enabled(&p, parent_id).await?;
 
     if get_category_by_name(name, &p)
         .await
@@ -115,6 +210,7 @@ pub async fn create_category_handler(
         req.description.as_deref(),
         req.emoji.as_deref(),
         &req.discord_category_id,
+        parent_id,
         &p,
     )
     .await
@@ -122,7 +218,7 @@ pub async fn create_category_handler(
     Ok(Json(created.into()))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct UpdateCategoryRequest {
     pub name: Option<String>,
     pub description: Option<Option<String>>,
@@ -130,8 +226,49 @@ pub struct UpdateCategoryRequest {
     pub discord_category_id: Option<String>,
     pub position: Option<i64>,
     pub enabled: Option<bool>,
+    #[schema(value_type = Option<String>)]
+    pub parent_id: Option<Option<String>>,
+    pub embed_color: Option<String>,
+    pub default_priority: Option<String>,
 }
 
+fn parse_priority(raw: &str) -> Result<Option<TicketPriority>, (StatusCode, String)> {
+    if raw.trim().is_empty() {
+        return Ok(None);
+    }
+    TicketPriority::parse(raw)
+        .map(Some)
+        .ok_or((StatusCode::BAD_REQUEST, "Invalid priority".to_string()))
+}
+
+fn parse_embed_color(raw: &str) -> Result<Option<String>, (StatusCode, String)> {
+    let hex = raw.trim().trim_start_matches('#');
+    if hex.is_empty() {
+        return Ok(None);
+    }
+    if hex.len() != 6 || u32::from_str_radix(hex, 16).is_err() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            "embed_color must be a 6-digit hex colour".to_string(),
+        ));
+    }
+    Ok(Some(hex.to_uppercase()))
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/categories/{id}",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    request_body = UpdateCategoryRequest,
+    responses(
+        (status = 200, description = "Category updated", body = CategoryDto),
+        (status = 400, description = "Invalid field value"),
+        (status = 404, description = "Category not found"),
+        (status = 409, description = "A category with this name already exists"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn update_category_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path(id): Path<String>,
@@ -160,16 +297,19 @@ pub async fn update_category_handler(
         }
     }
 
-    if let Some(true) = req.enabled
-        && !existing.enabled
+    let new_parent = match &req.parent_id {
+        Some(parent) => parent.as_deref().filter(|s| !s.is_empty()),
+        None => existing.parent_id.as_deref(),
+    };
+    if new_parent != existing.parent_id.as_deref()
+        && let Some(parent_id) = new_parent
     {
-        let enabled_count = count_enabled_categories(&p).await.map_err(internal)?;
-        if enabled_count as usize >= CATEGORY_BUTTON_HARD_LIMIT {
-            return Err((
-                StatusCode::BAD_REQUEST,
-                format!("Maximum {} enabled categories", CATEGORY_BUTTON_HARD_LIMIT),
-            ));
-        }
+        validate_parent(&p, Some(&existing.id), parent_id).await?;
+    }
+
+    let will_be_enabled = req.enabled.unwrap_or(existing.enabled);
+    if will_be_enabled && (!existing.enabled || new_parent != existing.parent_id.as_deref()) {
+        ensure_room_for_enabled(&p, new_parent).await?;
     }
 
     if let Some(ref did) = req.discord_category_id
@@ -181,6 +321,17 @@ pub async fn update_category_handler(
         ));
     }
 
+    let embed_color = req
+        .embed_color
+        .as_deref()
+        .map(parse_embed_color)
+        .transpose()?;
+    let default_priority = req
+        .default_priority
+        .as_deref()
+        .map(parse_priority)
+        .transpose()?;
+
     update_category(
         &id,
         trimmed_name,
@@ -194,6 +345,24 @@ pub async fn update_category_handler(
     .await
     .map_err(internal)?;
 
+    if new_parent != existing.parent_id.as_deref() {
+        set_category_parent(&id, new_parent, &p)
+            .await
+            .map_err(internal)?;
+    }
+
+    if let Some(color) = embed_color {
+        set_category_embed_color(&id, color.as_deref(), &p)
+            .await
+            .map_err(internal)?;
+    }
+
+    if let Some(priority) = default_priority {
+        set_category_default_priority(&id, priority, &p)
+            .await
+            .map_err(internal)?;
+    }
+
     let updated = get_category_by_id(&id, &p)
         .await
         .map_err(internal)?
@@ -201,6 +370,17 @@ pub async fn update_category_handler(
     Ok(Json(updated.into()))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/categories/{id}",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    responses(
+        (status = 204, description = "Category deleted"),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn delete_category_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path(id): Path<String>,
@@ -214,10 +394,16 @@ pub async fn delete_category_handler(
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, utoipa::ToSchema)]
 pub struct CategorySettingsDto {
     pub enabled: bool,
     pub selection_timeout_s: i64,
+    #[serde(default = "default_selection_mode")]
+    pub selection_mode: CategorySelectionMode,
+}
+
+fn default_selection_mode() -> CategorySelectionMode {
+    CategorySelectionMode::Buttons
 }
 
 impl From<TicketCategorySettings> for CategorySettingsDto {
@@ -225,10 +411,20 @@ impl From<TicketCategorySettings> for CategorySettingsDto {
         Self {
             enabled: s.enabled,
             selection_timeout_s: s.selection_timeout_s,
+            selection_mode: s.selection_mode,
         }
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/categories/settings",
+    tag = "categories",
+    responses(
+        (status = 200, description = "Category selection settings", body = CategorySettingsDto),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn get_category_settings_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
 ) -> Result<Json<CategorySettingsDto>, (StatusCode, String)> {
@@ -237,6 +433,17 @@ pub async fn get_category_settings_handler(
     Ok(Json(s.into()))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/categories/settings",
+    tag = "categories",
+    request_body = CategorySettingsDto,
+    responses(
+        (status = 200, description = "Settings saved", body = CategorySettingsDto),
+        (status = 400, description = "Invalid selection timeout"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn update_category_settings_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Json(req): Json<CategorySettingsDto>,
@@ -248,14 +455,62 @@ pub async fn update_category_settings_handler(
         ));
     }
     let p = pool(&bot_state).await?;
-    update_category_settings(req.enabled, req.selection_timeout_s, &p)
+    update_category_settings(req.enabled, req.selection_timeout_s, req.selection_mode, &p)
         .await
         .map_err(internal)?;
     let s = get_category_settings(&p).await.map_err(internal)?;
     Ok(Json(s.into()))
 }
 
-#[derive(Serialize, Deserialize)]
+#[utoipa::path(
+    get,
+    path = "/api/categories/priority-rules",
+    tag = "categories",
+    responses(
+        (status = 200, description = "Keyword rules raising the priority of new tickets", body = [PriorityRule]),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_priority_rules_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+) -> Result<Json<Vec<PriorityRule>>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    Ok(Json(list_priority_rules(&p).await.map_err(internal)?))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/categories/priority-rules",
+    tag = "categories",
+    request_body = [PriorityRule],
+    responses(
+        (status = 200, description = "Rules replaced", body = [PriorityRule]),
+        (status = 400, description = "Empty keyword"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn set_priority_rules_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Json(req): Json<Vec<PriorityRule>>,
+) -> Result<Json<Vec<PriorityRule>>, (StatusCode, String)> {
+    let mut rules = Vec::with_capacity(req.len());
+    for rule in req {
+        let keyword = rule.keyword.trim();
+        if keyword.is_empty() {
+            return Err((StatusCode::BAD_REQUEST, "Keyword required".to_string()));
+        }
+        rules.push(PriorityRule {
+            keyword: keyword.to_string(),
+            priority: rule.priority,
+        });
+    }
+
+    let p = pool(&bot_state).await?;
+    set_priority_rules(&rules, &p).await.map_err(internal)?;
+    Ok(Json(rules))
+}
+
+#[derive(Serialize, Deserialize, utoipa::ToSchema)]
 pub struct CategoryRolesDto {
     pub role_ids: Vec<String>,
 }
@@ -278,6 +533,17 @@ fn validate_role_id(raw: &str) -> Result<String, (StatusCode, String)> {
     Ok(trimmed.to_string())
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/categories/{id}/roles",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    responses(
+        (status = 200, description = "Roles allowed to see the category", body = CategoryRolesDto),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn list_category_roles_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path(id): Path<String>,
@@ -288,11 +554,23 @@ pub async fn list_category_roles_handler(
     Ok(Json(CategoryRolesDto { role_ids }))
 }
 
-#[derive(Deserialize)]
+#[derive(Deserialize, utoipa::ToSchema)]
 pub struct CategoryRoleRequest {
     pub role_id: String,
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/categories/{id}/roles",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    request_body = CategoryRoleRequest,
+    responses(
+        (status = 200, description = "Role added", body = CategoryRolesDto),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn add_category_role_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path(id): Path<String>,
@@ -308,6 +586,18 @@ pub async fn add_category_role_handler(
     Ok(Json(CategoryRolesDto { role_ids }))
 }
 
+#[utoipa::path(
+    put,
+    path = "/api/categories/{id}/roles",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    request_body = CategoryRolesDto,
+    responses(
+        (status = 200, description = "Role list replaced", body = CategoryRolesDto),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn set_category_roles_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path(id): Path<String>,
@@ -326,6 +616,21 @@ pub async fn set_category_roles_handler(
     Ok(Json(CategoryRolesDto { role_ids }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/categories/{id}/roles/{role_id}",
+    tag = "categories",
+    params(
+        ("id" = String, Path, description = "Category ID"),
+        ("role_id" = String, Path, description = "Discord role ID")
+    ),
+    responses(
+        (status = 200, description = "Role removed", body = CategoryRolesDto),
+        (status = 400, description = "Invalid role ID"),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn remove_category_role_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path((id, role_id)): Path<(String, String)>,
@@ -340,6 +645,17 @@ pub async fn remove_category_role_handler(
     Ok(Json(CategoryRolesDto { role_ids }))
 }
 
+#[utoipa::path(
+    delete,
+    path = "/api/categories/{id}/roles",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    responses(
+        (status = 204, description = "All role restrictions removed"),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn clear_category_roles_handler(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     Path(id): Path<String>,
@@ -349,3 +665,423 @@ pub async fn clear_category_roles_handler(
     clear_category_roles(&id, &p).await.map_err(internal)?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+#[derive(Serialize, Deserialize, utoipa::ToSchema)]
+pub struct CategoryFormDto {
+    pub title: String,
+    pub questions: Vec<CategoryFormQuestion>,
+}
+
+fn validate_form(req: &CategoryFormDto) -> Result<(), (StatusCode, String)> {
+    let bad = |msg: String| Err((StatusCode::BAD_REQUEST, msg));
+
+    let title_len = req.title.trim().chars().count();
+    if title_len == 0 || title_len > 45 {
+        return bad("Form title must be 1-45 characters".to_string());
+    }
+    if req.questions.is_empty() || req.questions.len() > CATEGORY_FORM_MAX_QUESTIONS {
+        return bad(format!(
+            "A form needs between 1 and {} questions",
+            CATEGORY_FORM_MAX_QUESTIONS
+        ));
+    }
+    for q in &req.questions {
+        let label_len = q.label.trim().chars().count();
+        if label_len == 0 || label_len > 45 {
+            return bad("Question labels must be 1-45 characters".to_string());
+        }
+        if q.placeholder
+            .as_ref()
+            .is_some_and(|p| p.chars().count() > 100)
+        {
+            return bad("Placeholders must be at most 100 characters".to_string());
+        }
+        if q.max_length.is_some_and(|m| m == 0 || m > 4000) {
+            return bad("max_length must be between 1 and 4000".to_string());
+        }
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/categories/{id}/form",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    responses(
+        (status = 200, description = "Intake form of the category", body = CategoryFormDto),
+        (status = 404, description = "Category or form not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_category_form_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<String>,
+) -> Result<Json<CategoryFormDto>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let _ = ensure_category_exists(&p, &id).await?;
+    let form = get_category_form(&id, &p)
+        .await
+        .map_err(internal)?
+        .ok_or((StatusCode::NOT_FOUND, "Form not found".to_string()))?;
+    Ok(Json(CategoryFormDto {
+        title: form.title,
+        questions: form.questions,
+    }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/categories/{id}/form",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    request_body = CategoryFormDto,
+    responses(
+        (status = 200, description = "Form saved", body = CategoryFormDto),
+        (status = 400, description = "Invalid form"),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn set_category_form_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<String>,
+    Json(req): Json<CategoryFormDto>,
+) -> Result<Json<CategoryFormDto>, (StatusCode, String)> {
+    validate_form(&req)?;
+    let p = pool(&bot_state).await?;
+    let _ = ensure_category_exists(&p, &id).await?;
+
+    let questions: Vec<CategoryFormQuestion> = req
+        .questions
+        .into_iter()
+        .map(|q| CategoryFormQuestion {
+            label: q.label.trim().to_string(),
+            placeholder: q
+                .placeholder
+                .map(|p| p.trim().to_string())
+                .filter(|p| !p.is_empty()),
+            ..q
+        })
+        .collect();
+    let title = req.title.trim().to_string();
+    set_category_form(&id, &title, &questions, &p)
+        .await
+        .map_err(internal)?;
+    Ok(Json(CategoryFormDto { title, questions }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/categories/{id}/form",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    responses(
+        (status = 204, description = "Form removed"),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn delete_category_form_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let _ = ensure_category_exists(&p, &id).await?;
+    delete_category_form(&id, &p).await.map_err(internal)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+const CATEGORY_MESSAGE_MAX_LEN: usize = 2000;
+
+#[derive(Serialize, Deserialize, utoipa::ToSchema)]
+pub struct CategoryMessagesDto {
+    pub variants: Vec<CategoryMessageVariant>,
+}
+
+fn normalize_message_variants(
+    variants: Vec<CategoryMessageVariant>,
+) -> Result<Vec<CategoryMessageVariant>, (StatusCode, String)> {
+    let bad = |msg: String| Err((StatusCode::BAD_REQUEST, msg));
+    let clean = |m: Option<String>| m.map(|m| m.trim().to_string()).filter(|m| !m.is_empty());
+
+    let mut normalized: Vec<CategoryMessageVariant> = Vec::with_capacity(variants.len());
+    for v in variants {
+        let language = match v.language.trim() {
+            "" => String::new(),
+            code => match Language::from_str(code) {
+                Some(lang) => lang.code().to_string(),
+                None => return bad(format!("Unknown language: {}", code)),
+            },
+        };
+        if normalized.iter().any(|n| n.language == language) {
+            return bad(format!("Duplicate variant for language '{}'", language));
+        }
+
+        let variant = CategoryMessageVariant {
+            language,
+            welcome_message: clean(v.welcome_message),
+            close_message: clean(v.close_message),
+            auto_reply: clean(v.auto_reply),
+        };
+        let too_long = [
+            &variant.welcome_message,
+            &variant.close_message,
+            &variant.auto_reply,
+        ]
+        .iter()
+        .any(|m| {
+            m.as_ref()
+                .is_some_and(|m| m.chars().count() > CATEGORY_MESSAGE_MAX_LEN)
+        });
+        if too_long {
+            return bad(format!(
+                "Messages must be at most {} characters",
+                CATEGORY_MESSAGE_MAX_LEN
+            ));
+        }
+
+        if variant.welcome_message.is_some()
+            || variant.close_message.is_some()
+            || variant.auto_reply.is_some()
+        {
+            normalized.push(variant);
+        }
+    }
+    Ok(normalized)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/categories/{id}/messages",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    responses(
+        (status = 200, description = "Welcome, close and auto-reply overrides per language", body = CategoryMessagesDto),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_category_messages_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<String>,
+) -> Result<Json<CategoryMessagesDto>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let _ = ensure_category_exists(&p, &id).await?;
+    let variants = list_category_message_variants(&id, &p)
+        .await
+        .map_err(internal)?;
+    Ok(Json(CategoryMessagesDto { variants }))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/categories/{id}/messages",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    request_body = CategoryMessagesDto,
+    responses(
+        (status = 200, description = "Message overrides replaced", body = CategoryMessagesDto),
+        (status = 400, description = "Unknown or duplicate language, or message too long"),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn set_category_messages_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<String>,
+    Json(req): Json<CategoryMessagesDto>,
+) -> Result<Json<CategoryMessagesDto>, (StatusCode, String)> {
+    let variants = normalize_message_variants(req.variants)?;
+    let p = pool(&bot_state).await?;
+    let _ = ensure_category_exists(&p, &id).await?;
+    set_category_message_variants(&id, &variants, &p)
+        .await
+        .map_err(internal)?;
+    Ok(Json(CategoryMessagesDto { variants }))
+}
+
+fn validate_snowflakes(raw: &[String], what: &str) -> Result<Vec<String>, (StatusCode, String)> {
+    let mut ids: Vec<String> = Vec::with_capacity(raw.len());
+    for id in raw {
+        let trimmed = id.trim();
+        trimmed
+            .parse::<u64>()
+            .map_err(|_| (StatusCode::BAD_REQUEST, format!("Invalid {what}")))?;
+        if !ids.iter().any(|i| i == trimmed) {
+            ids.push(trimmed.to_string());
+        }
+    }
+    Ok(ids)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/categories/{id}/access",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    responses(
+        (status = 200, description = "Staff roles and users allowed to handle the category's tickets", body = AccessList),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_category_access_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<String>,
+) -> Result<Json<AccessList>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let _ = ensure_category_exists(&p, &id).await?;
+    let access = get_category_access(&id, &p).await.map_err(internal)?;
+    Ok(Json(access))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/categories/{id}/access",
+    tag = "categories",
+    params(("id" = String, Path, description = "Category ID")),
+    request_body = AccessList,
+    responses(
+        (status = 200, description = "Access list replaced", body = AccessList),
+        (status = 400, description = "Invalid role or user ID"),
+        (status = 404, description = "Category not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn set_category_access_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<String>,
+    Json(req): Json<AccessList>,
+) -> Result<Json<AccessList>, (StatusCode, String)> {
+    let access = AccessList {
+        role_ids: validate_snowflakes(&req.role_ids, "role ID")?,
+        user_ids: validate_snowflakes(&req.user_ids, "user ID")?,
+    };
+    let p = pool(&bot_state).await?;
+    let _ = ensure_category_exists(&p, &id).await?;
+    set_category_access(&id, &access, &p)
+        .await
+        .map_err(internal)?;
+
+    let (ctx, config) = {
+        let state_lock = bot_state.lock().await;
+        let ctx = state_lock.bot_context.read().await.clone();
+        (ctx, state_lock.config())
+    };
+    if let (Some(ctx), Some(config)) = (ctx, config) {
+        let channels = list_open_channels_in_category(&id, &p)
+            .await
+            .unwrap_or_default();
+        let category_id = id.clone();
+        tokio::spawn(async move {
+            for channel in channels {
+                let Ok(channel_id) = channel.parse::<u64>() else {
+                    continue;
+                };
+                if let Err(e) = apply_category_access(
+                    &ctx,
+                    &config,
+                    ChannelId::new(channel_id),
+                    Some(&category_id),
+                )
+                .await
+                {
+                    error!("Failed to re-apply category access to {channel}: {e:?}");
+                }
+            }
+        });
+    }
+
+    Ok(Json(access))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::operations::init::test_pool;
+
+    fn question(label: &str) -> CategoryFormQuestion {
+        CategoryFormQuestion {
+            label: label.to_string(),
+            placeholder: None,
+            paragraph: false,
+            required: true,
+            max_length: None,
+        }
+    }
+
+    fn form(questions: Vec<CategoryFormQuestion>) -> CategoryFormDto {
+        CategoryFormDto {
+            title: "Report".to_string(),
+            questions,
+        }
+    }
+
+    #[test]
+    fn validate_form_accepts_discord_limits() {
+        let mut q = question(&"a".repeat(45));
+        q.placeholder = Some("p".repeat(100));
+        q.max_length = Some(4000);
+        let questions = vec![q; CATEGORY_FORM_MAX_QUESTIONS];
+
+        assert!(validate_form(&form(questions)).is_ok());
+    }
+
+    #[test]
+    fn validate_form_rejects_what_discord_refuses() {
+        let mut blank_title = form(vec![question("Order ID")]);
+        blank_title.title = "  ".to_string();
+        let mut long_placeholder = question("Order ID");
+        long_placeholder.placeholder = Some("p".repeat(101));
+        let mut zero_length = question("Order ID");
+        zero_length.max_length = Some(0);
+        let mut long_answer = question("Order ID");
+        long_answer.max_length = Some(4001);
+
+        for invalid in [
+            blank_title,
+            form(Vec::new()),
+            form(vec![question("Order ID"); CATEGORY_FORM_MAX_QUESTIONS + 1]),
+            form(vec![question(" ")]),
+            form(vec![question(&"a".repeat(46))]),
+            form(vec![long_placeholder]),
+            form(vec![zero_length]),
+            form(vec![long_answer]),
+        ] {
+            let err = validate_form(&invalid).unwrap_err();
+            assert_eq!(err.0, StatusCode::BAD_REQUEST, "{}", err.1);
+        }
+    }
+
+    #[tokio::test]
+    async fn subcategories_are_one_level_deep() {
+        let pool = test_pool().await;
+        let parent = create_category("Billing", None, None, "1", None, &pool)
+            .await
+            .unwrap();
+        let child = create_category("Refunds", None, None, "1", Some(&parent.id), &pool)
+            .await
+            .unwrap();
+        let other = create_category("Support", None, None, "1", None, &pool)
+            .await
+            .unwrap();
+
+        assert!(validate_parent(&pool, None, &parent.id).await.is_ok());
+        assert!(
+            validate_parent(&pool, Some(&other.id), &parent.id)
+                .await
+                .is_ok()
+        );
+
+        for (id, parent_id) in [
+            (Some(other.id.as_str()), other.id.as_str()),
+            (None, child.id.as_str()),
+            (Some(parent.id.as_str()), other.id.as_str()),
+            (None, "missing"),
+        ] {
+            let err = validate_parent(&pool, id, parent_id).await.unwrap_err();
+            assert_eq!(err.0, StatusCode::BAD_REQUEST, "{}", err.1);
+        }
+    }
+}
@@ -3,6 +3,7 @@ use crate::db::repr::{ApiKey, Permission};
 use crate::prelude::api::*;
 use crate::prelude::db::*;
 use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
 use crate::prelude::utils::*;
 use crate::types::{BotCommand, BotState};
 use axum::Json;
@@ -13,7 +14,24 @@ use serenity::all::{ChannelId, CreateChannel, GuildId, UserId};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::{error, info, warn};
 
+#[utoipa::path(
+    post,
+    path = "/api/externals/tickets/create",
+    tag = "externals",
+    security(("api_key" = [])),
+    request_body = CreateTicket,
+    responses(
+        (status = 200, description = "Ticket created", body = Object),
+        (status = 400, description = "Invalid Discord ID"),
+        (status = 403, description = "Missing permission, user not in the community guild or category not allowed for the key"),
+        (status = 404, description = "Discord user or category not found"),
+        (status = 409, description = "User already has an open ticket"),
+        (status = 429, description = "API key rate limit exceeded"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_external_ticket_create(
     Extension(api_key): Extension<ApiKey>,
     State(bot_state): State<Arc<Mutex<BotState>>>,
@@ -40,19 +58,23 @@ pub async fn handle_external_ticket_create(
         None
     };
 
+    let category_id = update.category_id.filter(|c| !c.trim().is_empty());
+    if !api_key.allows_category(category_id.as_deref()) {
+        return Err((
+            StatusCode::FORBIDDEN,
+            "This API key is not allowed to create tickets in this category".to_string(),
+        ));
+    }
+
     let user_id = UserId::new(user_id_u64);
     let staff_id = staff_discord_id_u64.map(UserId::new);
 
-    let (mut config, db_pool, bot_http, command_tx) = {
+    let (config, db_pool, bot_http, command_tx) = {
         let state = bot_state.lock().await;
-        let config = state
-            .config
-            .as_ref()
-            .ok_or((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                "Configuration not loaded".to_string(),
-            ))?
-            .clone();
+        let config = state.config().ok_or((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Configuration not loaded".to_string(),
+        ))?;
         let db_pool = state
             .db_pool
             .as_ref()
@@ -74,9 +96,7 @@ pub async fn handle_external_ticket_create(
         (config, db_pool, bot_http, command_tx)
     };
 
-    config.db_pool = Some(db_pool.clone());
-
-    println!(
+    info!(
         "API Key #{} creating ticket for Discord ID: {} Staff Discord ID (optional): {:?}",
         api_key.id, user_id_u64, staff_id
     );
@@ -150,9 +170,30 @@ pub async fn handle_external_ticket_create(
     }
 
     let username = user.name.clone();
-    let thread_name = format!("🔴・{}・0m", username);
+    let priority = initial_priority(&db_pool, category_id.as_deref(), None).await;
+    let thread_name = new_ticket_channel_name(priority, &username);
     let staff_guild_id = GuildId::new(config.bot.get_staff_guild_id());
-    let inbox_category_id = ChannelId::new(config.thread.inbox_category_id);
+    let inbox_category_id = match category_id.as_deref() {
+        Some(id) => match get_category_by_id(id, &db_pool).await {
+            Ok(Some(category)) if category.enabled => {
+                let parent = category.discord_category_id.parse::<u64>().map_err(|_| {
+                    (
+                        StatusCode::INTERNAL_SERVER_ERROR,
+                        "Category has an invalid Discord category ID".to_string(),
+                    )
+                })?;
+                ChannelId::new(parent)
+            }
+            Ok(_) => {
+                return Err((
+                    StatusCode::NOT_FOUND,
+                    "Category not found or disabled".to_string(),
+                ));
+            }
+            Err(e) => return Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+        },
+        None => ChannelId::new(config.thread.inbox_category_id),
+    };
 
     let channel_builder = CreateChannel::new(&thread_name).category(inbox_category_id);
 
@@ -166,19 +207,32 @@ pub async fn handle_external_ticket_create(
             )
         })?;
 
-    create_thread_for_user(&channel, user_id_u64 as i64, &username, false, &db_pool)
-        .await
-        .map_err(|e| {
-            let http_clone = bot_http.clone();
-            let channel_id = channel.id;
-            tokio::spawn(async move {
-                let _ = http_clone.delete_channel(channel_id, None).await;
-            });
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                format!("Failed to create thread record: {}", e),
-            )
-        })?;
+    let thread_id =
+        create_thread_for_user(&channel, user_id_u64 as i64, &username, false, &db_pool)
+            .await
+            .map_err(|e| {
+                let http_clone = bot_http.clone();
+                let channel_id = channel.id;
+                tokio::spawn(async move {
+                    let _ = http_clone.delete_channel(channel_id, None).await;
+                });
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    format!("Failed to create thread record: {}", e),
+                )
+            })?;
+
+    if let Some(id) = category_id.as_deref()
+        && let Err(e) = set_thread_category(&thread_id, Some(id), &db_pool).await
+    {
+        error!("Failed to set thread category for thread {thread_id}: {e}");
+    }
+
+    if priority != TicketPriority::Normal
+        && let Err(e) = set_thread_priority(&thread_id, priority, &db_pool).await
+    {
+        error!("Failed to set priority for thread {thread_id}: {e}");
+    }
 
     let community_guild_id = GuildId::new(config.bot.get_community_guild_id());
     let member_join_date = community_guild_id
@@ -225,13 +279,21 @@ pub async fn handle_external_ticket_create(
             .clone()
     };
 
+    if let Err(e) = apply_category_access(&ctx, &config, channel.id, category_id.as_deref()).await {
+        error!("Failed to apply category access for thread {thread_id}: {e}");
+    }
+
+    if let Err(e) = reorder_channels_by_priority(&ctx, &config, inbox_category_id).await {
+        error!("Failed to reorder ticket channels by priority: {e:?}");
+    }
+
     if let Err(e) = MessageBuilder::system_message(&ctx, &config)
         .to_channel(channel.id)
         .content(open_thread_message)
         .send(true)
         .await
     {
-        eprintln!(
+        error!(
             "Failed to send message to channel via MessageBuilder: {:?}",
             e
         );
@@ -239,10 +301,7 @@ pub async fn handle_external_ticket_create(
 
     if let Some(staff_user) = staff {
         if staff_user.bot {
-            eprintln!(
-                "Warning: staff user {} is a bot, skipping ping",
-                staff_user.id
-            );
+            warn!("staff user {} is a bot, skipping ping", staff_user.id);
         } else {
             send_welcome_message(&ctx, &channel, &config, &user, true).await;
 
@@ -252,26 +311,37 @@ pub async fn handle_external_ticket_create(
                 .send(true)
                 .await
             {
-                eprintln!(
+                error!(
                     "Failed to send staff ping message via MessageBuilder: {:?}",
                     e
                 );
             }
 
-            println!(
+            info!(
                 "API Key #{} - Staff member {} ({}) pinged in ticket for user {}",
                 api_key.id, staff_user.name, staff_user.id, username
             );
         }
     } else {
-        let _ = MessageBuilder::system_message(&ctx, &config)
-            .content(&config.bot.welcome_message)
+        let overrides = category_messages_for_user(&config, category_id.as_deref(), user_id).await;
+        let _ = overrides
+            .apply_color(MessageBuilder::system_message(&ctx, &config))
+            .content(overrides.welcome_message(&config, user_id))
             .to_user(user_id)
             .send(true)
             .await;
+
+        if let Some(auto_reply) = overrides.auto_reply(user_id) {
+            let _ = overrides
+                .apply_color(MessageBuilder::system_message(&ctx, &config))
+                .content(auto_reply)
+                .to_user(user_id)
+                .send(true)
+                .await;
+        }
     }
 
-    println!(
+    info!(
         "API Key #{} successfully created ticket for user {} (channel: {})",
         api_key.id, username, channel.id
     );
@@ -0,0 +1,159 @@
+use crate::db::operations::upsert_feature_state;
+use crate::features::{
+    Feature, FeatureSettingKind, feature_registry, load_feature_state, refresh_feature,
+    validate_feature_settings,
+};
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+async fn pool(bot_state: &Arc<Mutex<BotState>>) -> Result<SqlitePool, (StatusCode, String)> {
+    let state_lock = bot_state.lock().await;
+    match &state_lock.db_pool {
+        Some(p) => Ok(p.clone()),
+        None => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Database not initialized".to_string(),
+        )),
+    }
+}
+
+fn internal(e: impl ToString) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+#[derive(Serialize, Deserialize, utoipa::ToSchema)]
+pub struct FeatureFieldDto {
+    pub key: String,
+    pub kind: String,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    pub max_length: Option<usize>,
+    #[schema(value_type = Object)]
+    pub default: Value,
+}
+
+#[derive(Serialize, Deserialize, utoipa::ToSchema)]
+pub struct FeatureDto {
+    pub key: String,
+    pub enabled: bool,
+    #[schema(value_type = Object)]
+    pub settings: Map<String, Value>,
+    pub fields: Vec<FeatureFieldDto>,
+}
+
+#[derive(Deserialize, utoipa::ToSchema)]
+pub struct UpdateFeatureRequest {
+    pub enabled: Option<bool>,
+    #[schema(value_type = Option<Object>)]
+    pub settings: Option<Map<String, Value>>,
+}
+
+async fn feature_dto(feature: &dyn Feature, pool: &SqlitePool) -> FeatureDto {
+    let state = load_feature_state(feature, pool).await;
+    let fields = feature
+        .settings()
+        .into_iter()
+        .map(|s| {
+            let (kind, min, max, max_length) = match s.kind {
+                FeatureSettingKind::Boolean => ("boolean", None, None, None),
+                FeatureSettingKind::Integer { min, max } => ("integer", Some(min), Some(max), None),
+                FeatureSettingKind::Text { max_length } => ("text", None, None, Some(max_length)),
+            };
+            FeatureFieldDto {
+                key: s.key.to_string(),
+                kind: kind.to_string(),
+                min,
+                max,
+                max_length,
+                default: s.default,
+            }
+        })
+        .collect();
+
+    FeatureDto {
+        key: feature.key().to_string(),
+        enabled: state.enabled,
+        settings: state.settings,
+        fields,
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/features",
+    tag = "features",
+    responses(
+        (status = 200, description = "Registered features with their state", body = Vec<FeatureDto>),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_features_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+) -> Result<Json<Vec<FeatureDto>>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let mut features = Vec::new();
+    for feature in feature_registry().all() {
+        features.push(feature_dto(feature.as_ref(), &p).await);
+    }
+    Ok(Json(features))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/features/{key}",
+    tag = "features",
+    params(("key" = String, Path, description = "Feature key")),
+    request_body = UpdateFeatureRequest,
+    responses(
+        (status = 200, description = "Feature updated", body = FeatureDto),
+        (status = 400, description = "Invalid setting"),
+        (status = 404, description = "Unknown feature"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn update_feature_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(key): Path<String>,
+    Json(body): Json<UpdateFeatureRequest>,
+) -> Result<Json<FeatureDto>, (StatusCode, String)> {
+    let feature = feature_registry()
+        .get(&key)
+        .ok_or((StatusCode::NOT_FOUND, "Feature not found".to_string()))?;
+    let p = pool(&bot_state).await?;
+
+    let mut state = load_feature_state(feature.as_ref(), &p).await;
+    if let Some(settings) = body.settings {
+        state.settings.extend(settings);
+    }
+    if let Some(enabled) = body.enabled {
+        state.enabled = enabled;
+    }
+    validate_feature_settings(feature.as_ref(), &state.settings)
+        .map_err(|e| (StatusCode::BAD_REQUEST, e))?;
+
+    let settings = serde_json::to_string(&state.settings).map_err(internal)?;
+    upsert_feature_state(feature.key(), state.enabled, &settings, &p)
+        .await
+        .map_err(internal)?;
+
+    let (ctx, config) = {
+        let state_lock = bot_state.lock().await;
+        let ctx = state_lock.bot_context.read().await.clone();
+        (ctx, state_lock.config())
+    };
+    if let (Some(ctx), Some(config)) = (ctx, config) {
+        let key = feature.key();
+        tokio::spawn(async move {
+            refresh_feature(&ctx, &config, key).await;
+        });
+    }
+
+    Ok(Json(feature_dto(feature.as_ref(), &p).await))
+}
@@ -8,6 +8,16 @@ use serenity::all::ShardId;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+#[utoipa::path(
+    get,
+    path = "/api/health",
+    tag = "health",
+    security(()),
+    responses(
+        (status = 200, description = "Database reachable and bot connected (or stopped)", body = Object),
+        (status = 503, description = "Database unreachable or bot disconnected", body = Object)
+    )
+)]
 pub async fn handle_health(State(bot_state): State<Arc<Mutex<BotState>>>) -> impl IntoResponse {
     let state_lock = bot_state.lock().await;
 
@@ -0,0 +1,200 @@
+use crate::db::operations::{cancel_job_by_id, get_job, list_jobs, retry_job_by_id};
+use crate::db::repr::{Job, JobKind, JobStatus};
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+async fn pool(bot_state: &Arc<Mutex<BotState>>) -> Result<SqlitePool, (StatusCode, String)> {
+    let state_lock = bot_state.lock().await;
+    match &state_lock.db_pool {
+        Some(p) => Ok(p.clone()),
+        None => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Database not initialized".to_string(),
+        )),
+    }
+}
+
+fn internal(e: impl ToString) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+#[derive(Serialize, Deserialize, utoipa::ToSchema)]
+pub struct JobDto {
+    pub id: i64,
+    pub kind: String,
+    pub job_key: String,
+    pub payload: String,
+    pub status: String,
+    pub run_at: i64,
+    pub attempts: i64,
+    pub max_attempts: i64,
+    pub last_error: Option<String>,
+    pub locked_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+impl From<Job> for JobDto {
+    fn from(j: Job) -> Self {
+        Self {
+            id: j.id,
+            kind: j.kind,
+            job_key: j.job_key,
+            payload: j.payload,
+            status: j.status,
+            run_at: j.run_at,
+            attempts: j.attempts,
+            max_attempts: j.max_attempts,
+            last_error: j.last_error,
+            locked_at: j.locked_at,
+            created_at: j.created_at,
+            updated_at: j.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, utoipa::IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct JobQuery {
+    pub status: Option<String>,
+    pub kind: Option<String>,
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct PaginatedJobsResponse {
+    pub jobs: Vec<JobDto>,
+    pub total: i64,
+    pub page: i64,
+    pub page_size: i64,
+    pub total_pages: i64,
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/jobs",
+    tag = "jobs",
+    params(JobQuery),
+    responses(
+        (status = 200, description = "Paginated scheduled jobs", body = PaginatedJobsResponse),
+        (status = 400, description = "Unknown status or kind"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_jobs_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Query(params): Query<JobQuery>,
+) -> Result<Json<PaginatedJobsResponse>, (StatusCode, String)> {
+    let status = match params.status.as_deref().filter(|s| !s.is_empty()) {
+        Some(s) => Some(JobStatus::parse(s).ok_or((
+            StatusCode::BAD_REQUEST,
+            format!("Unknown job status '{}'", s),
+        ))?),
+        None => None,
+    };
+    let kind = match params.kind.as_deref().filter(|s| !s.is_empty()) {
+        Some(k) => Some(
+            JobKind::parse(k)
+                .ok_or((StatusCode::BAD_REQUEST, format!("Unknown job kind '{}'", k)))?,
+        ),
+        None => None,
+    };
+
+    let page = params.page.unwrap_or(1).max(1);
+    let page_size = params.page_size.unwrap_or(50).clamp(1, 200);
+
+    let p = pool(&bot_state).await?;
+    let (jobs, total) = list_jobs(status, kind, page_size, (page - 1) * page_size, &p)
+        .await
+        .map_err(internal)?;
+
+    Ok(Json(PaginatedJobsResponse {
+        jobs: jobs.into_iter().map(JobDto::from).collect(),
+        total,
+        page,
+        page_size,
+        total_pages: (total + page_size - 1) / page_size,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/jobs/{id}",
+    tag = "jobs",
+    params(("id" = i64, Path, description = "Job ID")),
+    responses(
+        (status = 200, description = "Job details", body = JobDto),
+        (status = 404, description = "Job not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn get_job_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+) -> Result<Json<JobDto>, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    let job = get_job(id, &p)
+        .await
+        .map_err(internal)?
+        .ok_or((StatusCode::NOT_FOUND, "Job not found".to_string()))?;
+    Ok(Json(job.into()))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/jobs/{id}/cancel",
+    tag = "jobs",
+    params(("id" = i64, Path, description = "Job ID")),
+    responses(
+        (status = 204, description = "Job cancelled"),
+        (status = 409, description = "Job is not pending"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn cancel_job_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    if cancel_job_by_id(id, &p).await.map_err(internal)? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err((
+            StatusCode::CONFLICT,
+            "Only pending jobs can be cancelled".to_string(),
+        ))
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/jobs/{id}/retry",
+    tag = "jobs",
+    params(("id" = i64, Path, description = "Job ID")),
+    responses(
+        (status = 204, description = "Job rescheduled"),
+        (status = 409, description = "Job has not failed or been cancelled"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn retry_job_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let p = pool(&bot_state).await?;
+    if retry_job_by_id(id, &p).await.map_err(internal)? {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err((
+            StatusCode::CONFLICT,
+            "Only failed or cancelled jobs can be retried".to_string(),
+        ))
+    }
+}
@@ -4,7 +4,9 @@ pub mod auth;
 pub mod bot;
 pub mod categories;
 pub mod externals;
+pub mod features;
 pub mod health;
+pub mod jobs;
 pub mod panel;
 pub mod user;
 
@@ -14,6 +16,8 @@ pub use auth::*;
 pub use bot::*;
 pub use categories::*;
 pub use externals::*;
+pub use features::*;
 pub use health::*;
+pub use jobs::*;
 pub use panel::*;
 pub use user::*;
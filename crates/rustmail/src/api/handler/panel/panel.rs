@@ -4,6 +4,15 @@ use axum::response::IntoResponse;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
-pub async fn handle_panel_check(State(..): State<Arc<Mutex<BotState>>>) -> impl IntoResponse {
+#[utoipa::path(
+    get,
+    path = "/api/panel/check",
+    tag = "panel",
+    responses(
+        (status = 200, description = "The session may access the panel", body = Object),
+        (status = 401, description = "No panel session")
+    )
+)]
+pub async fn handle_panel_check(_state: State<Arc<Mutex<BotState>>>) -> impl IntoResponse {
     axum::response::Json(serde_json::json!({ "authorized": true }))
 }
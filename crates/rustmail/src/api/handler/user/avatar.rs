@@ -6,12 +6,22 @@ use axum_extra::extract::CookieJar;
 use sqlx::Row;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use tracing::error;
 
-#[derive(serde::Deserialize, Debug, serde::Serialize)]
+#[derive(serde::Deserialize, Debug, serde::Serialize, utoipa::ToSchema)]
 pub struct UserAvatar {
     pub avatar_url: Option<String>,
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/user/avatar",
+    tag = "user",
+    responses(
+        (status = 200, description = "Avatar of the logged-in user", body = UserAvatar),
+        (status = 401, description = "No panel session")
+    )
+)]
 pub async fn handle_get_user_avatar(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     jar: CookieJar,
@@ -36,7 +46,7 @@ pub async fn handle_get_user_avatar(
     let user_id: u64 = match user_id_str.parse() {
         Ok(id) => id,
         Err(e) => {
-            eprintln!("Error parsing user ID: {}", e);
+            error!("Error parsing user ID: {}", e);
             return axum::response::Json(serde_json::json!(UserAvatar { avatar_url: None }));
         }
     };
@@ -78,7 +88,7 @@ pub async fn handle_get_user_avatar(
             }
         }
         Err(e) => {
-            eprintln!("Error fetching user avatar: {}", e);
+            error!("Error fetching user avatar: {}", e);
             UserAvatar { avatar_url: None }
         }
     };
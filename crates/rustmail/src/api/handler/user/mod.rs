@@ -1,5 +1,7 @@
 pub mod avatar;
 pub mod permissions;
+pub mod sessions;
 
 pub use avatar::*;
 pub use permissions::*;
+pub use sessions::*;
@@ -9,6 +9,16 @@ use axum_extra::extract::CookieJar;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
+#[utoipa::path(
+    get,
+    path = "/api/user/permissions",
+    tag = "user",
+    responses(
+        (status = 200, description = "Panel permissions of the logged-in user", body = [rustmail_types::api::panel_permissions::PanelPermission]),
+        (status = 401, description = "No panel session"),
+        (status = 500, description = "Internal error")
+    )
+)]
 pub async fn handle_get_user_permissions(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     jar: CookieJar,
@@ -27,7 +37,7 @@ pub async fn handle_get_user_permissions(
             }
         };
 
-        let cfg = match &state_lock.config {
+        let cfg = match state_lock.config() {
             Some(c) => c.clone(),
             None => {
                 return (
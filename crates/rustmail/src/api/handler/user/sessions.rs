@@ -0,0 +1,99 @@
+use crate::db::repr::PanelSession;
+use crate::prelude::api::*;
+use crate::prelude::db::*;
+use crate::prelude::types::*;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum_extra::extract::CookieJar;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+#[derive(Serialize, utoipa::ToSchema)]
+pub struct UserSessionDto {
+    #[serde(flatten)]
+    pub session: PanelSession,
+    pub current: bool,
+}
+
+async fn session_owner(
+    bot_state: &Arc<Mutex<BotState>>,
+    jar: &CookieJar,
+) -> Result<(SqlitePool, String, String), (StatusCode, String)> {
+    let db_pool = bot_state.lock().await.db_pool.clone().ok_or((
+        StatusCode::INTERNAL_SERVER_ERROR,
+        "Database not initialized".to_string(),
+    ))?;
+
+    let session_id = jar
+        .get("session_id")
+        .map(|c| c.value().to_string())
+        .ok_or((StatusCode::UNAUTHORIZED, "Unauthorized".to_string()))?;
+
+    let user_id = get_user_id_from_session(&session_id, &db_pool).await;
+    if user_id.is_empty() {
+        return Err((StatusCode::UNAUTHORIZED, "Unauthorized".to_string()));
+    }
+
+    Ok((db_pool, user_id, session_id))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/user/sessions",
+    tag = "user",
+    responses(
+        (status = 200, description = "Active panel sessions of the logged-in user", body = [UserSessionDto]),
+        (status = 401, description = "No panel session"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn list_user_sessions_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    jar: CookieJar,
+) -> Result<Json<Vec<UserSessionDto>>, (StatusCode, String)> {
+    let (db_pool, user_id, session_id) = session_owner(&bot_state, &jar).await?;
+    let current_id = get_session_public_id(&session_id, &db_pool).await;
+
+    let sessions = list_sessions(Some(&user_id), &db_pool)
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    Ok(Json(
+        sessions
+            .into_iter()
+            .map(|session| UserSessionDto {
+                current: current_id.as_deref() == Some(session.id.as_str()),
+                session,
+            })
+            .collect(),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/user/sessions/{id}",
+    tag = "user",
+    params(("id" = String, Path, description = "Session ID")),
+    responses(
+        (status = 204, description = "Session revoked"),
+        (status = 401, description = "No panel session"),
+        (status = 404, description = "Session not found"),
+        (status = 500, description = "Internal error")
+    )
+)]
+pub async fn revoke_user_session_handler(
+    State(bot_state): State<Arc<Mutex<BotState>>>,
+    jar: CookieJar,
+    Path(id): Path<String>,
+) -> Result<StatusCode, (StatusCode, String)> {
+    let (db_pool, user_id, _) = session_owner(&bot_state, &jar).await?;
+
+    match delete_session_by_public_id(&id, Some(&user_id), &db_pool).await {
+        Ok(true) => Ok(StatusCode::NO_CONTENT),
+        Ok(false) => Err((StatusCode::NOT_FOUND, "Session not found".to_string())),
+        Err(e) => Err((StatusCode::INTERNAL_SERVER_ERROR, e.to_string())),
+    }
+}
@@ -1,17 +1,22 @@
+use crate::db::repr::ApiKey;
 use crate::prelude::api::*;
 use crate::prelude::db::*;
 use crate::prelude::types::*;
-use axum::extract::Request;
 use axum::extract::State;
+use axum::extract::{ConnectInfo, OriginalUri, Request};
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Response};
 use axum_extra::extract::CookieJar;
 use chrono::Utc;
 use hyper::StatusCode;
+use hyper::header::RETRY_AFTER;
 use serenity::all::{GuildId, UserId};
-use sqlx::{Row, query};
+use sqlx::{Row, SqlitePool, query};
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Instant;
 use tokio::sync::Mutex;
+use tracing::error;
 
 async fn check_user_with_bot(bot_state: Arc<Mutex<BotState>>, user_id: &str) -> bool {
     let user_id_num = match user_id.parse::<u64>() {
@@ -78,10 +83,83 @@ async fn verify_user(user_id: &str, guild_id: u64, bot_state: Arc<Mutex<BotState
     check_user_with_api(user_id, guild_id, http).await
 }
 
+async fn run_with_api_key(
+    db_pool: SqlitePool,
+    api_key: ApiKey,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    let started = Instant::now();
+    let method = req.method().to_string();
+    let route = req
+        .extensions()
+        .get::<OriginalUri>()
+        .map(|uri| uri.path().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    // The socket peer, not a forwarded header: behind a reverse proxy this
+    // is the proxy's address.
+    let ip = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip());
+    let key_id = api_key.id;
+
+    let response = if ip.is_some_and(|ip| !api_key.allows_ip(ip))
+        || (ip.is_none() && !api_key.allowed_cidrs.is_empty())
+    {
+        (
+            StatusCode::FORBIDDEN,
+            "IP address not allowed for this API key",
+        )
+            .into_response()
+    } else if let Err(retry_after) = check_api_key_rate_limit(
+        api_key.id,
+        api_key.rate_limit_per_minute,
+        api_key.rate_limit_burst,
+    ) {
+        let retry_secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+        (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(RETRY_AFTER, retry_secs.to_string())],
+            "Rate limit exceeded for this API key",
+        )
+            .into_response()
+    } else {
+        let pool_clone = db_pool.clone();
+        tokio::spawn(async move {
+            let _ = update_last_used(&pool_clone, key_id).await;
+        });
+
+        req.extensions_mut().insert(api_key);
+        next.run(req).await
+    };
+
+    let status = response.status().as_u16();
+    let latency_ms = started.elapsed().as_millis() as i64;
+    tokio::spawn(async move {
+        let ip = ip.map(|ip| ip.to_string());
+        if let Err(e) = insert_api_key_request(
+            &db_pool,
+            key_id,
+            &method,
+            &route,
+            status,
+            latency_ms,
+            ip.as_deref(),
+        )
+        .await
+        {
+            error!("Failed to record API key request: {}", e);
+        }
+    });
+
+    response
+}
+
 pub async fn auth_middleware(
     State(bot_state): State<Arc<Mutex<BotState>>>,
     jar: CookieJar,
-    mut req: Request,
+    req: Request,
     next: Next,
 ) -> Response {
     let db_pool = {
@@ -106,21 +184,14 @@ pub async fn auth_middleware(
         return match get_api_key_by_hash(&db_pool, &key_hash).await {
             Ok(Some(api_key)) => {
                 if api_key.is_valid() {
-                    let pool_clone = db_pool.clone();
-                    let key_id = api_key.id;
-                    tokio::spawn(async move {
-                        let _ = update_last_used(&pool_clone, key_id).await;
-                    });
-
-                    req.extensions_mut().insert(api_key);
-                    next.run(req).await
+                    run_with_api_key(db_pool, api_key, req, next).await
                 } else {
                     (StatusCode::UNAUTHORIZED, "API key expired or inactive").into_response()
                 }
             }
             Ok(None) => (StatusCode::UNAUTHORIZED, "Invalid API key").into_response(),
             Err(e) => {
-                eprintln!("Error fetching API key: {}", e);
+                error!("Error fetching API key: {}", e);
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error").into_response()
             }
         };
@@ -137,7 +208,7 @@ pub async fn auth_middleware(
 
     let guild_id = {
         let state_lock = bot_state.lock().await;
-        match &state_lock.config {
+        match state_lock.config() {
             Some(config) => config.bot.get_staff_guild_id(),
             None => {
                 return (
@@ -177,6 +248,14 @@ pub async fn auth_middleware(
                 return (StatusCode::UNAUTHORIZED, "Invalid session").into_response();
             }
 
+            let ip = req
+                .extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|info| info.0.ip().to_string());
+            if let Err(e) = touch_session(&session_id, ip.as_deref(), now, &db_pool).await {
+                error!("Failed to update panel session activity: {e:?}");
+            }
+
             next.run(req).await
         }
         Err(_) => (StatusCode::UNAUTHORIZED, "Unauthorized").into_response(),
@@ -28,7 +28,7 @@ pub async fn require_panel_permission(
                     .into_response();
             }
         };
-        let cfg = match &state_lock.config {
+        let cfg = match state_lock.config() {
             Some(c) => c.clone(),
             None => {
                 return (StatusCode::INTERNAL_SERVER_ERROR, "Config not initialized")
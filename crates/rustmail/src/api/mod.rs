@@ -1,11 +1,13 @@
 pub mod handler;
 pub mod middleware;
+pub mod openapi;
 pub mod router;
 pub mod routes;
 pub mod utils;
 
 pub use handler::*;
 pub use middleware::*;
+pub use openapi::*;
 pub use router::*;
 pub use routes::*;
 pub use utils::*;
@@ -0,0 +1,260 @@
+use crate::prelude::api::*;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, SecurityScheme};
+use utoipa::{Modify, OpenApi};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "Rustmail API",
+        description = "HTTP API used by the Rustmail panel and external integrations."
+    ),
+    paths(
+        handle_health,
+        handle_login,
+        handle_callback,
+        handle_logout,
+        handle_start_bot,
+        handle_stop_bot,
+        handle_restart_bot,
+        handle_set_presence,
+        handle_update_profile,
+        handle_update_config,
+        handle_status_bot,
+        handle_tickets_bot,
+        handle_get_config,
+        list_config_revisions_handler,
+        get_config_revision_handler,
+        diff_config_revision_handler,
+        rollback_config_revision_handler,
+        handle_statistics,
+        handle_get_profile,
+        handle_list_members,
+        handle_list_roles,
+        handle_list_permissions,
+        handle_grant_permission,
+        handle_revoke_permission,
+        handle_list_command_permissions,
+        handle_set_command_permissions,
+        handle_list_sessions,
+        handle_revoke_session,
+        handle_revoke_user_sessions,
+        handle_list_bans,
+        handle_list_logs,
+        handle_list_backups,
+        handle_create_backup,
+        handle_download_backup,
+        handle_restore_backup,
+        handle_list_data_requests,
+        handle_data_subject_summary,
+        handle_export_user_data,
+        handle_erase_user_data,
+        handle_list_retention_rules,
+        handle_create_retention_rule,
+        handle_update_retention_rule,
+        handle_delete_retention_rule,
+        handle_preview_retention,
+        handle_run_retention_rule,
+        create_api_key_handler,
+        list_api_keys_handler,
+        revoke_api_key_handler,
+        delete_api_key_handler,
+        update_api_key_restrictions_handler,
+        list_api_key_requests_handler,
+        list_categories_handler,
+        create_category_handler,
+        update_category_handler,
+        delete_category_handler,
+        get_category_settings_handler,
+        update_category_settings_handler,
+        get_priority_rules_handler,
+        set_priority_rules_handler,
+        list_category_roles_handler,
+        add_category_role_handler,
+        set_category_roles_handler,
+        clear_category_roles_handler,
+        remove_category_role_handler,
+        get_category_form_handler,
+        set_category_form_handler,
+        delete_category_form_handler,
+        get_category_messages_handler,
+        set_category_messages_handler,
+        get_category_access_handler,
+        set_category_access_handler,
+        list_jobs_handler,
+        get_job_handler,
+        cancel_job_handler,
+        retry_job_handler,
+        list_features_handler,
+        update_feature_handler,
+        handle_panel_check,
+        handle_get_user_avatar,
+        handle_get_user_permissions,
+        list_user_sessions_handler,
+        revoke_user_session_handler,
+        handle_external_ticket_create,
+    ),
+    components(schemas(CompleteThread)),
+    modifiers(&SecuritySchemes),
+    security(("session" = []), ("api_key" = [])),
+    tags(
+        (name = "health", description = "Liveness probe"),
+        (name = "auth", description = "Discord OAuth2 login for the panel"),
+        (name = "bot", description = "Bot control, configuration and statistics"),
+        (name = "tickets", description = "Ticket history"),
+        (name = "admin", description = "Panel administration"),
+        (name = "apikeys", description = "API key management"),
+        (name = "categories", description = "Ticket categories"),
+        (name = "jobs", description = "Scheduled jobs"),
+        (name = "features", description = "Staff features and their settings"),
+        (name = "panel", description = "Panel access checks"),
+        (name = "user", description = "Logged-in panel user"),
+        (name = "externals", description = "Endpoints for external integrations"),
+    )
+)]
+pub struct ApiDoc;
+
+struct SecuritySchemes;
+
+impl Modify for SecuritySchemes {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "session",
+            SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new("session_id"))),
+        );
+        components.add_security_scheme(
+            "api_key",
+            SecurityScheme::ApiKey(ApiKey::Header(ApiKeyValue::new("X-API-Key"))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::{BTreeSet, HashMap};
+    use std::path::Path;
+
+    const METHODS: [&str; 5] = ["get", "post", "put", "patch", "delete"];
+
+    fn calls<'a>(source: &'a str, name: &str) -> Vec<&'a str> {
+        let needle = format!(".{}(", name);
+        let mut out = Vec::new();
+        let mut rest = source;
+        while let Some(start) = rest.find(&needle) {
+            let args = &rest[start + needle.len()..];
+            let mut depth = 1;
+            let end = args
+                .char_indices()
+                .find(|(_, c)| {
+                    match c {
+                        '(' => depth += 1,
+                        ')' => depth -= 1,
+                        _ => {}
+                    }
+                    depth == 0
+                })
+                .map(|(i, _)| i)
+                .unwrap_or(args.len());
+            out.push(&args[..end]);
+            rest = &args[end..];
+        }
+        out
+    }
+
+    fn first_string(args: &str) -> &str {
+        args.split('"').nth(1).unwrap_or_default()
+    }
+
+    fn join(prefix: &str, path: &str) -> String {
+        if path == "/" && !prefix.is_empty() {
+            prefix.to_string()
+        } else {
+            format!("{}{}", prefix, path)
+        }
+    }
+
+    fn read_functions(path: &Path, functions: &mut HashMap<String, String>) {
+        if path.is_dir() {
+            for entry in std::fs::read_dir(path).unwrap() {
+                read_functions(&entry.unwrap().path(), functions);
+            }
+            return;
+        }
+        let source = std::fs::read_to_string(path).unwrap();
+        for chunk in source.split("pub fn ").skip(1) {
+            let name = chunk.split('(').next().unwrap().trim().to_string();
+            functions.insert(name, chunk.to_string());
+        }
+    }
+
+    fn collect_routes(
+        functions: &HashMap<String, String>,
+        function: &str,
+        prefix: &str,
+        routes: &mut BTreeSet<(String, String)>,
+    ) {
+        let body = &functions[function];
+        for args in calls(body, "route") {
+            let path = join(prefix, first_string(args));
+            for method in METHODS {
+                if args.contains(&format!("{}(", method)) {
+                    routes.insert((method.to_string(), path.clone()));
+                }
+            }
+        }
+        for args in calls(body, "nest") {
+            let nested_prefix = join(prefix, first_string(args));
+            let variable = args.rsplit(',').next().unwrap().trim();
+            let binding = format!("let {} = ", variable);
+            let builder = body
+                .split(&binding)
+                .nth(1)
+                .and_then(|rest| rest.split('(').next())
+                .unwrap_or_else(|| panic!("cannot resolve nested router `{}`", variable));
+            collect_routes(functions, builder.trim(), &nested_prefix, routes);
+        }
+    }
+
+    #[test]
+    fn every_route_is_documented() {
+        let api_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/api");
+        let mut functions = HashMap::new();
+        read_functions(&api_dir.join("routes"), &mut functions);
+        read_functions(&api_dir.join("router.rs"), &mut functions);
+
+        let mut routes = BTreeSet::new();
+        collect_routes(&functions, "create_api_router", "", &mut routes);
+        assert!(!routes.is_empty());
+
+        let doc = ApiDoc::openapi();
+        let mut documented = BTreeSet::new();
+        for (path, item) in &doc.paths.paths {
+            let operations = [
+                ("get", item.get.is_some()),
+                ("post", item.post.is_some()),
+                ("put", item.put.is_some()),
+                ("patch", item.patch.is_some()),
+                ("delete", item.delete.is_some()),
+            ];
+            for (method, present) in operations {
+                if present {
+                    documented.insert((method.to_string(), path.clone()));
+                }
+            }
+        }
+
+        let undocumented: Vec<_> = routes.difference(&documented).collect();
+        let unknown: Vec<_> = documented.difference(&routes).collect();
+        assert!(
+            undocumented.is_empty(),
+            "routes missing from the OpenAPI document: {:?}",
+            undocumented
+        );
+        assert!(
+            unknown.is_empty(),
+            "documented routes that are not registered: {:?}",
+            unknown
+        );
+    }
+}
@@ -3,6 +3,8 @@ use crate::prelude::types::*;
 use axum::Router;
 use std::sync::Arc;
 use tokio::sync::Mutex;
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
 
 pub fn create_api_router(bot_state: Arc<Mutex<BotState>>) -> Router {
     let admin_router = create_admin_router(bot_state.clone());
@@ -13,6 +15,8 @@ pub fn create_api_router(bot_state: Arc<Mutex<BotState>>) -> Router {
     let panel_router = create_panel_router(bot_state.clone());
     let user_router = create_user_router(bot_state.clone());
     let external_router = create_external_router(bot_state.clone());
+    let jobs_router = create_jobs_router(bot_state.clone());
+    let features_router = create_features_router(bot_state.clone());
 
     Router::new()
         .route("/api/health", axum::routing::get(handle_health))
@@ -24,5 +28,8 @@ pub fn create_api_router(bot_state: Arc<Mutex<BotState>>) -> Router {
         .nest("/api/panel", panel_router)
         .nest("/api/user", user_router)
         .nest("/api/externals", external_router)
+        .nest("/api/jobs", jobs_router)
+        .nest("/api/features", features_router)
+        .merge(SwaggerUi::new("/api/docs").url("/api/openapi.json", ApiDoc::openapi()))
         .with_state(bot_state.clone())
 }
@@ -1,7 +1,7 @@
 use crate::prelude::api::*;
 use crate::prelude::types::*;
 use axum::Router;
-use axum::routing::{delete, get, post};
+use axum::routing::{delete, get, post, put};
 use rustmail_types::api::panel_permissions::PanelPermission;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -13,6 +13,14 @@ pub fn create_admin_router(bot_state: Arc<Mutex<BotState>>) -> Router<Arc<Mutex<
         .route("/permissions", get(handle_list_permissions))
         .route("/permissions", post(handle_grant_permission))
         .route("/permissions/{id}", delete(handle_revoke_permission))
+        .route("/commands", get(handle_list_command_permissions))
+        .route("/commands/{name}", put(handle_set_command_permissions))
+        .route("/sessions", get(handle_list_sessions))
+        .route("/sessions/{id}", delete(handle_revoke_session))
+        .route(
+            "/sessions/user/{user_id}",
+            delete(handle_revoke_user_sessions),
+        )
         .layer(axum::middleware::from_fn_with_state(
             bot_state.clone(),
             move |state, jar, req, next| {
@@ -26,9 +34,36 @@ pub fn create_admin_router(bot_state: Arc<Mutex<BotState>>) -> Router<Arc<Mutex<
         }),
     );
 
+    let logs_router = Router::new()
+        .route("/logs", get(handle_list_logs))
+        .route("/backups", get(handle_list_backups))
+        .route("/backups", post(handle_create_backup))
+        .route("/backups/{name}/download", get(handle_download_backup))
+        .route("/backups/{name}/restore", post(handle_restore_backup))
+        .route("/gdpr/requests", get(handle_list_data_requests))
+        .route("/gdpr/{user_id}/summary", get(handle_data_subject_summary))
+        .route("/gdpr/{user_id}/export", get(handle_export_user_data))
+        .route("/gdpr/{user_id}/erase", post(handle_erase_user_data))
+        .route("/retention/rules", get(handle_list_retention_rules))
+        .route("/retention/rules", post(handle_create_retention_rule))
+        .route("/retention/rules/{id}", put(handle_update_retention_rule))
+        .route(
+            "/retention/rules/{id}",
+            delete(handle_delete_retention_rule),
+        )
+        .route("/retention/rules/{id}/run", post(handle_run_retention_rule))
+        .route("/retention/preview", post(handle_preview_retention))
+        .layer(axum::middleware::from_fn_with_state(
+            bot_state.clone(),
+            move |state, jar, req, next| {
+                require_panel_permission(state, jar, req, next, PanelPermission::ManageBot)
+            },
+        ));
+
     Router::new()
         .merge(permissions_router)
         .merge(bans_router)
+        .merge(logs_router)
         .layer(axum::middleware::from_fn_with_state(
             bot_state,
             auth_middleware,
@@ -1,7 +1,7 @@
 use crate::prelude::api::*;
 use crate::prelude::types::*;
 use axum::Router;
-use axum::routing::{delete, get, post};
+use axum::routing::{delete, get, post, put};
 use rustmail_types::api::panel_permissions::PanelPermission;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -12,6 +12,11 @@ pub fn create_apikeys_router(bot_state: Arc<Mutex<BotState>>) -> Router<Arc<Mute
         .route("/", get(list_api_keys_handler))
         .route("/{id}/revoke", post(revoke_api_key_handler))
         .route("/{id}", delete(delete_api_key_handler))
+        .route(
+            "/{id}/restrictions",
+            put(update_api_key_restrictions_handler),
+        )
+        .route("/{id}/requests", get(list_api_key_requests_handler))
         .layer(axum::middleware::from_fn_with_state(
             bot_state.clone(),
             move |state, jar, req, next| {
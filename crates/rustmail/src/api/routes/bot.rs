@@ -22,6 +22,10 @@ pub fn create_bot_router(bot_state: Arc<Mutex<BotState>>) -> Router<Arc<Mutex<Bo
 
     let manage_config_routes = Router::new()
         .route("/config", put(handle_update_config))
+        .route(
+            "/config/revisions/{id}/rollback",
+            post(rollback_config_revision_handler),
+        )
         .layer(axum::middleware::from_fn_with_state(
             bot_state.clone(),
             move |state, jar, req, next| {
@@ -33,6 +37,12 @@ pub fn create_bot_router(bot_state: Arc<Mutex<BotState>>) -> Router<Arc<Mutex<Bo
         .route("/status", get(handle_status_bot))
         .route("/tickets", get(handle_tickets_bot))
         .route("/config", get(handle_get_config))
+        .route("/config/revisions", get(list_config_revisions_handler))
+        .route("/config/revisions/{id}", get(get_config_revision_handler))
+        .route(
+            "/config/revisions/{id}/diff",
+            get(diff_config_revision_handler),
+        )
         .route("/statistics", get(handle_statistics))
         .route("/profile", get(handle_get_profile))
         .layer(axum::middleware::from_fn_with_state(
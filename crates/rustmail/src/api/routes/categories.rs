@@ -14,10 +14,19 @@ pub fn create_categories_router(bot_state: Arc<Mutex<BotState>>) -> Router<Arc<M
         .route("/{id}", delete(delete_category_handler))
         .route("/settings", get(get_category_settings_handler))
         .route("/settings", put(update_category_settings_handler))
+        .route("/priority-rules", get(get_priority_rules_handler))
+        .route("/priority-rules", put(set_priority_rules_handler))
         .route("/{id}/roles", get(list_category_roles_handler))
         .route("/{id}/roles", post(add_category_role_handler))
         .route("/{id}/roles", put(set_category_roles_handler))
         .route("/{id}/roles", delete(clear_category_roles_handler))
+        .route("/{id}/form", get(get_category_form_handler))
+        .route("/{id}/form", put(set_category_form_handler))
+        .route("/{id}/form", delete(delete_category_form_handler))
+        .route("/{id}/messages", get(get_category_messages_handler))
+        .route("/{id}/messages", put(set_category_messages_handler))
+        .route("/{id}/access", get(get_category_access_handler))
+        .route("/{id}/access", put(set_category_access_handler))
         .route(
             "/{id}/roles/{role_id}",
             delete(remove_category_role_handler),
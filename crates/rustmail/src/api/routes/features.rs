@@ -0,0 +1,23 @@
+use crate::prelude::api::*;
+use crate::prelude::types::*;
+use axum::Router;
+use axum::routing::{get, put};
+use rustmail_types::api::panel_permissions::PanelPermission;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub fn create_features_router(bot_state: Arc<Mutex<BotState>>) -> Router<Arc<Mutex<BotState>>> {
+    Router::new()
+        .route("/", get(list_features_handler))
+        .route("/{key}", put(update_feature_handler))
+        .layer(axum::middleware::from_fn_with_state(
+            bot_state.clone(),
+            move |state, jar, req, next| {
+                require_panel_permission(state, jar, req, next, PanelPermission::ManageBot)
+            },
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            bot_state,
+            auth_middleware,
+        ))
+}
@@ -0,0 +1,25 @@
+use crate::prelude::api::*;
+use crate::prelude::types::*;
+use axum::Router;
+use axum::routing::{get, post};
+use rustmail_types::api::panel_permissions::PanelPermission;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+pub fn create_jobs_router(bot_state: Arc<Mutex<BotState>>) -> Router<Arc<Mutex<BotState>>> {
+    Router::new()
+        .route("/", get(list_jobs_handler))
+        .route("/{id}", get(get_job_handler))
+        .route("/{id}/cancel", post(cancel_job_handler))
+        .route("/{id}/retry", post(retry_job_handler))
+        .layer(axum::middleware::from_fn_with_state(
+            bot_state.clone(),
+            move |state, jar, req, next| {
+                require_panel_permission(state, jar, req, next, PanelPermission::ManageBot)
+            },
+        ))
+        .layer(axum::middleware::from_fn_with_state(
+            bot_state,
+            auth_middleware,
+        ))
+}
@@ -4,6 +4,8 @@ pub mod auth;
 pub mod bot;
 pub mod categories;
 pub mod externals;
+pub mod features;
+pub mod jobs;
 pub mod panel;
 pub mod user;
 
@@ -13,5 +15,7 @@ pub use auth::*;
 pub use bot::*;
 pub use categories::*;
 pub use externals::*;
+pub use features::*;
+pub use jobs::*;
 pub use panel::*;
 pub use user::*;
@@ -1,7 +1,7 @@
 use crate::prelude::api::*;
 use crate::prelude::types::*;
 use axum::Router;
-use axum::routing::get;
+use axum::routing::{delete, get};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -9,6 +9,8 @@ pub fn create_user_router(bot_state: Arc<Mutex<BotState>>) -> Router<Arc<Mutex<B
     Router::new()
         .route("/avatar", get(handle_get_user_avatar))
         .route("/permissions", get(handle_get_user_permissions))
+        .route("/sessions", get(list_user_sessions_handler))
+        .route("/sessions/{id}", delete(revoke_user_session_handler))
         .layer(axum::middleware::from_fn_with_state(
             bot_state,
             auth_middleware,
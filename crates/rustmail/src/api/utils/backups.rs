@@ -0,0 +1,105 @@
+use crate::prelude::api::*;
+use crate::prelude::db::*;
+use crate::prelude::modules::*;
+use crate::prelude::types::*;
+use serde::Serialize;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::{error, info, warn};
+
+#[derive(Debug, Serialize, utoipa::ToSchema)]
+pub struct RestoreReport {
+    pub restored: String,
+    pub safety_backup: String,
+    pub bot_restarted: bool,
+}
+
+pub async fn restore_backup(
+    bot_state: Arc<Mutex<BotState>>,
+    backup: &BackupInfo,
+) -> Result<RestoreReport, String> {
+    let _guard = BACKUP_LOCK.lock().await;
+
+    let (pool, backups_config, was_running) = {
+        let state = bot_state.lock().await;
+        let pool = state
+            .db_pool
+            .clone()
+            .ok_or_else(|| "Database not initialized".to_string())?;
+        let backups_config = state
+            .config()
+            .map(|c| c.backups.clone())
+            .unwrap_or_default();
+        let running = matches!(state.status, BotStatus::Running { .. });
+        (pool, backups_config, running)
+    };
+
+    let staged = stage_restore(backup).await.map_err(|e| e.to_string())?;
+
+    if was_running {
+        info!("Stopping the bot to restore {}", backup.name);
+        stop_bot(bot_state.clone()).await;
+    }
+
+    let safety_backup = match create_backup(&backups_config, &pool).await {
+        Ok(safety_backup) => safety_backup,
+        Err(e) => {
+            let _ = std::fs::remove_file(&staged);
+            if was_running {
+                start_bot(bot_state.clone()).await;
+            }
+            return Err(format!("Failed to back up the current database: {}", e));
+        }
+    };
+
+    pool.close().await;
+
+    let swapped = swap_database_file(&staged).map_err(|e| e.to_string());
+    let opened = match &swapped {
+        Ok(()) => init_database().await.map_err(|e| {
+            error!("Failed to open the restored database: {e}");
+            format!("Failed to open the restored database: {}", e)
+        }),
+        Err(e) => Err(e.clone()),
+    };
+
+    let (new_pool, result) = match opened {
+        Ok(new_pool) => {
+            discard_previous_database();
+            info!("Database restored from {}", backup.name);
+            (new_pool, Ok(()))
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(&staged);
+            if swapped.is_ok()
+                && let Err(rollback) = rollback_database_file()
+            {
+                error!("Failed to roll back the database restore: {rollback}");
+            }
+            warn!("Database restore failed, reopening the previous database");
+            let reopened = init_database()
+                .await
+                .map_err(|reopen| format!("{}; reopening the database failed: {}", e, reopen))?;
+            (reopened, Err(e))
+        }
+    };
+
+    {
+        let mut state = bot_state.lock().await;
+        state.db_pool = Some(new_pool.clone());
+        if let Some(config) = &state.config {
+            config.set_db_pool(new_pool);
+        }
+    }
+    get_admin_cache().invalidate_all();
+    get_permissions_cache().invalidate_all();
+
+    let bot_restarted =
+        was_running && matches!(start_bot(bot_state).await, StartBotResponse::Success(..));
+
+    result.map(|()| RestoreReport {
+        restored: backup.name.clone(),
+        safety_backup: safety_backup.name,
+        bot_restarted,
+    })
+}
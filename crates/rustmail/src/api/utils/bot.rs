@@ -1,11 +1,11 @@
-use crate::bot::run_bot;
-use crate::config::load_config;
+use crate::bot::{reload_config_locked, run_bot};
 use crate::types::{BotState, BotStatus};
 use axum::Json;
 use axum::http::StatusCode;
 use std::sync::Arc;
 use tokio::spawn;
 use tokio::sync::Mutex;
+use tracing::{error, info};
 
 pub enum StartBotResponse {
     Success(StatusCode, Json<&'static str>),
@@ -21,7 +21,9 @@ pub async fn start_bot(bot_state: Arc<Mutex<BotState>>) -> StartBotResponse {
     let mut state_lock = bot_state.lock().await;
     match state_lock.status {
         BotStatus::Stopped => {
-            state_lock.config = load_config("config.toml");
+            if let Err(e) = reload_config_locked(&mut state_lock) {
+                error!("Failed to load configuration: {e}");
+            }
 
             if state_lock.config.is_none() {
                 return StartBotResponse::Conflict(
@@ -69,7 +71,7 @@ pub async fn stop_bot(bot_state: Arc<Mutex<BotState>>) -> StopBotResponse {
         handle.await.unwrap();
         StopBotResponse::Success(StatusCode::OK, Json("Bot stopped"))
     } else {
-        println!("Not Starting bot stop");
+        info!("Not Starting bot stop");
         StopBotResponse::Conflict(StatusCode::CONFLICT, Json("Bot is not running"))
     }
 }
@@ -1,11 +1,15 @@
+pub mod backups;
 pub mod bot;
 pub mod get_user_id_from_session;
 pub mod panel_permissions;
 pub mod permissions_cache;
+pub mod rate_limit;
 pub mod user_permissions;
 
+pub use backups::*;
 pub use bot::*;
 pub use get_user_id_from_session::*;
 pub use panel_permissions::*;
 pub use permissions_cache::*;
+pub use rate_limit::*;
 pub use user_permissions::*;
@@ -0,0 +1,150 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+const BUCKET_PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy)]
+pub struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+    full_at: Instant,
+}
+
+impl TokenBucket {
+    pub fn new(capacity: f64, now: Instant) -> Self {
+        Self {
+            tokens: capacity,
+            last_refill: now,
+            full_at: now,
+        }
+    }
+
+    pub fn try_acquire(
+        &mut self,
+        per_minute: f64,
+        capacity: f64,
+        now: Instant,
+    ) -> Result<(), Duration> {
+        let per_second = per_minute / 60.0;
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * per_second).min(capacity);
+        self.last_refill = now;
+
+        let result = if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / per_second))
+        };
+        self.full_at = now + Duration::from_secs_f64((capacity - self.tokens) / per_second);
+        result
+    }
+
+    pub fn is_full(&self, now: Instant) -> bool {
+        now >= self.full_at
+    }
+}
+
+struct Buckets {
+    by_key: HashMap<i64, TokenBucket>,
+    last_pruned: Instant,
+}
+
+impl Buckets {
+    // Buckets that refilled completely behave like new ones and can be dropped.
+    fn prune(&mut self, now: Instant) {
+        if now.saturating_duration_since(self.last_pruned) < BUCKET_PRUNE_INTERVAL {
+            return;
+        }
+        self.by_key.retain(|_, bucket| !bucket.is_full(now));
+        self.last_pruned = now;
+    }
+}
+
+fn get_buckets() -> &'static Mutex<Buckets> {
+    static BUCKETS: OnceLock<Mutex<Buckets>> = OnceLock::new();
+    BUCKETS.get_or_init(|| {
+        Mutex::new(Buckets {
+            by_key: HashMap::new(),
+            last_pruned: Instant::now(),
+        })
+    })
+}
+
+pub fn check_api_key_rate_limit(
+    key_id: i64,
+    per_minute: Option<i64>,
+    burst: Option<i64>,
+) -> Result<(), Duration> {
+    let Some(per_minute) = per_minute.filter(|r| *r > 0) else {
+        return Ok(());
+    };
+    let capacity = burst.filter(|b| *b > 0).unwrap_or(per_minute) as f64;
+    let now = Instant::now();
+
+    let mut buckets = get_buckets().lock().unwrap_or_else(|e| e.into_inner());
+    buckets.prune(now);
+    buckets
+        .by_key
+        .entry(key_id)
+        .or_insert_with(|| TokenBucket::new(capacity, now))
+        .try_acquire(per_minute as f64, capacity, now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_allows_burst_then_limits() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(2.0, start);
+
+        assert!(bucket.try_acquire(60.0, 2.0, start).is_ok());
+        assert!(bucket.try_acquire(60.0, 2.0, start).is_ok());
+
+        let retry_after = bucket.try_acquire(60.0, 2.0, start).unwrap_err();
+        assert_eq!(retry_after.as_secs(), 1);
+    }
+
+    #[test]
+    fn bucket_refills_over_time() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(1.0, start);
+
+        assert!(bucket.try_acquire(60.0, 1.0, start).is_ok());
+        assert!(bucket.try_acquire(60.0, 1.0, start).is_err());
+        assert!(
+            bucket
+                .try_acquire(60.0, 1.0, start + Duration::from_secs(1))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn only_refilled_buckets_are_pruned() {
+        let start = Instant::now();
+        let mut slow = TokenBucket::new(2.0, start);
+        assert!(slow.try_acquire(1.0, 2.0, start).is_ok());
+        assert!(slow.try_acquire(1.0, 2.0, start).is_ok());
+        let mut fast = TokenBucket::new(2.0, start);
+        assert!(fast.try_acquire(60.0, 2.0, start).is_ok());
+
+        let mut buckets = Buckets {
+            by_key: HashMap::from([(1, slow), (2, fast)]),
+            last_pruned: start,
+        };
+
+        buckets.prune(start + Duration::from_secs(30));
+        assert_eq!(buckets.by_key.len(), 2);
+
+        buckets.prune(start + BUCKET_PRUNE_INTERVAL);
+        assert_eq!(buckets.by_key.keys().collect::<Vec<_>>(), [&1]);
+
+        buckets.prune(start + BUCKET_PRUNE_INTERVAL * 2);
+        assert!(buckets.by_key.is_empty());
+    }
+}
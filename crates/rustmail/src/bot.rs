@@ -2,10 +2,11 @@ use crate::prelude::commands::*;
 use crate::prelude::config::*;
 use crate::prelude::db::*;
 use crate::prelude::errors::*;
+use crate::prelude::features::*;
 use crate::prelude::handlers::*;
 use crate::prelude::panel_commands::*;
 use crate::prelude::types::*;
-use serenity::all::{ClientBuilder, GatewayIntents, ShardManager};
+use serenity::all::{ActivityData, ClientBuilder, Context, GatewayIntents, ShardManager};
 use serenity::cache::Settings as CacheSettings;
 use serenity::prelude::TypeMapKey;
 use std::collections::HashMap;
@@ -15,6 +16,7 @@ use std::sync::atomic::AtomicBool;
 use std::time::Duration;
 use tokio::sync::Mutex;
 use tokio::{select, spawn};
+use tracing::{error, info, warn};
 
 pub struct ShardManagerKey;
 
@@ -24,14 +26,22 @@ impl TypeMapKey for ShardManagerKey {
 
 pub async fn init_bot_state(config_path: &str) -> Arc<Mutex<BotState>> {
     let pool = init_database().await.expect("An error occurred!");
-    println!("Database connected!");
+    info!("Database connected!");
 
-    let config = load_config(config_path);
+    let config = load_config(config_path).map(|mut config| {
+        config.db_pool = Some(pool.clone());
+        config
+    });
+    if let Some(config) = &config {
+        record_startup_config_revision(config).await;
+    }
+    let config = config.map(ConfigHandle::new);
 
     let (command_tx, _command_rx) = tokio::sync::mpsc::channel(32);
 
     let bot_state = BotState {
         config,
+        config_path: config_path.to_string(),
         status: BotStatus::Stopped,
         db_pool: Some(pool),
         command_tx: command_tx.clone(),
@@ -44,6 +54,207 @@ pub async fn init_bot_state(config_path: &str) -> Arc<Mutex<BotState>> {
     Arc::new(Mutex::new(bot_state))
 }
 
+pub fn reload_config_locked(state: &mut BotState) -> Result<Vec<ConfigChange>, String> {
+    let mut config = load_config(&state.config_path)
+        .ok_or_else(|| format!("{} is missing or invalid", state.config_path))?;
+
+    match &state.config {
+        Some(handle) => Ok(handle.replace(config)),
+        None => {
+            config.db_pool = state.db_pool.clone();
+            state.config = Some(ConfigHandle::new(config));
+            Ok(Vec::new())
+        }
+    }
+}
+
+// Panel saves are recorded with their author before the file watcher sees them.
+static CONFIG_UPDATE_LOCK: Mutex<()> = Mutex::const_new(());
+
+async fn record_config_revision(config: &Config, origin: &ConfigRevisionOrigin) {
+    let Some(pool) = &config.db_pool else {
+        return;
+    };
+    let snapshot = match serde_json::to_string(&config.to_masked_response()) {
+        Ok(snapshot) => snapshot,
+        Err(e) => {
+            error!("Failed to serialize config revision: {e}");
+            return;
+        }
+    };
+    if let Err(e) = insert_config_revision(&snapshot, origin, pool).await {
+        error!("Failed to record config revision: {e:?}");
+    }
+}
+
+async fn record_startup_config_revision(config: &Config) {
+    let Some(pool) = &config.db_pool else {
+        return;
+    };
+    let unchanged = match get_latest_config_revision(pool).await {
+        Ok(Some(latest)) => serde_json::from_str::<ConfigResponse>(&latest.snapshot)
+            .is_ok_and(|snapshot| snapshot == config.to_masked_response()),
+        Ok(None) => false,
+        Err(_) => return,
+    };
+    if !unchanged {
+        record_config_revision(
+            config,
+            &ConfigRevisionOrigin::new(ConfigRevisionSource::Startup),
+        )
+        .await;
+    }
+}
+
+async fn apply_config_file(
+    bot_state: &Arc<Mutex<BotState>>,
+    origin: ConfigRevisionOrigin,
+) -> Result<Vec<ConfigChange>, String> {
+    let (changes, config, command_tx, running) = {
+        let mut state = bot_state.lock().await;
+        let changes = reload_config_locked(&mut state)?;
+        let running = matches!(state.status, BotStatus::Running { .. });
+        (changes, state.config(), state.command_tx.clone(), running)
+    };
+
+    if changes.is_empty() {
+        return Ok(changes);
+    }
+
+    for change in &changes {
+        match change.impact {
+            ReloadImpact::Live => info!("Configuration reloaded: {} applied", change.field),
+            ReloadImpact::Reconnect => warn!(
+                "Configuration reloaded: {} changed, restart the bot from the panel to apply it",
+                change.field
+            ),
+            ReloadImpact::Restart => warn!(
+                "Configuration reloaded: {} changed, restart rustmail to apply it",
+                change.field
+            ),
+        }
+    }
+
+    if let Some(config) = config {
+        record_config_revision(&config, &origin).await;
+    }
+
+    if running {
+        let _ = command_tx
+            .send(BotCommand::ConfigReloaded {
+                changes: changes.clone(),
+            })
+            .await;
+    }
+
+    Ok(changes)
+}
+
+pub async fn save_and_reload_config(
+    bot_state: &Arc<Mutex<BotState>>,
+    config: &Config,
+    origin: ConfigRevisionOrigin,
+) -> Result<Vec<ConfigChange>, String> {
+    let _guard = CONFIG_UPDATE_LOCK.lock().await;
+    let config_path = bot_state.lock().await.config_path.clone();
+    save_config_with_backup(config, &config_path).await?;
+    apply_config_file(bot_state, origin).await
+}
+
+async fn apply_reloaded_config(ctx: &Context, config: &Config, changes: &[ConfigChange]) {
+    let changed = |field: &str| changes.iter().any(|c| c.field == field);
+
+    if changed("bot.status") {
+        ctx.set_activity(Some(ActivityData::playing(&config.bot.status)));
+    }
+
+    if changed("bot.enable_features") || changed("bot.features_channel_id") {
+        sync_features(ctx, config).await;
+    }
+
+    if changed("thread.board_channel_id")
+        && config.thread.board_channel_id.is_some()
+        && let Some(pool) = &config.db_pool
+    {
+        request_board_refresh(pool).await;
+    }
+}
+
+const CONFIG_WATCH_INTERVAL: Duration = Duration::from_secs(2);
+
+#[cfg(unix)]
+struct HangupSignal(Option<tokio::signal::unix::Signal>);
+
+#[cfg(unix)]
+impl HangupSignal {
+    fn new() -> Self {
+        use tokio::signal::unix::{SignalKind, signal};
+        match signal(SignalKind::hangup()) {
+            Ok(signal) => Self(Some(signal)),
+            Err(e) => {
+                error!("Failed to install SIGHUP handler: {e}");
+                Self(None)
+            }
+        }
+    }
+
+    async fn recv(&mut self) {
+        match &mut self.0 {
+            Some(signal) => {
+                signal.recv().await;
+            }
+            None => std::future::pending().await,
+        }
+    }
+}
+
+#[cfg(not(unix))]
+struct HangupSignal;
+
+#[cfg(not(unix))]
+impl HangupSignal {
+    fn new() -> Self {
+        Self
+    }
+
+    async fn recv(&mut self) {
+        std::future::pending().await
+    }
+}
+
+fn config_modified_at(path: &str) -> Option<std::time::SystemTime> {
+    std::fs::metadata(path).and_then(|m| m.modified()).ok()
+}
+
+pub async fn watch_config(bot_state: Arc<Mutex<BotState>>) {
+    let path = bot_state.lock().await.config_path.clone();
+    let mut last_modified = config_modified_at(&path);
+    let mut ticker = tokio::time::interval(CONFIG_WATCH_INTERVAL);
+    let mut hangup = HangupSignal::new();
+
+    loop {
+        let hangup_received = select! {
+            _ = hangup.recv() => {
+                info!("SIGHUP received, reloading {}", path);
+                true
+            }
+            _ = ticker.tick() => false,
+        };
+
+        let _guard = CONFIG_UPDATE_LOCK.lock().await;
+        let modified = config_modified_at(&path);
+        if modified == last_modified && !hangup_received {
+            continue;
+        }
+        last_modified = modified;
+
+        let origin = ConfigRevisionOrigin::new(ConfigRevisionSource::File);
+        if let Err(e) = apply_config_file(&bot_state, origin).await {
+            error!("Configuration not reloaded: {e}");
+        }
+    }
+}
+
 pub async fn start_bot_if_config_valid(
     bot_state: Arc<Mutex<BotState>>,
 ) -> Result<(), ModmailError> {
@@ -86,14 +297,9 @@ pub async fn run_bot(
     let shutdown_rx_command = shutdown.clone();
     let shutdown_rx = shutdown.clone();
 
-    println!("Starting rustmail...");
+    info!("Starting rustmail...");
 
-    let pool = {
-        let state_lock = bot_state.lock().await;
-        state_lock.db_pool.clone().expect("Database pool not set")
-    };
-
-    let (mut config, maintenance_mode) = {
+    let (config_handle, maintenance_mode) = {
         let state_lock = bot_state.lock().await;
         if state_lock.config.is_none() {
             panic!("Config not set before starting rustmail!");
@@ -104,9 +310,9 @@ pub async fn run_bot(
         )
     };
 
-    let pagination = Arc::new(Mutex::new(HashMap::<String, PaginationContext>::new()));
+    let config = config_handle.load();
 
-    config.db_pool = Some(pool.clone());
+    let pagination = Arc::new(Mutex::new(HashMap::<String, PaginationContext>::new()));
 
     let intents = GatewayIntents::GUILDS
         | GatewayIntents::GUILD_MESSAGES
@@ -125,65 +331,39 @@ pub async fn run_bot(
     cache_settings.time_to_live = Duration::from_secs(6 * 60 * 60);
 
     let mut registry = CommandRegistry::new(shutdown_rx_command, pagination.clone());
-    registry.register_command(AddStaffCommand);
-    registry.register_command(AlertCommand);
-    registry.register_command(CloseCommand);
-    registry.register_command(DeleteCommand);
-    registry.register_command(EditCommand);
-    registry.register_command(ForceCloseCommand);
-    registry.register_command(HelpCommand);
-    registry.register_command(IdCommand);
-    registry.register_command(MoveCommand);
-    registry.register_command(NewThreadCommand);
-    registry.register_command(RecoverCommand);
-    registry.register_command(RemoveStaffCommand);
-    registry.register_command(ReplyCommand);
-    registry.register_command(AddReminderCommand);
-    registry.register_command(RemoveReminderCommand);
-    registry.register_command(ReminderSubscriptionCommand);
-    registry.register_command(LogsCommand);
-    registry.register_command(TakeCommand);
-    registry.register_command(ReleaseCommand);
-    registry.register_command(PingCommand);
-    registry.register_command(SnippetCommand);
-    registry.register_command(StatusCommand);
-    registry.register_command(CategoryCommand);
-    registry.register_command(RenameCommand);
-    registry.register_command(BaninfoCommand);
+    register_commands(&mut registry);
 
     let registry = Arc::new(registry);
 
     let mut client: serenity::Client = ClientBuilder::new(config.bot.token.clone(), intents)
         .cache_settings(cache_settings)
         .event_handler(ReadyHandler::new(
-            &config,
+            &config_handle,
             registry.clone(),
             shutdown_rx.clone(),
             bot_state.clone(),
         ))
         .event_handler(
             GuildMessagesHandler::new(
-                &config,
+                &config_handle,
                 registry.clone(),
-                shutdown_rx.clone(),
                 pagination.clone(),
                 maintenance_mode.clone(),
             )
             .await,
         )
-        .event_handler(TypingProxyHandler::new(&config))
-        .event_handler(GuildMembersHandler::new(&config))
-        .event_handler(GuildMessageReactionsHandler::new(&config))
-        .event_handler(GuildModerationHandler::new(&config))
+        .event_handler(TypingProxyHandler::new(&config_handle))
+        .event_handler(GuildMembersHandler::new(&config_handle))
+        .event_handler(GuildMessageReactionsHandler::new(&config_handle))
+        .event_handler(GuildModerationHandler::new(&config_handle))
         .event_handler(InteractionHandler::new(
-            &config,
+            &config_handle,
             registry.clone(),
-            shutdown_rx,
             pagination,
             maintenance_mode,
         ))
-        .event_handler(GuildHandler::new(&config))
-        .event_handler(GuildBanHandler::new(&config))
+        .event_handler(GuildHandler::new(&config_handle))
+        .event_handler(GuildBanHandler::new(&config_handle))
         .await
         .expect("Failed to create client.");
 
@@ -198,22 +378,22 @@ pub async fn run_bot(
     }
 
     if let Err(e) = config.validate_servers(&client.http).await {
-        eprintln!("Configuration validation error: {}", e);
-        eprintln!(
+        error!("Configuration validation error: {}", e);
+        error!(
             "Check that the server IDs are correct and that the rustmail has access to the servers."
         );
         process::exit(1);
     }
 
-    println!("Configuration successfully validated!!");
+    info!("Configuration successfully validated!!");
     if config.bot.is_dual_mode() {
-        println!(
+        info!(
             "Mode: Dual server (Community: {}, Staff: {})",
             config.bot.get_community_guild_id(),
             config.bot.get_staff_guild_id()
         );
     } else {
-        println!(
+        info!(
             "Mode: Mono server (ID: {})",
             config.bot.get_community_guild_id()
         );
@@ -223,14 +403,14 @@ pub async fn run_bot(
 
     let mut discord_task = spawn(async move {
         if let Err(e) = client.start().await {
-            println!("Failed to initialize client: {e}");
+            error!("Failed to initialize client: {e}");
         }
     });
 
     loop {
         select! {
             _ = shutdown.changed() => {
-                println!("Shutdown signal received, shutting down...");
+                info!("Shutdown signal received, shutting down...");
                 shard_manager.shutdown_all().await;
                 break;
             }
@@ -241,19 +421,81 @@ pub async fn run_bot(
                             let state_lock = bot_state.lock().await;
                             state_lock.bot_http.clone().expect("Failed to get bot http")
                         };
-                        let is_member = is_member(http, config.bot.get_staff_guild_id(), user_id).await;
+                        let staff_guild_id = config_handle.load().bot.get_staff_guild_id();
+                        let is_member = is_member(http, staff_guild_id, user_id).await;
 
                         let _ = resp.send(is_member);
                     }
+                    BotCommand::ListCommands { resp } => {
+                        let mut names: Vec<String> =
+                            registry.all().iter().map(|c| c.name().to_string()).collect();
+                        names.sort();
+                        let _ = resp.send(names);
+                    }
+                    BotCommand::SyncCommands => {
+                        let ctx = {
+                            let state_lock = bot_state.lock().await;
+                            state_lock.bot_context.read().await.clone()
+                        };
+                        if let Some(ctx) = ctx {
+                            sync_application_commands(&ctx, &config_handle.load(), &registry).await;
+                        }
+                    }
+                    BotCommand::ConfigReloaded { changes } => {
+                        let ctx = {
+                            let state_lock = bot_state.lock().await;
+                            state_lock.bot_context.read().await.clone()
+                        };
+                        if let Some(ctx) = ctx {
+                            apply_reloaded_config(&ctx, &config_handle.load(), &changes).await;
+                        }
+                    }
                 }
             }
 
             _ = &mut discord_task => {
-                println!("Discord task ended.");
+                info!("Discord task ended.");
                 break;
             }
         }
     }
 
-    println!("Bot has been shut down.");
+    info!("Bot has been shut down.");
+}
+
+pub fn register_commands(registry: &mut CommandRegistry) {
+    registry.register_command(AddStaffCommand);
+    registry.register_command(AlertCommand);
+    registry.register_command(CloseCommand);
+    registry.register_command(DeleteCommand);
+    registry.register_command(EditCommand);
+    registry.register_command(ForceCloseCommand);
+    registry.register_command(HelpCommand);
+    registry.register_command(IdCommand);
+    registry.register_command(MoveCommand);
+    registry.register_command(NewThreadCommand);
+    registry.register_command(RecoverCommand);
+    registry.register_command(RemoveStaffCommand);
+    registry.register_command(ReplyCommand);
+    registry.register_command(AddReminderCommand);
+    registry.register_command(RemoveReminderCommand);
+    registry.register_command(ReminderSubscriptionCommand);
+    registry.register_command(LogsCommand);
+    registry.register_command(TakeCommand);
+    registry.register_command(ReleaseCommand);
+    registry.register_command(PingCommand);
+    registry.register_command(SnippetCommand);
+    registry.register_command(StatusCommand);
+    registry.register_command(CategoryCommand);
+    registry.register_command(RenameCommand);
+    registry.register_command(PriorityCommand);
+    registry.register_command(HoldCommand);
+    registry.register_command(ResolveCommand);
+    registry.register_command(BaninfoCommand);
+    registry.register_command(GdprCommand);
+    for feature in feature_registry().all() {
+        for command in feature.commands() {
+            registry.register_feature_command(feature.key(), command);
+        }
+    }
 }
@@ -0,0 +1,176 @@
+use crate::api::handler::apikeys::normalize_restrictions;
+use crate::cli::{Args, CliResult, Output};
+use crate::prelude::db::*;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct ApiKeySummary {
+    id: i64,
+    name: String,
+    permissions: Vec<Permission>,
+    created_at: i64,
+    expires_at: Option<i64>,
+    last_used_at: Option<i64>,
+    is_active: bool,
+}
+
+#[derive(Serialize)]
+struct CreatedApiKey {
+    api_key: String,
+    #[serde(flatten)]
+    key: ApiKeySummary,
+}
+
+impl From<ApiKey> for ApiKeySummary {
+    fn from(key: ApiKey) -> Self {
+        Self {
+            id: key.id,
+            name: key.name,
+            permissions: key.permissions,
+            created_at: key.created_at,
+            expires_at: key.expires_at,
+            last_used_at: key.last_used_at,
+            is_active: key.is_active,
+        }
+    }
+}
+
+pub async fn run(mut args: Args, output: Output) -> CliResult {
+    match args
+        .required("apikey subcommand (create, list, revoke)")?
+        .as_str()
+    {
+        "create" => create(args, output).await,
+        "list" => list(args, output).await,
+        "revoke" => revoke(args, output).await,
+        other => Err(format!("Unknown apikey subcommand: {}", other)),
+    }
+}
+
+fn parse_permission(value: &str) -> Result<Permission, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_uppercase())).map_err(|_| {
+        format!(
+            "Unknown permission '{}' (create_ticket, read_tickets, update_ticket, delete_ticket, read_config, update_config, manage_bot)",
+            value
+        )
+    })
+}
+
+fn format_timestamp(ts: Option<i64>) -> String {
+    ts.and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0))
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+async fn create(mut args: Args, output: Output) -> CliResult {
+    let name = args
+        .option("--name")?
+        .filter(|n| !n.trim().is_empty())
+        .ok_or("--name is required")?;
+    let permissions = args
+        .options("--permission")?
+        .iter()
+        .map(|p| parse_permission(p))
+        .collect::<Result<Vec<_>, _>>()?;
+    if permissions.is_empty() {
+        return Err("At least one --permission is required".to_string());
+    }
+    let expires_at = match args.option("--expires-in-days")? {
+        Some(days) => {
+            let days: i64 = days
+                .parse()
+                .map_err(|_| "--expires-in-days must be a number")?;
+            Some(Utc::now().timestamp() + days * 86400)
+        }
+        None => None,
+    };
+    let rate_limit_per_minute = args
+        .option("--rate-limit")?
+        .map(|v| v.parse::<i64>())
+        .transpose()
+        .map_err(|_| "--rate-limit must be a number")?;
+    let restrictions = ApiKeyRestrictions {
+        rate_limit_per_minute,
+        rate_limit_burst: None,
+        allowed_cidrs: args.options("--allow-cidr")?,
+        allowed_category_ids: args.options("--category")?,
+    };
+    args.finish()?;
+
+    let restrictions = normalize_restrictions(restrictions).map_err(|(_, e)| e)?;
+
+    let pool = crate::cli::open_database().await?;
+    let (plain_key, key_hash) = generate_api_key()?;
+    let key = create_api_key(&pool, key_hash, name, permissions, expires_at, restrictions).await?;
+
+    let created = CreatedApiKey {
+        api_key: plain_key,
+        key: key.into(),
+    };
+    output.print(&created, || {
+        println!("Created API key #{} ({})", created.key.id, created.key.name);
+        println!("{}", created.api_key);
+        println!("Store it now: it cannot be shown again.");
+    });
+    Ok(())
+}
+
+async fn list(args: Args, output: Output) -> CliResult {
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let keys: Vec<ApiKeySummary> = list_api_keys(&pool)
+        .await?
+        .into_iter()
+        .map(ApiKeySummary::from)
+        .collect();
+
+    output.print(&keys, || {
+        if keys.is_empty() {
+            println!("No API keys");
+            return;
+        }
+        println!(
+            "{:<6} {:<24} {:<8} {:<17} {:<17} PERMISSIONS",
+            "ID", "NAME", "ACTIVE", "EXPIRES", "LAST USED"
+        );
+        for key in &keys {
+            let permissions = key
+                .permissions
+                .iter()
+                .map(|p| format!("{:?}", p))
+                .collect::<Vec<_>>()
+                .join(",");
+            println!(
+                "{:<6} {:<24} {:<8} {:<17} {:<17} {}",
+                key.id,
+                key.name,
+                if key.is_active { "yes" } else { "no" },
+                format_timestamp(key.expires_at),
+                format_timestamp(key.last_used_at),
+                permissions
+            );
+        }
+    });
+    Ok(())
+}
+
+async fn revoke(mut args: Args, output: Output) -> CliResult {
+    let id: i64 = args
+        .required("API key ID")?
+        .parse()
+        .map_err(|_| "API key ID must be a number")?;
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    if !list_api_keys(&pool).await?.iter().any(|k| k.id == id) {
+        return Err(format!("API key #{} not found", id));
+    }
+    revoke_api_key(&pool, id).await?;
+
+    output.print(&serde_json::json!({ "revoked": id }), || {
+        println!("Revoked API key #{}", id);
+    });
+    Ok(())
+}
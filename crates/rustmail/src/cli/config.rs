@@ -0,0 +1,56 @@
+use crate::cli::{Args, CliResult, Output};
+use crate::config::{build_headless_config, parse_config, resolve_config_path, validate_config};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct ConfigCheck {
+    path: String,
+    valid: bool,
+    errors: Vec<String>,
+}
+
+pub async fn run(mut args: Args, output: Output) -> CliResult {
+    match args.required("config subcommand (check)")?.as_str() {
+        "check" => check(args, output),
+        other => Err(format!("Unknown config subcommand: {}", other)),
+    }
+}
+
+fn check(mut args: Args, output: Output) -> CliResult {
+    let path = args
+        .option("--path")?
+        .unwrap_or_else(|| resolve_config_path("config.toml"));
+    args.finish()?;
+
+    let parsed = match std::fs::read_to_string(&path) {
+        Ok(content) => parse_config(&content),
+        Err(e) => build_headless_config()
+            .unwrap_or_else(|| Err(format!("Failed to read {}: {}", path, e))),
+    };
+    let errors = match parsed {
+        Err(e) => vec![e],
+        Ok(config) => validate_config(&config).err().into_iter().collect(),
+    };
+
+    let report = ConfigCheck {
+        valid: errors.is_empty(),
+        path,
+        errors,
+    };
+    output.print(&report, || {
+        if report.valid {
+            println!("{} is valid", report.path);
+        } else {
+            println!("{} is invalid:", report.path);
+            for error in &report.errors {
+                println!("  - {}", error);
+            }
+        }
+    });
+
+    if report.valid {
+        Ok(())
+    } else {
+        Err("Configuration check failed".to_string())
+    }
+}
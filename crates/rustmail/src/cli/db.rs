@@ -0,0 +1,90 @@
+use crate::cli::{Args, CliResult, Output};
+use crate::prelude::db::*;
+use serde::Serialize;
+use std::path::PathBuf;
+
+#[derive(Serialize)]
+struct MigrateReport {
+    applied: Vec<i64>,
+    total: usize,
+}
+
+#[derive(Serialize)]
+struct BackupReport {
+    path: String,
+    size_bytes: u64,
+}
+
+pub async fn run(mut args: Args, output: Output) -> CliResult {
+    match args.required("db subcommand (backup)")?.as_str() {
+        "backup" => backup(args, output).await,
+        other => Err(format!("Unknown db subcommand: {}", other)),
+    }
+}
+
+pub async fn migrate(args: Args, output: Output) -> CliResult {
+    args.finish()?;
+
+    let pool = connect_database()
+        .await
+        .map_err(|e| format!("Failed to open database: {}", e))?;
+    let before = list_applied_migrations(&pool)
+        .await
+        .map_err(|e| format!("Failed to read migrations: {}", e))?;
+    MIGRATOR
+        .run(&pool)
+        .await
+        .map_err(|e| format!("Migration failed: {}", e))?;
+    let after = list_applied_migrations(&pool)
+        .await
+        .map_err(|e| format!("Failed to read migrations: {}", e))?;
+
+    let report = MigrateReport {
+        applied: after
+            .iter()
+            .filter(|v| !before.contains(v))
+            .copied()
+            .collect(),
+        total: after.len(),
+    };
+    output.print(&report, || {
+        if report.applied.is_empty() {
+            println!("Database is up to date ({} migrations)", report.total);
+        } else {
+            for version in &report.applied {
+                println!("Applied {}", version);
+            }
+            println!(
+                "{} migration(s) applied, {} in total",
+                report.applied.len(),
+                report.total
+            );
+        }
+    });
+    Ok(())
+}
+
+async fn backup(mut args: Args, output: Output) -> CliResult {
+    let target = args
+        .option("--output")?
+        .map(PathBuf::from)
+        .unwrap_or_else(default_backup_path);
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let size_bytes = backup_database(&target, &pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let report = BackupReport {
+        path: target.display().to_string(),
+        size_bytes,
+    };
+    output.print(&report, || {
+        println!(
+            "Backup written to {} ({} bytes)",
+            report.path, report.size_bytes
+        );
+    });
+    Ok(())
+}
@@ -0,0 +1,65 @@
+use crate::cli::{Args, CliResult, Output};
+use crate::prelude::db::*;
+use crate::prelude::modules::*;
+use std::path::Path;
+
+pub async fn run(mut args: Args, output: Output) -> CliResult {
+    let source = args.required("import source (modmail, modmailbot)")?;
+    let source =
+        ImportSource::parse(&source).ok_or_else(|| format!("Unknown import source: {}", source))?;
+    let dry_run = args.flag("--dry-run");
+
+    let bundle = match source {
+        ImportSource::Modmail => {
+            let config_path = args.option("--config")?;
+            let logs_path = args.required("logs export file")?;
+            args.finish()?;
+
+            let logs = std::fs::read_to_string(&logs_path)
+                .map_err(|e| format!("Failed to read {}: {}", logs_path, e))?;
+            let config = config_path
+                .map(|path| {
+                    std::fs::read_to_string(&path)
+                        .map_err(|e| format!("Failed to read {}: {}", path, e))
+                })
+                .transpose()?;
+            parse_modmail_export(&logs, config.as_deref())?
+        }
+        ImportSource::Modmailbot => {
+            let path = args.required("modmailbot database file")?;
+            args.finish()?;
+            read_modmailbot_database(Path::new(&path)).await?
+        }
+    };
+
+    let pool = crate::cli::open_database().await?;
+    let report = import_bundle(source, &bundle, dry_run, &pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    output.print(&report, || {
+        if report.dry_run {
+            println!("Dry run, nothing was written:");
+        }
+        println!(
+            "Threads: {} imported, {} already imported",
+            report.threads_imported, report.threads_skipped
+        );
+        println!("Messages: {} imported", report.messages_imported);
+        println!(
+            "Snippets: {} imported, {} skipped (key exists)",
+            report.snippets_imported, report.snippets_skipped
+        );
+        println!(
+            "Blocked users: {} imported, {} skipped (already blocked)",
+            report.blocked_imported, report.blocked_skipped
+        );
+        if report.unsupported > 0 {
+            println!(
+                "{} record(s) have no equivalent in Rustmail and were left out",
+                report.unsupported
+            );
+        }
+    });
+    Ok(())
+}
@@ -0,0 +1,242 @@
+use crate::cli::{Args, CliResult, Output};
+use crate::config::resolve_config_path;
+use crate::prelude::db::*;
+use crate::prelude::modules::*;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io::{BufRead, IsTerminal, Write};
+
+#[derive(Serialize)]
+struct ExportSummary {
+    path: String,
+    config: bool,
+    secrets_included: bool,
+    history_included: bool,
+    rows: BTreeMap<String, usize>,
+}
+
+pub async fn run(mut args: Args, output: Output) -> CliResult {
+    match args
+        .required("instance subcommand (export, ids, import)")?
+        .as_str()
+    {
+        "export" => export(args, output).await,
+        "ids" => ids(args, output),
+        "import" => import(args, output).await,
+        other => Err(format!("Unknown instance subcommand: {}", other)),
+    }
+}
+
+fn read_bundle(path: &str) -> Result<InstanceBundle, String> {
+    let content =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid instance export {}: {}", path, e))
+}
+
+fn parse_id(value: &str) -> Result<String, String> {
+    let value = value.trim();
+    match value.parse::<u64>() {
+        Ok(id) if id != 0 => Ok(value.to_string()),
+        _ => Err(format!("Invalid Discord ID: {}", value)),
+    }
+}
+
+fn read_mapping(path: &str) -> Result<BTreeMap<String, String>, String> {
+    let content =
+        std::fs::read_to_string(path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let table: toml::Table =
+        toml::from_str(&content).map_err(|e| format!("Invalid mapping {}: {}", path, e))?;
+
+    table
+        .into_iter()
+        .map(|(old, new)| {
+            let new = match new {
+                toml::Value::String(new) => new,
+                toml::Value::Integer(new) => new.to_string(),
+                other => return Err(format!("Invalid ID for {}: {}", old, other)),
+            };
+            Ok((parse_id(&old)?, parse_id(&new)?))
+        })
+        .collect()
+}
+
+fn prompt_mapping(ids: &[DiscordIdUse]) -> Result<BTreeMap<String, String>, String> {
+    let mut mapping = BTreeMap::new();
+    if ids.is_empty() {
+        return Ok(mapping);
+    }
+
+    println!("Enter the ID of each entry on the new server, or press Enter to keep it.");
+    let mut lines = std::io::stdin().lock().lines();
+    for used in ids {
+        loop {
+            print!(
+                "{} {} ({}): ",
+                used.kind.as_str(),
+                used.id,
+                used.used_by.join(", ")
+            );
+            std::io::stdout().flush().map_err(|e| e.to_string())?;
+
+            let answer = match lines.next() {
+                Some(line) => line.map_err(|e| e.to_string())?,
+                None => return Err("Import cancelled".to_string()),
+            };
+            if answer.trim().is_empty() {
+                break;
+            }
+            match parse_id(&answer) {
+                Ok(new) => {
+                    mapping.insert(used.id.clone(), new);
+                    break;
+                }
+                Err(e) => println!("{}", e),
+            }
+        }
+    }
+
+    Ok(mapping)
+}
+
+async fn export(mut args: Args, output: Output) -> CliResult {
+    let include_secrets = args.flag("--include-secrets");
+    let include_history = args.flag("--include-history");
+    let path = args.required("export file")?;
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let bundle = export_instance(
+        &resolve_config_path("config.toml"),
+        include_secrets,
+        include_history,
+        &pool,
+    )
+    .await?;
+
+    let json = serde_json::to_string_pretty(&bundle)
+        .map_err(|e| format!("Failed to serialize the export: {}", e))?;
+    std::fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+
+    let summary = ExportSummary {
+        path,
+        config: bundle.config.is_some(),
+        secrets_included: include_secrets,
+        history_included: include_history,
+        rows: bundle
+            .tables
+            .iter()
+            .map(|(table, rows)| (table.clone(), rows.len()))
+            .collect(),
+    };
+    output.print(&summary, || {
+        println!("Exported to {}", summary.path);
+        if !summary.config {
+            println!("config.toml was not found and is not included");
+        } else if summary.secrets_included {
+            println!("The export contains the bot token and OAuth2 secret, keep it private");
+        }
+        for (table, count) in &summary.rows {
+            println!("  {}: {}", table, count);
+        }
+    });
+    Ok(())
+}
+
+fn ids(mut args: Args, output: Output) -> CliResult {
+    let path = args.required("export file")?;
+    args.finish()?;
+
+    let bundle = read_bundle(&path)?;
+    let ids = discord_ids(&bundle);
+    output.print(&ids, || {
+        println!(
+            "# Discord IDs of {}. Set each value to the ID on the new",
+            path
+        );
+        println!("# server, then pass this file to `rustmail instance import --mapping`.");
+        for used in &ids {
+            println!();
+            println!("# {}: {}", used.kind.as_str(), used.used_by.join(", "));
+            println!("\"{}\" = \"{}\"", used.id, used.id);
+        }
+    });
+    Ok(())
+}
+
+async fn import(mut args: Args, output: Output) -> CliResult {
+    let mapping_path = args.option("--mapping")?;
+    let replace_config = args.flag("--replace-config");
+    let dry_run = args.flag("--dry-run");
+    let path = args.required("export file")?;
+    args.finish()?;
+
+    let bundle = read_bundle(&path)?;
+    let mapping = match mapping_path {
+        Some(mapping_path) => read_mapping(&mapping_path)?,
+        None if output == Output::Human && std::io::stdin().is_terminal() => {
+            prompt_mapping(&discord_ids(&bundle))?
+        }
+        None => BTreeMap::new(),
+    };
+
+    let pool = crate::cli::open_database().await?;
+    let report = import_instance(
+        bundle,
+        &mapping,
+        &resolve_config_path("config.toml"),
+        replace_config,
+        dry_run,
+        &pool,
+    )
+    .await?;
+
+    output.print(&report, || {
+        if report.dry_run {
+            println!("Dry run, nothing was written:");
+        }
+        match report.config {
+            ConfigImportOutcome::Written => println!("Configuration: written"),
+            ConfigImportOutcome::Kept => {
+                println!("Configuration: kept the existing file (use --replace-config)")
+            }
+            ConfigImportOutcome::Absent => println!("Configuration: not in the export"),
+        }
+
+        let tables: std::collections::BTreeSet<&String> = report
+            .imported
+            .keys()
+            .chain(report.skipped.keys())
+            .collect();
+        for table in tables {
+            println!(
+                "  {}: {} imported, {} skipped",
+                table,
+                report.imported.get(table).unwrap_or(&0),
+                report.skipped.get(table).unwrap_or(&0)
+            );
+        }
+
+        if !report.remapped.is_empty() {
+            println!("Remapped IDs:");
+            for (old, new) in &report.remapped {
+                println!("  {} -> {}", old, new);
+            }
+        }
+
+        if !report.api_keys.is_empty() {
+            if report.dry_run {
+                println!("API keys that would be recreated with new secrets:");
+            } else {
+                println!("API keys were recreated with new secrets, shown only once:");
+            }
+            for key in &report.api_keys {
+                if report.dry_run {
+                    println!("  {}", key.name);
+                } else {
+                    println!("  {}: {}", key.name, key.api_key);
+                }
+            }
+        }
+    });
+    Ok(())
+}
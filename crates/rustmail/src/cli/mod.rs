@@ -0,0 +1,215 @@
+use crate::prelude::db::*;
+use serde::Serialize;
+use sqlx::SqlitePool;
+
+pub mod apikey;
+pub mod config;
+pub mod db;
+pub mod import;
+pub mod instance;
+pub mod permissions;
+pub mod snippets;
+pub mod tickets;
+
+pub const SUBCOMMANDS: [&str; 9] = [
+    "config",
+    "migrate",
+    "apikey",
+    "permissions",
+    "tickets",
+    "snippets",
+    "db",
+    "import",
+    "instance",
+];
+
+pub type CliResult = Result<(), String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Output {
+    Human,
+    Json,
+}
+
+impl Output {
+    pub fn from_args(args: &mut Args) -> Self {
+        if args.flag("--json") {
+            Output::Json
+        } else {
+            Output::Human
+        }
+    }
+
+    pub fn print<T: Serialize>(&self, value: &T, human: impl FnOnce()) {
+        match self {
+            Output::Human => human(),
+            Output::Json => match serde_json::to_string_pretty(value) {
+                Ok(json) => println!("{}", json),
+                Err(e) => eprintln!("Failed to serialize output: {}", e),
+            },
+        }
+    }
+}
+
+pub struct Args {
+    items: Vec<String>,
+}
+
+impl Args {
+    pub fn new(items: &[String]) -> Self {
+        Self {
+            items: items.to_vec(),
+        }
+    }
+
+    pub fn flag(&mut self, name: &str) -> bool {
+        match self.items.iter().position(|a| a == name) {
+            Some(index) => {
+                self.items.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn option(&mut self, name: &str) -> Result<Option<String>, String> {
+        let prefix = format!("{}=", name);
+        let Some(index) = self
+            .items
+            .iter()
+            .position(|a| a == name || a.starts_with(&prefix))
+        else {
+            return Ok(None);
+        };
+
+        let arg = self.items.remove(index);
+        if let Some(value) = arg.strip_prefix(&prefix) {
+            return Ok(Some(value.to_string()));
+        }
+        if index < self.items.len() && !self.items[index].starts_with("--") {
+            return Ok(Some(self.items.remove(index)));
+        }
+        Err(format!("{} needs a value", name))
+    }
+
+    pub fn options(&mut self, name: &str) -> Result<Vec<String>, String> {
+        let mut values = Vec::new();
+        while let Some(value) = self.option(name)? {
+            values.push(value);
+        }
+        Ok(values)
+    }
+
+    pub fn positional(&mut self) -> Option<String> {
+        let index = self.items.iter().position(|a| !a.starts_with("--"))?;
+        Some(self.items.remove(index))
+    }
+
+    pub fn required(&mut self, what: &str) -> Result<String, String> {
+        self.positional().ok_or_else(|| format!("Missing {}", what))
+    }
+
+    pub fn finish(self) -> CliResult {
+        match self.items.first() {
+            Some(arg) => Err(format!("Unexpected argument: {}", arg)),
+            None => Ok(()),
+        }
+    }
+}
+
+pub async fn open_database() -> Result<SqlitePool, String> {
+    init_database()
+        .await
+        .map_err(|e| format!("Failed to open database: {}", e))
+}
+
+pub async fn run(command: &str, rest: &[String]) -> i32 {
+    let mut args = Args::new(rest);
+    let output = Output::from_args(&mut args);
+
+    let result = match command {
+        "config" => config::run(args, output).await,
+        "migrate" => db::migrate(args, output).await,
+        "apikey" => apikey::run(args, output).await,
+        "permissions" => permissions::run(args, output).await,
+        "tickets" => tickets::run(args, output).await,
+        "snippets" => snippets::run(args, output).await,
+        "db" => db::run(args, output).await,
+        "import" => import::run(args, output).await,
+        "instance" => instance::run(args, output).await,
+        _ => Err(format!("Unknown command: {}", command)),
+    };
+
+    match result {
+        Ok(()) => 0,
+        Err(e) => {
+            match output {
+                Output::Human => eprintln!("error: {}", e),
+                Output::Json => eprintln!("{}", serde_json::json!({ "error": e })),
+            }
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(items: &[&str]) -> Args {
+        Args::new(&items.iter().map(|s| s.to_string()).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn json_flag_is_taken_from_anywhere() {
+        let mut parsed = args(&["list", "--json", "--limit", "5"]);
+        assert_eq!(Output::from_args(&mut parsed), Output::Json);
+        assert_eq!(parsed.positional().as_deref(), Some("list"));
+        assert_eq!(parsed.option("--limit").unwrap().as_deref(), Some("5"));
+        assert!(parsed.finish().is_ok());
+
+        assert_eq!(Output::from_args(&mut args(&["list"])), Output::Human);
+    }
+
+    #[test]
+    fn options_accept_both_forms_and_repeat() {
+        let mut parsed = args(&["--tag", "a", "--tag=b", "--name=x", "--tag", "c"]);
+        assert_eq!(parsed.options("--tag").unwrap(), ["a", "b", "c"]);
+        assert_eq!(parsed.option("--name").unwrap().as_deref(), Some("x"));
+        assert_eq!(parsed.option("--name").unwrap(), None);
+        assert!(parsed.options("--other").unwrap().is_empty());
+        assert!(parsed.finish().is_ok());
+    }
+
+    #[test]
+    fn option_without_a_value_is_an_error() {
+        assert_eq!(
+            args(&["--limit"]).option("--limit").unwrap_err(),
+            "--limit needs a value"
+        );
+        assert_eq!(
+            args(&["--limit", "--json"]).option("--limit").unwrap_err(),
+            "--limit needs a value"
+        );
+        assert!(args(&["--tag", "a", "--tag"]).options("--tag").is_err());
+    }
+
+    #[test]
+    fn missing_positional_is_reported() {
+        assert_eq!(
+            args(&["--json"]).required("ticket ID").unwrap_err(),
+            "Missing ticket ID"
+        );
+    }
+
+    #[test]
+    fn unconsumed_arguments_are_rejected() {
+        let mut parsed = args(&["show", "42", "--verbose"]);
+        assert_eq!(parsed.required("ticket ID").unwrap(), "show");
+        assert_eq!(parsed.required("ticket ID").unwrap(), "42");
+        assert_eq!(
+            parsed.finish().unwrap_err(),
+            "Unexpected argument: --verbose"
+        );
+    }
+}
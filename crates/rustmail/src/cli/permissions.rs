@@ -0,0 +1,181 @@
+use crate::cli::{Args, CliResult, Output};
+use crate::prelude::db::*;
+use chrono::Utc;
+use rustmail_types::api::panel_permissions::*;
+use sqlx::{Row, SqlitePool, query};
+
+pub async fn run(mut args: Args, output: Output) -> CliResult {
+    match args
+        .required("permissions subcommand (grant, revoke, list)")?
+        .as_str()
+    {
+        "grant" => grant(args, output).await,
+        "revoke" => revoke(args, output).await,
+        "list" => list(args, output).await,
+        other => Err(format!("Unknown permissions subcommand: {}", other)),
+    }
+}
+
+fn parse_grant(args: &mut Args) -> Result<(SubjectType, String, PanelPermission), String> {
+    let subject = match (args.option("--user")?, args.option("--role")?) {
+        (Some(id), None) => (SubjectType::User, id),
+        (None, Some(id)) => (SubjectType::Role, id),
+        _ => return Err("Pass exactly one of --user <id> or --role <id>".to_string()),
+    };
+    if subject.1.parse::<u64>().is_err() {
+        return Err(format!("'{}' is not a Discord ID", subject.1));
+    }
+    let permission = args.required("permission")?;
+    let permission = PanelPermission::from_str(&permission).ok_or_else(|| {
+        format!(
+            "Unknown permission '{}' (view_panel, manage_bot, manage_config, manage_tickets, manage_api_keys, manage_permissions, manage_categories, view_bans)",
+            permission
+        )
+    })?;
+    Ok((subject.0, subject.1, permission))
+}
+
+async fn grant(mut args: Args, output: Output) -> CliResult {
+    let (subject_type, subject_id, permission) = parse_grant(&mut args)?;
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let now = Utc::now().timestamp();
+    query(
+        "INSERT INTO panel_permissions (subject_type, subject_id, permission, granted_by, granted_at)
+         VALUES (?, ?, ?, 'cli', ?)
+         ON CONFLICT(subject_type, subject_id, permission) DO UPDATE SET granted_by = 'cli', granted_at = ?",
+    )
+    .bind(subject_type.as_str())
+    .bind(&subject_id)
+    .bind(permission.as_str())
+    .bind(now)
+    .bind(now)
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Database error: {}", e))?;
+
+    output.print(
+        &serde_json::json!({
+            "granted": permission.as_str(),
+            "subject_type": subject_type.as_str(),
+            "subject_id": subject_id,
+        }),
+        || {
+            println!(
+                "Granted {} to {} {}",
+                permission.as_str(),
+                subject_type.as_str(),
+                subject_id
+            )
+        },
+    );
+    Ok(())
+}
+
+// Role members are only known to Discord, so their sessions run until the permission cache expires.
+async fn revoke(mut args: Args, output: Output) -> CliResult {
+    let (subject_type, subject_id, permission) = parse_grant(&mut args)?;
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let removed = query(
+        "DELETE FROM panel_permissions WHERE subject_type = ? AND subject_id = ? AND permission = ?",
+    )
+    .bind(subject_type.as_str())
+    .bind(&subject_id)
+    .bind(permission.as_str())
+    .execute(&pool)
+    .await
+    .map_err(|e| format!("Database error: {}", e))?
+    .rows_affected();
+    if removed == 0 {
+        return Err(format!(
+            "{} {} does not have {}",
+            subject_type.as_str(),
+            subject_id,
+            permission.as_str()
+        ));
+    }
+
+    let sessions_ended = match subject_type {
+        SubjectType::User => delete_sessions_for_user(&subject_id, &pool)
+            .await
+            .map_err(|e| e.to_string())?,
+        SubjectType::Role => 0,
+    };
+
+    output.print(
+        &serde_json::json!({
+            "revoked": permission.as_str(),
+            "subject_type": subject_type.as_str(),
+            "subject_id": subject_id,
+            "sessions_ended": sessions_ended,
+        }),
+        || {
+            println!(
+                "Revoked {} from {} {}",
+                permission.as_str(),
+                subject_type.as_str(),
+                subject_id
+            );
+            if sessions_ended > 0 {
+                println!("Ended {} panel session(s)", sessions_ended);
+            }
+        },
+    );
+    Ok(())
+}
+
+async fn list_entries(pool: &SqlitePool) -> Result<Vec<PanelPermissionEntry>, String> {
+    let rows = query(
+        "SELECT id, subject_type, subject_id, permission, granted_by, granted_at
+         FROM panel_permissions ORDER BY granted_at DESC",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Database error: {}", e))?;
+
+    Ok(rows
+        .iter()
+        .filter_map(|row| {
+            Some(PanelPermissionEntry {
+                id: row.get("id"),
+                subject_type: SubjectType::from_str(row.get("subject_type"))?,
+                subject_id: row.get("subject_id"),
+                permission: PanelPermission::from_str(row.get("permission"))?,
+                granted_by: row.get("granted_by"),
+                granted_at: row.get("granted_at"),
+            })
+        })
+        .collect())
+}
+
+async fn list(args: Args, output: Output) -> CliResult {
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let entries = list_entries(&pool).await?;
+
+    output.print(&entries, || {
+        if entries.is_empty() {
+            println!("No panel permissions granted");
+            return;
+        }
+        println!(
+            "{:<6} {:<6} {:<20} {:<20} GRANTED BY",
+            "ID", "TYPE", "SUBJECT", "PERMISSION"
+        );
+        for entry in &entries {
+            println!(
+                "{:<6} {:<6} {:<20} {:<20} {}",
+                entry.id,
+                entry.subject_type.as_str(),
+                entry.subject_id,
+                entry.permission.as_str(),
+                entry.granted_by
+            );
+        }
+    });
+    Ok(())
+}
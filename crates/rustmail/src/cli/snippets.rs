@@ -0,0 +1,112 @@
+use crate::cli::{Args, CliResult, Output};
+use crate::prelude::db::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct SnippetEntry {
+    key: String,
+    content: String,
+    #[serde(default)]
+    created_by: Option<String>,
+}
+
+#[derive(Default, Serialize)]
+struct ImportReport {
+    created: Vec<String>,
+    updated: Vec<String>,
+    skipped: Vec<String>,
+}
+
+pub async fn run(mut args: Args, output: Output) -> CliResult {
+    match args
+        .required("snippets subcommand (import, export)")?
+        .as_str()
+    {
+        "import" => import(args, output).await,
+        "export" => export(args, output).await,
+        other => Err(format!("Unknown snippets subcommand: {}", other)),
+    }
+}
+
+async fn export(mut args: Args, output: Output) -> CliResult {
+    let target = args.option("--output")?;
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let entries: Vec<SnippetEntry> = get_all_snippets(&pool)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|s| SnippetEntry {
+            key: s.key,
+            content: s.content,
+            created_by: Some(s.created_by),
+        })
+        .collect();
+    let json = serde_json::to_string_pretty(&entries)
+        .map_err(|e| format!("Failed to serialize snippets: {}", e))?;
+
+    match target {
+        Some(path) => {
+            std::fs::write(&path, json).map_err(|e| format!("Failed to write {}: {}", path, e))?;
+            output.print(
+                &serde_json::json!({ "exported": entries.len(), "path": path }),
+                || println!("Exported {} snippets to {}", entries.len(), path),
+            );
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+async fn import(mut args: Args, output: Output) -> CliResult {
+    let path = args.required("snippets file")?;
+    let overwrite = args.flag("--overwrite");
+    args.finish()?;
+
+    let content =
+        std::fs::read_to_string(&path).map_err(|e| format!("Failed to read {}: {}", path, e))?;
+    let entries: Vec<SnippetEntry> =
+        serde_json::from_str(&content).map_err(|e| format!("Invalid snippets file: {}", e))?;
+
+    let pool = crate::cli::open_database().await?;
+    let mut report = ImportReport::default();
+    for entry in entries {
+        let key = entry.key.trim().to_string();
+        if key.is_empty() || entry.content.is_empty() {
+            report.skipped.push(key);
+            continue;
+        }
+        let exists = get_snippet_by_key(&key, &pool)
+            .await
+            .map_err(|e| e.to_string())?
+            .is_some();
+        if exists && !overwrite {
+            report.skipped.push(key);
+        } else if exists {
+            update_snippet(&key, &entry.content, &pool)
+                .await
+                .map_err(|e| e.to_string())?;
+            report.updated.push(key);
+        } else {
+            let created_by = entry.created_by.unwrap_or_else(|| "cli".to_string());
+            create_snippet(&key, &entry.content, &created_by, &pool)
+                .await
+                .map_err(|e| e.to_string())?;
+            report.created.push(key);
+        }
+    }
+
+    output.print(&report, || {
+        println!(
+            "{} created, {} updated, {} skipped",
+            report.created.len(),
+            report.updated.len(),
+            report.skipped.len()
+        );
+        if !report.skipped.is_empty() && !overwrite {
+            println!("Skipped keys already exist; pass --overwrite to replace them.");
+        }
+    });
+    Ok(())
+}
@@ -0,0 +1,259 @@
+use crate::cli::{Args, CliResult, Output};
+use serde::Serialize;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+
+macro_rules! select_tickets {
+    ($filter:literal) => {
+        concat!(
+            "SELECT id, user_id, user_name, channel_id, status, priority, workflow_state,
+                    category_name, CAST(created_at AS TEXT) AS created_at,
+                    CAST(closed_at AS TEXT) AS closed_at, closed_by
+             FROM threads ",
+            $filter
+        )
+    };
+}
+
+#[derive(Serialize)]
+struct Ticket {
+    id: String,
+    user_id: i64,
+    user_name: String,
+    channel_id: String,
+    open: bool,
+    priority: String,
+    workflow_state: String,
+    category_name: Option<String>,
+    created_at: String,
+    closed_at: Option<String>,
+    closed_by: Option<String>,
+}
+
+#[derive(Serialize)]
+struct TicketMessage {
+    message_number: Option<i64>,
+    user_id: i64,
+    user_name: String,
+    is_anonymous: bool,
+    is_internal: bool,
+    created_at: String,
+    content: String,
+}
+
+#[derive(Serialize)]
+struct TicketTranscript {
+    #[serde(flatten)]
+    ticket: Ticket,
+    messages: Vec<TicketMessage>,
+}
+
+fn row_to_ticket(row: &SqliteRow) -> Ticket {
+    Ticket {
+        id: row.get("id"),
+        user_id: row.get("user_id"),
+        user_name: row.get("user_name"),
+        channel_id: row.get("channel_id"),
+        open: row.get::<i64, _>("status") == 1,
+        priority: row.get("priority"),
+        workflow_state: row.get("workflow_state"),
+        category_name: row.get("category_name"),
+        created_at: row.get("created_at"),
+        closed_at: row.get("closed_at"),
+        closed_by: row.get("closed_by"),
+    }
+}
+
+pub async fn run(mut args: Args, output: Output) -> CliResult {
+    match args
+        .required("tickets subcommand (list, show, export)")?
+        .as_str()
+    {
+        "list" => list(args, output).await,
+        "show" => show(args, output).await,
+        "export" => export(args, output).await,
+        other => Err(format!("Unknown tickets subcommand: {}", other)),
+    }
+}
+
+async fn list(mut args: Args, output: Output) -> CliResult {
+    let status = args
+        .option("--status")?
+        .unwrap_or_else(|| "open".to_string());
+    let status = match status.as_str() {
+        "open" => Some(1),
+        "closed" => Some(0),
+        "all" => None,
+        other => return Err(format!("Unknown status '{}' (open, closed, all)", other)),
+    };
+    let limit: i64 = args
+        .option("--limit")?
+        .map(|l| l.parse())
+        .transpose()
+        .map_err(|_| "--limit must be a number")?
+        .unwrap_or(50);
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let rows = sqlx::query(select_tickets!(
+        "WHERE ?1 IS NULL OR status = ?1 ORDER BY created_at DESC LIMIT ?2"
+    ))
+    .bind(status)
+    .bind(limit)
+    .fetch_all(&pool)
+    .await
+    .map_err(|e| format!("Failed to fetch tickets: {}", e))?;
+    let tickets: Vec<Ticket> = rows.iter().map(row_to_ticket).collect();
+
+    output.print(&tickets, || {
+        if tickets.is_empty() {
+            println!("No tickets");
+            return;
+        }
+        println!(
+            "{:<38} {:<24} {:<8} {:<16} {:<20} CATEGORY",
+            "ID", "USER", "PRIORITY", "STATE", "CREATED"
+        );
+        for ticket in &tickets {
+            println!(
+                "{:<38} {:<24} {:<8} {:<16} {:<20} {}",
+                ticket.id,
+                ticket.user_name,
+                ticket.priority,
+                ticket.workflow_state,
+                ticket.created_at,
+                ticket.category_name.as_deref().unwrap_or("-")
+            );
+        }
+    });
+    Ok(())
+}
+
+async fn load_transcript(id: &str, pool: &SqlitePool) -> Result<TicketTranscript, String> {
+    let row = sqlx::query(select_tickets!("WHERE id = ?1 OR channel_id = ?1"))
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| format!("Failed to fetch ticket: {}", e))?
+        .ok_or_else(|| format!("Ticket {} not found", id))?;
+    let ticket = row_to_ticket(&row);
+
+    let messages = sqlx::query(
+        "SELECT message_number, user_id, user_name, is_anonymous, is_internal,
+                CAST(created_at AS TEXT) AS created_at, content
+         FROM thread_messages WHERE thread_id = ? ORDER BY created_at ASC, id ASC",
+    )
+    .bind(&ticket.id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch messages: {}", e))?
+    .iter()
+    .map(|row| TicketMessage {
+        message_number: row.get("message_number"),
+        user_id: row.get("user_id"),
+        user_name: row.get("user_name"),
+        is_anonymous: row.get("is_anonymous"),
+        is_internal: row.get("is_internal"),
+        created_at: row.get("created_at"),
+        content: row.get("content"),
+    })
+    .collect();
+
+    Ok(TicketTranscript { ticket, messages })
+}
+
+fn print_summary(ticket: &Ticket) {
+    println!("Ticket   {}", ticket.id);
+    println!("User     {} ({})", ticket.user_name, ticket.user_id);
+    println!("Channel  {}", ticket.channel_id);
+    println!(
+        "Status   {} / {}",
+        if ticket.open { "open" } else { "closed" },
+        ticket.workflow_state
+    );
+    println!("Priority {}", ticket.priority);
+    if let Some(category) = &ticket.category_name {
+        println!("Category {}", category);
+    }
+    println!("Created  {}", ticket.created_at);
+    if let Some(closed_at) = &ticket.closed_at {
+        println!(
+            "Closed   {} by {}",
+            closed_at,
+            ticket.closed_by.as_deref().unwrap_or("-")
+        );
+    }
+}
+
+fn render_transcript(transcript: &TicketTranscript) -> String {
+    let mut out = String::new();
+    for message in &transcript.messages {
+        let mut author = message.user_name.clone();
+        if message.is_anonymous {
+            author.push_str(" (anonymous)");
+        }
+        if message.is_internal {
+            author.push_str(" (internal)");
+        }
+        let number = message
+            .message_number
+            .map(|n| format!("#{} ", n))
+            .unwrap_or_default();
+        out.push_str(&format!(
+            "[{}] {}{}: {}\n",
+            message.created_at, number, author, message.content
+        ));
+    }
+    out
+}
+
+async fn show(mut args: Args, output: Output) -> CliResult {
+    let id = args.required("ticket ID")?;
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let transcript = load_transcript(&id, &pool).await?;
+
+    output.print(&transcript, || {
+        print_summary(&transcript.ticket);
+        println!();
+        print!("{}", render_transcript(&transcript));
+    });
+    Ok(())
+}
+
+async fn export(mut args: Args, output: Output) -> CliResult {
+    let id = args.required("ticket ID")?;
+    let target = args.option("--output")?;
+    args.finish()?;
+
+    let pool = crate::cli::open_database().await?;
+    let transcript = load_transcript(&id, &pool).await?;
+
+    let content = match output {
+        Output::Json => serde_json::to_string_pretty(&transcript)
+            .map_err(|e| format!("Failed to serialize ticket: {}", e))?,
+        Output::Human => format!(
+            "Ticket {} - {} ({})\nCreated {}\n\n{}",
+            transcript.ticket.id,
+            transcript.ticket.user_name,
+            transcript.ticket.user_id,
+            transcript.ticket.created_at,
+            render_transcript(&transcript)
+        ),
+    };
+
+    match target {
+        Some(path) => {
+            std::fs::write(&path, content)
+                .map_err(|e| format!("Failed to write {}: {}", path, e))?;
+            eprintln!(
+                "Exported {} messages to {}",
+                transcript.messages.len(),
+                path
+            );
+        }
+        None => println!("{}", content),
+    }
+    Ok(())
+}
@@ -1,15 +1,10 @@
 use crate::prelude::config::*;
 use crate::prelude::db::*;
 use crate::prelude::utils::*;
-use chrono::Local;
 use serenity::all::{ChannelId, CommandInteraction, Context, GuildId, Message, RoleId, UserId};
 use sqlx::SqlitePool;
 use std::collections::{HashMap, HashSet};
-use std::sync::Arc;
-use std::time::Duration;
-use tokio::select;
-use tokio::sync::watch::Receiver;
-use tokio::time::sleep;
+use tracing::error;
 
 pub async fn send_register_confirmation_from_message(
     reminder_id: i64,
@@ -109,122 +104,81 @@ pub async fn send_register_confirmation_from_command(
         .await;
 }
 
-pub fn spawn_reminder(
-    reminder: &Reminder,
-    reminder_id: Option<i64>,
+pub async fn send_reminder(
     ctx: &Context,
     config: &Config,
     pool: &SqlitePool,
-    shutdown: Arc<Receiver<bool>>,
-) {
-    let pool = pool.clone();
-    let config = config.clone();
-    let ctx = ctx.clone();
-    let reminder = reminder.clone();
-    let mut shutdown_rx = shutdown.as_ref().clone();
-
-    tokio::spawn(async move {
-        let now = Local::now().timestamp();
-        let delay_duration = if reminder.trigger_time > now {
-            reminder.trigger_time - now
-        } else {
-            0
-        };
-        select! {
-            _ = sleep(Duration::from_secs(delay_duration as u64)) => {}
-            _ = shutdown_rx.changed() => {
-                return;
-            }
-        }
+    reminder: &Reminder,
+) -> Result<(), String> {
+    let mut params = HashMap::new();
+    params.insert(
+        "time".to_string(),
+        format!("<t:{}:F>", reminder.trigger_time),
+    );
+    params.insert(
+        "remaining_time".to_string(),
+        format!("<t:{}:R>", reminder.trigger_time),
+    );
 
-        if let Some(reminder_id) = reminder_id {
-            match is_reminder_active(reminder_id, &pool).await {
-                Ok(active) => {
-                    if !active {
-                        return;
-                    }
-                }
-                Err(e) => {
-                    eprintln!("Failed to check reminder status: {}", e);
-                    return;
-                }
-            }
-        }
+    params.insert("user".to_string(), reminder.user_id.to_string());
+    params.insert("content".to_string(), reminder.reminder_content.to_string());
 
-        let mut params = HashMap::new();
-        params.insert(
-            "time".to_string(),
-            format!("<t:{}:F>", reminder.trigger_time),
-        );
-        params.insert(
-            "remaining_time".to_string(),
-            format!("<t:{}:R>", reminder.trigger_time),
-        );
+    let (mentions, is_role_targeted) = if let Some(ref target_roles_str) = reminder.target_roles {
+        let role_mentions: String = target_roles_str
+            .split(',')
+            .filter_map(|s| s.trim().parse::<u64>().ok())
+            .map(|id| format!("<@&{}>", id))
+            .collect::<Vec<_>>()
+            .join(", ");
+        params.insert("roles".to_string(), role_mentions);
 
-        params.insert("user".to_string(), reminder.user_id.to_string());
-        params.insert("content".to_string(), reminder.reminder_content.to_string());
+        let members =
+            get_targeted_mentions(ctx, pool, reminder.guild_id as u64, target_roles_str).await;
+        (members, true)
+    } else {
+        (vec![UserId::new(reminder.user_id as u64)], false)
+    };
 
-        let (mentions, is_role_targeted) = if let Some(ref target_roles_str) = reminder.target_roles
-        {
-            let role_mentions: String = target_roles_str
-                .split(',')
-                .filter_map(|s| s.trim().parse::<u64>().ok())
-                .map(|id| format!("<@&{}>", id))
-                .collect::<Vec<_>>()
-                .join(", ");
-            params.insert("roles".to_string(), role_mentions);
+    if mentions.is_empty() {
+        if let Err(e) = update_reminder_status(reminder, true, pool).await {
+            error!("Failed to update reminder status: {}", e);
+        }
+        return Ok(());
+    }
 
-            let members =
-                get_targeted_mentions(&ctx, &pool, reminder.guild_id as u64, target_roles_str)
-                    .await;
-            (members, true)
-        } else {
-            (vec![UserId::new(reminder.user_id as u64)], false)
-        };
+    let (key_with_content, key_without_content) = if is_role_targeted {
+        (
+            "reminder.show_with_content_roles",
+            "reminder.show_without_content_roles",
+        )
+    } else {
+        (
+            "reminder.show_with_content",
+            "reminder.show_without_content",
+        )
+    };
 
-        if mentions.is_empty() {
-            if let Err(e) = update_reminder_status(&reminder, true, &pool).await {
-                eprintln!("Failed to update reminder status: {}", e);
-            }
-            return;
-        }
+    let key = if !reminder.reminder_content.is_empty() {
+        key_with_content
+    } else {
+        key_without_content
+    };
 
-        let (key_with_content, key_without_content) = if is_role_targeted {
-            (
-                "reminder.show_with_content_roles",
-                "reminder.show_without_content_roles",
-            )
-        } else {
-            (
-                "reminder.show_with_content",
-                "reminder.show_without_content",
-            )
-        };
+    MessageBuilder::system_message(ctx, config)
+        .translated_content(key, Some(&params), None, None)
+        .await
+        .to_channel(ChannelId::new(reminder.channel_id as u64))
+        .color(hex_string_to_int(&config.reminders.embed_color) as u32)
+        .mention(mentions)
+        .send(true)
+        .await
+        .map_err(|e| e.to_string())?;
 
-        if !reminder.reminder_content.is_empty() {
-            let _ = MessageBuilder::system_message(&ctx, &config)
-                .translated_content(key_with_content, Some(&params), None, None)
-                .await
-                .to_channel(ChannelId::new(reminder.channel_id as u64))
-                .color(hex_string_to_int(&config.reminders.embed_color) as u32)
-                .mention(mentions)
-                .send(true)
-                .await;
-        } else {
-            let _ = MessageBuilder::system_message(&ctx, &config)
-                .translated_content(key_without_content, Some(&params), None, None)
-                .await
-                .to_channel(ChannelId::new(reminder.channel_id as u64))
-                .color(hex_string_to_int(&config.reminders.embed_color) as u32)
-                .mention(mentions)
-                .send(true)
-                .await;
-        }
+    if let Err(e) = update_reminder_status(reminder, true, pool).await {
+        error!("Failed to update reminder status: {}", e);
+    }
 
-        if let Err(e) = update_reminder_status(&reminder, true, &pool).await {
-            eprintln!("Failed to update reminder status: {}", e);
-        }
-    });
+    Ok(())
 }
 
 async fn get_targeted_mentions(
@@ -247,7 +201,7 @@ async fn get_targeted_mentions(
     let members = match guild_id_obj.members(&ctx.http, None, None).await {
         Ok(m) => m,
         Err(e) => {
-            eprintln!("Failed to fetch guild members: {}", e);
+            error!("Failed to fetch guild members: {}", e);
             return vec![];
         }
     };
@@ -259,7 +213,7 @@ async fn get_targeted_mentions(
                 optouts_by_role.insert(*role_id, optouts.into_iter().map(|id| id as u64).collect());
             }
             Err(e) => {
-                eprintln!("Failed to get optouts for role {}: {}", role_id, e);
+                error!("Failed to get optouts for role {}: {}", role_id, e);
                 optouts_by_role.insert(*role_id, HashSet::new());
             }
         }
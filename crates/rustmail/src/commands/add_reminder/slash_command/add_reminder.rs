@@ -13,6 +13,7 @@ use serenity::all::{
     CreateCommandOption, GuildId, ResolvedOption, RoleId,
 };
 use std::sync::Arc;
+use tracing::error;
 
 pub struct AddReminderCommand;
 
@@ -99,7 +100,7 @@ impl RegistrableCommand for AddReminderCommand {
         command: &CommandInteraction,
         _options: &[ResolvedOption<'_>],
         config: &Config,
-        handler: Arc<InteractionHandler>,
+        _handler: Arc<InteractionHandler>,
     ) -> BoxFuture<'_, ModmailResult<()>> {
         let ctx = ctx.clone();
         let command = command.clone();
@@ -211,7 +212,7 @@ impl RegistrableCommand for AddReminderCommand {
             let reminder_id = match insert_reminder(&reminder, pool).await {
                 Ok(id) => id,
                 Err(e) => {
-                    eprintln!("Failed to insert reminder: {}", e);
+                    error!("Failed to insert reminder: {}", e);
                     return Err(e);
                 }
             };
@@ -227,15 +228,6 @@ impl RegistrableCommand for AddReminderCommand {
             )
             .await;
 
-            spawn_reminder(
-                &reminder,
-                Some(reminder_id),
-                &ctx,
-                &config,
-                pool,
-                handler.shutdown.clone(),
-            );
-
             Ok(())
         })
     }
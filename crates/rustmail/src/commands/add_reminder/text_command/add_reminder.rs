@@ -10,12 +10,13 @@ use serenity::all::{Context, GuildId, Message, RoleId};
 use sqlx::SqlitePool;
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::error;
 
 pub async fn add_reminder(
     ctx: Context,
     msg: Message,
     config: &Config,
-    handler: Arc<GuildMessagesHandler>,
+    _handler: Arc<GuildMessagesHandler>,
 ) -> ModmailResult<()> {
     let pool = config
         .db_pool
@@ -102,7 +103,7 @@ pub async fn add_reminder(
     let reminder_id = match insert_reminder(&reminder, pool).await {
         Ok(id) => id,
         Err(e) => {
-            eprintln!("Failed to insert reminder: {}", e);
+            error!("Failed to insert reminder: {}", e);
             return Err(e);
         }
     };
@@ -120,15 +121,6 @@ pub async fn add_reminder(
 
     let _ = msg.delete(&ctx.http).await;
 
-    spawn_reminder(
-        &reminder,
-        Some(reminder_id),
-        &ctx,
-        config,
-        pool,
-        handler.shutdown.clone(),
-    );
-
     Ok(())
 }
 
@@ -83,6 +83,13 @@ pub async fn anonreply(
     ticket_status.last_message_by = TicketAuthor::Staff;
     ticket_status.last_message_at = Utc::now().timestamp();
     update_thread_status_db(&thread.id, &ticket_status, db_pool).await?;
+    record_message_workflow(
+        &thread.id,
+        TicketAuthor::Staff,
+        Some(&msg.author.id.to_string()),
+        db_pool,
+    )
+    .await?;
 
     let _ = msg.delete(&ctx.http).await;
 
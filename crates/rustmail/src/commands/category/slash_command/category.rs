@@ -7,8 +7,8 @@ use crate::prelude::i18n::*;
 use crate::prelude::utils::*;
 use serenity::FutureExt;
 use serenity::all::{
-    CommandDataOption, CommandDataOptionValue, CommandInteraction, CommandOptionType, Context,
-    CreateCommand, CreateCommandOption, ResolvedOption,
+    AutocompleteChoice, CommandDataOption, CommandDataOptionValue, CommandInteraction,
+    CommandOptionType, Context, CreateCommand, CreateCommandOption, ResolvedOption,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -43,7 +43,8 @@ impl RegistrableCommand for CategoryCommand {
                                 "discord_category_id",
                                 "Discord category channel ID",
                             )
-                            .required(true),
+                            .required(true)
+                            .set_autocomplete(true),
                         )
                         .add_sub_option(
                             CreateCommandOption::new(
@@ -81,7 +82,8 @@ impl RegistrableCommand for CategoryCommand {
                                 "name",
                                 "Category name",
                             )
-                            .required(true),
+                            .required(true)
+                            .set_autocomplete(true),
                         ),
                     )
                     .add_option(
@@ -96,7 +98,8 @@ impl RegistrableCommand for CategoryCommand {
                                 "old_name",
                                 "Current name",
                             )
-                            .required(true),
+                            .required(true)
+                            .set_autocomplete(true),
                         )
                         .add_sub_option(
                             CreateCommandOption::new(
@@ -119,7 +122,8 @@ impl RegistrableCommand for CategoryCommand {
                                 "name",
                                 "Category name",
                             )
-                            .required(true),
+                            .required(true)
+                            .set_autocomplete(true),
                         )
                         .add_sub_option(
                             CreateCommandOption::new(
@@ -142,7 +146,8 @@ impl RegistrableCommand for CategoryCommand {
                                 "name",
                                 "Category name",
                             )
-                            .required(true),
+                            .required(true)
+                            .set_autocomplete(true),
                         ),
                     )
                     .add_option(
@@ -157,7 +162,8 @@ impl RegistrableCommand for CategoryCommand {
                                 "name",
                                 "Category name",
                             )
-                            .required(true),
+                            .required(true)
+                            .set_autocomplete(true),
                         ),
                     )
                     .add_option(
@@ -203,7 +209,8 @@ impl RegistrableCommand for CategoryCommand {
                                     "name",
                                     "Category name",
                                 )
-                                .required(true),
+                                .required(true)
+                                .set_autocomplete(true),
                             )
                             .add_sub_option(
                                 CreateCommandOption::new(
@@ -226,7 +233,8 @@ impl RegistrableCommand for CategoryCommand {
                                     "name",
                                     "Category name",
                                 )
-                                .required(true),
+                                .required(true)
+                                .set_autocomplete(true),
                             )
                             .add_sub_option(
                                 CreateCommandOption::new(
@@ -249,7 +257,8 @@ impl RegistrableCommand for CategoryCommand {
                                     "name",
                                     "Category name",
                                 )
-                                .required(true),
+                                .required(true)
+                                .set_autocomplete(true),
                             ),
                         )
                         .add_sub_option(
@@ -264,7 +273,8 @@ impl RegistrableCommand for CategoryCommand {
                                     "name",
                                     "Category name",
                                 )
-                                .required(true),
+                                .required(true)
+                                .set_autocomplete(true),
                             ),
                         ),
                     ),
@@ -318,6 +328,45 @@ impl RegistrableCommand for CategoryCommand {
             }
         })
     }
+
+    fn autocomplete<'a>(
+        &'a self,
+        ctx: &'a Context,
+        command: &'a CommandInteraction,
+        config: &'a Config,
+    ) -> BoxFuture<'a, Vec<AutocompleteChoice>> {
+        Box::pin(async move {
+            let (Some(pool), Some(focused)) =
+                (config.db_pool.as_ref(), command.data.autocomplete())
+            else {
+                return Vec::new();
+            };
+
+            if focused.name == "discord_category_id" {
+                let categories = fetch_server_categories(ctx, config).await;
+                return filter_choices(
+                    focused.value,
+                    categories
+                        .into_iter()
+                        .map(|(id, name)| (format!("{} ({})", name, id), id.to_string())),
+                );
+            }
+
+            let subcommand = command.data.options.first().map(|o| o.name.as_str());
+            let categories = list_all_categories(pool).await.unwrap_or_default();
+            filter_choices(
+                focused.value,
+                categories
+                    .into_iter()
+                    .filter(|c| match subcommand {
+                        Some("enable") => !c.enabled,
+                        Some("disable") => c.enabled,
+                        _ => true,
+                    })
+                    .map(|c| (c.name.clone(), c.name)),
+            )
+        })
+    }
 }
 
 fn get_string(opts: &[CommandDataOption], key: &str) -> Option<String> {
@@ -400,7 +449,7 @@ async fn sub_create(
         .await;
     }
 
-    let enabled = count_enabled_categories(pool).await?;
+    let enabled = count_enabled_categories(None, pool).await?;
     if enabled as usize >= CATEGORY_BUTTON_HARD_LIMIT {
         let mut params = HashMap::new();
         params.insert("max".to_string(), CATEGORY_BUTTON_HARD_LIMIT.to_string());
@@ -423,6 +472,7 @@ async fn sub_create(
         description.as_deref(),
         emoji.as_deref(),
         &discord_id,
+        None,
         pool,
     )
     .await?;
@@ -587,7 +637,7 @@ async fn sub_enable_one(
         None => return reply(ctx, command, config, "category.not_found", None).await,
     };
     if enable && !cat.enabled {
-        let enabled_count = count_enabled_categories(pool).await?;
+        let enabled_count = count_enabled_categories(cat.parent_id.as_deref(), pool).await?;
         if enabled_count as usize >= CATEGORY_BUTTON_HARD_LIMIT {
             let mut params = HashMap::new();
             params.insert("max".to_string(), CATEGORY_BUTTON_HARD_LIMIT.to_string());
@@ -624,7 +674,7 @@ async fn sub_timeout(
         _ => return reply(ctx, command, config, "category.text_usage", None).await,
     };
     let settings = get_category_settings(pool).await?;
-    update_category_settings(settings.enabled, secs, pool).await?;
+    update_category_settings(settings.enabled, secs, settings.selection_mode, pool).await?;
     let mut params = HashMap::new();
     params.insert("seconds".to_string(), secs.to_string());
     reply(
@@ -645,7 +695,13 @@ async fn sub_feature(
     enable: bool,
 ) -> ModmailResult<()> {
     let settings = get_category_settings(pool).await?;
-    update_category_settings(enable, settings.selection_timeout_s, pool).await?;
+    update_category_settings(
+        enable,
+        settings.selection_timeout_s,
+        settings.selection_mode,
+        pool,
+    )
+    .await?;
     let key = if enable {
         "category.feature_enabled"
     } else {
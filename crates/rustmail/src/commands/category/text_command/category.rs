@@ -108,8 +108,8 @@ async fn handle_create(
         .map(|s| s.to_string())
         .filter(|s| !s.is_empty());
 
-    let enabled = list_enabled_categories(pool).await?;
-    if enabled.len() >= CATEGORY_BUTTON_HARD_LIMIT {
+    let enabled = count_enabled_categories(None, pool).await?;
+    if enabled as usize >= CATEGORY_BUTTON_HARD_LIMIT {
         let mut params = HashMap::new();
         params.insert("max".to_string(), CATEGORY_BUTTON_HARD_LIMIT.to_string());
         return send_translated(ctx, config, msg, "category.too_many_enabled", Some(&params)).await;
@@ -124,6 +124,7 @@ async fn handle_create(
         description.as_deref(),
         emoji.as_deref(),
         discord_id_raw,
+        None,
         pool,
     )
     .await?;
@@ -285,7 +286,7 @@ async fn handle_enable_one(
         None => return send_translated(ctx, config, msg, "category.not_found", None).await,
     };
     if enable && !cat.enabled {
-        let enabled_count = count_enabled_categories(pool).await?;
+        let enabled_count = count_enabled_categories(cat.parent_id.as_deref(), pool).await?;
         if enabled_count as usize >= CATEGORY_BUTTON_HARD_LIMIT {
             let mut params = HashMap::new();
             params.insert("max".to_string(), CATEGORY_BUTTON_HARD_LIMIT.to_string());
@@ -316,7 +317,7 @@ async fn handle_timeout(
         _ => return send_translated(ctx, config, msg, "category.text_usage", None).await,
     };
     let settings = get_category_settings(pool).await?;
-    update_category_settings(settings.enabled, secs, pool).await?;
+    update_category_settings(settings.enabled, secs, settings.selection_mode, pool).await?;
     let mut params = HashMap::new();
     params.insert("seconds".to_string(), secs.to_string());
     send_translated(ctx, config, msg, "category.timeout_updated", Some(&params)).await
@@ -330,7 +331,13 @@ async fn handle_feature_toggle(
     enable: bool,
 ) -> ModmailResult<()> {
     let settings = get_category_settings(pool).await?;
-    update_category_settings(enable, settings.selection_timeout_s, pool).await?;
+    update_category_settings(
+        enable,
+        settings.selection_timeout_s,
+        settings.selection_mode,
+        pool,
+    )
+    .await?;
     let key = if enable {
         "category.feature_enabled"
     } else {
@@ -15,6 +15,7 @@ use serenity::all::{
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+use tracing::error;
 
 pub struct CloseCommand;
 
@@ -297,10 +298,8 @@ impl RegistrableCommand for CloseCommand {
                 )
                 .await
                 {
-                    eprintln!("Failed to persist scheduled closure: {e:?}");
+                    error!("Failed to persist scheduled closure: {e:?}");
                 }
-
-                schedule_one(&ctx, &config, thread_id, close_at);
                 return Ok(());
             }
 
@@ -317,8 +316,10 @@ impl RegistrableCommand for CloseCommand {
                 get_required_permissions_channel_from_command(&ctx, &command).await;
 
             if user_still_member && !silent {
-                let _ = MessageBuilder::system_message(&ctx, &config)
-                    .content(&config.bot.close_message)
+                let overrides = category_messages_for_thread(&config, &thread.id, user_id).await;
+                let _ = overrides
+                    .apply_color(MessageBuilder::system_message(&ctx, &config))
+                    .content(overrides.close_message(&config, user_id))
                     .to_user(user_id)
                     .send(true)
                     .await;
@@ -10,6 +10,7 @@ use serenity::all::{Channel, Context, GuildId, Message, PermissionOverwriteType,
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
+use tracing::error;
 
 pub async fn close(
     ctx: Context,
@@ -238,10 +239,8 @@ pub async fn close(
         )
         .await
         {
-            eprintln!("Failed to persist scheduled closure: {e:?}");
+            error!("Failed to persist scheduled closure: {e:?}");
         }
-
-        schedule_one(&ctx, config, thread_id, close_at);
         return Ok(());
     }
 
@@ -257,8 +256,10 @@ pub async fn close(
     let required_permissions = get_required_permissions_channel_from_message(&ctx, &msg).await;
 
     if user_still_member && !silent {
-        let _ = MessageBuilder::system_message(&ctx, config)
-            .content(&config.bot.close_message)
+        let overrides = category_messages_for_thread(config, &thread.id, user_id).await;
+        let _ = overrides
+            .apply_color(MessageBuilder::system_message(&ctx, config))
+            .content(overrides.close_message(config, user_id))
             .to_user(user_id)
             .send(true)
             .await;
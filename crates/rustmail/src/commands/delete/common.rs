@@ -4,6 +4,7 @@ use crate::prelude::errors::*;
 use crate::prelude::utils::*;
 use serenity::all::{ChannelId, Context, Message, MessageId, UserId};
 use std::collections::HashMap;
+use tracing::error;
 
 pub async fn get_thread_info(
     channel_id: &str,
@@ -72,7 +73,7 @@ pub async fn delete_inbox_message(
             .delete_message(&ctx.http, MessageId::new(msg_id))
             .await
     {
-        eprintln!("Failed to delete inbox message: {}", e);
+        error!("Failed to delete inbox message: {}", e);
         return Err(ModmailError::Command(CommandError::DiscordDeleteFailed));
     }
     Ok(())
@@ -87,18 +88,18 @@ pub async fn delete_dm_message(ctx: &Context, user_id: i64, message_ids: &Messag
                 match dm_channel.message(&ctx.http, msg_id).await {
                     Ok(dm_message) => {
                         if let Err(e) = dm_message.delete(&ctx.http).await {
-                            eprintln!("Failed to delete DM message: {}", e);
+                            error!("Failed to delete DM message: {}", e);
                         }
                     }
                     Err(e) => {
-                        eprintln!("Failed to fetch DM message for deletion: {}", e);
+                        error!("Failed to fetch DM message for deletion: {}", e);
                     }
                 }
             } else {
-                eprintln!("Failed to create DM channel for deletion");
+                error!("Failed to create DM channel for deletion");
             }
         } else {
-            eprintln!("Failed to get user for DM deletion");
+            error!("Failed to get user for DM deletion");
         }
     }
 }
@@ -110,7 +111,7 @@ pub async fn delete_database_message(
     if let Some(dm_msg_id) = &message_ids.dm_message_id
         && let Err(e) = delete_message(dm_msg_id, pool).await
     {
-        eprintln!("Failed to delete message from database: {}", e);
+        error!("Failed to delete message from database: {}", e);
         return Err(database_connection_failed());
     }
     Ok(())
@@ -122,7 +123,7 @@ pub async fn update_message_numbers(
     pool: &sqlx::SqlitePool,
 ) {
     if let Err(e) = update_message_numbers_after_deletion(channel_id, message_number, pool).await {
-        eprintln!("Failed to update message numbers: {}", e);
+        error!("Failed to update message numbers: {}", e);
     }
 }
 
@@ -55,9 +55,15 @@ mod tests {
                 create_ticket_by_create_channel: false,
                 close_on_leave: false,
                 auto_archive_duration: 0,
+                resolved_close_delay: 0,
+                inactivity_close_delay: 0,
+                response_sla: 0,
+                board_channel_id: None,
             },
             notifications: NotificationsConfig::default(),
             logs: LogsConfig::default(),
+            logging: LoggingConfig::default(),
+            backups: BackupConfig::default(),
             language: LanguageConfig::default(),
             reminders: ReminderConfig::default(),
             error_handling: ErrorHandlingConfig::default(),
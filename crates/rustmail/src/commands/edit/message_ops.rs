@@ -5,6 +5,7 @@ use crate::prelude::utils::*;
 use serenity::all::{
     ChannelId, CommandInteraction, Context, EditMessage, GuildId, Message, MessageId, User, UserId,
 };
+use tracing::error;
 
 use sqlx::SqlitePool;
 
@@ -218,6 +219,6 @@ pub async fn edit_messages<'a>(
 
 pub async fn cleanup_command_message(ctx: &Context, msg: &Message) {
     if let Err(e) = msg.delete(&ctx.http).await {
-        eprintln!("Failed to delete command message: {:?}", e);
+        error!("Failed to delete command message: {:?}", e);
     }
 }
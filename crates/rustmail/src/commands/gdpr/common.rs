@@ -0,0 +1,108 @@
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
+use serenity::all::{CreateActionRow, CreateAttachment};
+use std::collections::HashMap;
+
+pub enum GdprRequest {
+    Export,
+    Erase(ErasureMode),
+}
+
+impl GdprRequest {
+    pub fn parse(action: &str, mode: Option<&str>) -> Option<Self> {
+        match action {
+            "export" => Some(GdprRequest::Export),
+            "erase" => match mode {
+                None => Some(GdprRequest::Erase(ErasureMode::Anonymize)),
+                Some(mode) => ErasureMode::parse(mode).map(GdprRequest::Erase),
+            },
+            _ => None,
+        }
+    }
+}
+
+pub struct GdprReply {
+    pub content: String,
+    pub attachment: Option<CreateAttachment>,
+    pub components: Vec<CreateActionRow>,
+}
+
+impl GdprReply {
+    fn text(content: String) -> Self {
+        Self {
+            content,
+            attachment: None,
+            components: Vec::new(),
+        }
+    }
+}
+
+pub async fn gdpr_reply(
+    config: &Config,
+    requester: &str,
+    user_id: &str,
+    request: GdprRequest,
+) -> ModmailResult<GdprReply> {
+    let pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+    let mut params = HashMap::new();
+    params.insert("user_id".to_string(), user_id.to_string());
+
+    match request {
+        GdprRequest::Export => {
+            let bytes = export_user_data(user_id, "command", requester, pool).await?;
+            let content = get_translated_message(
+                config,
+                "gdpr.export_ready",
+                Some(&params),
+                None,
+                None,
+                None,
+            )
+            .await;
+            Ok(GdprReply {
+                content,
+                attachment: Some(CreateAttachment::bytes(
+                    bytes,
+                    data_export_file_name(user_id),
+                )),
+                components: Vec::new(),
+            })
+        }
+        GdprRequest::Erase(mode) => {
+            if user_has_open_thread(user_id, pool).await? {
+                let content = get_translated_message(
+                    config,
+                    "gdpr.open_ticket",
+                    Some(&params),
+                    None,
+                    None,
+                    None,
+                )
+                .await;
+                return Ok(GdprReply::text(content));
+            }
+
+            let counts = count_user_data(&collect_user_data(user_id, pool).await?);
+            if counts.is_empty() {
+                let content =
+                    get_translated_message(config, "gdpr.no_data", Some(&params), None, None, None)
+                        .await;
+                return Ok(GdprReply::text(content));
+            }
+
+            let (content, components) =
+                erasure_prompt(config, user_id, mode, requester, &counts).await;
+            Ok(GdprReply {
+                content,
+                attachment: None,
+                components,
+            })
+        }
+    }
+}
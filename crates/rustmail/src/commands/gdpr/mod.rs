@@ -0,0 +1,6 @@
+pub mod common;
+pub mod slash_command;
+pub mod text_command;
+
+pub use slash_command::*;
+pub use text_command::*;
@@ -0,0 +1,149 @@
+use crate::commands::gdpr::common::{GdprRequest, gdpr_reply};
+use crate::prelude::commands::*;
+use crate::prelude::config::*;
+use crate::prelude::errors::*;
+use crate::prelude::handlers::*;
+use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
+use crate::prelude::utils::*;
+use serenity::FutureExt;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, Context, CreateCommand,
+    CreateCommandOption, ResolvedOption,
+};
+use std::sync::Arc;
+
+pub struct GdprCommand;
+
+#[async_trait::async_trait]
+impl RegistrableCommand for GdprCommand {
+    fn name(&self) -> &'static str {
+        "gdpr"
+    }
+
+    fn doc<'a>(&self, config: &'a Config) -> BoxFuture<'a, String> {
+        async move { get_translated_message(config, "help.gdpr", None, None, None, None).await }
+            .boxed()
+    }
+
+    fn register(&self, config: &Config) -> BoxFuture<'_, Vec<CreateCommand>> {
+        let config = config.clone();
+
+        Box::pin(async move {
+            let cmd_desc = get_translated_message(
+                &config,
+                "slash_command.gdpr_command_description",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+            let action_desc = get_translated_message(
+                &config,
+                "slash_command.gdpr_action_option",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+            let user_desc = get_translated_message(
+                &config,
+                "slash_command.gdpr_user_option",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+            let mode_desc = get_translated_message(
+                &config,
+                "slash_command.gdpr_mode_option",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+            vec![
+                CreateCommand::new(self.name())
+                    .description(cmd_desc)
+                    .add_option(
+                        CreateCommandOption::new(CommandOptionType::String, "action", action_desc)
+                            .add_string_choice("export", "export")
+                            .add_string_choice("erase", "erase")
+                            .required(true),
+                    )
+                    .add_option(
+                        CreateCommandOption::new(CommandOptionType::User, "user", user_desc)
+                            .required(true),
+                    )
+                    .add_option(
+                        CreateCommandOption::new(CommandOptionType::String, "mode", mode_desc)
+                            .add_string_choice("anonymize", "anonymize")
+                            .add_string_choice("delete", "delete")
+                            .required(false),
+                    ),
+            ]
+        })
+    }
+
+    fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _options: &[ResolvedOption<'_>],
+        config: &Config,
+        _handler: Arc<InteractionHandler>,
+    ) -> BoxFuture<'_, ModmailResult<()>> {
+        let ctx = ctx.clone();
+        let command = command.clone();
+        let config = config.clone();
+
+        Box::pin(async move {
+            defer_response_ephemeral(&ctx, &command).await?;
+
+            let is_admin = match command.guild_id {
+                Some(guild_id) => is_bot_admin(&ctx, &config, guild_id, command.user.id).await,
+                None => false,
+            };
+            if !is_admin {
+                return Err(permission_denied());
+            }
+
+            let mut action = None;
+            let mut user_id = None;
+            let mut mode = None;
+            for option in &command.data.options {
+                match (option.name.as_str(), &option.value) {
+                    ("action", CommandDataOptionValue::String(val)) => action = Some(val.clone()),
+                    ("user", CommandDataOptionValue::User(id)) => user_id = Some(id.to_string()),
+                    ("mode", CommandDataOptionValue::String(val)) => mode = Some(val.clone()),
+                    _ => {}
+                }
+            }
+
+            let (Some(action), Some(user_id)) = (action, user_id) else {
+                return Err(ModmailError::Command(CommandError::MissingArguments));
+            };
+            let request =
+                GdprRequest::parse(&action, mode.as_deref()).ok_or_else(invalid_command)?;
+
+            let reply =
+                gdpr_reply(&config, &command.user.id.to_string(), &user_id, request).await?;
+
+            let mut builder = MessageBuilder::system_message(&ctx, &config)
+                .content(reply.content)
+                .components(reply.components)
+                .ephemeral(true);
+            if let Some(attachment) = reply.attachment {
+                builder = builder.add_attachments(vec![attachment]);
+            }
+            builder.send_interaction_followup(&command, false).await?;
+
+            Ok(())
+        })
+    }
+}
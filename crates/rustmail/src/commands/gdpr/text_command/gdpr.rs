@@ -0,0 +1,42 @@
+use crate::commands::gdpr::common::{GdprRequest, gdpr_reply};
+use crate::prelude::config::*;
+use crate::prelude::errors::*;
+use crate::prelude::handlers::*;
+use crate::prelude::modules::*;
+use crate::prelude::utils::*;
+use serenity::all::{Context, Message};
+use std::sync::Arc;
+
+pub async fn gdpr(
+    ctx: Context,
+    msg: Message,
+    config: &Config,
+    _handler: Arc<GuildMessagesHandler>,
+) -> ModmailResult<()> {
+    if !is_message_author_admin(&ctx, config, &msg).await {
+        return Err(permission_denied());
+    }
+
+    let content =
+        extract_reply_content(&msg.content, &config.command.prefix, &["gdpr"]).unwrap_or_default();
+    let mut args = content.split_whitespace();
+    let (Some(action), Some(user)) = (args.next(), args.next()) else {
+        return Err(ModmailError::Command(CommandError::MissingArguments));
+    };
+    let request =
+        GdprRequest::parse(&action.to_lowercase(), args.next()).ok_or_else(invalid_command)?;
+    let user_id = parse_data_subject(user).ok_or_else(invalid_command)?;
+
+    let reply = gdpr_reply(config, &msg.author.id.to_string(), &user_id, request).await?;
+
+    let mut builder = MessageBuilder::system_message(&ctx, config)
+        .content(reply.content)
+        .components(reply.components)
+        .to_channel(msg.channel_id);
+    if let Some(attachment) = reply.attachment {
+        builder = builder.add_attachments(vec![attachment]);
+    }
+    builder.send(false).await?;
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod gdpr;
+
+pub use gdpr::*;
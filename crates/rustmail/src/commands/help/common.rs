@@ -5,6 +5,7 @@ use crate::prelude::i18n::*;
 use crate::prelude::utils::*;
 use serenity::all::{CommandInteraction, Context, Message};
 use std::sync::Arc;
+use tracing::warn;
 
 pub async fn display_commands_list(
     ctx: &Context,
@@ -42,7 +43,7 @@ pub async fn display_commands_list(
         return Ok(());
     }
 
-    println!("No valid message or command interaction provided.");
+    warn!("No valid message or command interaction provided.");
     Ok(())
 }
 
@@ -80,7 +81,7 @@ pub async fn display_command_help(
             return Ok(());
         }
 
-        println!("No valid message or command interaction provided.");
+        warn!("No valid message or command interaction provided.");
         Ok(())
     } else {
         Err(ModmailError::Command(CommandError::UnknownCommand(
@@ -0,0 +1,87 @@
+use crate::modules::{hold_ticket, resume_ticket, workflow_state_label};
+use crate::prelude::commands::*;
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::handlers::*;
+use crate::prelude::utils::*;
+use chrono::Utc;
+use serenity::all::{Context, Message};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub async fn hold(
+    ctx: Context,
+    msg: Message,
+    config: &Config,
+    _handler: Arc<GuildMessagesHandler>,
+) -> ModmailResult<()> {
+    let db_pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+
+    if !is_a_ticket_channel(msg.channel_id, db_pool).await {
+        return Err(ModmailError::Thread(ThreadError::NotAThreadChannel));
+    }
+
+    let thread = match get_thread_by_channel_id(&msg.channel_id.to_string(), db_pool).await {
+        Some(thread) => thread,
+        None => return Err(thread_not_found()),
+    };
+
+    let arg =
+        extract_reply_content(&msg.content, &config.command.prefix, &["hold"]).unwrap_or_default();
+    let arg = arg.trim();
+
+    if arg.eq_ignore_ascii_case("cancel") {
+        let current = get_thread_workflow(&thread.id, db_pool)
+            .await?
+            .map(|(s, _)| s);
+        if current != Some(TicketWorkflowState::OnHold) {
+            return Err(ModmailError::Command(CommandError::TicketNotOnHold));
+        }
+
+        let state = resume_ticket(&ctx, config, &thread, Some(msg.author.id)).await?;
+        let mut params = HashMap::new();
+        params.insert(
+            "state".to_string(),
+            workflow_state_label(config, state, msg.author.id, msg.guild_id).await,
+        );
+        let _ = MessageBuilder::system_message(&ctx, config)
+            .translated_content("hold.canceled", Some(&params), None, None)
+            .await
+            .to_channel(msg.channel_id)
+            .send(true)
+            .await;
+        return Ok(());
+    }
+
+    let duration = if arg.is_empty() {
+        None
+    } else {
+        Some(parse_duration_spec(arg).ok_or(ModmailError::Command(
+            CommandError::InvalidArguments(arg.to_string()),
+        ))?)
+    };
+    let until = duration.map(|d| Utc::now().timestamp() + d.as_secs() as i64);
+
+    hold_ticket(&ctx, config, &thread, until, msg.author.id).await?;
+
+    let (key, params) = match duration {
+        Some(d) => {
+            let mut params = HashMap::new();
+            params.insert("time".to_string(), format_duration(d.as_secs()));
+            ("hold.confirmation", Some(params))
+        }
+        None => ("hold.confirmation_indefinite", None),
+    };
+    let _ = MessageBuilder::system_message(&ctx, config)
+        .translated_content(key, params.as_ref(), None, None)
+        .await
+        .to_channel(msg.channel_id)
+        .send(true)
+        .await;
+
+    Ok(())
+}
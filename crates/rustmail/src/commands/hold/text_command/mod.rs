@@ -0,0 +1,3 @@
+pub mod hold;
+
+pub use hold::*;
@@ -10,6 +10,7 @@ use crate::prelude::types::*;
 use serenity::all::{ButtonStyle, ChannelId, CommandInteraction, Context, Message};
 use sqlx::SqlitePool;
 use std::sync::Arc;
+use tracing::error;
 use uuid::Uuid;
 
 pub async fn handle_logs_in_thread(
@@ -49,7 +50,7 @@ pub async fn handle_logs_from_user_id(
     let logs = match get_logs_from_user_id(user_id, pool).await {
         Ok(logs) => logs,
         Err(e) => {
-            eprintln!("Error retrieving logs for user ID {}: {:?}", user_id, e);
+            error!("Error retrieving logs for user ID {}: {:?}", user_id, e);
             return Err(ModmailError::Database(DatabaseError::QueryFailed(
                 "Failed to retrieve logs.".to_string(),
             )));
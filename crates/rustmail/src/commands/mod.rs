@@ -2,7 +2,9 @@ use crate::prelude::config::*;
 use crate::prelude::errors::*;
 use crate::prelude::handlers::*;
 use crate::prelude::types::*;
-use serenity::all::{CommandInteraction, Context, CreateCommand, ResolvedOption};
+use serenity::all::{
+    AutocompleteChoice, CommandInteraction, Context, CreateCommand, ResolvedOption,
+};
 use std::any::Any;
 use std::collections::HashMap;
 use std::pin::Pin;
@@ -19,12 +21,15 @@ pub mod close;
 pub mod delete;
 pub mod edit;
 pub mod force_close;
+pub mod gdpr;
 pub mod help;
+pub mod hold;
 pub mod id;
 pub mod logs;
 pub mod move_thread;
 pub mod new_thread;
 pub mod ping;
+pub mod priority;
 pub mod recover;
 pub mod release;
 pub mod reminder_subscription;
@@ -32,6 +37,7 @@ pub mod remove_reminder;
 pub mod remove_staff;
 pub mod rename;
 pub mod reply;
+pub mod resolve;
 pub mod snippet;
 pub mod status;
 pub mod take;
@@ -46,12 +52,15 @@ pub use close::*;
 pub use delete::*;
 pub use edit::*;
 pub use force_close::*;
+pub use gdpr::*;
 pub use help::*;
+pub use hold::*;
 pub use id::*;
 pub use logs::*;
 pub use move_thread::*;
 pub use new_thread::*;
 pub use ping::*;
+pub use priority::*;
 pub use recover::*;
 pub use release::*;
 pub use reminder_subscription::*;
@@ -59,6 +68,7 @@ pub use remove_reminder::*;
 pub use remove_staff::*;
 pub use rename::*;
 pub use reply::*;
+pub use resolve::*;
 pub use snippet::*;
 pub use status::*;
 pub use take::*;
@@ -84,6 +94,15 @@ pub trait RegistrableCommand: Any + Send + Sync {
         config: &Config,
         handler: Arc<InteractionHandler>,
     ) -> BoxFuture<'_, ModmailResult<()>>;
+
+    fn autocomplete<'a>(
+        &'a self,
+        _ctx: &'a Context,
+        _command: &'a CommandInteraction,
+        _config: &'a Config,
+    ) -> BoxFuture<'a, Vec<AutocompleteChoice>> {
+        Box::pin(async { Vec::new() })
+    }
 }
 
 pub trait CommunityRegistrable: RegistrableCommand {
@@ -92,6 +111,7 @@ pub trait CommunityRegistrable: RegistrableCommand {
 
 pub struct CommandRegistry {
     commands: HashMap<&'static str, Arc<dyn RegistrableCommand>>,
+    feature_commands: HashMap<&'static str, &'static str>,
     _shutdown: Arc<Receiver<bool>>,
     _pagination: PaginationStore,
 }
@@ -100,6 +120,7 @@ impl CommandRegistry {
     pub fn new(shutdown: Receiver<bool>, pagination: PaginationStore) -> Self {
         Self {
             commands: HashMap::new(),
+            feature_commands: HashMap::new(),
             _shutdown: Arc::new(shutdown),
             _pagination: pagination,
         }
@@ -113,6 +134,19 @@ impl CommandRegistry {
         self.commands.insert(command.name(), Arc::new(command));
     }
 
+    pub fn register_feature_command(
+        &mut self,
+        feature_key: &'static str,
+        command: Arc<dyn RegistrableCommand>,
+    ) {
+        self.feature_commands.insert(command.name(), feature_key);
+        self.commands.insert(command.name(), command);
+    }
+
+    pub fn feature_of(&self, name: &str) -> Option<&'static str> {
+        self.feature_commands.get(name).copied()
+    }
+
     pub fn get(&self, name: &str) -> Option<Arc<dyn RegistrableCommand>> {
         self.commands.get(name).cloned()
     }
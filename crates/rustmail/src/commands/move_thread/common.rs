@@ -33,6 +33,23 @@ pub async fn extract_category_name(msg: &Message, config: &Config) -> String {
 pub async fn fetch_server_categories(ctx: &Context, config: &Config) -> Vec<(ChannelId, String)> {
     let staff_guild_id = GuildId::new(config.bot.get_staff_guild_id());
 
+    // Autocomplete calls this on every keystroke, so prefer the cache.
+    let cached: Vec<(ChannelId, String)> = ctx
+        .cache
+        .guild(staff_guild_id)
+        .map(|guild| {
+            guild
+                .channels
+                .values()
+                .filter(|channel| channel.kind == serenity::model::channel::ChannelType::Category)
+                .map(|channel| (channel.id, channel.name.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    if !cached.is_empty() {
+        return cached;
+    }
+
     match staff_guild_id.channels(&ctx.http).await {
         Ok(channels) => {
             let mut cats = Vec::new();
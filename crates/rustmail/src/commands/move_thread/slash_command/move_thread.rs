@@ -4,14 +4,16 @@ use crate::prelude::db::*;
 use crate::prelude::errors::*;
 use crate::prelude::handlers::*;
 use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
 use crate::prelude::utils::*;
 use serenity::FutureExt;
 use serenity::all::{
-    CommandDataOptionValue, CommandInteraction, CommandOptionType, Context, CreateCommand,
-    CreateCommandOption, ResolvedOption,
+    AutocompleteChoice, CommandDataOptionValue, CommandInteraction, CommandOptionType, Context,
+    CreateCommand, CreateCommandOption, ResolvedOption,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::error;
 
 pub struct MoveCommand;
 
@@ -52,11 +54,12 @@ impl RegistrableCommand for MoveCommand {
             vec![
                 CreateCommand::new("move").description(cmd_desc).add_option(
                     CreateCommandOption::new(
-                        CommandOptionType::Channel,
+                        CommandOptionType::String,
                         "category",
                         catagory_field_desc,
                     )
-                    .required(true),
+                    .required(true)
+                    .set_autocomplete(true),
                 ),
             ]
         })
@@ -98,16 +101,11 @@ impl RegistrableCommand for MoveCommand {
                 .find(|opt| opt.name == "category")
                 .ok_or(ModmailError::Command(CommandError::MissingArguments))?;
 
-            let category_channel = match &category_option.value {
-                CommandDataOptionValue::Channel(category) => category,
+            let category_name = match &category_option.value {
+                CommandDataOptionValue::String(value) => value.trim().to_string(),
                 _ => return Err(ModmailError::Command(CommandError::MissingArguments)),
             };
 
-            let category_name = category_channel
-                .name(&ctx.http)
-                .await
-                .map_err(|_| ModmailError::Command(CommandError::MissingArguments))?;
-
             if category_name.is_empty() {
                 return Err(ModmailError::Thread(ThreadError::CategoryNotFound));
             }
@@ -117,7 +115,12 @@ impl RegistrableCommand for MoveCommand {
                 return Err(ModmailError::Discord(DiscordError::FailedToFetchCategories));
             }
 
-            let target_category = find_best_match_category(&category_name, &categories);
+            // Autocomplete submits the category ID; typed text falls back to fuzzy name matching.
+            let target_category = categories
+                .iter()
+                .find(|(id, _)| id.to_string() == category_name)
+                .cloned()
+                .or_else(|| find_best_match_category(&category_name, &categories));
 
             match target_category {
                 Some((category_id, category_name)) => {
@@ -125,9 +128,11 @@ impl RegistrableCommand for MoveCommand {
                         move_channel_to_category_by_command_option(&ctx, &command, category_id)
                             .await
                     {
-                        eprintln!("Failed to move channel: {}", e);
+                        error!("Failed to move channel: {}", e);
                         return Err(ModmailError::Discord(DiscordError::FailedToMoveChannel));
                     }
+                    sync_ticket_category_after_move(&ctx, &config, command.channel_id, category_id)
+                        .await;
 
                     let mut params = HashMap::new();
                     params.insert("category".to_string(), category_name.to_string());
@@ -156,4 +161,48 @@ impl RegistrableCommand for MoveCommand {
             }
         })
     }
+
+    fn autocomplete<'a>(
+        &'a self,
+        ctx: &'a Context,
+        command: &'a CommandInteraction,
+        config: &'a Config,
+    ) -> BoxFuture<'a, Vec<AutocompleteChoice>> {
+        Box::pin(async move {
+            let Some(focused) = command.data.autocomplete() else {
+                return Vec::new();
+            };
+            if focused.name != "category" {
+                return Vec::new();
+            }
+
+            let server_categories = fetch_server_categories(ctx, config).await;
+
+            // Ticket categories first, then the remaining Discord categories.
+            let mut items: Vec<(String, String)> = Vec::new();
+            if let Some(pool) = config.db_pool.as_ref() {
+                for category in list_enabled_categories(pool).await.unwrap_or_default() {
+                    let discord_name = server_categories
+                        .iter()
+                        .find(|(id, _)| id.to_string() == category.discord_category_id)
+                        .map(|(_, name)| name.clone());
+                    let Some(discord_name) = discord_name else {
+                        continue;
+                    };
+                    items.push((
+                        format!("{} ({})", category.name, discord_name),
+                        category.discord_category_id,
+                    ));
+                }
+            }
+            for (id, name) in server_categories {
+                let id = id.to_string();
+                if !items.iter().any(|(_, value)| *value == id) {
+                    items.push((name, id));
+                }
+            }
+
+            filter_choices(focused.value, items)
+        })
+    }
 }
@@ -3,6 +3,7 @@ use crate::prelude::config::*;
 use crate::prelude::errors::*;
 use crate::prelude::handlers::*;
 use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
 use crate::prelude::utils::*;
 use serenity::all::{Context, Message};
 use std::collections::HashMap;
@@ -42,6 +43,7 @@ pub async fn move_thread(
                     e.to_string(),
                 )));
             }
+            sync_ticket_category_after_move(&ctx, config, msg.channel_id, category_id).await;
 
             let mut params = HashMap::new();
             params.insert("category".to_string(), category_name.to_string());
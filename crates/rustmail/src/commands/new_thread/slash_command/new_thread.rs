@@ -12,6 +12,7 @@ use serenity::all::{
 };
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::{error, info};
 
 pub struct NewThreadCommand;
 
@@ -150,7 +151,7 @@ impl RegistrableCommand for NewThreadCommand {
             {
                 Ok(channel) => channel,
                 Err(e) => {
-                    eprintln!("Failed to create channel: {}", e);
+                    error!("Failed to create channel: {}", e);
                     return Err(ModmailError::Discord(DiscordError::ChannelCreationFailed));
                 }
             };
@@ -166,7 +167,7 @@ impl RegistrableCommand for NewThreadCommand {
             {
                 Ok(thread_id) => thread_id,
                 Err(e) => {
-                    eprintln!("Failed to create thread in database: {}", e);
+                    error!("Failed to create thread in database: {}", e);
                     let _ = guild_channel.delete(&ctx.http).await;
                     return Err(ModmailError::Database(DatabaseError::InsertFailed(
                         e.to_string(),
@@ -217,7 +218,7 @@ impl RegistrableCommand for NewThreadCommand {
             params.insert("channel_id".to_string(), guild_channel.to_string());
             params.insert("staff".to_string(), command.user.name.clone());
 
-            println!(
+            info!(
                 "Thread created for user {} in channel {}",
                 user.name, guild_channel
             );
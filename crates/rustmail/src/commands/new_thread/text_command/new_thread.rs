@@ -8,6 +8,7 @@ use crate::prelude::utils::*;
 use serenity::all::{ChannelId, Context, GuildId, Message};
 use std::collections::HashMap;
 use std::sync::Arc;
+use tracing::error;
 
 pub async fn new_thread(
     ctx: Context,
@@ -71,7 +72,7 @@ pub async fn new_thread(
     {
         Ok(channel) => channel,
         Err(e) => {
-            eprintln!("Failed to create channel: {}", e);
+            error!("Failed to create channel: {}", e);
             send_error_message(
                 &ctx,
                 &msg,
@@ -126,7 +127,7 @@ pub async fn new_thread(
         {
             Ok(thread_id) => thread_id,
             Err(e) => {
-                eprintln!("Failed to create thread in database: {}", e);
+                error!("Failed to create thread in database: {}", e);
                 let _ = guild_channel.delete(&ctx.http).await;
                 send_error_message(&ctx, &msg, config, "new_thread.database_error", None).await;
                 return Ok(());
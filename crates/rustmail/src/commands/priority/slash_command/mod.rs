@@ -0,0 +1,3 @@
+pub mod priority;
+
+pub use priority::*;
@@ -0,0 +1,172 @@
+use crate::modules::{priority_label, set_ticket_priority};
+use crate::prelude::commands::*;
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::handlers::*;
+use crate::prelude::i18n::*;
+use crate::prelude::utils::*;
+use serenity::FutureExt;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, Context, CreateCommand,
+    CreateCommandOption, ResolvedOption,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct PriorityCommand;
+
+#[async_trait::async_trait]
+impl RegistrableCommand for PriorityCommand {
+    fn name(&self) -> &'static str {
+        "priority"
+    }
+
+    fn doc<'a>(&self, config: &'a Config) -> BoxFuture<'a, String> {
+        async move { get_translated_message(config, "help.priority", None, None, None, None).await }
+            .boxed()
+    }
+
+    fn register(&self, config: &Config) -> BoxFuture<'_, Vec<CreateCommand>> {
+        let config = config.clone();
+
+        Box::pin(async move {
+            let cmd_desc = get_translated_message(
+                &config,
+                "slash_command.priority_command_description",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+            let level_desc = get_translated_message(
+                &config,
+                "slash_command.priority_level_option",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+            let mut level =
+                CreateCommandOption::new(CommandOptionType::String, "level", level_desc)
+                    .required(false);
+            for priority in TicketPriority::ALL {
+                let label = get_translated_message(
+                    &config,
+                    &format!("priority.{}", priority.as_str()),
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+                .await;
+                level = level.add_string_choice(label, priority.as_str());
+            }
+
+            vec![
+                CreateCommand::new(self.name())
+                    .description(cmd_desc)
+                    .add_option(level),
+            ]
+        })
+    }
+
+    fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _options: &[ResolvedOption<'_>],
+        config: &Config,
+        _handler: Arc<InteractionHandler>,
+    ) -> BoxFuture<'_, ModmailResult<()>> {
+        let ctx = ctx.clone();
+        let command = command.clone();
+        let config = config.clone();
+
+        Box::pin(async move {
+            let db_pool = config
+                .db_pool
+                .as_ref()
+                .ok_or_else(database_connection_failed)?;
+
+            defer_response(&ctx, &command).await?;
+
+            if !is_a_ticket_channel(command.channel_id, db_pool).await {
+                return Err(ModmailError::Thread(ThreadError::NotAThreadChannel));
+            }
+
+            let thread =
+                match get_thread_by_channel_id(&command.channel_id.to_string(), db_pool).await {
+                    Some(thread) => thread,
+                    None => return Err(thread_not_found()),
+                };
+
+            let mut level: Option<String> = None;
+            for option in &command.data.options {
+                if option.name.as_str() == "level"
+                    && let CommandDataOptionValue::String(val) = &option.value
+                {
+                    level = Some(val.clone());
+                }
+            }
+
+            let Some(level) = level else {
+                let current = get_thread_priority(&thread.id, db_pool).await?;
+                let mut params = HashMap::new();
+                params.insert(
+                    "priority".to_string(),
+                    priority_label(&config, current, command.user.id, command.guild_id).await,
+                );
+                let _ = MessageBuilder::system_message(&ctx, &config)
+                    .translated_content("priority.current", Some(&params), None, None)
+                    .await
+                    .to_channel(command.channel_id)
+                    .send_interaction_followup(&command, true)
+                    .await;
+                return Ok(());
+            };
+
+            let priority = TicketPriority::parse(&level)
+                .ok_or(ModmailError::Command(CommandError::InvalidPriority))?;
+
+            tokio::spawn(async move {
+                let applied = match set_ticket_priority(&ctx, &config, &thread, priority).await {
+                    Ok(applied) => applied,
+                    Err(e) => {
+                        if let Some(error_handler) = &config.error_handler {
+                            let _ = error_handler
+                                .reply_to_command_with_error(&ctx, &command, &e)
+                                .await;
+                        }
+                        return;
+                    }
+                };
+
+                let key = if applied {
+                    "priority.confirmation"
+                } else {
+                    "priority.confirmation_rate_limited"
+                };
+
+                let mut params = HashMap::new();
+                params.insert(
+                    "priority".to_string(),
+                    priority_label(&config, priority, command.user.id, command.guild_id).await,
+                );
+
+                let _ = MessageBuilder::system_message(&ctx, &config)
+                    .translated_content(key, Some(&params), None, None)
+                    .await
+                    .to_channel(command.channel_id)
+                    .send_interaction_followup(&command, true)
+                    .await;
+            });
+
+            Ok(())
+        })
+    }
+}
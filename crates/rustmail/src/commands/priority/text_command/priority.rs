@@ -0,0 +1,87 @@
+use crate::modules::{priority_label, set_ticket_priority};
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::handlers::*;
+use crate::prelude::utils::*;
+use serenity::all::{Context, Message};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub async fn priority_command(
+    ctx: Context,
+    msg: Message,
+    config: &Config,
+    _handler: Arc<GuildMessagesHandler>,
+) -> ModmailResult<()> {
+    let db_pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+
+    if !is_a_ticket_channel(msg.channel_id, db_pool).await {
+        return Err(ModmailError::Thread(ThreadError::NotAThreadChannel));
+    }
+
+    let thread = match get_thread_by_channel_id(&msg.channel_id.to_string(), db_pool).await {
+        Some(thread) => thread,
+        None => return Err(thread_not_found()),
+    };
+
+    let level = extract_reply_content(&msg.content, &config.command.prefix, &["priority", "prio"])
+        .unwrap_or_default();
+
+    if level.trim().is_empty() {
+        let current = get_thread_priority(&thread.id, db_pool).await?;
+        let mut params = HashMap::new();
+        params.insert(
+            "priority".to_string(),
+            priority_label(config, current, msg.author.id, msg.guild_id).await,
+        );
+        let _ = MessageBuilder::system_message(&ctx, config)
+            .translated_content("priority.current", Some(&params), None, None)
+            .await
+            .to_channel(msg.channel_id)
+            .send(true)
+            .await;
+        return Ok(());
+    }
+
+    let priority = TicketPriority::parse(&level)
+        .ok_or(ModmailError::Command(CommandError::InvalidPriority))?;
+
+    let config_clone = config.clone();
+
+    tokio::spawn(async move {
+        let applied = match set_ticket_priority(&ctx, &config_clone, &thread, priority).await {
+            Ok(applied) => applied,
+            Err(e) => {
+                if let Some(error_handler) = &config_clone.error_handler {
+                    let _ = error_handler.reply_to_msg_with_error(&ctx, &msg, &e).await;
+                }
+                return;
+            }
+        };
+
+        let key = if applied {
+            "priority.confirmation"
+        } else {
+            "priority.confirmation_rate_limited"
+        };
+
+        let mut params = HashMap::new();
+        params.insert(
+            "priority".to_string(),
+            priority_label(&config_clone, priority, msg.author.id, msg.guild_id).await,
+        );
+
+        let _ = MessageBuilder::system_message(&ctx, &config_clone)
+            .translated_content(key, Some(&params), None, None)
+            .await
+            .to_channel(msg.channel_id)
+            .send(true)
+            .await;
+    });
+
+    Ok(())
+}
@@ -5,10 +5,11 @@ use crate::prelude::errors::*;
 use crate::prelude::handlers::*;
 use crate::prelude::i18n::*;
 use crate::prelude::utils::*;
+use chrono::DateTime;
 use serenity::FutureExt;
 use serenity::all::{
-    CommandDataOptionValue, CommandInteraction, CommandOptionType, Context, CreateCommand,
-    CreateCommandOption, ResolvedOption,
+    AutocompleteChoice, CommandDataOptionValue, CommandInteraction, CommandOptionType, Context,
+    CreateCommand, CreateCommandOption, ResolvedOption,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -51,9 +52,13 @@ impl RegistrableCommand for RemoveReminderCommand {
             )
             .await;
 
-            vec![CreateCommand::new(name).description(cmd_desc).add_option(
-                CreateCommandOption::new(CommandOptionType::Number, "id", id_desc).required(true),
-            )]
+            vec![
+                CreateCommand::new(name).description(cmd_desc).add_option(
+                    CreateCommandOption::new(CommandOptionType::Number, "id", id_desc)
+                        .required(true)
+                        .set_autocomplete(true),
+                ),
+            ]
         })
     }
 
@@ -120,6 +125,8 @@ impl RegistrableCommand for RemoveReminderCommand {
 
             match update_reminder_status(&reminder, true, pool).await {
                 Ok(_) => {
+                    let _ = cancel_job(JobKind::Reminder, &reminder_id.to_string(), pool).await;
+
                     let mut params = HashMap::new();
                     params.insert("id".to_string(), reminder_id.to_string());
 
@@ -145,4 +152,46 @@ impl RegistrableCommand for RemoveReminderCommand {
             Ok(())
         })
     }
+
+    fn autocomplete<'a>(
+        &'a self,
+        _ctx: &'a Context,
+        command: &'a CommandInteraction,
+        config: &'a Config,
+    ) -> BoxFuture<'a, Vec<AutocompleteChoice>> {
+        Box::pin(async move {
+            let (Some(pool), Some(focused)) =
+                (config.db_pool.as_ref(), command.data.autocomplete())
+            else {
+                return Vec::new();
+            };
+            if focused.name != "id" {
+                return Vec::new();
+            }
+
+            let reminders = list_pending_reminders_for_user(
+                command.user.id.get() as i64,
+                AUTOCOMPLETE_MAX_CHOICES as i64 * 4,
+                pool,
+            )
+            .await
+            .unwrap_or_default();
+
+            filter_choices(
+                focused.value,
+                reminders.into_iter().map(|(id, content, trigger_time)| {
+                    let when = DateTime::from_timestamp(trigger_time, 0)
+                        .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
+                        .unwrap_or_default();
+                    let preview: String = content.chars().take(50).collect();
+                    let label = if preview.is_empty() {
+                        format!("{} — {}", id, when)
+                    } else {
+                        format!("{} — {} — {}", id, when, preview)
+                    };
+                    (label, id)
+                }),
+            )
+        })
+    }
 }
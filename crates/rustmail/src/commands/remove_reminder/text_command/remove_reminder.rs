@@ -61,6 +61,8 @@ pub async fn remove_reminder(
 
     match update_reminder_status(&reminder, true, pool).await {
         Ok(_) => {
+            let _ = cancel_job(JobKind::Reminder, &reminder_id.to_string(), pool).await;
+
             let mut params = HashMap::new();
             params.insert("id".to_string(), reminder_id.to_string());
 
@@ -201,6 +201,13 @@ impl RegistrableCommand for ReplyCommand {
             ticket_status.last_message_by = TicketAuthor::Staff;
             ticket_status.last_message_at = Utc::now().timestamp();
             update_thread_status_db(&thread.id, &ticket_status, db_pool).await?;
+            record_message_workflow(
+                &thread.id,
+                TicketAuthor::Staff,
+                Some(&command.user.id.to_string()),
+                db_pool,
+            )
+            .await?;
 
             let mut sr = MessageBuilder::begin_staff_reply(
                 &ctx,
@@ -0,0 +1,5 @@
+pub mod slash_command;
+pub mod text_command;
+
+pub use slash_command::*;
+pub use text_command::*;
@@ -0,0 +1,3 @@
+pub mod resolve;
+
+pub use resolve::*;
@@ -0,0 +1,177 @@
+use crate::modules::{resolve_ticket, resume_ticket, workflow_state_label};
+use crate::prelude::commands::*;
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::handlers::*;
+use crate::prelude::i18n::*;
+use crate::prelude::utils::*;
+use serenity::FutureExt;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, Context, CreateCommand,
+    CreateCommandOption, ResolvedOption,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub struct ResolveCommand;
+
+#[async_trait::async_trait]
+impl RegistrableCommand for ResolveCommand {
+    fn name(&self) -> &'static str {
+        "resolve"
+    }
+
+    fn doc<'a>(&self, config: &'a Config) -> BoxFuture<'a, String> {
+        async move { get_translated_message(config, "help.resolve", None, None, None, None).await }
+            .boxed()
+    }
+
+    fn register(&self, config: &Config) -> BoxFuture<'_, Vec<CreateCommand>> {
+        let config = config.clone();
+
+        Box::pin(async move {
+            let cmd_desc = get_translated_message(
+                &config,
+                "slash_command.resolve_command_description",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+            let delay_desc = get_translated_message(
+                &config,
+                "slash_command.resolve_delay_option",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+            let cancel_desc = get_translated_message(
+                &config,
+                "slash_command.resolve_cancel_option",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+            vec![
+                CreateCommand::new(self.name())
+                    .description(cmd_desc)
+                    .add_option(
+                        CreateCommandOption::new(CommandOptionType::String, "delay", delay_desc)
+                            .required(false),
+                    )
+                    .add_option(
+                        CreateCommandOption::new(CommandOptionType::Boolean, "cancel", cancel_desc)
+                            .required(false),
+                    ),
+            ]
+        })
+    }
+
+    fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _options: &[ResolvedOption<'_>],
+        config: &Config,
+        _handler: Arc<InteractionHandler>,
+    ) -> BoxFuture<'_, ModmailResult<()>> {
+        let ctx = ctx.clone();
+        let command = command.clone();
+        let config = config.clone();
+
+        Box::pin(async move {
+            let db_pool = config
+                .db_pool
+                .as_ref()
+                .ok_or_else(database_connection_failed)?;
+
+            defer_response(&ctx, &command).await?;
+
+            if !is_a_ticket_channel(command.channel_id, db_pool).await {
+                return Err(ModmailError::Thread(ThreadError::NotAThreadChannel));
+            }
+
+            let thread =
+                match get_thread_by_channel_id(&command.channel_id.to_string(), db_pool).await {
+                    Some(thread) => thread,
+                    None => return Err(thread_not_found()),
+                };
+
+            let mut delay_arg: Option<String> = None;
+            let mut cancel = false;
+            for option in &command.data.options {
+                match (option.name.as_str(), &option.value) {
+                    ("delay", CommandDataOptionValue::String(val)) => {
+                        delay_arg = Some(val.trim().to_string());
+                    }
+                    ("cancel", CommandDataOptionValue::Boolean(val)) => cancel = *val,
+                    _ => {}
+                }
+            }
+
+            if cancel {
+                let current = get_thread_workflow(&thread.id, db_pool)
+                    .await?
+                    .map(|(s, _)| s);
+                if current != Some(TicketWorkflowState::Resolved) {
+                    return Err(ModmailError::Command(CommandError::TicketNotResolved));
+                }
+
+                let state = resume_ticket(&ctx, &config, &thread, Some(command.user.id)).await?;
+                let mut params = HashMap::new();
+                params.insert(
+                    "state".to_string(),
+                    workflow_state_label(&config, state, command.user.id, command.guild_id).await,
+                );
+                let _ = MessageBuilder::system_message(&ctx, &config)
+                    .translated_content("resolve.canceled", Some(&params), None, None)
+                    .await
+                    .to_channel(command.channel_id)
+                    .send_interaction_followup(&command, true)
+                    .await;
+                return Ok(());
+            }
+
+            let delay = match delay_arg.filter(|d| !d.is_empty()) {
+                Some(arg) => parse_duration_spec(&arg)
+                    .ok_or(ModmailError::Command(CommandError::InvalidArguments(arg)))?
+                    .as_secs(),
+                None => config.thread.resolved_close_delay,
+            };
+
+            let channel = command
+                .channel_id
+                .to_channel(&ctx.http)
+                .await?
+                .guild()
+                .ok_or(ModmailError::Thread(ThreadError::NotAThreadChannel))?;
+
+            let close_at =
+                resolve_ticket(&ctx, &config, &thread, &channel, delay, command.user.id).await?;
+
+            let (key, params) = match close_at {
+                Some(_) => {
+                    let mut params = HashMap::new();
+                    params.insert("time".to_string(), format_duration(delay));
+                    ("resolve.confirmation", Some(params))
+                }
+                None => ("resolve.confirmation_no_close", None),
+            };
+            let _ = MessageBuilder::system_message(&ctx, &config)
+                .translated_content(key, params.as_ref(), None, None)
+                .await
+                .to_channel(command.channel_id)
+                .send_interaction_followup(&command, true)
+                .await;
+
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,93 @@
+use crate::modules::{resolve_ticket, resume_ticket, workflow_state_label};
+use crate::prelude::commands::*;
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::handlers::*;
+use crate::prelude::utils::*;
+use serenity::all::{Context, Message};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub async fn resolve(
+    ctx: Context,
+    msg: Message,
+    config: &Config,
+    _handler: Arc<GuildMessagesHandler>,
+) -> ModmailResult<()> {
+    let db_pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+
+    if !is_a_ticket_channel(msg.channel_id, db_pool).await {
+        return Err(ModmailError::Thread(ThreadError::NotAThreadChannel));
+    }
+
+    let thread = match get_thread_by_channel_id(&msg.channel_id.to_string(), db_pool).await {
+        Some(thread) => thread,
+        None => return Err(thread_not_found()),
+    };
+
+    let arg = extract_reply_content(&msg.content, &config.command.prefix, &["resolve"])
+        .unwrap_or_default();
+    let arg = arg.trim();
+
+    if arg.eq_ignore_ascii_case("cancel") {
+        let current = get_thread_workflow(&thread.id, db_pool)
+            .await?
+            .map(|(s, _)| s);
+        if current != Some(TicketWorkflowState::Resolved) {
+            return Err(ModmailError::Command(CommandError::TicketNotResolved));
+        }
+
+        let state = resume_ticket(&ctx, config, &thread, Some(msg.author.id)).await?;
+        let mut params = HashMap::new();
+        params.insert(
+            "state".to_string(),
+            workflow_state_label(config, state, msg.author.id, msg.guild_id).await,
+        );
+        let _ = MessageBuilder::system_message(&ctx, config)
+            .translated_content("resolve.canceled", Some(&params), None, None)
+            .await
+            .to_channel(msg.channel_id)
+            .send(true)
+            .await;
+        return Ok(());
+    }
+
+    let delay = if arg.is_empty() {
+        config.thread.resolved_close_delay
+    } else {
+        parse_duration_spec(arg)
+            .ok_or(ModmailError::Command(CommandError::InvalidArguments(
+                arg.to_string(),
+            )))?
+            .as_secs()
+    };
+
+    let channel = msg
+        .channel(&ctx.http)
+        .await?
+        .guild()
+        .ok_or(ModmailError::Thread(ThreadError::NotAThreadChannel))?;
+
+    let close_at = resolve_ticket(&ctx, config, &thread, &channel, delay, msg.author.id).await?;
+
+    let (key, params) = match close_at {
+        Some(_) => {
+            let mut params = HashMap::new();
+            params.insert("time".to_string(), format_duration(delay));
+            ("resolve.confirmation", Some(params))
+        }
+        None => ("resolve.confirmation_no_close", None),
+    };
+    let _ = MessageBuilder::system_message(&ctx, config)
+        .translated_content(key, params.as_ref(), None, None)
+        .await
+        .to_channel(msg.channel_id)
+        .send(true)
+        .await;
+
+    Ok(())
+}
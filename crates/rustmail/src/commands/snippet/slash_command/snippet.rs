@@ -7,8 +7,8 @@ use crate::prelude::utils::*;
 use regex::Regex;
 use serenity::FutureExt;
 use serenity::all::{
-    CommandDataOption, CommandDataOptionValue, CommandInteraction, CommandOptionType, Context,
-    CreateCommand, CreateCommandOption, ResolvedOption,
+    AutocompleteChoice, CommandDataOption, CommandDataOptionValue, CommandInteraction,
+    CommandOptionType, Context, CreateCommand, CreateCommandOption, ResolvedOption,
 };
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -162,7 +162,8 @@ impl RegistrableCommand for SnippetCommand {
                                     "key",
                                     key_desc.clone(),
                                 )
-                                .required(true),
+                                .required(true)
+                                .set_autocomplete(true),
                             ),
                     )
                     .add_option(
@@ -173,7 +174,8 @@ impl RegistrableCommand for SnippetCommand {
                                     "key",
                                     key_desc.clone(),
                                 )
-                                .required(true),
+                                .required(true)
+                                .set_autocomplete(true),
                             )
                             .add_sub_option(
                                 CreateCommandOption::new(
@@ -196,7 +198,8 @@ impl RegistrableCommand for SnippetCommand {
                                 "key",
                                 key_desc.clone(),
                             )
-                            .required(true),
+                            .required(true)
+                            .set_autocomplete(true),
                         ),
                     )
                     .add_option(
@@ -207,7 +210,8 @@ impl RegistrableCommand for SnippetCommand {
                                     "key",
                                     key_desc,
                                 )
-                                .required(true),
+                                .required(true)
+                                .set_autocomplete(true),
                             ),
                     ),
             ]
@@ -266,6 +270,33 @@ impl RegistrableCommand for SnippetCommand {
             }
         })
     }
+
+    fn autocomplete<'a>(
+        &'a self,
+        _ctx: &'a Context,
+        command: &'a CommandInteraction,
+        config: &'a Config,
+    ) -> BoxFuture<'a, Vec<AutocompleteChoice>> {
+        Box::pin(async move {
+            let (Some(pool), Some(focused)) =
+                (config.db_pool.as_ref(), command.data.autocomplete())
+            else {
+                return Vec::new();
+            };
+            if focused.name != "key" {
+                return Vec::new();
+            }
+
+            let snippets = get_all_snippets(pool).await.unwrap_or_default();
+            filter_choices(
+                focused.value,
+                snippets.into_iter().map(|snippet| {
+                    let preview: String = snippet.content.chars().take(60).collect();
+                    (format!("{} — {}", snippet.key, preview), snippet.key)
+                }),
+            )
+        })
+    }
 }
 
 async fn handle_create(
@@ -3,55 +3,13 @@ use crate::config::Config;
 use crate::errors::{CommandError, ModmailError, ModmailResult};
 use crate::handlers::GuildMessagesHandler;
 use crate::i18n::get_translated_message;
+use crate::modules::command_permissions::is_message_author_admin;
 use crate::utils::{MessageBuilder, extract_reply_content};
-use serenity::all::{ActivityData, Context, Message, Permissions};
+use serenity::all::{ActivityData, Context, Message};
 use std::str::FromStr;
 use std::sync::Arc;
 use std::sync::atomic::Ordering;
 
-async fn is_user_admin_or_super_admin(ctx: &Context, msg: &Message, config: &Config) -> bool {
-    let user_id = msg.author.id.get();
-
-    if config.bot.panel_super_admin_users.contains(&user_id) {
-        return true;
-    }
-
-    let guild_id = match msg.guild_id {
-        Some(id) => id,
-        None => return false,
-    };
-
-    let member = match guild_id.member(&ctx.http, msg.author.id).await {
-        Ok(m) => m,
-        Err(_) => return false,
-    };
-
-    if !config.bot.panel_super_admin_roles.is_empty() {
-        for role_id in &member.roles {
-            if config.bot.panel_super_admin_roles.contains(&role_id.get()) {
-                return true;
-            }
-        }
-    }
-
-    let guild = match guild_id.to_partial_guild(&ctx.http).await {
-        Ok(g) => g,
-        Err(_) => return false,
-    };
-
-    if guild.owner_id == msg.author.id {
-        return true;
-    }
-
-    member.roles.iter().any(|role_id| {
-        guild
-            .roles
-            .get(role_id)
-            .map(|role| role.permissions.contains(Permissions::ADMINISTRATOR))
-            .unwrap_or(false)
-    })
-}
-
 pub async fn status_command(
     ctx: Context,
     msg: Message,
@@ -68,9 +26,7 @@ pub async fn status_command(
         Err(_) => return Err(ModmailError::Command(CommandError::InvalidStatusValue)),
     };
 
-    if bot_status == BotStatus::Maintenance
-        && !is_user_admin_or_super_admin(&ctx, &msg, config).await
-    {
+    if bot_status == BotStatus::Maintenance && !is_message_author_admin(&ctx, config, &msg).await {
         return Err(ModmailError::Command(
             CommandError::MaintenanceModeNotAllowed,
         ));
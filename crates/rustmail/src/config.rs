@@ -1,11 +1,14 @@
 use crate::prelude::errors::*;
 use crate::prelude::i18n::*;
+use serde::Serialize;
 use serenity::all::GuildId;
 use serenity::http::Http;
 use sqlx::SqlitePool;
+use std::collections::BTreeMap;
 use std::fs;
 use std::net::UdpSocket;
-use std::sync::Arc;
+use std::sync::{Arc, RwLock};
+use tracing::{error, warn};
 
 pub use rustmail_types::*;
 #[derive(Debug, Clone)]
@@ -18,6 +21,8 @@ pub struct Config {
     pub notifications: NotificationsConfig,
     pub reminders: ReminderConfig,
     pub logs: LogsConfig,
+    pub logging: LoggingConfig,
+    pub backups: BackupConfig,
 
     pub db_pool: Option<SqlitePool>,
     pub error_handler: Option<Arc<ErrorHandler>>,
@@ -25,6 +30,155 @@ pub struct Config {
         Arc<std::sync::Mutex<std::collections::HashMap<u64, Arc<tokio::sync::Mutex<()>>>>>,
 }
 
+#[derive(Debug, Clone)]
+pub struct ConfigHandle {
+    current: Arc<RwLock<Arc<Config>>>,
+}
+
+impl ConfigHandle {
+    pub fn new(config: Config) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(config))),
+        }
+    }
+
+    pub fn load(&self) -> Arc<Config> {
+        self.current
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    // The database pool and the per-ticket locks belong to the running process.
+    pub fn replace(&self, mut next: Config) -> Vec<ConfigChange> {
+        let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+        if next.db_pool.is_none() {
+            next.db_pool = current.db_pool.clone();
+        }
+        next.thread_locks = current.thread_locks.clone();
+        if next.error_handler.is_none() {
+            next.error_handler = current.error_handler.clone();
+        }
+        let changes = diff_config(&current, &next);
+        *current = Arc::new(next);
+        changes
+    }
+
+    pub fn set_db_pool(&self, pool: SqlitePool) {
+        let mut current = self.current.write().unwrap_or_else(|e| e.into_inner());
+        let mut next = (**current).clone();
+        next.db_pool = Some(pool);
+        *current = Arc::new(next);
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ReloadImpact {
+    Live,
+    Reconnect,
+    Restart,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ConfigChange {
+    pub field: String,
+    pub impact: ReloadImpact,
+}
+
+pub fn reload_impact(field: &str) -> ReloadImpact {
+    match field {
+        "bot.token" => ReloadImpact::Reconnect,
+        f if f.starts_with("bot.mode.") => ReloadImpact::Reconnect,
+        "bot.enable_panel" | "bot.panel_port" => ReloadImpact::Restart,
+        f if f.starts_with("logging.") => ReloadImpact::Restart,
+        _ => ReloadImpact::Live,
+    }
+}
+
+fn collect_leaves(
+    prefix: &str,
+    value: serde_json::Value,
+    out: &mut BTreeMap<String, serde_json::Value>,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, value) in map {
+                let path = if prefix.is_empty() {
+                    key
+                } else {
+                    format!("{prefix}.{key}")
+                };
+                collect_leaves(&path, value, out);
+            }
+        }
+        other => {
+            out.insert(prefix.to_string(), other);
+        }
+    }
+}
+
+pub fn config_fields(config: &ConfigResponse) -> BTreeMap<String, serde_json::Value> {
+    let mut fields = BTreeMap::new();
+    if let Ok(value) = serde_json::to_value(config) {
+        collect_leaves("", value, &mut fields);
+    }
+    fields
+}
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct ConfigFieldDiff {
+    pub field: String,
+    pub from: Option<serde_json::Value>,
+    pub to: Option<serde_json::Value>,
+    pub impact: ReloadImpact,
+}
+
+pub fn diff_config_responses(old: &ConfigResponse, new: &ConfigResponse) -> Vec<ConfigFieldDiff> {
+    let mut old = config_fields(old);
+    let mut new = config_fields(new);
+
+    let mut fields: Vec<String> = old.keys().chain(new.keys()).cloned().collect();
+    fields.sort();
+    fields.dedup();
+
+    fields
+        .into_iter()
+        .filter_map(|field| {
+            let from = old.remove(&field);
+            let to = new.remove(&field);
+            (from != to).then(|| ConfigFieldDiff {
+                impact: reload_impact(&field),
+                field,
+                from,
+                to,
+            })
+        })
+        .collect()
+}
+
+pub fn diff_config(old: &Config, new: &Config) -> Vec<ConfigChange> {
+    diff_config_responses(&old.to_response(), &new.to_response())
+        .into_iter()
+        .map(|diff| ConfigChange {
+            field: diff.field,
+            impact: diff.impact,
+        })
+        .collect()
+}
+
+pub fn mask_secret(secret: &str) -> String {
+    let len = secret.chars().count();
+    if len <= 8 {
+        "*".repeat(len)
+    } else {
+        let chars: Vec<char> = secret.chars().collect();
+        let start: String = chars.iter().take(4).collect();
+        let end: String = chars.iter().skip(len - 4).collect();
+        format!("{}...{}", start, end)
+    }
+}
+
 fn get_local_ip() -> Option<String> {
     let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
     socket.connect("1.1.1.1:80").ok()?;
@@ -52,41 +206,307 @@ pub fn resolve_port(default: u16) -> u16 {
         .unwrap_or(default)
 }
 
-fn apply_env_overrides(config: &mut ConfigResponse) {
-    if let Ok(token) = std::env::var("RUSTMAIL_BOT_TOKEN")
-        && !token.is_empty()
-    {
-        config.bot.token = token;
+const ENV_PREFIX: &str = "RUSTMAIL_";
+
+pub const CONFIG_SEED_VAR: &str = "RUSTMAIL_CONFIG_SEED";
+
+struct EnvOverride {
+    path: Vec<String>,
+    value: toml::Value,
+}
+
+fn default_config_table() -> toml::Table {
+    let defaults = ConfigResponse {
+        bot: BotConfig {
+            token: String::new(),
+            mode: ServerMode::Single { guild_id: 0 },
+            status: "Need help? DM me!".to_string(),
+            welcome_message:
+                "Thank you for contacting support! A staff member will be with you shortly."
+                    .to_string(),
+            close_message: "Your ticket has been closed.".to_string(),
+            typing_proxy_from_user: true,
+            typing_proxy_from_staff: true,
+            enable_rustmail_logs: false,
+            enable_discord_logs: false,
+            enable_features: false,
+            enable_panel: false,
+            client_id: 0,
+            client_secret: String::new(),
+            redirect_url: String::new(),
+            timezone: chrono_tz::UTC,
+            logs_channel_id: None,
+            features_channel_id: None,
+            ip: None,
+            panel_super_admin_users: vec![],
+            panel_super_admin_roles: vec![],
+            panel_port: 3002,
+        },
+        command: CommandConfig::default(),
+        thread: ThreadConfig::default(),
+        language: LanguageConfig::default(),
+        error_handling: ErrorHandlingConfig::default(),
+        notifications: NotificationsConfig::default(),
+        reminders: ReminderConfig::default(),
+        logs: LogsConfig::default(),
+        logging: LoggingConfig::default(),
+        backups: BackupConfig::default(),
+    };
+    toml::Table::try_from(defaults).unwrap_or_default()
+}
+
+fn merge_tables(base: &mut toml::Table, other: toml::Table) {
+    for (key, value) in other {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(base)), toml::Value::Table(other)) => {
+                merge_tables(base, other)
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
     }
+}
 
-    if let Ok(client_secret) = std::env::var("RUSTMAIL_BOT_CLIENT_SECRET")
-        && !client_secret.is_empty()
-    {
-        config.bot.client_secret = client_secret;
+fn table_get<'a>(table: &'a toml::Table, path: &[String]) -> Option<&'a toml::Value> {
+    let (last, parents) = path.split_last()?;
+    let mut current = table;
+    for key in parents {
+        current = current.get(key)?.as_table()?;
     }
+    current.get(last)
+}
 
-    if let Ok(client_id) = std::env::var("RUSTMAIL_BOT_CLIENT_ID")
-        && let Ok(id) = client_id.parse::<u64>()
-    {
-        config.bot.client_id = id;
+fn table_set(table: &mut toml::Table, path: &[String], value: toml::Value) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut current = table;
+    for key in parents {
+        let entry = current
+            .entry(key.clone())
+            .or_insert_with(|| toml::Value::Table(toml::Table::new()));
+        if !entry.is_table() {
+            *entry = toml::Value::Table(toml::Table::new());
+        }
+        let Some(next) = entry.as_table_mut() else {
+            return;
+        };
+        current = next;
+    }
+    current.insert(last.clone(), value);
+}
+
+// `bot_mode_guild_id` -> ["bot", "mode", "guild_id"], following the keys of `template`.
+fn resolve_env_path(template: &toml::Table, name: &str, nested: bool) -> Option<Vec<String>> {
+    let mut keys: Vec<&String> = template.keys().collect();
+    keys.sort_by_key(|k| std::cmp::Reverse(k.len()));
+
+    for key in keys {
+        if name == key {
+            return Some(vec![key.clone()]);
+        }
+        if let Some(rest) = name
+            .strip_prefix(key.as_str())
+            .and_then(|rest| rest.strip_prefix('_'))
+            && let Some(toml::Value::Table(child)) = template.get(key)
+        {
+            let mut path = vec![key.clone()];
+            path.extend(resolve_env_path(child, rest, true)?);
+            return Some(path);
+        }
+    }
+
+    nested.then(|| vec![name.to_string()])
+}
+
+fn parse_toml_literal(raw: &str) -> Option<toml::Value> {
+    toml::from_str::<toml::Table>(&format!("v = {}", raw))
+        .ok()?
+        .remove("v")
+}
+
+fn parse_env_value(raw: &str, template: Option<&toml::Value>) -> Result<toml::Value, String> {
+    let trimmed = raw.trim();
+    match template {
+        Some(toml::Value::String(_)) => Ok(toml::Value::String(raw.to_string())),
+        Some(toml::Value::Integer(_)) => trimmed
+            .parse()
+            .map(toml::Value::Integer)
+            .map_err(|_| "expected an integer".to_string()),
+        Some(toml::Value::Float(_)) => trimmed
+            .parse()
+            .map(toml::Value::Float)
+            .map_err(|_| "expected a number".to_string()),
+        Some(toml::Value::Boolean(_)) => match trimmed.to_lowercase().as_str() {
+            "true" | "1" | "yes" | "on" => Ok(toml::Value::Boolean(true)),
+            "false" | "0" | "no" | "off" => Ok(toml::Value::Boolean(false)),
+            _ => Err("expected true or false".to_string()),
+        },
+        Some(toml::Value::Array(items)) if !trimmed.starts_with('[') => trimmed
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(|item| parse_env_value(item, items.first()))
+            .collect::<Result<Vec<_>, _>>()
+            .map(toml::Value::Array),
+        Some(toml::Value::Array(_)) => parse_toml_literal(trimmed)
+            .filter(toml::Value::is_array)
+            .ok_or_else(|| "expected a TOML array".to_string()),
+        Some(toml::Value::Table(_)) => parse_toml_literal(trimmed)
+            .filter(toml::Value::is_table)
+            .ok_or_else(|| "expected an inline TOML table".to_string()),
+        _ => {
+            Ok(parse_toml_literal(trimmed).unwrap_or_else(|| toml::Value::String(raw.to_string())))
+        }
     }
 }
 
+fn collect_env_overrides(template: &toml::Table) -> Result<Vec<EnvOverride>, String> {
+    let mut vars: Vec<(String, String)> = std::env::vars()
+        .filter(|(key, _)| key.starts_with(ENV_PREFIX))
+        .collect();
+    vars.sort();
+
+    let mut overrides = Vec::new();
+    let mut errors = Vec::new();
+    for (var, raw) in vars {
+        let name = var[ENV_PREFIX.len()..].to_lowercase();
+        let (name, from_file) = match name.strip_suffix("_file") {
+            Some(name) => (name.to_string(), true),
+            None => (name, false),
+        };
+        let Some(path) = resolve_env_path(template, &name, false).filter(|p| p.len() > 1) else {
+            continue;
+        };
+
+        let raw = if from_file {
+            match fs::read_to_string(&raw) {
+                Ok(content) => content.trim_end_matches(['\r', '\n']).to_string(),
+                Err(e) => {
+                    errors.push(format!("{}: failed to read {}: {}", var, raw, e));
+                    continue;
+                }
+            }
+        } else {
+            raw
+        };
+        if raw.is_empty() {
+            continue;
+        }
+
+        match parse_env_value(&raw, table_get(template, &path)) {
+            Ok(value) => overrides.push(EnvOverride { path, value }),
+            Err(e) => errors.push(format!("{}: {}", var, e)),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(overrides)
+    } else {
+        Err(format!(
+            "Invalid environment configuration: {}",
+            errors.join("; ")
+        ))
+    }
+}
+
+fn apply_env_overrides(table: &mut toml::Table) -> Result<Vec<EnvOverride>, String> {
+    let mut template = default_config_table();
+    merge_tables(&mut template, table.clone());
+
+    let overrides = collect_env_overrides(&template)?;
+    for o in &overrides {
+        table_set(table, &o.path, o.value.clone());
+    }
+    Ok(overrides)
+}
+
+fn read_seed_table() -> Option<Result<toml::Table, String>> {
+    let path = std::env::var(CONFIG_SEED_VAR)
+        .ok()
+        .filter(|p| !p.is_empty())?;
+    Some(
+        fs::read_to_string(&path)
+            .map_err(|e| format!("Failed to read {} ({}): {}", CONFIG_SEED_VAR, path, e))
+            .and_then(|content| {
+                toml::from_str(&content)
+                    .map_err(|e| format!("Failed to parse seed file {}: {}", path, e))
+            }),
+    )
+}
+
+pub fn read_config_table(path: &str) -> toml::Table {
+    let mut table = match fs::read_to_string(path) {
+        Ok(content) => toml::from_str(&content).unwrap_or_default(),
+        Err(_) => {
+            let mut table = default_config_table();
+            if let Some(Ok(seed)) = read_seed_table() {
+                merge_tables(&mut table, seed);
+            }
+            table
+        }
+    };
+    let _ = apply_env_overrides(&mut table);
+    table
+}
+
+pub fn build_headless_config() -> Option<Result<Config, String>> {
+    let mut table = default_config_table();
+    let seed = read_seed_table();
+    let has_seed = seed.is_some();
+    if let Some(seed) = seed {
+        match seed {
+            Ok(seed) => merge_tables(&mut table, seed),
+            Err(e) => return Some(Err(e)),
+        }
+    }
+
+    let overrides = match apply_env_overrides(&mut table) {
+        Ok(overrides) => overrides,
+        Err(e) => return Some(Err(e)),
+    };
+    let sets_server = overrides.iter().any(|o| {
+        o.path.first().is_some_and(|k| k == "bot") && o.path.get(1).is_some_and(|k| k == "mode")
+    });
+    if !has_seed && !sets_server {
+        return None;
+    }
+
+    Some(config_from_table(table))
+}
+
 pub fn load_config(path: &str) -> Option<Config> {
-    let content = match fs::read_to_string(path) {
-        Ok(c) => c,
-        Err(_) => return None,
+    let result = match fs::read_to_string(path) {
+        Ok(content) => parse_config(&content),
+        Err(_) => build_headless_config()?,
     };
 
-    let mut config_response: ConfigResponse = match toml::from_str(&content) {
-        Ok(c) => c,
+    match result {
+        Ok(config) => Some(config),
         Err(e) => {
-            eprintln!("Failed to parse config.toml: {}", e);
-            return None;
+            error!("{}", e);
+            None
         }
-    };
+    }
+}
+
+pub fn parse_config(content: &str) -> Result<Config, String> {
+    let mut table: toml::Table =
+        toml::from_str(content).map_err(|e| format!("Failed to parse config.toml: {}", e))?;
+    apply_env_overrides(&mut table)?;
+    config_from_table(table)
+}
+
+#[cfg(test)]
+pub fn test_config() -> Config {
+    config_from_table(default_config_table()).unwrap()
+}
 
-    apply_env_overrides(&mut config_response);
+fn config_from_table(table: toml::Table) -> Result<Config, String> {
+    let config_response: ConfigResponse = toml::Value::Table(table)
+        .try_into()
+        .map_err(|e| format!("Failed to parse config.toml: {}", e))?;
 
     let mut bot = config_response.bot;
     if bot.ip.is_none() {
@@ -94,51 +514,46 @@ pub fn load_config(path: &str) -> Option<Config> {
     }
 
     if u64::from_str_radix(&config_response.thread.user_message_color, 16).is_err() {
-        eprintln!(
+        return Err(
             "Incorrect user message color in the config.toml! Please put a color in hex format!"
+                .to_string(),
         );
-        return None;
     }
 
     if u64::from_str_radix(&config_response.thread.staff_message_color, 16).is_err() {
-        eprintln!(
+        return Err(
             "Incorrect staff message color in the config.toml! Please put a color in hex format!"
+                .to_string(),
         );
-        return None;
     }
 
     if u64::from_str_radix(&config_response.reminders.embed_color, 16).is_err() {
-        eprintln!(
+        return Err(
             "Incorrect reminder embed color in the config.toml! Please put a color in hex format!"
+                .to_string(),
         );
-        return None;
     }
 
     if !config_response
         .language
         .is_language_supported(config_response.language.get_default_language())
     {
-        eprintln!(
-            "Warning: Default language '{}' is not in supported languages list",
+        warn!(
+            "Default language '{}' is not in supported languages list",
             config_response.language.default_language
         );
     }
 
-    if let Err(e) = bot.validate_logs_config() {
-        eprintln!("Invalid logs configuration: {}", e);
-        return None;
-    }
-
-    if let Err(e) = bot.validate_features_config() {
-        eprintln!("Invalid features configuration: {}", e);
-        return None;
-    }
+    bot.validate_logs_config()
+        .map_err(|e| format!("Invalid logs configuration: {}", e))?;
+    bot.validate_features_config()
+        .map_err(|e| format!("Invalid features configuration: {}", e))?;
 
     let default_lang = config_response.language.get_default_language();
     let fallback_lang = config_response.language.get_fallback_language();
     let error_handler = Arc::new(ErrorHandler::with_languages(default_lang, fallback_lang));
 
-    Some(Config {
+    Ok(Config {
         bot,
         command: config_response.command,
         thread: config_response.thread,
@@ -147,6 +562,8 @@ pub fn load_config(path: &str) -> Option<Config> {
         notifications: config_response.notifications,
         reminders: config_response.reminders,
         logs: config_response.logs,
+        logging: config_response.logging,
+        backups: config_response.backups,
         db_pool: None,
         error_handler: Some(error_handler),
         thread_locks: Arc::new(std::sync::Mutex::new(std::collections::HashMap::new())),
@@ -166,6 +583,10 @@ pub fn validate_config(config: &Config) -> Result<(), String> {
         return Err("Invalid reminder embed color format (must be hex)".to_string());
     }
 
+    if config.backups.enabled && config.backups.interval_hours == 0 {
+        return Err("Backup interval must be at least one hour".to_string());
+    }
+
     config.bot.validate_logs_config()?;
     config.bot.validate_features_config()?;
 
@@ -188,18 +609,7 @@ pub async fn save_config_with_backup(config: &Config, path: &str) -> Result<(),
         fs::copy(path, &backup_path).map_err(|e| format!("Failed to create backup: {}", e))?;
     }
 
-    let config_response = ConfigResponse {
-        bot: config.bot.clone(),
-        command: config.command.clone(),
-        thread: config.thread.clone(),
-        language: config.language.clone(),
-        error_handling: config.error_handling.clone(),
-        notifications: config.notifications.clone(),
-        reminders: config.reminders.clone(),
-        logs: config.logs.clone(),
-    };
-
-    let toml_content = toml::to_string_pretty(&config_response)
+    let toml_content = toml::to_string_pretty(&config.to_response())
         .map_err(|e| format!("Failed to serialize config: {}", e))?;
 
     fs::write(path, toml_content).map_err(|e| format!("Failed to write config file: {}", e))?;
@@ -236,6 +646,28 @@ impl LanguageConfigExt for LanguageConfig {
 }
 
 impl Config {
+    pub fn to_response(&self) -> ConfigResponse {
+        ConfigResponse {
+            bot: self.bot.clone(),
+            command: self.command.clone(),
+            thread: self.thread.clone(),
+            language: self.language.clone(),
+            error_handling: self.error_handling.clone(),
+            notifications: self.notifications.clone(),
+            reminders: self.reminders.clone(),
+            logs: self.logs.clone(),
+            logging: self.logging.clone(),
+            backups: self.backups.clone(),
+        }
+    }
+
+    pub fn to_masked_response(&self) -> ConfigResponse {
+        let mut response = self.to_response();
+        response.bot.token = mask_secret(&self.bot.token);
+        response.bot.client_secret = mask_secret(&self.bot.client_secret);
+        response
+    }
+
     pub async fn validate_servers(&self, http: &Http) -> Result<(), String> {
         match &self.bot.mode {
             ServerMode::Single { guild_id } => {
@@ -252,7 +684,7 @@ impl Config {
                 let staff_guild_id = GuildId::new(*staff_guild_id);
 
                 if let Err(e) = community_guild_id.to_partial_guild(http).await {
-                    eprintln!("Error fetching community guild: {}", e);
+                    error!("Error fetching community guild: {}", e);
                     return Err(format!(
                         "Serveur communautaire introuvable: {}",
                         community_guild_id
@@ -274,3 +706,66 @@ impl Config {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_connection_and_startup_fields_need_a_restart() {
+        assert_eq!(reload_impact("bot.token"), ReloadImpact::Reconnect);
+        assert_eq!(reload_impact("bot.mode.guild_id"), ReloadImpact::Reconnect);
+        assert_eq!(reload_impact("bot.panel_port"), ReloadImpact::Restart);
+        assert_eq!(reload_impact("logging.level"), ReloadImpact::Restart);
+        assert_eq!(reload_impact("bot.status"), ReloadImpact::Live);
+        assert_eq!(reload_impact("thread.board_channel_id"), ReloadImpact::Live);
+    }
+
+    #[test]
+    fn env_names_resolve_to_config_fields_by_type() {
+        let template = default_config_table();
+        let path = |name: &str| resolve_env_path(&template, name, false);
+
+        assert_eq!(path("bot_panel_port").unwrap(), ["bot", "panel_port"]);
+        assert_eq!(
+            path("bot_mode_guild_id").unwrap(),
+            ["bot", "mode", "guild_id"]
+        );
+        assert_eq!(
+            path("error_handling_show_detailed_errors").unwrap(),
+            ["error_handling", "show_detailed_errors"]
+        );
+        assert_eq!(
+            path("thread_board_channel_id").unwrap(),
+            ["thread", "board_channel_id"]
+        );
+        assert_eq!(path("logging_level").unwrap(), ["logging", "level"]);
+        assert!(path("database_url").is_none());
+        assert!(path("port").is_none());
+
+        let value = |name: &str, raw: &str| {
+            parse_env_value(raw, table_get(&template, &path(name).unwrap()))
+        };
+        assert_eq!(
+            value("bot_panel_port", "8080"),
+            Ok(toml::Value::Integer(8080))
+        );
+        assert_eq!(
+            value("bot_status", "42"),
+            Ok(toml::Value::String("42".into()))
+        );
+        assert_eq!(
+            value("bot_enable_panel", "yes"),
+            Ok(toml::Value::Boolean(true))
+        );
+        assert!(value("bot_enable_panel", "maybe").is_err());
+        assert_eq!(
+            value("bot_panel_super_admin_users", "1, 2"),
+            Ok(toml::Value::Array(vec![1.into(), 2.into()]))
+        );
+        assert_eq!(
+            value("bot_logs_channel_id", "7"),
+            Ok(toml::Value::Integer(7))
+        );
+    }
+}
@@ -1,12 +1,53 @@
-use crate::db::repr::{ApiKey, Permission};
+use crate::db::repr::{ApiKey, ApiKeyRequestLog, Permission};
 use chrono::Utc;
 use hex;
+use ipnet::IpNet;
 use rand::RngExt;
 use sha2::{Digest, Sha256};
+use sqlx::sqlite::SqliteRow;
 use sqlx::{Row, SqlitePool};
+use std::net::IpAddr;
 
 const API_KEY_PREFIX: &str = "rustmail";
 const API_KEY_LENGTH: usize = 32;
+const API_KEY_REQUEST_LOG_KEEP: i64 = 1000;
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct ApiKeyRestrictions {
+    #[serde(default)]
+    pub rate_limit_per_minute: Option<i64>,
+    #[serde(default)]
+    pub rate_limit_burst: Option<i64>,
+    #[serde(default)]
+    pub allowed_cidrs: Vec<String>,
+    #[serde(default)]
+    pub allowed_category_ids: Vec<String>,
+}
+
+fn row_to_api_key(row: &SqliteRow) -> Result<ApiKey, String> {
+    let permissions_json: String = row.get("permissions");
+    let permissions: Vec<Permission> = serde_json::from_str(&permissions_json)
+        .map_err(|e| format!("Failed to deserialize permissions: {}", e))?;
+    let allowed_cidrs: Vec<String> =
+        serde_json::from_str(&row.get::<String, _>("allowed_cidrs")).unwrap_or_default();
+    let allowed_category_ids: Vec<String> =
+        serde_json::from_str(&row.get::<String, _>("allowed_category_ids")).unwrap_or_default();
+
+    Ok(ApiKey {
+        id: row.get("id"),
+        key_hash: row.get("key_hash"),
+        name: row.get("name"),
+        permissions,
+        created_at: row.get("created_at"),
+        expires_at: row.get("expires_at"),
+        last_used_at: row.get("last_used_at"),
+        is_active: row.get::<i64, _>("is_active") == 1,
+        rate_limit_per_minute: row.get("rate_limit_per_minute"),
+        rate_limit_burst: row.get("rate_limit_burst"),
+        allowed_cidrs,
+        allowed_category_ids,
+    })
+}
 
 pub fn generate_api_key() -> Result<(String, String), String> {
     let mut rng = rand::rng();
@@ -36,26 +77,44 @@ pub fn _validate_api_key(key: &str, stored_hash: &str) -> bool {
         == 1
 }
 
+pub fn parse_cidr(value: &str) -> Option<IpNet> {
+    let value = value.trim();
+    value
+        .parse::<IpNet>()
+        .ok()
+        .or_else(|| value.parse::<IpAddr>().ok().map(IpNet::from))
+}
+
 pub async fn create_api_key(
     pool: &SqlitePool,
     key_hash: String,
     name: String,
     permissions: Vec<Permission>,
     expires_at: Option<i64>,
+    restrictions: ApiKeyRestrictions,
 ) -> Result<ApiKey, String> {
     let created_at = Utc::now().timestamp();
     let permissions_json = serde_json::to_string(&permissions)
         .map_err(|e| format!("Failed to serialize permissions: {}", e))?;
+    let cidrs_json = serde_json::to_string(&restrictions.allowed_cidrs)
+        .map_err(|e| format!("Failed to serialize allowed CIDRs: {}", e))?;
+    let categories_json = serde_json::to_string(&restrictions.allowed_category_ids)
+        .map_err(|e| format!("Failed to serialize allowed categories: {}", e))?;
 
     let result = sqlx::query(
-        "INSERT INTO api_keys (key_hash, name, permissions, created_at, expires_at, is_active)
-         VALUES (?, ?, ?, ?, ?, 1)",
+        "INSERT INTO api_keys (key_hash, name, permissions, created_at, expires_at, is_active,
+                               rate_limit_per_minute, rate_limit_burst, allowed_cidrs, allowed_category_ids)
+         VALUES (?, ?, ?, ?, ?, 1, ?, ?, ?, ?)",
     )
     .bind(&key_hash)
     .bind(&name)
     .bind(&permissions_json)
     .bind(created_at)
     .bind(expires_at)
+    .bind(restrictions.rate_limit_per_minute)
+    .bind(restrictions.rate_limit_burst)
+    .bind(&cidrs_json)
+    .bind(&categories_json)
     .execute(pool)
     .await
     .map_err(|e| format!("Failed to insert API key: {}", e))?;
@@ -71,6 +130,10 @@ pub async fn create_api_key(
         expires_at,
         last_used_at: None,
         is_active: true,
+        rate_limit_per_minute: restrictions.rate_limit_per_minute,
+        rate_limit_burst: restrictions.rate_limit_burst,
+        allowed_cidrs: restrictions.allowed_cidrs,
+        allowed_category_ids: restrictions.allowed_category_ids,
     })
 }
 
@@ -79,7 +142,8 @@ pub async fn get_api_key_by_hash(
     key_hash: &str,
 ) -> Result<Option<ApiKey>, String> {
     let row = sqlx::query(
-        "SELECT id, key_hash, name, permissions, created_at, expires_at, last_used_at, is_active
+        "SELECT id, key_hash, name, permissions, created_at, expires_at, last_used_at, is_active,
+                rate_limit_per_minute, rate_limit_burst, allowed_cidrs, allowed_category_ids
          FROM api_keys WHERE key_hash = ? AND is_active = 1",
     )
     .bind(key_hash)
@@ -87,55 +151,20 @@ pub async fn get_api_key_by_hash(
     .await
     .map_err(|e| format!("Failed to fetch API key: {}", e))?;
 
-    match row {
-        Some(row) => {
-            let permissions_json: String = row.get("permissions");
-            let permissions: Vec<Permission> = serde_json::from_str(&permissions_json)
-                .map_err(|e| format!("Failed to deserialize permissions: {}", e))?;
-
-            Ok(Some(ApiKey {
-                id: row.get("id"),
-                key_hash: row.get("key_hash"),
-                name: row.get("name"),
-                permissions,
-                created_at: row.get("created_at"),
-                expires_at: row.get("expires_at"),
-                last_used_at: row.get("last_used_at"),
-                is_active: row.get::<i64, _>("is_active") == 1,
-            }))
-        }
-        None => Ok(None),
-    }
+    row.as_ref().map(row_to_api_key).transpose()
 }
 
 pub async fn list_api_keys(pool: &SqlitePool) -> Result<Vec<ApiKey>, String> {
     let rows = sqlx::query(
-        "SELECT id, key_hash, name, permissions, created_at, expires_at, last_used_at, is_active
+        "SELECT id, key_hash, name, permissions, created_at, expires_at, last_used_at, is_active,
+                rate_limit_per_minute, rate_limit_burst, allowed_cidrs, allowed_category_ids
          FROM api_keys ORDER BY created_at DESC",
     )
     .fetch_all(pool)
     .await
     .map_err(|e| format!("Failed to fetch API keys: {}", e))?;
 
-    let mut keys = Vec::new();
-    for row in rows {
-        let permissions_json: String = row.get("permissions");
-        let permissions: Vec<Permission> = serde_json::from_str(&permissions_json)
-            .map_err(|e| format!("Failed to deserialize permissions: {}", e))?;
-
-        keys.push(ApiKey {
-            id: row.get("id"),
-            key_hash: row.get("key_hash"),
-            name: row.get("name"),
-            permissions,
-            created_at: row.get("created_at"),
-            expires_at: row.get("expires_at"),
-            last_used_at: row.get("last_used_at"),
-            is_active: row.get::<i64, _>("is_active") == 1,
-        });
-    }
-
-    Ok(keys)
+    rows.iter().map(row_to_api_key).collect()
 }
 
 pub async fn revoke_api_key(pool: &SqlitePool, id: i64) -> Result<(), String> {
@@ -148,7 +177,40 @@ pub async fn revoke_api_key(pool: &SqlitePool, id: i64) -> Result<(), String> {
     Ok(())
 }
 
+pub async fn update_api_key_restrictions(
+    pool: &SqlitePool,
+    id: i64,
+    restrictions: &ApiKeyRestrictions,
+) -> Result<bool, String> {
+    let cidrs_json = serde_json::to_string(&restrictions.allowed_cidrs)
+        .map_err(|e| format!("Failed to serialize allowed CIDRs: {}", e))?;
+    let categories_json = serde_json::to_string(&restrictions.allowed_category_ids)
+        .map_err(|e| format!("Failed to serialize allowed categories: {}", e))?;
+
+    let result = sqlx::query(
+        "UPDATE api_keys
+         SET rate_limit_per_minute = ?, rate_limit_burst = ?, allowed_cidrs = ?, allowed_category_ids = ?
+         WHERE id = ?",
+    )
+    .bind(restrictions.rate_limit_per_minute)
+    .bind(restrictions.rate_limit_burst)
+    .bind(&cidrs_json)
+    .bind(&categories_json)
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to update API key restrictions: {}", e))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
 pub async fn delete_api_key(pool: &SqlitePool, id: i64) -> Result<(), String> {
+    sqlx::query("DELETE FROM api_key_requests WHERE api_key_id = ?")
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| format!("Failed to delete API key request logs: {}", e))?;
+
     sqlx::query("DELETE FROM api_keys WHERE id = ?")
         .bind(id)
         .execute(pool)
@@ -171,6 +233,77 @@ pub async fn update_last_used(pool: &SqlitePool, id: i64) -> Result<(), String>
     Ok(())
 }
 
+pub async fn insert_api_key_request(
+    pool: &SqlitePool,
+    api_key_id: i64,
+    method: &str,
+    route: &str,
+    status: u16,
+    latency_ms: i64,
+    ip: Option<&str>,
+) -> Result<(), String> {
+    sqlx::query(
+        "INSERT INTO api_key_requests (api_key_id, method, route, status, latency_ms, ip, created_at)
+         VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(api_key_id)
+    .bind(method)
+    .bind(route)
+    .bind(status as i64)
+    .bind(latency_ms)
+    .bind(ip)
+    .bind(Utc::now().timestamp())
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to insert API key request log: {}", e))?;
+
+    sqlx::query(
+        "DELETE FROM api_key_requests
+         WHERE api_key_id = ?1 AND id <= (
+             SELECT id FROM api_key_requests WHERE api_key_id = ?1
+             ORDER BY id DESC LIMIT 1 OFFSET ?2
+         )",
+    )
+    .bind(api_key_id)
+    .bind(API_KEY_REQUEST_LOG_KEEP)
+    .execute(pool)
+    .await
+    .map_err(|e| format!("Failed to prune API key request logs: {}", e))?;
+
+    Ok(())
+}
+
+pub async fn list_api_key_requests(
+    pool: &SqlitePool,
+    api_key_id: i64,
+    limit: i64,
+) -> Result<Vec<ApiKeyRequestLog>, String> {
+    let rows = sqlx::query(
+        "SELECT id, api_key_id, method, route, status, latency_ms, ip, created_at
+         FROM api_key_requests WHERE api_key_id = ?
+         ORDER BY id DESC LIMIT ?",
+    )
+    .bind(api_key_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| format!("Failed to fetch API key request logs: {}", e))?;
+
+    Ok(rows
+        .iter()
+        .map(|row| ApiKeyRequestLog {
+            id: row.get("id"),
+            api_key_id: row.get("api_key_id"),
+            method: row.get("method"),
+            route: row.get("route"),
+            status: row.get("status"),
+            latency_ms: row.get("latency_ms"),
+            ip: row.get("ip"),
+            created_at: row.get("created_at"),
+        })
+        .collect())
+}
+
 impl ApiKey {
     pub fn has_permission(&self, permission: Permission) -> bool {
         self.permissions.contains(&permission)
@@ -188,4 +321,20 @@ impl ApiKey {
     pub fn is_valid(&self) -> bool {
         self.is_active && !self.is_expired()
     }
+
+    pub fn allows_ip(&self, ip: IpAddr) -> bool {
+        self.allowed_cidrs.is_empty()
+            || self
+                .allowed_cidrs
+                .iter()
+                .filter_map(|cidr| parse_cidr(cidr))
+                .any(|net| net.contains(&ip))
+    }
+
+    pub fn allows_category(&self, category_id: Option<&str>) -> bool {
+        if self.allowed_category_ids.is_empty() {
+            return true;
+        }
+        category_id.is_some_and(|id| self.allowed_category_ids.iter().any(|c| c == id))
+    }
 }
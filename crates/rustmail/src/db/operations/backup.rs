@@ -0,0 +1,48 @@
+use crate::config::resolve_db_path;
+use crate::prelude::errors::*;
+use sqlx::SqlitePool;
+use std::path::{Path, PathBuf};
+use tracing::error;
+
+pub fn default_backup_dir() -> PathBuf {
+    let db_path = resolve_db_path("db/db.sqlite");
+    Path::new(&db_path)
+        .parent()
+        .map(|p| p.join("backups"))
+        .unwrap_or_else(|| PathBuf::from("backups"))
+}
+
+pub fn default_backup_path() -> PathBuf {
+    default_backup_dir().join(format!(
+        "rustmail-{}.sqlite",
+        chrono::Utc::now().format("%Y%m%d-%H%M%S")
+    ))
+}
+
+pub async fn backup_database(target: &Path, pool: &SqlitePool) -> ModmailResult<u64> {
+    if target.exists() {
+        return Err(validation_failed(&format!(
+            "{} already exists",
+            target.display()
+        )));
+    }
+    if let Some(parent) = target.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent).map_err(|e| {
+            error!("Failed to create backup directory: {e}");
+            validation_failed("Failed to create backup directory")
+        })?;
+    }
+
+    sqlx::query("VACUUM INTO ?")
+        .bind(target.to_string_lossy().to_string())
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to back up database: {e}");
+            validation_failed("Failed to back up database")
+        })?;
+
+    Ok(std::fs::metadata(target).map(|m| m.len()).unwrap_or(0))
+}
@@ -1,6 +1,7 @@
 use crate::db::repr::{BannedUser, TrackedMember};
 use crate::prelude::errors::*;
 use sqlx::{Row, SqlitePool};
+use tracing::error;
 
 fn row_to_tracked_member(row: sqlx::sqlite::SqliteRow) -> TrackedMember {
     let roles_json: String = row.get("roles");
@@ -74,7 +75,7 @@ pub async fn upsert_tracked_member(member: &TrackedMember, pool: &SqlitePool) ->
     .execute(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to upsert tracked member: {e:?}");
+        error!("Failed to upsert tracked member: {e:?}");
         validation_failed("Failed to upsert tracked member")
     })?;
 
@@ -101,7 +102,7 @@ pub async fn bulk_upsert_tracked_members(
     const CHUNK_SIZE: usize = 99;
 
     let mut tx = pool.begin().await.map_err(|e| {
-        eprintln!("Failed to begin tracked members transaction: {e:?}");
+        error!("Failed to begin tracked members transaction: {e:?}");
         validation_failed("Failed to begin tracked members transaction")
     })?;
 
@@ -137,13 +138,13 @@ pub async fn bulk_upsert_tracked_members(
         );
 
         builder.build().execute(&mut *tx).await.map_err(|e| {
-            eprintln!("Failed to bulk upsert tracked members chunk: {e:?}");
+            error!("Failed to bulk upsert tracked members chunk: {e:?}");
             validation_failed("Failed to bulk upsert tracked members chunk")
         })?;
     }
 
     tx.commit().await.map_err(|e| {
-        eprintln!("Failed to commit tracked members transaction: {e:?}");
+        error!("Failed to commit tracked members transaction: {e:?}");
         validation_failed("Failed to commit tracked members transaction")
     })?;
 
@@ -168,7 +169,7 @@ pub async fn get_tracked_member(
     .fetch_optional(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to fetch tracked member: {e:?}");
+        error!("Failed to fetch tracked member: {e:?}");
         validation_failed("Failed to fetch tracked member")
     })?;
 
@@ -186,7 +187,7 @@ pub async fn delete_tracked_member(
         .execute(pool)
         .await
         .map_err(|e| {
-            eprintln!("Failed to delete tracked member: {e:?}");
+            error!("Failed to delete tracked member: {e:?}");
             validation_failed("Failed to delete tracked member")
         })?;
     Ok(())
@@ -230,7 +231,7 @@ pub async fn save_banned_user(user: &BannedUser, pool: &SqlitePool) -> ModmailRe
     .execute(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to save banned user: {e:?}");
+        error!("Failed to save banned user: {e:?}");
         validation_failed("Failed to save banned user")
     })?;
 
@@ -255,7 +256,7 @@ pub async fn get_banned_user_by_id(
     .fetch_optional(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to fetch banned user: {e:?}");
+        error!("Failed to fetch banned user: {e:?}");
         validation_failed("Failed to fetch banned user")
     })?;
 
@@ -287,7 +288,7 @@ pub async fn find_banned_users_by_username(
     .fetch_all(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to search banned users: {e:?}");
+        error!("Failed to search banned users: {e:?}");
         validation_failed("Failed to search banned users")
     })?;
 
@@ -311,7 +312,7 @@ pub async fn get_all_banned_users(
     .fetch_all(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to fetch all banned users: {e:?}");
+        error!("Failed to fetch all banned users: {e:?}");
         validation_failed("Failed to fetch all banned users")
     })?;
 
@@ -0,0 +1,165 @@
+use crate::db::operations::expedite_job;
+use crate::db::repr::{BoardTicket, JobKind, TicketPriority, TicketWorkflowState};
+use crate::prelude::errors::*;
+use crate::prelude::types::*;
+use chrono::Utc;
+use sqlx::{Row, SqlitePool};
+use tracing::error;
+
+pub const TICKET_BOARD_KEY: &str = "board";
+
+const TICKET_BOARD_DEBOUNCE_SECS: i64 = 3;
+
+pub async fn request_board_refresh(pool: &SqlitePool) {
+    let run_at = Utc::now().timestamp() + TICKET_BOARD_DEBOUNCE_SECS;
+    if let Err(e) = expedite_job(JobKind::TicketBoardRefresh, TICKET_BOARD_KEY, run_at, pool).await
+    {
+        error!("Failed to schedule ticket board refresh: {e:?}");
+    }
+}
+
+pub async fn list_board_tickets(pool: &SqlitePool) -> ModmailResult<Vec<BoardTicket>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT t.id,
+               t.channel_id,
+               t.user_name,
+               COALESCE(c.name, NULLIF(t.category_name, '')) AS category_name,
+               t.priority,
+               t.workflow_state,
+               ts.taken_by,
+               ts.last_message_by,
+               ts.last_message_at,
+               COALESCE(t.state_changed_at, ts.last_message_at) AS state_changed_at
+        FROM threads t
+        JOIN thread_status ts ON ts.thread_id = t.id
+        LEFT JOIN ticket_categories c ON c.id = t.ticket_category_id
+        WHERE t.status = 1
+          AND NOT EXISTS (
+              SELECT 1 FROM ticket_category_access a WHERE a.category_id = t.ticket_category_id
+          )
+        "#,
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list board tickets: {e:?}");
+        validation_failed("Failed to list board tickets")
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| BoardTicket {
+            thread_id: row.get("id"),
+            channel_id: row.get("channel_id"),
+            user_name: row.get("user_name"),
+            category_name: row.get("category_name"),
+            priority: TicketPriority::parse(&row.get::<String, _>("priority")).unwrap_or_default(),
+            workflow_state: TicketWorkflowState::parse(&row.get::<String, _>("workflow_state"))
+                .unwrap_or(TicketWorkflowState::Open),
+            taken_by: row.get("taken_by"),
+            last_message_by: TicketAuthor::from_str(&row.get::<String, _>("last_message_by")),
+            last_message_at: row.get("last_message_at"),
+            state_changed_at: row.get("state_changed_at"),
+        })
+        .collect())
+}
+
+pub async fn list_board_messages(pool: &SqlitePool) -> ModmailResult<Vec<(i64, String, String)>> {
+    let rows = sqlx::query(
+        "SELECT page, channel_id, message_id FROM ticket_board_messages ORDER BY page ASC",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list board messages: {e:?}");
+        validation_failed("Failed to list board messages")
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            (
+                row.get("page"),
+                row.get("channel_id"),
+                row.get("message_id"),
+            )
+        })
+        .collect())
+}
+
+pub async fn set_board_message(
+    page: i64,
+    channel_id: &str,
+    message_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO ticket_board_messages (page, channel_id, message_id) VALUES (?, ?, ?)
+        ON CONFLICT(page) DO UPDATE SET channel_id = excluded.channel_id, message_id = excluded.message_id
+        "#,
+    )
+    .bind(page)
+    .bind(channel_id)
+    .bind(message_id)
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to store board message: {e:?}");
+        validation_failed("Failed to store board message")
+    })?;
+    Ok(())
+}
+
+pub async fn delete_board_message(page: i64, pool: &SqlitePool) -> ModmailResult<()> {
+    sqlx::query("DELETE FROM ticket_board_messages WHERE page = ?")
+        .bind(page)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to delete board message: {e:?}");
+            validation_failed("Failed to delete board message")
+        })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::operations::init::{insert_test_ticket, test_pool};
+
+    async fn insert_open_ticket(id: &str, category_id: Option<&str>, pool: &SqlitePool) {
+        insert_test_ticket(id, id.parse().unwrap(), None, category_id, pool).await;
+        sqlx::query(
+            "INSERT INTO thread_status (thread_id, channel_id, owner_id, last_message_by, last_message_at) VALUES (?1, ?1, ?1, 'user', 0)",
+        )
+        .bind(id)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn board_leaves_out_restricted_categories() {
+        let pool = test_pool().await;
+        insert_open_ticket("1", None, &pool).await;
+        insert_open_ticket("2", Some("open"), &pool).await;
+        insert_open_ticket("3", Some("restricted"), &pool).await;
+        sqlx::query(
+            "INSERT INTO ticket_category_access (category_id, target_type, target_id) VALUES ('restricted', 'role', '5')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let mut ids: Vec<String> = list_board_tickets(&pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|t| t.thread_id)
+            .collect();
+        ids.sort();
+        assert_eq!(ids, ["1", "2"]);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::db::repr::AccessList;
+use crate::prelude::errors::*;
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+use tracing::error;
+
+pub async fn get_command_permissions(
+    command_name: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<AccessList> {
+    let rows = sqlx::query(
+        r#"
+        SELECT target_type, target_id FROM command_permissions
+        WHERE command_name = ?
+        ORDER BY target_type, target_id
+        "#,
+    )
+    .bind(command_name)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to fetch command permissions: {e:?}");
+        validation_failed("Failed to fetch command permissions")
+    })?;
+
+    let mut list = AccessList::default();
+    for row in rows {
+        list.push(&row.get::<String, _>("target_type"), row.get("target_id"));
+    }
+    Ok(list)
+}
+
+pub async fn list_command_permissions(
+    pool: &SqlitePool,
+) -> ModmailResult<HashMap<String, AccessList>> {
+    let rows = sqlx::query(
+        "SELECT command_name, target_type, target_id FROM command_permissions ORDER BY target_id",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list command permissions: {e:?}");
+        validation_failed("Failed to list command permissions")
+    })?;
+
+    let mut lists: HashMap<String, AccessList> = HashMap::new();
+    for row in rows {
+        lists
+            .entry(row.get("command_name"))
+            .or_default()
+            .push(&row.get::<String, _>("target_type"), row.get("target_id"));
+    }
+    Ok(lists)
+}
+
+pub async fn set_command_permissions(
+    command_name: &str,
+    access: &AccessList,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    let mut tx = pool.begin().await.map_err(|e| {
+        error!("Failed to begin command permissions tx: {e:?}");
+        validation_failed("Failed to save command permissions")
+    })?;
+
+    sqlx::query("DELETE FROM command_permissions WHERE command_name = ?")
+        .bind(command_name)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("Failed to clear command permissions: {e:?}");
+            validation_failed("Failed to save command permissions")
+        })?;
+
+    let targets = access
+        .role_ids
+        .iter()
+        .map(|id| ("role", id))
+        .chain(access.user_ids.iter().map(|id| ("user", id)));
+    for (target_type, target_id) in targets {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO command_permissions (command_name, target_type, target_id)
+            VALUES (?, ?, ?)
+            "#,
+        )
+        .bind(command_name)
+        .bind(target_type)
+        .bind(target_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("Failed to insert command permission: {e:?}");
+            validation_failed("Failed to save command permissions")
+        })?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        error!("Failed to commit command permissions: {e:?}");
+        validation_failed("Failed to save command permissions")
+    })?;
+    Ok(())
+}
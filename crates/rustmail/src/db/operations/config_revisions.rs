@@ -0,0 +1,124 @@
+use crate::db::repr::{ConfigRevision, ConfigRevisionOrigin, ConfigRevisionSource};
+use crate::prelude::errors::*;
+use chrono::Utc;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use tracing::error;
+
+fn row_to_config_revision(row: SqliteRow) -> ConfigRevision {
+    ConfigRevision {
+        id: row.get("id"),
+        snapshot: row.get("snapshot"),
+        source: ConfigRevisionSource::parse(&row.get::<String, _>("source"))
+            .unwrap_or(ConfigRevisionSource::File),
+        author_id: row.get("author_id"),
+        rollback_of: row.get("rollback_of"),
+        created_at: row.get("created_at"),
+    }
+}
+
+pub async fn insert_config_revision(
+    snapshot: &str,
+    origin: &ConfigRevisionOrigin,
+    pool: &SqlitePool,
+) -> ModmailResult<i64> {
+    let result = sqlx::query(
+        "INSERT INTO config_revisions (snapshot, source, author_id, rollback_of, created_at) VALUES (?, ?, ?, ?, ?)",
+    )
+    .bind(snapshot)
+    .bind(origin.source.as_str())
+    .bind(&origin.author_id)
+    .bind(origin.rollback_of)
+    .bind(Utc::now().timestamp())
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to record config revision: {e:?}");
+        validation_failed("Failed to record config revision")
+    })?;
+
+    Ok(result.last_insert_rowid())
+}
+
+pub async fn get_config_revision(
+    id: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<Option<ConfigRevision>> {
+    let row = sqlx::query(
+        "SELECT id, snapshot, source, author_id, rollback_of, created_at FROM config_revisions WHERE id = ?",
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to get config revision: {e:?}");
+        validation_failed("Failed to get config revision")
+    })?;
+
+    Ok(row.map(row_to_config_revision))
+}
+
+pub async fn get_latest_config_revision(
+    pool: &SqlitePool,
+) -> ModmailResult<Option<ConfigRevision>> {
+    let row = sqlx::query(
+        "SELECT id, snapshot, source, author_id, rollback_of, created_at FROM config_revisions ORDER BY id DESC LIMIT 1",
+    )
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to get latest config revision: {e:?}");
+        validation_failed("Failed to get latest config revision")
+    })?;
+
+    Ok(row.map(row_to_config_revision))
+}
+
+pub async fn get_previous_config_revision(
+    id: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<Option<ConfigRevision>> {
+    let row = sqlx::query(
+        "SELECT id, snapshot, source, author_id, rollback_of, created_at FROM config_revisions WHERE id < ? ORDER BY id DESC LIMIT 1",
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to get previous config revision: {e:?}");
+        validation_failed("Failed to get previous config revision")
+    })?;
+
+    Ok(row.map(row_to_config_revision))
+}
+
+pub async fn list_config_revisions(
+    limit: i64,
+    offset: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<(Vec<ConfigRevision>, i64)> {
+    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM config_revisions")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to count config revisions: {e:?}");
+            validation_failed("Failed to count config revisions")
+        })?;
+
+    let rows = sqlx::query(
+        "SELECT id, snapshot, source, author_id, rollback_of, created_at FROM config_revisions ORDER BY id DESC LIMIT ? OFFSET ?",
+    )
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list config revisions: {e:?}");
+        validation_failed("Failed to list config revisions")
+    })?;
+
+    Ok((
+        rows.into_iter().map(row_to_config_revision).collect(),
+        total,
+    ))
+}
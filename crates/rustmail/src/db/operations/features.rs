@@ -37,3 +37,50 @@ pub async fn upsert_feature_message(
     .await?;
     Ok(())
 }
+
+pub async fn delete_feature_message(
+    feature_key: &str,
+    pool: &SqlitePool,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM features_messages WHERE feature_key = ?")
+        .bind(feature_key)
+        .execute(pool)
+        .await?;
+    Ok(())
+}
+
+pub async fn get_feature_state(
+    feature_key: &str,
+    pool: &SqlitePool,
+) -> Result<Option<(bool, String)>, sqlx::Error> {
+    sqlx::query_as::<_, (bool, String)>(
+        "SELECT enabled, settings FROM feature_settings WHERE feature_key = ?",
+    )
+    .bind(feature_key)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn upsert_feature_state(
+    feature_key: &str,
+    enabled: bool,
+    settings: &str,
+    pool: &SqlitePool,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        INSERT INTO feature_settings (feature_key, enabled, settings, updated_at)
+        VALUES (?, ?, ?, CAST(strftime('%s', 'now') AS INTEGER))
+        ON CONFLICT(feature_key) DO UPDATE SET
+            enabled = excluded.enabled,
+            settings = excluded.settings,
+            updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(feature_key)
+    .bind(enabled)
+    .bind(settings)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
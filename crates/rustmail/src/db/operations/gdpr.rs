@@ -0,0 +1,539 @@
+use crate::db::repr::{DataRequestAction, DataSubjectRequest, ErasureMode};
+use crate::prelude::errors::*;
+use chrono::Utc;
+use serde_json::{Map, Value};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Column, Row, SqlitePool, TypeInfo, ValueRef};
+use std::collections::BTreeMap;
+use tracing::error;
+
+macro_rules! user_threads {
+    () => {
+        "(SELECT id FROM threads WHERE user_id = CAST(?1 AS INTEGER))"
+    };
+}
+
+const USER_DATA_QUERIES: [(&str, &str); 18] = [
+    (
+        "threads",
+        "SELECT * FROM threads WHERE user_id = CAST(?1 AS INTEGER) ORDER BY created_at",
+    ),
+    (
+        "thread_messages",
+        concat!(
+            "SELECT * FROM thread_messages WHERE thread_id IN ",
+            user_threads!(),
+            " OR user_id = CAST(?1 AS INTEGER) ORDER BY created_at, id"
+        ),
+    ),
+    (
+        "thread_status",
+        concat!(
+            "SELECT * FROM thread_status WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "thread_form_answers",
+        concat!(
+            "SELECT * FROM thread_form_answers WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "thread_state_history",
+        concat!(
+            "SELECT * FROM thread_state_history WHERE thread_id IN ",
+            user_threads!(),
+            " OR changed_by = ?1 ORDER BY id"
+        ),
+    ),
+    (
+        "scheduled_closures",
+        concat!(
+            "SELECT * FROM scheduled_closures WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "imported_records",
+        concat!(
+            "SELECT * FROM imported_records WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "reminders",
+        concat!(
+            "SELECT * FROM reminders WHERE user_id = CAST(?1 AS INTEGER) OR thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "reminder_optouts",
+        "SELECT * FROM reminder_optouts WHERE user_id = CAST(?1 AS INTEGER)",
+    ),
+    (
+        "staff_alerts",
+        "SELECT * FROM staff_alerts WHERE staff_user_id = CAST(?1 AS INTEGER) OR thread_user_id = CAST(?1 AS INTEGER)",
+    ),
+    (
+        "pending_category_selections",
+        "SELECT * FROM pending_category_selections WHERE user_id = CAST(?1 AS INTEGER)",
+    ),
+    (
+        "tracked_members",
+        "SELECT * FROM tracked_members WHERE user_id = ?1",
+    ),
+    (
+        "banned_users",
+        "SELECT * FROM banned_users WHERE user_id = ?1 OR banned_by = ?1",
+    ),
+    (
+        "blocked_users",
+        "SELECT * FROM blocked_users WHERE user_id = ?1",
+    ),
+    ("poll_votes", "SELECT * FROM poll_votes WHERE user_id = ?1"),
+    (
+        "sessions_panel",
+        "SELECT public_id, created_at, last_seen_at, expires_at, ip, user_agent FROM sessions_panel WHERE user_id = ?1",
+    ),
+    (
+        "panel_permissions",
+        "SELECT * FROM panel_permissions WHERE subject_type = 'user' AND subject_id = ?1",
+    ),
+    (
+        "archived_staff_stats",
+        "SELECT * FROM archived_staff_stats WHERE user_id = ?1",
+    ),
+];
+
+// These need the tickets still linked to the user, so they run before `threads` is updated.
+const ANONYMIZE_STATEMENTS: [(&str, &str); 5] = [
+    (
+        "thread_form_answers",
+        concat!(
+            "DELETE FROM thread_form_answers WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "thread_status",
+        concat!(
+            "UPDATE thread_status SET owner_id = '0' WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "thread_messages",
+        concat!(
+            "UPDATE thread_messages SET user_id = 0, user_name = 'Deleted user', content = '[erased]', dm_message_id = NULL WHERE user_id = CAST(?1 AS INTEGER) AND thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "imported_records",
+        concat!(
+            "DELETE FROM imported_records WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "threads",
+        "UPDATE threads SET user_id = 0, user_name = 'Deleted user' WHERE user_id = CAST(?1 AS INTEGER)",
+    ),
+];
+
+// `thread_messages` and `thread_status` restrict the deletion of a ticket, so they go first.
+const DELETE_STATEMENTS: [(&str, &str); 8] = [
+    (
+        "thread_messages",
+        concat!(
+            "DELETE FROM thread_messages WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "thread_status",
+        concat!(
+            "DELETE FROM thread_status WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "thread_form_answers",
+        concat!(
+            "DELETE FROM thread_form_answers WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "thread_state_history",
+        concat!(
+            "DELETE FROM thread_state_history WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "scheduled_closures",
+        concat!(
+            "DELETE FROM scheduled_closures WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "reminders",
+        concat!("DELETE FROM reminders WHERE thread_id IN ", user_threads!()),
+    ),
+    (
+        "imported_records",
+        concat!(
+            "DELETE FROM imported_records WHERE thread_id IN ",
+            user_threads!()
+        ),
+    ),
+    (
+        "threads",
+        "DELETE FROM threads WHERE user_id = CAST(?1 AS INTEGER)",
+    ),
+];
+
+// Messages the user wrote in other tickets as staff keep their content.
+const ERASE_STATEMENTS: [(&str, &str); 14] = [
+    (
+        "thread_messages",
+        "UPDATE thread_messages SET user_id = 0, user_name = 'Deleted user' WHERE user_id = CAST(?1 AS INTEGER)",
+    ),
+    (
+        "thread_state_history",
+        "UPDATE thread_state_history SET changed_by = NULL WHERE changed_by = ?1",
+    ),
+    (
+        "reminders",
+        "DELETE FROM reminders WHERE user_id = CAST(?1 AS INTEGER)",
+    ),
+    (
+        "reminder_optouts",
+        "DELETE FROM reminder_optouts WHERE user_id = CAST(?1 AS INTEGER)",
+    ),
+    (
+        "staff_alerts",
+        "DELETE FROM staff_alerts WHERE staff_user_id = CAST(?1 AS INTEGER) OR thread_user_id = CAST(?1 AS INTEGER)",
+    ),
+    (
+        "pending_category_selections",
+        "DELETE FROM pending_category_selections WHERE user_id = CAST(?1 AS INTEGER)",
+    ),
+    (
+        "tracked_members",
+        "DELETE FROM tracked_members WHERE user_id = ?1",
+    ),
+    (
+        "banned_users",
+        "DELETE FROM banned_users WHERE user_id = ?1",
+    ),
+    (
+        "banned_users",
+        "UPDATE banned_users SET banned_by = NULL WHERE banned_by = ?1",
+    ),
+    (
+        "blocked_users",
+        "DELETE FROM blocked_users WHERE user_id = ?1",
+    ),
+    ("poll_votes", "DELETE FROM poll_votes WHERE user_id = ?1"),
+    (
+        "sessions_panel",
+        "DELETE FROM sessions_panel WHERE user_id = ?1",
+    ),
+    (
+        "panel_permissions",
+        "DELETE FROM panel_permissions WHERE subject_type = 'user' AND subject_id = ?1",
+    ),
+    (
+        "archived_staff_stats",
+        "DELETE FROM archived_staff_stats WHERE user_id = ?1",
+    ),
+];
+
+pub(crate) fn row_to_json(row: &SqliteRow) -> Value {
+    let mut object = Map::new();
+    for column in row.columns() {
+        let index = column.ordinal();
+        let value = match row.try_get_raw(index) {
+            Ok(raw) if raw.is_null() => Value::Null,
+            Ok(raw) => match raw.type_info().name() {
+                "INTEGER" => row.try_get::<i64, _>(index).map(Value::from),
+                "REAL" => row.try_get::<f64, _>(index).map(Value::from),
+                _ => row.try_get::<String, _>(index).map(Value::from),
+            }
+            .unwrap_or(Value::Null),
+            Err(_) => Value::Null,
+        };
+        object.insert(column.name().to_string(), value);
+    }
+    Value::Object(object)
+}
+
+pub async fn collect_user_data(
+    user_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<BTreeMap<String, Vec<Value>>> {
+    let mut data = BTreeMap::new();
+    for (table, query) in USER_DATA_QUERIES {
+        let rows = sqlx::query(query)
+            .bind(user_id)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| {
+                error!("Failed to collect {table} for a data export: {e:?}");
+                validation_failed("Failed to collect user data")
+            })?;
+        data.insert(table.to_string(), rows.iter().map(row_to_json).collect());
+    }
+
+    Ok(data)
+}
+
+pub async fn user_has_open_thread(user_id: &str, pool: &SqlitePool) -> ModmailResult<bool> {
+    sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM threads WHERE user_id = CAST(?1 AS INTEGER) AND status = 1)",
+    )
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to check for open tickets: {e:?}");
+        validation_failed("Failed to check for open tickets")
+    })
+}
+
+pub async fn erase_user_data(
+    user_id: &str,
+    mode: ErasureMode,
+    pool: &SqlitePool,
+) -> ModmailResult<BTreeMap<String, u64>> {
+    if user_has_open_thread(user_id, pool).await? {
+        return Err(validation_failed(
+            "The user has an open ticket, close it before erasing their data",
+        ));
+    }
+
+    let mut tx = pool.begin().await.map_err(|e| {
+        error!("Failed to begin erasure transaction: {e:?}");
+        validation_failed("Failed to begin erasure transaction")
+    })?;
+
+    let tickets: &[(&str, &str)] = match mode {
+        ErasureMode::Anonymize => &ANONYMIZE_STATEMENTS,
+        ErasureMode::Delete => &DELETE_STATEMENTS,
+    };
+    let mut summary = BTreeMap::new();
+    for (table, statement) in tickets.iter().chain(ERASE_STATEMENTS.iter()) {
+        let result = sqlx::query(*statement)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                error!("Failed to erase {table}: {e:?}");
+                validation_failed("Failed to erase user data")
+            })?;
+        *summary.entry(table.to_string()).or_insert(0) += result.rows_affected();
+    }
+
+    tx.commit().await.map_err(|e| {
+        error!("Failed to commit erasure transaction: {e:?}");
+        validation_failed("Failed to commit erasure transaction")
+    })?;
+
+    summary.retain(|_, count| *count > 0);
+    Ok(summary)
+}
+
+pub async fn insert_data_subject_request(
+    user_id: &str,
+    action: DataRequestAction,
+    mode: Option<ErasureMode>,
+    source: &str,
+    requested_by: &str,
+    summary: &BTreeMap<String, u64>,
+    pool: &SqlitePool,
+) -> ModmailResult<i64> {
+    let summary = serde_json::to_string(summary).unwrap_or_else(|_| "{}".to_string());
+    let result = sqlx::query(
+        "INSERT INTO data_subject_requests (user_id, action, mode, source, requested_by, summary, created_at) VALUES (?, ?, ?, ?, ?, ?, ?)",
+    )
+    .bind(user_id)
+    .bind(action.as_str())
+    .bind(mode.map(|m| m.as_str()))
+    .bind(source)
+    .bind(requested_by)
+    .bind(summary)
+    .bind(Utc::now().timestamp())
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to record data subject request: {e:?}");
+        validation_failed("Failed to record data subject request")
+    })?;
+
+    Ok(result.last_insert_rowid())
+}
+
+pub async fn list_data_subject_requests(
+    user_id: Option<&str>,
+    limit: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<Vec<DataSubjectRequest>> {
+    let rows = sqlx::query(
+        "SELECT id, user_id, action, mode, source, requested_by, summary, created_at FROM data_subject_requests WHERE ?1 IS NULL OR user_id = ?1 ORDER BY id DESC LIMIT ?2",
+    )
+    .bind(user_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list data subject requests: {e:?}");
+        validation_failed("Failed to list data subject requests")
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| DataSubjectRequest {
+            id: row.get("id"),
+            user_id: row.get("user_id"),
+            action: DataRequestAction::parse(&row.get::<String, _>("action"))
+                .unwrap_or(DataRequestAction::Export),
+            mode: row
+                .get::<Option<String>, _>("mode")
+                .as_deref()
+                .and_then(ErasureMode::parse),
+            source: row.get("source"),
+            requested_by: row.get("requested_by"),
+            summary: serde_json::from_str(&row.get::<String, _>("summary")).unwrap_or(Value::Null),
+            created_at: row.get("created_at"),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::operations::init::{insert_test_ticket, test_count, test_pool};
+
+    async fn insert_message(thread_id: &str, user_id: i64, content: &str, pool: &SqlitePool) {
+        sqlx::query(
+            "INSERT INTO thread_messages (thread_id, user_id, user_name, is_anonymous, content, thread_status) VALUES (?, ?, 'Someone', false, ?, 0)",
+        )
+        .bind(thread_id)
+        .bind(user_id)
+        .bind(content)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn seeded_pool() -> SqlitePool {
+        let pool = test_pool().await;
+        insert_test_ticket("10", 1, Some(0), None, &pool).await;
+        insert_message("10", 1, "my address", &pool).await;
+        sqlx::query(
+            "INSERT INTO thread_status (thread_id, channel_id, owner_id, last_message_by, last_message_at) VALUES ('10', 10, '1', 'user', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO imported_records (source, external_id, thread_id, imported_at) VALUES ('modmail', 'abc', '10', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        insert_test_ticket("20", 2, Some(0), None, &pool).await;
+        insert_message("20", 1, "staff answer", &pool).await;
+        pool
+    }
+
+    #[tokio::test]
+    async fn collect_user_data_includes_tickets_messages_and_imports() {
+        let pool = seeded_pool().await;
+
+        let data = collect_user_data("1", &pool).await.unwrap();
+
+        assert_eq!(data["threads"].len(), 1);
+        assert_eq!(data["thread_messages"].len(), 2);
+        assert_eq!(data["thread_status"].len(), 1);
+        assert_eq!(data["imported_records"][0]["external_id"], "abc");
+        assert!(data["reminders"].is_empty());
+    }
+
+    #[tokio::test]
+    async fn delete_erasure_removes_tickets_after_their_dependents() {
+        let pool = seeded_pool().await;
+
+        let summary = erase_user_data("1", ErasureMode::Delete, &pool)
+            .await
+            .unwrap();
+
+        assert_eq!(summary["threads"], 1);
+        assert_eq!(summary["imported_records"], 1);
+        assert_eq!(test_count("SELECT COUNT(*) FROM threads", &pool).await, 1);
+        assert_eq!(
+            test_count("SELECT COUNT(*) FROM thread_status", &pool).await,
+            0
+        );
+        assert_eq!(
+            test_count("SELECT COUNT(*) FROM imported_records", &pool).await,
+            0
+        );
+        assert_eq!(
+            test_count(
+                "SELECT COUNT(*) FROM thread_messages WHERE user_id = 0 AND content = 'staff answer'",
+                &pool
+            )
+            .await,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn anonymize_erasure_keeps_tickets_without_the_user() {
+        let pool = seeded_pool().await;
+
+        erase_user_data("1", ErasureMode::Anonymize, &pool)
+            .await
+            .unwrap();
+
+        assert_eq!(test_count("SELECT COUNT(*) FROM threads", &pool).await, 2);
+        assert_eq!(
+            test_count("SELECT COUNT(*) FROM threads WHERE user_id = 1", &pool).await,
+            0
+        );
+        assert_eq!(
+            test_count(
+                "SELECT COUNT(*) FROM thread_messages WHERE content = 'my address'",
+                &pool
+            )
+            .await,
+            0
+        );
+        assert_eq!(
+            test_count("SELECT COUNT(*) FROM imported_records", &pool).await,
+            0
+        );
+        assert!(collect_user_data("1", &pool).await.unwrap()["threads"].is_empty());
+    }
+
+    #[tokio::test]
+    async fn erasure_is_refused_while_a_ticket_is_open() {
+        let pool = seeded_pool().await;
+        insert_test_ticket("30", 1, None, None, &pool).await;
+
+        assert!(
+            erase_user_data("1", ErasureMode::Delete, &pool)
+                .await
+                .is_err()
+        );
+        assert_eq!(test_count("SELECT COUNT(*) FROM threads", &pool).await, 3);
+    }
+}
@@ -0,0 +1,370 @@
+use crate::db::repr::{
+    ImportBundle, ImportReport, ImportSource, ImportedBlock, ImportedSnippet, ImportedThread,
+};
+use crate::prelude::errors::*;
+use chrono::{DateTime, Utc};
+use sqlx::{Sqlite, SqlitePool, Transaction};
+use std::collections::HashSet;
+use tracing::error;
+use uuid::Uuid;
+
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M:%S";
+
+const PERMANENT_BLOCK_EXPIRES_AT: &str = "9999-12-31 23:59:59";
+
+fn datetime(value: &DateTime<Utc>) -> String {
+    value.format(DATETIME_FORMAT).to_string()
+}
+
+fn import_failed(what: &str, e: sqlx::Error) -> ModmailError {
+    error!("Failed to import {what}: {e:?}");
+    validation_failed(&format!("Failed to import {what}"))
+}
+
+// Replies without a number, or with a duplicate, are numbered after the highest.
+fn message_numbers(thread: &ImportedThread) -> Vec<Option<i64>> {
+    let mut next = thread
+        .messages
+        .iter()
+        .filter_map(|m| m.message_number)
+        .max()
+        .unwrap_or(0)
+        + 1;
+    let mut used = HashSet::new();
+
+    thread
+        .messages
+        .iter()
+        .map(|message| {
+            if !message.from_staff || message.is_internal {
+                return None;
+            }
+            match message.message_number {
+                Some(number) if used.insert(number) => Some(number),
+                _ => {
+                    used.insert(next);
+                    next += 1;
+                    Some(next - 1)
+                }
+            }
+        })
+        .collect()
+}
+
+async fn import_thread(
+    source: ImportSource,
+    thread: &ImportedThread,
+    tx: &mut Transaction<'_, Sqlite>,
+) -> ModmailResult<Option<u64>> {
+    let thread_id = Uuid::new_v4().to_string();
+    let recorded = sqlx::query(
+        "INSERT INTO imported_records (source, external_id, thread_id, imported_at) VALUES (?, ?, ?, ?) ON CONFLICT (source, external_id) DO NOTHING",
+    )
+    .bind(source.as_str())
+    .bind(&thread.external_id)
+    .bind(&thread_id)
+    .bind(Utc::now().timestamp())
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| import_failed("thread", e))?;
+    if recorded.rows_affected() == 0 {
+        return Ok(None);
+    }
+
+    let numbers = message_numbers(thread);
+    let next_message_number = numbers.iter().flatten().max().unwrap_or(&0) + 1;
+    let closed_at = thread.closed_at.timestamp();
+
+    sqlx::query(
+        "INSERT INTO threads (id, user_id, user_name, channel_id, created_at, next_message_number, status, closed_at, closed_by, workflow_state, state_changed_at) VALUES (?, ?, ?, ?, ?, ?, 0, ?, ?, 'closed', ?)",
+    )
+    .bind(&thread_id)
+    .bind(thread.user_id)
+    .bind(&thread.user_name)
+    .bind(&thread.channel_id)
+    .bind(datetime(&thread.created_at))
+    .bind(next_message_number)
+    .bind(closed_at)
+    .bind(&thread.closed_by)
+    .bind(closed_at)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| import_failed("thread", e))?;
+
+    for (message, number) in thread.messages.iter().zip(numbers) {
+        sqlx::query(
+            "INSERT INTO thread_messages (thread_id, user_id, user_name, is_anonymous, dm_message_id, inbox_message_id, message_number, created_at, content, thread_status, is_internal) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, 1, ?)",
+        )
+        .bind(&thread_id)
+        .bind(message.user_id)
+        .bind(&message.user_name)
+        .bind(message.is_anonymous)
+        .bind(&message.dm_message_id)
+        .bind(&message.inbox_message_id)
+        .bind(number)
+        .bind(datetime(&message.created_at))
+        .bind(&message.content)
+        .bind(message.is_internal)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| import_failed("message", e))?;
+    }
+
+    Ok(Some(thread.messages.len() as u64))
+}
+
+async fn import_snippet(
+    snippet: &ImportedSnippet,
+    tx: &mut Transaction<'_, Sqlite>,
+) -> ModmailResult<bool> {
+    let created_at = snippet
+        .created_at
+        .as_ref()
+        .map(datetime)
+        .unwrap_or_else(|| datetime(&Utc::now()));
+    let result = sqlx::query(
+        "INSERT INTO snippets (key, content, created_by, created_at, updated_at) VALUES (?, ?, ?, ?, ?) ON CONFLICT (key) DO NOTHING",
+    )
+    .bind(&snippet.key)
+    .bind(&snippet.content)
+    .bind(&snippet.created_by)
+    .bind(&created_at)
+    .bind(&created_at)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| import_failed("snippet", e))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+async fn import_block(
+    block: &ImportedBlock,
+    tx: &mut Transaction<'_, Sqlite>,
+) -> ModmailResult<bool> {
+    let expires_at = block
+        .expires_at
+        .as_ref()
+        .map(datetime)
+        .unwrap_or_else(|| PERMANENT_BLOCK_EXPIRES_AT.to_string());
+    let result = sqlx::query(
+        "INSERT INTO blocked_users (user_id, user_name, blocked_by, blocked_at, expires_at) VALUES (?, ?, ?, ?, ?) ON CONFLICT (user_id) DO NOTHING",
+    )
+    .bind(&block.user_id)
+    .bind(&block.user_name)
+    .bind(&block.blocked_by)
+    .bind(datetime(&block.blocked_at))
+    .bind(&expires_at)
+    .execute(&mut **tx)
+    .await
+    .map_err(|e| import_failed("blocked user", e))?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn import_bundle(
+    source: ImportSource,
+    bundle: &ImportBundle,
+    dry_run: bool,
+    pool: &SqlitePool,
+) -> ModmailResult<ImportReport> {
+    let mut tx = pool.begin().await.map_err(|e| {
+        error!("Failed to begin import transaction: {e:?}");
+        validation_failed("Failed to begin import transaction")
+    })?;
+
+    let mut report = ImportReport {
+        unsupported: bundle.unsupported,
+        dry_run,
+        ..Default::default()
+    };
+
+    for thread in &bundle.threads {
+        match import_thread(source, thread, &mut tx).await? {
+            Some(messages) => {
+                report.threads_imported += 1;
+                report.messages_imported += messages;
+            }
+            None => report.threads_skipped += 1,
+        }
+    }
+    for snippet in &bundle.snippets {
+        if import_snippet(snippet, &mut tx).await? {
+            report.snippets_imported += 1;
+        } else {
+            report.snippets_skipped += 1;
+        }
+    }
+    for block in &bundle.blocked {
+        if import_block(block, &mut tx).await? {
+            report.blocked_imported += 1;
+        } else {
+            report.blocked_skipped += 1;
+        }
+    }
+
+    let finished = if dry_run {
+        tx.rollback().await
+    } else {
+        tx.commit().await
+    };
+    finished.map_err(|e| {
+        error!("Failed to finish import transaction: {e:?}");
+        validation_failed("Failed to finish import transaction")
+    })?;
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::operations::init::{test_count, test_pool};
+    use crate::db::repr::ImportedMessage;
+
+    fn message(
+        from_staff: bool,
+        is_internal: bool,
+        message_number: Option<i64>,
+    ) -> ImportedMessage {
+        ImportedMessage {
+            user_id: if from_staff { 7 } else { 1 },
+            user_name: "Someone".to_string(),
+            content: "hello".to_string(),
+            created_at: Utc::now(),
+            from_staff,
+            is_anonymous: false,
+            is_internal,
+            message_number,
+            dm_message_id: None,
+            inbox_message_id: None,
+        }
+    }
+
+    fn thread(external_id: &str, messages: Vec<ImportedMessage>) -> ImportedThread {
+        ImportedThread {
+            external_id: external_id.to_string(),
+            user_id: 1,
+            user_name: "User".to_string(),
+            channel_id: "100".to_string(),
+            created_at: Utc::now(),
+            closed_at: Utc::now(),
+            closed_by: Some("7".to_string()),
+            messages,
+        }
+    }
+
+    fn bundle() -> ImportBundle {
+        ImportBundle {
+            threads: vec![thread(
+                "abc",
+                vec![message(false, false, None), message(true, false, Some(1))],
+            )],
+            snippets: vec![ImportedSnippet {
+                key: "hi".to_string(),
+                content: "Hello!".to_string(),
+                created_by: "7".to_string(),
+                created_at: None,
+            }],
+            blocked: vec![ImportedBlock {
+                user_id: "2".to_string(),
+                user_name: "Spammer".to_string(),
+                blocked_by: "7".to_string(),
+                blocked_at: Utc::now(),
+                expires_at: None,
+            }],
+            unsupported: 0,
+        }
+    }
+
+    #[test]
+    fn message_numbers_renumber_duplicates_and_missing_after_the_highest() {
+        let thread = thread(
+            "abc",
+            vec![
+                message(true, false, Some(2)),
+                message(true, false, Some(2)),
+                message(false, false, None),
+                message(true, false, None),
+                message(true, true, Some(5)),
+            ],
+        );
+
+        assert_eq!(
+            message_numbers(&thread),
+            [Some(2), Some(6), None, Some(7), None]
+        );
+    }
+
+    #[test]
+    fn message_numbers_count_from_one_without_source_numbers() {
+        let thread = thread(
+            "abc",
+            vec![
+                message(true, false, None),
+                message(true, true, None),
+                message(true, false, None),
+            ],
+        );
+
+        assert_eq!(message_numbers(&thread), [Some(1), None, Some(2)]);
+    }
+
+    #[tokio::test]
+    async fn import_skips_what_an_earlier_run_imported() {
+        let pool = test_pool().await;
+
+        let first = import_bundle(ImportSource::Modmail, &bundle(), false, &pool)
+            .await
+            .unwrap();
+        let second = import_bundle(ImportSource::Modmail, &bundle(), false, &pool)
+            .await
+            .unwrap();
+
+        assert_eq!(first.threads_imported, 1);
+        assert_eq!(first.messages_imported, 2);
+        assert_eq!((first.snippets_imported, first.blocked_imported), (1, 1));
+        assert_eq!(second.threads_imported, 0);
+        assert_eq!(second.threads_skipped, 1);
+        assert_eq!((second.snippets_skipped, second.blocked_skipped), (1, 1));
+        assert_eq!(test_count("SELECT COUNT(*) FROM threads", &pool).await, 1);
+        assert_eq!(
+            test_count("SELECT COUNT(*) FROM thread_messages", &pool).await,
+            2
+        );
+        assert_eq!(
+            test_count(
+                "SELECT COUNT(*) FROM imported_records r JOIN threads t ON t.id = r.thread_id WHERE r.external_id = 'abc'",
+                &pool
+            )
+            .await,
+            1
+        );
+
+        let other_source = import_bundle(ImportSource::Modmailbot, &bundle(), false, &pool)
+            .await
+            .unwrap();
+        assert_eq!(other_source.threads_imported, 1);
+    }
+
+    #[tokio::test]
+    async fn dry_run_reports_without_writing() {
+        let pool = test_pool().await;
+
+        let report = import_bundle(ImportSource::Modmail, &bundle(), true, &pool)
+            .await
+            .unwrap();
+
+        assert!(report.dry_run);
+        assert_eq!(report.threads_imported, 1);
+        assert_eq!(report.snippets_imported, 1);
+        for query in [
+            "SELECT COUNT(*) FROM threads",
+            "SELECT COUNT(*) FROM thread_messages",
+            "SELECT COUNT(*) FROM imported_records",
+            "SELECT COUNT(*) FROM snippets",
+            "SELECT COUNT(*) FROM blocked_users",
+        ] {
+            assert_eq!(test_count(query, &pool).await, 0, "{query}");
+        }
+    }
+}
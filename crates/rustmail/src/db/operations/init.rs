@@ -1,14 +1,26 @@
 use crate::config::resolve_db_path;
 use sqlx::{
     SqlitePool,
+    migrate::Migrator,
     sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous},
 };
 use std::fs;
 use std::path::Path;
 use std::str::FromStr;
 use std::time::Duration;
+use tracing::info;
+
+pub static MIGRATOR: Migrator = sqlx::migrate!("../../migrations");
 
 pub async fn init_database() -> Result<SqlitePool, sqlx::Error> {
+    let pool = connect_database().await?;
+    MIGRATOR.run(&pool).await?;
+
+    info!("Database connection pool established");
+    Ok(pool)
+}
+
+pub async fn connect_database() -> Result<SqlitePool, sqlx::Error> {
     let db_path = resolve_db_path("db/db.sqlite");
 
     if let Some(parent) = Path::new(&db_path).parent() {
@@ -19,7 +31,7 @@ pub async fn init_database() -> Result<SqlitePool, sqlx::Error> {
 
     if !Path::new(&db_path).exists() {
         fs::File::create(&db_path)?;
-        println!("Database file created at: {}", db_path);
+        info!("Database file created at: {}", db_path);
     }
 
     let connect_options = SqliteConnectOptions::from_str(&format!("sqlite://{}", db_path))?
@@ -32,12 +44,62 @@ pub async fn init_database() -> Result<SqlitePool, sqlx::Error> {
         .connect_with(connect_options)
         .await?;
 
-    sqlx::migrate!("../../migrations").run(&pool).await?;
-
-    println!("Database connection pool established");
     Ok(pool)
 }
 
+#[cfg(test)]
+pub async fn test_pool() -> SqlitePool {
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .expect("in-memory database");
+    MIGRATOR.run(&pool).await.expect("migrations");
+    pool
+}
+
+#[cfg(test)]
+pub async fn test_count(query: &'static str, pool: &SqlitePool) -> i64 {
+    sqlx::query_scalar(query).fetch_one(pool).await.unwrap()
+}
+
+#[cfg(test)]
+pub async fn insert_test_ticket(
+    id: &str,
+    user_id: i64,
+    closed_at: Option<i64>,
+    category_id: Option<&str>,
+    pool: &SqlitePool,
+) {
+    sqlx::query(
+        "INSERT INTO threads (id, user_id, user_name, channel_id, created_at, status, closed_at, closed_by, category_name, ticket_category_id) VALUES (?1, ?2, 'User', ?1, '2023-11-01 00:00:00', ?3, ?4, ?5, 'Support', ?6)",
+    )
+    .bind(id)
+    .bind(user_id)
+    .bind(closed_at.is_none())
+    .bind(closed_at)
+    .bind(closed_at.map(|_| "7"))
+    .bind(category_id)
+    .execute(pool)
+    .await
+    .unwrap();
+}
+
+pub async fn list_applied_migrations(pool: &SqlitePool) -> Result<Vec<i64>, sqlx::Error> {
+    let exists: Option<String> = sqlx::query_scalar(
+        "SELECT name FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations'",
+    )
+    .fetch_optional(pool)
+    .await?;
+    if exists.is_none() {
+        return Ok(Vec::new());
+    }
+
+    sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success = 1 ORDER BY version")
+        .fetch_all(pool)
+        .await
+}
+
 pub async fn get_system_metadata(
     key: &str,
     pool: &SqlitePool,
@@ -0,0 +1,322 @@
+use crate::db::operations::gdpr::row_to_json;
+use crate::db::repr::{InstanceImportReport, RecreatedApiKey};
+use crate::prelude::errors::*;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashSet};
+use tracing::error;
+
+macro_rules! insert_row {
+    ($table:literal ($first:literal $(, $column:literal)*) $($tail:expr),+) => {
+        concat!(
+            "INSERT INTO ", $table, " (", $first, $(", ", $column,)* ") ",
+            "SELECT json_extract(?1, '$.", $first, "')",
+            $(", json_extract(?1, '$.", $column, "')",)*
+            $(" ", $tail,)+
+        )
+    };
+}
+
+macro_rules! category_exists {
+    () => {
+        "WHERE EXISTS (SELECT 1 FROM ticket_categories WHERE id = json_extract(?1, '$.category_id'))"
+    };
+}
+
+macro_rules! thread_exists {
+    () => {
+        "WHERE EXISTS (SELECT 1 FROM threads WHERE id = json_extract(?1, '$.thread_id'))"
+    };
+}
+
+const INSTANCE_TABLES: [(&str, &str); 11] = [
+    (
+        "ticket_categories",
+        "SELECT * FROM ticket_categories ORDER BY parent_id IS NOT NULL, position",
+    ),
+    (
+        "ticket_category_roles",
+        "SELECT * FROM ticket_category_roles",
+    ),
+    (
+        "ticket_category_access",
+        "SELECT * FROM ticket_category_access",
+    ),
+    (
+        "ticket_category_forms",
+        "SELECT * FROM ticket_category_forms",
+    ),
+    (
+        "ticket_category_messages",
+        "SELECT * FROM ticket_category_messages",
+    ),
+    (
+        "ticket_category_settings",
+        "SELECT * FROM ticket_category_settings",
+    ),
+    ("snippets", "SELECT * FROM snippets ORDER BY id"),
+    (
+        "panel_permissions",
+        "SELECT * FROM panel_permissions ORDER BY id",
+    ),
+    (
+        "api_keys",
+        "SELECT name, permissions, created_at, expires_at, rate_limit_per_minute, rate_limit_burst, allowed_cidrs, allowed_category_ids FROM api_keys WHERE is_active = 1 ORDER BY id",
+    ),
+    (
+        "reminders",
+        "SELECT * FROM reminders WHERE completed = 0 ORDER BY id",
+    ),
+    ("scheduled_closures", "SELECT * FROM scheduled_closures"),
+];
+
+const HISTORY_TABLES: [(&str, &str); 3] = [
+    ("threads", "SELECT * FROM threads ORDER BY created_at"),
+    (
+        "thread_messages",
+        "SELECT * FROM thread_messages ORDER BY thread_id, id",
+    ),
+    ("thread_status", "SELECT * FROM thread_status"),
+];
+
+// In dependency order; existing rows are skipped so an import can run again.
+const INSERT_STATEMENTS: [(&str, &str); 14] = [
+    (
+        "ticket_categories",
+        insert_row!(
+            "ticket_categories" (
+                "id", "name", "description", "emoji", "discord_category_id", "position",
+                "enabled", "created_at", "updated_at", "parent_id", "embed_color",
+                "default_priority"
+            )
+            "WHERE true ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "ticket_category_roles",
+        insert_row!(
+            "ticket_category_roles" ("category_id", "role_id", "created_at")
+            category_exists!(), "ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "ticket_category_access",
+        insert_row!(
+            "ticket_category_access" ("category_id", "target_type", "target_id")
+            category_exists!(), "ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "ticket_category_forms",
+        insert_row!(
+            "ticket_category_forms" ("category_id", "title", "questions", "updated_at")
+            category_exists!(), "ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "ticket_category_messages",
+        insert_row!(
+            "ticket_category_messages" (
+                "category_id", "language", "welcome_message", "close_message", "auto_reply",
+                "updated_at"
+            )
+            category_exists!(), "ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "ticket_category_settings",
+        insert_row!(
+            "ticket_category_settings" ("id", "enabled", "selection_timeout_s", "selection_mode")
+            "WHERE true ON CONFLICT (id) DO UPDATE SET enabled = excluded.enabled, selection_timeout_s = excluded.selection_timeout_s, selection_mode = excluded.selection_mode"
+        ),
+    ),
+    (
+        "snippets",
+        insert_row!(
+            "snippets" ("key", "content", "created_by", "created_at", "updated_at")
+            "WHERE true ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "panel_permissions",
+        insert_row!(
+            "panel_permissions" ("subject_type", "subject_id", "permission", "granted_by", "granted_at")
+            "WHERE true ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "api_keys",
+        insert_row!(
+            "api_keys" (
+                "key_hash", "name", "permissions", "created_at", "expires_at",
+                "rate_limit_per_minute", "rate_limit_burst", "allowed_cidrs",
+                "allowed_category_ids"
+            )
+            "WHERE NOT EXISTS (SELECT 1 FROM api_keys WHERE name = json_extract(?1, '$.name') AND is_active = 1)"
+        ),
+    ),
+    (
+        "threads",
+        insert_row!(
+            "threads" (
+                "id", "user_id", "user_name", "channel_id", "created_at", "next_message_number",
+                "status", "user_left", "closed_at", "closed_by", "category_id", "category_name",
+                "required_permissions", "ticket_category_id", "silent", "priority",
+                "workflow_state", "state_until", "state_changed_at"
+            )
+            "WHERE true ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "thread_messages",
+        insert_row!(
+            "thread_messages" (
+                "thread_id", "user_id", "user_name", "is_anonymous", "dm_message_id",
+                "inbox_message_id", "message_number", "created_at", "content", "thread_status",
+                "is_internal"
+            )
+            thread_exists!(), "ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "thread_status",
+        insert_row!(
+            "thread_status" (
+                "thread_id", "channel_id", "owner_id", "taken_by", "last_message_by",
+                "last_message_at", "label"
+            )
+            thread_exists!(), "ON CONFLICT DO NOTHING"
+        ),
+    ),
+    (
+        "reminders",
+        insert_row!(
+            "reminders" (
+                "thread_id", "user_id", "channel_id", "guild_id", "reminder_content",
+                "trigger_time", "created_at", "completed", "target_roles"
+            )
+            thread_exists!(),
+            "AND NOT EXISTS (SELECT 1 FROM reminders WHERE thread_id = json_extract(?1, '$.thread_id') AND user_id = json_extract(?1, '$.user_id') AND trigger_time = json_extract(?1, '$.trigger_time') AND reminder_content = json_extract(?1, '$.reminder_content'))"
+        ),
+    ),
+    (
+        "scheduled_closures",
+        insert_row!(
+            "scheduled_closures" (
+                "thread_id", "close_at", "silent", "closed_at", "closed_by", "category_id",
+                "category_name", "required_permissions"
+            )
+            thread_exists!(), "ON CONFLICT DO NOTHING"
+        ),
+    ),
+];
+
+pub async fn collect_instance_tables(
+    include_history: bool,
+    pool: &SqlitePool,
+) -> ModmailResult<BTreeMap<String, Vec<Value>>> {
+    let history: &[(&str, &str)] = if include_history {
+        &HISTORY_TABLES
+    } else {
+        &[]
+    };
+
+    let mut tables = BTreeMap::new();
+    for &(table, query) in INSTANCE_TABLES.iter().chain(history) {
+        let rows = sqlx::query(query).fetch_all(pool).await.map_err(|e| {
+            error!("Failed to collect {table} for an instance export: {e:?}");
+            validation_failed("Failed to collect instance data")
+        })?;
+        tables.insert(table.to_string(), rows.iter().map(row_to_json).collect());
+    }
+
+    Ok(tables)
+}
+
+pub async fn import_instance_tables(
+    tables: &BTreeMap<String, Vec<Value>>,
+    api_key_secrets: &[String],
+    dry_run: bool,
+    pool: &SqlitePool,
+) -> ModmailResult<InstanceImportReport> {
+    let mut tx = pool.begin().await.map_err(|e| {
+        error!("Failed to begin instance import transaction: {e:?}");
+        validation_failed("Failed to begin instance import transaction")
+    })?;
+
+    let mut report = InstanceImportReport {
+        dry_run,
+        ..Default::default()
+    };
+    let mut imported_threads = HashSet::new();
+
+    for &(table, statement) in INSERT_STATEMENTS.iter() {
+        let Some(rows) = tables.get(table) else {
+            continue;
+        };
+
+        let (mut imported, mut skipped) = (0, 0);
+        for (index, row) in rows.iter().enumerate() {
+            let thread_id = row.get("thread_id").and_then(Value::as_str);
+            let of_new_thread = thread_id.is_some_and(|id| imported_threads.contains(id));
+            if matches!(table, "thread_messages" | "thread_status") && !of_new_thread {
+                skipped += 1;
+                continue;
+            }
+
+            let result = sqlx::query(statement)
+                .bind(row.to_string())
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    error!("Failed to import {table}: {e:?}");
+                    validation_failed(&format!("Failed to import {table}"))
+                })?;
+            if result.rows_affected() == 0 {
+                skipped += 1;
+                continue;
+            }
+
+            imported += 1;
+            match table {
+                "threads" => {
+                    if let Some(id) = row.get("id").and_then(Value::as_str) {
+                        imported_threads.insert(id.to_string());
+                    }
+                }
+                "api_keys" => {
+                    if let Some(secret) = api_key_secrets.get(index) {
+                        report.api_keys.push(RecreatedApiKey {
+                            name: row
+                                .get("name")
+                                .and_then(Value::as_str)
+                                .unwrap_or_default()
+                                .to_string(),
+                            api_key: secret.clone(),
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if imported > 0 {
+            report.imported.insert(table.to_string(), imported);
+        }
+        if skipped > 0 {
+            report.skipped.insert(table.to_string(), skipped);
+        }
+    }
+
+    let finished = if dry_run {
+        tx.rollback().await
+    } else {
+        tx.commit().await
+    };
+    finished.map_err(|e| {
+        error!("Failed to finish instance import transaction: {e:?}");
+        validation_failed("Failed to finish instance import transaction")
+    })?;
+
+    Ok(report)
+}
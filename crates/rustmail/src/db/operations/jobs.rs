@@ -0,0 +1,459 @@
+use crate::db::repr::{Job, JobKind, JobStatus};
+use crate::prelude::errors::*;
+use chrono::Utc;
+use sqlx::{Row, SqlitePool};
+use tracing::error;
+
+pub const JOB_DEFAULT_MAX_ATTEMPTS: i64 = 5;
+
+fn row_to_job(row: sqlx::sqlite::SqliteRow) -> Job {
+    Job {
+        id: row.get::<i64, _>("id"),
+        kind: row.get::<String, _>("kind"),
+        job_key: row.get::<String, _>("job_key"),
+        payload: row.get::<String, _>("payload"),
+        status: row.get::<String, _>("status"),
+        run_at: row.get::<i64, _>("run_at"),
+        attempts: row.get::<i64, _>("attempts"),
+        max_attempts: row.get::<i64, _>("max_attempts"),
+        last_error: row.get::<Option<String>, _>("last_error"),
+        locked_by: row.get::<Option<String>, _>("locked_by"),
+        locked_at: row.get::<Option<i64>, _>("locked_at"),
+        created_at: row.get::<i64, _>("created_at"),
+        updated_at: row.get::<i64, _>("updated_at"),
+    }
+}
+
+// One row per key: enqueuing again resets the run time, the payload and the attempts.
+pub async fn enqueue_job(
+    kind: JobKind,
+    key: &str,
+    payload: &str,
+    run_at: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<i64> {
+    let now = Utc::now().timestamp();
+
+    let id: i64 = sqlx::query_scalar(
+        r#"
+        INSERT INTO jobs (kind, job_key, payload, status, run_at, attempts, max_attempts,
+                          created_at, updated_at)
+        VALUES (?, ?, ?, 'pending', ?, 0, ?, ?, ?)
+        ON CONFLICT(kind, job_key) DO UPDATE SET
+            payload = excluded.payload,
+            status = 'pending',
+            run_at = excluded.run_at,
+            attempts = 0,
+            last_error = NULL,
+            locked_by = NULL,
+            locked_at = NULL,
+            updated_at = excluded.updated_at
+        RETURNING id
+        "#,
+    )
+    .bind(kind.as_str())
+    .bind(key)
+    .bind(payload)
+    .bind(run_at)
+    .bind(JOB_DEFAULT_MAX_ATTEMPTS)
+    .bind(now)
+    .bind(now)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to enqueue job: {e:?}");
+        validation_failed("Failed to enqueue job")
+    })?;
+
+    Ok(id)
+}
+
+pub async fn ensure_job(
+    kind: JobKind,
+    key: &str,
+    run_at: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    let existing: Option<String> =
+        sqlx::query_scalar("SELECT status FROM jobs WHERE kind = ? AND job_key = ?")
+            .bind(kind.as_str())
+            .bind(key)
+            .fetch_optional(pool)
+            .await
+            .map_err(|_| validation_failed("Failed to fetch job"))?;
+
+    match existing.as_deref().and_then(JobStatus::parse) {
+        Some(JobStatus::Pending) | Some(JobStatus::Running) => Ok(()),
+        _ => enqueue_job(kind, key, "{}", run_at, pool).await.map(|_| ()),
+    }
+}
+
+pub async fn expedite_job(
+    kind: JobKind,
+    key: &str,
+    run_at: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    let res = sqlx::query(
+        r#"
+        UPDATE jobs SET run_at = MIN(run_at, ?), updated_at = ?
+        WHERE kind = ? AND job_key = ? AND status = 'pending'
+        "#,
+    )
+    .bind(run_at)
+    .bind(Utc::now().timestamp())
+    .bind(kind.as_str())
+    .bind(key)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to update job"))?;
+
+    if res.rows_affected() > 0 {
+        return Ok(());
+    }
+    ensure_job(kind, key, run_at, pool).await
+}
+
+pub async fn cancel_job(kind: JobKind, key: &str, pool: &SqlitePool) -> ModmailResult<bool> {
+    let res = sqlx::query(
+        r#"
+        UPDATE jobs SET status = 'cancelled', updated_at = ?
+        WHERE kind = ? AND job_key = ? AND status = 'pending'
+        "#,
+    )
+    .bind(Utc::now().timestamp())
+    .bind(kind.as_str())
+    .bind(key)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to cancel job"))?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn cancel_job_by_id(id: i64, pool: &SqlitePool) -> ModmailResult<bool> {
+    let res = sqlx::query(
+        "UPDATE jobs SET status = 'cancelled', updated_at = ? WHERE id = ? AND status = 'pending'",
+    )
+    .bind(Utc::now().timestamp())
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to cancel job"))?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn retry_job_by_id(id: i64, pool: &SqlitePool) -> ModmailResult<bool> {
+    let now = Utc::now().timestamp();
+    let res = sqlx::query(
+        r#"
+        UPDATE jobs
+        SET status = 'pending', run_at = ?, attempts = 0, last_error = NULL,
+            locked_by = NULL, locked_at = NULL, updated_at = ?
+        WHERE id = ? AND status IN ('failed', 'cancelled')
+        "#,
+    )
+    .bind(now)
+    .bind(now)
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to retry job"))?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn claim_due_jobs(
+    worker_id: &str,
+    limit: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<Vec<Job>> {
+    let now = Utc::now().timestamp();
+    let rows = sqlx::query(
+        r#"
+        UPDATE jobs
+        SET status = 'running', attempts = attempts + 1, locked_by = ?, locked_at = ?,
+            updated_at = ?
+        WHERE id IN (
+            SELECT id FROM jobs
+            WHERE status = 'pending' AND run_at <= ?
+            ORDER BY run_at ASC
+            LIMIT ?
+        )
+        RETURNING id, kind, job_key, payload, status, run_at, attempts, max_attempts,
+                  last_error, locked_by, locked_at, created_at, updated_at
+        "#,
+    )
+    .bind(worker_id)
+    .bind(now)
+    .bind(now)
+    .bind(now)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to claim due jobs: {e:?}");
+        validation_failed("Failed to claim due jobs")
+    })?;
+
+    Ok(rows.into_iter().map(row_to_job).collect())
+}
+
+pub async fn complete_job(id: i64, pool: &SqlitePool) -> ModmailResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE jobs
+        SET status = 'completed', last_error = NULL, locked_by = NULL, locked_at = NULL,
+            updated_at = ?
+        WHERE id = ? AND status = 'running'
+        "#,
+    )
+    .bind(Utc::now().timestamp())
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to complete job"))?;
+
+    Ok(())
+}
+
+pub async fn reschedule_job(id: i64, run_at: i64, pool: &SqlitePool) -> ModmailResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE jobs
+        SET status = 'pending', run_at = ?, attempts = 0, locked_by = NULL, locked_at = NULL,
+            updated_at = ?
+        WHERE id = ? AND status = 'running'
+        "#,
+    )
+    .bind(run_at)
+    .bind(Utc::now().timestamp())
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to reschedule job"))?;
+
+    Ok(())
+}
+
+pub async fn fail_job(
+    id: i64,
+    error: &str,
+    retry_at: Option<i64>,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE jobs
+        SET status = CASE WHEN ?1 IS NULL THEN 'failed' ELSE 'pending' END,
+            run_at = COALESCE(?1, run_at), last_error = ?2, locked_by = NULL,
+            locked_at = NULL, updated_at = ?3
+        WHERE id = ?4 AND status = 'running'
+        "#,
+    )
+    .bind(retry_at)
+    .bind(error)
+    .bind(Utc::now().timestamp())
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to record job failure"))?;
+
+    Ok(())
+}
+
+pub async fn renew_job_lease(id: i64, worker_id: &str, pool: &SqlitePool) -> ModmailResult<bool> {
+    let now = Utc::now().timestamp();
+    let res = sqlx::query(
+        r#"
+        UPDATE jobs SET locked_at = ?, updated_at = ?
+        WHERE id = ? AND status = 'running' AND locked_by = ?
+        "#,
+    )
+    .bind(now)
+    .bind(now)
+    .bind(id)
+    .bind(worker_id)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to renew job lease"))?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn requeue_stale_jobs(
+    worker_id: &str,
+    lease_cutoff: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<u64> {
+    let res = sqlx::query(
+        r#"
+        UPDATE jobs
+        SET status = 'pending', locked_by = NULL, locked_at = NULL, updated_at = ?
+        WHERE status = 'running'
+          AND (locked_by IS NULL OR locked_by != ? OR locked_at < ?)
+        "#,
+    )
+    .bind(Utc::now().timestamp())
+    .bind(worker_id)
+    .bind(lease_cutoff)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to requeue stale jobs"))?;
+
+    Ok(res.rows_affected())
+}
+
+pub async fn get_job(id: i64, pool: &SqlitePool) -> ModmailResult<Option<Job>> {
+    let row = sqlx::query(
+        r#"
+        SELECT id, kind, job_key, payload, status, run_at, attempts, max_attempts,
+               last_error, locked_by, locked_at, created_at, updated_at
+        FROM jobs
+        WHERE id = ?
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to fetch job"))?;
+
+    Ok(row.map(row_to_job))
+}
+
+pub async fn list_jobs(
+    status: Option<JobStatus>,
+    kind: Option<JobKind>,
+    limit: i64,
+    offset: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<(Vec<Job>, i64)> {
+    let status = status.map(|s| s.as_str());
+    let kind = kind.map(|k| k.as_str());
+
+    let total: i64 = sqlx::query_scalar(
+        r#"
+        SELECT COUNT(*) FROM jobs
+        WHERE (?1 IS NULL OR status = ?1) AND (?2 IS NULL OR kind = ?2)
+        "#,
+    )
+    .bind(status)
+    .bind(kind)
+    .fetch_one(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to count jobs"))?;
+
+    let rows = sqlx::query(
+        r#"
+        SELECT id, kind, job_key, payload, status, run_at, attempts, max_attempts,
+               last_error, locked_by, locked_at, created_at, updated_at
+        FROM jobs
+        WHERE (?1 IS NULL OR status = ?1) AND (?2 IS NULL OR kind = ?2)
+        ORDER BY CASE status WHEN 'running' THEN 0 WHEN 'pending' THEN 1 ELSE 2 END,
+                 run_at ASC
+        LIMIT ?3 OFFSET ?4
+        "#,
+    )
+    .bind(status)
+    .bind(kind)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list jobs: {e:?}");
+        validation_failed("Failed to list jobs")
+    })?;
+
+    Ok((rows.into_iter().map(row_to_job).collect(), total))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::operations::init::test_pool;
+
+    async fn status(id: i64, pool: &SqlitePool) -> String {
+        get_job(id, pool).await.unwrap().unwrap().status
+    }
+
+    #[tokio::test]
+    async fn only_one_worker_claims_a_due_job() {
+        let pool = test_pool().await;
+        let id = enqueue_job(JobKind::Reminder, "1", "{}", 0, &pool)
+            .await
+            .unwrap();
+        enqueue_job(JobKind::Reminder, "2", "{}", i64::MAX, &pool)
+            .await
+            .unwrap();
+
+        let (a, b) = tokio::join!(
+            claim_due_jobs("a", 10, &pool),
+            claim_due_jobs("b", 10, &pool)
+        );
+        let claimed: Vec<Job> = a.unwrap().into_iter().chain(b.unwrap()).collect();
+
+        assert_eq!(claimed.len(), 1);
+        assert_eq!(claimed[0].id, id);
+        assert_eq!(claimed[0].attempts, 1);
+        assert_eq!(status(id, &pool).await, "running");
+    }
+
+    #[tokio::test]
+    async fn running_jobs_are_requeued_once_their_lease_expires() {
+        let pool = test_pool().await;
+        let id = enqueue_job(JobKind::Reminder, "1", "{}", 0, &pool)
+            .await
+            .unwrap();
+        claim_due_jobs("a", 10, &pool).await.unwrap();
+        let now = Utc::now().timestamp();
+
+        assert_eq!(requeue_stale_jobs("a", now - 60, &pool).await.unwrap(), 0);
+
+        sqlx::query("UPDATE jobs SET locked_at = ? WHERE id = ?")
+            .bind(now - 120)
+            .bind(id)
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert!(renew_job_lease(id, "a", &pool).await.unwrap());
+        assert!(!renew_job_lease(id, "b", &pool).await.unwrap());
+        assert_eq!(requeue_stale_jobs("a", now - 60, &pool).await.unwrap(), 0);
+
+        assert_eq!(requeue_stale_jobs("a", now + 1, &pool).await.unwrap(), 1);
+        assert_eq!(status(id, &pool).await, "pending");
+    }
+
+    #[tokio::test]
+    async fn jobs_of_another_worker_are_requeued() {
+        let pool = test_pool().await;
+        let id = enqueue_job(JobKind::Reminder, "1", "{}", 0, &pool)
+            .await
+            .unwrap();
+        claim_due_jobs("previous", 10, &pool).await.unwrap();
+
+        assert_eq!(requeue_stale_jobs("current", 0, &pool).await.unwrap(), 1);
+        assert_eq!(
+            claim_due_jobs("current", 10, &pool).await.unwrap()[0].id,
+            id
+        );
+    }
+
+    #[tokio::test]
+    async fn cancelled_jobs_are_not_claimed_until_retried() {
+        let pool = test_pool().await;
+        let id = enqueue_job(JobKind::Reminder, "1", "{}", 0, &pool)
+            .await
+            .unwrap();
+
+        assert!(cancel_job(JobKind::Reminder, "1", &pool).await.unwrap());
+        assert!(!cancel_job(JobKind::Reminder, "1", &pool).await.unwrap());
+        assert!(claim_due_jobs("a", 10, &pool).await.unwrap().is_empty());
+
+        assert!(retry_job_by_id(id, &pool).await.unwrap());
+        let claimed = claim_due_jobs("a", 10, &pool).await.unwrap();
+        assert_eq!(claimed.len(), 1);
+        assert!(!cancel_job_by_id(id, &pool).await.unwrap());
+    }
+}
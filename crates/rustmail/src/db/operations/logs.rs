@@ -1,6 +1,7 @@
 use crate::prelude::errors::*;
 use crate::prelude::types::*;
 use sqlx::SqlitePool;
+use tracing::error;
 
 pub async fn get_logs_from_user_id(
     user_id: &str,
@@ -23,7 +24,7 @@ pub async fn get_logs_from_user_id(
     .fetch_all(pool)
     .await
     .map_err(|e| {
-        eprintln!(
+        error!(
             "Database error getting logs for user ID {}: {:?}",
             user_id, e
         );
@@ -4,6 +4,7 @@ use crate::prelude::errors::*;
 use serenity::all::{Message, MessageId, UserId};
 use serenity::client::Context;
 use sqlx::{Error, SqlitePool};
+use tracing::error;
 
 #[derive(Debug, Clone)]
 pub struct MessageIds {
@@ -78,7 +79,7 @@ pub async fn get_message_ids_by_number(
         }),
         Ok(None) => None,
         Err(e) => {
-            eprintln!("Database error getting message IDs: {:?}", e);
+            error!("Database error getting message IDs: {:?}", e);
             None
         }
     }
@@ -213,7 +214,7 @@ pub async fn get_message_ids_by_message_id(
         }),
         Ok(None) => None,
         Err(e) => {
-            eprintln!("Database error getting message IDs by message ID: {:?}", e);
+            error!("Database error getting message IDs by message ID: {:?}", e);
             None
         }
     }
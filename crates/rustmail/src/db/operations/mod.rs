@@ -1,27 +1,53 @@
 pub mod api_keys;
+pub mod backup;
 pub mod banned_users;
+pub mod board;
+pub mod command_permissions;
+pub mod config_revisions;
 pub mod features;
+pub mod gdpr;
+pub mod imports;
 pub mod init;
+pub mod instance;
+pub mod jobs;
 pub mod logs;
 pub mod messages;
+pub mod polls;
+pub mod priorities;
 pub mod reminder_optouts;
 pub mod reminders;
+pub mod retention;
 pub mod scheduled;
+pub mod sessions;
 pub mod snippets;
 pub mod statistics;
 pub mod threads;
 pub mod ticket_categories;
+pub mod workflow;
 
 pub use api_keys::*;
+pub use backup::*;
 pub use banned_users::*;
+pub use board::*;
+pub use command_permissions::*;
+pub use config_revisions::*;
 pub use features::*;
+pub use gdpr::*;
+pub use imports::*;
 pub use init::*;
+pub use instance::*;
+pub use jobs::*;
 pub use logs::*;
 pub use messages::*;
+pub use polls::*;
+pub use priorities::*;
 pub use reminder_optouts::*;
 pub use reminders::*;
+pub use retention::*;
 pub use scheduled::*;
+pub use sessions::*;
 pub use snippets::*;
 pub use statistics::*;
 pub use threads::*;
 pub use ticket_categories::*;
+pub use workflow::*;
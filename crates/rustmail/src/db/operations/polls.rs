@@ -0,0 +1,179 @@
+use crate::db::repr::Poll;
+use crate::prelude::errors::*;
+use sqlx::{Row, SqlitePool};
+use tracing::error;
+
+fn row_to_poll(row: sqlx::sqlite::SqliteRow) -> Poll {
+    let options: String = row.get("options");
+    Poll {
+        id: row.get("id"),
+        channel_id: row.get("channel_id"),
+        message_id: row.get("message_id"),
+        question: row.get("question"),
+        options: serde_json::from_str(&options).unwrap_or_default(),
+        created_by: row.get("created_by"),
+        closes_at: row.get("closes_at"),
+        closed_at: row.get("closed_at"),
+    }
+}
+
+pub async fn create_poll(
+    channel_id: &str,
+    question: &str,
+    options: &[String],
+    created_by: &str,
+    created_at: i64,
+    closes_at: Option<i64>,
+    pool: &SqlitePool,
+) -> ModmailResult<i64> {
+    let options = serde_json::to_string(options).unwrap_or_else(|_| "[]".to_string());
+
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO polls (channel_id, question, options, created_by, created_at, closes_at)
+        VALUES (?, ?, ?, ?, ?, ?)
+        RETURNING id
+        "#,
+    )
+    .bind(channel_id)
+    .bind(question)
+    .bind(options)
+    .bind(created_by)
+    .bind(created_at)
+    .bind(closes_at)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to create poll: {e:?}");
+        validation_failed("Failed to create poll")
+    })
+}
+
+pub async fn set_poll_message(
+    poll_id: i64,
+    message_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query("UPDATE polls SET message_id = ? WHERE id = ?")
+        .bind(message_id)
+        .bind(poll_id)
+        .execute(pool)
+        .await
+        .map_err(|_| validation_failed("Failed to update poll"))?;
+
+    Ok(())
+}
+
+pub async fn delete_poll(poll_id: i64, pool: &SqlitePool) -> ModmailResult<()> {
+    sqlx::query("DELETE FROM polls WHERE id = ?")
+        .bind(poll_id)
+        .execute(pool)
+        .await
+        .map_err(|_| validation_failed("Failed to delete poll"))?;
+
+    Ok(())
+}
+
+pub async fn get_poll(poll_id: i64, pool: &SqlitePool) -> ModmailResult<Option<Poll>> {
+    let row = sqlx::query(
+        r#"
+        SELECT id, channel_id, message_id, question, options, created_by, closes_at, closed_at
+        FROM polls WHERE id = ?
+        "#,
+    )
+    .bind(poll_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to fetch poll"))?;
+
+    Ok(row.map(row_to_poll))
+}
+
+pub async fn close_poll(poll_id: i64, closed_at: i64, pool: &SqlitePool) -> ModmailResult<bool> {
+    let res = sqlx::query("UPDATE polls SET closed_at = ? WHERE id = ? AND closed_at IS NULL")
+        .bind(closed_at)
+        .bind(poll_id)
+        .execute(pool)
+        .await
+        .map_err(|_| validation_failed("Failed to close poll"))?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn get_poll_vote(
+    poll_id: i64,
+    user_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Option<usize>> {
+    let index: Option<i64> =
+        sqlx::query_scalar("SELECT option_index FROM poll_votes WHERE poll_id = ? AND user_id = ?")
+            .bind(poll_id)
+            .bind(user_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|_| validation_failed("Failed to fetch poll vote"))?;
+
+    Ok(index.map(|i| i as usize))
+}
+
+pub async fn set_poll_vote(
+    poll_id: i64,
+    user_id: &str,
+    option_index: usize,
+    voted_at: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO poll_votes (poll_id, user_id, option_index, voted_at)
+        VALUES (?, ?, ?, ?)
+        ON CONFLICT(poll_id, user_id) DO UPDATE SET
+            option_index = excluded.option_index,
+            voted_at = excluded.voted_at
+        "#,
+    )
+    .bind(poll_id)
+    .bind(user_id)
+    .bind(option_index as i64)
+    .bind(voted_at)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to record poll vote"))?;
+
+    Ok(())
+}
+
+pub async fn delete_poll_vote(poll_id: i64, user_id: &str, pool: &SqlitePool) -> ModmailResult<()> {
+    sqlx::query("DELETE FROM poll_votes WHERE poll_id = ? AND user_id = ?")
+        .bind(poll_id)
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .map_err(|_| validation_failed("Failed to remove poll vote"))?;
+
+    Ok(())
+}
+
+pub async fn count_poll_votes(
+    poll_id: i64,
+    option_count: usize,
+    pool: &SqlitePool,
+) -> ModmailResult<Vec<u64>> {
+    let rows = sqlx::query(
+        "SELECT option_index, COUNT(*) AS votes FROM poll_votes WHERE poll_id = ? GROUP BY option_index",
+    )
+    .bind(poll_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to count poll votes"))?;
+
+    let mut tallies = vec![0u64; option_count];
+    for row in rows {
+        let index = row.get::<i64, _>("option_index") as usize;
+        if let Some(slot) = tallies.get_mut(index) {
+            *slot = row.get::<i64, _>("votes") as u64;
+        }
+    }
+
+    Ok(tallies)
+}
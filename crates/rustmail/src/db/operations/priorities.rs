@@ -0,0 +1,118 @@
+use crate::db::operations::request_board_refresh;
+use crate::db::repr::{PriorityRule, TicketPriority};
+use crate::prelude::errors::*;
+use chrono::Utc;
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+use tracing::error;
+
+pub async fn get_thread_priority(
+    thread_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<TicketPriority> {
+    let priority: Option<String> = sqlx::query_scalar("SELECT priority FROM threads WHERE id = ?")
+        .bind(thread_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to fetch thread priority: {e:?}");
+            validation_failed("Failed to fetch thread priority")
+        })?;
+    Ok(priority
+        .as_deref()
+        .and_then(TicketPriority::parse)
+        .unwrap_or_default())
+}
+
+pub async fn set_thread_priority(
+    thread_id: &str,
+    priority: TicketPriority,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query("UPDATE threads SET priority = ? WHERE id = ?")
+        .bind(priority.as_str())
+        .bind(thread_id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to set thread priority: {e:?}");
+            validation_failed("Failed to set thread priority")
+        })?;
+    request_board_refresh(pool).await;
+    Ok(())
+}
+
+pub async fn list_open_channel_priorities(
+    pool: &SqlitePool,
+) -> ModmailResult<HashMap<String, TicketPriority>> {
+    let rows = sqlx::query("SELECT channel_id, priority FROM threads WHERE status = 1")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to list ticket priorities: {e:?}");
+            validation_failed("Failed to list ticket priorities")
+        })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let priority = TicketPriority::parse(&row.get::<String, _>("priority"));
+            (row.get("channel_id"), priority.unwrap_or_default())
+        })
+        .collect())
+}
+
+pub async fn list_priority_rules(pool: &SqlitePool) -> ModmailResult<Vec<PriorityRule>> {
+    let rows = sqlx::query("SELECT keyword, priority FROM priority_rules ORDER BY id ASC")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to list priority rules: {e:?}");
+            validation_failed("Failed to list priority rules")
+        })?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            Some(PriorityRule {
+                keyword: row.get("keyword"),
+                priority: TicketPriority::parse(&row.get::<String, _>("priority"))?,
+            })
+        })
+        .collect())
+}
+
+pub async fn set_priority_rules(rules: &[PriorityRule], pool: &SqlitePool) -> ModmailResult<()> {
+    let mut tx = pool.begin().await.map_err(|e| {
+        error!("Failed to begin priority rules tx: {e:?}");
+        validation_failed("Failed to save priority rules")
+    })?;
+
+    sqlx::query("DELETE FROM priority_rules")
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("Failed to clear priority rules: {e:?}");
+            validation_failed("Failed to save priority rules")
+        })?;
+
+    let now = Utc::now().timestamp();
+    for rule in rules {
+        sqlx::query("INSERT INTO priority_rules (keyword, priority, created_at) VALUES (?, ?, ?)")
+            .bind(&rule.keyword)
+            .bind(rule.priority.as_str())
+            .bind(now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                error!("Failed to insert priority rule: {e:?}");
+                validation_failed("Failed to save priority rules")
+            })?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        error!("Failed to commit priority rules: {e:?}");
+        validation_failed("Failed to save priority rules")
+    })?;
+    Ok(())
+}
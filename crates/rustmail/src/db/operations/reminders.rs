@@ -1,3 +1,5 @@
+use crate::db::operations::jobs::enqueue_job;
+use crate::db::repr::JobKind;
 use crate::prelude::errors::*;
 
 #[derive(Debug, Clone)]
@@ -52,7 +54,17 @@ pub async fn insert_reminder(reminder: &Reminder, pool: &sqlx::SqlitePool) -> Mo
     .execute(pool)
     .await?;
 
-    Ok(result.last_insert_rowid())
+    let reminder_id = result.last_insert_rowid();
+    enqueue_job(
+        JobKind::Reminder,
+        &reminder_id.to_string(),
+        "{}",
+        reminder.trigger_time,
+        pool,
+    )
+    .await?;
+
+    Ok(reminder_id)
 }
 
 pub async fn update_reminder_status(
@@ -75,23 +87,6 @@ pub async fn update_reminder_status(
     Ok(())
 }
 
-pub async fn get_all_pending_reminders(
-    pool: &sqlx::SqlitePool,
-) -> Result<Vec<Reminder>, sqlx::Error> {
-    let rows = sqlx::query_as!(
-        Reminder,
-        r#"
-        SELECT thread_id, user_id, channel_id, guild_id, reminder_content, trigger_time, created_at, completed, target_roles
-        FROM reminders
-        WHERE completed = FALSE
-        ORDER BY trigger_time ASC
-        "#
-    )
-    .fetch_all(pool)
-    .await?;
-    Ok(rows)
-}
-
 pub async fn get_reminder_by_id(
     reminder_id: i64,
     pool: &sqlx::SqlitePool,
@@ -110,24 +105,22 @@ pub async fn get_reminder_by_id(
     Ok(row)
 }
 
-pub async fn is_reminder_active(
-    reminder_id: i64,
+pub async fn list_pending_reminders_for_user(
+    user_id: i64,
+    limit: i64,
     pool: &sqlx::SqlitePool,
-) -> Result<bool, sqlx::Error> {
-    let row = sqlx::query!(
+) -> Result<Vec<(i64, String, i64)>, sqlx::Error> {
+    sqlx::query_as::<_, (i64, String, i64)>(
         r#"
-        SELECT completed
+        SELECT id, reminder_content, trigger_time
         FROM reminders
-        WHERE id = ?
+        WHERE user_id = ? AND completed = 0
+        ORDER BY trigger_time ASC
+        LIMIT ?
         "#,
-        reminder_id
     )
-    .fetch_optional(pool)
-    .await?;
-
-    if let Some(record) = row {
-        Ok(!record.completed)
-    } else {
-        Ok(false)
-    }
+    .bind(user_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
 }
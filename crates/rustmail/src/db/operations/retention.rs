@@ -0,0 +1,676 @@
+use crate::db::repr::{RetentionRule, RetentionTarget};
+use crate::prelude::errors::*;
+use chrono::Utc;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{Row, SqlitePool};
+use std::collections::BTreeMap;
+use tracing::error;
+
+macro_rules! expired_threads {
+    () => {
+        "(SELECT id FROM threads WHERE status = 0 AND closed_at < ?1 AND (?2 IS NULL OR ticket_category_id = ?2))"
+    };
+}
+
+const MESSAGE_CONTENT_COUNTS: [(&str, &str); 2] = [
+    (
+        "thread_messages",
+        concat!(
+            "SELECT COUNT(*) FROM thread_messages WHERE content != '[expired]' AND thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "thread_form_answers",
+        concat!(
+            "SELECT COUNT(*) FROM thread_form_answers WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+];
+
+// The rows stay for the statistics.
+const MESSAGE_CONTENT_STATEMENTS: [(&str, &str); 2] = [
+    (
+        "thread_messages",
+        concat!(
+            "UPDATE thread_messages SET content = '[expired]' WHERE content != '[expired]' AND thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "thread_form_answers",
+        concat!(
+            "DELETE FROM thread_form_answers WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+];
+
+const CLOSED_TICKETS_COUNTS: [(&str, &str); 7] = [
+    (
+        "threads",
+        concat!(
+            "SELECT COUNT(*) FROM threads WHERE id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "thread_messages",
+        concat!(
+            "SELECT COUNT(*) FROM thread_messages WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "thread_status",
+        concat!(
+            "SELECT COUNT(*) FROM thread_status WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "thread_form_answers",
+        concat!(
+            "SELECT COUNT(*) FROM thread_form_answers WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "thread_state_history",
+        concat!(
+            "SELECT COUNT(*) FROM thread_state_history WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "scheduled_closures",
+        concat!(
+            "SELECT COUNT(*) FROM scheduled_closures WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "reminders",
+        concat!(
+            "SELECT COUNT(*) FROM reminders WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+];
+
+// Keeps deleted tickets counted on the dashboard.
+const CLOSED_TICKETS_ARCHIVE: [&str; 5] = [
+    concat!(
+        "INSERT INTO archived_ticket_stats (day, category_name, created) ",
+        "SELECT date(created_at), COALESCE(category_name, ''), COUNT(*) FROM threads WHERE id IN ",
+        expired_threads!(),
+        " GROUP BY 1, 2 ",
+        "ON CONFLICT (day, category_name) DO UPDATE SET created = created + excluded.created"
+    ),
+    concat!(
+        "INSERT INTO archived_ticket_stats (day, category_name, closed, resolution_seconds, response_seconds, responded) ",
+        "SELECT date(t.closed_at, 'unixepoch'), COALESCE(t.category_name, ''), COUNT(*), ",
+        "COALESCE(SUM(t.closed_at - strftime('%s', t.created_at)), 0), ",
+        "COALESCE(SUM(CASE WHEN r.first_response > 0 THEN r.first_response END), 0), ",
+        "COUNT(CASE WHEN r.first_response > 0 THEN 1 END) ",
+        "FROM threads t LEFT JOIN (",
+        "SELECT m.thread_id, strftime('%s', MIN(m.created_at)) - strftime('%s', f.created_at) AS first_response ",
+        "FROM thread_messages m JOIN threads f ON f.id = m.thread_id ",
+        "WHERE m.message_number IS NOT NULL AND m.thread_id IN ",
+        expired_threads!(),
+        " GROUP BY m.thread_id",
+        ") r ON r.thread_id = t.id WHERE t.id IN ",
+        expired_threads!(),
+        " GROUP BY 1, 2 ",
+        "ON CONFLICT (day, category_name) DO UPDATE SET closed = closed + excluded.closed, ",
+        "resolution_seconds = resolution_seconds + excluded.resolution_seconds, ",
+        "response_seconds = response_seconds + excluded.response_seconds, ",
+        "responded = responded + excluded.responded"
+    ),
+    concat!(
+        "INSERT INTO archived_staff_stats (day, user_id, user_name, messages) ",
+        "SELECT date(created_at), CAST(user_id AS TEXT), MAX(user_name), COUNT(*) FROM thread_messages ",
+        "WHERE message_number IS NOT NULL AND thread_id IN ",
+        expired_threads!(),
+        " GROUP BY 1, 2 ",
+        "ON CONFLICT (day, user_id) DO UPDATE SET messages = messages + excluded.messages, ",
+        "user_name = excluded.user_name"
+    ),
+    concat!(
+        "INSERT INTO archived_staff_stats (day, user_id, tickets_closed) ",
+        "SELECT date(closed_at, 'unixepoch'), closed_by, COUNT(*) FROM threads ",
+        "WHERE closed_by IS NOT NULL AND id IN ",
+        expired_threads!(),
+        " GROUP BY 1, 2 ",
+        "ON CONFLICT (day, user_id) DO UPDATE SET tickets_closed = tickets_closed + excluded.tickets_closed"
+    ),
+    concat!(
+        "INSERT INTO archived_staff_stats (day, user_id, user_name, response_seconds, responded) ",
+        "SELECT date(t.closed_at, 'unixepoch'), CAST(r.user_id AS TEXT), MAX(r.user_name), SUM(r.response_time), COUNT(*) FROM (",
+        "SELECT m.thread_id, m.user_id, m.user_name, ",
+        "strftime('%s', MIN(m.created_at)) - strftime('%s', f.created_at) AS response_time ",
+        "FROM thread_messages m JOIN threads f ON f.id = m.thread_id ",
+        "WHERE m.message_number IS NOT NULL AND m.thread_id IN ",
+        expired_threads!(),
+        " GROUP BY m.thread_id, m.user_id, m.user_name HAVING response_time > 0",
+        ") r JOIN threads t ON t.id = r.thread_id WHERE true GROUP BY 1, 2 ",
+        "ON CONFLICT (day, user_id) DO UPDATE SET response_seconds = response_seconds + excluded.response_seconds, ",
+        "responded = responded + excluded.responded, ",
+        "user_name = CASE WHEN user_name = '' THEN excluded.user_name ELSE user_name END"
+    ),
+];
+
+// `thread_messages` and `thread_status` restrict the deletion of a ticket, so they go first.
+const CLOSED_TICKETS_STATEMENTS: [(&str, &str); 7] = [
+    (
+        "thread_messages",
+        concat!(
+            "DELETE FROM thread_messages WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "thread_status",
+        concat!(
+            "DELETE FROM thread_status WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "thread_form_answers",
+        concat!(
+            "DELETE FROM thread_form_answers WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "thread_state_history",
+        concat!(
+            "DELETE FROM thread_state_history WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "scheduled_closures",
+        concat!(
+            "DELETE FROM scheduled_closures WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "reminders",
+        concat!(
+            "DELETE FROM reminders WHERE thread_id IN ",
+            expired_threads!()
+        ),
+    ),
+    (
+        "threads",
+        concat!("DELETE FROM threads WHERE id IN ", expired_threads!()),
+    ),
+];
+
+const TRACKED_MEMBERS_COUNTS: [(&str, &str); 1] = [(
+    "tracked_members",
+    "SELECT COUNT(*) FROM tracked_members WHERE last_seen_at < ?1",
+)];
+
+const TRACKED_MEMBERS_STATEMENTS: [(&str, &str); 1] = [(
+    "tracked_members",
+    "DELETE FROM tracked_members WHERE last_seen_at < ?1",
+)];
+
+// Expired, or revoked and unused since the cutoff.
+macro_rules! dead_api_keys {
+    () => {
+        "(expires_at < ?1 OR (is_active = 0 AND COALESCE(last_used_at, created_at) < ?1))"
+    };
+}
+
+const EXPIRED_API_KEYS_COUNTS: [(&str, &str); 2] = [
+    (
+        "api_keys",
+        concat!("SELECT COUNT(*) FROM api_keys WHERE ", dead_api_keys!()),
+    ),
+    (
+        "api_key_requests",
+        concat!(
+            "SELECT COUNT(*) FROM api_key_requests WHERE api_key_id IN (SELECT id FROM api_keys WHERE ",
+            dead_api_keys!(),
+            ")"
+        ),
+    ),
+];
+
+// `api_key_requests` follow through `ON DELETE CASCADE`.
+const EXPIRED_API_KEYS_STATEMENTS: [(&str, &str); 2] = [
+    (
+        "api_key_requests",
+        concat!(
+            "DELETE FROM api_key_requests WHERE api_key_id IN (SELECT id FROM api_keys WHERE ",
+            dead_api_keys!(),
+            ")"
+        ),
+    ),
+    (
+        "api_keys",
+        concat!("DELETE FROM api_keys WHERE ", dead_api_keys!()),
+    ),
+];
+
+fn counts_for(target: RetentionTarget) -> &'static [(&'static str, &'static str)] {
+    match target {
+        RetentionTarget::MessageContent => &MESSAGE_CONTENT_COUNTS,
+        RetentionTarget::ClosedTickets => &CLOSED_TICKETS_COUNTS,
+        RetentionTarget::TrackedMembers => &TRACKED_MEMBERS_COUNTS,
+        RetentionTarget::ExpiredApiKeys => &EXPIRED_API_KEYS_COUNTS,
+    }
+}
+
+fn statements_for(target: RetentionTarget) -> &'static [(&'static str, &'static str)] {
+    match target {
+        RetentionTarget::MessageContent => &MESSAGE_CONTENT_STATEMENTS,
+        RetentionTarget::ClosedTickets => &CLOSED_TICKETS_STATEMENTS,
+        RetentionTarget::TrackedMembers => &TRACKED_MEMBERS_STATEMENTS,
+        RetentionTarget::ExpiredApiKeys => &EXPIRED_API_KEYS_STATEMENTS,
+    }
+}
+
+pub fn retention_cutoff(older_than_days: i64, now: i64) -> i64 {
+    now - older_than_days.max(0) * 24 * 60 * 60
+}
+
+pub async fn preview_retention(
+    target: RetentionTarget,
+    cutoff: i64,
+    ticket_category_id: Option<&str>,
+    pool: &SqlitePool,
+) -> ModmailResult<BTreeMap<String, u64>> {
+    let mut counts = BTreeMap::new();
+    for &(table, query) in counts_for(target) {
+        let mut query = sqlx::query_scalar(query).bind(cutoff);
+        if target.is_ticket_data() {
+            query = query.bind(ticket_category_id);
+        }
+        let count: i64 = query.fetch_one(pool).await.map_err(|e| {
+            error!("Failed to count {table} for a retention preview: {e:?}");
+            validation_failed("Failed to preview retention rule")
+        })?;
+        if count > 0 {
+            counts.insert(table.to_string(), count as u64);
+        }
+    }
+
+    Ok(counts)
+}
+
+pub async fn apply_retention(
+    target: RetentionTarget,
+    cutoff: i64,
+    ticket_category_id: Option<&str>,
+    pool: &SqlitePool,
+) -> ModmailResult<BTreeMap<String, u64>> {
+    let mut tx = pool.begin().await.map_err(|e| {
+        error!("Failed to begin retention transaction: {e:?}");
+        validation_failed("Failed to begin retention transaction")
+    })?;
+
+    if target == RetentionTarget::ClosedTickets {
+        for statement in CLOSED_TICKETS_ARCHIVE {
+            sqlx::query(statement)
+                .bind(cutoff)
+                .bind(ticket_category_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    error!("Failed to archive ticket statistics: {e:?}");
+                    validation_failed("Failed to archive ticket statistics")
+                })?;
+        }
+    }
+
+    let mut affected = BTreeMap::new();
+    for &(table, statement) in statements_for(target) {
+        let mut query = sqlx::query(statement).bind(cutoff);
+        if target.is_ticket_data() {
+            query = query.bind(ticket_category_id);
+        }
+        let result = query.execute(&mut *tx).await.map_err(|e| {
+            error!("Failed to prune {table}: {e:?}");
+            validation_failed("Failed to apply retention rule")
+        })?;
+        if result.rows_affected() > 0 {
+            affected.insert(table.to_string(), result.rows_affected());
+        }
+    }
+
+    tx.commit().await.map_err(|e| {
+        error!("Failed to commit retention transaction: {e:?}");
+        validation_failed("Failed to commit retention transaction")
+    })?;
+
+    Ok(affected)
+}
+
+fn rule_from_row(row: &SqliteRow) -> Option<RetentionRule> {
+    Some(RetentionRule {
+        id: row.get("id"),
+        target: RetentionTarget::parse(&row.get::<String, _>("target"))?,
+        older_than_days: row.get("older_than_days"),
+        ticket_category_id: row.get("ticket_category_id"),
+        enabled: row.get::<i64, _>("enabled") != 0,
+        last_run_at: row.get("last_run_at"),
+        last_affected: row.get("last_affected"),
+        created_at: row.get("created_at"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+pub async fn list_retention_rules(pool: &SqlitePool) -> ModmailResult<Vec<RetentionRule>> {
+    let rows = sqlx::query(
+        "SELECT id, target, older_than_days, ticket_category_id, enabled, last_run_at, last_affected, created_at, updated_at FROM retention_rules ORDER BY id",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list retention rules: {e:?}");
+        validation_failed("Failed to list retention rules")
+    })?;
+
+    Ok(rows.iter().filter_map(rule_from_row).collect())
+}
+
+pub async fn get_retention_rule(
+    id: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<Option<RetentionRule>> {
+    let row = sqlx::query(
+        "SELECT id, target, older_than_days, ticket_category_id, enabled, last_run_at, last_affected, created_at, updated_at FROM retention_rules WHERE id = ?",
+    )
+    .bind(id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to fetch retention rule: {e:?}");
+        validation_failed("Failed to fetch retention rule")
+    })?;
+
+    Ok(row.as_ref().and_then(rule_from_row))
+}
+
+pub async fn insert_retention_rule(
+    target: RetentionTarget,
+    older_than_days: i64,
+    ticket_category_id: Option<&str>,
+    enabled: bool,
+    pool: &SqlitePool,
+) -> ModmailResult<i64> {
+    let now = Utc::now().timestamp();
+    let result = sqlx::query(
+        "INSERT INTO retention_rules (target, older_than_days, ticket_category_id, enabled, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)",
+    )
+    .bind(target.as_str())
+    .bind(older_than_days)
+    .bind(ticket_category_id)
+    .bind(enabled)
+    .bind(now)
+    .bind(now)
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to insert retention rule: {e:?}");
+        validation_failed("Failed to insert retention rule")
+    })?;
+
+    Ok(result.last_insert_rowid())
+}
+
+pub async fn update_retention_rule(
+    id: i64,
+    target: RetentionTarget,
+    older_than_days: i64,
+    ticket_category_id: Option<&str>,
+    enabled: bool,
+    pool: &SqlitePool,
+) -> ModmailResult<bool> {
+    let result = sqlx::query(
+        "UPDATE retention_rules SET target = ?, older_than_days = ?, ticket_category_id = ?, enabled = ?, updated_at = ? WHERE id = ?",
+    )
+    .bind(target.as_str())
+    .bind(older_than_days)
+    .bind(ticket_category_id)
+    .bind(enabled)
+    .bind(Utc::now().timestamp())
+    .bind(id)
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to update retention rule: {e:?}");
+        validation_failed("Failed to update retention rule")
+    })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn delete_retention_rule(id: i64, pool: &SqlitePool) -> ModmailResult<bool> {
+    let result = sqlx::query("DELETE FROM retention_rules WHERE id = ?")
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to delete retention rule: {e:?}");
+            validation_failed("Failed to delete retention rule")
+        })?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn record_retention_run(
+    id: i64,
+    affected: u64,
+    ran_at: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query("UPDATE retention_rules SET last_run_at = ?, last_affected = ? WHERE id = ?")
+        .bind(ran_at)
+        .bind(affected as i64)
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to record retention run: {e:?}");
+            validation_failed("Failed to record retention run")
+        })?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::operations::init::{insert_test_ticket, test_count, test_pool};
+
+    const CUTOFF: i64 = 1_700_000_000;
+
+    async fn insert_ticket(id: &str, closed_at: Option<i64>, category: &str, pool: &SqlitePool) {
+        insert_test_ticket(id, id.parse().unwrap(), closed_at, Some(category), pool).await;
+        sqlx::query(
+            "INSERT INTO thread_messages (thread_id, user_id, user_name, is_anonymous, message_number, created_at, content, thread_status) VALUES (?, 7, 'Staff', false, 1, '2023-11-01 00:10:00', 'hello', 0)",
+        )
+        .bind(id)
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO thread_form_answers (thread_id, category_id, submitted_at) VALUES (?, ?, 0)",
+        )
+        .bind(id)
+        .bind(category)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[test]
+    fn retention_cutoff_counts_whole_days_back() {
+        assert_eq!(retention_cutoff(2, CUTOFF), CUTOFF - 2 * 86_400);
+        assert_eq!(retention_cutoff(-5, CUTOFF), CUTOFF);
+    }
+
+    #[tokio::test]
+    async fn message_content_expires_tickets_closed_before_the_cutoff() {
+        let pool = test_pool().await;
+        insert_ticket("1", Some(CUTOFF - 1), "a", &pool).await;
+        insert_ticket("2", Some(CUTOFF), "a", &pool).await;
+        insert_ticket("3", None, "a", &pool).await;
+
+        let preview = preview_retention(RetentionTarget::MessageContent, CUTOFF, None, &pool)
+            .await
+            .unwrap();
+        let applied = apply_retention(RetentionTarget::MessageContent, CUTOFF, None, &pool)
+            .await
+            .unwrap();
+
+        assert_eq!(preview, applied);
+        assert_eq!(applied["thread_messages"], 1);
+        assert_eq!(applied["thread_form_answers"], 1);
+        assert_eq!(
+            test_count(
+                "SELECT COUNT(*) FROM thread_messages WHERE content = '[expired]' AND thread_id = '1'",
+                &pool
+            )
+            .await,
+            1
+        );
+        assert_eq!(
+            test_count("SELECT COUNT(*) FROM thread_form_answers", &pool).await,
+            2
+        );
+        assert!(
+            apply_retention(RetentionTarget::MessageContent, CUTOFF, None, &pool)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn closed_tickets_are_archived_then_deleted_within_their_category() {
+        let pool = test_pool().await;
+        insert_ticket("1", Some(CUTOFF - 1), "a", &pool).await;
+        insert_ticket("2", Some(CUTOFF - 1), "b", &pool).await;
+        insert_ticket("3", Some(CUTOFF), "a", &pool).await;
+        sqlx::query(
+            "INSERT INTO thread_status (thread_id, channel_id, owner_id, last_message_by, last_message_at) VALUES ('1', 1, '1', 'staff', 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let applied = apply_retention(RetentionTarget::ClosedTickets, CUTOFF, Some("a"), &pool)
+            .await
+            .unwrap();
+
+        assert_eq!(applied["threads"], 1);
+        assert_eq!(applied["thread_status"], 1);
+        assert_eq!(
+            test_count("SELECT COUNT(*) FROM threads WHERE id IN ('2', '3')", &pool).await,
+            2
+        );
+        assert_eq!(
+            test_count(
+                "SELECT SUM(closed + created) FROM archived_ticket_stats WHERE category_name = 'Support'",
+                &pool
+            )
+            .await,
+            2
+        );
+        assert_eq!(
+            test_count(
+                "SELECT SUM(messages + tickets_closed + responded) FROM archived_staff_stats WHERE user_id = '7'",
+                &pool
+            )
+            .await,
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn tracked_members_unseen_since_the_cutoff_are_deleted() {
+        let pool = test_pool().await;
+        for (user_id, last_seen_at) in [("1", CUTOFF - 1), ("2", CUTOFF)] {
+            sqlx::query(
+                "INSERT INTO tracked_members (guild_id, user_id, username, first_seen_at, last_seen_at) VALUES ('9', ?, 'member', 0, ?)",
+            )
+            .bind(user_id)
+            .bind(last_seen_at)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+
+        let applied = apply_retention(RetentionTarget::TrackedMembers, CUTOFF, None, &pool)
+            .await
+            .unwrap();
+
+        assert_eq!(applied["tracked_members"], 1);
+        assert_eq!(
+            test_count(
+                "SELECT COUNT(*) FROM tracked_members WHERE user_id = '2'",
+                &pool
+            )
+            .await,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn expired_and_long_revoked_api_keys_are_deleted_with_their_requests() {
+        let pool = test_pool().await;
+        let keys = [
+            ("expired", Some(CUTOFF - 1), None, 1),
+            ("expires_at_cutoff", Some(CUTOFF), None, 1),
+            ("revoked_unused", None, Some(CUTOFF - 1), 0),
+            ("revoked_recently_used", None, Some(CUTOFF), 0),
+            ("active", None, Some(0), 1),
+        ];
+        for (name, expires_at, last_used_at, is_active) in keys {
+            sqlx::query(
+                "INSERT INTO api_keys (key_hash, name, permissions, created_at, expires_at, last_used_at, is_active) VALUES (?1, ?1, '[]', 0, ?2, ?3, ?4)",
+            )
+            .bind(name)
+            .bind(expires_at)
+            .bind(last_used_at)
+            .bind(is_active)
+            .execute(&pool)
+            .await
+            .unwrap();
+        }
+        sqlx::query(
+            "INSERT INTO api_key_requests (api_key_id, method, route, status, latency_ms, created_at) SELECT id, 'GET', '/', 200, 1, 0 FROM api_keys",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let preview = preview_retention(RetentionTarget::ExpiredApiKeys, CUTOFF, None, &pool)
+            .await
+            .unwrap();
+        let applied = apply_retention(RetentionTarget::ExpiredApiKeys, CUTOFF, None, &pool)
+            .await
+            .unwrap();
+
+        assert_eq!(preview, applied);
+        assert_eq!(applied["api_keys"], 2);
+        assert_eq!(applied["api_key_requests"], 2);
+        let remaining: Vec<String> = sqlx::query_scalar("SELECT name FROM api_keys ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(
+            remaining,
+            ["expires_at_cutoff", "revoked_recently_used", "active"]
+        );
+    }
+}
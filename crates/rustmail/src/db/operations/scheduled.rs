@@ -1,5 +1,8 @@
+use crate::db::operations::jobs::{cancel_job, enqueue_job};
+use crate::db::repr::JobKind;
 use crate::prelude::errors::*;
 use sqlx::{Row, SqlitePool};
+use tracing::error;
 
 #[derive(Debug, Clone)]
 pub struct ScheduledClosure {
@@ -53,10 +56,12 @@ pub async fn upsert_scheduled_closure(
     .execute(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to upsert scheduled closure: {e:?}");
+        error!("Failed to upsert scheduled closure: {e:?}");
         common::validation_failed("Failed to upsert scheduled closure")
     })?;
 
+    enqueue_job(JobKind::ScheduledClosure, thread_id, "{}", close_at, pool).await?;
+
     Ok(())
 }
 
@@ -66,6 +71,7 @@ pub async fn delete_scheduled_closure(thread_id: &str, pool: &SqlitePool) -> Mod
         .execute(pool)
         .await
         .map_err(|_| common::validation_failed("Failed to delete scheduled closure"))?;
+    cancel_job(JobKind::ScheduledClosure, thread_id, pool).await?;
     Ok(res.rows_affected() > 0)
 }
 
@@ -91,23 +97,3 @@ pub async fn get_scheduled_closure(
         required_permissions: row.get::<String, _>(6),
     }))
 }
-
-pub async fn get_all_scheduled_closures(pool: &SqlitePool) -> ModmailResult<Vec<ScheduledClosure>> {
-    let rows = sqlx::query("SELECT thread_id, close_at, silent FROM scheduled_closures")
-        .fetch_all(pool)
-        .await
-        .map_err(|_| common::validation_failed("Failed to fetch scheduled closures"))?;
-
-    Ok(rows
-        .into_iter()
-        .map(|row| ScheduledClosure {
-            thread_id: row.get::<String, _>(0),
-            close_at: row.get::<i64, _>(1),
-            silent: row.get::<i64, _>(2) != 0,
-            closed_by: row.get::<String, _>(3),
-            category_id: row.get::<String, _>(4),
-            category_name: row.get::<String, _>(5),
-            required_permissions: row.get::<String, _>(6),
-        })
-        .collect())
-}
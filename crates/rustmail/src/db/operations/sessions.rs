@@ -0,0 +1,312 @@
+use crate::db::repr::PanelSession;
+use crate::prelude::errors::*;
+use sqlx::{Row, SqlitePool};
+use tracing::error;
+use uuid::Uuid;
+
+const SESSION_TOUCH_INTERVAL_SECS: i64 = 60;
+
+fn row_to_session(row: sqlx::sqlite::SqliteRow) -> PanelSession {
+    PanelSession {
+        id: row.get::<String, _>("public_id"),
+        user_id: row.get::<String, _>("user_id"),
+        created_at: row.get::<i64, _>("created_at"),
+        expires_at: row.get::<i64, _>("expires_at"),
+        last_seen_at: row.get::<Option<i64>, _>("last_seen_at"),
+        ip: row.get::<Option<String>, _>("ip"),
+        user_agent: row.get::<Option<String>, _>("user_agent"),
+    }
+}
+
+pub struct NewPanelSession<'a> {
+    pub session_id: &'a str,
+    pub user_id: &'a str,
+    pub access_token: &'a str,
+    pub refresh_token: &'a str,
+    pub expires_at: i64,
+    pub avatar_hash: Option<&'a str>,
+    pub user_agent: Option<&'a str>,
+    pub now: i64,
+}
+
+pub async fn create_session(session: NewPanelSession<'_>, pool: &SqlitePool) -> ModmailResult<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO sessions_panel (session_id, public_id, user_id, access_token, refresh_token,
+                                    expires_at, avatar_hash, created_at, last_seen_at, user_agent)
+        VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        "#,
+    )
+    .bind(session.session_id)
+    .bind(Uuid::new_v4().simple().to_string())
+    .bind(session.user_id)
+    .bind(session.access_token)
+    .bind(session.refresh_token)
+    .bind(session.expires_at)
+    .bind(session.avatar_hash)
+    .bind(session.now)
+    .bind(session.now)
+    .bind(session.user_agent)
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to store panel session: {e:?}");
+        validation_failed("Failed to store panel session")
+    })?;
+
+    Ok(())
+}
+
+pub async fn list_sessions(
+    user_id: Option<&str>,
+    pool: &SqlitePool,
+) -> ModmailResult<Vec<PanelSession>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT public_id, user_id, created_at, expires_at, last_seen_at, ip, user_agent
+        FROM sessions_panel
+        WHERE ? IS NULL OR user_id = ?
+        ORDER BY COALESCE(last_seen_at, created_at) DESC
+        "#,
+    )
+    .bind(user_id)
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to list panel sessions"))?;
+
+    Ok(rows.into_iter().map(row_to_session).collect())
+}
+
+pub async fn get_session_public_id(session_id: &str, pool: &SqlitePool) -> Option<String> {
+    sqlx::query_scalar("SELECT public_id FROM sessions_panel WHERE session_id = ?")
+        .bind(session_id)
+        .fetch_optional(pool)
+        .await
+        .ok()
+        .flatten()
+}
+
+pub async fn delete_session(session_id: &str, pool: &SqlitePool) -> ModmailResult<bool> {
+    let res = sqlx::query("DELETE FROM sessions_panel WHERE session_id = ?")
+        .bind(session_id)
+        .execute(pool)
+        .await
+        .map_err(|_| validation_failed("Failed to revoke panel session"))?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn delete_session_by_public_id(
+    public_id: &str,
+    user_id: Option<&str>,
+    pool: &SqlitePool,
+) -> ModmailResult<bool> {
+    let res = sqlx::query(
+        "DELETE FROM sessions_panel WHERE public_id = ? AND (? IS NULL OR user_id = ?)",
+    )
+    .bind(public_id)
+    .bind(user_id)
+    .bind(user_id)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to revoke panel session"))?;
+
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn delete_sessions_for_user(user_id: &str, pool: &SqlitePool) -> ModmailResult<u64> {
+    let res = sqlx::query("DELETE FROM sessions_panel WHERE user_id = ?")
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .map_err(|_| validation_failed("Failed to revoke panel sessions"))?;
+
+    Ok(res.rows_affected())
+}
+
+pub async fn list_session_user_ids(pool: &SqlitePool) -> ModmailResult<Vec<String>> {
+    sqlx::query_scalar("SELECT DISTINCT user_id FROM sessions_panel")
+        .fetch_all(pool)
+        .await
+        .map_err(|_| validation_failed("Failed to list panel sessions"))
+}
+
+pub async fn delete_expired_sessions(
+    now: i64,
+    created_before: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<u64> {
+    let res = sqlx::query("DELETE FROM sessions_panel WHERE expires_at < ? OR created_at < ?")
+        .bind(now)
+        .bind(created_before)
+        .execute(pool)
+        .await
+        .map_err(|_| validation_failed("Failed to delete expired panel sessions"))?;
+
+    Ok(res.rows_affected())
+}
+
+pub async fn touch_session(
+    session_id: &str,
+    ip: Option<&str>,
+    now: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE sessions_panel SET last_seen_at = ?, ip = COALESCE(?, ip)
+        WHERE session_id = ? AND (last_seen_at IS NULL OR last_seen_at < ?)
+        "#,
+    )
+    .bind(now)
+    .bind(ip)
+    .bind(session_id)
+    .bind(now - SESSION_TOUCH_INTERVAL_SECS)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to update panel session"))?;
+
+    Ok(())
+}
+
+pub async fn list_sessions_to_refresh(
+    before: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<Vec<(String, String)>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT session_id, refresh_token FROM sessions_panel
+        WHERE expires_at < ? AND refresh_token IS NOT NULL AND refresh_token != ''
+        "#,
+    )
+    .bind(before)
+    .fetch_all(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to list panel sessions"))?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            (
+                row.get::<String, _>("session_id"),
+                row.get::<String, _>("refresh_token"),
+            )
+        })
+        .collect())
+}
+
+pub async fn update_session_tokens(
+    session_id: &str,
+    access_token: &str,
+    refresh_token: &str,
+    expires_at: i64,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query(
+        r#"
+        UPDATE sessions_panel SET access_token = ?, refresh_token = ?, expires_at = ?
+        WHERE session_id = ?
+        "#,
+    )
+    .bind(access_token)
+    .bind(refresh_token)
+    .bind(expires_at)
+    .bind(session_id)
+    .execute(pool)
+    .await
+    .map_err(|_| validation_failed("Failed to update panel session"))?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::operations::init::test_pool;
+
+    async fn insert(
+        pool: &SqlitePool,
+        session_id: &str,
+        user_id: &str,
+        created_at: i64,
+        expires_at: i64,
+    ) {
+        create_session(
+            NewPanelSession {
+                session_id,
+                user_id,
+                access_token: "access",
+                refresh_token: "refresh",
+                expires_at,
+                avatar_hash: None,
+                user_agent: None,
+                now: created_at,
+            },
+            pool,
+        )
+        .await
+        .unwrap();
+    }
+
+    async fn remaining(pool: &SqlitePool) -> Vec<String> {
+        sqlx::query_scalar("SELECT session_id FROM sessions_panel ORDER BY session_id")
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn revoking_by_public_id_checks_the_owner() {
+        let pool = test_pool().await;
+        insert(&pool, "a", "1", 100, 1000).await;
+        insert(&pool, "b", "2", 100, 1000).await;
+        let public_id = get_session_public_id("a", &pool).await.unwrap();
+
+        assert!(
+            !delete_session_by_public_id(&public_id, Some("2"), &pool)
+                .await
+                .unwrap()
+        );
+        assert_eq!(remaining(&pool).await, ["a", "b"]);
+
+        assert!(
+            delete_session_by_public_id(&public_id, Some("1"), &pool)
+                .await
+                .unwrap()
+        );
+        assert_eq!(remaining(&pool).await, ["b"]);
+
+        let public_id = get_session_public_id("b", &pool).await.unwrap();
+        assert!(
+            delete_session_by_public_id(&public_id, None, &pool)
+                .await
+                .unwrap()
+        );
+        assert!(remaining(&pool).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn revoking_a_user_leaves_other_users_signed_in() {
+        let pool = test_pool().await;
+        insert(&pool, "a", "1", 100, 1000).await;
+        insert(&pool, "b", "1", 100, 1000).await;
+        insert(&pool, "c", "2", 100, 1000).await;
+
+        assert_eq!(delete_sessions_for_user("1", &pool).await.unwrap(), 2);
+        assert_eq!(remaining(&pool).await, ["c"]);
+        assert_eq!(delete_sessions_for_user("1", &pool).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn expiry_cleanup_keeps_sessions_on_the_boundary() {
+        let pool = test_pool().await;
+        insert(&pool, "expired", "1", 500, 999).await;
+        insert(&pool, "expires_now", "1", 500, 1000).await;
+        insert(&pool, "too_old", "1", 199, 5000).await;
+        insert(&pool, "created_at_cutoff", "1", 200, 5000).await;
+
+        assert_eq!(delete_expired_sessions(1000, 200, &pool).await.unwrap(), 2);
+        assert_eq!(remaining(&pool).await, ["created_at_cutoff", "expires_now"]);
+    }
+}
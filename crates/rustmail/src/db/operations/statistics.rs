@@ -1,7 +1,8 @@
 use serde::Serialize;
 use sqlx::{FromRow, SqlitePool};
+use utoipa::ToSchema;
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct StatisticsOverview {
     pub open_tickets: i64,
     pub total_closed: i64,
@@ -12,14 +13,14 @@ pub struct StatisticsOverview {
     pub avg_resolution_time_seconds: Option<i64>,
 }
 
-#[derive(Debug, Clone, Serialize, FromRow)]
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
 pub struct DailyActivity {
     pub date: String,
     pub created: i64,
     pub closed: i64,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct CategoryStats {
     pub name: String,
     pub count: i64,
@@ -32,7 +33,7 @@ struct CategoryRow {
     cnt: i64,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct StaffMember {
     pub user_id: String,
     pub username: String,
@@ -49,7 +50,7 @@ struct StaffRow {
     tickets_closed: i64,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct TopPerformer {
     pub user_id: String,
     pub username: String,
@@ -77,14 +78,14 @@ struct MostTicketsRow {
     cnt: i64,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct TopPerformers {
     pub fastest_responder: Option<TopPerformer>,
     pub most_messages: Option<TopPerformer>,
     pub most_tickets_closed: Option<TopPerformer>,
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct Statistics {
     pub overview: StatisticsOverview,
     pub activity: Vec<DailyActivity>,
@@ -114,40 +115,49 @@ async fn get_overview(pool: &SqlitePool) -> Result<StatisticsOverview, sqlx::Err
         .fetch_one(pool)
         .await?;
 
-    let total_closed: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM threads WHERE status = 0")
-        .fetch_one(pool)
-        .await?;
+    let total_closed: i64 = sqlx::query_scalar(
+        "SELECT (SELECT COUNT(*) FROM threads WHERE status = 0) + (SELECT COALESCE(SUM(closed), 0) FROM archived_ticket_stats)"
+    )
+    .fetch_one(pool)
+    .await?;
 
     let closed_today: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM threads WHERE status = 0 AND closed_at >= strftime('%s', 'now', 'start of day')"
+        "SELECT (SELECT COUNT(*) FROM threads WHERE status = 0 AND closed_at >= strftime('%s', 'now', 'start of day')) + (SELECT COALESCE(SUM(closed), 0) FROM archived_ticket_stats WHERE day >= date('now', 'start of day'))"
     )
     .fetch_one(pool)
     .await?;
 
     let closed_this_week: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM threads WHERE status = 0 AND closed_at >= strftime('%s', 'now', '-7 days')"
+        "SELECT (SELECT COUNT(*) FROM threads WHERE status = 0 AND closed_at >= strftime('%s', 'now', '-7 days')) + (SELECT COALESCE(SUM(closed), 0) FROM archived_ticket_stats WHERE day >= date('now', '-7 days'))"
     )
     .fetch_one(pool)
     .await?;
 
     let closed_this_month: i64 = sqlx::query_scalar(
-        "SELECT COUNT(*) FROM threads WHERE status = 0 AND closed_at >= strftime('%s', 'now', 'start of month')"
+        "SELECT (SELECT COUNT(*) FROM threads WHERE status = 0 AND closed_at >= strftime('%s', 'now', 'start of month')) + (SELECT COALESCE(SUM(closed), 0) FROM archived_ticket_stats WHERE day >= date('now', 'start of month'))"
     )
     .fetch_one(pool)
     .await?;
 
     let avg_response_time: Option<i64> = sqlx::query_scalar(
         r#"
-        SELECT CAST(AVG(first_response_time) AS INTEGER)
+        SELECT CAST((live.total + archived.total) * 1.0 / NULLIF(live.n + archived.n, 0) AS INTEGER)
         FROM (
-            SELECT strftime('%s', MIN(m.created_at)) - strftime('%s', t.created_at) AS first_response_time
-            FROM threads t
-            JOIN thread_messages m ON m.thread_id = t.id
-            WHERE m.message_number IS NOT NULL
-            AND t.status = 0
-            GROUP BY t.id
-            HAVING first_response_time > 0
-        )
+            SELECT COALESCE(SUM(first_response_time), 0) AS total, COUNT(*) AS n
+            FROM (
+                SELECT strftime('%s', MIN(m.created_at)) - strftime('%s', t.created_at) AS first_response_time
+                FROM threads t
+                JOIN thread_messages m ON m.thread_id = t.id
+                WHERE m.message_number IS NOT NULL
+                AND t.status = 0
+                GROUP BY t.id
+                HAVING first_response_time > 0
+            )
+        ) live,
+        (
+            SELECT COALESCE(SUM(response_seconds), 0) AS total, COALESCE(SUM(responded), 0) AS n
+            FROM archived_ticket_stats
+        ) archived
         "#
     )
     .fetch_optional(pool)
@@ -155,7 +165,18 @@ async fn get_overview(pool: &SqlitePool) -> Result<StatisticsOverview, sqlx::Err
     .flatten();
 
     let avg_resolution_time: Option<i64> = sqlx::query_scalar(
-        "SELECT CAST(AVG(closed_at - strftime('%s', created_at)) AS INTEGER) FROM threads WHERE status = 0 AND closed_at IS NOT NULL"
+        r#"
+        SELECT CAST((live.total + archived.total) * 1.0 / NULLIF(live.n + archived.n, 0) AS INTEGER)
+        FROM (
+            SELECT COALESCE(SUM(closed_at - strftime('%s', created_at)), 0) AS total, COUNT(*) AS n
+            FROM threads
+            WHERE status = 0 AND closed_at IS NOT NULL
+        ) live,
+        (
+            SELECT COALESCE(SUM(resolution_seconds), 0) AS total, COALESCE(SUM(closed), 0) AS n
+            FROM archived_ticket_stats
+        ) archived
+        "#,
     )
     .fetch_optional(pool)
     .await?
@@ -203,10 +224,28 @@ async fn get_daily_activity(
     .fetch_all(pool)
     .await?;
 
-    let created_map: HashMap<String, i64> =
+    #[derive(sqlx::FromRow)]
+    struct ArchivedRow {
+        day: String,
+        created: i64,
+        closed: i64,
+    }
+
+    let archived_rows: Vec<ArchivedRow> = sqlx::query_as(
+        "SELECT day, SUM(created) as created, SUM(closed) as closed FROM archived_ticket_stats WHERE day >= ? GROUP BY day"
+    )
+    .bind(&start_str)
+    .fetch_all(pool)
+    .await?;
+
+    let mut created_map: HashMap<String, i64> =
         created_rows.into_iter().map(|r| (r.day, r.cnt)).collect();
-    let closed_map: HashMap<String, i64> =
+    let mut closed_map: HashMap<String, i64> =
         closed_rows.into_iter().map(|r| (r.day, r.cnt)).collect();
+    for row in archived_rows {
+        *created_map.entry(row.day.clone()).or_default() += row.created;
+        *closed_map.entry(row.day).or_default() += row.closed;
+    }
 
     let mut results = Vec::new();
     for i in 0..days {
@@ -223,9 +262,11 @@ async fn get_daily_activity(
 }
 
 async fn get_category_stats(pool: &SqlitePool) -> Result<Vec<CategoryStats>, sqlx::Error> {
-    let total: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM threads WHERE status = 0")
-        .fetch_one(pool)
-        .await?;
+    let total: i64 = sqlx::query_scalar(
+        "SELECT (SELECT COUNT(*) FROM threads WHERE status = 0) + (SELECT COALESCE(SUM(closed), 0) FROM archived_ticket_stats)"
+    )
+    .fetch_one(pool)
+    .await?;
 
     if total == 0 {
         return Ok(vec![]);
@@ -233,12 +274,23 @@ async fn get_category_stats(pool: &SqlitePool) -> Result<Vec<CategoryStats>, sql
 
     let rows: Vec<CategoryRow> = sqlx::query_as(
         r#"
-        SELECT
-            COALESCE(category_name, 'Uncategorized') as name,
-            COUNT(*) as cnt
-        FROM threads
-        WHERE status = 0
-        GROUP BY category_name
+        SELECT name, SUM(cnt) as cnt
+        FROM (
+            SELECT
+                COALESCE(category_name, 'Uncategorized') as name,
+                COUNT(*) as cnt
+            FROM threads
+            WHERE status = 0
+            GROUP BY category_name
+            UNION ALL
+            SELECT
+                CASE WHEN category_name = '' THEN 'Uncategorized' ELSE category_name END,
+                SUM(closed)
+            FROM archived_ticket_stats
+            GROUP BY category_name
+            HAVING SUM(closed) > 0
+        )
+        GROUP BY name
         ORDER BY cnt DESC
         LIMIT 10
         "#,
@@ -263,28 +315,41 @@ async fn get_staff_leaderboard(
     let rows: Vec<StaffRow> = sqlx::query_as(
         r#"
         SELECT
-            m.user_id as user_id,
-            m.user_name as username,
-            COUNT(*) as messages_count,
-            COALESCE(closed.tickets_closed, 0) as tickets_closed
-        FROM thread_messages m
-        JOIN threads t ON m.thread_id = t.id
-        LEFT JOIN (
-            SELECT closed_by, COUNT(*) as tickets_closed
-            FROM threads
-            WHERE status = 0
-            AND closed_at >= strftime('%s', 'now', '-' || ? || ' days')
-            GROUP BY closed_by
-        ) closed ON CAST(m.user_id AS TEXT) = closed.closed_by
-        WHERE m.message_number IS NOT NULL
-        AND m.created_at >= strftime('%s', 'now', '-' || ? || ' days')
-        GROUP BY m.user_id, m.user_name
+            CAST(user_id AS INTEGER) as user_id,
+            MAX(username) as username,
+            SUM(messages_count) as messages_count,
+            SUM(tickets_closed) as tickets_closed
+        FROM (
+            SELECT
+                m.user_id as user_id,
+                m.user_name as username,
+                COUNT(*) as messages_count,
+                COALESCE(closed.tickets_closed, 0) as tickets_closed
+            FROM thread_messages m
+            JOIN threads t ON m.thread_id = t.id
+            LEFT JOIN (
+                SELECT closed_by, COUNT(*) as tickets_closed
+                FROM threads
+                WHERE status = 0
+                AND closed_at >= strftime('%s', 'now', '-' || ?1 || ' days')
+                GROUP BY closed_by
+            ) closed ON CAST(m.user_id AS TEXT) = closed.closed_by
+            WHERE m.message_number IS NOT NULL
+            AND m.created_at >= strftime('%s', 'now', '-' || ?1 || ' days')
+            GROUP BY m.user_id, m.user_name
+            UNION ALL
+            SELECT user_id, user_name, SUM(messages), SUM(tickets_closed)
+            FROM archived_staff_stats
+            WHERE day >= date('now', '-' || ?1 || ' days')
+            GROUP BY user_id
+        )
+        GROUP BY CAST(user_id AS INTEGER)
+        HAVING SUM(messages_count) > 0
         ORDER BY messages_count DESC
         LIMIT 20
         "#,
     )
     .bind(days)
-    .bind(days)
     .fetch_all(pool)
     .await?;
 
@@ -304,22 +369,35 @@ async fn get_top_performers(pool: &SqlitePool) -> Result<TopPerformers, sqlx::Er
     let fastest: Option<FastestResponderRow> = sqlx::query_as(
         r#"
         SELECT
-            m.user_id as user_id,
-            m.user_name as username,
-            CAST(AVG(response_time) AS INTEGER) as avg_time
+            CAST(user_id AS INTEGER) as user_id,
+            MAX(username) as username,
+            CAST(SUM(total) * 1.0 / SUM(n) AS INTEGER) as avg_time
         FROM (
             SELECT
-                m.user_id,
-                m.user_name,
-                strftime('%s', MIN(m.created_at)) - strftime('%s', t.created_at) AS response_time
-            FROM thread_messages m
-            JOIN threads t ON m.thread_id = t.id
-            WHERE m.message_number IS NOT NULL
-            GROUP BY m.thread_id, m.user_id, m.user_name
-            HAVING response_time > 0
-        ) m
-        GROUP BY m.user_id, m.user_name
-        HAVING COUNT(*) >= 5
+                m.user_id as user_id,
+                m.user_name as username,
+                SUM(response_time) as total,
+                COUNT(*) as n
+            FROM (
+                SELECT
+                    m.user_id,
+                    m.user_name,
+                    strftime('%s', MIN(m.created_at)) - strftime('%s', t.created_at) AS response_time
+                FROM thread_messages m
+                JOIN threads t ON m.thread_id = t.id
+                WHERE m.message_number IS NOT NULL
+                GROUP BY m.thread_id, m.user_id, m.user_name
+                HAVING response_time > 0
+            ) m
+            GROUP BY m.user_id, m.user_name
+            UNION ALL
+            SELECT user_id, user_name, SUM(response_seconds), SUM(responded)
+            FROM archived_staff_stats
+            GROUP BY user_id
+            HAVING SUM(responded) > 0
+        )
+        GROUP BY CAST(user_id AS INTEGER)
+        HAVING SUM(n) >= 5
         ORDER BY avg_time ASC
         LIMIT 1
         "#,
@@ -330,12 +408,24 @@ async fn get_top_performers(pool: &SqlitePool) -> Result<TopPerformers, sqlx::Er
     let most_messages: Option<MostMessagesRow> = sqlx::query_as(
         r#"
         SELECT
-            user_id as user_id,
-            user_name as username,
-            COUNT(*) as cnt
-        FROM thread_messages
-        WHERE message_number IS NOT NULL
-        GROUP BY user_id, user_name
+            CAST(user_id AS INTEGER) as user_id,
+            MAX(username) as username,
+            SUM(cnt) as cnt
+        FROM (
+            SELECT
+                user_id as user_id,
+                user_name as username,
+                COUNT(*) as cnt
+            FROM thread_messages
+            WHERE message_number IS NOT NULL
+            GROUP BY user_id, user_name
+            UNION ALL
+            SELECT user_id, user_name, SUM(messages)
+            FROM archived_staff_stats
+            GROUP BY user_id
+            HAVING SUM(messages) > 0
+        )
+        GROUP BY CAST(user_id AS INTEGER)
         ORDER BY cnt DESC
         LIMIT 1
         "#,
@@ -346,17 +436,29 @@ async fn get_top_performers(pool: &SqlitePool) -> Result<TopPerformers, sqlx::Er
     let most_tickets: Option<MostTicketsRow> = sqlx::query_as(
         r#"
         SELECT
-            t.closed_by as user_id,
-            COALESCE(m.user_name, t.closed_by) as username,
-            COUNT(*) as cnt
-        FROM threads t
-        LEFT JOIN (
-            SELECT DISTINCT CAST(user_id AS TEXT) as user_id_str, user_name
-            FROM thread_messages
-            WHERE message_number IS NOT NULL
-        ) m ON m.user_id_str = t.closed_by
-        WHERE t.status = 0 AND t.closed_by IS NOT NULL
-        GROUP BY t.closed_by
+            user_id,
+            MAX(username) as username,
+            SUM(cnt) as cnt
+        FROM (
+            SELECT
+                t.closed_by as user_id,
+                COALESCE(m.user_name, t.closed_by) as username,
+                COUNT(*) as cnt
+            FROM threads t
+            LEFT JOIN (
+                SELECT DISTINCT CAST(user_id AS TEXT) as user_id_str, user_name
+                FROM thread_messages
+                WHERE message_number IS NOT NULL
+            ) m ON m.user_id_str = t.closed_by
+            WHERE t.status = 0 AND t.closed_by IS NOT NULL
+            GROUP BY t.closed_by
+            UNION ALL
+            SELECT user_id, COALESCE(NULLIF(MAX(user_name), ''), user_id), SUM(tickets_closed)
+            FROM archived_staff_stats
+            GROUP BY user_id
+            HAVING SUM(tickets_closed) > 0
+        )
+        GROUP BY user_id
         ORDER BY cnt DESC
         LIMIT 1
         "#,
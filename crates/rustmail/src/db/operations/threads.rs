@@ -4,6 +4,7 @@ use crate::prelude::types::*;
 use chrono::Utc;
 use serenity::all::{ChannelId, GuildChannel, UserId};
 use sqlx::{Error, SqlitePool};
+use tracing::{error, info};
 use uuid::Uuid;
 
 pub async fn get_thread_channel_by_user_id(user_id: UserId, pool: &SqlitePool) -> Option<String> {
@@ -12,7 +13,7 @@ pub async fn get_thread_channel_by_user_id(user_id: UserId, pool: &SqlitePool) -
         .fetch_optional(pool)
         .await
         .map_err(|e| {
-            eprintln!("Database error getting thread channel: {:?}", e);
+            error!("Database error getting thread channel: {:?}", e);
             e
         })
         .ok()
@@ -30,7 +31,7 @@ pub async fn get_thread_by_user_id(user_id: UserId, pool: &SqlitePool) -> Option
     .fetch_optional(pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error getting thread by channel ID: {:?}", e);
+        error!("Database error getting thread by channel ID: {:?}", e);
         e
     })
     .ok()
@@ -43,7 +44,7 @@ pub async fn get_thread_id_by_user_id(user_id: UserId, pool: &SqlitePool) -> Opt
         .fetch_optional(pool)
         .await
         .map_err(|e| {
-            eprintln!("Database error getting thread ID: {:?}", e);
+            error!("Database error getting thread ID: {:?}", e);
             e
         })
         .ok()
@@ -59,7 +60,7 @@ pub async fn get_thread_by_channel_id(channel_id: &str, pool: &SqlitePool) -> Op
     .fetch_optional(pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error getting thread by channel ID: {:?}", e);
+        error!("Database error getting thread by channel ID: {:?}", e);
         e
     })
     .ok()
@@ -75,7 +76,7 @@ pub async fn get_thread_by_id(thread_id: &str, pool: &SqlitePool) -> Option<Thre
     .fetch_optional(pool)
     .await
     .map_err(|e| {
-        eprintln!("Database error getting thread by id: {:?}", e);
+        error!("Database error getting thread by id: {:?}", e);
         e
     })
     .ok()
@@ -88,7 +89,7 @@ pub async fn get_user_id_from_channel_id(channel_id: &str, pool: &SqlitePool) ->
         .fetch_optional(pool)
         .await
         .map_err(|e| {
-            eprintln!("Database error getting user ID from channel: {:?}", e);
+            error!("Database error getting user ID from channel: {:?}", e);
             e
         })
         .ok()
@@ -101,7 +102,7 @@ pub async fn get_user_name_from_thread_id(thread_id: &str, pool: &SqlitePool) ->
         .fetch_optional(pool)
         .await
         .map_err(|e| {
-            eprintln!("Database error getting user name: {:?}", e);
+            error!("Database error getting user name: {:?}", e);
             e
         })
         .ok()
@@ -130,7 +131,23 @@ pub async fn create_thread_for_user(
     .execute(&pool.clone())
     .await
     {
-        Ok(_) => Ok(thread_id.clone()),
+        Ok(_) => {
+            let now = Utc::now().timestamp();
+            sqlx::query("UPDATE threads SET state_changed_at = ? WHERE id = ?")
+                .bind(now)
+                .bind(&thread_id)
+                .execute(pool)
+                .await?;
+            sqlx::query(
+                "INSERT INTO thread_state_history (thread_id, from_state, to_state, changed_by, created_at) VALUES (?, NULL, 'open', NULL, ?)",
+            )
+            .bind(&thread_id)
+            .bind(now)
+            .execute(pool)
+            .await?;
+            request_board_refresh(pool).await;
+            Ok(thread_id.clone())
+        }
         Err(Error::Database(db_err))
             if db_err.code() == Some(std::borrow::Cow::Borrowed("2067")) =>
         {
@@ -191,7 +208,7 @@ pub async fn is_thread_silent(thread_id: &str, pool: &SqlitePool) -> bool {
         .fetch_optional(pool)
         .await
         .map_err(|e| {
-            eprintln!("Database error reading thread silent flag: {:?}", e);
+            error!("Database error reading thread silent flag: {:?}", e);
             e
         })
         .ok()
@@ -206,7 +223,7 @@ pub async fn mark_thread_engaged(thread_id: &str, pool: &SqlitePool) {
         .execute(pool)
         .await
     {
-        eprintln!("Failed to clear thread silent flag: {:?}", e);
+        error!("Failed to clear thread silent flag: {:?}", e);
     }
 }
 
@@ -243,6 +260,15 @@ pub async fn close_thread(
     .execute(pool)
     .await?;
 
+    set_thread_workflow_state(
+        thread_id,
+        TicketWorkflowState::Closed,
+        None,
+        Some(closed_by),
+        pool,
+    )
+    .await?;
+
     Ok(())
 }
 
@@ -474,7 +500,9 @@ pub async fn get_all_thread_status(pool: &SqlitePool) -> Vec<TicketState> {
                ts.taken_by,
                ts.last_message_by,
                ts.last_message_at,
-               ts.label
+               ts.label,
+               t.priority,
+               t.workflow_state
         FROM thread_status ts
         JOIN threads t ON ts.thread_id = t.id
         WHERE t.status = 1
@@ -495,11 +523,17 @@ pub async fn get_all_thread_status(pool: &SqlitePool) -> Vec<TicketState> {
                     ),
                     last_message_at: r.get("last_message_at"),
                     label: r.get("label"),
+                    priority: TicketPriority::parse(&r.get::<String, _>("priority"))
+                        .unwrap_or_default(),
+                    workflow_state: TicketWorkflowState::parse(
+                        &r.get::<String, _>("workflow_state"),
+                    )
+                    .unwrap_or(TicketWorkflowState::Open),
                 })
                 .collect()
         }
         Err(e) => {
-            eprintln!("Database error getting thread statuses: {:?}", e);
+            error!("Database error getting thread statuses: {:?}", e);
             vec![]
         }
     }
@@ -509,14 +543,17 @@ pub async fn get_thread_status(thread_id: &str, pool: &SqlitePool) -> Option<Tic
     match sqlx::query(
         r#"
         SELECT
-            channel_id,
-            owner_id,
-            taken_by,
-            last_message_by,
-            last_message_at,
-            label
-        FROM thread_status
-        WHERE thread_id = ?
+            ts.channel_id,
+            ts.owner_id,
+            ts.taken_by,
+            ts.last_message_by,
+            ts.last_message_at,
+            ts.label,
+            COALESCE(t.priority, 'normal') AS priority,
+            COALESCE(t.workflow_state, 'open') AS workflow_state
+        FROM thread_status ts
+        LEFT JOIN threads t ON t.id = ts.thread_id
+        WHERE ts.thread_id = ?
         "#,
     )
     .bind(thread_id)
@@ -534,11 +571,15 @@ pub async fn get_thread_status(thread_id: &str, pool: &SqlitePool) -> Option<Tic
                 ),
                 last_message_at: row.get("last_message_at"),
                 label: row.get("label"),
+                priority: TicketPriority::parse(&row.get::<String, _>("priority"))
+                    .unwrap_or_default(),
+                workflow_state: TicketWorkflowState::parse(&row.get::<String, _>("workflow_state"))
+                    .unwrap_or(TicketWorkflowState::Open),
             })
         }
         Ok(None) => None,
         Err(e) => {
-            eprintln!(
+            error!(
                 "⚠️ Database error getting thread status for id {}: {:?}",
                 thread_id, e
             );
@@ -554,7 +595,7 @@ pub async fn update_thread_status_db(
 ) -> ModmailResult<()> {
     let last_message_by = format!("{:?}", ticket.last_message_by).to_lowercase();
 
-    println!(
+    info!(
         "Updating thread status for thread_id {}: taken_by={:?}, last_message_by={}, last_message_at={}",
         thread_id, ticket.taken_by, last_message_by, ticket.last_message_at
     );
@@ -578,5 +619,6 @@ pub async fn update_thread_status_db(
     .execute(pool)
     .await?;
 
+    request_board_refresh(pool).await;
     Ok(())
 }
@@ -1,19 +1,29 @@
-use crate::db::repr::{PendingCategorySelection, TicketCategory, TicketCategorySettings};
+use crate::db::operations::board::request_board_refresh;
+use crate::db::operations::jobs::{cancel_job, enqueue_job};
+use crate::db::repr::{
+    AccessList, CategoryForm, CategoryFormQuestion, CategoryMessageVariant, CategorySelectionMode,
+    FormAnswer, JobKind, PendingCategorySelection, TicketCategory, TicketCategorySettings,
+    TicketPriority,
+};
 use crate::prelude::errors::*;
 use chrono::Utc;
 use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+use tracing::error;
 use uuid::Uuid;
 
 pub const CATEGORY_BUTTON_HARD_LIMIT: usize = 24;
+// Discord caps modals at five text inputs.
+pub const CATEGORY_FORM_MAX_QUESTIONS: usize = 5;
 
 pub async fn get_category_settings(pool: &SqlitePool) -> ModmailResult<TicketCategorySettings> {
     let row = sqlx::query(
-        "SELECT enabled, selection_timeout_s FROM ticket_category_settings WHERE id = 1",
+        "SELECT enabled, selection_timeout_s, selection_mode FROM ticket_category_settings WHERE id = 1",
     )
     .fetch_optional(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to fetch category settings: {e:?}");
+        error!("Failed to fetch category settings: {e:?}");
         validation_failed("Failed to fetch category settings")
     })?;
 
@@ -21,10 +31,13 @@ pub async fn get_category_settings(pool: &SqlitePool) -> ModmailResult<TicketCat
         Some(row) => TicketCategorySettings {
             enabled: row.get::<i64, _>("enabled") != 0,
             selection_timeout_s: row.get::<i64, _>("selection_timeout_s"),
+            selection_mode: CategorySelectionMode::parse(&row.get::<String, _>("selection_mode"))
+                .unwrap_or(CategorySelectionMode::Buttons),
         },
         None => TicketCategorySettings {
             enabled: false,
             selection_timeout_s: 300,
+            selection_mode: CategorySelectionMode::Buttons,
         },
     })
 }
@@ -32,23 +45,26 @@ pub async fn get_category_settings(pool: &SqlitePool) -> ModmailResult<TicketCat
 pub async fn update_category_settings(
     enabled: bool,
     selection_timeout_s: i64,
+    selection_mode: CategorySelectionMode,
     pool: &SqlitePool,
 ) -> ModmailResult<()> {
     sqlx::query(
         r#"
-        INSERT INTO ticket_category_settings (id, enabled, selection_timeout_s)
-        VALUES (1, ?, ?)
+        INSERT INTO ticket_category_settings (id, enabled, selection_timeout_s, selection_mode)
+        VALUES (1, ?, ?, ?)
         ON CONFLICT(id) DO UPDATE SET
             enabled = excluded.enabled,
-            selection_timeout_s = excluded.selection_timeout_s
+            selection_timeout_s = excluded.selection_timeout_s,
+            selection_mode = excluded.selection_mode
         "#,
     )
     .bind(enabled as i64)
     .bind(selection_timeout_s)
+    .bind(selection_mode.as_str())
     .execute(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to update category settings: {e:?}");
+        error!("Failed to update category settings: {e:?}");
         validation_failed("Failed to update category settings")
     })?;
 
@@ -64,6 +80,12 @@ fn row_to_category(row: sqlx::sqlite::SqliteRow) -> TicketCategory {
         discord_category_id: row.get::<String, _>("discord_category_id"),
         position: row.get::<i64, _>("position"),
         enabled: row.get::<i64, _>("enabled") != 0,
+        parent_id: row.get::<Option<String>, _>("parent_id"),
+        embed_color: row.get::<Option<String>, _>("embed_color"),
+        default_priority: row
+            .get::<Option<String>, _>("default_priority")
+            .as_deref()
+            .and_then(TicketPriority::parse),
         created_at: row.get::<i64, _>("created_at"),
         updated_at: row.get::<i64, _>("updated_at"),
     }
@@ -73,7 +95,7 @@ pub async fn list_all_categories(pool: &SqlitePool) -> ModmailResult<Vec<TicketC
     let rows = sqlx::query(
         r#"
         SELECT id, name, description, emoji, discord_category_id,
-               position, enabled, created_at, updated_at
+               position, enabled, parent_id, embed_color, default_priority, created_at, updated_at
         FROM ticket_categories
         ORDER BY position ASC, created_at ASC
         "#,
@@ -81,7 +103,7 @@ pub async fn list_all_categories(pool: &SqlitePool) -> ModmailResult<Vec<TicketC
     .fetch_all(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to list categories: {e:?}");
+        error!("Failed to list categories: {e:?}");
         validation_failed("Failed to list categories")
     })?;
 
@@ -92,7 +114,7 @@ pub async fn list_enabled_categories(pool: &SqlitePool) -> ModmailResult<Vec<Tic
     let rows = sqlx::query(
         r#"
         SELECT id, name, description, emoji, discord_category_id,
-               position, enabled, created_at, updated_at
+               position, enabled, parent_id, embed_color, default_priority, created_at, updated_at
         FROM ticket_categories
         WHERE enabled = 1
         ORDER BY position ASC, created_at ASC
@@ -101,21 +123,27 @@ pub async fn list_enabled_categories(pool: &SqlitePool) -> ModmailResult<Vec<Tic
     .fetch_all(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to list enabled categories: {e:?}");
+        error!("Failed to list enabled categories: {e:?}");
         validation_failed("Failed to list enabled categories")
     })?;
 
     Ok(rows.into_iter().map(row_to_category).collect())
 }
 
-pub async fn count_enabled_categories(pool: &SqlitePool) -> ModmailResult<i64> {
-    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM ticket_categories WHERE enabled = 1")
-        .fetch_one(pool)
-        .await
-        .map_err(|e| {
-            eprintln!("Failed to count enabled categories: {e:?}");
-            validation_failed("Failed to count enabled categories")
-        })?;
+pub async fn count_enabled_categories(
+    parent_id: Option<&str>,
+    pool: &SqlitePool,
+) -> ModmailResult<i64> {
+    let count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM ticket_categories WHERE enabled = 1 AND parent_id IS ?",
+    )
+    .bind(parent_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to count enabled categories: {e:?}");
+        validation_failed("Failed to count enabled categories")
+    })?;
     Ok(count)
 }
 
@@ -126,7 +154,7 @@ pub async fn get_category_by_id(
     let row = sqlx::query(
         r#"
         SELECT id, name, description, emoji, discord_category_id,
-               position, enabled, created_at, updated_at
+               position, enabled, parent_id, embed_color, default_priority, created_at, updated_at
         FROM ticket_categories
         WHERE id = ?
         "#,
@@ -135,7 +163,7 @@ pub async fn get_category_by_id(
     .fetch_optional(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to fetch category: {e:?}");
+        error!("Failed to fetch category: {e:?}");
         validation_failed("Failed to fetch category")
     })?;
 
@@ -149,7 +177,7 @@ pub async fn get_category_by_name(
     let row = sqlx::query(
         r#"
         SELECT id, name, description, emoji, discord_category_id,
-               position, enabled, created_at, updated_at
+               position, enabled, parent_id, embed_color, default_priority, created_at, updated_at
         FROM ticket_categories
         WHERE name = ? COLLATE NOCASE
         LIMIT 1
@@ -159,7 +187,7 @@ pub async fn get_category_by_name(
     .fetch_optional(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to fetch category by name: {e:?}");
+        error!("Failed to fetch category by name: {e:?}");
         validation_failed("Failed to fetch category by name")
     })?;
 
@@ -171,6 +199,7 @@ pub async fn create_category(
     description: Option<&str>,
     emoji: Option<&str>,
     discord_category_id: &str,
+    parent_id: Option<&str>,
     pool: &SqlitePool,
 ) -> ModmailResult<TicketCategory> {
     let id = Uuid::new_v4().to_string();
@@ -181,7 +210,7 @@ pub async fn create_category(
             .fetch_one(pool)
             .await
             .map_err(|e| {
-                eprintln!("Failed to compute category position: {e:?}");
+                error!("Failed to compute category position: {e:?}");
                 validation_failed("Failed to compute category position")
             })?;
     let position = max_position.unwrap_or(-1) + 1;
@@ -189,8 +218,9 @@ pub async fn create_category(
     sqlx::query(
         r#"
         INSERT INTO ticket_categories
-            (id, name, description, emoji, discord_category_id, position, enabled, created_at, updated_at)
-        VALUES (?, ?, ?, ?, ?, ?, 1, ?, ?)
+            (id, name, description, emoji, discord_category_id, position, enabled, parent_id,
+             created_at, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, 1, ?, ?, ?)
         "#,
     )
     .bind(&id)
@@ -199,12 +229,13 @@ pub async fn create_category(
     .bind(emoji)
     .bind(discord_category_id)
     .bind(position)
+    .bind(parent_id)
     .bind(now)
     .bind(now)
     .execute(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to create category: {e:?}");
+        error!("Failed to create category: {e:?}");
         validation_failed("Failed to create category")
     })?;
 
@@ -216,6 +247,9 @@ pub async fn create_category(
         discord_category_id: discord_category_id.to_string(),
         position,
         enabled: true,
+        parent_id: parent_id.map(|s| s.to_string()),
+        embed_color: None,
+        default_priority: None,
         created_at: now,
         updated_at: now,
     })
@@ -268,7 +302,7 @@ pub async fn update_category(
     .execute(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to update category: {e:?}");
+        error!("Failed to update category: {e:?}");
         validation_failed("Failed to update category")
     })?;
 
@@ -281,22 +315,437 @@ pub async fn delete_category(id: &str, pool: &SqlitePool) -> ModmailResult<bool>
         .execute(pool)
         .await
         .map_err(|e| {
-            eprintln!("Failed to delete category roles: {e:?}");
+            error!("Failed to delete category roles: {e:?}");
             validation_failed("Failed to delete category roles")
         })?;
 
+    delete_category_form(id, pool).await?;
+    set_category_message_variants(id, &[], pool).await?;
+    set_category_access(id, &AccessList::default(), pool).await?;
+
+    // Subcategories are promoted to the top level rather than deleted.
+    sqlx::query("UPDATE ticket_categories SET parent_id = NULL WHERE parent_id = ?")
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to detach subcategories: {e:?}");
+            validation_failed("Failed to detach subcategories")
+        })?;
+
     let res = sqlx::query("DELETE FROM ticket_categories WHERE id = ?")
         .bind(id)
         .execute(pool)
         .await
         .map_err(|e| {
-            eprintln!("Failed to delete category: {e:?}");
+            error!("Failed to delete category: {e:?}");
             validation_failed("Failed to delete category")
         })?;
 
     Ok(res.rows_affected() > 0)
 }
 
+pub async fn set_category_parent(
+    id: &str,
+    parent_id: Option<&str>,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query("UPDATE ticket_categories SET parent_id = ?, updated_at = ? WHERE id = ?")
+        .bind(parent_id)
+        .bind(Utc::now().timestamp())
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to set category parent: {e:?}");
+            validation_failed("Failed to set category parent")
+        })?;
+    Ok(())
+}
+
+pub async fn set_category_default_priority(
+    id: &str,
+    priority: Option<TicketPriority>,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query("UPDATE ticket_categories SET default_priority = ?, updated_at = ? WHERE id = ?")
+        .bind(priority.map(|p| p.as_str()))
+        .bind(Utc::now().timestamp())
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to set category default priority: {e:?}");
+            validation_failed("Failed to set category default priority")
+        })?;
+    Ok(())
+}
+
+pub async fn set_category_embed_color(
+    id: &str,
+    embed_color: Option<&str>,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query("UPDATE ticket_categories SET embed_color = ?, updated_at = ? WHERE id = ?")
+        .bind(embed_color)
+        .bind(Utc::now().timestamp())
+        .bind(id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to set category embed color: {e:?}");
+            validation_failed("Failed to set category embed color")
+        })?;
+    Ok(())
+}
+
+pub async fn list_category_message_variants(
+    category_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Vec<CategoryMessageVariant>> {
+    let rows = sqlx::query(
+        r#"
+        SELECT language, welcome_message, close_message, auto_reply
+        FROM ticket_category_messages
+        WHERE category_id = ?
+        ORDER BY language ASC
+        "#,
+    )
+    .bind(category_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list category messages: {e:?}");
+        validation_failed("Failed to list category messages")
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| CategoryMessageVariant {
+            language: row.get("language"),
+            welcome_message: row.get("welcome_message"),
+            close_message: row.get("close_message"),
+            auto_reply: row.get("auto_reply"),
+        })
+        .collect())
+}
+
+pub async fn set_category_message_variants(
+    category_id: &str,
+    variants: &[CategoryMessageVariant],
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    let mut tx = pool.begin().await.map_err(|e| {
+        error!("Failed to begin category messages tx: {e:?}");
+        validation_failed("Failed to save category messages")
+    })?;
+
+    sqlx::query("DELETE FROM ticket_category_messages WHERE category_id = ?")
+        .bind(category_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("Failed to clear category messages: {e:?}");
+            validation_failed("Failed to save category messages")
+        })?;
+
+    let now = Utc::now().timestamp();
+    for v in variants {
+        sqlx::query(
+            r#"
+            INSERT INTO ticket_category_messages
+                (category_id, language, welcome_message, close_message, auto_reply, updated_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            "#,
+        )
+        .bind(category_id)
+        .bind(&v.language)
+        .bind(&v.welcome_message)
+        .bind(&v.close_message)
+        .bind(&v.auto_reply)
+        .bind(now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("Failed to insert category messages: {e:?}");
+            validation_failed("Failed to save category messages")
+        })?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        error!("Failed to commit category messages tx: {e:?}");
+        validation_failed("Failed to save category messages")
+    })?;
+    Ok(())
+}
+
+pub async fn get_category_access(
+    category_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<AccessList> {
+    let rows = sqlx::query(
+        r#"
+        SELECT target_type, target_id FROM ticket_category_access
+        WHERE category_id = ?
+        ORDER BY target_type, target_id
+        "#,
+    )
+    .bind(category_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to fetch category access: {e:?}");
+        validation_failed("Failed to fetch category access")
+    })?;
+
+    let mut list = AccessList::default();
+    for row in rows {
+        list.push(&row.get::<String, _>("target_type"), row.get("target_id"));
+    }
+    Ok(list)
+}
+
+pub async fn list_category_access(pool: &SqlitePool) -> ModmailResult<HashMap<String, AccessList>> {
+    let rows = sqlx::query(
+        "SELECT category_id, target_type, target_id FROM ticket_category_access ORDER BY target_id",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list category access: {e:?}");
+        validation_failed("Failed to list category access")
+    })?;
+
+    let mut lists: HashMap<String, AccessList> = HashMap::new();
+    for row in rows {
+        lists
+            .entry(row.get("category_id"))
+            .or_default()
+            .push(&row.get::<String, _>("target_type"), row.get("target_id"));
+    }
+    Ok(lists)
+}
+
+pub async fn set_category_access(
+    category_id: &str,
+    access: &AccessList,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    let mut tx = pool.begin().await.map_err(|e| {
+        error!("Failed to begin category access tx: {e:?}");
+        validation_failed("Failed to save category access")
+    })?;
+
+    sqlx::query("DELETE FROM ticket_category_access WHERE category_id = ?")
+        .bind(category_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("Failed to clear category access: {e:?}");
+            validation_failed("Failed to save category access")
+        })?;
+
+    let targets = access
+        .role_ids
+        .iter()
+        .map(|id| ("role", id))
+        .chain(access.user_ids.iter().map(|id| ("user", id)));
+    for (target_type, target_id) in targets {
+        sqlx::query(
+            r#"
+            INSERT OR IGNORE INTO ticket_category_access (category_id, target_type, target_id)
+            VALUES (?, ?, ?)
+            "#,
+        )
+        .bind(category_id)
+        .bind(target_type)
+        .bind(target_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("Failed to insert category access: {e:?}");
+            validation_failed("Failed to save category access")
+        })?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        error!("Failed to commit category access tx: {e:?}");
+        validation_failed("Failed to save category access")
+    })?;
+    Ok(())
+}
+
+pub async fn get_thread_ticket_category_id(
+    thread_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Option<String>> {
+    let category_id: Option<Option<String>> =
+        sqlx::query_scalar("SELECT ticket_category_id FROM threads WHERE id = ?")
+            .bind(thread_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| {
+                error!("Failed to fetch thread category: {e:?}");
+                validation_failed("Failed to fetch thread category")
+            })?;
+    Ok(category_id.flatten())
+}
+
+pub async fn list_open_channels_in_category(
+    category_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Vec<String>> {
+    sqlx::query_scalar("SELECT channel_id FROM threads WHERE ticket_category_id = ? AND status = 1")
+        .bind(category_id)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to list open category tickets: {e:?}");
+            validation_failed("Failed to list open category tickets")
+        })
+}
+
+pub async fn get_thread_category_embed_color(
+    thread_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Option<String>> {
+    let color: Option<Option<String>> = sqlx::query_scalar(
+        r#"
+        SELECT c.embed_color
+        FROM threads t
+        JOIN ticket_categories c ON c.id = t.ticket_category_id
+        WHERE t.id = ?
+        "#,
+    )
+    .bind(thread_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to fetch thread category color: {e:?}");
+        validation_failed("Failed to fetch thread category color")
+    })?;
+    Ok(color.flatten())
+}
+
+pub async fn get_category_form(
+    category_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Option<CategoryForm>> {
+    let row = sqlx::query(
+        "SELECT category_id, title, questions FROM ticket_category_forms WHERE category_id = ?",
+    )
+    .bind(category_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to fetch category form: {e:?}");
+        validation_failed("Failed to fetch category form")
+    })?;
+
+    Ok(row.map(|row| {
+        let questions: String = row.get("questions");
+        CategoryForm {
+            category_id: row.get("category_id"),
+            title: row.get("title"),
+            questions: serde_json::from_str::<Vec<CategoryFormQuestion>>(&questions)
+                .unwrap_or_default(),
+        }
+    }))
+}
+
+pub async fn set_category_form(
+    category_id: &str,
+    title: &str,
+    questions: &[CategoryFormQuestion],
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    let questions = serde_json::to_string(questions)
+        .map_err(|_| validation_failed("Failed to serialize form questions"))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO ticket_category_forms (category_id, title, questions, updated_at)
+        VALUES (?, ?, ?, ?)
+        ON CONFLICT(category_id) DO UPDATE SET
+            title = excluded.title,
+            questions = excluded.questions,
+            updated_at = excluded.updated_at
+        "#,
+    )
+    .bind(category_id)
+    .bind(title)
+    .bind(questions)
+    .bind(Utc::now().timestamp())
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to save category form: {e:?}");
+        validation_failed("Failed to save category form")
+    })?;
+    Ok(())
+}
+
+pub async fn delete_category_form(category_id: &str, pool: &SqlitePool) -> ModmailResult<bool> {
+    let res = sqlx::query("DELETE FROM ticket_category_forms WHERE category_id = ?")
+        .bind(category_id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to delete category form: {e:?}");
+            validation_failed("Failed to delete category form")
+        })?;
+    Ok(res.rows_affected() > 0)
+}
+
+pub async fn save_thread_form_answers(
+    thread_id: &str,
+    category_id: &str,
+    answers: &[FormAnswer],
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    let answers = serde_json::to_string(answers)
+        .map_err(|_| validation_failed("Failed to serialize form answers"))?;
+
+    sqlx::query(
+        r#"
+        INSERT INTO thread_form_answers (thread_id, category_id, answers, submitted_at)
+        VALUES (?, ?, ?, ?)
+        ON CONFLICT(thread_id) DO UPDATE SET
+            category_id = excluded.category_id,
+            answers = excluded.answers,
+            submitted_at = excluded.submitted_at
+        "#,
+    )
+    .bind(thread_id)
+    .bind(category_id)
+    .bind(answers)
+    .bind(Utc::now().timestamp())
+    .execute(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to save form answers: {e:?}");
+        validation_failed("Failed to save form answers")
+    })?;
+    Ok(())
+}
+
+pub async fn get_thread_form_answers(
+    thread_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Option<Vec<FormAnswer>>> {
+    let answers: Option<String> =
+        sqlx::query_scalar("SELECT answers FROM thread_form_answers WHERE thread_id = ?")
+            .bind(thread_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| {
+                error!("Failed to fetch form answers: {e:?}");
+                validation_failed("Failed to fetch form answers")
+            })?;
+
+    Ok(answers.map(|a| serde_json::from_str(&a).unwrap_or_default()))
+}
+
 pub async fn list_category_role_ids(
     category_id: &str,
     pool: &SqlitePool,
@@ -308,7 +757,7 @@ pub async fn list_category_role_ids(
     .fetch_all(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to list category roles: {e:?}");
+        error!("Failed to list category roles: {e:?}");
         validation_failed("Failed to list category roles")
     })?;
 
@@ -334,7 +783,7 @@ pub async fn add_category_role(
     .execute(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to add category role: {e:?}");
+        error!("Failed to add category role: {e:?}");
         validation_failed("Failed to add category role")
     })?;
 
@@ -353,7 +802,7 @@ pub async fn remove_category_role(
             .execute(pool)
             .await
             .map_err(|e| {
-                eprintln!("Failed to remove category role: {e:?}");
+                error!("Failed to remove category role: {e:?}");
                 validation_failed("Failed to remove category role")
             })?;
 
@@ -366,7 +815,7 @@ pub async fn clear_category_roles(category_id: &str, pool: &SqlitePool) -> Modma
         .execute(pool)
         .await
         .map_err(|e| {
-            eprintln!("Failed to clear category roles: {e:?}");
+            error!("Failed to clear category roles: {e:?}");
             validation_failed("Failed to clear category roles")
         })?;
 
@@ -379,7 +828,7 @@ pub async fn set_category_roles(
     pool: &SqlitePool,
 ) -> ModmailResult<()> {
     let mut tx = pool.begin().await.map_err(|e| {
-        eprintln!("Failed to begin transaction: {e:?}");
+        error!("Failed to begin transaction: {e:?}");
         validation_failed("Failed to begin transaction")
     })?;
 
@@ -388,7 +837,7 @@ pub async fn set_category_roles(
         .execute(&mut *tx)
         .await
         .map_err(|e| {
-            eprintln!("Failed to clear category roles in tx: {e:?}");
+            error!("Failed to clear category roles in tx: {e:?}");
             validation_failed("Failed to clear category roles")
         })?;
 
@@ -407,13 +856,13 @@ pub async fn set_category_roles(
         .execute(&mut *tx)
         .await
         .map_err(|e| {
-            eprintln!("Failed to insert category role in tx: {e:?}");
+            error!("Failed to insert category role in tx: {e:?}");
             validation_failed("Failed to insert category role")
         })?;
     }
 
     tx.commit().await.map_err(|e| {
-        eprintln!("Failed to commit category roles tx: {e:?}");
+        error!("Failed to commit category roles tx: {e:?}");
         validation_failed("Failed to commit category roles")
     })?;
 
@@ -431,9 +880,10 @@ pub async fn set_thread_category(
         .execute(pool)
         .await
         .map_err(|e| {
-            eprintln!("Failed to set thread category: {e:?}");
+            error!("Failed to set thread category: {e:?}");
             validation_failed("Failed to set thread category")
         })?;
+    request_board_refresh(pool).await;
     Ok(())
 }
 
@@ -459,7 +909,8 @@ pub async fn upsert_pending_selection(
             dm_channel_id = excluded.dm_channel_id,
             started_at = excluded.started_at,
             expires_at = excluded.expires_at,
-            queued_msg_ids = excluded.queued_msg_ids
+            queued_msg_ids = excluded.queued_msg_ids,
+            picked_parent_id = NULL
         "#,
     )
     .bind(user_id)
@@ -471,10 +922,19 @@ pub async fn upsert_pending_selection(
     .execute(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to upsert pending selection: {e:?}");
+        error!("Failed to upsert pending selection: {e:?}");
         validation_failed("Failed to upsert pending selection")
     })?;
 
+    enqueue_job(
+        JobKind::CategorySelectionTimeout,
+        &user_id.to_string(),
+        "{}",
+        expires_at,
+        pool,
+    )
+    .await?;
+
     Ok(())
 }
 
@@ -489,6 +949,7 @@ fn row_to_pending(row: sqlx::sqlite::SqliteRow) -> PendingCategorySelection {
         started_at: row.get::<i64, _>("started_at"),
         expires_at: row.get::<i64, _>("expires_at"),
         queued_msg_ids,
+        picked_parent_id: row.get::<Option<String>, _>("picked_parent_id"),
     }
 }
 
@@ -498,7 +959,8 @@ pub async fn get_pending_selection(
 ) -> ModmailResult<Option<PendingCategorySelection>> {
     let row = sqlx::query(
         r#"
-        SELECT user_id, prompt_msg_id, dm_channel_id, started_at, expires_at, queued_msg_ids
+        SELECT user_id, prompt_msg_id, dm_channel_id, started_at, expires_at, queued_msg_ids,
+               picked_parent_id
         FROM pending_category_selections
         WHERE user_id = ?
         "#,
@@ -507,7 +969,7 @@ pub async fn get_pending_selection(
     .fetch_optional(pool)
     .await
     .map_err(|e| {
-        eprintln!("Failed to fetch pending selection: {e:?}");
+        error!("Failed to fetch pending selection: {e:?}");
         validation_failed("Failed to fetch pending selection")
     })?;
 
@@ -532,40 +994,44 @@ pub async fn append_queued_message(
             .execute(pool)
             .await
             .map_err(|e| {
-                eprintln!("Failed to append queued message: {e:?}");
+                error!("Failed to append queued message: {e:?}");
                 validation_failed("Failed to append queued message")
             })?;
     }
     Ok(())
 }
 
+pub async fn set_pending_parent(
+    user_id: i64,
+    parent_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<()> {
+    sqlx::query("UPDATE pending_category_selections SET picked_parent_id = ? WHERE user_id = ?")
+        .bind(parent_id)
+        .bind(user_id)
+        .execute(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to update pending selection: {e:?}");
+            validation_failed("Failed to update pending selection")
+        })?;
+    Ok(())
+}
+
 pub async fn delete_pending_selection(user_id: i64, pool: &SqlitePool) -> ModmailResult<bool> {
     let res = sqlx::query("DELETE FROM pending_category_selections WHERE user_id = ?")
         .bind(user_id)
         .execute(pool)
         .await
         .map_err(|e| {
-            eprintln!("Failed to delete pending selection: {e:?}");
+            error!("Failed to delete pending selection: {e:?}");
             validation_failed("Failed to delete pending selection")
         })?;
-    Ok(res.rows_affected() > 0)
-}
-
-pub async fn list_all_pending_selections(
-    pool: &SqlitePool,
-) -> ModmailResult<Vec<PendingCategorySelection>> {
-    let rows = sqlx::query(
-        r#"
-        SELECT user_id, prompt_msg_id, dm_channel_id, started_at, expires_at, queued_msg_ids
-        FROM pending_category_selections
-        "#,
+    cancel_job(
+        JobKind::CategorySelectionTimeout,
+        &user_id.to_string(),
+        pool,
     )
-    .fetch_all(pool)
-    .await
-    .map_err(|e| {
-        eprintln!("Failed to list pending selections: {e:?}");
-        validation_failed("Failed to list pending selections")
-    })?;
-
-    Ok(rows.into_iter().map(row_to_pending).collect())
+    .await?;
+    Ok(res.rows_affected() > 0)
 }
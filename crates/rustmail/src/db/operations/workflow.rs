@@ -0,0 +1,227 @@
+use crate::db::operations::request_board_refresh;
+use crate::db::repr::{ThreadStateChange, TicketWorkflowState};
+use crate::prelude::errors::*;
+use crate::prelude::types::*;
+use chrono::Utc;
+use sqlx::{Row, SqlitePool};
+use tracing::error;
+
+pub async fn get_thread_workflow(
+    thread_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Option<(TicketWorkflowState, Option<i64>)>> {
+    let row = sqlx::query("SELECT workflow_state, state_until FROM threads WHERE id = ?")
+        .bind(thread_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| {
+            error!("Failed to fetch thread workflow state: {e:?}");
+            validation_failed("Failed to fetch thread workflow state")
+        })?;
+
+    Ok(row.map(|row| {
+        let state = TicketWorkflowState::parse(&row.get::<String, _>("workflow_state"))
+            .unwrap_or(TicketWorkflowState::Open);
+        (state, row.get("state_until"))
+    }))
+}
+
+pub async fn set_thread_workflow_state(
+    thread_id: &str,
+    state: TicketWorkflowState,
+    until: Option<i64>,
+    changed_by: Option<&str>,
+    pool: &SqlitePool,
+) -> ModmailResult<bool> {
+    let now = Utc::now().timestamp();
+    let mut tx = pool.begin().await.map_err(|e| {
+        error!("Failed to begin transaction: {e:?}");
+        validation_failed("Failed to update thread workflow state")
+    })?;
+
+    let current: Option<String> =
+        sqlx::query_scalar("SELECT workflow_state FROM threads WHERE id = ?")
+            .bind(thread_id)
+            .fetch_optional(&mut *tx)
+            .await
+            .map_err(|e| {
+                error!("Failed to fetch thread workflow state: {e:?}");
+                validation_failed("Failed to update thread workflow state")
+            })?;
+    let Some(current) = current else {
+        return Ok(false);
+    };
+    let from = TicketWorkflowState::parse(&current);
+    let changed = from != Some(state);
+
+    let result = if changed {
+        sqlx::query(
+            "UPDATE threads SET workflow_state = ?, state_until = ?, state_changed_at = ? WHERE id = ?",
+        )
+        .bind(state.as_str())
+        .bind(until)
+        .bind(now)
+        .bind(thread_id)
+        .execute(&mut *tx)
+        .await
+    } else {
+        sqlx::query("UPDATE threads SET state_until = ? WHERE id = ?")
+            .bind(until)
+            .bind(thread_id)
+            .execute(&mut *tx)
+            .await
+    };
+    result.map_err(|e| {
+        error!("Failed to update thread workflow state: {e:?}");
+        validation_failed("Failed to update thread workflow state")
+    })?;
+
+    if changed {
+        sqlx::query(
+            "INSERT INTO thread_state_history (thread_id, from_state, to_state, changed_by, created_at) VALUES (?, ?, ?, ?, ?)",
+        )
+        .bind(thread_id)
+        .bind(from.map(|s| s.as_str()))
+        .bind(state.as_str())
+        .bind(changed_by)
+        .bind(now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("Failed to record thread state change: {e:?}");
+            validation_failed("Failed to update thread workflow state")
+        })?;
+    }
+
+    tx.commit().await.map_err(|e| {
+        error!("Failed to commit thread workflow state: {e:?}");
+        validation_failed("Failed to update thread workflow state")
+    })?;
+
+    if changed {
+        request_board_refresh(pool).await;
+    }
+
+    Ok(changed)
+}
+
+pub async fn record_message_workflow(
+    thread_id: &str,
+    author: TicketAuthor,
+    actor: Option<&str>,
+    pool: &SqlitePool,
+) -> ModmailResult<TicketWorkflowState> {
+    let Some((current, _)) = get_thread_workflow(thread_id, pool).await? else {
+        return Ok(TicketWorkflowState::Open);
+    };
+    let next = current.after_message(author);
+    if next != current {
+        set_thread_workflow_state(thread_id, next, None, actor, pool).await?;
+    }
+    Ok(next)
+}
+
+pub async fn list_inactive_threads(before: i64, pool: &SqlitePool) -> ModmailResult<Vec<String>> {
+    sqlx::query_scalar(
+        r#"
+        SELECT t.id
+        FROM threads t
+        JOIN thread_status ts ON ts.thread_id = t.id
+        WHERE t.status = 1
+          AND t.workflow_state = 'awaiting_user'
+          AND MAX(ts.last_message_at, COALESCE(t.state_changed_at, 0)) < ?
+          AND NOT EXISTS (SELECT 1 FROM scheduled_closures sc WHERE sc.thread_id = t.id)
+        "#,
+    )
+    .bind(before)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list inactive threads: {e:?}");
+        validation_failed("Failed to list inactive threads")
+    })
+}
+
+pub async fn list_thread_state_history(
+    thread_id: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Vec<ThreadStateChange>> {
+    let rows = sqlx::query(
+        "SELECT from_state, to_state, changed_by, created_at FROM thread_state_history WHERE thread_id = ? ORDER BY id ASC",
+    )
+    .bind(thread_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| {
+        error!("Failed to list thread state history: {e:?}");
+        validation_failed("Failed to list thread state history")
+    })?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            Some(ThreadStateChange {
+                from_state: row
+                    .get::<Option<String>, _>("from_state")
+                    .as_deref()
+                    .and_then(TicketWorkflowState::parse),
+                to_state: TicketWorkflowState::parse(&row.get::<String, _>("to_state"))?,
+                changed_by: row.get("changed_by"),
+                created_at: row.get("created_at"),
+            })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::operations::init::{insert_test_ticket, test_pool};
+
+    async fn insert_open_ticket(
+        id: &str,
+        state: TicketWorkflowState,
+        last_message_at: i64,
+        state_changed_at: i64,
+        pool: &SqlitePool,
+    ) {
+        insert_test_ticket(id, id.parse().unwrap(), None, None, pool).await;
+        sqlx::query("UPDATE threads SET workflow_state = ?, state_changed_at = ? WHERE id = ?")
+            .bind(state.as_str())
+            .bind(state_changed_at)
+            .bind(id)
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO thread_status (thread_id, channel_id, owner_id, last_message_by, last_message_at) VALUES (?1, ?1, ?1, 'staff', ?2)",
+        )
+        .bind(id)
+        .bind(last_message_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn only_tickets_left_waiting_for_the_user_are_inactive() {
+        use TicketWorkflowState::*;
+
+        let pool = test_pool().await;
+        insert_open_ticket("1", AwaitingUser, 100, 100, &pool).await;
+        insert_open_ticket("2", AwaitingUser, 100, 900, &pool).await;
+        insert_open_ticket("3", AwaitingUser, 900, 100, &pool).await;
+        insert_open_ticket("4", OnHold, 100, 100, &pool).await;
+        insert_open_ticket("5", Resolved, 100, 100, &pool).await;
+        insert_open_ticket("6", AwaitingStaff, 100, 100, &pool).await;
+        insert_open_ticket("7", AwaitingUser, 100, 100, &pool).await;
+        sqlx::query(
+            "INSERT INTO scheduled_closures (thread_id, close_at, silent, closed_by, category_id, category_name, required_permissions) VALUES ('7', 2000, 0, '7', '', '', '0')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        assert_eq!(list_inactive_threads(500, &pool).await.unwrap(), ["1"]);
+    }
+}
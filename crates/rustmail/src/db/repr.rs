@@ -1,3 +1,5 @@
+use crate::types::threads_status::TicketAuthor;
+
 #[derive(Debug, Clone)]
 pub struct Thread {
     pub id: String,
@@ -16,6 +18,33 @@ pub struct ApiKey {
     pub expires_at: Option<i64>,
     pub last_used_at: Option<i64>,
     pub is_active: bool,
+    pub rate_limit_per_minute: Option<i64>,
+    pub rate_limit_burst: Option<i64>,
+    pub allowed_cidrs: Vec<String>,
+    pub allowed_category_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct PanelSession {
+    pub id: String,
+    pub user_id: String,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub last_seen_at: Option<i64>,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct ApiKeyRequestLog {
+    pub id: i64,
+    pub api_key_id: i64,
+    pub method: String,
+    pub route: String,
+    pub status: i64,
+    pub latency_ms: i64,
+    pub ip: Option<String>,
+    pub created_at: i64,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -27,14 +56,258 @@ pub struct TicketCategory {
     pub discord_category_id: String,
     pub position: i64,
     pub enabled: bool,
+    pub parent_id: Option<String>,
+    pub embed_color: Option<String>,
+    pub default_priority: Option<TicketPriority>,
     pub created_at: i64,
     pub updated_at: i64,
 }
 
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    serde::Serialize,
+    serde::Deserialize,
+    utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TicketPriority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Urgent,
+}
+
+impl TicketPriority {
+    pub const ALL: [TicketPriority; 4] = [
+        TicketPriority::Low,
+        TicketPriority::Normal,
+        TicketPriority::High,
+        TicketPriority::Urgent,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TicketPriority::Low => "low",
+            TicketPriority::Normal => "normal",
+            TicketPriority::High => "high",
+            TicketPriority::Urgent => "urgent",
+        }
+    }
+
+    pub fn response_sla(&self, base: u64) -> u64 {
+        match self {
+            TicketPriority::Low => base.saturating_mul(2),
+            TicketPriority::Normal => base,
+            TicketPriority::High => base / 2,
+            TicketPriority::Urgent => base / 4,
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "low" => Some(TicketPriority::Low),
+            "normal" => Some(TicketPriority::Normal),
+            "high" => Some(TicketPriority::High),
+            "urgent" => Some(TicketPriority::Urgent),
+            _ => None,
+        }
+    }
+
+    pub fn channel_prefix(&self) -> &'static str {
+        match self {
+            TicketPriority::Low => "🔻",
+            TicketPriority::Normal => "",
+            TicketPriority::High => "🔺",
+            TicketPriority::Urgent => "🚨",
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum TicketWorkflowState {
+    Open,
+    AwaitingUser,
+    AwaitingStaff,
+    OnHold,
+    Resolved,
+    Closed,
+}
+
+impl TicketWorkflowState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TicketWorkflowState::Open => "open",
+            TicketWorkflowState::AwaitingUser => "awaiting_user",
+            TicketWorkflowState::AwaitingStaff => "awaiting_staff",
+            TicketWorkflowState::OnHold => "on_hold",
+            TicketWorkflowState::Resolved => "resolved",
+            TicketWorkflowState::Closed => "closed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "open" => Some(TicketWorkflowState::Open),
+            "awaiting_user" => Some(TicketWorkflowState::AwaitingUser),
+            "awaiting_staff" => Some(TicketWorkflowState::AwaitingStaff),
+            "on_hold" => Some(TicketWorkflowState::OnHold),
+            "resolved" => Some(TicketWorkflowState::Resolved),
+            "closed" => Some(TicketWorkflowState::Closed),
+            _ => None,
+        }
+    }
+
+    pub fn channel_marker(&self) -> &'static str {
+        match self {
+            TicketWorkflowState::Open => "⚪",
+            TicketWorkflowState::AwaitingUser => "🔵",
+            TicketWorkflowState::AwaitingStaff => "🔴",
+            TicketWorkflowState::OnHold => "⏸️",
+            TicketWorkflowState::Resolved => "✅",
+            TicketWorkflowState::Closed => "⚫",
+        }
+    }
+
+    // A user message always wakes the ticket up; staff messages leave holds and resolutions in place.
+    pub fn after_message(self, author: TicketAuthor) -> Self {
+        match (self, author) {
+            (TicketWorkflowState::Closed, _) => TicketWorkflowState::Closed,
+            (_, TicketAuthor::User) => TicketWorkflowState::AwaitingStaff,
+            (TicketWorkflowState::OnHold | TicketWorkflowState::Resolved, TicketAuthor::Staff) => {
+                self
+            }
+            (_, TicketAuthor::Staff) => TicketWorkflowState::AwaitingUser,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardTicket {
+    pub thread_id: String,
+    pub channel_id: String,
+    pub user_name: String,
+    pub category_name: Option<String>,
+    pub priority: TicketPriority,
+    pub workflow_state: TicketWorkflowState,
+    pub taken_by: Option<String>,
+    pub last_message_by: TicketAuthor,
+    pub last_message_at: i64,
+    pub state_changed_at: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct ThreadStateChange {
+    pub from_state: Option<TicketWorkflowState>,
+    pub to_state: TicketWorkflowState,
+    pub changed_by: Option<String>,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct PriorityRule {
+    pub keyword: String,
+    pub priority: TicketPriority,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum CategorySelectionMode {
+    Buttons,
+    Select,
+}
+
+impl CategorySelectionMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CategorySelectionMode::Buttons => "buttons",
+            CategorySelectionMode::Select => "select",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "buttons" => Some(CategorySelectionMode::Buttons),
+            "select" => Some(CategorySelectionMode::Select),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct TicketCategorySettings {
     pub enabled: bool,
     pub selection_timeout_s: i64,
+    pub selection_mode: CategorySelectionMode,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct CategoryFormQuestion {
+    pub label: String,
+    pub placeholder: Option<String>,
+    #[serde(default)]
+    pub paragraph: bool,
+    #[serde(default)]
+    pub required: bool,
+    pub max_length: Option<u16>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CategoryForm {
+    pub category_id: String,
+    pub title: String,
+    pub questions: Vec<CategoryFormQuestion>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct FormAnswer {
+    pub question: String,
+    pub answer: String,
+}
+
+#[derive(
+    Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize, utoipa::ToSchema,
+)]
+pub struct AccessList {
+    #[serde(default)]
+    pub role_ids: Vec<String>,
+    #[serde(default)]
+    pub user_ids: Vec<String>,
+}
+
+impl AccessList {
+    pub fn is_empty(&self) -> bool {
+        self.role_ids.is_empty() && self.user_ids.is_empty()
+    }
+
+    pub fn push(&mut self, target_type: &str, target_id: String) {
+        match target_type {
+            "role" => self.role_ids.push(target_id),
+            "user" => self.user_ids.push(target_id),
+            _ => {}
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize, utoipa::ToSchema)]
+pub struct CategoryMessageVariant {
+    #[serde(default)]
+    pub language: String,
+    pub welcome_message: Option<String>,
+    pub close_message: Option<String>,
+    pub auto_reply: Option<String>,
 }
 
 #[allow(dead_code)]
@@ -46,6 +319,7 @@ pub struct PendingCategorySelection {
     pub started_at: i64,
     pub expires_at: i64,
     pub queued_msg_ids: Vec<String>,
+    pub picked_parent_id: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -78,7 +352,9 @@ pub struct BannedUser {
     pub roles_unknown: bool,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, utoipa::ToSchema,
+)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum Permission {
     CreateTicket,
@@ -89,3 +365,443 @@ pub enum Permission {
     UpdateConfig,
     ManageBot,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobKind {
+    ScheduledClosure,
+    Reminder,
+    CategorySelectionTimeout,
+    ThreadStatusRefresh,
+    SessionMaintenance,
+    PollClose,
+    HoldExpiry,
+    TicketBoardRefresh,
+    DatabaseBackup,
+    Retention,
+    InactivityClose,
+}
+
+impl JobKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobKind::ScheduledClosure => "scheduled_closure",
+            JobKind::Reminder => "reminder",
+            JobKind::CategorySelectionTimeout => "category_selection_timeout",
+            JobKind::ThreadStatusRefresh => "thread_status_refresh",
+            JobKind::SessionMaintenance => "session_maintenance",
+            JobKind::PollClose => "poll_close",
+            JobKind::HoldExpiry => "hold_expiry",
+            JobKind::TicketBoardRefresh => "ticket_board_refresh",
+            JobKind::DatabaseBackup => "database_backup",
+            JobKind::Retention => "retention",
+            JobKind::InactivityClose => "inactivity_close",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "scheduled_closure" => Some(JobKind::ScheduledClosure),
+            "reminder" => Some(JobKind::Reminder),
+            "category_selection_timeout" => Some(JobKind::CategorySelectionTimeout),
+            "thread_status_refresh" => Some(JobKind::ThreadStatusRefresh),
+            "session_maintenance" => Some(JobKind::SessionMaintenance),
+            "poll_close" => Some(JobKind::PollClose),
+            "hold_expiry" => Some(JobKind::HoldExpiry),
+            "ticket_board_refresh" => Some(JobKind::TicketBoardRefresh),
+            "database_backup" => Some(JobKind::DatabaseBackup),
+            "retention" => Some(JobKind::Retention),
+            "inactivity_close" => Some(JobKind::InactivityClose),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Completed => "completed",
+            JobStatus::Failed => "failed",
+            JobStatus::Cancelled => "cancelled",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(JobStatus::Pending),
+            "running" => Some(JobStatus::Running),
+            "completed" => Some(JobStatus::Completed),
+            "failed" => Some(JobStatus::Failed),
+            "cancelled" => Some(JobStatus::Cancelled),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Job {
+    pub id: i64,
+    pub kind: String,
+    pub job_key: String,
+    pub payload: String,
+    pub status: String,
+    pub run_at: i64,
+    pub attempts: i64,
+    pub max_attempts: i64,
+    pub last_error: Option<String>,
+    pub locked_by: Option<String>,
+    pub locked_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Poll {
+    pub id: i64,
+    pub channel_id: String,
+    pub message_id: Option<String>,
+    pub question: String,
+    pub options: Vec<String>,
+    pub created_by: String,
+    pub closes_at: Option<i64>,
+    pub closed_at: Option<i64>,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigRevisionSource {
+    Startup,
+    Panel,
+    File,
+    Rollback,
+}
+
+impl ConfigRevisionSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConfigRevisionSource::Startup => "startup",
+            ConfigRevisionSource::Panel => "panel",
+            ConfigRevisionSource::File => "file",
+            ConfigRevisionSource::Rollback => "rollback",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "startup" => Some(ConfigRevisionSource::Startup),
+            "panel" => Some(ConfigRevisionSource::Panel),
+            "file" => Some(ConfigRevisionSource::File),
+            "rollback" => Some(ConfigRevisionSource::Rollback),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigRevisionOrigin {
+    pub source: ConfigRevisionSource,
+    pub author_id: Option<String>,
+    pub rollback_of: Option<i64>,
+}
+
+impl ConfigRevisionOrigin {
+    pub fn new(source: ConfigRevisionSource) -> Self {
+        Self {
+            source,
+            author_id: None,
+            rollback_of: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfigRevision {
+    pub id: i64,
+    pub snapshot: String,
+    pub source: ConfigRevisionSource,
+    pub author_id: Option<String>,
+    pub rollback_of: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ErasureMode {
+    Anonymize,
+    Delete,
+}
+
+impl ErasureMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErasureMode::Anonymize => "anonymize",
+            ErasureMode::Delete => "delete",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "anonymize" => Some(ErasureMode::Anonymize),
+            "delete" => Some(ErasureMode::Delete),
+            _ => None,
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum DataRequestAction {
+    Export,
+    Erase,
+}
+
+impl DataRequestAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DataRequestAction::Export => "export",
+            DataRequestAction::Erase => "erase",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "export" => Some(DataRequestAction::Export),
+            "erase" => Some(DataRequestAction::Erase),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+pub struct DataSubjectRequest {
+    pub id: i64,
+    pub user_id: String,
+    pub action: DataRequestAction,
+    pub mode: Option<ErasureMode>,
+    pub source: String,
+    pub requested_by: String,
+    #[schema(value_type = Object)]
+    pub summary: serde_json::Value,
+    pub created_at: i64,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, utoipa::ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum RetentionTarget {
+    MessageContent,
+    ClosedTickets,
+    TrackedMembers,
+    ExpiredApiKeys,
+}
+
+impl RetentionTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RetentionTarget::MessageContent => "message_content",
+            RetentionTarget::ClosedTickets => "closed_tickets",
+            RetentionTarget::TrackedMembers => "tracked_members",
+            RetentionTarget::ExpiredApiKeys => "expired_api_keys",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "message_content" => Some(RetentionTarget::MessageContent),
+            "closed_tickets" => Some(RetentionTarget::ClosedTickets),
+            "tracked_members" => Some(RetentionTarget::TrackedMembers),
+            "expired_api_keys" => Some(RetentionTarget::ExpiredApiKeys),
+            _ => None,
+        }
+    }
+
+    pub fn is_ticket_data(&self) -> bool {
+        matches!(
+            self,
+            RetentionTarget::MessageContent | RetentionTarget::ClosedTickets
+        )
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, utoipa::ToSchema)]
+pub struct RetentionRule {
+    pub id: i64,
+    pub target: RetentionTarget,
+    pub older_than_days: i64,
+    pub ticket_category_id: Option<String>,
+    pub enabled: bool,
+    pub last_run_at: Option<i64>,
+    pub last_affected: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportSource {
+    Modmail,
+    Modmailbot,
+}
+
+impl ImportSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportSource::Modmail => "modmail",
+            ImportSource::Modmailbot => "modmailbot",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "modmail" => Some(ImportSource::Modmail),
+            "modmailbot" => Some(ImportSource::Modmailbot),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportedMessage {
+    pub user_id: i64,
+    pub user_name: String,
+    pub content: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub from_staff: bool,
+    pub is_anonymous: bool,
+    pub is_internal: bool,
+    pub message_number: Option<i64>,
+    pub dm_message_id: Option<String>,
+    pub inbox_message_id: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportedThread {
+    pub external_id: String,
+    pub user_id: i64,
+    pub user_name: String,
+    pub channel_id: String,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub closed_at: chrono::DateTime<chrono::Utc>,
+    pub closed_by: Option<String>,
+    pub messages: Vec<ImportedMessage>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportedSnippet {
+    pub key: String,
+    pub content: String,
+    pub created_by: String,
+    pub created_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ImportedBlock {
+    pub user_id: String,
+    pub user_name: String,
+    pub blocked_by: String,
+    pub blocked_at: chrono::DateTime<chrono::Utc>,
+    pub expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ImportBundle {
+    pub threads: Vec<ImportedThread>,
+    pub snippets: Vec<ImportedSnippet>,
+    pub blocked: Vec<ImportedBlock>,
+    pub unsupported: u64,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ImportReport {
+    pub threads_imported: u64,
+    pub threads_skipped: u64,
+    pub messages_imported: u64,
+    pub snippets_imported: u64,
+    pub snippets_skipped: u64,
+    pub blocked_imported: u64,
+    pub blocked_skipped: u64,
+    pub unsupported: u64,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InstanceBundle {
+    pub format: u32,
+    pub rustmail_version: String,
+    pub schema_version: i64,
+    pub exported_at: i64,
+    pub secrets_included: bool,
+    pub history_included: bool,
+    pub config: Option<serde_json::Value>,
+    pub tables: std::collections::BTreeMap<String, Vec<serde_json::Value>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DiscordIdKind {
+    Guild,
+    Category,
+    Channel,
+    Role,
+}
+
+impl DiscordIdKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DiscordIdKind::Guild => "guild",
+            DiscordIdKind::Category => "category",
+            DiscordIdKind::Channel => "channel",
+            DiscordIdKind::Role => "role",
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct DiscordIdUse {
+    pub id: String,
+    pub kind: DiscordIdKind,
+    pub used_by: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigImportOutcome {
+    #[default]
+    Absent,
+    Written,
+    Kept,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct RecreatedApiKey {
+    pub name: String,
+    pub api_key: String,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct InstanceImportReport {
+    pub config: ConfigImportOutcome,
+    pub imported: std::collections::BTreeMap<String, u64>,
+    pub skipped: std::collections::BTreeMap<String, u64>,
+    pub remapped: std::collections::BTreeMap<String, String>,
+    pub api_keys: Vec<RecreatedApiKey>,
+    pub dry_run: bool,
+}
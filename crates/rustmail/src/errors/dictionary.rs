@@ -261,6 +261,9 @@ impl DictionaryManager {
                 }
                 CommandError::StatusIsMissing => ("status.status_is_missing".to_string(), None),
                 CommandError::InvalidStatusValue => ("status.invalid_status".to_string(), None),
+                CommandError::InvalidPriority => ("priority.invalid".to_string(), None),
+                CommandError::TicketNotOnHold => ("hold.not_on_hold".to_string(), None),
+                CommandError::TicketNotResolved => ("resolve.not_resolved".to_string(), None),
                 CommandError::MaintenanceModeNotAllowed => {
                     ("status.maintenance_not_allowed".to_string(), None)
                 }
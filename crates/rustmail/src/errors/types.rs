@@ -71,6 +71,9 @@ pub enum CommandError {
     SnippetContentTooLong,
     StatusIsMissing,
     InvalidStatusValue,
+    InvalidPriority,
+    TicketNotOnHold,
+    TicketNotResolved,
     MaintenanceModeNotAllowed,
     ReminderAlreadySubscribed(String),
     ReminderAlreadyUnsubscribed(String),
@@ -194,6 +197,9 @@ impl fmt::Display for CommandError {
             }
             CommandError::StatusIsMissing => write!(f, "Status is missing"),
             CommandError::InvalidStatusValue => write!(f, "Invalid status value"),
+            CommandError::InvalidPriority => write!(f, "Invalid ticket priority"),
+            CommandError::TicketNotOnHold => write!(f, "Ticket is not on hold"),
+            CommandError::TicketNotResolved => write!(f, "Ticket is not resolved"),
             CommandError::MaintenanceModeNotAllowed => {
                 write!(f, "Only admins can enable maintenance mode")
             }
@@ -1,30 +1,249 @@
+use crate::prelude::commands::*;
 use crate::prelude::config::*;
 use crate::prelude::db::*;
+use crate::prelude::utils::*;
 use async_trait::async_trait;
+use serde_json::{Map, Value};
 use serenity::all::ButtonStyle;
-use serenity::all::{ChannelId, ComponentInteraction, Context, CreateMessage};
+use serenity::all::{
+    ChannelId, ComponentInteraction, Context, CreateInteractionResponse, CreateMessage, GuildId,
+    MessageId, ModalInteraction, UserId,
+};
 use serenity::builder::{CreateActionRow, CreateButton};
-use std::sync::Arc;
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock};
+use tracing::{error, warn};
 
 pub mod poll;
 
 pub use poll::*;
 
+pub type FeatureResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+#[derive(Debug, Clone)]
+pub enum FeatureSettingKind {
+    Boolean,
+    Integer { min: i64, max: i64 },
+    Text { max_length: usize },
+}
+
+#[derive(Debug, Clone)]
+pub struct FeatureSetting {
+    pub key: &'static str,
+    pub kind: FeatureSettingKind,
+    pub default: Value,
+}
+
+impl FeatureSetting {
+    pub fn boolean(key: &'static str, default: bool) -> Self {
+        Self {
+            key,
+            kind: FeatureSettingKind::Boolean,
+            default: Value::Bool(default),
+        }
+    }
+
+    pub fn integer(key: &'static str, min: i64, max: i64, default: i64) -> Self {
+        Self {
+            key,
+            kind: FeatureSettingKind::Integer { min, max },
+            default: Value::from(default),
+        }
+    }
+
+    pub fn text(key: &'static str, max_length: usize, default: &str) -> Self {
+        Self {
+            key,
+            kind: FeatureSettingKind::Text { max_length },
+            default: Value::String(default.to_string()),
+        }
+    }
+
+    pub fn validate(&self, value: &Value) -> Result<(), String> {
+        match (&self.kind, value) {
+            (FeatureSettingKind::Boolean, Value::Bool(_)) => Ok(()),
+            (FeatureSettingKind::Integer { min, max }, Value::Number(n)) => match n.as_i64() {
+                Some(v) if (*min..=*max).contains(&v) => Ok(()),
+                _ => Err(format!(
+                    "'{}' must be an integer between {} and {}",
+                    self.key, min, max
+                )),
+            },
+            (FeatureSettingKind::Text { max_length }, Value::String(s)) => {
+                if s.chars().count() <= *max_length {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "'{}' must be at most {} characters",
+                        self.key, max_length
+                    ))
+                }
+            }
+            _ => Err(format!("'{}' has the wrong type", self.key)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeatureState {
+    pub enabled: bool,
+    pub settings: Map<String, Value>,
+}
+
+impl FeatureState {
+    pub fn bool(&self, key: &str) -> bool {
+        self.settings
+            .get(key)
+            .and_then(Value::as_bool)
+            .unwrap_or(false)
+    }
+
+    pub fn int(&self, key: &str) -> i64 {
+        self.settings.get(key).and_then(Value::as_i64).unwrap_or(0)
+    }
+
+    pub fn text(&self, key: &str) -> &str {
+        self.settings
+            .get(key)
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+    }
+}
+
 #[async_trait]
-pub trait Feature<'a>: Send + Sync {
+pub trait Feature: Send + Sync {
     fn key(&self) -> &'static str;
-    async fn build_message(&self, ctx: &'a Context, config: &'a Config) -> CreateMessage;
+
+    fn enabled_by_default(&self) -> bool {
+        true
+    }
+
+    fn settings(&self) -> Vec<FeatureSetting> {
+        Vec::new()
+    }
+
+    fn validate_settings(&self, _settings: &Map<String, Value>) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn commands(&self) -> Vec<Arc<dyn RegistrableCommand>> {
+        Vec::new()
+    }
+
+    async fn build_message(
+        &self,
+        _ctx: &Context,
+        _config: &Config,
+        _state: &FeatureState,
+    ) -> Option<CreateMessage> {
+        None
+    }
+
     async fn handle_interaction(
         &self,
         ctx: &Context,
         config: &Config,
+        state: &FeatureState,
         interaction: &ComponentInteraction,
         action: &str,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+    ) -> FeatureResult;
+
+    async fn handle_modal(
+        &self,
+        _ctx: &Context,
+        _config: &Config,
+        _state: &FeatureState,
+        _interaction: &ModalInteraction,
+        _action: &str,
+    ) -> FeatureResult {
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+pub struct FeatureRegistry {
+    features: Vec<Arc<dyn Feature>>,
+}
+
+impl FeatureRegistry {
+    pub fn register_feature<F: Feature + 'static>(&mut self, feature: F) {
+        self.features.push(Arc::new(feature));
+    }
+
+    pub fn get(&self, key: &str) -> Option<Arc<dyn Feature>> {
+        self.features.iter().find(|f| f.key() == key).cloned()
+    }
+
+    pub fn all(&self) -> &[Arc<dyn Feature>] {
+        &self.features
+    }
 }
 
-pub fn registry<'a>() -> Vec<Arc<dyn Feature<'a>>> {
-    vec![Arc::new(PollFeature)]
+static FEATURES: LazyLock<FeatureRegistry> = LazyLock::new(|| {
+    let mut registry = FeatureRegistry::default();
+    registry.register_feature(PollFeature);
+    registry
+});
+
+pub fn feature_registry() -> &'static FeatureRegistry {
+    &FEATURES
+}
+
+pub fn validate_feature_settings(
+    feature: &dyn Feature,
+    settings: &Map<String, Value>,
+) -> Result<(), String> {
+    let fields = feature.settings();
+    for (key, value) in settings {
+        let Some(field) = fields.iter().find(|f| f.key == key) else {
+            return Err(format!("Unknown setting '{}'", key));
+        };
+        field.validate(value)?;
+    }
+    feature.validate_settings(settings)
+}
+
+pub async fn load_feature_state(feature: &dyn Feature, pool: &SqlitePool) -> FeatureState {
+    let mut settings: Map<String, Value> = feature
+        .settings()
+        .into_iter()
+        .map(|s| (s.key.to_string(), s.default))
+        .collect();
+
+    let stored = match get_feature_state(feature.key(), pool).await {
+        Ok(stored) => stored,
+        Err(e) => {
+            error!(
+                "Failed to load settings of feature {}: {}",
+                feature.key(),
+                e
+            );
+            None
+        }
+    };
+
+    let Some((enabled, raw)) = stored else {
+        return FeatureState {
+            enabled: feature.enabled_by_default(),
+            settings,
+        };
+    };
+
+    let fields = feature.settings();
+    if let Ok(Value::Object(saved)) = serde_json::from_str::<Value>(&raw) {
+        for (key, value) in saved {
+            // Values saved by an older version may no longer fit the field.
+            if fields
+                .iter()
+                .any(|f| f.key == key && f.validate(&value).is_ok())
+            {
+                settings.insert(key, value);
+            }
+        }
+    }
+
+    FeatureState { enabled, settings }
 }
 
 pub fn make_buttons(pairs: &[(&str, &str, ButtonStyle, bool)]) -> Vec<CreateActionRow> {
@@ -45,62 +264,133 @@ pub fn make_buttons(pairs: &[(&str, &str, ButtonStyle, bool)]) -> Vec<CreateActi
     vec![row]
 }
 
-pub async fn sync_features(ctx: &Context, config: &Config) {
-    if !config.bot.enable_features {
+pub async fn ephemeral_reply(
+    ctx: &Context,
+    config: &Config,
+    key: &str,
+    params: Option<&HashMap<String, String>>,
+    user_id: UserId,
+    guild_id: Option<GuildId>,
+) -> CreateInteractionResponse {
+    CreateInteractionResponse::Message(
+        MessageBuilder::system_message(ctx, config)
+            .translated_content(key, params, Some(user_id), guild_id.map(|g| g.get()))
+            .await
+            .build_interaction_message()
+            .await
+            .ephemeral(true),
+    )
+}
+
+async fn remove_feature_message(ctx: &Context, key: &str, pool: &SqlitePool) {
+    let Ok(Some((stored_channel, stored_message))) = get_feature_message(key, pool).await else {
         return;
-    }
-    let channel_id_u64 = match config.bot.features_channel_id {
-        Some(id) => id,
-        None => return,
     };
-    let channel_id = ChannelId::new(channel_id_u64);
 
-    for feature in registry() {
-        let key = feature.key();
-        let pool = match &config.db_pool {
-            Some(p) => p,
-            None => continue,
-        };
+    if let (Ok(channel_id), Ok(message_id)) =
+        (stored_channel.parse::<u64>(), stored_message.parse::<u64>())
+        && let Err(e) = ChannelId::new(channel_id)
+            .delete_message(&ctx.http, MessageId::new(message_id))
+            .await
+    {
+        warn!("Failed to delete message of feature {}: {}", key, e);
+    }
 
-        let mut send_new = false;
+    if let Err(e) = delete_feature_message(key, pool).await {
+        error!("Failed to forget message of feature {}: {}", key, e);
+    }
+}
 
-        if let Ok(Some((stored_channel, stored_message))) = get_feature_message(key, pool).await {
-            if let (Ok(stored_channel_id), Ok(stored_message_id)) =
-                (stored_channel.parse::<u64>(), stored_message.parse::<u64>())
-            {
-                let ch = ChannelId::new(stored_channel_id);
-                if ch.message(&ctx.http, stored_message_id).await.is_err() {
-                    send_new = true;
-                }
-            } else {
-                send_new = true;
+async fn sync_feature(
+    ctx: &Context,
+    config: &Config,
+    feature: &dyn Feature,
+    channel_id: Option<ChannelId>,
+    pool: &SqlitePool,
+) {
+    let key = feature.key();
+    let state = load_feature_state(feature, pool).await;
+
+    let Some(channel_id) = channel_id.filter(|_| state.enabled) else {
+        remove_feature_message(ctx, key, pool).await;
+        return;
+    };
+
+    if let Ok(Some((stored_channel, stored_message))) = get_feature_message(key, pool).await {
+        let current = match (stored_channel.parse::<u64>(), stored_message.parse::<u64>()) {
+            (Ok(ch), Ok(msg)) if ch == channel_id.get() => {
+                ChannelId::new(ch).message(&ctx.http, msg).await.is_ok()
             }
-        } else {
-            send_new = true;
+            _ => false,
+        };
+        if current {
+            return;
         }
+        // Posted in a previous features channel, or deleted by hand.
+        remove_feature_message(ctx, key, pool).await;
+    }
 
-        if send_new {
-            let msg_create = feature.build_message(ctx, config).await;
-            match channel_id.send_message(&ctx.http, msg_create).await {
-                Ok(sent) => {
-                    let _ = upsert_feature_message(
-                        key,
-                        &channel_id_u64.to_string(),
-                        &sent.id.to_string(),
-                        pool,
-                    )
-                    .await;
-                }
-                Err(err) => {
-                    eprintln!("Failed to send feature {}: {}", key, err);
-                }
-            }
+    let Some(msg_create) = feature.build_message(ctx, config, &state).await else {
+        return;
+    };
+    match channel_id.send_message(&ctx.http, msg_create).await {
+        Ok(sent) => {
+            let _ = upsert_feature_message(
+                key,
+                &channel_id.get().to_string(),
+                &sent.id.to_string(),
+                pool,
+            )
+            .await;
+        }
+        Err(err) => {
+            error!("Failed to send feature {}: {}", key, err);
         }
     }
 }
 
+pub async fn sync_features(ctx: &Context, config: &Config) {
+    let Some(pool) = &config.db_pool else {
+        return;
+    };
+    let channel_id = config
+        .bot
+        .features_channel_id
+        .filter(|_| config.bot.enable_features)
+        .map(ChannelId::new);
+
+    for feature in feature_registry().all() {
+        sync_feature(ctx, config, feature.as_ref(), channel_id, pool).await;
+    }
+}
+
+pub async fn refresh_feature(ctx: &Context, config: &Config, key: &str) {
+    let Some(pool) = &config.db_pool else {
+        return;
+    };
+    let Some(feature) = feature_registry().get(key) else {
+        return;
+    };
+
+    remove_feature_message(ctx, key, pool).await;
+    let channel_id = config
+        .bot
+        .features_channel_id
+        .filter(|_| config.bot.enable_features)
+        .map(ChannelId::new);
+    sync_feature(ctx, config, feature.as_ref(), channel_id, pool).await;
+}
+
+pub async fn is_feature_enabled(config: &Config, key: &str) -> bool {
+    let (Some(pool), Some(feature)) = (&config.db_pool, feature_registry().get(key)) else {
+        return false;
+    };
+    config.bot.enable_features && load_feature_state(feature.as_ref(), pool).await.enabled
+}
+
+// The action keeps any further `:` segments so features can carry ids in it.
 fn parse_custom_id(custom_id: &str) -> Option<(String, String)> {
-    let parts: Vec<&str> = custom_id.split(':').collect();
+    let parts: Vec<&str> = custom_id.splitn(3, ':').collect();
     if parts.len() >= 3 && parts[0] == "feature" {
         Some((parts[1].to_string(), parts[2].to_string()))
     } else {
@@ -108,19 +398,99 @@ fn parse_custom_id(custom_id: &str) -> Option<(String, String)> {
     }
 }
 
+async fn resolve_feature(
+    ctx: &Context,
+    config: &Config,
+    key: &str,
+    user_id: UserId,
+    guild_id: Option<GuildId>,
+) -> Option<Result<(Arc<dyn Feature>, FeatureState), CreateInteractionResponse>> {
+    let feature = feature_registry().get(key)?;
+    let pool = config.db_pool.as_ref()?;
+
+    let state = load_feature_state(feature.as_ref(), pool).await;
+    if !config.bot.enable_features || !state.enabled {
+        return Some(Err(ephemeral_reply(
+            ctx,
+            config,
+            "feature.disabled",
+            None,
+            user_id,
+            guild_id,
+        )
+        .await));
+    }
+    Some(Ok((feature, state)))
+}
+
 pub async fn handle_feature_component_interaction(
     ctx: &Context,
     config: &Config,
     interaction: &ComponentInteraction,
 ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
-    if let Some((key, action)) = parse_custom_id(&interaction.data.custom_id) {
-        for f in registry() {
-            if f.key() == key {
-                f.handle_interaction(ctx, config, interaction, &action)
-                    .await?;
-                return Ok(true);
-            }
+    let Some((key, action)) = parse_custom_id(&interaction.data.custom_id) else {
+        return Ok(false);
+    };
+
+    match resolve_feature(ctx, config, &key, interaction.user.id, interaction.guild_id).await {
+        None => Ok(false),
+        Some(Err(response)) => {
+            interaction.create_response(&ctx.http, response).await?;
+            Ok(true)
+        }
+        Some(Ok((feature, state))) => {
+            feature
+                .handle_interaction(ctx, config, &state, interaction, &action)
+                .await?;
+            Ok(true)
         }
     }
-    Ok(false)
+}
+
+pub async fn handle_feature_modal_interaction(
+    ctx: &Context,
+    config: &Config,
+    interaction: &ModalInteraction,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let Some((key, action)) = parse_custom_id(&interaction.data.custom_id) else {
+        return Ok(false);
+    };
+
+    match resolve_feature(ctx, config, &key, interaction.user.id, interaction.guild_id).await {
+        None => Ok(false),
+        Some(Err(response)) => {
+            interaction.create_response(&ctx.http, response).await?;
+            Ok(true)
+        }
+        Some(Ok((feature, state))) => {
+            feature
+                .handle_modal(ctx, config, &state, interaction, &action)
+                .await?;
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_are_checked_against_their_kind() {
+        let max = FeatureSetting::integer("max", 2, 20, 20);
+        assert!(max.validate(&Value::from(5)).is_ok());
+        assert!(max.validate(&Value::from(21)).is_err());
+        assert!(max.validate(&Value::Bool(true)).is_err());
+
+        let text = FeatureSetting::text("name", 3, "");
+        assert!(text.validate(&Value::from("abc")).is_ok());
+        assert!(text.validate(&Value::from("abcd")).is_err());
+    }
+
+    #[test]
+    fn unknown_settings_are_rejected() {
+        let mut settings = Map::new();
+        settings.insert("nope".to_string(), Value::Bool(true));
+        assert!(validate_feature_settings(&PollFeature, &settings).is_err());
+    }
 }
@@ -1,70 +1,746 @@
+use crate::prelude::commands::*;
 use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
 use crate::prelude::features::*;
+use crate::prelude::handlers::*;
+use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
 use crate::prelude::utils::*;
 use async_trait::async_trait;
+use chrono::Utc;
+use serde_json::{Map, Value};
 use serenity::all::{
-    ButtonStyle, ComponentInteraction, Context, CreateInteractionResponse, CreateMessage,
-    InputTextStyle,
+    ActionRowComponent, ButtonStyle, ChannelId, Colour, CommandInteraction, ComponentInteraction,
+    Context, CreateCommand, CreateEmbed, CreateEmbedFooter, CreateInteractionResponse,
+    CreateInteractionResponseMessage, CreateMessage, EditMessage, InputTextStyle, MessageId,
+    ModalInteraction, ResolvedOption,
 };
-use serenity::builder::{CreateActionRow, CreateInputText, CreateModal};
+use serenity::builder::{CreateActionRow, CreateButton, CreateInputText, CreateModal};
+use sqlx::SqlitePool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::error;
+
+pub const POLL_MIN_OPTIONS: usize = 2;
+// Four rows of five vote buttons; the fifth row holds the close button.
+pub const POLL_MAX_OPTIONS: usize = 20;
+const POLL_OPTION_MAX_LEN: usize = 80;
+const POLL_BAR_WIDTH: usize = 10;
+
+const POLL_OPEN_COLOR: u32 = 0x5865F2;
+const POLL_CLOSED_COLOR: u32 = 0x57F287;
 
 #[derive(Default)]
 pub struct PollFeature;
 
+pub fn parse_poll_options(raw: &str) -> Vec<String> {
+    let mut options: Vec<String> = Vec::new();
+    for option in raw.split(['|', '\n']) {
+        let option: String = option.trim().chars().take(POLL_OPTION_MAX_LEN).collect();
+        if option.is_empty()
+            || options
+                .iter()
+                .any(|existing| existing.to_lowercase() == option.to_lowercase())
+        {
+            continue;
+        }
+        options.push(option);
+    }
+    options
+}
+
+fn tally_bar(votes: u64, total: u64) -> String {
+    let filled = (votes * POLL_BAR_WIDTH as u64 + total / 2)
+        .checked_div(total)
+        .unwrap_or(0) as usize;
+    format!(
+        "{}{}",
+        "█".repeat(filled),
+        "░".repeat(POLL_BAR_WIDTH - filled)
+    )
+}
+
+fn percent(votes: u64, total: u64) -> u64 {
+    (votes * 100 + total / 2).checked_div(total).unwrap_or(0)
+}
+
+fn tally_lines(poll: &Poll, tallies: &[u64]) -> String {
+    let total: u64 = tallies.iter().sum();
+    poll.options
+        .iter()
+        .enumerate()
+        .map(|(i, option)| {
+            let votes = tallies.get(i).copied().unwrap_or(0);
+            format!(
+                "**{}.** {}\n`{}` {} ({}%)",
+                i + 1,
+                option,
+                tally_bar(votes, total),
+                votes,
+                percent(votes, total)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+async fn translate(config: &Config, key: &str, params: &[(&str, String)]) -> String {
+    let params: HashMap<String, String> = params
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect();
+    get_translated_message(config, key, Some(&params), None, None, None).await
+}
+
+async fn poll_embed(config: &Config, poll: &Poll, tallies: &[u64], live: bool) -> CreateEmbed {
+    let total: u64 = tallies.iter().sum();
+    let mut description = if live {
+        tally_lines(poll, tallies)
+    } else {
+        poll.options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| format!("**{}.** {}", i + 1, option))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
+    description.push_str("\n\n");
+    description
+        .push_str(&translate(config, "poll.total_votes", &[("count", total.to_string())]).await);
+    if let Some(closes_at) = poll.closes_at {
+        description.push('\n');
+        description.push_str(
+            &translate(
+                config,
+                "poll.closes",
+                &[("time", format!("<t:{}:R>", closes_at))],
+            )
+            .await,
+        );
+    }
+
+    CreateEmbed::new()
+        .title(poll.question.clone())
+        .description(description)
+        .color(Colour::new(POLL_OPEN_COLOR))
+        .footer(CreateEmbedFooter::new(
+            translate(config, "poll.footer", &[("id", poll.id.to_string())]).await,
+        ))
+}
+
+async fn results_embed(
+    config: &Config,
+    poll: &Poll,
+    tallies: &[u64],
+    closed_at: i64,
+) -> CreateEmbed {
+    let total: u64 = tallies.iter().sum();
+    let best = tallies.iter().copied().max().unwrap_or(0);
+
+    let summary = if total == 0 {
+        translate(config, "poll.no_votes", &[]).await
+    } else {
+        let winners: Vec<&str> = poll
+            .options
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| tallies.get(*i).copied() == Some(best))
+            .map(|(_, option)| option.as_str())
+            .collect();
+        if winners.len() == 1 {
+            translate(config, "poll.winner", &[("option", winners[0].to_string())]).await
+        } else {
+            translate(config, "poll.tie", &[("options", winners.join(", "))]).await
+        }
+    };
+
+    let description = format!(
+        "{}\n\n{}\n{}\n{}",
+        tally_lines(poll, tallies),
+        summary,
+        translate(config, "poll.total_votes", &[("count", total.to_string())]).await,
+        translate(
+            config,
+            "poll.closed_on",
+            &[("time", format!("<t:{}:R>", closed_at))]
+        )
+        .await,
+    );
+
+    CreateEmbed::new()
+        .title(
+            translate(
+                config,
+                "poll.results_title",
+                &[("question", poll.question.clone())],
+            )
+            .await,
+        )
+        .description(description)
+        .color(Colour::new(POLL_CLOSED_COLOR))
+        .footer(CreateEmbedFooter::new(
+            translate(config, "poll.footer", &[("id", poll.id.to_string())]).await,
+        ))
+}
+
+async fn poll_components(config: &Config, poll: &Poll) -> Vec<CreateActionRow> {
+    let mut rows: Vec<CreateActionRow> = poll
+        .options
+        .chunks(5)
+        .enumerate()
+        .map(|(row, options)| {
+            let buttons = options
+                .iter()
+                .enumerate()
+                .map(|(i, option)| {
+                    let index = row * 5 + i;
+                    let label: String = format!("{}. {}", index + 1, option)
+                        .chars()
+                        .take(80)
+                        .collect();
+                    CreateButton::new(format!("feature:poll:vote:{}:{}", poll.id, index))
+                        .label(label)
+                        .style(ButtonStyle::Secondary)
+                })
+                .collect();
+            CreateActionRow::Buttons(buttons)
+        })
+        .collect();
+
+    rows.push(CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("feature:poll:close:{}", poll.id))
+            .label(translate(config, "poll.close_button", &[]).await)
+            .style(ButtonStyle::Danger),
+    ]));
+
+    rows
+}
+
+fn modal_value(interaction: &ModalInteraction, custom_id: &str) -> Option<String> {
+    interaction
+        .data
+        .components
+        .iter()
+        .flat_map(|row| row.components.iter())
+        .find_map(|comp| match comp {
+            ActionRowComponent::InputText(input) if input.custom_id == custom_id => {
+                input.value.as_deref().map(|s| s.trim().to_string())
+            }
+            _ => None,
+        })
+}
+
+fn parse_poll_action(action: &str) -> Option<(&str, i64, Option<usize>)> {
+    let mut parts = action.split(':');
+    let verb = parts.next()?;
+    let poll_id = parts.next()?.parse().ok()?;
+    let index = parts.next().and_then(|i| i.parse().ok());
+    Some((verb, poll_id, index))
+}
+
+async fn finish_poll(
+    ctx: &Context,
+    config: &Config,
+    poll: &Poll,
+    pool: &SqlitePool,
+) -> Result<bool, String> {
+    let now = Utc::now().timestamp();
+    if !close_poll(poll.id, now, pool)
+        .await
+        .map_err(|e| format!("{e:?}"))?
+    {
+        return Ok(false);
+    }
+
+    let tallies = count_poll_votes(poll.id, poll.options.len(), pool)
+        .await
+        .map_err(|e| format!("{e:?}"))?;
+
+    let (Ok(channel_id), Some(Ok(message_id))) = (
+        poll.channel_id.parse::<u64>(),
+        poll.message_id.as_deref().map(str::parse::<u64>),
+    ) else {
+        return Ok(true);
+    };
+
+    let edit = EditMessage::new()
+        .embed(results_embed(config, poll, &tallies, now).await)
+        .components(vec![]);
+    if let Err(e) = ChannelId::new(channel_id)
+        .edit_message(&ctx.http, MessageId::new(message_id), edit)
+        .await
+    {
+        error!("Failed to post results of poll {}: {}", poll.id, e);
+    }
+
+    Ok(true)
+}
+
+pub async fn run_poll_close_job(ctx: &Context, config: &Config, key: &str) -> JobResult {
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Err("Database pool is not set in config".to_string());
+    };
+
+    let poll_id = key
+        .parse::<i64>()
+        .map_err(|_| format!("Invalid poll id '{}'", key))?;
+
+    let poll = match get_poll(poll_id, pool).await {
+        Ok(Some(poll)) if poll.closed_at.is_none() => poll,
+        Ok(_) => return Ok(JobOutcome::Done),
+        Err(e) => return Err(format!("{e:?}")),
+    };
+
+    if let Some(closes_at) = poll.closes_at
+        && closes_at > Utc::now().timestamp()
+    {
+        return Ok(JobOutcome::RunAt(closes_at));
+    }
+
+    finish_poll(ctx, config, &poll, pool).await?;
+    Ok(JobOutcome::Done)
+}
+
+impl PollFeature {
+    async fn create_from_modal(
+        &self,
+        ctx: &Context,
+        config: &Config,
+        state: &FeatureState,
+        interaction: &ModalInteraction,
+        pool: &SqlitePool,
+    ) -> Result<CreateInteractionResponse, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = interaction.user.id;
+        let guild_id = interaction.guild_id;
+
+        let question = modal_value(interaction, "question").unwrap_or_default();
+        let options = parse_poll_options(&modal_value(interaction, "options").unwrap_or_default());
+        let mut duration = modal_value(interaction, "duration").unwrap_or_default();
+        if duration.is_empty() {
+            duration = state.text("default_duration").trim().to_string();
+        }
+        let max_options =
+            (state.int("max_options") as usize).clamp(POLL_MIN_OPTIONS, POLL_MAX_OPTIONS);
+
+        if question.is_empty() || !(POLL_MIN_OPTIONS..=max_options).contains(&options.len()) {
+            let mut params = HashMap::new();
+            params.insert("min".to_string(), POLL_MIN_OPTIONS.to_string());
+            params.insert("max".to_string(), max_options.to_string());
+            return Ok(ephemeral_reply(
+                ctx,
+                config,
+                "poll.invalid_options",
+                Some(&params),
+                user_id,
+                guild_id,
+            )
+            .await);
+        }
+
+        let now = Utc::now().timestamp();
+        let closes_at = if duration.is_empty() {
+            None
+        } else {
+            match parse_duration_spec(&duration) {
+                Some(d) => Some(now + d.as_secs() as i64),
+                None => {
+                    let mut params = HashMap::new();
+                    params.insert("duration".to_string(), duration);
+                    return Ok(ephemeral_reply(
+                        ctx,
+                        config,
+                        "poll.invalid_duration",
+                        Some(&params),
+                        user_id,
+                        guild_id,
+                    )
+                    .await);
+                }
+            }
+        };
+
+        let channel_id = interaction.channel_id;
+        let poll_id = create_poll(
+            &channel_id.to_string(),
+            &question,
+            &options,
+            &user_id.to_string(),
+            now,
+            closes_at,
+            pool,
+        )
+        .await?;
+
+        let poll = Poll {
+            id: poll_id,
+            channel_id: channel_id.to_string(),
+            message_id: None,
+            question,
+            options,
+            created_by: user_id.to_string(),
+            closes_at,
+            closed_at: None,
+        };
+
+        let message = CreateMessage::new()
+            .embed(
+                poll_embed(
+                    config,
+                    &poll,
+                    &vec![0; poll.options.len()],
+                    state.bool("live_results"),
+                )
+                .await,
+            )
+            .components(poll_components(config, &poll).await);
+        let sent = match channel_id.send_message(&ctx.http, message).await {
+            Ok(sent) => sent,
+            Err(e) => {
+                let _ = delete_poll(poll_id, pool).await;
+                return Err(e.into());
+            }
+        };
+
+        set_poll_message(poll_id, &sent.id.to_string(), pool).await?;
+        if let Some(closes_at) = closes_at {
+            enqueue_job(
+                JobKind::PollClose,
+                &poll_id.to_string(),
+                "{}",
+                closes_at,
+                pool,
+            )
+            .await?;
+        }
+
+        Ok(ephemeral_reply(ctx, config, "poll.created", None, user_id, guild_id).await)
+    }
+
+    async fn vote(
+        ctx: &Context,
+        config: &Config,
+        state: &FeatureState,
+        interaction: &ComponentInteraction,
+        poll: &Poll,
+        index: usize,
+        pool: &SqlitePool,
+    ) -> Result<CreateInteractionResponse, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = interaction.user.id;
+        let guild_id = interaction.guild_id;
+
+        if guild_id.map(|g| g.get()) != Some(config.bot.get_staff_guild_id()) {
+            return Ok(
+                ephemeral_reply(ctx, config, "poll.staff_only", None, user_id, guild_id).await,
+            );
+        }
+        if index >= poll.options.len() {
+            return Ok(CreateInteractionResponse::Acknowledge);
+        }
+
+        let voter = user_id.to_string();
+        if get_poll_vote(poll.id, &voter, pool).await? == Some(index) {
+            delete_poll_vote(poll.id, &voter, pool).await?;
+        } else {
+            set_poll_vote(poll.id, &voter, index, Utc::now().timestamp(), pool).await?;
+        }
+
+        let tallies = count_poll_votes(poll.id, poll.options.len(), pool).await?;
+        Ok(CreateInteractionResponse::UpdateMessage(
+            CreateInteractionResponseMessage::new()
+                .embed(poll_embed(config, poll, &tallies, state.bool("live_results")).await)
+                .components(poll_components(config, poll).await),
+        ))
+    }
+
+    async fn close(
+        &self,
+        ctx: &Context,
+        config: &Config,
+        interaction: &ComponentInteraction,
+        poll: &Poll,
+        pool: &SqlitePool,
+    ) -> Result<CreateInteractionResponse, Box<dyn std::error::Error + Send + Sync>> {
+        let user_id = interaction.user.id;
+        let guild_id = interaction.guild_id;
+
+        let can_manage = interaction
+            .member
+            .as_ref()
+            .and_then(|m| m.permissions)
+            .is_some_and(|p| p.manage_messages());
+        if poll.created_by != user_id.to_string() && !can_manage {
+            return Ok(ephemeral_reply(
+                ctx,
+                config,
+                "poll.close_forbidden",
+                None,
+                user_id,
+                guild_id,
+            )
+            .await);
+        }
+
+        if !finish_poll(ctx, config, poll, pool).await? {
+            return Ok(ephemeral_reply(
+                ctx,
+                config,
+                "poll.already_closed",
+                None,
+                user_id,
+                guild_id,
+            )
+            .await);
+        }
+        let _ = cancel_job(JobKind::PollClose, &poll.id.to_string(), pool).await;
+
+        Ok(ephemeral_reply(ctx, config, "poll.closed", None, user_id, guild_id).await)
+    }
+}
+
+pub async fn poll_modal(config: &Config) -> CreateModal {
+    CreateModal::new(
+        "feature:poll:create",
+        translate(config, "poll.modal_title", &[]).await,
+    )
+    .components(vec![
+        CreateActionRow::InputText(
+            CreateInputText::new(
+                InputTextStyle::Short,
+                translate(config, "poll.modal_question", &[]).await,
+                "question",
+            )
+            .min_length(3)
+            .max_length(200),
+        ),
+        CreateActionRow::InputText(
+            CreateInputText::new(
+                InputTextStyle::Paragraph,
+                translate(config, "poll.modal_options", &[]).await,
+                "options",
+            )
+            .min_length(3)
+            .max_length(1000),
+        ),
+        CreateActionRow::InputText(
+            CreateInputText::new(
+                InputTextStyle::Short,
+                translate(config, "poll.modal_duration", &[]).await,
+                "duration",
+            )
+            .required(false)
+            .max_length(20),
+        ),
+    ])
+}
+
 #[async_trait]
-impl<'a> Feature<'a> for PollFeature {
+impl Feature for PollFeature {
     fn key(&self) -> &'static str {
         "poll"
     }
 
-    async fn build_message(&self, ctx: &'a Context, config: &'a Config) -> CreateMessage {
-        let row = make_buttons(&[
-            (
-                "Créer un sondage",
-                "feature:poll:create",
-                ButtonStyle::Success,
-                false,
+    fn settings(&self) -> Vec<FeatureSetting> {
+        vec![
+            FeatureSetting::integer(
+                "max_options",
+                POLL_MIN_OPTIONS as i64,
+                POLL_MAX_OPTIONS as i64,
+                POLL_MAX_OPTIONS as i64,
             ),
-            ("Test", "feature:poll:delete", ButtonStyle::Danger, false),
-        ]);
+            FeatureSetting::text("default_duration", 20, ""),
+            FeatureSetting::boolean("live_results", true),
+        ]
+    }
+
+    fn validate_settings(&self, settings: &Map<String, Value>) -> Result<(), String> {
+        match settings.get("default_duration").and_then(Value::as_str) {
+            Some(d) if !d.trim().is_empty() && parse_duration_spec(d.trim()).is_none() => {
+                Err(format!("'{}' is not a valid duration", d))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn commands(&self) -> Vec<Arc<dyn RegistrableCommand>> {
+        vec![Arc::new(PollCommand)]
+    }
+
+    async fn build_message(
+        &self,
+        ctx: &Context,
+        config: &Config,
+        _state: &FeatureState,
+    ) -> Option<CreateMessage> {
+        let label = translate(config, "poll.create_button", &[]).await;
+        let row = make_buttons(&[(
+            label.as_str(),
+            "feature:poll:create",
+            ButtonStyle::Success,
+            false,
+        )]);
 
-        MessageBuilder::system_message(ctx, config)
-            .content("Sondage (poll):\n\n- Commande: !poll Question ? | Option 1 | Option 2 | ...\n- Bouton ci-dessous: ouvre un formulaire pour créer un sondage dans ce salon.")
-            .components(row)
-            .build()
-            .await
+        Some(
+            MessageBuilder::system_message(ctx, config)
+                .translated_content("poll.feature_description", None, None, None)
+                .await
+                .components(row)
+                .build()
+                .await,
+        )
     }
 
     async fn handle_interaction(
         &self,
         ctx: &Context,
-        _config: &Config,
+        config: &Config,
+        state: &FeatureState,
         interaction: &ComponentInteraction,
         action: &str,
-    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    ) -> FeatureResult {
+        if action == "create" {
+            interaction
+                .create_response(
+                    &ctx.http,
+                    CreateInteractionResponse::Modal(poll_modal(config).await),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let Some((verb, poll_id, index)) = parse_poll_action(action) else {
+            return Ok(());
+        };
+        let Some(pool) = config.db_pool.as_ref() else {
+            return Ok(());
+        };
+
+        let user_id = interaction.user.id;
+        let guild_id = interaction.guild_id;
+        let response = match get_poll(poll_id, pool).await? {
+            None => ephemeral_reply(ctx, config, "poll.not_found", None, user_id, guild_id).await,
+            Some(poll) if poll.closed_at.is_some() => {
+                ephemeral_reply(ctx, config, "poll.already_closed", None, user_id, guild_id).await
+            }
+            Some(poll) => match (verb, index) {
+                ("vote", Some(index)) => {
+                    Self::vote(ctx, config, state, interaction, &poll, index, pool).await?
+                }
+                ("close", _) => self.close(ctx, config, interaction, &poll, pool).await?,
+                _ => CreateInteractionResponse::Acknowledge,
+            },
+        };
+
+        interaction.create_response(&ctx.http, response).await?;
+        Ok(())
+    }
+
+    async fn handle_modal(
+        &self,
+        ctx: &Context,
+        config: &Config,
+        state: &FeatureState,
+        interaction: &ModalInteraction,
+        action: &str,
+    ) -> FeatureResult {
         if action != "create" {
             return Ok(());
         }
+        let Some(pool) = config.db_pool.as_ref() else {
+            return Ok(());
+        };
 
-        let modal = CreateModal::new("feature:poll:create", "Créer un sondage").components(vec![
-            CreateActionRow::InputText(
-                CreateInputText::new(InputTextStyle::Short, "Question", "question")
-                    .min_length(3)
-                    .max_length(200),
-            ),
-            CreateActionRow::InputText(
-                CreateInputText::new(
-                    InputTextStyle::Paragraph,
-                    "Options (séparées par | ou retour à la ligne)",
-                    "options",
-                )
-                .min_length(3)
-                .max_length(1000),
-            ),
-        ]);
-        interaction
-            .create_response(&ctx.http, CreateInteractionResponse::Modal(modal))
+        let response = self
+            .create_from_modal(ctx, config, state, interaction, pool)
             .await?;
+        interaction.create_response(&ctx.http, response).await?;
         Ok(())
     }
 }
+
+pub struct PollCommand;
+
+impl RegistrableCommand for PollCommand {
+    fn name(&self) -> &'static str {
+        "poll"
+    }
+
+    fn doc<'a>(&self, config: &'a Config) -> BoxFuture<'a, String> {
+        Box::pin(async move {
+            get_translated_message(config, "help.poll", None, None, None, None).await
+        })
+    }
+
+    fn register(&self, config: &Config) -> BoxFuture<'_, Vec<CreateCommand>> {
+        let config = config.clone();
+
+        Box::pin(async move {
+            let cmd_desc = get_translated_message(
+                &config,
+                "slash_command.poll_command_description",
+                None,
+                None,
+                None,
+                None,
+            )
+            .await;
+
+            vec![CreateCommand::new(self.name()).description(cmd_desc)]
+        })
+    }
+
+    fn run(
+        &self,
+        ctx: &Context,
+        command: &CommandInteraction,
+        _options: &[ResolvedOption<'_>],
+        config: &Config,
+        _handler: Arc<InteractionHandler>,
+    ) -> BoxFuture<'_, ModmailResult<()>> {
+        let ctx = ctx.clone();
+        let command = command.clone();
+        let config = config.clone();
+
+        Box::pin(async move {
+            command
+                .create_response(
+                    &ctx.http,
+                    CreateInteractionResponse::Modal(poll_modal(&config).await),
+                )
+                .await?;
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn options_split_on_pipes_and_new_lines() {
+        assert_eq!(
+            parse_poll_options("Yes | No\nMaybe"),
+            vec!["Yes", "No", "Maybe"]
+        );
+    }
+
+    #[test]
+    fn options_drop_empty_and_duplicate_entries() {
+        assert_eq!(
+            parse_poll_options(" Yes ||\n\nyes| No |"),
+            vec!["Yes", "No"]
+        );
+    }
+
+    #[test]
+    fn tally_bar_is_proportional() {
+        assert_eq!(tally_bar(0, 0), "░".repeat(POLL_BAR_WIDTH));
+        assert_eq!(
+            tally_bar(1, 2),
+            format!("{}{}", "█".repeat(5), "░".repeat(5))
+        );
+        assert_eq!(tally_bar(3, 3), "█".repeat(POLL_BAR_WIDTH));
+    }
+}
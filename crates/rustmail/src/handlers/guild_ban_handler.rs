@@ -6,24 +6,25 @@ use serenity::all::audit_log::Action;
 use serenity::all::{Context, EventHandler, GuildId, Member, MemberAction, User, UserId};
 use serenity::async_trait;
 use sqlx::SqlitePool;
+use tracing::{error, info};
 
 pub struct GuildBanHandler {
-    pub config: Config,
+    pub config: ConfigHandle,
 }
 
 impl GuildBanHandler {
-    pub fn new(config: &Config) -> Self {
+    pub fn new(config: &ConfigHandle) -> Self {
         Self {
             config: config.clone(),
         }
     }
 
-    fn pool(&self) -> Option<&SqlitePool> {
-        self.config.db_pool.as_ref()
-    }
-
-    fn should_track(&self, guild_id: GuildId) -> bool {
-        self.config.bot.is_community_guild(guild_id.get())
+    fn tracking_pool(&self, guild_id: GuildId) -> Option<SqlitePool> {
+        let config = self.config.load();
+        if !config.bot.is_community_guild(guild_id.get()) {
+            return None;
+        }
+        config.db_pool.clone()
     }
 }
 
@@ -46,7 +47,7 @@ async fn record_member(pool: &SqlitePool, member: &Member) {
     let now = Utc::now().timestamp();
     let tracked = member_to_tracked(member, now);
     if let Err(e) = upsert_tracked_member(&tracked, pool).await {
-        eprintln!("Failed to track member {}: {:?}", member.user.id, e);
+        error!("Failed to track member {}: {:?}", member.user.id, e);
     }
 }
 
@@ -82,13 +83,10 @@ async fn fetch_ban_audit(
 #[async_trait]
 impl EventHandler for GuildBanHandler {
     async fn guild_member_addition(&self, _ctx: Context, new_member: Member) {
-        if !self.should_track(new_member.guild_id) {
-            return;
-        }
-        let Some(pool) = self.pool() else {
+        let Some(pool) = self.tracking_pool(new_member.guild_id) else {
             return;
         };
-        record_member(pool, &new_member).await;
+        record_member(&pool, &new_member).await;
     }
 
     async fn guild_member_update(
@@ -101,20 +99,14 @@ impl EventHandler for GuildBanHandler {
         let Some(new_member) = new else {
             return;
         };
-        if !self.should_track(new_member.guild_id) {
-            return;
-        }
-        let Some(pool) = self.pool() else {
+        let Some(pool) = self.tracking_pool(new_member.guild_id) else {
             return;
         };
-        record_member(pool, &new_member).await;
+        record_member(&pool, &new_member).await;
     }
 
     async fn guild_ban_addition(&self, ctx: Context, guild_id: GuildId, banned_user: User) {
-        if !self.should_track(guild_id) {
-            return;
-        }
-        let Some(pool) = self.pool() else {
+        let Some(pool) = self.tracking_pool(guild_id) else {
             return;
         };
 
@@ -122,7 +114,7 @@ impl EventHandler for GuildBanHandler {
         let user_id_str = banned_user.id.to_string();
         let now = Utc::now().timestamp();
 
-        let tracked = get_tracked_member(&guild_id_str, &user_id_str, pool)
+        let tracked = get_tracked_member(&guild_id_str, &user_id_str, &pool)
             .await
             .unwrap_or(None);
 
@@ -159,17 +151,17 @@ impl EventHandler for GuildBanHandler {
             },
         };
 
-        match save_banned_user(&record, pool).await {
+        match save_banned_user(&record, &pool).await {
             Ok(()) => {
-                if let Err(e) = delete_tracked_member(&guild_id_str, &user_id_str, pool).await {
-                    eprintln!(
+                if let Err(e) = delete_tracked_member(&guild_id_str, &user_id_str, &pool).await {
+                    error!(
                         "Failed to delete tracked member {} from guild {} after saving banned user: {:?}",
                         banned_user.id, guild_id, e
                     );
                 }
             }
             Err(e) => {
-                eprintln!("Failed to save banned user {}: {:?}", banned_user.id, e);
+                error!("Failed to save banned user {}: {:?}", banned_user.id, e);
             }
         }
     }
@@ -194,14 +186,14 @@ pub async fn backfill_tracked_members(
 
     loop {
         if *shutdown.borrow() {
-            println!("Tracked member backfill cancelled due to shutdown.");
+            info!("Tracked member backfill cancelled due to shutdown.");
             return;
         }
 
         let page = match guild_id.members(&ctx.http, Some(PAGE_LIMIT), after).await {
             Ok(p) => p,
             Err(e) => {
-                eprintln!("Failed to fetch guild members for backfill: {:?}", e);
+                error!("Failed to fetch guild members for backfill: {:?}", e);
                 return;
             }
         };
@@ -219,7 +211,7 @@ pub async fn backfill_tracked_members(
         match bulk_upsert_tracked_members(&tracked_batch, pool).await {
             Ok(()) => total += page_len,
             Err(e) => {
-                eprintln!(
+                error!(
                     "Failed to backfill tracked members page in guild {}: {:?}",
                     guild_id, e
                 );
@@ -233,7 +225,7 @@ pub async fn backfill_tracked_members(
         after = last_id;
     }
 
-    println!(
+    info!(
         "Backfilled {} tracked members for community guild {}",
         total, guild_id
     );
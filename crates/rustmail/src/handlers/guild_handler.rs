@@ -4,13 +4,14 @@ use crate::prelude::utils::*;
 use async_trait::async_trait;
 use serenity::all::{Context, GuildChannel, Message};
 use serenity::client::EventHandler;
+use tracing::{error, info};
 
 pub struct GuildHandler {
-    pub config: Config,
+    pub config: ConfigHandle,
 }
 
 impl GuildHandler {
-    pub fn new(config: &Config) -> Self {
+    pub fn new(config: &ConfigHandle) -> Self {
         Self {
             config: config.clone(),
         }
@@ -25,10 +26,11 @@ impl EventHandler for GuildHandler {
         channel: GuildChannel,
         _messages: Option<Vec<Message>>,
     ) {
-        let pool = match &self.config.db_pool {
+        let config = self.config.load();
+        let pool = match &config.db_pool {
             Some(pool) => pool,
             None => {
-                eprintln!("Database pool is not set in config.");
+                error!("Database pool is not set in config.");
                 return;
             }
         };
@@ -58,10 +60,10 @@ impl EventHandler for GuildHandler {
             .await
             {
                 Ok(_) => {
-                    println!("Close thread successfully by deleted channel!");
+                    info!("Close thread successfully by deleted channel!");
                 }
                 Err(e) => {
-                    eprintln!(
+                    error!(
                         "Failed to close thread for deleted channel {}: {}",
                         channel.id, e
                     );
@@ -3,140 +3,190 @@ use crate::prelude::config::*;
 use crate::prelude::features::*;
 use crate::prelude::modules::*;
 use crate::prelude::types::*;
-use crate::utils::{MessageBuilder, defer_response};
-use serenity::all::{Context, EventHandler, Interaction, Permissions};
+use crate::utils::{MessageBuilder, defer_response, respond_autocomplete};
+use serenity::all::{Context, EventHandler, Interaction};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use tokio::sync::watch::Receiver;
+use tracing::{Instrument, error, info_span};
 
 #[derive(Clone)]
 pub struct InteractionHandler {
-    pub config: Arc<Config>,
+    pub config: ConfigHandle,
     pub registry: Arc<CommandRegistry>,
-    pub shutdown: Arc<Receiver<bool>>,
     pub pagination: PaginationStore,
     pub maintenance_mode: Arc<AtomicBool>,
 }
 
 impl InteractionHandler {
     pub fn new(
-        config: &Config,
+        config: &ConfigHandle,
         registry: Arc<CommandRegistry>,
-        shutdown: Receiver<bool>,
         pagination: PaginationStore,
         maintenance_mode: Arc<AtomicBool>,
     ) -> Self {
         Self {
-            config: Arc::new(config.clone()),
+            config: config.clone(),
             registry,
-            shutdown: Arc::new(shutdown),
             pagination,
             maintenance_mode,
         }
     }
 }
 
-async fn is_interaction_user_maintenance_exempt(
-    ctx: &Context,
-    guild_id: serenity::all::GuildId,
-    user_id: serenity::all::UserId,
-    config: &Config,
-) -> bool {
-    if config.bot.panel_super_admin_users.contains(&user_id.get()) {
-        return true;
-    }
-
-    let member = match guild_id.member(&ctx.http, user_id).await {
-        Ok(m) => m,
-        Err(_) => return false,
-    };
-
-    if !config.bot.panel_super_admin_roles.is_empty() {
-        for role_id in &member.roles {
-            if config.bot.panel_super_admin_roles.contains(&role_id.get()) {
-                return true;
-            }
-        }
-    }
-
-    let guild = match guild_id.to_partial_guild(&ctx.http).await {
-        Ok(g) => g,
-        Err(_) => return false,
-    };
-
-    if guild.owner_id == user_id {
-        return true;
-    }
-
-    member.roles.iter().any(|role_id| {
-        guild
-            .roles
-            .get(role_id)
-            .map(|role| role.permissions.contains(Permissions::ADMINISTRATOR))
-            .unwrap_or(false)
-    })
-}
-
 #[async_trait::async_trait]
 impl EventHandler for InteractionHandler {
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        let config = self.config.load();
         match interaction {
             Interaction::Component(mut comp) => {
-                match handle_category_component_interaction(&ctx, &self.config, &comp).await {
+                match handle_board_component_interaction(&ctx, &config, &comp).await {
                     Ok(true) => return,
                     Ok(false) => {}
                     Err(e) => {
-                        eprintln!("category interaction error: {e:?}");
+                        error!("board interaction error: {e:?}");
                         return;
                     }
                 }
-                if let Err(..) =
-                    handle_feature_component_interaction(&ctx, &self.config, &comp).await
-                {
-                    return;
+                match handle_gdpr_component_interaction(&ctx, &config, &comp).await {
+                    Ok(true) => return,
+                    Ok(false) => {}
+                    Err(e) => {
+                        error!("gdpr interaction error: {e:?}");
+                        return;
+                    }
+                }
+                match handle_category_component_interaction(&ctx, &config, &comp).await {
+                    Ok(true) => return,
+                    Ok(false) => {}
+                    Err(e) => {
+                        error!("category interaction error: {e:?}");
+                        return;
+                    }
+                }
+                match handle_feature_component_interaction(&ctx, &config, &comp).await {
+                    Ok(true) => return,
+                    Ok(false) => {}
+                    Err(e) => {
+                        error!("feature interaction error: {e:?}");
+                        return;
+                    }
                 }
-                if let Err(..) =
-                    handle_thread_component_interaction(&ctx, &self.config, &mut comp).await
+                if let Err(e) = handle_thread_component_interaction(&ctx, &config, &mut comp).await
                 {
+                    error!("thread interaction error: {e:?}");
                     return;
                 }
-                if let Err(..) = handle_command_component_interaction(
+                if let Err(e) = handle_command_component_interaction(
                     &ctx,
-                    &self.config,
+                    &config,
                     &mut comp,
                     self.pagination.clone(),
                 )
                 .await
                 {
-                    return;
+                    error!("command interaction error: {e:?}");
                 }
             }
             Interaction::Modal(mut modal) => {
-                if let Err(..) =
-                    handle_thread_modal_interaction(&ctx, &self.config, &mut modal).await
-                {
-                    return;
+                match handle_feature_modal_interaction(&ctx, &config, &modal).await {
+                    Ok(true) => return,
+                    Ok(false) => {}
+                    Err(e) => {
+                        error!("feature modal error: {e:?}");
+                        return;
+                    }
+                }
+                match handle_category_modal_interaction(&ctx, &config, &modal).await {
+                    Ok(true) => return,
+                    Ok(false) => {}
+                    Err(e) => {
+                        error!("category form error: {e:?}");
+                        return;
+                    }
+                }
+                if let Err(e) = handle_thread_modal_interaction(&ctx, &config, &mut modal).await {
+                    error!("thread modal error: {e:?}");
                 }
             }
             Interaction::Command(command) => {
                 if self.maintenance_mode.load(Ordering::Relaxed)
                     && let Some(guild_id) = command.guild_id
-                    && !is_interaction_user_maintenance_exempt(
-                        &ctx,
-                        guild_id,
+                    && !is_bot_admin(&ctx, &config, guild_id, command.user.id).await
+                {
+                    defer_response(&ctx, &command).await.ok();
+
+                    let _ = MessageBuilder::system_message(&ctx, &config)
+                        .translated_content(
+                            "status.maintenance_mode_active",
+                            None,
+                            Some(command.user.id),
+                            command.guild_id.map(|g| g.get()),
+                        )
+                        .await
+                        .send_interaction_followup(&command, true)
+                        .await;
+                    return;
+                }
+
+                if let Some(feature_key) = self.registry.feature_of(command.data.name.as_str())
+                    && !is_feature_enabled(&config, feature_key).await
+                {
+                    defer_response(&ctx, &command).await.ok();
+
+                    let _ = MessageBuilder::system_message(&ctx, &config)
+                        .translated_content(
+                            "feature.disabled",
+                            None,
+                            Some(command.user.id),
+                            command.guild_id.map(|g| g.get()),
+                        )
+                        .await
+                        .send_interaction_followup(&command, true)
+                        .await;
+                    return;
+                }
+
+                let identity = match command.guild_id {
+                    Some(guild_id) => StaffIdentity::resolve(&ctx, guild_id, command.user.id).await,
+                    None => None,
+                };
+
+                if command.guild_id.is_some()
+                    && !can_access_ticket_channel(&config, command.channel_id, identity.as_ref())
+                        .await
+                {
+                    defer_response(&ctx, &command).await.ok();
+
+                    let _ = MessageBuilder::system_message(&ctx, &config)
+                        .translated_content(
+                            "category.access_denied",
+                            None,
+                            Some(command.user.id),
+                            command.guild_id.map(|g| g.get()),
+                        )
+                        .await
+                        .send_interaction_followup(&command, true)
+                        .await;
+                    return;
+                }
+
+                if let Some(guild_id) = command.guild_id
+                    && !can_run_command(
+                        &config,
+                        command.data.name.as_str(),
                         command.user.id,
-                        &self.config,
+                        identity.as_ref(),
                     )
                     .await
                 {
                     defer_response(&ctx, &command).await.ok();
 
-                    let _ = MessageBuilder::system_message(&ctx, &self.config)
+                    let _ = MessageBuilder::system_message(&ctx, &config)
                         .translated_content(
-                            "status.maintenance_mode_active",
+                            "command.insufficient_permissions",
                             None,
                             Some(command.user.id),
-                            command.guild_id.map(|g| g.get()),
+                            Some(guild_id.get()),
                         )
                         .await
                         .send_interaction_followup(&command, true)
@@ -147,22 +197,39 @@ impl EventHandler for InteractionHandler {
                 let ctx = ctx.clone();
                 let command = command.clone();
                 let options = command.data.options().clone();
-                let config = self.config.clone();
 
                 if let Some(handler) = self.registry.get(command.data.name.as_str()) {
                     let result = handler
                         .run(&ctx, &command, &options, &config, Arc::new(self.clone()))
+                        .instrument(info_span!(
+                            "command",
+                            command = %command.data.name,
+                            user_id = %command.user.id,
+                            channel_id = %command.channel_id
+                        ))
                         .await;
 
                     if let Err(e) = result
-                        && let Some(error_handler) = &self.config.error_handler
+                        && let Some(error_handler) = &config.error_handler
                     {
                         let _ = error_handler
                             .reply_to_command_with_error(&ctx, &command, &e)
                             .await;
                     }
                 } else {
-                    eprintln!("Command {} not found", command.data.name);
+                    error!("Command {} not found", command.data.name);
+                }
+            }
+            Interaction::Autocomplete(command) => {
+                let Some(handler) = self.registry.get(command.data.name.as_str()) else {
+                    return;
+                };
+                let choices = handler.autocomplete(&ctx, &command, &config).await;
+                if let Err(e) = respond_autocomplete(&ctx, &command, choices).await {
+                    error!(
+                        "Failed to answer autocomplete for {}: {e:?}",
+                        command.data.name
+                    );
                 }
             }
             _ => {}
@@ -9,13 +9,14 @@ use serenity::{
     async_trait,
 };
 use std::collections::HashMap;
+use tracing::error;
 
 pub struct GuildMembersHandler {
-    pub config: Config,
+    pub config: ConfigHandle,
 }
 
 impl GuildMembersHandler {
-    pub fn new(config: &Config) -> Self {
+    pub fn new(config: &ConfigHandle) -> Self {
         Self {
             config: config.clone(),
         }
@@ -31,14 +32,15 @@ impl EventHandler for GuildMembersHandler {
         user: User,
         _member: Option<Member>,
     ) {
-        if !self.config.bot.is_community_guild(guild_id.get()) {
+        let config = self.config.load();
+        if !config.bot.is_community_guild(guild_id.get()) {
             return;
         }
 
-        let pool = match &self.config.db_pool {
+        let pool = match &config.db_pool {
             Some(pool) => pool,
             None => {
-                eprintln!("Database pool is not set in config.");
+                error!("Database pool is not set in config.");
                 return;
             }
         };
@@ -47,7 +49,7 @@ impl EventHandler for GuildMembersHandler {
             Some(thread) => match thread.channel_id.parse::<u64>() {
                 Ok(channel_id_num) => (thread, serenity::all::ChannelId::new(channel_id_num)),
                 Err(err) => {
-                    eprintln!("Invalid channel ID format for user {} : {}", user.id, err);
+                    error!("Invalid channel ID format for user {} : {}", user.id, err);
                     return;
                 }
             },
@@ -60,22 +62,15 @@ impl EventHandler for GuildMembersHandler {
 
         let close_buttons = make_buttons(&[
             (
-                get_translated_message(
-                    &self.config,
-                    "thread.ask_to_keep_open",
-                    None,
-                    None,
-                    None,
-                    None,
-                )
-                .await
-                .as_ref(),
+                get_translated_message(&config, "thread.ask_to_keep_open", None, None, None, None)
+                    .await
+                    .as_ref(),
                 "ticket:keep",
                 ButtonStyle::Success,
                 false,
             ),
             (
-                get_translated_message(&self.config, "thread.ask_to_close", None, None, None, None)
+                get_translated_message(&config, "thread.ask_to_close", None, None, None, None)
                     .await
                     .as_ref(),
                 "ticket:delete",
@@ -84,7 +79,7 @@ impl EventHandler for GuildMembersHandler {
             ),
         ]);
 
-        let _ = MessageBuilder::system_message(&ctx, &self.config)
+        let _ = MessageBuilder::system_message(&ctx, &config)
             .translated_content(
                 "user.left_server_notification",
                 Some(&params),
@@ -98,7 +93,7 @@ impl EventHandler for GuildMembersHandler {
             .await;
 
         if let Err(e) = update_thread_user_left(&thread.channel_id, pool).await {
-            eprintln!("Erreur lors de la mise à jour du statut du thread: {:?}", e);
+            error!("Erreur lors de la mise à jour du statut du thread: {:?}", e);
         }
 
         let closed_by = "user_left_server".to_string();
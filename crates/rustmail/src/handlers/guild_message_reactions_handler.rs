@@ -3,14 +3,15 @@ use crate::prelude::db::*;
 use crate::prelude::errors::*;
 use serenity::all::{ChannelId, Context, EventHandler, MessageId, Reaction, UserId};
 use serenity::async_trait;
+use tracing::error;
 
 #[derive(Clone)]
 pub struct GuildMessageReactionsHandler {
-    pub config: Config,
+    pub config: ConfigHandle,
 }
 
 impl GuildMessageReactionsHandler {
-    pub fn new(config: &Config) -> Self {
+    pub fn new(config: &ConfigHandle) -> Self {
         Self {
             config: config.clone(),
         }
@@ -20,14 +21,16 @@ impl GuildMessageReactionsHandler {
 #[async_trait]
 impl EventHandler for GuildMessageReactionsHandler {
     async fn reaction_add(&self, ctx: Context, reaction: Reaction) {
-        if let Err(e) = handle_reaction_add(&ctx, &reaction, &self.config).await {
-            eprintln!("Error handling reaction add: {}", e);
+        let config = self.config.load();
+        if let Err(e) = handle_reaction_add(&ctx, &reaction, &config).await {
+            error!("Error handling reaction add: {}", e);
         }
     }
 
     async fn reaction_remove(&self, ctx: Context, reaction: Reaction) {
-        if let Err(e) = handle_reaction_remove(&ctx, &reaction, &self.config).await {
-            eprintln!("Error handling reaction remove: {}", e);
+        let config = self.config.load();
+        if let Err(e) = handle_reaction_remove(&ctx, &reaction, &config).await {
+            error!("Error handling reaction remove: {}", e);
         }
     }
 
@@ -37,11 +40,11 @@ impl EventHandler for GuildMessageReactionsHandler {
         channel_id: ChannelId,
         removed_from_message_id: MessageId,
     ) {
+        let config = self.config.load();
         if let Err(e) =
-            handle_all_reaction_remove(&ctx, &removed_from_message_id, channel_id, &self.config)
-                .await
+            handle_all_reaction_remove(&ctx, &removed_from_message_id, channel_id, &config).await
         {
-            eprintln!("Error handling all reaction remove: {}", e);
+            error!("Error handling all reaction remove: {}", e);
         }
     }
 }
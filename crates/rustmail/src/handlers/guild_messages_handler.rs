@@ -7,7 +7,7 @@ use crate::prelude::modules::*;
 use crate::prelude::types::*;
 use crate::prelude::utils::*;
 use crate::wrap_command;
-use serenity::all::{GuildId, MessageId, Permissions, UserId};
+use serenity::all::{GuildId, MessageId, UserId};
 use serenity::{
     all::{ChannelId, Context, EventHandler, Message, MessageUpdateEvent},
     async_trait,
@@ -17,7 +17,7 @@ use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{LazyLock, Mutex};
 use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc};
 use tokio::sync::Mutex as AsyncMutex;
-use tokio::sync::watch::Receiver;
+use tracing::{Instrument, error, info_span};
 
 static SUPPRESSED_DELETES: LazyLock<Mutex<HashSet<u64>>> =
     LazyLock::new(|| Mutex::new(HashSet::new()));
@@ -33,66 +33,73 @@ type StaticCommandFunc = dyn for<'a> Fn(
     + Sync
     + 'static;
 
+#[derive(Clone)]
+pub struct TextCommand {
+    pub command: &'static str,
+    pub run: CommandFunc,
+}
+
 #[derive(Clone)]
 pub struct GuildMessagesHandler {
-    pub config: Arc<Config>,
-    pub commands: Arc<AsyncMutex<HashMap<String, CommandFunc>>>,
+    pub config: ConfigHandle,
+    pub commands: Arc<AsyncMutex<HashMap<String, TextCommand>>>,
     pub registry: Arc<CommandRegistry>,
-    pub shutdown: Arc<Receiver<bool>>,
     pub pagination: PaginationStore,
     pub maintenance_mode: Arc<AtomicBool>,
 }
 
 impl GuildMessagesHandler {
     pub async fn new(
-        config: &Config,
+        config: &ConfigHandle,
         registry: Arc<CommandRegistry>,
-        shutdown: Receiver<bool>,
         pagination: PaginationStore,
         maintenance_mode: Arc<AtomicBool>,
     ) -> Self {
         let h = Self {
-            config: Arc::new(config.clone()),
+            config: config.clone(),
             commands: Arc::new(AsyncMutex::new(HashMap::new())),
             registry,
-            shutdown: Arc::new(shutdown),
             pagination,
             maintenance_mode,
         };
 
-        let mut lock = h.commands.lock().await;
-
-        wrap_command!(lock, ["reply", "r"], reply);
-        wrap_command!(lock, ["edit", "e"], edit);
-        wrap_command!(lock, ["close", "c"], close);
-        wrap_command!(lock, "recover", recover);
-        wrap_command!(lock, "alert", alert);
-        wrap_command!(lock, ["move", "mv"], move_thread);
-        wrap_command!(lock, ["nt", "new_thread"], new_thread);
-        wrap_command!(lock, "delete", delete);
-        wrap_command!(lock, ["anonreply", "ar"], anonreply);
-        wrap_command!(lock, ["force_close", "fc"], force_close);
-        wrap_command!(lock, ["addmod", "am"], add_staff);
-        wrap_command!(lock, ["delmod", "dm"], remove_staff);
-        wrap_command!(lock, "id", id);
-        wrap_command!(lock, "help", help);
-        wrap_command!(lock, ["remind", "rem"], add_reminder);
-        wrap_command!(lock, ["unremind", "urem"], remove_reminder);
-        wrap_command!(lock, "logs", logs);
-        wrap_command!(lock, "take", take);
-        wrap_command!(lock, "release", release);
-        wrap_command!(lock, "ping", ping);
-        wrap_command!(lock, ["snippet", "s"], snippet_command);
-        wrap_command!(lock, "status", status_command);
-        wrap_command!(lock, "category", category_command);
-        wrap_command!(lock, ["rename", "rn"], rename_ticket);
-        wrap_command!(lock, ["baninfo", "bi"], baninfo);
-
-        drop(lock);
+        register_text_commands(&mut *h.commands.lock().await);
         h
     }
 }
 
+fn register_text_commands(map: &mut HashMap<String, TextCommand>) {
+    wrap_command!(map, ["reply", "r"], reply);
+    wrap_command!(map, ["edit", "e"], edit);
+    wrap_command!(map, ["close", "c"], close);
+    wrap_command!(map, "recover", recover);
+    wrap_command!(map, "alert", alert);
+    wrap_command!(map, ["move", "mv"], move_thread);
+    wrap_command!(map, ["new_thread", "nt"], new_thread);
+    wrap_command!(map, "delete", delete);
+    wrap_command!(map, "reply" => ["anonreply", "ar"], anonreply);
+    wrap_command!(map, ["force_close", "fc"], force_close);
+    wrap_command!(map, ["addmod", "am"], add_staff);
+    wrap_command!(map, ["delmod", "dm"], remove_staff);
+    wrap_command!(map, "id", id);
+    wrap_command!(map, "help", help);
+    wrap_command!(map, ["remind", "rem"], add_reminder);
+    wrap_command!(map, ["unremind", "urem"], remove_reminder);
+    wrap_command!(map, "logs", logs);
+    wrap_command!(map, "take", take);
+    wrap_command!(map, "release", release);
+    wrap_command!(map, "ping", ping);
+    wrap_command!(map, ["snippet", "s"], snippet_command);
+    wrap_command!(map, "status", status_command);
+    wrap_command!(map, "category", category_command);
+    wrap_command!(map, ["rename", "rn"], rename_ticket);
+    wrap_command!(map, ["priority", "prio"], priority_command);
+    wrap_command!(map, ["hold"], hold);
+    wrap_command!(map, ["resolve"], resolve);
+    wrap_command!(map, ["baninfo", "bi"], baninfo);
+    wrap_command!(map, "gdpr", gdpr);
+}
+
 async fn manage_incoming_message(
     ctx: &Context,
     msg: &Message,
@@ -208,55 +215,13 @@ async fn manage_incoming_message(
     Ok(())
 }
 
-async fn is_user_maintenance_exempt(ctx: &Context, msg: &Message, config: &Config) -> bool {
-    let user_id = msg.author.id.get();
-
-    if config.bot.panel_super_admin_users.contains(&user_id) {
-        return true;
-    }
-
-    let guild_id = match msg.guild_id {
-        Some(id) => id,
-        None => return false,
-    };
-
-    let member = match guild_id.member(&ctx.http, msg.author.id).await {
-        Ok(m) => m,
-        Err(_) => return false,
-    };
-
-    if !config.bot.panel_super_admin_roles.is_empty() {
-        for role_id in &member.roles {
-            if config.bot.panel_super_admin_roles.contains(&role_id.get()) {
-                return true;
-            }
-        }
-    }
-
-    let guild = match guild_id.to_partial_guild(&ctx.http).await {
-        Ok(g) => g,
-        Err(_) => return false,
-    };
-
-    if guild.owner_id == msg.author.id {
-        return true;
-    }
-
-    member.roles.iter().any(|role_id| {
-        guild
-            .roles
-            .get(role_id)
-            .map(|role| role.permissions.contains(Permissions::ADMINISTRATOR))
-            .unwrap_or(false)
-    })
-}
-
 #[async_trait]
 impl EventHandler for GuildMessagesHandler {
     async fn message(&self, ctx: Context, msg: Message) {
+        let config = self.config.load();
         if msg.guild_id.is_none() {
             if self.maintenance_mode.load(Ordering::Relaxed) {
-                let _ = MessageBuilder::system_message(&ctx, &self.config)
+                let _ = MessageBuilder::system_message(&ctx, &config)
                     .translated_content(
                         "status.maintenance_mode_active_user",
                         None,
@@ -270,30 +235,33 @@ impl EventHandler for GuildMessagesHandler {
                 return;
             }
 
-            if let Err(error) = manage_incoming_message(&ctx, &msg, &self.config).await {
-                if let Some(error_handler) = &self.config.error_handler {
+            if let Err(error) = manage_incoming_message(&ctx, &msg, &config)
+                .instrument(info_span!("dm_message", user_id = %msg.author.id))
+                .await
+            {
+                if let Some(error_handler) = &config.error_handler {
                     let _ = error_handler
                         .reply_to_msg_with_error(&ctx, &msg, &error)
                         .await;
                 } else {
-                    eprintln!("DM handling error: {}", error);
+                    error!("DM handling error: {}", error);
                 }
             }
             return;
         }
 
         let message_content = &msg.content;
-        if message_content.starts_with(&self.config.command.prefix) {
+        if message_content.starts_with(&config.command.prefix) {
             let mut command_name = &message_content[1..];
 
             if let Some(i) = message_content.find(" ") {
-                command_name = &message_content[self.config.command.prefix.len()..i];
+                command_name = &message_content[config.command.prefix.len()..i];
             }
 
             if self.maintenance_mode.load(Ordering::Relaxed)
-                && !is_user_maintenance_exempt(&ctx, &msg, &self.config).await
+                && !is_message_author_admin(&ctx, &config, &msg).await
             {
-                let _ = MessageBuilder::system_message(&ctx, &self.config)
+                let _ = MessageBuilder::system_message(&ctx, &config)
                     .translated_content("status.maintenance_mode_active", None, None, None)
                     .await
                     .to_channel(msg.channel_id)
@@ -302,40 +270,95 @@ impl EventHandler for GuildMessagesHandler {
                 return;
             }
 
-            let commands_lock = self.commands.lock().await;
+            let text_command = self.commands.lock().await.get(command_name).cloned();
+            let is_command = text_command.is_some();
+            let identity = match msg.guild_id {
+                Some(guild_id) if is_command => {
+                    StaffIdentity::resolve(&ctx, guild_id, msg.author.id).await
+                }
+                _ => None,
+            };
 
-            if let Some(command_func) = commands_lock.get(command_name)
-                && let Err(error) = command_func(
+            if let Some(guild_id) = msg.guild_id
+                && let Some(text_command) = &text_command
+                && !can_run_command(
+                    &config,
+                    text_command.command,
+                    msg.author.id,
+                    identity.as_ref(),
+                )
+                .await
+            {
+                let _ = MessageBuilder::system_message(&ctx, &config)
+                    .translated_content(
+                        "command.insufficient_permissions",
+                        None,
+                        Some(msg.author.id),
+                        Some(guild_id.get()),
+                    )
+                    .await
+                    .to_channel(msg.channel_id)
+                    .send(true)
+                    .await;
+                return;
+            }
+
+            if msg.guild_id.is_some()
+                && is_command
+                && !can_access_ticket_channel(&config, msg.channel_id, identity.as_ref()).await
+            {
+                let _ = MessageBuilder::system_message(&ctx, &config)
+                    .translated_content(
+                        "category.access_denied",
+                        None,
+                        Some(msg.author.id),
+                        msg.guild_id.map(|g| g.get()),
+                    )
+                    .await
+                    .to_channel(msg.channel_id)
+                    .send(true)
+                    .await;
+                return;
+            }
+
+            if let Some(text_command) = text_command
+                && let Err(error) = (text_command.run)(
                     ctx.clone(),
                     msg.clone(),
-                    &self.config.clone(),
+                    &config.clone(),
                     Arc::new(self.clone()),
                 )
+                .instrument(info_span!(
+                    "command",
+                    command = command_name,
+                    user_id = %msg.author.id,
+                    channel_id = %msg.channel_id
+                ))
                 .await
             {
-                if let Some(error_handler) = &self.config.error_handler {
+                if let Some(error_handler) = &config.error_handler {
                     let _ = error_handler
                         .reply_to_msg_with_error(&ctx, &msg, &error)
                         .await;
                 } else {
-                    eprintln!("Command error: {}", error);
+                    error!("Command error: {}", error);
                 }
             }
             return;
         }
 
         if let Some(guild_id) = msg.guild_id {
-            let staff_guild_id = self.config.bot.get_staff_guild_id();
+            let staff_guild_id = config.bot.get_staff_guild_id();
             if guild_id.get() == staff_guild_id
                 && !msg.author.bot
-                && let Some(pool) = &self.config.db_pool
+                && let Some(pool) = &config.db_pool
             {
                 let channel_id_str = msg.channel_id.to_string();
                 if let Some(_thread) = get_thread_by_channel_id(&channel_id_str, pool).await
                     && let Err(e) =
-                        insert_internal_message(&ctx, &msg, &_thread.id, pool, &self.config).await
+                        insert_internal_message(&ctx, &msg, &_thread.id, pool, &config).await
                 {
-                    eprintln!("Failed to record internal message: {}", e);
+                    error!("Failed to record internal message: {}", e);
                 }
             }
         }
@@ -349,13 +372,14 @@ impl EventHandler for GuildMessagesHandler {
         deleted_message_id: MessageId,
         _guild_id: Option<GuildId>,
     ) {
+        let config = self.config.load();
         {
             let mut suppressed = SUPPRESSED_DELETES.lock().unwrap();
             if suppressed.remove(&deleted_message_id.get()) {
                 return;
             }
         }
-        let pool = match &self.config.db_pool {
+        let pool = match &config.db_pool {
             Some(p) => p,
             None => return,
         };
@@ -373,8 +397,8 @@ impl EventHandler for GuildMessagesHandler {
             None => return,
         };
 
-        if self.config.logs.show_log_on_delete {
-            let guild_id = self.config.bot.get_community_guild_id();
+        if config.logs.show_log_on_delete {
+            let guild_id = config.bot.get_community_guild_id();
             let mut params = HashMap::new();
 
             params.insert(
@@ -393,7 +417,7 @@ impl EventHandler for GuildMessagesHandler {
                 "delete.removed_by_user"
             };
 
-            let _ = MessageBuilder::system_message(&ctx, &self.config)
+            let _ = MessageBuilder::system_message(&ctx, &config)
                 .translated_content(
                     key,
                     Some(&params),
@@ -437,6 +461,7 @@ impl EventHandler for GuildMessagesHandler {
         _new: Option<Message>,
         event: MessageUpdateEvent,
     ) {
+        let config = self.config.load();
         match event.author {
             Some(user) => {
                 if user.bot {
@@ -444,7 +469,7 @@ impl EventHandler for GuildMessagesHandler {
                 }
             }
             None => {
-                eprintln!("Message update event without author");
+                error!("Message update event without author");
                 return;
             }
         };
@@ -456,7 +481,7 @@ impl EventHandler for GuildMessagesHandler {
             .ok()
             .and_then(|channel| channel.private())
         {
-            let pool = match &self.config.db_pool {
+            let pool = match &config.db_pool {
                 Some(p) => p,
                 None => return,
             };
@@ -464,7 +489,7 @@ impl EventHandler for GuildMessagesHandler {
             let message = match get_thread_message_by_dm_message_id(event.id, pool).await {
                 Ok(message) => message,
                 Err(e) => {
-                    eprintln!("Failed to get thread message by DM message ID: {}", e);
+                    error!("Failed to get thread message by DM message ID: {}", e);
                     return;
                 }
             };
@@ -475,7 +500,7 @@ impl EventHandler for GuildMessagesHandler {
             {
                 let inbox_builder = MessageBuilder::user_message(
                     &ctx,
-                    &self.config,
+                    &config,
                     UserId::new(message.user_id as u64),
                     message.user_name,
                 )
@@ -485,7 +510,7 @@ impl EventHandler for GuildMessagesHandler {
                 let channel_id_parse = match thread.channel_id.parse::<u64>() {
                     Ok(id) => ChannelId::new(id),
                     Err(e) => {
-                        eprintln!("Failed to parse channel ID: {}", e);
+                        error!("Failed to parse channel ID: {}", e);
                         return;
                     }
                 };
@@ -495,11 +520,11 @@ impl EventHandler for GuildMessagesHandler {
                         edit_inbox_message(&ctx, channel_id_parse, &inbox_message_id, edit_msg)
                             .await
                     {
-                        eprintln!("Failed to edit mirrored staff message: {}", e);
+                        error!("Failed to edit mirrored staff message: {}", e);
                         return;
                     }
 
-                    if self.config.logs.show_log_on_edit {
+                    if config.logs.show_log_on_edit {
                         let old_content: String = if let Some(old) = old_if_available {
                             old.content
                         } else {
@@ -512,7 +537,7 @@ impl EventHandler for GuildMessagesHandler {
                         };
                         let after = format!("`{}`", content.clone());
 
-                        let guild_id = self.config.bot.get_community_guild_id();
+                        let guild_id = config.bot.get_community_guild_id();
                         let message_link = format!(
                             "https://discord.com/channels/{}/{}/{}",
                             guild_id,
@@ -525,7 +550,7 @@ impl EventHandler for GuildMessagesHandler {
                         params.insert("after".to_string(), after);
                         params.insert("link".to_string(), message_link);
 
-                        let _ = MessageBuilder::system_message(&ctx, &self.config)
+                        let _ = MessageBuilder::system_message(&ctx, &config)
                             .translated_content(
                                 "edit.modification_from_user",
                                 Some(&params),
@@ -544,3 +569,30 @@ impl EventHandler for GuildMessagesHandler {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::register_commands;
+
+    #[test]
+    fn every_text_command_runs_a_registered_command() {
+        let (_shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+        let mut registry = CommandRegistry::new(shutdown_rx, PaginationStore::default());
+        register_commands(&mut registry);
+
+        let mut text_commands = HashMap::new();
+        register_text_commands(&mut text_commands);
+
+        for (name, text_command) in &text_commands {
+            assert!(
+                registry.get(text_command.command).is_some(),
+                "text command {name} runs unregistered command {}",
+                text_command.command
+            );
+        }
+        assert_eq!(text_commands["anonreply"].command, "reply");
+        assert_eq!(text_commands["nt"].command, "new_thread");
+        assert_eq!(text_commands["dm"].command, "delmod");
+    }
+}
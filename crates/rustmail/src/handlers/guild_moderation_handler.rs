@@ -10,13 +10,14 @@ use serenity::{
     all::{AuditLogEntry, ChannelId, Context, EventHandler, GuildId},
     async_trait,
 };
+use tracing::error;
 
 pub struct GuildModerationHandler {
-    pub config: Config,
+    pub config: ConfigHandle,
 }
 
 impl GuildModerationHandler {
-    pub fn new(config: &Config) -> Self {
+    pub fn new(config: &ConfigHandle) -> Self {
         Self {
             config: config.clone(),
         }
@@ -98,6 +99,7 @@ impl EventHandler for GuildModerationHandler {
         entry: AuditLogEntry,
         guild_id: GuildId,
     ) {
+        let config = self.config.load();
         if let Action::Channel(ChannelAction::Create) = entry.action
             && entry.user_id != ctx.cache.current_user().id
         {
@@ -105,15 +107,14 @@ impl EventHandler for GuildModerationHandler {
                 Some(id) => ChannelId::new(id.get()),
                 None => return,
             };
-            manage_creating_ticket_via_opening_thread(&ctx, &self.config, guild_id, channel_id)
-                .await;
+            manage_creating_ticket_via_opening_thread(&ctx, &config, guild_id, channel_id).await;
         }
 
-        if !self.config.bot.enable_discord_logs {
+        if !config.bot.enable_discord_logs {
             return;
         }
 
-        let logs_channel_id = match self.config.bot.logs_channel_id {
+        let logs_channel_id = match config.bot.logs_channel_id {
             Some(channel_id) => channel_id,
             None => return,
         };
@@ -121,11 +122,11 @@ impl EventHandler for GuildModerationHandler {
         let user = match entry.user_id.to_user(ctx.clone()).await {
             Ok(user) => user,
             Err(_) => {
-                eprintln!("Unable to get User from user_id for showing logs");
+                error!("Unable to get User from user_id for showing logs");
                 return;
             }
         };
 
-        send_audit_log(&ctx, &self.config, &entry, &user, guild_id, logs_channel_id).await;
+        send_audit_log(&ctx, &config, &entry, &user, guild_id, logs_channel_id).await;
     }
 }
@@ -1,34 +1,104 @@
-use crate::db::get_all_thread_status;
 use crate::handlers::guild_ban_handler::backfill_tracked_members;
 use crate::prelude::commands::*;
 use crate::prelude::config::*;
+use crate::prelude::db::*;
 use crate::prelude::features::*;
 use crate::prelude::modules::*;
 use crate::prelude::types::*;
-use serenity::all::{ActivityData, CreateCommand, GuildId};
+use serenity::all::{ActivityData, CreateCommand, GuildId, Permissions, RoleId};
 use serenity::{
     all::{Context, EventHandler, Ready},
     async_trait,
 };
-use sqlx::SqlitePool;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Duration;
 use tokio::sync::{Mutex, watch::Receiver};
-use tokio::time::interval;
+use tracing::{error, info};
+
+pub async fn sync_application_commands(ctx: &Context, config: &Config, registry: &CommandRegistry) {
+    let staff_guild_id = GuildId::new(config.bot.get_staff_guild_id());
+    let community_guild_id = GuildId::new(config.bot.get_community_guild_id());
+
+    let matrix = match &config.db_pool {
+        Some(pool) => list_command_permissions(pool).await.unwrap_or_default(),
+        None => HashMap::new(),
+    };
+    let role_permissions: HashMap<RoleId, Permissions> = if matrix.is_empty() {
+        HashMap::new()
+    } else {
+        match staff_guild_id.roles(&ctx.http).await {
+            Ok(roles) => roles
+                .into_iter()
+                .map(|(id, role)| (id, role.permissions))
+                .collect(),
+            Err(e) => {
+                error!(
+                    "Failed to fetch staff roles for command permissions: {:?}",
+                    e
+                );
+                HashMap::new()
+            }
+        }
+    };
+
+    let mut guild_commands: Vec<CreateCommand> = Vec::new();
+    let mut community_commands: Vec<CreateCommand> = Vec::new();
+
+    for command in registry.all() {
+        let mut cmds = command.register(config).await;
+        if let Some(permissions) = matrix
+            .get(command.name())
+            .and_then(|access| slash_default_permissions(access, &role_permissions))
+        {
+            cmds = cmds
+                .into_iter()
+                .map(|c| c.default_member_permissions(permissions))
+                .collect();
+        }
+        guild_commands.append(&mut cmds);
+
+        if let Some(commu) = command.as_community() {
+            let mut commu_cmds = commu.register_community(config).await;
+            community_commands.append(&mut commu_cmds);
+        }
+    }
+
+    if staff_guild_id == community_guild_id {
+        if let Err(e) = staff_guild_id.set_commands(&ctx.http, guild_commands).await {
+            error!("set_commands() failed: {:?}", e);
+        }
+        return;
+    }
+
+    if let Err(e) = staff_guild_id
+        .set_commands(&ctx.http, guild_commands.clone())
+        .await
+    {
+        error!("set_commands() failed: {:?}", e);
+    }
+
+    if let Err(e) = community_guild_id
+        .set_commands(&ctx.http, community_commands)
+        .await
+    {
+        error!("set_commands() failed: {:?}", e);
+    }
+}
 
 #[derive(Clone)]
 pub struct ReadyHandler {
-    pub config: Config,
+    pub config: ConfigHandle,
     pub registry: Arc<CommandRegistry>,
     pub shutdown: Arc<Receiver<bool>>,
     pub bot_state: Arc<Mutex<BotState>>,
     backfill_started: Arc<AtomicBool>,
+    scheduler_started: Arc<AtomicBool>,
 }
 
 impl ReadyHandler {
     pub fn new(
-        config: &Config,
+        config: &ConfigHandle,
         registry: Arc<CommandRegistry>,
         shutdown: Receiver<bool>,
         bot_state: Arc<Mutex<BotState>>,
@@ -39,6 +109,7 @@ impl ReadyHandler {
             shutdown: Arc::new(shutdown),
             bot_state,
             backfill_started: Arc::new(AtomicBool::new(false)),
+            scheduler_started: Arc::new(AtomicBool::new(false)),
         }
     }
 }
@@ -46,7 +117,8 @@ impl ReadyHandler {
 #[async_trait]
 impl EventHandler for ReadyHandler {
     async fn ready(&self, ctx: Context, ready: Ready) {
-        println!("{} is online !", ready.user.name);
+        let config = self.config.load();
+        info!("{} is online !", ready.user.name);
 
         {
             let state = self.bot_state.lock().await;
@@ -54,17 +126,12 @@ impl EventHandler for ReadyHandler {
             *ctx_lock = Some(ctx.clone());
         }
 
-        let pool = match &self.config.db_pool {
-            Some(pool) => pool,
-            None => {
-                eprintln!("Database pool is not set in config.");
-                return;
-            }
-        };
-
-        let config = self.config.clone();
+        if config.db_pool.is_none() {
+            error!("Database pool is not set in config.");
+            return;
+        }
 
-        ctx.set_activity(Option::from(ActivityData::playing(&self.config.bot.status)));
+        ctx.set_activity(Option::from(ActivityData::playing(&config.bot.status)));
 
         tokio::spawn({
             let ctx = ctx.clone();
@@ -74,11 +141,21 @@ impl EventHandler for ReadyHandler {
                 let recovery_results = recover_missing_messages(&ctx, &config).await;
                 send_recovery_summary(&ctx, &config, &recovery_results).await;
                 sync_features(&ctx, &config).await;
-                hydrate_scheduled_closures(&ctx, &config).await;
-                hydrate_pending_category_selections(&ctx, &config).await;
             }
         });
 
+        if self
+            .scheduler_started
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+        {
+            tokio::spawn(run_job_scheduler(
+                ctx.clone(),
+                self.config.clone(),
+                (*self.shutdown).clone(),
+            ));
+        }
+
         if self
             .backfill_started
             .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
@@ -95,80 +172,6 @@ impl EventHandler for ReadyHandler {
             });
         }
 
-        load_reminders(&ctx, &self.config, &pool.clone(), self.shutdown.clone()).await;
-
-        update_threads_status(&ctx, &pool.clone());
-
-        let staff_guild_id = GuildId::new(self.config.bot.get_staff_guild_id());
-        let community_guild_id = GuildId::new(self.config.bot.get_community_guild_id());
-
-        let mut guild_commands: Vec<CreateCommand> = Vec::new();
-        let mut community_commands: Vec<CreateCommand> = Vec::new();
-
-        for command in self.registry.all() {
-            let mut cmds = command.register(&self.config).await;
-            guild_commands.append(&mut cmds);
-
-            if let Some(commu) = command.as_community() {
-                let mut commu_cmds = commu.register_community(&self.config).await;
-                community_commands.append(&mut commu_cmds);
-            }
-        }
-
-        if staff_guild_id == community_guild_id {
-            if let Err(e) = staff_guild_id.set_commands(&ctx.http, guild_commands).await {
-                eprintln!("set_commands() failed: {:?}", e);
-            }
-            return;
-        }
-
-        if let Err(e) = staff_guild_id
-            .set_commands(&ctx.http, guild_commands.clone())
-            .await
-        {
-            eprintln!("set_commands() failed: {:?}", e);
-        }
-
-        if let Err(e) = community_guild_id
-            .set_commands(&ctx.http, community_commands)
-            .await
-        {
-            eprintln!("set_commands() failed: {:?}", e);
-        }
+        sync_application_commands(&ctx, &config, &self.registry).await;
     }
 }
-
-fn update_threads_status(ctx: &Context, pool: &SqlitePool) {
-    tokio::spawn({
-        let ctx = ctx.clone();
-        let pool = pool.clone();
-
-        async move {
-            let mut interval = interval(Duration::from_secs(60 * 10));
-
-            interval.tick().await;
-
-            loop {
-                let tickets_status = get_all_thread_status(&pool).await;
-
-                for ticket in tickets_status.iter() {
-                    let ctx = ctx.clone();
-                    let ticket = ticket.clone();
-
-                    if let Err(e) = update_thread_status_ui(&ctx, &ticket).await {
-                        eprintln!(
-                            "Failed to update thread status for channel {}: {:?}",
-                            ticket.channel_id, e
-                        );
-                    }
-
-                    tokio::time::sleep(Duration::from_millis(500)).await;
-                }
-
-                println!("Updated {} ticket statuses", tickets_status.len());
-
-                interval.tick().await;
-            }
-        }
-    });
-}
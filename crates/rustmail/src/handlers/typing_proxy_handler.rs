@@ -6,14 +6,15 @@ use sqlx::SqlitePool;
 use std::time::Duration;
 use tokio::spawn;
 use tokio::time::sleep;
+use tracing::error;
 
 #[derive(Clone)]
 pub struct TypingProxyHandler {
-    pub config: Config,
+    pub config: ConfigHandle,
 }
 
 impl TypingProxyHandler {
-    pub fn new(config: &Config) -> Self {
+    pub fn new(config: &ConfigHandle) -> Self {
         Self {
             config: config.clone(),
         }
@@ -51,20 +52,21 @@ async fn handle_staff_typing(ctx: &Context, event: &TypingStartEvent, pool: &Sql
 #[async_trait]
 impl EventHandler for TypingProxyHandler {
     async fn typing_start(&self, ctx: Context, event: TypingStartEvent) {
+        let config = self.config.load();
         if event.user_id == ctx.cache.current_user().id {
             return;
         }
-        let pool = match &self.config.db_pool {
+        let pool = match &config.db_pool {
             Some(pool) => pool,
             None => {
-                eprintln!("Database pool is not set in config.");
+                error!("Database pool is not set in config.");
                 return;
             }
         };
-        if self.config.bot.typing_proxy_from_user && event.guild_id.is_none() {
+        if config.bot.typing_proxy_from_user && event.guild_id.is_none() {
             handle_user_typing(&ctx, &event, pool).await;
         }
-        if self.config.bot.typing_proxy_from_staff && event.guild_id.is_some() {
+        if config.bot.typing_proxy_from_staff && event.guild_id.is_some() {
             handle_staff_typing(&ctx, &event, pool).await;
         }
     }
@@ -2230,4 +2230,400 @@ pub fn load_english_messages(dict: &mut ErrorDictionary) {
         "category.roles_cleared".to_string(),
         DictionaryMessage::new("Cleared {count} role link(s) from category **{name}**."),
     );
+    dict.messages.insert(
+        "poll.feature_description".to_string(),
+        DictionaryMessage::new("**Staff polls**\n\nUse the button below to create a poll in this channel. Each staff member has one vote, which they can change (or withdraw by clicking the same option again) until the poll closes."),
+    );
+    dict.messages.insert(
+        "poll.create_button".to_string(),
+        DictionaryMessage::new("Create a poll"),
+    );
+    dict.messages.insert(
+        "poll.modal_title".to_string(),
+        DictionaryMessage::new("Create a poll"),
+    );
+    dict.messages.insert(
+        "poll.modal_question".to_string(),
+        DictionaryMessage::new("Question"),
+    );
+    dict.messages.insert(
+        "poll.modal_options".to_string(),
+        DictionaryMessage::new("Options (separated by | or new lines)"),
+    );
+    dict.messages.insert(
+        "poll.modal_duration".to_string(),
+        DictionaryMessage::new("Duration, e.g. 30m, 2h, 1d (optional)"),
+    );
+    dict.messages.insert(
+        "poll.invalid_options".to_string(),
+        DictionaryMessage::new(
+            "A poll needs a question and between {min} and {max} distinct options.",
+        ),
+    );
+    dict.messages.insert(
+        "poll.invalid_duration".to_string(),
+        DictionaryMessage::new("Invalid duration `{duration}`. Use a format like 30m, 2h or 1d."),
+    );
+    dict.messages.insert(
+        "poll.created".to_string(),
+        DictionaryMessage::new("Poll created."),
+    );
+    dict.messages.insert(
+        "poll.not_found".to_string(),
+        DictionaryMessage::new("This poll no longer exists."),
+    );
+    dict.messages.insert(
+        "poll.already_closed".to_string(),
+        DictionaryMessage::new("This poll is already closed."),
+    );
+    dict.messages.insert(
+        "poll.staff_only".to_string(),
+        DictionaryMessage::new("Only staff members can vote on this poll."),
+    );
+    dict.messages.insert(
+        "poll.close_button".to_string(),
+        DictionaryMessage::new("Close poll"),
+    );
+    dict.messages.insert(
+        "poll.close_forbidden".to_string(),
+        DictionaryMessage::new("Only the poll author or members with the Manage Messages permission can close this poll."),
+    );
+    dict.messages.insert(
+        "poll.closed".to_string(),
+        DictionaryMessage::new("Poll closed."),
+    );
+    dict.messages.insert(
+        "poll.total_votes".to_string(),
+        DictionaryMessage::new("Total votes: {count}"),
+    );
+    dict.messages.insert(
+        "poll.closes".to_string(),
+        DictionaryMessage::new("Closes {time}"),
+    );
+    dict.messages.insert(
+        "poll.closed_on".to_string(),
+        DictionaryMessage::new("Closed {time}"),
+    );
+    dict.messages.insert(
+        "poll.footer".to_string(),
+        DictionaryMessage::new("Poll #{id}"),
+    );
+    dict.messages.insert(
+        "poll.results_title".to_string(),
+        DictionaryMessage::new("Results: {question}"),
+    );
+    dict.messages.insert(
+        "poll.winner".to_string(),
+        DictionaryMessage::new("Winner: **{option}**"),
+    );
+    dict.messages.insert(
+        "poll.tie".to_string(),
+        DictionaryMessage::new("Tie between: **{options}**"),
+    );
+    dict.messages.insert(
+        "poll.no_votes".to_string(),
+        DictionaryMessage::new("No votes were cast."),
+    );
+    dict.messages.insert(
+        "feature.disabled".to_string(),
+        DictionaryMessage::new("This feature is currently disabled."),
+    );
+    dict.messages.insert(
+        "help.poll".to_string(),
+        DictionaryMessage::new("Open the form to create a staff poll in the current channel."),
+    );
+    dict.messages.insert(
+        "slash_command.poll_command_description".to_string(),
+        DictionaryMessage::new("Create a staff poll in this channel"),
+    );
+    dict.messages.insert(
+        "category.select_placeholder".to_string(),
+        DictionaryMessage::new("Choose a category"),
+    );
+    dict.messages.insert(
+        "category.subcategory_placeholder".to_string(),
+        DictionaryMessage::new("Choose a topic within {name}"),
+    );
+    dict.messages.insert(
+        "category.general_option_label".to_string(),
+        DictionaryMessage::new("{name} (general)"),
+    );
+    dict.messages.insert(
+        "category.form_summary_title".to_string(),
+        DictionaryMessage::new("Intake form: {category}"),
+    );
+    dict.messages.insert(
+        "category.form_no_answer".to_string(),
+        DictionaryMessage::new("*No answer*"),
+    );
+    dict.messages.insert(
+        "category.access_denied".to_string(),
+        DictionaryMessage::new("You don't have access to tickets of this category."),
+    );
+    dict.messages.insert(
+        "help.priority".to_string(),
+        DictionaryMessage::new("Set the priority of the current ticket: `low`, `normal`, `high` or `urgent`. Higher priorities are marked in the channel name and listed first in the category. Use `!priority <level>` or `!prio <level>`, or `!priority` alone to see the current one."),
+    );
+    dict.messages.insert(
+        "priority.current".to_string(),
+        DictionaryMessage::new("This ticket's priority is **{priority}**."),
+    );
+    dict.messages.insert(
+        "priority.confirmation".to_string(),
+        DictionaryMessage::new("The ticket priority has been set to **{priority}**.\nDue to **Discord's API**, the channel name change may take up to **10 minutes**."),
+    );
+    dict.messages.insert(
+        "priority.confirmation_rate_limited".to_string(),
+        DictionaryMessage::new("The ticket priority has been set to **{priority}**.\n⚠️ **Discord's API** enforces a limit of **2** channel name updates every **10 minutes**. The change will be **__automatically__** applied once the cooldown expires."),
+    );
+    dict.messages.insert(
+        "priority.invalid".to_string(),
+        DictionaryMessage::new("Invalid priority. Use `low`, `normal`, `high` or `urgent`."),
+    );
+    dict.messages
+        .insert("priority.low".to_string(), DictionaryMessage::new("low"));
+    dict.messages.insert(
+        "priority.normal".to_string(),
+        DictionaryMessage::new("normal"),
+    );
+    dict.messages
+        .insert("priority.high".to_string(), DictionaryMessage::new("high"));
+    dict.messages.insert(
+        "priority.urgent".to_string(),
+        DictionaryMessage::new("urgent"),
+    );
+    dict.messages.insert(
+        "slash_command.priority_command_description".to_string(),
+        DictionaryMessage::new("Set the priority of the current ticket."),
+    );
+    dict.messages.insert(
+        "slash_command.priority_level_option".to_string(),
+        DictionaryMessage::new("The priority to set."),
+    );
+    dict.messages.insert(
+        "help.hold".to_string(),
+        DictionaryMessage::new("Put the current ticket on hold: `!hold 2d` holds it for two days, `!hold` until further notice and `!hold cancel` lifts the hold. A message from the user lifts it too."),
+    );
+    dict.messages.insert(
+        "hold.confirmation".to_string(),
+        DictionaryMessage::new("This ticket is on hold for {time}."),
+    );
+    dict.messages.insert(
+        "hold.confirmation_indefinite".to_string(),
+        DictionaryMessage::new("This ticket is on hold until further notice."),
+    );
+    dict.messages.insert(
+        "hold.canceled".to_string(),
+        DictionaryMessage::new("The hold has been lifted. The ticket is now **{state}**."),
+    );
+    dict.messages.insert(
+        "hold.expired".to_string(),
+        DictionaryMessage::new("The hold has expired. The ticket is now **{state}**."),
+    );
+    dict.messages.insert(
+        "hold.not_on_hold".to_string(),
+        DictionaryMessage::new("This ticket is not on hold."),
+    );
+    dict.messages.insert(
+        "help.resolve".to_string(),
+        DictionaryMessage::new("Mark the current ticket as resolved. It closes after the configured delay unless the user replies. Use `!resolve 2h` for another delay and `!resolve cancel` to reopen it."),
+    );
+    dict.messages.insert(
+        "resolve.confirmation".to_string(),
+        DictionaryMessage::new("This ticket is marked as resolved and will be closed in {time} unless the user replies."),
+    );
+    dict.messages.insert(
+        "resolve.confirmation_no_close".to_string(),
+        DictionaryMessage::new("This ticket is marked as resolved."),
+    );
+    dict.messages.insert(
+        "resolve.canceled".to_string(),
+        DictionaryMessage::new("The ticket is no longer resolved. It is now **{state}**."),
+    );
+    dict.messages.insert(
+        "resolve.not_resolved".to_string(),
+        DictionaryMessage::new("This ticket is not marked as resolved."),
+    );
+    dict.messages.insert(
+        "workflow.state.open".to_string(),
+        DictionaryMessage::new("open"),
+    );
+    dict.messages.insert(
+        "workflow.state.awaiting_user".to_string(),
+        DictionaryMessage::new("awaiting user"),
+    );
+    dict.messages.insert(
+        "workflow.state.awaiting_staff".to_string(),
+        DictionaryMessage::new("awaiting staff"),
+    );
+    dict.messages.insert(
+        "workflow.state.on_hold".to_string(),
+        DictionaryMessage::new("on hold"),
+    );
+    dict.messages.insert(
+        "workflow.state.resolved".to_string(),
+        DictionaryMessage::new("resolved"),
+    );
+    dict.messages.insert(
+        "workflow.state.closed".to_string(),
+        DictionaryMessage::new("closed"),
+    );
+    dict.messages.insert(
+        "slash_command.hold_command_description".to_string(),
+        DictionaryMessage::new("Put the current ticket on hold."),
+    );
+    dict.messages.insert(
+        "slash_command.hold_duration_option".to_string(),
+        DictionaryMessage::new(
+            "How long to hold the ticket (e.g. 2d, 12h). Leave empty for no limit.",
+        ),
+    );
+    dict.messages.insert(
+        "slash_command.hold_cancel_option".to_string(),
+        DictionaryMessage::new("Lift the current hold."),
+    );
+    dict.messages.insert(
+        "slash_command.resolve_command_description".to_string(),
+        DictionaryMessage::new("Mark the current ticket as resolved."),
+    );
+    dict.messages.insert(
+        "slash_command.resolve_delay_option".to_string(),
+        DictionaryMessage::new(
+            "Delay before the ticket closes (e.g. 2h). Defaults to the configured delay.",
+        ),
+    );
+    dict.messages.insert(
+        "slash_command.resolve_cancel_option".to_string(),
+        DictionaryMessage::new("Reopen a resolved ticket."),
+    );
+    dict.messages.insert(
+        "board.title".to_string(),
+        DictionaryMessage::new("Open tickets ({count})"),
+    );
+    dict.messages.insert(
+        "board.empty".to_string(),
+        DictionaryMessage::new("No open tickets."),
+    );
+    dict.messages.insert(
+        "board.page".to_string(),
+        DictionaryMessage::new("Page {page}/{pages}"),
+    );
+    dict.messages.insert(
+        "board.ticket".to_string(),
+        DictionaryMessage::new("{channel} · {category}\n{priority} · {state}\n{taken}\nLast message: {author}, {wait} ago"),
+    );
+    dict.messages.insert(
+        "board.taken_by".to_string(),
+        DictionaryMessage::new("Taken by {staff}"),
+    );
+    dict.messages.insert(
+        "board.not_taken".to_string(),
+        DictionaryMessage::new("Not taken"),
+    );
+    dict.messages.insert(
+        "board.author_staff".to_string(),
+        DictionaryMessage::new("staff"),
+    );
+    dict.messages.insert(
+        "board.author_user".to_string(),
+        DictionaryMessage::new("user"),
+    );
+    dict.messages.insert(
+        "board.overdue".to_string(),
+        DictionaryMessage::new("⚠️ Waiting for staff for {wait}"),
+    );
+    dict.messages.insert(
+        "board.take_button".to_string(),
+        DictionaryMessage::new("Take #{number} ({user})"),
+    );
+    dict.messages.insert(
+        "board.taken".to_string(),
+        DictionaryMessage::new("You took the ticket {channel}."),
+    );
+    dict.messages.insert(
+        "board.ticket_gone".to_string(),
+        DictionaryMessage::new("This ticket is no longer open."),
+    );
+    dict.messages.insert(
+        "board.not_allowed".to_string(),
+        DictionaryMessage::new("You are not allowed to take this ticket."),
+    );
+    dict.messages.insert(
+        "help.gdpr".to_string(),
+        DictionaryMessage::new("Export or erase everything stored about a user. Use `!gdpr export <user>` for a JSON bundle, or `!gdpr erase <user> [anonymize|delete]`: anonymize keeps the tickets without personal data, delete removes them. Erasures ask for confirmation, refuse users with an open ticket and are recorded. Bot admins only."),
+    );
+    dict.messages.insert(
+        "slash_command.gdpr_command_description".to_string(),
+        DictionaryMessage::new("Export or erase the data stored about a user"),
+    );
+    dict.messages.insert(
+        "slash_command.gdpr_action_option".to_string(),
+        DictionaryMessage::new("Export the data or erase it"),
+    );
+    dict.messages.insert(
+        "slash_command.gdpr_user_option".to_string(),
+        DictionaryMessage::new("The user the data is about"),
+    );
+    dict.messages.insert(
+        "slash_command.gdpr_mode_option".to_string(),
+        DictionaryMessage::new("Erasure mode: anonymize (default) or delete"),
+    );
+    dict.messages.insert(
+        "gdpr.export_ready".to_string(),
+        DictionaryMessage::new("Everything stored about `{user_id}` is attached."),
+    );
+    dict.messages.insert(
+        "gdpr.no_data".to_string(),
+        DictionaryMessage::new("Nothing is stored about `{user_id}`."),
+    );
+    dict.messages.insert(
+        "gdpr.open_ticket".to_string(),
+        DictionaryMessage::new(
+            "`{user_id}` has an open ticket. Close it before erasing their data.",
+        ),
+    );
+    dict.messages.insert(
+        "gdpr.erase_prompt".to_string(),
+        DictionaryMessage::new("This will **{mode}** everything stored about `{user_id}`:\n{counts}\nThis cannot be undone. Confirm within 10 minutes."),
+    );
+    dict.messages.insert(
+        "gdpr.mode_anonymize".to_string(),
+        DictionaryMessage::new("anonymize"),
+    );
+    dict.messages.insert(
+        "gdpr.mode_delete".to_string(),
+        DictionaryMessage::new("delete"),
+    );
+    dict.messages.insert(
+        "gdpr.confirm_button".to_string(),
+        DictionaryMessage::new("Erase"),
+    );
+    dict.messages.insert(
+        "gdpr.cancel_button".to_string(),
+        DictionaryMessage::new("Cancel"),
+    );
+    dict.messages.insert(
+        "gdpr.not_requester".to_string(),
+        DictionaryMessage::new("Only the staff member who asked for this erasure can answer it."),
+    );
+    dict.messages.insert(
+        "gdpr.not_allowed".to_string(),
+        DictionaryMessage::new("Only bot admins can erase user data."),
+    );
+    dict.messages.insert(
+        "gdpr.expired".to_string(),
+        DictionaryMessage::new("This confirmation has expired. Run the command again."),
+    );
+    dict.messages.insert(
+        "gdpr.cancelled".to_string(),
+        DictionaryMessage::new("Erasure cancelled."),
+    );
+    dict.messages.insert(
+        "gdpr.erased".to_string(),
+        DictionaryMessage::new("The data of `{user_id}` was erased ({mode}):\n{counts}"),
+    );
+    dict.messages.insert(
+        "gdpr.erase_failed".to_string(),
+        DictionaryMessage::new("Erasure failed, nothing was changed: {error}"),
+    );
 }
@@ -2245,4 +2245,406 @@ pub fn load_french_messages(dict: &mut ErrorDictionary) {
             "{count} association(s) de rôle supprimée(s) de la catégorie **{name}**.",
         ),
     );
+    dict.messages.insert(
+        "poll.feature_description".to_string(),
+        DictionaryMessage::new("**Sondages du staff**\n\nUtilisez le bouton ci-dessous pour créer un sondage dans ce salon. Chaque membre du staff dispose d'un vote, qu'il peut modifier (ou retirer en cliquant à nouveau sur la même option) jusqu'à la clôture du sondage."),
+    );
+    dict.messages.insert(
+        "poll.create_button".to_string(),
+        DictionaryMessage::new("Créer un sondage"),
+    );
+    dict.messages.insert(
+        "poll.modal_title".to_string(),
+        DictionaryMessage::new("Créer un sondage"),
+    );
+    dict.messages.insert(
+        "poll.modal_question".to_string(),
+        DictionaryMessage::new("Question"),
+    );
+    dict.messages.insert(
+        "poll.modal_options".to_string(),
+        DictionaryMessage::new("Options (séparées par | ou retour à la ligne)"),
+    );
+    dict.messages.insert(
+        "poll.modal_duration".to_string(),
+        DictionaryMessage::new("Durée, ex. 30m, 2h, 1d (facultatif)"),
+    );
+    dict.messages.insert(
+        "poll.invalid_options".to_string(),
+        DictionaryMessage::new(
+            "Un sondage nécessite une question et entre {min} et {max} options distinctes.",
+        ),
+    );
+    dict.messages.insert(
+        "poll.invalid_duration".to_string(),
+        DictionaryMessage::new(
+            "Durée invalide `{duration}`. Utilisez un format comme 30m, 2h ou 1d.",
+        ),
+    );
+    dict.messages.insert(
+        "poll.created".to_string(),
+        DictionaryMessage::new("Sondage créé."),
+    );
+    dict.messages.insert(
+        "poll.not_found".to_string(),
+        DictionaryMessage::new("Ce sondage n'existe plus."),
+    );
+    dict.messages.insert(
+        "poll.already_closed".to_string(),
+        DictionaryMessage::new("Ce sondage est déjà clôturé."),
+    );
+    dict.messages.insert(
+        "poll.staff_only".to_string(),
+        DictionaryMessage::new("Seuls les membres du staff peuvent voter à ce sondage."),
+    );
+    dict.messages.insert(
+        "poll.close_button".to_string(),
+        DictionaryMessage::new("Clôturer le sondage"),
+    );
+    dict.messages.insert(
+        "poll.close_forbidden".to_string(),
+        DictionaryMessage::new("Seul l'auteur du sondage ou les membres ayant la permission Gérer les messages peuvent le clôturer."),
+    );
+    dict.messages.insert(
+        "poll.closed".to_string(),
+        DictionaryMessage::new("Sondage clôturé."),
+    );
+    dict.messages.insert(
+        "poll.total_votes".to_string(),
+        DictionaryMessage::new("Total des votes : {count}"),
+    );
+    dict.messages.insert(
+        "poll.closes".to_string(),
+        DictionaryMessage::new("Clôture {time}"),
+    );
+    dict.messages.insert(
+        "poll.closed_on".to_string(),
+        DictionaryMessage::new("Clôturé {time}"),
+    );
+    dict.messages.insert(
+        "poll.footer".to_string(),
+        DictionaryMessage::new("Sondage n°{id}"),
+    );
+    dict.messages.insert(
+        "poll.results_title".to_string(),
+        DictionaryMessage::new("Résultats : {question}"),
+    );
+    dict.messages.insert(
+        "poll.winner".to_string(),
+        DictionaryMessage::new("Gagnant : **{option}**"),
+    );
+    dict.messages.insert(
+        "poll.tie".to_string(),
+        DictionaryMessage::new("Égalité entre : **{options}**"),
+    );
+    dict.messages.insert(
+        "poll.no_votes".to_string(),
+        DictionaryMessage::new("Aucun vote n'a été exprimé."),
+    );
+    dict.messages.insert(
+        "feature.disabled".to_string(),
+        DictionaryMessage::new("Cette fonctionnalité est actuellement désactivée."),
+    );
+    dict.messages.insert(
+        "help.poll".to_string(),
+        DictionaryMessage::new(
+            "Ouvre le formulaire de création d'un sondage staff dans le salon actuel.",
+        ),
+    );
+    dict.messages.insert(
+        "slash_command.poll_command_description".to_string(),
+        DictionaryMessage::new("Créer un sondage staff dans ce salon"),
+    );
+    dict.messages.insert(
+        "category.select_placeholder".to_string(),
+        DictionaryMessage::new("Choisissez une catégorie"),
+    );
+    dict.messages.insert(
+        "category.subcategory_placeholder".to_string(),
+        DictionaryMessage::new("Choisissez un sujet dans {name}"),
+    );
+    dict.messages.insert(
+        "category.general_option_label".to_string(),
+        DictionaryMessage::new("{name} (général)"),
+    );
+    dict.messages.insert(
+        "category.form_summary_title".to_string(),
+        DictionaryMessage::new("Formulaire d'accueil : {category}"),
+    );
+    dict.messages.insert(
+        "category.form_no_answer".to_string(),
+        DictionaryMessage::new("*Pas de réponse*"),
+    );
+    dict.messages.insert(
+        "category.access_denied".to_string(),
+        DictionaryMessage::new("Vous n'avez pas accès aux tickets de cette catégorie."),
+    );
+    dict.messages.insert(
+        "help.priority".to_string(),
+        DictionaryMessage::new("Définit la priorité du ticket actuel : `low`, `normal`, `high` ou `urgent`. Les priorités élevées sont signalées dans le nom du salon et placées en tête de la catégorie. Utilisez `!priority <niveau>` ou `!prio <niveau>`, ou `!priority` seul pour voir la priorité actuelle."),
+    );
+    dict.messages.insert(
+        "priority.current".to_string(),
+        DictionaryMessage::new("La priorité de ce ticket est **{priority}**."),
+    );
+    dict.messages.insert(
+        "priority.confirmation".to_string(),
+        DictionaryMessage::new("La priorité du ticket a été définie sur **{priority}**.\nEn raison de **l'API Discord**, le changement de nom du salon peut prendre jusqu'à **10 minutes**."),
+    );
+    dict.messages.insert(
+        "priority.confirmation_rate_limited".to_string(),
+        DictionaryMessage::new("La priorité du ticket a été définie sur **{priority}**.\n⚠️ **L'API Discord** limite les changements de nom de salon à **2** toutes les **10 minutes**. Le changement sera appliqué **__automatiquement__** à la fin du délai."),
+    );
+    dict.messages.insert(
+        "priority.invalid".to_string(),
+        DictionaryMessage::new("Priorité invalide. Utilisez `low`, `normal`, `high` ou `urgent`."),
+    );
+    dict.messages
+        .insert("priority.low".to_string(), DictionaryMessage::new("basse"));
+    dict.messages.insert(
+        "priority.normal".to_string(),
+        DictionaryMessage::new("normale"),
+    );
+    dict.messages
+        .insert("priority.high".to_string(), DictionaryMessage::new("haute"));
+    dict.messages.insert(
+        "priority.urgent".to_string(),
+        DictionaryMessage::new("urgente"),
+    );
+    dict.messages.insert(
+        "slash_command.priority_command_description".to_string(),
+        DictionaryMessage::new("Définit la priorité du ticket actuel."),
+    );
+    dict.messages.insert(
+        "slash_command.priority_level_option".to_string(),
+        DictionaryMessage::new("La priorité à appliquer."),
+    );
+    dict.messages.insert(
+        "help.hold".to_string(),
+        DictionaryMessage::new("Met le ticket en attente : `!hold 2d` pour deux jours, `!hold` jusqu'à nouvel ordre et `!hold cancel` pour lever l'attente. Un message de l'utilisateur la lève également."),
+    );
+    dict.messages.insert(
+        "hold.confirmation".to_string(),
+        DictionaryMessage::new("Ce ticket est en attente pour {time}."),
+    );
+    dict.messages.insert(
+        "hold.confirmation_indefinite".to_string(),
+        DictionaryMessage::new("Ce ticket est en attente jusqu'à nouvel ordre."),
+    );
+    dict.messages.insert(
+        "hold.canceled".to_string(),
+        DictionaryMessage::new("L'attente a été levée. Le ticket est maintenant **{state}**."),
+    );
+    dict.messages.insert(
+        "hold.expired".to_string(),
+        DictionaryMessage::new("L'attente a expiré. Le ticket est maintenant **{state}**."),
+    );
+    dict.messages.insert(
+        "hold.not_on_hold".to_string(),
+        DictionaryMessage::new("Ce ticket n'est pas en attente."),
+    );
+    dict.messages.insert(
+        "help.resolve".to_string(),
+        DictionaryMessage::new("Marque le ticket comme résolu. Il sera fermé après le délai configuré sauf si l'utilisateur répond. Utilisez `!resolve 2h` pour un autre délai et `!resolve cancel` pour le rouvrir."),
+    );
+    dict.messages.insert(
+        "resolve.confirmation".to_string(),
+        DictionaryMessage::new("Ce ticket est marqué comme résolu et sera fermé dans {time} sauf si l'utilisateur répond."),
+    );
+    dict.messages.insert(
+        "resolve.confirmation_no_close".to_string(),
+        DictionaryMessage::new("Ce ticket est marqué comme résolu."),
+    );
+    dict.messages.insert(
+        "resolve.canceled".to_string(),
+        DictionaryMessage::new("Le ticket n'est plus résolu. Il est maintenant **{state}**."),
+    );
+    dict.messages.insert(
+        "resolve.not_resolved".to_string(),
+        DictionaryMessage::new("Ce ticket n'est pas marqué comme résolu."),
+    );
+    dict.messages.insert(
+        "workflow.state.open".to_string(),
+        DictionaryMessage::new("ouvert"),
+    );
+    dict.messages.insert(
+        "workflow.state.awaiting_user".to_string(),
+        DictionaryMessage::new("en attente de l'utilisateur"),
+    );
+    dict.messages.insert(
+        "workflow.state.awaiting_staff".to_string(),
+        DictionaryMessage::new("en attente du staff"),
+    );
+    dict.messages.insert(
+        "workflow.state.on_hold".to_string(),
+        DictionaryMessage::new("en pause"),
+    );
+    dict.messages.insert(
+        "workflow.state.resolved".to_string(),
+        DictionaryMessage::new("résolu"),
+    );
+    dict.messages.insert(
+        "workflow.state.closed".to_string(),
+        DictionaryMessage::new("fermé"),
+    );
+    dict.messages.insert(
+        "slash_command.hold_command_description".to_string(),
+        DictionaryMessage::new("Met le ticket actuel en attente."),
+    );
+    dict.messages.insert(
+        "slash_command.hold_duration_option".to_string(),
+        DictionaryMessage::new(
+            "Durée de l'attente (ex. 2d, 12h). Laisser vide pour aucune limite.",
+        ),
+    );
+    dict.messages.insert(
+        "slash_command.hold_cancel_option".to_string(),
+        DictionaryMessage::new("Lever l'attente en cours."),
+    );
+    dict.messages.insert(
+        "slash_command.resolve_command_description".to_string(),
+        DictionaryMessage::new("Marque le ticket actuel comme résolu."),
+    );
+    dict.messages.insert(
+        "slash_command.resolve_delay_option".to_string(),
+        DictionaryMessage::new(
+            "Délai avant la fermeture (ex. 2h). Par défaut, le délai configuré.",
+        ),
+    );
+    dict.messages.insert(
+        "slash_command.resolve_cancel_option".to_string(),
+        DictionaryMessage::new("Rouvrir un ticket résolu."),
+    );
+    dict.messages.insert(
+        "board.title".to_string(),
+        DictionaryMessage::new("Tickets ouverts ({count})"),
+    );
+    dict.messages.insert(
+        "board.empty".to_string(),
+        DictionaryMessage::new("Aucun ticket ouvert."),
+    );
+    dict.messages.insert(
+        "board.page".to_string(),
+        DictionaryMessage::new("Page {page}/{pages}"),
+    );
+    dict.messages.insert(
+        "board.ticket".to_string(),
+        DictionaryMessage::new("{channel} · {category}\n{priority} · {state}\n{taken}\nDernier message : {author}, il y a {wait}"),
+    );
+    dict.messages.insert(
+        "board.taken_by".to_string(),
+        DictionaryMessage::new("Pris par {staff}"),
+    );
+    dict.messages.insert(
+        "board.not_taken".to_string(),
+        DictionaryMessage::new("Non pris"),
+    );
+    dict.messages.insert(
+        "board.author_staff".to_string(),
+        DictionaryMessage::new("staff"),
+    );
+    dict.messages.insert(
+        "board.author_user".to_string(),
+        DictionaryMessage::new("utilisateur"),
+    );
+    dict.messages.insert(
+        "board.overdue".to_string(),
+        DictionaryMessage::new("⚠️ En attente du staff depuis {wait}"),
+    );
+    dict.messages.insert(
+        "board.take_button".to_string(),
+        DictionaryMessage::new("Prendre #{number} ({user})"),
+    );
+    dict.messages.insert(
+        "board.taken".to_string(),
+        DictionaryMessage::new("Vous avez pris le ticket {channel}."),
+    );
+    dict.messages.insert(
+        "board.ticket_gone".to_string(),
+        DictionaryMessage::new("Ce ticket n'est plus ouvert."),
+    );
+    dict.messages.insert(
+        "board.not_allowed".to_string(),
+        DictionaryMessage::new("Vous n'êtes pas autorisé à prendre ce ticket."),
+    );
+    dict.messages.insert(
+        "help.gdpr".to_string(),
+        DictionaryMessage::new("Exporte ou efface tout ce qui est stocké sur un utilisateur. Utilise `!gdpr export <utilisateur>` pour une archive JSON, ou `!gdpr erase <utilisateur> [anonymize|delete]` : anonymize conserve les tickets sans données personnelles, delete les supprime. Les effacements demandent une confirmation, sont refusés si l'utilisateur a un ticket ouvert et sont journalisés. Réservé aux admins du bot."),
+    );
+    dict.messages.insert(
+        "slash_command.gdpr_command_description".to_string(),
+        DictionaryMessage::new("Exporter ou effacer les données stockées sur un utilisateur"),
+    );
+    dict.messages.insert(
+        "slash_command.gdpr_action_option".to_string(),
+        DictionaryMessage::new("Exporter les données ou les effacer"),
+    );
+    dict.messages.insert(
+        "slash_command.gdpr_user_option".to_string(),
+        DictionaryMessage::new("L'utilisateur concerné"),
+    );
+    dict.messages.insert(
+        "slash_command.gdpr_mode_option".to_string(),
+        DictionaryMessage::new("Mode d'effacement : anonymize (par défaut) ou delete"),
+    );
+    dict.messages.insert(
+        "gdpr.export_ready".to_string(),
+        DictionaryMessage::new("Tout ce qui est stocké sur `{user_id}` est en pièce jointe."),
+    );
+    dict.messages.insert(
+        "gdpr.no_data".to_string(),
+        DictionaryMessage::new("Rien n'est stocké sur `{user_id}`."),
+    );
+    dict.messages.insert(
+        "gdpr.open_ticket".to_string(),
+        DictionaryMessage::new(
+            "`{user_id}` a un ticket ouvert. Ferme-le avant d'effacer ses données.",
+        ),
+    );
+    dict.messages.insert(
+        "gdpr.erase_prompt".to_string(),
+        DictionaryMessage::new("Cette action va **{mode}** tout ce qui est stocké sur `{user_id}` :\n{counts}\nC'est irréversible. Confirme dans les 10 minutes."),
+    );
+    dict.messages.insert(
+        "gdpr.mode_anonymize".to_string(),
+        DictionaryMessage::new("anonymiser"),
+    );
+    dict.messages.insert(
+        "gdpr.mode_delete".to_string(),
+        DictionaryMessage::new("supprimer"),
+    );
+    dict.messages.insert(
+        "gdpr.confirm_button".to_string(),
+        DictionaryMessage::new("Effacer"),
+    );
+    dict.messages.insert(
+        "gdpr.cancel_button".to_string(),
+        DictionaryMessage::new("Annuler"),
+    );
+    dict.messages.insert(
+        "gdpr.not_requester".to_string(),
+        DictionaryMessage::new(
+            "Seul le membre du staff à l'origine de cet effacement peut y répondre.",
+        ),
+    );
+    dict.messages.insert(
+        "gdpr.not_allowed".to_string(),
+        DictionaryMessage::new("Seuls les admins du bot peuvent effacer des données utilisateur."),
+    );
+    dict.messages.insert(
+        "gdpr.expired".to_string(),
+        DictionaryMessage::new("Cette confirmation a expiré. Relance la commande."),
+    );
+    dict.messages.insert(
+        "gdpr.cancelled".to_string(),
+        DictionaryMessage::new("Effacement annulé."),
+    );
+    dict.messages.insert(
+        "gdpr.erased".to_string(),
+        DictionaryMessage::new("Les données de `{user_id}` ont été effacées ({mode}) :\n{counts}"),
+    );
+    dict.messages.insert(
+        "gdpr.erase_failed".to_string(),
+        DictionaryMessage::new("L'effacement a échoué, rien n'a été modifié : {error}"),
+    );
 }
@@ -0,0 +1,211 @@
+use chrono::Utc;
+use serde::Serialize;
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{LazyLock, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::Layer;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::registry::LookupSpan;
+
+pub static LOG_BUFFER: LazyLock<LogBuffer> = LazyLock::new(|| LogBuffer::new(1000));
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct LogEntry {
+    pub id: u64,
+    pub timestamp: i64,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    pub fields: BTreeMap<String, String>,
+}
+
+impl LogEntry {
+    pub fn level(&self) -> Level {
+        self.level.parse().unwrap_or(Level::INFO)
+    }
+}
+
+pub struct LogBuffer {
+    entries: Mutex<VecDeque<LogEntry>>,
+    capacity: AtomicUsize,
+    next_id: AtomicU64,
+}
+
+impl LogBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity: AtomicUsize::new(capacity),
+            next_id: AtomicU64::new(1),
+        }
+    }
+
+    pub fn set_capacity(&self, capacity: usize) {
+        self.capacity.store(capacity, Ordering::Relaxed);
+        if let Ok(mut entries) = self.entries.lock() {
+            while entries.len() > capacity {
+                entries.pop_front();
+            }
+        }
+    }
+
+    fn push(&self, mut entry: LogEntry) {
+        let capacity = self.capacity.load(Ordering::Relaxed);
+        if capacity == 0 {
+            return;
+        }
+        entry.id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut entries) = self.entries.lock() {
+            while entries.len() >= capacity {
+                entries.pop_front();
+            }
+            entries.push_back(entry);
+        }
+    }
+
+    pub fn snapshot<F>(&self, limit: usize, filter: F) -> Vec<LogEntry>
+    where
+        F: Fn(&LogEntry) -> bool,
+    {
+        let Ok(entries) = self.entries.lock() else {
+            return Vec::new();
+        };
+        let mut matching: Vec<LogEntry> = entries
+            .iter()
+            .rev()
+            .filter(|e| filter(e))
+            .take(limit)
+            .cloned()
+            .collect();
+        matching.reverse();
+        matching
+    }
+}
+
+#[derive(Default)]
+struct FieldCollector {
+    message: Option<String>,
+    fields: BTreeMap<String, String>,
+}
+
+impl Visit for FieldCollector {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = Some(value.to_string());
+        } else {
+            self.fields
+                .insert(field.name().to_string(), value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if field.name() == "message" {
+            self.message = Some(format!("{:?}", value));
+        } else {
+            self.fields
+                .insert(field.name().to_string(), format!("{:?}", value));
+        }
+    }
+}
+
+struct SpanFields(BTreeMap<String, String>);
+
+pub struct LogBufferLayer;
+
+impl<S> Layer<S> for LogBufferLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut collector = FieldCollector::default();
+        attrs.record(&mut collector);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(SpanFields(collector.fields));
+        }
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut collector = FieldCollector::default();
+        values.record(&mut collector);
+        let mut extensions = span.extensions_mut();
+        if let Some(fields) = extensions.get_mut::<SpanFields>() {
+            fields.0.extend(collector.fields);
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = BTreeMap::new();
+        if let Some(scope) = ctx.event_scope(event) {
+            for span in scope.from_root() {
+                if let Some(span_fields) = span.extensions().get::<SpanFields>() {
+                    fields.extend(span_fields.0.clone());
+                }
+            }
+        }
+
+        let mut collector = FieldCollector::default();
+        event.record(&mut collector);
+        fields.extend(collector.fields);
+
+        let metadata = event.metadata();
+        LOG_BUFFER.push(LogEntry {
+            id: 0,
+            timestamp: Utc::now().timestamp_millis(),
+            level: metadata.level().to_string(),
+            target: metadata.target().to_string(),
+            message: collector.message.unwrap_or_default(),
+            fields,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(message: &str) -> LogEntry {
+        LogEntry {
+            id: 0,
+            timestamp: 0,
+            level: "INFO".to_string(),
+            target: "rustmail".to_string(),
+            message: message.to_string(),
+            fields: BTreeMap::new(),
+        }
+    }
+
+    #[test]
+    fn buffer_drops_oldest_entries() {
+        let buffer = LogBuffer::new(2);
+        buffer.push(entry("a"));
+        buffer.push(entry("b"));
+        buffer.push(entry("c"));
+
+        let messages: Vec<String> = buffer
+            .snapshot(10, |_| true)
+            .into_iter()
+            .map(|e| e.message)
+            .collect();
+        assert_eq!(messages, vec!["b", "c"]);
+    }
+
+    #[test]
+    fn snapshot_keeps_most_recent_matches() {
+        let buffer = LogBuffer::new(10);
+        for m in ["a", "b", "c", "d"] {
+            buffer.push(entry(m));
+        }
+
+        let snapshot = buffer.snapshot(2, |e| e.message != "d");
+        let messages: Vec<&str> = snapshot.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, vec!["b", "c"]);
+        assert!(snapshot[0].id < snapshot[1].id);
+    }
+}
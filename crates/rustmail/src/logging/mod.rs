@@ -0,0 +1,99 @@
+pub mod buffer;
+
+pub use buffer::*;
+
+use crate::config::read_config_table;
+use rustmail_types::{LogFormat, LogRotation, LoggingConfig};
+use serde::Deserialize;
+use tracing_appender::non_blocking::WorkerGuard;
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer, Registry, fmt};
+
+const LOG_FILE_PREFIX: &str = "rustmail.log";
+
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+#[derive(Deserialize, Default)]
+struct LoggingSection {
+    #[serde(default)]
+    logging: LoggingConfig,
+}
+
+pub fn read_logging_config(path: &str) -> LoggingConfig {
+    toml::Value::Table(read_config_table(path))
+        .try_into::<LoggingSection>()
+        .map(|section| section.logging)
+        .unwrap_or_default()
+}
+
+pub fn init_logging(config: &LoggingConfig) -> Option<WorkerGuard> {
+    let filter = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new(&config.level))
+        .unwrap_or_else(|_| EnvFilter::new("info"));
+
+    LOG_BUFFER.set_capacity(config.buffer_size);
+
+    let mut layers: Vec<BoxedLayer> = vec![
+        format_layer(config.format, std::io::stdout, true),
+        Box::new(LogBufferLayer),
+    ];
+
+    // Reported once the subscriber is installed; logging then goes to stdout
+    // and the buffer only.
+    let mut file_error = None;
+    let guard = config.directory.as_ref().and_then(|directory| {
+        let rotation = match config.rotation {
+            LogRotation::Hourly => Rotation::HOURLY,
+            LogRotation::Daily => Rotation::DAILY,
+            LogRotation::Never => Rotation::NEVER,
+        };
+        let appender = match RollingFileAppender::builder()
+            .rotation(rotation)
+            .filename_prefix(LOG_FILE_PREFIX)
+            .build(directory)
+        {
+            Ok(appender) => appender,
+            Err(e) => {
+                file_error = Some(format!(
+                    "Failed to open log directory {}, logging to stdout only: {}",
+                    directory, e
+                ));
+                return None;
+            }
+        };
+        let (writer, guard) = tracing_appender::non_blocking(appender);
+        layers.push(format_layer(config.format, writer, false));
+        Some(guard)
+    });
+
+    if let Err(e) = tracing_subscriber::registry()
+        .with(layers)
+        .with(filter)
+        .try_init()
+    {
+        eprintln!("Failed to initialize logging: {}", e);
+    }
+
+    if let Some(e) = file_error {
+        tracing::error!("{}", e);
+    }
+
+    guard
+}
+
+fn format_layer<W>(format: LogFormat, writer: W, ansi: bool) -> BoxedLayer
+where
+    W: for<'w> fmt::MakeWriter<'w> + Send + Sync + 'static,
+{
+    match format {
+        LogFormat::Pretty => fmt::layer().with_ansi(ansi).with_writer(writer).boxed(),
+        LogFormat::Json => fmt::layer()
+            .json()
+            .with_current_span(true)
+            .with_span_list(true)
+            .with_writer(writer)
+            .boxed(),
+    }
+}
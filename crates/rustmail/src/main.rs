@@ -1,6 +1,10 @@
-use crate::bot::{init_bot_state, start_bot_if_config_valid};
-use crate::config::{resolve_bind_address, resolve_config_path, resolve_port};
+use crate::bot::{init_bot_state, start_bot_if_config_valid, watch_config};
+use crate::config::{
+    build_headless_config, resolve_bind_address, resolve_config_path, resolve_port,
+};
+use crate::logging::{init_logging, read_logging_config};
 use crate::prelude::api::*;
+use crate::setup::headless::run_headless_setup;
 use crate::setup::router::create_setup_router;
 use crate::setup::state::new_setup_state;
 use axum::extract::Path;
@@ -14,11 +18,13 @@ use std::net::SocketAddr;
 use std::{env, process};
 use tokio::signal;
 use tower_http::compression::CompressionLayer;
+use tracing::{error, info};
 
 const VERSION: &str = env!("CARGO_PKG_VERSION");
 
 mod api;
 mod bot;
+mod cli;
 mod commands;
 mod config;
 mod db;
@@ -26,6 +32,7 @@ mod errors;
 mod features;
 mod handlers;
 mod i18n;
+mod logging;
 mod modules;
 mod panel_commands;
 mod prelude;
@@ -87,23 +94,71 @@ fn print_help() {
     println!();
     println!("USAGE:");
     println!("    rustmail [OPTIONS]");
+    println!("    rustmail <COMMAND> [ARGS] [--json]");
     println!();
     println!("OPTIONS:");
     println!("    -h, --help       Print this help message");
     println!("    -v, --version    Print version information");
     println!();
+    println!("COMMANDS:");
+    println!("    config check [--path FILE]                 Validate config.toml");
+    println!("    migrate                                    Apply pending database migrations");
+    println!(
+        "    apikey create --name NAME --permission P   Create an API key (--permission repeatable,"
+    );
+    println!("                                               --expires-in-days N, --rate-limit N,");
+    println!("                                               --allow-cidr CIDR, --category ID)");
+    println!("    apikey list                                List API keys");
+    println!("    apikey revoke ID                           Revoke an API key");
+    println!("    permissions grant --user|--role ID PERM    Grant a panel permission");
+    println!("    permissions revoke --user|--role ID PERM   Revoke a panel permission");
+    println!("    permissions list                           List granted panel permissions");
+    println!("    tickets list [--status open|closed|all] [--limit N]");
+    println!("    tickets show ID                            Show a ticket and its messages");
+    println!("    tickets export ID [--output FILE]          Export a ticket transcript");
+    println!("    snippets export [--output FILE]            Export snippets as JSON");
+    println!("    snippets import FILE [--overwrite]         Import snippets from JSON");
+    println!("    db backup [--output FILE]                  Write a copy of the database");
+    println!(
+        "    import modmail LOGS [--config FILE]        Import a Python modmail bot's mongoexport"
+    );
+    println!(
+        "    import modmailbot DATABASE                 Import a Node modmailbot's SQLite database"
+    );
+    println!("                                               (--dry-run reports without writing)");
+    println!(
+        "    instance export FILE                       Export the configuration and settings"
+    );
+    println!(
+        "                                               (--include-secrets, --include-history)"
+    );
+    println!("    instance ids FILE                          Print the Discord IDs of an export");
+    println!(
+        "    instance import FILE [--mapping FILE]      Import an export, remapping Discord IDs"
+    );
+    println!("                                               (--replace-config, --dry-run)");
+    println!();
+    println!("    Commands use the same config.toml and database as the bot and can run");
+    println!("    while it is online. --json prints machine-readable output.");
+    println!();
     println!("CONFIGURATION:");
     println!("    Rustmail requires a config.toml file in the current directory.");
-    println!("    If no configuration is found, a setup wizard will launch on port 3002.");
+    println!("    If no configuration is found, a setup wizard will launch on port 3002,");
+    println!("    unless RUSTMAIL_CONFIG_SEED or RUSTMAIL_BOT_MODE_* variables describe one.");
+    println!("    Edits to the file are applied live; send SIGHUP to reload it immediately.");
     println!();
     println!("ENVIRONMENT VARIABLES:");
     println!("    RUSTMAIL_CONFIG_PATH      Path to config.toml (default: config.toml)");
-    println!("    RUSTMAIL_BOT_TOKEN        Overrides bot.token from config.toml");
-    println!("    RUSTMAIL_BOT_CLIENT_ID    Overrides bot.client_id from config.toml");
-    println!("    RUSTMAIL_BOT_CLIENT_SECRET Overrides bot.client_secret from config.toml");
+    println!(
+        "    RUSTMAIL_<SECTION>_<FIELD> Overrides any config.toml field, e.g. RUSTMAIL_BOT_TOKEN"
+    );
+    println!("                              or RUSTMAIL_THREAD_INBOX_CATEGORY_ID");
+    println!("    RUSTMAIL_<SECTION>_<FIELD>_FILE Reads the value from a file (for secrets)");
+    println!("    RUSTMAIL_CONFIG_SEED      Partial config.toml used when config.toml is missing");
     println!("    RUSTMAIL_DATABASE_URL     Database path (default: db/db.sqlite)");
     println!("    RUSTMAIL_BIND_ADDRESS     Bind address (default: 0.0.0.0)");
     println!("    RUSTMAIL_PORT             Port (default: 3002)");
+    println!("    RUST_LOG                  Log filter, overrides logging.level from config.toml");
     println!();
     println!("DOCUMENTATION:");
     println!("    https://docs.rustmail.rs");
@@ -135,12 +190,17 @@ async fn run_setup_mode() {
         .await
         .unwrap_or_else(|_| panic!("Failed to bind to {}", addr));
 
-    println!("No configuration found.");
+    info!("No configuration found.");
     let display_host = if bind_address == "0.0.0.0" || bind_address == "::" {
         "localhost"
     } else {
         bind_address.as_str()
     };
+    info!(
+        "Setup wizard available at http://{}:{}/setup",
+        display_host, port
+    );
+    // The token stays out of the log file and the admin log buffer.
     println!(
         "Setup wizard available at http://{}:{}/setup?token={}",
         display_host, port, setup_token
@@ -155,10 +215,10 @@ async fn run_setup_mode() {
     .with_graceful_shutdown(async move {
         tokio::select! {
             _ = shutdown_rx.recv() => {
-                println!("Setup complete, transitioning to bot mode...");
+                info!("Setup complete, transitioning to bot mode...");
             }
             _ = shutdown_signal() => {
-                println!("Shutdown signal received during setup");
+                info!("Shutdown signal received during setup");
                 process::exit(0);
             }
         }
@@ -181,6 +241,9 @@ async fn main() {
                 print_help();
                 process::exit(0);
             }
+            command if cli::SUBCOMMANDS.contains(&command) => {
+                process::exit(cli::run(command, &args[2..]).await);
+            }
             _ => {
                 eprintln!("Unknown option: {}", arg);
                 eprintln!("Run 'rustmail --help' for usage information.");
@@ -190,6 +253,14 @@ async fn main() {
     }
 
     let config_path = resolve_config_path("config.toml");
+    let _log_guard = init_logging(&read_logging_config(&config_path));
+
+    if !std::path::Path::new(&config_path).exists()
+        && let Some(config) = build_headless_config()
+    {
+        run_headless_setup(config).await;
+    }
+
     let mut bot_state = init_bot_state(&config_path).await;
 
     let has_config = {
@@ -203,10 +274,11 @@ async fn main() {
     }
 
     let _ = start_bot_if_config_valid(bot_state.clone()).await;
+    tokio::spawn(watch_config(bot_state.clone()));
 
     let config = {
         let state = bot_state.lock().await;
-        state.config.clone()
+        state.config()
     };
 
     if let Some(config) = config {
@@ -227,7 +299,7 @@ async fn main() {
                     {
                         Ok(l) => l,
                         Err(e) => {
-                            eprintln!(
+                            error!(
                                 "Failed to bind to {}:{} ({}), falling back to 0.0.0.0:3002",
                                 bind_address, port, e
                             );
@@ -236,7 +308,7 @@ async fn main() {
                                 .expect("Failed to bind to 0.0.0.0:3002")
                         }
                     };
-                println!("listening on {}", listener.local_addr().unwrap());
+                info!("listening on {}", listener.local_addr().unwrap());
 
                 axum::serve(
                     listener,
@@ -249,11 +321,11 @@ async fn main() {
 
             tokio::select! {
                 _ = server_task => {},
-                _ = tokio::signal::ctrl_c() => { println!("Shutting down"); }
+                _ = tokio::signal::ctrl_c() => { info!("Shutting down"); }
             }
         } else {
             let _ = tokio::signal::ctrl_c().await;
-            println!("Shutting down");
+            info!("Shutting down");
         }
     }
 }
@@ -262,5 +334,5 @@ async fn shutdown_signal() {
     signal::ctrl_c()
         .await
         .expect("Failed to install Ctrl+C handler");
-    println!("Shutdown signal received");
+    info!("Shutdown signal received");
 }
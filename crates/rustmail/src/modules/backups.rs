@@ -0,0 +1,376 @@
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::modules::*;
+use chrono::{DateTime, Datelike, NaiveDateTime, Utc};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use serde::Serialize;
+use sqlx::SqlitePool;
+use std::collections::HashSet;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tokio::sync::Mutex;
+use tokio::task::spawn_blocking;
+use tracing::{error, info};
+
+pub const DATABASE_BACKUP_KEY: &str = "all";
+
+const BACKUP_IDLE_CHECK_SECS: i64 = 60 * 60;
+
+const BACKUP_PREFIX: &str = "rustmail-";
+const BACKUP_TIMESTAMP_FORMAT: &str = "%Y%m%d-%H%M%S";
+const SQLITE_HEADER: &[u8; 16] = b"SQLite format 3\0";
+
+// A restore must not swap the database file while a backup of it is being written.
+pub static BACKUP_LOCK: Mutex<()> = Mutex::const_new(());
+
+#[derive(Debug, Clone, Serialize, utoipa::ToSchema)]
+pub struct BackupInfo {
+    pub name: String,
+    pub created_at: i64,
+    pub size_bytes: u64,
+    pub compressed: bool,
+    #[serde(skip)]
+    pub path: PathBuf,
+}
+
+fn io_failed(context: &str, e: impl std::fmt::Display) -> ModmailError {
+    error!("{context}: {e}");
+    validation_failed(context)
+}
+
+pub fn backup_directory(config: &BackupConfig) -> PathBuf {
+    config
+        .directory
+        .as_deref()
+        .filter(|d| !d.is_empty())
+        .map(PathBuf::from)
+        .unwrap_or_else(default_backup_dir)
+}
+
+fn parse_backup_name(name: &str) -> Option<(DateTime<Utc>, bool)> {
+    let stem = name.strip_prefix(BACKUP_PREFIX)?;
+    let (timestamp, compressed) = match stem.strip_suffix(".sqlite.gz") {
+        Some(timestamp) => (timestamp, true),
+        None => (stem.strip_suffix(".sqlite")?, false),
+    };
+    let created_at = NaiveDateTime::parse_from_str(timestamp, BACKUP_TIMESTAMP_FORMAT).ok()?;
+    Some((created_at.and_utc(), compressed))
+}
+
+pub fn list_backups(config: &BackupConfig) -> ModmailResult<Vec<BackupInfo>> {
+    let dir = backup_directory(config);
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(io_failed("Failed to read backup directory", e)),
+    };
+
+    let mut backups: Vec<BackupInfo> = entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            let (created_at, compressed) = parse_backup_name(&name)?;
+            let metadata = entry.metadata().ok().filter(|m| m.is_file())?;
+            Some(BackupInfo {
+                name,
+                created_at: created_at.timestamp(),
+                size_bytes: metadata.len(),
+                compressed,
+                path: entry.path(),
+            })
+        })
+        .collect();
+    backups.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+
+    Ok(backups)
+}
+
+// Only listed names resolve, so a name cannot point outside the backup directory.
+pub fn find_backup(config: &BackupConfig, name: &str) -> ModmailResult<Option<BackupInfo>> {
+    Ok(list_backups(config)?.into_iter().find(|b| b.name == name))
+}
+
+fn compress_file(source: &Path, target: &Path) -> std::io::Result<()> {
+    let mut input = File::open(source)?;
+    let mut encoder = GzEncoder::new(File::create(target)?, Compression::default());
+    std::io::copy(&mut input, &mut encoder)?;
+    encoder.finish()?.sync_all()
+}
+
+pub async fn create_backup(config: &BackupConfig, pool: &SqlitePool) -> ModmailResult<BackupInfo> {
+    let dir = backup_directory(config);
+    let created_at = Utc::now();
+    let stem = format!(
+        "{}{}",
+        BACKUP_PREFIX,
+        created_at.format(BACKUP_TIMESTAMP_FORMAT)
+    );
+    let name = if config.compress {
+        format!("{stem}.sqlite.gz")
+    } else {
+        format!("{stem}.sqlite")
+    };
+    let target = dir.join(&name);
+    if target.exists() {
+        return Err(validation_failed("A backup was already taken this second"));
+    }
+
+    let partial = dir.join(format!("{stem}.partial"));
+    let _ = fs::remove_file(&partial);
+    backup_database(&partial, pool).await?;
+
+    let compress = config.compress;
+    let (source, destination) = (partial.clone(), target.clone());
+    let finished = spawn_blocking(move || {
+        if compress {
+            compress_file(&source, &destination)
+        } else {
+            fs::rename(&source, &destination)
+        }
+    })
+    .await;
+    let _ = fs::remove_file(&partial);
+    match finished {
+        Ok(Ok(())) => {}
+        Ok(Err(e)) => {
+            let _ = fs::remove_file(&target);
+            return Err(io_failed("Failed to write backup", e));
+        }
+        Err(e) => return Err(io_failed("Failed to write backup", e)),
+    }
+
+    let size_bytes = fs::metadata(&target).map(|m| m.len()).unwrap_or(0);
+    Ok(BackupInfo {
+        name,
+        created_at: created_at.timestamp(),
+        size_bytes,
+        compressed: compress,
+        path: target,
+    })
+}
+
+// Newest backup of each of the `keep_daily` latest days and `keep_weekly` latest ISO weeks.
+fn retained_backups(
+    backups: &[BackupInfo],
+    keep_daily: usize,
+    keep_weekly: usize,
+) -> HashSet<String> {
+    let mut keep = HashSet::new();
+    let mut days = Vec::new();
+    let mut weeks = Vec::new();
+
+    for backup in backups {
+        let Some(created_at) = DateTime::from_timestamp(backup.created_at, 0) else {
+            continue;
+        };
+        let day = created_at.date_naive();
+        let week = (created_at.iso_week().year(), created_at.iso_week().week());
+
+        if !days.contains(&day) {
+            if days.len() < keep_daily {
+                keep.insert(backup.name.clone());
+            }
+            days.push(day);
+        }
+        if !weeks.contains(&week) {
+            if weeks.len() < keep_weekly {
+                keep.insert(backup.name.clone());
+            }
+            weeks.push(week);
+        }
+    }
+    if let Some(newest) = backups.first() {
+        keep.insert(newest.name.clone());
+    }
+
+    keep
+}
+
+pub fn prune_backups(config: &BackupConfig) -> ModmailResult<usize> {
+    if config.keep_daily == 0 && config.keep_weekly == 0 {
+        return Ok(0);
+    }
+
+    let backups = list_backups(config)?;
+    let keep = retained_backups(&backups, config.keep_daily, config.keep_weekly);
+    let mut deleted = 0;
+    for backup in backups.iter().filter(|b| !keep.contains(&b.name)) {
+        match fs::remove_file(&backup.path) {
+            Ok(()) => deleted += 1,
+            Err(e) => error!("Failed to delete old backup {}: {e}", backup.name),
+        }
+    }
+
+    Ok(deleted)
+}
+
+pub async fn run_backup_job(config: &Config, pool: &SqlitePool) -> JobResult {
+    let now = Utc::now().timestamp();
+    let backups = &config.backups;
+    if !backups.enabled {
+        return Ok(JobOutcome::RunAt(now + BACKUP_IDLE_CHECK_SECS));
+    }
+    let interval = backups.interval_hours.clamp(1, 24 * 365) as i64 * 60 * 60;
+
+    let _guard = BACKUP_LOCK.lock().await;
+
+    let latest = list_backups(backups)
+        .map_err(|e| format!("{e:?}"))?
+        .first()
+        .map(|b| b.created_at);
+    if let Some(latest) = latest
+        && latest + interval > now
+    {
+        return Ok(JobOutcome::RunAt(latest + interval));
+    }
+
+    let backup = create_backup(backups, pool)
+        .await
+        .map_err(|e| format!("{e:?}"))?;
+    info!(
+        "Database backup written to {} ({} bytes)",
+        backup.path.display(),
+        backup.size_bytes
+    );
+
+    match prune_backups(backups) {
+        Ok(0) => {}
+        Ok(n) => info!("Deleted {} old backup(s)", n),
+        Err(e) => error!("Failed to apply backup retention: {e:?}"),
+    }
+
+    Ok(JobOutcome::RunAt(now + interval))
+}
+
+fn stage_file(backup: &Path, compressed: bool, staged: &Path) -> std::io::Result<()> {
+    if compressed {
+        let mut decoder = GzDecoder::new(File::open(backup)?);
+        let mut output = File::create(staged)?;
+        std::io::copy(&mut decoder, &mut output)?;
+        output.sync_all()?;
+    } else {
+        fs::copy(backup, staged)?;
+    }
+
+    let mut header = [0u8; 16];
+    File::open(staged)?.read_exact(&mut header)?;
+    if &header != SQLITE_HEADER {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            "not a SQLite database",
+        ));
+    }
+    Ok(())
+}
+
+fn sqlite_sidecar(db_path: &str, suffix: &str) -> PathBuf {
+    PathBuf::from(format!("{db_path}{suffix}"))
+}
+
+pub async fn stage_restore(backup: &BackupInfo) -> ModmailResult<PathBuf> {
+    let staged = sqlite_sidecar(&resolve_db_path("db/db.sqlite"), "-restore");
+    let (source, compressed, target) = (backup.path.clone(), backup.compressed, staged.clone());
+
+    match spawn_blocking(move || stage_file(&source, compressed, &target)).await {
+        Ok(Ok(())) => Ok(staged),
+        Ok(Err(e)) => {
+            let _ = fs::remove_file(&staged);
+            Err(io_failed("Backup is not a readable SQLite database", e))
+        }
+        Err(e) => Err(io_failed("Failed to prepare restore", e)),
+    }
+}
+
+pub fn swap_database_file(staged: &Path) -> ModmailResult<()> {
+    let db_path = resolve_db_path("db/db.sqlite");
+    let previous = sqlite_sidecar(&db_path, "-previous");
+
+    for suffix in ["-wal", "-shm"] {
+        let _ = fs::remove_file(sqlite_sidecar(&db_path, suffix));
+    }
+    fs::rename(&db_path, &previous)
+        .map_err(|e| io_failed("Failed to move the current database aside", e))?;
+    if let Err(e) = fs::rename(staged, &db_path) {
+        let _ = fs::rename(&previous, &db_path);
+        return Err(io_failed(
+            "Failed to move the restored database in place",
+            e,
+        ));
+    }
+
+    Ok(())
+}
+
+pub fn rollback_database_file() -> ModmailResult<()> {
+    let db_path = resolve_db_path("db/db.sqlite");
+    for suffix in ["-wal", "-shm"] {
+        let _ = fs::remove_file(sqlite_sidecar(&db_path, suffix));
+    }
+    fs::rename(sqlite_sidecar(&db_path, "-previous"), &db_path)
+        .map_err(|e| io_failed("Failed to put the previous database back", e))
+}
+
+pub fn discard_previous_database() {
+    let previous = sqlite_sidecar(&resolve_db_path("db/db.sqlite"), "-previous");
+    if let Err(e) = fs::remove_file(&previous) {
+        error!("Failed to delete {}: {e}", previous.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backup(timestamp: &str) -> BackupInfo {
+        let name = format!("{BACKUP_PREFIX}{timestamp}.sqlite");
+        let (created_at, compressed) = parse_backup_name(&name).unwrap();
+        BackupInfo {
+            name,
+            created_at: created_at.timestamp(),
+            size_bytes: 0,
+            compressed,
+            path: PathBuf::new(),
+        }
+    }
+
+    #[test]
+    fn parses_only_rustmail_backup_names() {
+        assert!(parse_backup_name("rustmail-20261019-120000.sqlite").is_some_and(|(_, c)| !c));
+        assert!(parse_backup_name("rustmail-20261019-120000.sqlite.gz").is_some_and(|(_, c)| c));
+        assert!(parse_backup_name("rustmail-20261019-120000.partial").is_none());
+        assert!(parse_backup_name("../db.sqlite").is_none());
+    }
+
+    #[test]
+    fn retention_keeps_newest_per_day_and_week() {
+        // 2026-10-19 is a Monday, 2026-10-18 the Sunday of the previous ISO week.
+        let backups = [
+            backup("20261019-180000"),
+            backup("20261019-060000"),
+            backup("20261018-060000"),
+            backup("20261017-060000"),
+            backup("20261010-060000"),
+            backup("20261001-060000"),
+        ];
+
+        let keep = retained_backups(&backups, 2, 2);
+        let mut keep: Vec<_> = keep.iter().map(String::as_str).collect();
+        keep.sort();
+        assert_eq!(
+            keep,
+            [
+                "rustmail-20261018-060000.sqlite",
+                "rustmail-20261019-180000.sqlite",
+            ]
+        );
+
+        let keep = retained_backups(&backups, 1, 3);
+        assert!(keep.contains("rustmail-20261010-060000.sqlite"));
+        assert!(!keep.contains("rustmail-20261017-060000.sqlite"));
+        assert_eq!(keep.len(), 3);
+    }
+}
@@ -0,0 +1,463 @@
+use crate::prelude::commands::*;
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
+use crate::prelude::types::*;
+use crate::prelude::utils::*;
+use chrono::Utc;
+use serenity::all::{
+    ButtonStyle, ChannelId, Colour, ComponentInteraction, Context, CreateActionRow, CreateButton,
+    CreateEmbed, CreateEmbedFooter, CreateInteractionResponse, CreateInteractionResponseMessage,
+    CreateMessage, EditMessage, GuildId, MessageId, Timestamp,
+};
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use tracing::error;
+
+// One button per ticket, in rows of five.
+const BOARD_TICKETS_PER_PAGE: usize = 15;
+
+pub const TICKET_BOARD_REFRESH_INTERVAL_SECS: i64 = 60;
+
+pub fn sort_board_tickets(tickets: &mut [BoardTicket]) {
+    tickets.sort_by_key(|t| (Reverse(t.priority), t.last_message_at));
+}
+
+pub fn is_past_sla(ticket: &BoardTicket, sla: u64, now: i64) -> bool {
+    sla > 0
+        && matches!(
+            ticket.workflow_state,
+            TicketWorkflowState::Open | TicketWorkflowState::AwaitingStaff
+        )
+        && now - ticket.state_changed_at >= ticket.priority.response_sla(sla) as i64
+}
+
+async fn translate(config: &Config, key: &str, params: &[(&str, String)]) -> String {
+    let params: HashMap<String, String> = params
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect();
+    get_translated_message(config, key, Some(&params), None, None, None).await
+}
+
+async fn ticket_field_value(config: &Config, ticket: &BoardTicket, now: i64) -> String {
+    let category = ticket
+        .category_name
+        .clone()
+        .unwrap_or_else(|| "-".to_string());
+    let taken = match &ticket.taken_by {
+        Some(staff_id) => {
+            translate(
+                config,
+                "board.taken_by",
+                &[("staff", format!("<@{staff_id}>"))],
+            )
+            .await
+        }
+        None => translate(config, "board.not_taken", &[]).await,
+    };
+    let author_key = match ticket.last_message_by {
+        TicketAuthor::Staff => "board.author_staff",
+        TicketAuthor::User => "board.author_user",
+    };
+
+    let value = translate(
+        config,
+        "board.ticket",
+        &[
+            ("channel", format!("<#{}>", ticket.channel_id)),
+            ("category", category),
+            (
+                "priority",
+                translate(
+                    config,
+                    &format!("priority.{}", ticket.priority.as_str()),
+                    &[],
+                )
+                .await,
+            ),
+            (
+                "state",
+                translate(
+                    config,
+                    &format!("workflow.state.{}", ticket.workflow_state.as_str()),
+                    &[],
+                )
+                .await,
+            ),
+            ("taken", taken),
+            ("author", translate(config, author_key, &[]).await),
+            (
+                "wait",
+                format_duration(now.saturating_sub(ticket.last_message_at).max(0) as u64),
+            ),
+        ],
+    )
+    .await;
+
+    if !is_past_sla(ticket, config.thread.response_sla, now) {
+        return value;
+    }
+    let overdue = translate(
+        config,
+        "board.overdue",
+        &[(
+            "wait",
+            format_duration(now.saturating_sub(ticket.state_changed_at).max(0) as u64),
+        )],
+    )
+    .await;
+    format!("{overdue}\n{value}")
+}
+
+async fn board_page(
+    config: &Config,
+    tickets: &[BoardTicket],
+    first_index: usize,
+    page: usize,
+    pages: usize,
+    total: usize,
+) -> (CreateEmbed, Vec<CreateActionRow>) {
+    let now = Utc::now().timestamp();
+    let mut embed = CreateEmbed::new()
+        .title(translate(config, "board.title", &[("count", total.to_string())]).await)
+        .color(Colour::new(
+            hex_string_to_int(&config.thread.system_message_color) as u32,
+        ))
+        .timestamp(Timestamp::now());
+
+    if pages > 1 {
+        embed = embed.footer(CreateEmbedFooter::new(
+            translate(
+                config,
+                "board.page",
+                &[
+                    ("page", (page + 1).to_string()),
+                    ("pages", pages.to_string()),
+                ],
+            )
+            .await,
+        ));
+    }
+
+    if tickets.is_empty() {
+        embed = embed.description(translate(config, "board.empty", &[]).await);
+    }
+
+    let mut buttons = Vec::with_capacity(tickets.len());
+    for (i, ticket) in tickets.iter().enumerate() {
+        let number = first_index + i + 1;
+        let name: String = format!(
+            "{number}. {}{} {}",
+            ticket.priority.channel_prefix(),
+            ticket.workflow_state.channel_marker(),
+            ticket.user_name
+        )
+        .chars()
+        .take(256)
+        .collect();
+        embed = embed.field(name, ticket_field_value(config, ticket, now).await, false);
+
+        let label: String = translate(
+            config,
+            "board.take_button",
+            &[
+                ("number", number.to_string()),
+                ("user", ticket.user_name.clone()),
+            ],
+        )
+        .await
+        .chars()
+        .take(80)
+        .collect();
+        buttons.push(
+            CreateButton::new(format!("board:take:{}", ticket.thread_id))
+                .label(label)
+                .style(ButtonStyle::Secondary)
+                .disabled(ticket.taken_by.is_some()),
+        );
+    }
+
+    let mut rows = Vec::new();
+    let mut buttons = buttons.into_iter().peekable();
+    while buttons.peek().is_some() {
+        rows.push(CreateActionRow::Buttons(buttons.by_ref().take(5).collect()));
+    }
+
+    (embed, rows)
+}
+
+pub async fn run_ticket_board_job(ctx: &Context, config: &Config) -> JobResult {
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Err("Database pool is not set in config".to_string());
+    };
+    let Some(board_channel) = config.thread.board_channel_id.map(ChannelId::new) else {
+        return Ok(JobOutcome::Done);
+    };
+
+    let mut tickets = list_board_tickets(pool).await.map_err(|e| e.to_string())?;
+    sort_board_tickets(&mut tickets);
+    let total = tickets.len();
+
+    let chunks: Vec<&[BoardTicket]> = if tickets.is_empty() {
+        vec![&[]]
+    } else {
+        tickets.chunks(BOARD_TICKETS_PER_PAGE).collect()
+    };
+    let pages = chunks.len();
+
+    let existing: HashMap<i64, (String, String)> = list_board_messages(pool)
+        .await
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .map(|(page, channel_id, message_id)| (page, (channel_id, message_id)))
+        .collect();
+
+    for (page, chunk) in chunks.into_iter().enumerate() {
+        let (embed, components) = board_page(
+            config,
+            chunk,
+            page * BOARD_TICKETS_PER_PAGE,
+            page,
+            pages,
+            total,
+        )
+        .await;
+
+        let previous = existing.get(&(page as i64)).and_then(|(channel, message)| {
+            Some((
+                ChannelId::new(channel.parse().ok()?),
+                MessageId::new(message.parse().ok()?),
+            ))
+        });
+
+        if let Some((channel_id, message_id)) = previous {
+            if channel_id == board_channel {
+                let edit = EditMessage::new()
+                    .embed(embed.clone())
+                    .components(components.clone());
+                if board_channel
+                    .edit_message(&ctx.http, message_id, edit)
+                    .await
+                    .is_ok()
+                {
+                    continue;
+                }
+            } else {
+                let _ = channel_id.delete_message(&ctx.http, message_id).await;
+            }
+        }
+
+        let message = CreateMessage::new().embed(embed).components(components);
+        match board_channel.send_message(&ctx.http, message).await {
+            Ok(sent) => {
+                set_board_message(
+                    page as i64,
+                    &board_channel.to_string(),
+                    &sent.id.to_string(),
+                    pool,
+                )
+                .await
+                .map_err(|e| e.to_string())?;
+            }
+            Err(e) => return Err(format!("Failed to post ticket board: {e}")),
+        }
+    }
+
+    for (page, (channel_id, message_id)) in existing {
+        if page < pages as i64 {
+            continue;
+        }
+        if let (Ok(channel_id), Ok(message_id)) = (channel_id.parse(), message_id.parse()) {
+            let _ = ChannelId::new(channel_id)
+                .delete_message(&ctx.http, MessageId::new(message_id))
+                .await;
+        }
+        delete_board_message(page, pool)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    Ok(JobOutcome::RunAt(
+        Utc::now().timestamp() + TICKET_BOARD_REFRESH_INTERVAL_SECS,
+    ))
+}
+
+async fn respond_ephemeral(
+    ctx: &Context,
+    interaction: &ComponentInteraction,
+    content: String,
+) -> serenity::Result<()> {
+    interaction
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .ephemeral(true),
+            ),
+        )
+        .await
+}
+
+pub async fn handle_board_component_interaction(
+    ctx: &Context,
+    config: &Config,
+    interaction: &ComponentInteraction,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let Some(thread_id) = interaction.data.custom_id.strip_prefix("board:take:") else {
+        return Ok(false);
+    };
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Ok(true);
+    };
+    let user_id = interaction.user.id;
+    let guild_id = interaction
+        .guild_id
+        .unwrap_or_else(|| GuildId::new(config.bot.get_staff_guild_id()));
+
+    let Some(thread) = get_thread_by_id(thread_id, pool).await else {
+        respond_ephemeral(
+            ctx,
+            interaction,
+            translate(config, "board.ticket_gone", &[]).await,
+        )
+        .await?;
+        return Ok(true);
+    };
+    let Some(mut ticket_status) = get_thread_status(&thread.id, pool).await else {
+        respond_ephemeral(
+            ctx,
+            interaction,
+            translate(config, "board.ticket_gone", &[]).await,
+        )
+        .await?;
+        return Ok(true);
+    };
+    let channel_id = ChannelId::new(thread.channel_id.parse::<u64>().unwrap_or(0));
+
+    let identity = StaffIdentity::resolve(ctx, guild_id, user_id).await;
+    if !can_run_command(config, "take", user_id, identity.as_ref()).await
+        || !can_access_ticket_channel(config, channel_id, identity.as_ref()).await
+    {
+        respond_ephemeral(
+            ctx,
+            interaction,
+            translate(config, "board.not_allowed", &[]).await,
+        )
+        .await?;
+        return Ok(true);
+    }
+
+    if ticket_status.taken_by.is_some() {
+        respond_ephemeral(
+            ctx,
+            interaction,
+            translate(config, "take.ticket_already_taken", &[]).await,
+        )
+        .await?;
+        return Ok(true);
+    }
+
+    ticket_status.taken_by = Some(user_id.to_string());
+    update_thread_status_db(&thread.id, &ticket_status, pool).await?;
+
+    respond_ephemeral(
+        ctx,
+        interaction,
+        translate(
+            config,
+            "board.taken",
+            &[("channel", format!("<#{channel_id}>"))],
+        )
+        .await,
+    )
+    .await?;
+
+    let ctx = ctx.clone();
+    let config = config.clone();
+    tokio::spawn(async move {
+        if let Err(e) = update_thread_status_ui(&ctx, &ticket_status).await {
+            error!("Failed to rename ticket taken from the board: {e:?}");
+        }
+        let mut params = HashMap::new();
+        params.insert("staff".to_string(), format!("<@{user_id}>"));
+        let _ = MessageBuilder::system_message(&ctx, &config)
+            .translated_content("take.confirmation", Some(&params), None, None)
+            .await
+            .to_channel(channel_id)
+            .send(true)
+            .await;
+    });
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticket(id: &str, priority: TicketPriority, last_message_at: i64) -> BoardTicket {
+        BoardTicket {
+            thread_id: id.to_string(),
+            channel_id: "1".to_string(),
+            user_name: id.to_string(),
+            category_name: None,
+            priority,
+            workflow_state: TicketWorkflowState::AwaitingStaff,
+            taken_by: None,
+            last_message_by: TicketAuthor::User,
+            last_message_at,
+            state_changed_at: last_message_at,
+        }
+    }
+
+    #[test]
+    fn board_lists_urgent_then_longest_waiting() {
+        let mut tickets = vec![
+            ticket("recent", TicketPriority::Normal, 200),
+            ticket("urgent", TicketPriority::Urgent, 300),
+            ticket("oldest", TicketPriority::Normal, 100),
+        ];
+        sort_board_tickets(&mut tickets);
+
+        let order: Vec<&str> = tickets.iter().map(|t| t.thread_id.as_str()).collect();
+        assert_eq!(order, vec!["urgent", "oldest", "recent"]);
+    }
+
+    #[test]
+    fn only_tickets_waiting_for_staff_go_past_the_sla() {
+        let waiting = ticket("waiting", TicketPriority::Normal, 100);
+        assert!(!is_past_sla(&waiting, 0, 10_000));
+        assert!(!is_past_sla(&waiting, 60, 159));
+        assert!(is_past_sla(&waiting, 60, 160));
+
+        let on_hold = BoardTicket {
+            workflow_state: TicketWorkflowState::OnHold,
+            ..ticket("on_hold", TicketPriority::Normal, 100)
+        };
+        assert!(!is_past_sla(&on_hold, 60, 10_000));
+
+        let answered = BoardTicket {
+            workflow_state: TicketWorkflowState::AwaitingUser,
+            ..ticket("answered", TicketPriority::Normal, 100)
+        };
+        assert!(!is_past_sla(&answered, 60, 10_000));
+    }
+
+    #[test]
+    fn higher_priorities_get_a_shorter_sla() {
+        let urgent = ticket("urgent", TicketPriority::Urgent, 100);
+        assert!(!is_past_sla(&urgent, 60, 114));
+        assert!(is_past_sla(&urgent, 60, 115));
+
+        let high = ticket("high", TicketPriority::High, 100);
+        assert!(!is_past_sla(&high, 60, 129));
+        assert!(is_past_sla(&high, 60, 130));
+
+        let low = ticket("low", TicketPriority::Low, 100);
+        assert!(!is_past_sla(&low, 60, 219));
+        assert!(is_past_sla(&low, 60, 220));
+    }
+}
@@ -2,52 +2,124 @@ use crate::modules::threads::create_or_get_thread_for_user;
 use crate::prelude::config::*;
 use crate::prelude::db::*;
 use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
 use crate::prelude::utils::*;
 use chrono::Utc;
 use serenity::all::{
-    ButtonStyle, ChannelId, ComponentInteraction, Context, CreateAllowedMentions,
-    CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage, Message,
-    ReactionType, RoleId, UserId,
+    ActionRowComponent, ButtonStyle, ChannelId, Colour, ComponentInteraction,
+    ComponentInteractionDataKind, Context, CreateAllowedMentions, CreateEmbed,
+    CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage, InputTextStyle,
+    Message, ModalInteraction, ReactionType, RoleId, UserId,
+};
+use serenity::builder::{
+    CreateActionRow, CreateButton, CreateInputText, CreateModal, CreateSelectMenu,
+    CreateSelectMenuKind, CreateSelectMenuOption,
 };
-use serenity::builder::{CreateActionRow, CreateButton};
 use std::collections::HashMap;
-use tokio::time::{Duration, sleep};
+use tracing::error;
 
 pub const CATEGORY_BUTTON_MAX_PER_ROW: usize = 5;
 pub const CATEGORY_BUTTON_MAX_ROWS: usize = 5;
+const SELECT_LABEL_MAX_LEN: usize = 100;
+
+struct CategoryChoice {
+    action: String,
+    label: String,
+    description: Option<String>,
+    emoji: Option<String>,
+    primary: bool,
+}
+
+fn truncate(s: &str, max: usize) -> String {
+    s.chars().take(max).collect()
+}
 
 fn build_category_components(
-    categories: &[TicketCategory],
-    default_label: &str,
+    choices: &[CategoryChoice],
+    mode: CategorySelectionMode,
+    placeholder: &str,
 ) -> Vec<CreateActionRow> {
-    let mut buttons: Vec<CreateButton> = Vec::new();
-
-    for cat in categories.iter().take(CATEGORY_BUTTON_HARD_LIMIT) {
-        let mut btn = CreateButton::new(format!("category:pick:{}", cat.id))
-            .label(cat.name.clone())
-            .style(ButtonStyle::Primary);
-        if let Some(emoji) = cat.emoji.as_deref()
-            && let Some(react) = parse_emoji(emoji)
-        {
-            btn = btn.emoji(react);
+    match mode {
+        CategorySelectionMode::Select => {
+            let options = choices
+                .iter()
+                .take(CATEGORY_BUTTON_HARD_LIMIT + 1)
+                .map(|choice| {
+                    let mut option = CreateSelectMenuOption::new(
+                        truncate(&choice.label, SELECT_LABEL_MAX_LEN),
+                        choice.action.clone(),
+                    );
+                    if let Some(desc) = choice.description.as_deref().filter(|d| !d.is_empty()) {
+                        option = option.description(truncate(desc, SELECT_LABEL_MAX_LEN));
+                    }
+                    if let Some(react) = choice.emoji.as_deref().and_then(parse_emoji) {
+                        option = option.emoji(react);
+                    }
+                    option
+                })
+                .collect();
+            let menu =
+                CreateSelectMenu::new("category:select", CreateSelectMenuKind::String { options })
+                    .placeholder(truncate(placeholder, 150));
+            vec![CreateActionRow::SelectMenu(menu)]
+        }
+        CategorySelectionMode::Buttons => {
+            let buttons: Vec<CreateButton> = choices
+                .iter()
+                .take(CATEGORY_BUTTON_HARD_LIMIT + 1)
+                .map(|choice| {
+                    let style = if choice.primary {
+                        ButtonStyle::Primary
+                    } else {
+                        ButtonStyle::Secondary
+                    };
+                    let mut btn = CreateButton::new(format!("category:{}", choice.action))
+                        .label(truncate(&choice.label, 80))
+                        .style(style);
+                    if let Some(react) = choice.emoji.as_deref().and_then(parse_emoji) {
+                        btn = btn.emoji(react);
+                    }
+                    btn
+                })
+                .collect();
+
+            let mut rows: Vec<CreateActionRow> = Vec::new();
+            for chunk in buttons.chunks(CATEGORY_BUTTON_MAX_PER_ROW) {
+                if rows.len() >= CATEGORY_BUTTON_MAX_ROWS {
+                    break;
+                }
+                rows.push(CreateActionRow::Buttons(chunk.to_vec()));
+            }
+            rows
         }
-        buttons.push(btn);
     }
+}
 
-    buttons.push(
-        CreateButton::new("category:default")
-            .label(default_label.to_string())
-            .style(ButtonStyle::Secondary),
-    );
-
-    let mut rows: Vec<CreateActionRow> = Vec::new();
-    for chunk in buttons.chunks(CATEGORY_BUTTON_MAX_PER_ROW) {
-        if rows.len() >= CATEGORY_BUTTON_MAX_ROWS {
-            break;
-        }
-        rows.push(CreateActionRow::Buttons(chunk.to_vec()));
+fn category_choice(cat: &TicketCategory, verb: &str) -> CategoryChoice {
+    CategoryChoice {
+        action: format!("{}:{}", verb, cat.id),
+        label: cat.name.clone(),
+        description: cat.description.clone(),
+        emoji: cat.emoji.clone(),
+        primary: true,
     }
-    rows
+}
+
+fn top_level_choices(categories: &[TicketCategory], default_label: &str) -> Vec<CategoryChoice> {
+    let mut choices: Vec<CategoryChoice> = categories
+        .iter()
+        .filter(|c| c.parent_id.is_none())
+        .take(CATEGORY_BUTTON_HARD_LIMIT)
+        .map(|c| category_choice(c, "pick"))
+        .collect();
+    choices.push(CategoryChoice {
+        action: "default".to_string(),
+        label: default_label.to_string(),
+        description: None,
+        emoji: None,
+        primary: false,
+    });
+    choices
 }
 
 fn parse_emoji(raw: &str) -> Option<ReactionType> {
@@ -76,7 +148,7 @@ pub async fn maybe_start_category_selection(ctx: &Context, config: &Config, msg:
         match append_queued_message(msg.author.id.get() as i64, &msg.id.to_string(), pool).await {
             Ok(_) => return true,
             Err(err) => {
-                eprintln!(
+                error!(
                     "failed to append queued message for user {} and message {}: {}",
                     msg.author.id.get(),
                     msg.id,
@@ -91,7 +163,7 @@ pub async fn maybe_start_category_selection(ctx: &Context, config: &Config, msg:
         Ok(c) => c,
         Err(_) => return false,
     };
-    if categories.is_empty() {
+    if !categories.iter().any(|c| c.parent_id.is_none()) {
         return false;
     }
 
@@ -109,7 +181,21 @@ pub async fn maybe_start_category_selection(ctx: &Context, config: &Config, msg:
     )
     .await;
 
-    let components = build_category_components(&categories, &default_label);
+    let placeholder = get_translated_message(
+        config,
+        "category.select_placeholder",
+        None,
+        Some(msg.author.id),
+        None,
+        None,
+    )
+    .await;
+
+    let components = build_category_components(
+        &top_level_choices(&categories, &default_label),
+        settings.selection_mode,
+        &placeholder,
+    );
 
     let sent = MessageBuilder::system_message(ctx, config)
         .translated_content(
@@ -127,7 +213,7 @@ pub async fn maybe_start_category_selection(ctx: &Context, config: &Config, msg:
     let prompt = match sent {
         Ok(m) => m,
         Err(e) => {
-            eprintln!("Failed to send category prompt: {e:?}");
+            error!("Failed to send category prompt: {e:?}");
             return false;
         }
     };
@@ -147,40 +233,36 @@ pub async fn maybe_start_category_selection(ctx: &Context, config: &Config, msg:
     )
     .await
     {
-        eprintln!("Failed to persist pending selection: {e:?}");
+        error!("Failed to persist pending selection: {e:?}");
         return false;
     }
 
-    schedule_category_timeout(ctx, config, msg.author.id.get() as i64, expires_at);
     true
 }
 
-pub fn schedule_category_timeout(ctx: &Context, config: &Config, user_id: i64, expires_at: i64) {
-    let ctx_clone = ctx.clone();
-    let config_clone = config.clone();
-    let now = Utc::now().timestamp();
-    let delay = (expires_at - now).max(0) as u64;
+pub async fn run_category_timeout_job(ctx: &Context, config: &Config, key: &str) -> JobResult {
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Err("Database pool is not set in config".to_string());
+    };
 
-    tokio::spawn(async move {
-        if delay > 0 {
-            sleep(Duration::from_secs(delay)).await;
-        }
-        let pool = match config_clone.db_pool.as_ref() {
-            Some(p) => p,
-            None => return,
-        };
-        if let Ok(Some(pending)) = get_pending_selection(user_id, pool).await {
-            if pending.expires_at <= Utc::now().timestamp() {
-                if let Err(e) =
-                    finalize_with_category(&ctx_clone, &config_clone, user_id, None).await
-                {
-                    eprintln!("Failed to finalize expired selection: {e:?}");
-                }
-            } else {
-                schedule_category_timeout(&ctx_clone, &config_clone, user_id, pending.expires_at);
-            }
-        }
-    });
+    let user_id = key
+        .parse::<i64>()
+        .map_err(|_| format!("Invalid user id '{}'", key))?;
+
+    let pending = match get_pending_selection(user_id, pool).await {
+        Ok(Some(p)) => p,
+        Ok(None) => return Ok(JobOutcome::Done),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    if pending.expires_at > Utc::now().timestamp() {
+        return Ok(JobOutcome::RunAt(pending.expires_at));
+    }
+
+    finalize_with_category(ctx, config, user_id, None, None)
+        .await
+        .map_err(|e| e.to_string())?;
+    Ok(JobOutcome::Done)
 }
 
 pub async fn finalize_with_category(
@@ -188,6 +270,7 @@ pub async fn finalize_with_category(
     config: &Config,
     user_id: i64,
     category_id: Option<&str>,
+    answers: Option<&[FormAnswer]>,
 ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
     let pool = match &config.db_pool {
         Some(p) => p,
@@ -208,7 +291,7 @@ pub async fn finalize_with_category(
                 (parent, Some(cat.id.clone()))
             }
             Some(cat) => {
-                eprintln!(
+                error!(
                     "Selected category {} is disabled; falling back to default inbox",
                     cat.id
                 );
@@ -220,12 +303,23 @@ pub async fn finalize_with_category(
         (None, None)
     };
 
+    let dm_channel = ChannelId::new(pending.dm_channel_id.parse::<u64>().unwrap_or(0));
+    let mut queued = Vec::with_capacity(pending.queued_msg_ids.len());
+    for mid in &pending.queued_msg_ids {
+        if let Ok(id_u64) = mid.parse::<u64>()
+            && let Ok(m) = dm_channel.message(&ctx.http, id_u64).await
+        {
+            queued.push(m);
+        }
+    }
+
     let (target_channel_id, is_new) = create_or_get_thread_for_user(
         ctx,
         config,
         user,
         discord_override,
         ticket_cat_id.as_deref(),
+        queued.first().map(|m| m.content.as_str()),
     )
     .await?;
 
@@ -233,16 +327,20 @@ pub async fn finalize_with_category(
         && let Some(cat_id) = ticket_cat_id.as_deref()
         && let Err(e) = mention_category_roles(ctx, pool, target_channel_id, cat_id).await
     {
-        eprintln!("Failed to mention category roles: {e:?}");
+        error!("Failed to mention category roles: {e:?}");
     }
 
-    let dm_channel = ChannelId::new(pending.dm_channel_id.parse::<u64>().unwrap_or(0));
-    for mid in &pending.queued_msg_ids {
-        if let Ok(id_u64) = mid.parse::<u64>()
-            && let Ok(m) = dm_channel.message(&ctx.http, id_u64).await
-            && let Err(e) = send_to_thread(ctx, target_channel_id, &m, config, false).await
-        {
-            eprintln!("Failed to forward queued DM message: {e:?}");
+    if let (Some(cat_id), Some(answers)) = (ticket_cat_id.as_deref(), answers)
+        && !answers.is_empty()
+        && let Err(e) =
+            post_form_answers(ctx, config, pool, target_channel_id, cat_id, answers).await
+    {
+        error!("Failed to post intake form answers: {e:?}");
+    }
+
+    for m in &queued {
+        if let Err(e) = send_to_thread(ctx, target_channel_id, m, config, false).await {
+            error!("Failed to forward queued DM message: {e:?}");
         }
     }
 
@@ -253,39 +351,325 @@ pub async fn finalize_with_category(
     Ok(())
 }
 
+fn clear_components() -> CreateInteractionResponse {
+    CreateInteractionResponse::UpdateMessage(
+        CreateInteractionResponseMessage::new().components(vec![]),
+    )
+}
+
+fn subcategory_picker<'a>(
+    categories: &'a [TicketCategory],
+    id: &str,
+) -> Option<(&'a TicketCategory, Vec<TicketCategory>)> {
+    let parent = categories.iter().find(|c| c.id == id)?;
+    let children: Vec<TicketCategory> = categories
+        .iter()
+        .filter(|c| c.parent_id.as_deref() == Some(id))
+        .cloned()
+        .collect();
+    (!children.is_empty()).then_some((parent, children))
+}
+
+fn is_selectable(categories: &[TicketCategory], picked_parent: Option<&str>, id: &str) -> bool {
+    let Some(category) = categories.iter().find(|c| c.id == id) else {
+        return false;
+    };
+    match category.parent_id.as_deref() {
+        Some(parent_id) => picked_parent == Some(parent_id),
+        None => {
+            picked_parent == Some(id)
+                || !categories
+                    .iter()
+                    .any(|c| c.parent_id.as_deref() == Some(id))
+        }
+    }
+}
+
+async fn subcategory_components(
+    config: &Config,
+    mode: CategorySelectionMode,
+    parent: &TicketCategory,
+    children: &[TicketCategory],
+    user_id: UserId,
+) -> Vec<CreateActionRow> {
+    let mut params = HashMap::new();
+    params.insert("name".to_string(), parent.name.clone());
+
+    let mut choices: Vec<CategoryChoice> = children
+        .iter()
+        .take(CATEGORY_BUTTON_HARD_LIMIT)
+        .map(|c| category_choice(c, "choose"))
+        .collect();
+    choices.push(CategoryChoice {
+        label: get_translated_message(
+            config,
+            "category.general_option_label",
+            Some(&params),
+            Some(user_id),
+            None,
+            None,
+        )
+        .await,
+        primary: false,
+        ..category_choice(parent, "choose")
+    });
+
+    let placeholder = get_translated_message(
+        config,
+        "category.subcategory_placeholder",
+        Some(&params),
+        Some(user_id),
+        None,
+        None,
+    )
+    .await;
+
+    build_category_components(&choices, mode, &placeholder)
+}
+
+fn form_modal(form: &CategoryForm) -> CreateModal {
+    let rows = form
+        .questions
+        .iter()
+        .take(CATEGORY_FORM_MAX_QUESTIONS)
+        .enumerate()
+        .map(|(i, q)| {
+            let style = if q.paragraph {
+                InputTextStyle::Paragraph
+            } else {
+                InputTextStyle::Short
+            };
+            let mut input = CreateInputText::new(style, truncate(&q.label, 45), format!("q{}", i))
+                .required(q.required);
+            if let Some(placeholder) = q.placeholder.as_deref() {
+                input = input.placeholder(truncate(placeholder, 100));
+            }
+            if let Some(max) = q.max_length {
+                input = input.max_length(max);
+            }
+            CreateActionRow::InputText(input)
+        })
+        .collect();
+
+    CreateModal::new(
+        format!("category:form:{}", form.category_id),
+        truncate(&form.title, 45),
+    )
+    .components(rows)
+}
+
 pub async fn handle_category_component_interaction(
     ctx: &Context,
     config: &Config,
     interaction: &ComponentInteraction,
 ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
     let custom_id = &interaction.data.custom_id;
-    if !custom_id.starts_with("category:") {
+    let Some(action) = custom_id.strip_prefix("category:") else {
         return Ok(false);
-    }
+    };
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Ok(true);
+    };
 
-    let _ = interaction
-        .create_response(
-            &ctx.http,
-            CreateInteractionResponse::UpdateMessage(
-                CreateInteractionResponseMessage::new().components(vec![]),
-            ),
-        )
-        .await;
+    let action = if action == "select" {
+        match &interaction.data.kind {
+            ComponentInteractionDataKind::StringSelect { values } if !values.is_empty() => {
+                values[0].clone()
+            }
+            _ => return Ok(true),
+        }
+    } else {
+        action.to_string()
+    };
 
     let user_id = interaction.user.id.get() as i64;
 
-    let category_id: Option<String> = if custom_id == "category:default" {
+    let Some(pending) = get_pending_selection(user_id, pool).await? else {
+        let _ = interaction
+            .create_response(&ctx.http, clear_components())
+            .await;
+        return Ok(true);
+    };
+
+    let categories = list_enabled_categories(pool).await?;
+    let mut picked_parent = pending.picked_parent_id;
+    let category_id = if action == "default" {
         None
-    } else if let Some(rest) = custom_id.strip_prefix("category:pick:") {
-        Some(rest.to_string())
+    } else if let Some(id) = action.strip_prefix("pick:") {
+        if let Some((parent, children)) = subcategory_picker(&categories, id)
+            && parent.parent_id.is_none()
+        {
+            set_pending_parent(user_id, id, pool).await?;
+            let settings = get_category_settings(pool).await?;
+            let components = subcategory_components(
+                config,
+                settings.selection_mode,
+                parent,
+                &children,
+                interaction.user.id,
+            )
+            .await;
+            interaction
+                .create_response(
+                    &ctx.http,
+                    CreateInteractionResponse::UpdateMessage(
+                        CreateInteractionResponseMessage::new().components(components),
+                    ),
+                )
+                .await?;
+            return Ok(true);
+        }
+        picked_parent = None;
+        Some(id.to_string())
+    } else if let Some(id) = action.strip_prefix("choose:") {
+        Some(id.to_string())
     } else {
         return Ok(true);
     };
 
-    finalize_with_category(ctx, config, user_id, category_id.as_deref()).await?;
+    if let Some(id) = category_id.as_deref()
+        && !is_selectable(&categories, picked_parent.as_deref(), id)
+    {
+        let _ = interaction
+            .create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+            .await;
+        return Ok(true);
+    }
+
+    if let Some(id) = category_id.as_deref()
+        && let Some(form) = get_category_form(id, pool).await?
+        && !form.questions.is_empty()
+    {
+        interaction
+            .create_response(
+                &ctx.http,
+                CreateInteractionResponse::Modal(form_modal(&form)),
+            )
+            .await?;
+        return Ok(true);
+    }
+
+    let _ = interaction
+        .create_response(&ctx.http, clear_components())
+        .await;
+
+    finalize_with_category(ctx, config, user_id, category_id.as_deref(), None).await?;
+    Ok(true)
+}
+
+fn modal_answers(interaction: &ModalInteraction, form: &CategoryForm) -> Vec<FormAnswer> {
+    let values: HashMap<&str, &str> = interaction
+        .data
+        .components
+        .iter()
+        .flat_map(|row| row.components.iter())
+        .filter_map(|comp| match comp {
+            ActionRowComponent::InputText(input) => input
+                .value
+                .as_deref()
+                .map(|v| (input.custom_id.as_str(), v.trim())),
+            _ => None,
+        })
+        .collect();
+
+    form.questions
+        .iter()
+        .enumerate()
+        .map(|(i, q)| FormAnswer {
+            question: q.label.clone(),
+            answer: values
+                .get(format!("q{}", i).as_str())
+                .copied()
+                .unwrap_or_default()
+                .to_string(),
+        })
+        .collect()
+}
+
+pub async fn handle_category_modal_interaction(
+    ctx: &Context,
+    config: &Config,
+    interaction: &ModalInteraction,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let Some(category_id) = interaction.data.custom_id.strip_prefix("category:form:") else {
+        return Ok(false);
+    };
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Ok(true);
+    };
+
+    let user_id = interaction.user.id.get() as i64;
+    let picked_parent = get_pending_selection(user_id, pool)
+        .await?
+        .and_then(|pending| pending.picked_parent_id);
+    let categories = list_enabled_categories(pool).await?;
+    if !is_selectable(&categories, picked_parent.as_deref(), category_id) {
+        let _ = interaction
+            .create_response(&ctx.http, CreateInteractionResponse::Acknowledge)
+            .await;
+        return Ok(true);
+    }
+
+    let _ = interaction
+        .create_response(&ctx.http, clear_components())
+        .await;
+
+    let answers = match get_category_form(category_id, pool).await? {
+        Some(form) => modal_answers(interaction, &form),
+        None => Vec::new(),
+    };
+
+    finalize_with_category(ctx, config, user_id, Some(category_id), Some(&answers)).await?;
     Ok(true)
 }
 
+async fn post_form_answers(
+    ctx: &Context,
+    config: &Config,
+    pool: &sqlx::SqlitePool,
+    channel_id: ChannelId,
+    category_id: &str,
+    answers: &[FormAnswer],
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    if let Some(thread) = get_thread_by_channel_id(&channel_id.to_string(), pool).await {
+        save_thread_form_answers(&thread.id, category_id, answers, pool).await?;
+    }
+
+    let category_name = get_category_by_id(category_id, pool)
+        .await?
+        .map(|c| c.name)
+        .unwrap_or_default();
+    let mut params = HashMap::new();
+    params.insert("category".to_string(), category_name);
+    let title = get_translated_message(
+        config,
+        "category.form_summary_title",
+        Some(&params),
+        None,
+        None,
+        None,
+    )
+    .await;
+    let no_answer =
+        get_translated_message(config, "category.form_no_answer", None, None, None, None).await;
+
+    let mut embed = CreateEmbed::new().title(title).color(Colour::new(0x5865F2));
+    for answer in answers {
+        let value = if answer.answer.is_empty() {
+            no_answer.clone()
+        } else {
+            truncate(&answer.answer, 1024)
+        };
+        embed = embed.field(truncate(&answer.question, 256), value, false);
+    }
+
+    let sent = channel_id
+        .send_message(&ctx.http, CreateMessage::new().embed(embed))
+        .await?;
+    sent.pin(&ctx.http).await?;
+    Ok(())
+}
+
 async fn mention_category_roles(
     ctx: &Context,
     pool: &sqlx::SqlitePool,
@@ -319,24 +703,290 @@ async fn mention_category_roles(
     Ok(())
 }
 
-pub async fn hydrate_pending_category_selections(ctx: &Context, config: &Config) {
-    let Some(pool) = config.db_pool.as_ref() else {
-        return;
-    };
-    let list = match list_all_pending_selections(pool).await {
-        Ok(l) => l,
-        Err(e) => {
-            eprintln!("Failed to load pending category selections: {e:?}");
-            return;
+#[derive(Debug, Default)]
+pub struct CategoryMessages {
+    pub welcome_message: Option<String>,
+    pub close_message: Option<String>,
+    pub auto_reply: Option<String>,
+    pub color: Option<u32>,
+    pub category_name: Option<String>,
+}
+
+impl CategoryMessages {
+    pub fn welcome_message(&self, config: &Config, user_id: UserId) -> String {
+        self.message_or(&self.welcome_message, &config.bot.welcome_message, user_id)
+    }
+
+    pub fn close_message(&self, config: &Config, user_id: UserId) -> String {
+        self.message_or(&self.close_message, &config.bot.close_message, user_id)
+    }
+
+    pub fn auto_reply(&self, user_id: UserId) -> Option<String> {
+        self.auto_reply
+            .as_deref()
+            .map(|message| self.fill_placeholders(message, user_id))
+    }
+
+    pub fn apply_color<'a>(&self, builder: MessageBuilder<'a>) -> MessageBuilder<'a> {
+        match self.color {
+            Some(color) => builder.color(color),
+            None => builder,
         }
+    }
+
+    fn message_or(&self, message: &Option<String>, default: &str, user_id: UserId) -> String {
+        self.fill_placeholders(message.as_deref().unwrap_or(default), user_id)
+    }
+
+    fn fill_placeholders(&self, message: &str, user_id: UserId) -> String {
+        message
+            .replace("{user}", &format!("<@{}>", user_id))
+            .replace("{category}", self.category_name.as_deref().unwrap_or(""))
+    }
+}
+
+// The variant in `language`, then the catch-all one; blank messages count as unset.
+fn select_variant_messages(
+    variants: &[CategoryMessageVariant],
+    language: &str,
+) -> CategoryMessages {
+    let exact = variants.iter().find(|v| v.language == language);
+    let fallback = variants.iter().find(|v| v.language.is_empty());
+    let pick = |field: fn(&CategoryMessageVariant) -> &Option<String>| {
+        exact
+            .and_then(|v| field(v).clone())
+            .or_else(|| fallback.and_then(|v| field(v).clone()))
+            .filter(|m| !m.trim().is_empty())
     };
-    for pending in list {
-        if pending.expires_at <= Utc::now().timestamp() {
-            if let Err(e) = finalize_with_category(ctx, config, pending.user_id, None).await {
-                eprintln!("Failed to hydrate-finalize pending selection: {e:?}");
-            }
-        } else {
-            schedule_category_timeout(ctx, config, pending.user_id, pending.expires_at);
+
+    CategoryMessages {
+        welcome_message: pick(|v| &v.welcome_message),
+        close_message: pick(|v| &v.close_message),
+        auto_reply: pick(|v| &v.auto_reply),
+        ..Default::default()
+    }
+}
+
+pub async fn category_messages_for_user(
+    config: &Config,
+    category_id: Option<&str>,
+    user_id: UserId,
+) -> CategoryMessages {
+    let (Some(pool), Some(category_id)) = (&config.db_pool, category_id) else {
+        return CategoryMessages::default();
+    };
+
+    let category = get_category_by_id(category_id, pool).await.ok().flatten();
+    let color = category
+        .as_ref()
+        .and_then(|cat| cat.embed_color.as_deref())
+        .map(|c| hex_string_to_int(c) as u32);
+    let category_name = category.map(|cat| cat.name);
+
+    let variants = list_category_message_variants(category_id, pool)
+        .await
+        .unwrap_or_default();
+    if variants.is_empty() {
+        return CategoryMessages {
+            color,
+            category_name,
+            ..Default::default()
+        };
+    }
+
+    let language = match &config.error_handler {
+        Some(handler) => handler.get_user_language(user_id, None).await,
+        None => config.language.get_default_language(),
+    };
+
+    CategoryMessages {
+        color,
+        category_name,
+        ..select_variant_messages(&variants, language.code())
+    }
+}
+
+pub async fn category_messages_for_thread(
+    config: &Config,
+    thread_id: &str,
+    user_id: UserId,
+) -> CategoryMessages {
+    let category_id = match &config.db_pool {
+        Some(pool) => get_thread_ticket_category_id(thread_id, pool)
+            .await
+            .ok()
+            .flatten(),
+        None => None,
+    };
+    category_messages_for_user(config, category_id.as_deref(), user_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn category(id: &str, parent_id: Option<&str>) -> TicketCategory {
+        TicketCategory {
+            id: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            emoji: None,
+            discord_category_id: "1".to_string(),
+            position: 0,
+            enabled: true,
+            parent_id: parent_id.map(str::to_string),
+            embed_color: None,
+            default_priority: None,
+            created_at: 0,
+            updated_at: 0,
+        }
+    }
+
+    fn variant(
+        language: &str,
+        welcome_message: Option<&str>,
+        close_message: Option<&str>,
+        auto_reply: Option<&str>,
+    ) -> CategoryMessageVariant {
+        CategoryMessageVariant {
+            language: language.to_string(),
+            welcome_message: welcome_message.map(str::to_string),
+            close_message: close_message.map(str::to_string),
+            auto_reply: auto_reply.map(str::to_string),
         }
     }
+
+    #[test]
+    fn messages_come_from_the_language_then_the_catch_all_variant() {
+        let variants = [
+            variant(
+                "",
+                Some("Welcome"),
+                Some("Bye"),
+                Some("Include your order ID"),
+            ),
+            variant("fr", Some("Bienvenue"), None, Some("  ")),
+        ];
+
+        let fr = select_variant_messages(&variants, "fr");
+        assert_eq!(fr.welcome_message.as_deref(), Some("Bienvenue"));
+        assert_eq!(fr.close_message.as_deref(), Some("Bye"));
+        assert_eq!(fr.auto_reply, None);
+
+        let de = select_variant_messages(&variants, "de");
+        assert_eq!(de.welcome_message.as_deref(), Some("Welcome"));
+        assert_eq!(de.auto_reply.as_deref(), Some("Include your order ID"));
+    }
+
+    #[test]
+    fn unset_messages_fall_back_to_the_configuration() {
+        let user_id = UserId::new(42);
+
+        let messages = select_variant_messages(&[variant("fr", None, None, None)], "en");
+        assert_eq!(
+            messages.message_or(&messages.welcome_message, "Hello {user}", user_id),
+            "Hello <@42>"
+        );
+        assert_eq!(messages.auto_reply(user_id), None);
+
+        let overridden = CategoryMessages {
+            close_message: Some("Billing closed".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            overridden.message_or(&overridden.close_message, "Closed", user_id),
+            "Billing closed"
+        );
+    }
+
+    #[test]
+    fn placeholders_are_filled_in_every_message() {
+        let user_id = UserId::new(42);
+        let messages = CategoryMessages {
+            welcome_message: Some("Hi {user}, welcome to {category}".to_string()),
+            auto_reply: Some("{category}: include your order ID, {user}".to_string()),
+            category_name: Some("Billing".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            messages.message_or(&messages.welcome_message, "", user_id),
+            "Hi <@42>, welcome to Billing"
+        );
+        assert_eq!(
+            messages.auto_reply(user_id).as_deref(),
+            Some("Billing: include your order ID, <@42>")
+        );
+        assert_eq!(
+            CategoryMessages::default().fill_placeholders("{category}|{user}", user_id),
+            "|<@42>"
+        );
+    }
+
+    #[test]
+    fn top_level_picker_lists_parents_then_the_default_inbox() {
+        let categories = [
+            category("billing", None),
+            category("refunds", Some("billing")),
+            category("support", None),
+        ];
+
+        let actions: Vec<String> = top_level_choices(&categories, "Other")
+            .into_iter()
+            .map(|c| c.action)
+            .collect();
+        assert_eq!(actions, ["pick:billing", "pick:support", "default"]);
+    }
+
+    #[test]
+    fn picking_a_parent_offers_its_children() {
+        let categories = [
+            category("billing", None),
+            category("refunds", Some("billing")),
+            category("invoices", Some("billing")),
+            category("support", None),
+            category("bugs", Some("support")),
+        ];
+
+        let (parent, children) = subcategory_picker(&categories, "billing").unwrap();
+        assert_eq!(parent.id, "billing");
+        assert_eq!(
+            children.iter().map(|c| c.id.as_str()).collect::<Vec<_>>(),
+            ["refunds", "invoices"]
+        );
+    }
+
+    #[test]
+    fn categories_without_children_are_chosen_directly() {
+        let categories = [
+            category("billing", None),
+            category("refunds", Some("billing")),
+            category("other", None),
+        ];
+
+        assert!(subcategory_picker(&categories, "other").is_none());
+        assert!(subcategory_picker(&categories, "refunds").is_none());
+        assert!(subcategory_picker(&categories, "unknown").is_none());
+    }
+
+    #[test]
+    fn only_the_picked_parent_and_its_children_can_be_chosen() {
+        let categories = [
+            category("billing", None),
+            category("refunds", Some("billing")),
+            category("support", None),
+            category("bugs", Some("support")),
+            category("other", None),
+        ];
+
+        assert!(is_selectable(&categories, None, "other"));
+        assert!(!is_selectable(&categories, None, "billing"));
+        assert!(!is_selectable(&categories, None, "refunds"));
+
+        assert!(is_selectable(&categories, Some("billing"), "billing"));
+        assert!(is_selectable(&categories, Some("billing"), "refunds"));
+        assert!(!is_selectable(&categories, Some("billing"), "bugs"));
+        assert!(!is_selectable(&categories, Some("billing"), "support"));
+        assert!(!is_selectable(&categories, Some("billing"), "unknown"));
+    }
 }
@@ -0,0 +1,311 @@
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use serenity::all::{
+    ChannelId, Context, EditChannel, GuildId, Http, PermissionOverwrite, PermissionOverwriteType,
+    Permissions, Role, RoleId, UserId,
+};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::error;
+
+fn ticket_permissions() -> Permissions {
+    Permissions::VIEW_CHANNEL
+        | Permissions::SEND_MESSAGES
+        | Permissions::READ_MESSAGE_HISTORY
+        | Permissions::ATTACH_FILES
+        | Permissions::EMBED_LINKS
+}
+
+pub struct StaffIdentity {
+    pub user_id: UserId,
+    pub role_ids: Vec<RoleId>,
+    pub is_admin: bool,
+}
+
+impl StaffIdentity {
+    pub async fn resolve(ctx: &Context, guild_id: GuildId, user_id: UserId) -> Option<Self> {
+        let cached = ctx.cache.guild(guild_id).and_then(|guild| {
+            let member = guild.members.get(&user_id)?;
+            Some(Self::from_member(
+                user_id,
+                member.roles.clone(),
+                guild.owner_id,
+                &guild.roles,
+            ))
+        });
+        match cached {
+            Some(identity) => Some(identity),
+            None => Self::fetch(&ctx.http, guild_id, user_id).await,
+        }
+    }
+
+    pub async fn fetch(http: &Arc<Http>, guild_id: GuildId, user_id: UserId) -> Option<Self> {
+        let guild = guild_id.to_partial_guild(http).await.ok()?;
+        let member = guild_id.member(http, user_id).await.ok()?;
+
+        Some(Self::from_member(
+            user_id,
+            member.roles,
+            guild.owner_id,
+            &guild.roles,
+        ))
+    }
+
+    fn from_member(
+        user_id: UserId,
+        role_ids: Vec<RoleId>,
+        owner_id: UserId,
+        guild_roles: &HashMap<RoleId, Role>,
+    ) -> Self {
+        let is_admin = owner_id == user_id
+            || role_ids.iter().any(|role_id| {
+                guild_roles
+                    .get(role_id)
+                    .is_some_and(|role| role.permissions.contains(Permissions::ADMINISTRATOR))
+            });
+
+        Self {
+            user_id,
+            role_ids,
+            is_admin,
+        }
+    }
+
+    pub fn can_access(&self, access: &AccessList) -> bool {
+        if self.is_admin || access.is_empty() {
+            return true;
+        }
+        access.user_ids.contains(&self.user_id.to_string())
+            || self
+                .role_ids
+                .iter()
+                .any(|r| access.role_ids.contains(&r.to_string()))
+    }
+}
+
+pub async fn inaccessible_category_ids(
+    identity: &StaffIdentity,
+    pool: &sqlx::SqlitePool,
+) -> ModmailResult<Vec<String>> {
+    if identity.is_admin {
+        return Ok(Vec::new());
+    }
+    Ok(list_category_access(pool)
+        .await?
+        .into_iter()
+        .filter(|(_, access)| !identity.can_access(access))
+        .map(|(id, _)| id)
+        .collect())
+}
+
+pub async fn can_access_ticket_channel(
+    config: &Config,
+    channel_id: ChannelId,
+    identity: Option<&StaffIdentity>,
+) -> bool {
+    let Some(pool) = &config.db_pool else {
+        return true;
+    };
+    let Some(thread) = get_thread_by_channel_id(&channel_id.to_string(), pool).await else {
+        return true;
+    };
+    let Ok(Some(category_id)) = get_thread_ticket_category_id(&thread.id, pool).await else {
+        return true;
+    };
+    let access = match get_category_access(&category_id, pool).await {
+        Ok(access) => access,
+        Err(_) => return false,
+    };
+    if access.is_empty() {
+        return true;
+    }
+
+    identity.is_some_and(|identity| identity.can_access(&access))
+}
+
+pub fn restrict_overwrites(
+    inherited: Vec<PermissionOverwrite>,
+    access: &AccessList,
+    everyone: RoleId,
+    bot_id: UserId,
+) -> Vec<PermissionOverwrite> {
+    let allowed_roles: Vec<RoleId> = access
+        .role_ids
+        .iter()
+        .filter_map(|id| id.parse::<u64>().ok().map(RoleId::new))
+        .collect();
+    let mut allowed_users: Vec<UserId> = access
+        .user_ids
+        .iter()
+        .filter_map(|id| id.parse::<u64>().ok().map(UserId::new))
+        .collect();
+    allowed_users.push(bot_id);
+
+    let is_allowed = |kind: &PermissionOverwriteType| match kind {
+        PermissionOverwriteType::Role(id) => allowed_roles.contains(id),
+        PermissionOverwriteType::Member(id) => allowed_users.contains(id),
+        _ => false,
+    };
+
+    let mut overwrites: Vec<PermissionOverwrite> = inherited
+        .into_iter()
+        .map(|mut o| {
+            if is_allowed(&o.kind) {
+                o.allow |= ticket_permissions();
+                o.deny &= !ticket_permissions();
+            } else {
+                o.allow &= !Permissions::VIEW_CHANNEL;
+                o.deny |= Permissions::VIEW_CHANNEL;
+            }
+            o
+        })
+        .collect();
+
+    let missing = allowed_roles
+        .iter()
+        .map(|id| PermissionOverwriteType::Role(*id))
+        .chain(
+            allowed_users
+                .iter()
+                .map(|id| PermissionOverwriteType::Member(*id)),
+        )
+        .chain(std::iter::once(PermissionOverwriteType::Role(everyone)))
+        .filter(|kind| !overwrites.iter().any(|o| o.kind == *kind))
+        .collect::<Vec<_>>();
+
+    for kind in missing {
+        let (allow, deny) = if kind == PermissionOverwriteType::Role(everyone) {
+            (Permissions::empty(), Permissions::VIEW_CHANNEL)
+        } else {
+            (ticket_permissions(), Permissions::empty())
+        };
+        overwrites.push(PermissionOverwrite { allow, deny, kind });
+    }
+
+    overwrites
+}
+
+pub async fn apply_category_access(
+    ctx: &Context,
+    config: &Config,
+    channel_id: ChannelId,
+    category_id: Option<&str>,
+) -> ModmailResult<()> {
+    let pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+    let Some(category_id) = category_id else {
+        return Ok(());
+    };
+    let access = get_category_access(category_id, pool).await?;
+    if access.is_empty() {
+        return Ok(());
+    }
+
+    let channel = channel_id
+        .to_channel(&ctx.http)
+        .await?
+        .guild()
+        .ok_or_else(|| validation_failed("Ticket channel is not a guild channel"))?;
+    let everyone = RoleId::new(channel.guild_id.get());
+    let bot_id = ctx.cache.current_user().id;
+
+    let overwrites = restrict_overwrites(channel.permission_overwrites, &access, everyone, bot_id);
+    channel_id
+        .edit(&ctx.http, EditChannel::new().permissions(overwrites))
+        .await?;
+    Ok(())
+}
+
+pub async fn sync_ticket_category_after_move(
+    ctx: &Context,
+    config: &Config,
+    channel_id: ChannelId,
+    discord_category_id: ChannelId,
+) {
+    let Some(pool) = &config.db_pool else {
+        return;
+    };
+    let Some(thread) = get_thread_by_channel_id(&channel_id.to_string(), pool).await else {
+        return;
+    };
+    let current = get_thread_ticket_category_id(&thread.id, pool)
+        .await
+        .ok()
+        .flatten();
+    let categories = list_all_categories(pool).await.unwrap_or_default();
+    let target = discord_category_id.to_string();
+
+    let new_category = categories
+        .iter()
+        .filter(|c| c.discord_category_id == target)
+        .find(|c| Some(&c.id) == current.as_ref())
+        .or_else(|| categories.iter().find(|c| c.discord_category_id == target))
+        .map(|c| c.id.clone());
+
+    if new_category != current
+        && let Err(e) = set_thread_category(&thread.id, new_category.as_deref(), pool).await
+    {
+        error!("Failed to update ticket category after move: {e:?}");
+    }
+
+    if let Err(e) = apply_category_access(ctx, config, channel_id, new_category.as_deref()).await {
+        error!("Failed to apply category access after move: {e:?}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overwrite(kind: PermissionOverwriteType, allow: Permissions) -> PermissionOverwrite {
+        PermissionOverwrite {
+            allow,
+            deny: Permissions::empty(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn restrict_overwrites_hides_channel_from_unlisted_staff() {
+        let everyone = RoleId::new(1);
+        let listed = RoleId::new(2);
+        let unlisted = RoleId::new(3);
+        let bot = UserId::new(9);
+        let access = AccessList {
+            role_ids: vec![listed.to_string()],
+            user_ids: Vec::new(),
+        };
+        let inherited = vec![
+            overwrite(
+                PermissionOverwriteType::Role(unlisted),
+                Permissions::VIEW_CHANNEL,
+            ),
+            overwrite(PermissionOverwriteType::Role(listed), Permissions::empty()),
+        ];
+
+        let result = restrict_overwrites(inherited, &access, everyone, bot);
+        let find = |kind: PermissionOverwriteType| result.iter().find(|o| o.kind == kind).unwrap();
+
+        let unlisted = find(PermissionOverwriteType::Role(unlisted));
+        assert!(unlisted.deny.contains(Permissions::VIEW_CHANNEL));
+        assert!(!unlisted.allow.contains(Permissions::VIEW_CHANNEL));
+        assert!(
+            find(PermissionOverwriteType::Role(listed))
+                .allow
+                .contains(Permissions::VIEW_CHANNEL)
+        );
+        assert!(
+            find(PermissionOverwriteType::Member(bot))
+                .allow
+                .contains(Permissions::VIEW_CHANNEL)
+        );
+        assert!(
+            find(PermissionOverwriteType::Role(everyone))
+                .deny
+                .contains(Permissions::VIEW_CHANNEL)
+        );
+    }
+}
@@ -0,0 +1,121 @@
+use crate::modules::category_access::StaffIdentity;
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use serenity::all::{Context, GuildId, Message, Permissions, RoleId, UserId};
+use std::collections::HashMap;
+
+pub async fn is_bot_admin(
+    ctx: &Context,
+    config: &Config,
+    guild_id: GuildId,
+    user_id: UserId,
+) -> bool {
+    if config.bot.panel_super_admin_users.contains(&user_id.get()) {
+        return true;
+    }
+    match StaffIdentity::resolve(ctx, guild_id, user_id).await {
+        Some(identity) => {
+            identity.is_admin
+                || identity
+                    .role_ids
+                    .iter()
+                    .any(|r| config.bot.panel_super_admin_roles.contains(&r.get()))
+        }
+        None => false,
+    }
+}
+
+pub async fn is_message_author_admin(ctx: &Context, config: &Config, msg: &Message) -> bool {
+    match msg.guild_id {
+        Some(guild_id) => is_bot_admin(ctx, config, guild_id, msg.author.id).await,
+        None => config
+            .bot
+            .panel_super_admin_users
+            .contains(&msg.author.id.get()),
+    }
+}
+
+// Commands missing from the permission matrix are open to every staff member.
+pub async fn can_run_command(
+    config: &Config,
+    command_name: &str,
+    user_id: UserId,
+    identity: Option<&StaffIdentity>,
+) -> bool {
+    let Some(pool) = &config.db_pool else {
+        return true;
+    };
+    let access = match get_command_permissions(command_name, pool).await {
+        Ok(access) => access,
+        Err(_) => return false,
+    };
+    if access.is_empty() || access.user_ids.contains(&user_id.to_string()) {
+        return true;
+    }
+    if config.bot.panel_super_admin_users.contains(&user_id.get()) {
+        return true;
+    }
+
+    match identity {
+        Some(identity) => {
+            identity.can_access(&access)
+                || identity
+                    .role_ids
+                    .iter()
+                    .any(|r| config.bot.panel_super_admin_roles.contains(&r.get()))
+        }
+        None => false,
+    }
+}
+
+// The permissions every allowed role shares, so Discord hides the command from members who could never run it.
+pub fn slash_default_permissions(
+    access: &AccessList,
+    role_permissions: &HashMap<RoleId, Permissions>,
+) -> Option<Permissions> {
+    if access.role_ids.is_empty() || !access.user_ids.is_empty() {
+        return None;
+    }
+    let mut shared = Permissions::all();
+    for role_id in &access.role_ids {
+        let role_id = RoleId::new(role_id.parse::<u64>().ok()?);
+        shared &= *role_permissions.get(&role_id)?;
+    }
+    // An empty set would mean "administrators only" to Discord.
+    (!shared.is_empty()).then_some(shared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slash_default_permissions_keeps_what_allowed_roles_share() {
+        let roles = HashMap::from([
+            (
+                RoleId::new(1),
+                Permissions::MANAGE_MESSAGES | Permissions::KICK_MEMBERS,
+            ),
+            (
+                RoleId::new(2),
+                Permissions::MANAGE_MESSAGES | Permissions::BAN_MEMBERS,
+            ),
+        ]);
+        let mut access = AccessList {
+            role_ids: vec!["1".to_string(), "2".to_string()],
+            user_ids: Vec::new(),
+        };
+
+        assert_eq!(
+            slash_default_permissions(&access, &roles),
+            Some(Permissions::MANAGE_MESSAGES)
+        );
+
+        access.user_ids.push("3".to_string());
+        assert_eq!(slash_default_permissions(&access, &roles), None);
+        assert_eq!(
+            slash_default_permissions(&AccessList::default(), &roles),
+            None
+        );
+    }
+}
@@ -0,0 +1,295 @@
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
+use chrono::Utc;
+use serde_json::json;
+use serenity::all::{
+    ButtonStyle, ComponentInteraction, Context, CreateActionRow, CreateButton,
+    CreateInteractionResponse, CreateInteractionResponseMessage, GuildId,
+};
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashMap};
+use tracing::error;
+
+const ERASURE_CONFIRMATION_TTL_SECS: i64 = 600;
+
+pub fn parse_data_subject(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    let inner = trimmed
+        .strip_prefix("<@")
+        .and_then(|value| value.strip_suffix('>'))
+        .map(|value| value.trim_start_matches('!'))
+        .unwrap_or(trimmed);
+    inner.parse::<u64>().ok().map(|id| id.to_string())
+}
+
+pub fn data_export_file_name(user_id: &str) -> String {
+    format!("rustmail-user-{user_id}.json")
+}
+
+pub fn count_user_data(data: &BTreeMap<String, Vec<serde_json::Value>>) -> BTreeMap<String, u64> {
+    data.iter()
+        .filter(|(_, rows)| !rows.is_empty())
+        .map(|(table, rows)| (table.clone(), rows.len() as u64))
+        .collect()
+}
+
+pub async fn export_user_data(
+    user_id: &str,
+    source: &str,
+    requested_by: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<Vec<u8>> {
+    let data = collect_user_data(user_id, pool).await?;
+    let counts = count_user_data(&data);
+
+    let bundle = json!({
+        "user_id": user_id,
+        "generated_at": Utc::now().to_rfc3339(),
+        "tables": data,
+    });
+    let bytes = serde_json::to_vec_pretty(&bundle).map_err(|e| {
+        error!("Failed to serialize data export: {e:?}");
+        validation_failed("Failed to serialize data export")
+    })?;
+
+    insert_data_subject_request(
+        user_id,
+        DataRequestAction::Export,
+        None,
+        source,
+        requested_by,
+        &counts,
+        pool,
+    )
+    .await?;
+
+    Ok(bytes)
+}
+
+pub async fn erase_data_subject(
+    user_id: &str,
+    mode: ErasureMode,
+    source: &str,
+    requested_by: &str,
+    pool: &SqlitePool,
+) -> ModmailResult<BTreeMap<String, u64>> {
+    let summary = erase_user_data(user_id, mode, pool).await?;
+    insert_data_subject_request(
+        user_id,
+        DataRequestAction::Erase,
+        Some(mode),
+        source,
+        requested_by,
+        &summary,
+        pool,
+    )
+    .await?;
+
+    Ok(summary)
+}
+
+pub fn format_row_counts(counts: &BTreeMap<String, u64>) -> String {
+    counts
+        .iter()
+        .map(|(table, count)| format!("• `{table}`: {count}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+async fn translate(config: &Config, key: &str, params: &[(&str, String)]) -> String {
+    let params: HashMap<String, String> = params
+        .iter()
+        .map(|(k, v)| (k.to_string(), v.clone()))
+        .collect();
+    get_translated_message(config, key, Some(&params), None, None, None).await
+}
+
+async fn mode_label(config: &Config, mode: ErasureMode) -> String {
+    let key = match mode {
+        ErasureMode::Anonymize => "gdpr.mode_anonymize",
+        ErasureMode::Delete => "gdpr.mode_delete",
+    };
+    translate(config, key, &[]).await
+}
+
+pub async fn erasure_prompt(
+    config: &Config,
+    user_id: &str,
+    mode: ErasureMode,
+    requester: &str,
+    counts: &BTreeMap<String, u64>,
+) -> (String, Vec<CreateActionRow>) {
+    let content = translate(
+        config,
+        "gdpr.erase_prompt",
+        &[
+            ("user_id", user_id.to_string()),
+            ("mode", mode_label(config, mode).await),
+            ("counts", format_row_counts(counts)),
+        ],
+    )
+    .await;
+
+    let buttons = vec![
+        CreateButton::new(format!(
+            "gdpr:erase:{user_id}:{}:{requester}",
+            mode.as_str()
+        ))
+        .label(translate(config, "gdpr.confirm_button", &[]).await)
+        .style(ButtonStyle::Danger),
+        CreateButton::new(format!("gdpr:cancel:{requester}"))
+            .label(translate(config, "gdpr.cancel_button", &[]).await)
+            .style(ButtonStyle::Secondary),
+    ];
+
+    (content, vec![CreateActionRow::Buttons(buttons)])
+}
+
+async fn respond_ephemeral(
+    ctx: &Context,
+    interaction: &ComponentInteraction,
+    content: String,
+) -> serenity::Result<()> {
+    interaction
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .ephemeral(true),
+            ),
+        )
+        .await
+}
+
+async fn close_prompt(
+    ctx: &Context,
+    interaction: &ComponentInteraction,
+    content: String,
+) -> serenity::Result<()> {
+    interaction
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new()
+                    .content(content)
+                    .embeds(vec![])
+                    .components(vec![]),
+            ),
+        )
+        .await
+}
+
+pub async fn handle_gdpr_component_interaction(
+    ctx: &Context,
+    config: &Config,
+    interaction: &ComponentInteraction,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let custom_id = interaction.data.custom_id.as_str();
+
+    if let Some(requester) = custom_id.strip_prefix("gdpr:cancel:") {
+        if requester != interaction.user.id.to_string() {
+            respond_ephemeral(
+                ctx,
+                interaction,
+                translate(config, "gdpr.not_requester", &[]).await,
+            )
+            .await?;
+            return Ok(true);
+        }
+        close_prompt(
+            ctx,
+            interaction,
+            translate(config, "gdpr.cancelled", &[]).await,
+        )
+        .await?;
+        return Ok(true);
+    }
+
+    let Some(rest) = custom_id.strip_prefix("gdpr:erase:") else {
+        return Ok(false);
+    };
+    let mut parts = rest.splitn(3, ':');
+    let (Some(user_id), Some(mode), Some(requester)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Ok(true);
+    };
+    let Some(mode) = ErasureMode::parse(mode) else {
+        return Ok(true);
+    };
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Ok(true);
+    };
+
+    if requester != interaction.user.id.to_string() {
+        respond_ephemeral(
+            ctx,
+            interaction,
+            translate(config, "gdpr.not_requester", &[]).await,
+        )
+        .await?;
+        return Ok(true);
+    }
+
+    let guild_id = interaction
+        .guild_id
+        .unwrap_or_else(|| GuildId::new(config.bot.get_staff_guild_id()));
+    if !is_bot_admin(ctx, config, guild_id, interaction.user.id).await {
+        respond_ephemeral(
+            ctx,
+            interaction,
+            translate(config, "gdpr.not_allowed", &[]).await,
+        )
+        .await?;
+        return Ok(true);
+    }
+
+    let age = Utc::now().timestamp() - interaction.message.timestamp.unix_timestamp();
+    if age > ERASURE_CONFIRMATION_TTL_SECS {
+        close_prompt(
+            ctx,
+            interaction,
+            translate(config, "gdpr.expired", &[]).await,
+        )
+        .await?;
+        return Ok(true);
+    }
+
+    let content = match erase_data_subject(user_id, mode, "command", requester, pool).await {
+        Ok(summary) => {
+            translate(
+                config,
+                "gdpr.erased",
+                &[
+                    ("user_id", user_id.to_string()),
+                    ("mode", mode_label(config, mode).await),
+                    ("counts", format_row_counts(&summary)),
+                ],
+            )
+            .await
+        }
+        Err(e) => {
+            error!("Failed to erase the data of {user_id}: {e:?}");
+            translate(config, "gdpr.erase_failed", &[("error", e.to_string())]).await
+        }
+    };
+    close_prompt(ctx, interaction, content).await?;
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ids_and_mentions() {
+        assert_eq!(parse_data_subject("123").as_deref(), Some("123"));
+        assert_eq!(parse_data_subject(" <@123> ").as_deref(), Some("123"));
+        assert_eq!(parse_data_subject("<@!123>").as_deref(), Some("123"));
+        assert_eq!(parse_data_subject("bob"), None);
+    }
+}
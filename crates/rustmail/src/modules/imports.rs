@@ -0,0 +1,453 @@
+use crate::prelude::db::*;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::Value;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions, SqliteRow};
+use sqlx::{Row, SqlitePool};
+use std::collections::HashMap;
+use std::path::Path;
+
+const IMPORT_AUTHOR: &str = "import";
+
+// `thread_messages.message_type` values of the Node bot.
+const MODMAILBOT_CHAT: i64 = 2;
+const MODMAILBOT_FROM_USER: i64 = 3;
+const MODMAILBOT_TO_USER: i64 = 4;
+const MODMAILBOT_COMMAND: i64 = 6;
+
+pub fn parse_import_timestamp(value: &Value) -> Option<DateTime<Utc>> {
+    match value {
+        Value::String(s) => {
+            if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+                return Some(dt.with_timezone(&Utc));
+            }
+            ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"]
+                .iter()
+                .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+                .map(|naive| naive.and_utc())
+                .or_else(|| s.parse::<i64>().ok().and_then(from_unix))
+        }
+        Value::Number(n) => n.as_i64().and_then(from_unix),
+        Value::Object(object) => object
+            .get("$date")
+            .or_else(|| object.get("$numberLong"))
+            .and_then(parse_import_timestamp),
+        _ => None,
+    }
+}
+
+// Values above 10^11 are taken as milliseconds.
+fn from_unix(value: i64) -> Option<DateTime<Utc>> {
+    if value.abs() > 100_000_000_000 {
+        DateTime::from_timestamp_millis(value)
+    } else {
+        DateTime::from_timestamp(value, 0)
+    }
+}
+
+fn json_text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Object(object) => object
+            .get("$oid")
+            .or_else(|| object.get("$numberLong"))
+            .and_then(json_text),
+        _ => None,
+    }
+}
+
+fn json_user_id(value: &Value) -> Option<i64> {
+    json_text(value)?.parse().ok()
+}
+
+fn read_json_documents(content: &str) -> Result<Vec<Value>, String> {
+    if content.trim_start().starts_with('[') {
+        return serde_json::from_str(content).map_err(|e| format!("Invalid JSON export: {}", e));
+    }
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            serde_json::from_str(line)
+                .map_err(|e| format!("Invalid JSON on line {}: {}", index + 1, e))
+        })
+        .collect()
+}
+
+fn with_attachments(content: &str, urls: impl IntoIterator<Item = String>) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    if !content.is_empty() {
+        lines.push(content.to_string());
+    }
+    lines.extend(urls.into_iter().filter(|url| !url.is_empty()));
+    lines.join("\n")
+}
+
+fn modmail_message(value: &Value, unsupported: &mut u64) -> Option<ImportedMessage> {
+    let author = &value["author"];
+    let is_mod = author["mod"].as_bool().unwrap_or(false);
+    let (from_staff, is_anonymous, is_internal) = match value["type"].as_str() {
+        None | Some("thread_message") => (is_mod, false, false),
+        Some("anonymous") => (true, true, false),
+        Some("system") | Some("internal") | Some("note") => (true, false, true),
+        Some(_) => {
+            *unsupported += 1;
+            return None;
+        }
+    };
+
+    let Some(created_at) = parse_import_timestamp(&value["timestamp"]) else {
+        *unsupported += 1;
+        return None;
+    };
+    let attachments = value["attachments"]
+        .as_array()
+        .map(|list| {
+            list.iter()
+                .filter_map(|a| json_text(a).or_else(|| a["url"].as_str().map(str::to_string)))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+
+    Some(ImportedMessage {
+        user_id: json_user_id(&author["id"]).unwrap_or(0),
+        user_name: author["name"].as_str().unwrap_or("Unknown").to_string(),
+        content: with_attachments(value["content"].as_str().unwrap_or(""), attachments),
+        created_at,
+        from_staff,
+        is_anonymous,
+        is_internal,
+        message_number: None,
+        dm_message_id: None,
+        inbox_message_id: None,
+    })
+}
+
+fn modmail_thread(log: &Value, unsupported: &mut u64) -> Option<ImportedThread> {
+    let external_id = json_text(&log["key"]).or_else(|| json_text(&log["_id"]))?;
+    let recipient = &log["recipient"];
+    let user_id = json_user_id(&recipient["id"])?;
+    let created_at = parse_import_timestamp(&log["created_at"])?;
+
+    let messages: Vec<ImportedMessage> = log["messages"]
+        .as_array()
+        .map(|list| {
+            list.iter()
+                .filter_map(|m| modmail_message(m, unsupported))
+                .collect()
+        })
+        .unwrap_or_default();
+    let closed_at = parse_import_timestamp(&log["closed_at"])
+        .or_else(|| messages.iter().map(|m| m.created_at).max())
+        .unwrap_or(created_at);
+
+    Some(ImportedThread {
+        external_id,
+        user_id,
+        user_name: recipient["name"].as_str().unwrap_or("Unknown").to_string(),
+        channel_id: json_text(&log["channel_id"]).unwrap_or_default(),
+        created_at,
+        closed_at,
+        closed_by: json_text(&log["closer"]["id"]),
+        messages,
+    })
+}
+
+fn modmail_config(config: &Value, bundle: &mut ImportBundle) {
+    if let Some(snippets) = config["snippets"].as_object() {
+        for (key, content) in snippets {
+            let Some(content) = content.as_str() else {
+                bundle.unsupported += 1;
+                continue;
+            };
+            bundle.snippets.push(ImportedSnippet {
+                key: key.clone(),
+                content: content.to_string(),
+                created_by: IMPORT_AUTHOR.to_string(),
+                created_at: None,
+            });
+        }
+    }
+
+    if let Some(blocked) = config["blocked"].as_object() {
+        for (user_id, entry) in blocked {
+            if user_id.parse::<u64>().is_err() {
+                bundle.unsupported += 1;
+                continue;
+            }
+            bundle.blocked.push(ImportedBlock {
+                user_id: user_id.clone(),
+                user_name: user_id.clone(),
+                blocked_by: json_text(&entry["blocked_by"])
+                    .unwrap_or_else(|| IMPORT_AUTHOR.to_string()),
+                blocked_at: parse_import_timestamp(&entry["blocked_at"]).unwrap_or_else(Utc::now),
+                expires_at: parse_import_timestamp(&entry["until"])
+                    .or_else(|| parse_import_timestamp(&entry["end_time"])),
+            });
+        }
+    }
+}
+
+pub fn parse_modmail_export(logs: &str, config: Option<&str>) -> Result<ImportBundle, String> {
+    let mut bundle = ImportBundle::default();
+
+    for log in read_json_documents(logs)? {
+        match modmail_thread(&log, &mut bundle.unsupported) {
+            Some(thread) => bundle.threads.push(thread),
+            None => bundle.unsupported += 1,
+        }
+    }
+    if let Some(config) = config {
+        for document in read_json_documents(config)? {
+            modmail_config(&document, &mut bundle);
+        }
+    }
+
+    Ok(bundle)
+}
+
+fn row_text(row: &SqliteRow, column: &str) -> Option<String> {
+    row.try_get::<Option<String>, _>(column)
+        .ok()
+        .flatten()
+        .or_else(|| {
+            row.try_get::<Option<i64>, _>(column)
+                .ok()
+                .flatten()
+                .map(|v| v.to_string())
+        })
+}
+
+fn row_timestamp(row: &SqliteRow, column: &str) -> Option<DateTime<Utc>> {
+    row_text(row, column).and_then(|value| parse_import_timestamp(&Value::String(value)))
+}
+
+async fn modmailbot_tables(pool: &SqlitePool) -> Result<Vec<String>, String> {
+    sqlx::query_scalar("SELECT name FROM sqlite_master WHERE type = 'table'")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to read the database: {}", e))
+}
+
+async fn fetch_rows(query: &'static str, pool: &SqlitePool) -> Result<Vec<SqliteRow>, String> {
+    sqlx::query(query)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| format!("Failed to read the database: {}", e))
+}
+
+fn modmailbot_message(row: &SqliteRow, unsupported: &mut u64) -> Option<ImportedMessage> {
+    let message_type = row.try_get::<i64, _>("message_type").unwrap_or(0);
+    let (from_staff, is_internal) = match message_type {
+        MODMAILBOT_FROM_USER => (false, false),
+        MODMAILBOT_TO_USER => (true, false),
+        MODMAILBOT_CHAT | MODMAILBOT_COMMAND => (true, true),
+        _ => {
+            *unsupported += 1;
+            return None;
+        }
+    };
+    let Some(created_at) = row_timestamp(row, "created_at") else {
+        *unsupported += 1;
+        return None;
+    };
+    let attachments = row_text(row, "attachments")
+        .and_then(|raw| serde_json::from_str::<Vec<String>>(&raw).ok())
+        .unwrap_or_default();
+
+    Some(ImportedMessage {
+        user_id: row_text(row, "user_id")
+            .and_then(|id| id.parse().ok())
+            .unwrap_or(0),
+        user_name: row_text(row, "user_name").unwrap_or_else(|| "Unknown".to_string()),
+        content: with_attachments(&row_text(row, "body").unwrap_or_default(), attachments),
+        created_at,
+        from_staff,
+        is_anonymous: row.try_get::<bool, _>("is_anonymous").unwrap_or(false),
+        is_internal,
+        message_number: if is_internal {
+            None
+        } else {
+            row.try_get::<Option<i64>, _>("message_number")
+                .ok()
+                .flatten()
+        },
+        dm_message_id: row_text(row, "dm_message_id"),
+        inbox_message_id: row_text(row, "inbox_message_id"),
+    })
+}
+
+fn attach_modmailbot_notes(notes: Vec<SqliteRow>, bundle: &mut ImportBundle) {
+    for row in notes {
+        let user_id = row_text(&row, "user_id").and_then(|id| id.parse::<i64>().ok());
+        let created_at = row_timestamp(&row, "created_at");
+        let (Some(user_id), Some(created_at)) = (user_id, created_at) else {
+            bundle.unsupported += 1;
+            continue;
+        };
+
+        let target = bundle
+            .threads
+            .iter_mut()
+            .filter(|t| t.user_id == user_id)
+            .min_by_key(|t| {
+                (
+                    t.created_at > created_at,
+                    (t.created_at - created_at).num_seconds().abs(),
+                )
+            });
+        let Some(thread) = target else {
+            bundle.unsupported += 1;
+            continue;
+        };
+
+        let author = row_text(&row, "author_id").unwrap_or_default();
+        thread.messages.push(ImportedMessage {
+            user_id: author.parse().unwrap_or(0),
+            user_name: author,
+            content: row_text(&row, "body").unwrap_or_default(),
+            created_at,
+            from_staff: true,
+            is_anonymous: false,
+            is_internal: true,
+            message_number: None,
+            dm_message_id: None,
+            inbox_message_id: None,
+        });
+        thread.messages.sort_by_key(|m| m.created_at);
+    }
+}
+
+pub async fn read_modmailbot_database(path: &Path) -> Result<ImportBundle, String> {
+    if !path.is_file() {
+        return Err(format!("{} does not exist", path.display()));
+    }
+    let options = SqliteConnectOptions::new().filename(path).read_only(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .map_err(|e| format!("Failed to open {}: {}", path.display(), e))?;
+
+    let tables = modmailbot_tables(&pool).await?;
+    if !tables.iter().any(|t| t == "threads") || !tables.iter().any(|t| t == "thread_messages") {
+        return Err(format!(
+            "{} is not a modmailbot database: threads or thread_messages is missing",
+            path.display()
+        ));
+    }
+
+    let mut bundle = ImportBundle::default();
+
+    let mut messages: HashMap<String, Vec<ImportedMessage>> = HashMap::new();
+    for row in fetch_rows(
+        "SELECT * FROM thread_messages ORDER BY created_at, id",
+        &pool,
+    )
+    .await?
+    {
+        let Some(thread_id) = row_text(&row, "thread_id") else {
+            bundle.unsupported += 1;
+            continue;
+        };
+        if let Some(message) = modmailbot_message(&row, &mut bundle.unsupported) {
+            messages.entry(thread_id).or_default().push(message);
+        }
+    }
+
+    for row in fetch_rows("SELECT * FROM threads ORDER BY created_at", &pool).await? {
+        let id = row_text(&row, "id");
+        let user_id = row_text(&row, "user_id").and_then(|id| id.parse::<i64>().ok());
+        let created_at = row_timestamp(&row, "created_at");
+        let (Some(id), Some(user_id), Some(created_at)) = (id, user_id, created_at) else {
+            bundle.unsupported += 1;
+            continue;
+        };
+
+        let messages = messages.remove(&id).unwrap_or_default();
+        let closed_at = messages
+            .iter()
+            .map(|m| m.created_at)
+            .max()
+            .unwrap_or(created_at);
+        bundle.threads.push(ImportedThread {
+            external_id: id,
+            user_id,
+            user_name: row_text(&row, "user_name").unwrap_or_else(|| "Unknown".to_string()),
+            channel_id: row_text(&row, "channel_id").unwrap_or_default(),
+            created_at,
+            closed_at,
+            closed_by: None,
+            messages,
+        });
+    }
+    bundle.unsupported += messages.values().map(|m| m.len() as u64).sum::<u64>();
+
+    if tables.iter().any(|t| t == "notes") {
+        let notes = fetch_rows("SELECT * FROM notes ORDER BY created_at", &pool).await?;
+        attach_modmailbot_notes(notes, &mut bundle);
+    }
+
+    if tables.iter().any(|t| t == "snippets") {
+        for row in fetch_rows("SELECT * FROM snippets", &pool).await? {
+            let (Some(key), Some(content)) = (row_text(&row, "trigger"), row_text(&row, "body"))
+            else {
+                bundle.unsupported += 1;
+                continue;
+            };
+            bundle.snippets.push(ImportedSnippet {
+                key,
+                content,
+                created_by: row_text(&row, "created_by")
+                    .unwrap_or_else(|| IMPORT_AUTHOR.to_string()),
+                created_at: row_timestamp(&row, "created_at"),
+            });
+        }
+    }
+
+    if tables.iter().any(|t| t == "blocked_users") {
+        for row in fetch_rows("SELECT * FROM blocked_users", &pool).await? {
+            let Some(user_id) = row_text(&row, "user_id") else {
+                bundle.unsupported += 1;
+                continue;
+            };
+            bundle.blocked.push(ImportedBlock {
+                user_name: row_text(&row, "user_name").unwrap_or_else(|| user_id.clone()),
+                user_id,
+                blocked_by: row_text(&row, "blocked_by")
+                    .unwrap_or_else(|| IMPORT_AUTHOR.to_string()),
+                blocked_at: row_timestamp(&row, "blocked_at").unwrap_or_else(Utc::now),
+                expires_at: row_timestamp(&row, "expires_at"),
+            });
+        }
+    }
+
+    pool.close().await;
+    Ok(bundle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_a_modmail_log() {
+        let logs = r#"{"_id":{"$oid":"6123"},"key":"abc","open":false,"created_at":"2021-03-01T10:00:00.123456","closed_at":"2021-03-02T09:00:00","channel_id":"555","recipient":{"id":"42","name":"bob","mod":false},"closer":{"id":"7","name":"alice","mod":true},"messages":[{"timestamp":"2021-03-01T10:00:00","author":{"id":"42","name":"bob","mod":false},"content":"help","type":"thread_message","attachments":[{"url":"https://cdn/a.png"}]},{"timestamp":"2021-03-01T10:05:00","author":{"id":"7","name":"alice","mod":true},"content":"hi","type":"anonymous"},{"timestamp":"2021-03-01T10:06:00","author":{"id":"7","name":"alice","mod":true},"content":"check","type":"system"},{"timestamp":"2021-03-01T10:07:00","author":{"id":"7","name":"alice","mod":true},"content":"x","type":"mystery"}]}"#;
+        let config = r#"{"snippets":{"hello":"Hi there"},"blocked":{"99":"spam"}}"#;
+
+        let bundle = parse_modmail_export(logs, Some(config)).unwrap();
+        let thread = &bundle.threads[0];
+
+        assert_eq!(thread.external_id, "abc");
+        assert_eq!(thread.user_id, 42);
+        assert_eq!(thread.closed_by.as_deref(), Some("7"));
+        assert_eq!(thread.messages.len(), 3);
+        assert_eq!(thread.messages[0].content, "help\nhttps://cdn/a.png");
+        assert!(thread.messages[1].from_staff && thread.messages[1].is_anonymous);
+        assert!(thread.messages[2].is_internal);
+        assert_eq!(bundle.unsupported, 1);
+        assert_eq!(bundle.snippets[0].key, "hello");
+        assert_eq!(bundle.blocked[0].user_id, "99");
+    }
+}
@@ -0,0 +1,376 @@
+use crate::config::parse_config;
+use crate::prelude::db::*;
+use chrono::Utc;
+use serde_json::Value;
+use sqlx::SqlitePool;
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+pub const INSTANCE_BUNDLE_FORMAT: u32 = 1;
+
+const CONFIG_SECRETS: [&str; 2] = ["/bot/token", "/bot/client_secret"];
+
+const CONFIG_IDS: [(&str, DiscordIdKind); 8] = [
+    ("/bot/mode/guild_id", DiscordIdKind::Guild),
+    ("/bot/mode/community_guild_id", DiscordIdKind::Guild),
+    ("/bot/mode/staff_guild_id", DiscordIdKind::Guild),
+    ("/thread/inbox_category_id", DiscordIdKind::Category),
+    ("/bot/logs_channel_id", DiscordIdKind::Channel),
+    ("/bot/features_channel_id", DiscordIdKind::Channel),
+    ("/thread/board_channel_id", DiscordIdKind::Channel),
+    ("/bot/panel_super_admin_roles", DiscordIdKind::Role),
+];
+
+// Ticket channels only exist on the server the tickets were opened on, so they are not remapped.
+type TableId = (
+    &'static str,
+    &'static str,
+    Option<(&'static str, &'static str)>,
+    DiscordIdKind,
+);
+
+const TABLE_IDS: [TableId; 6] = [
+    (
+        "ticket_categories",
+        "discord_category_id",
+        None,
+        DiscordIdKind::Category,
+    ),
+    (
+        "ticket_category_roles",
+        "role_id",
+        None,
+        DiscordIdKind::Role,
+    ),
+    (
+        "ticket_category_access",
+        "target_id",
+        Some(("target_type", "role")),
+        DiscordIdKind::Role,
+    ),
+    (
+        "panel_permissions",
+        "subject_id",
+        Some(("subject_type", "role")),
+        DiscordIdKind::Role,
+    ),
+    ("reminders", "guild_id", None, DiscordIdKind::Guild),
+    ("reminders", "target_roles", None, DiscordIdKind::Role),
+];
+
+fn row_matches(row: &Value, filter: Option<(&str, &str)>) -> bool {
+    filter.is_none_or(|(column, value)| row.get(column).and_then(Value::as_str) == Some(value))
+}
+
+// `0` stands for an unset option.
+fn ids_of(value: &Value) -> Vec<String> {
+    match value {
+        Value::Number(n) => vec![n.to_string()],
+        Value::String(s) => s.split(',').map(|id| id.trim().to_string()).collect(),
+        Value::Array(items) => items.iter().flat_map(ids_of).collect(),
+        _ => Vec::new(),
+    }
+    .into_iter()
+    .filter(|id| id.parse::<u64>().is_ok_and(|id| id != 0))
+    .collect()
+}
+
+fn remap_value(value: &mut Value, mapping: &BTreeMap<String, String>) {
+    match value {
+        Value::Number(n) => {
+            if let Some(new) = mapping.get(&n.to_string()) {
+                *value = new
+                    .parse::<i64>()
+                    .map(Value::from)
+                    .unwrap_or_else(|_| Value::String(new.clone()));
+            }
+        }
+        Value::String(s) if s.split(',').any(|id| mapping.contains_key(id.trim())) => {
+            let ids: Vec<&str> = s
+                .split(',')
+                .map(|id| mapping.get(id.trim()).map(String::as_str).unwrap_or(id))
+                .collect();
+            *s = ids.join(",");
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| remap_value(item, mapping)),
+        _ => {}
+    }
+}
+
+pub fn discord_ids(bundle: &InstanceBundle) -> Vec<DiscordIdUse> {
+    let mut ids: Vec<DiscordIdUse> = Vec::new();
+    let mut record = |kind: DiscordIdKind, place: String, value: &Value| {
+        for id in ids_of(value) {
+            match ids.iter_mut().find(|used| used.id == id) {
+                Some(used) if used.used_by.contains(&place) => {}
+                Some(used) => used.used_by.push(place.clone()),
+                None => ids.push(DiscordIdUse {
+                    id,
+                    kind,
+                    used_by: vec![place.clone()],
+                }),
+            }
+        }
+    };
+
+    if let Some(config) = &bundle.config {
+        for (pointer, kind) in CONFIG_IDS {
+            if let Some(value) = config.pointer(pointer) {
+                record(kind, pointer[1..].replace('/', "."), value);
+            }
+        }
+    }
+    for (table, column, filter, kind) in TABLE_IDS {
+        for row in bundle.tables.get(table).into_iter().flatten() {
+            if let Some(value) = row.get(column).filter(|_| row_matches(row, filter)) {
+                record(kind, format!("{table}.{column}"), value);
+            }
+        }
+    }
+
+    ids
+}
+
+pub fn remap_discord_ids(bundle: &mut InstanceBundle, mapping: &BTreeMap<String, String>) {
+    if let Some(config) = &mut bundle.config {
+        for (pointer, _) in CONFIG_IDS {
+            if let Some(value) = config.pointer_mut(pointer) {
+                remap_value(value, mapping);
+            }
+        }
+    }
+    for (table, column, filter, _) in TABLE_IDS {
+        for row in bundle.tables.get_mut(table).into_iter().flatten() {
+            if !row_matches(row, filter) {
+                continue;
+            }
+            if let Some(value) = row.get_mut(column) {
+                remap_value(value, mapping);
+            }
+        }
+    }
+}
+
+async fn schema_version(pool: &SqlitePool) -> Result<i64, String> {
+    let applied = list_applied_migrations(pool)
+        .await
+        .map_err(|e| format!("Failed to read the schema version: {}", e))?;
+    Ok(applied.last().copied().unwrap_or(0))
+}
+
+pub async fn export_instance(
+    config_path: &str,
+    include_secrets: bool,
+    include_history: bool,
+    pool: &SqlitePool,
+) -> Result<InstanceBundle, String> {
+    let config = match fs::read_to_string(config_path) {
+        Ok(content) => {
+            let table: toml::Table = toml::from_str(&content)
+                .map_err(|e| format!("Failed to parse {}: {}", config_path, e))?;
+            let mut config = serde_json::to_value(table)
+                .map_err(|e| format!("Failed to convert {}: {}", config_path, e))?;
+            if !include_secrets {
+                for pointer in CONFIG_SECRETS {
+                    if let Some(secret) = config.pointer_mut(pointer) {
+                        *secret = Value::String(String::new());
+                    }
+                }
+            }
+            Some(config)
+        }
+        Err(_) => None,
+    };
+
+    let tables = collect_instance_tables(include_history, pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(InstanceBundle {
+        format: INSTANCE_BUNDLE_FORMAT,
+        rustmail_version: env!("CARGO_PKG_VERSION").to_string(),
+        schema_version: schema_version(pool).await?,
+        exported_at: Utc::now().timestamp(),
+        secrets_included: include_secrets,
+        history_included: include_history,
+        config,
+        tables,
+    })
+}
+
+fn bundled_config(
+    config: Value,
+    secrets_included: bool,
+    config_path: &str,
+) -> Result<String, String> {
+    let mut table: toml::Table = serde_json::from_value(config)
+        .map_err(|e| format!("Invalid configuration in the export: {}", e))?;
+
+    if !secrets_included {
+        let current = fs::read_to_string(config_path)
+            .ok()
+            .and_then(|content| toml::from_str::<toml::Table>(&content).ok());
+        let current_bot = current.as_ref().and_then(|t| t.get("bot"));
+        if let (Some(toml::Value::Table(bot)), Some(current_bot)) =
+            (table.get_mut("bot"), current_bot)
+        {
+            for key in ["token", "client_secret"] {
+                if let Some(secret) = current_bot.get(key) {
+                    bot.insert(key.to_string(), secret.clone());
+                }
+            }
+        }
+    }
+
+    let content = toml::to_string(&table)
+        .map_err(|e| format!("Failed to serialize the configuration: {}", e))?;
+    parse_config(&content).map_err(|e| format!("Invalid configuration in the export: {}", e))?;
+    Ok(content)
+}
+
+pub async fn import_instance(
+    mut bundle: InstanceBundle,
+    mapping: &BTreeMap<String, String>,
+    config_path: &str,
+    replace_config: bool,
+    dry_run: bool,
+    pool: &SqlitePool,
+) -> Result<InstanceImportReport, String> {
+    if bundle.format != INSTANCE_BUNDLE_FORMAT {
+        return Err(format!(
+            "Unsupported instance export format {}",
+            bundle.format
+        ));
+    }
+    let schema = schema_version(pool).await?;
+    if bundle.schema_version != schema {
+        return Err(format!(
+            "The export was made at schema version {} (Rustmail {}) and this database is at {}, export and import with the same Rustmail version",
+            bundle.schema_version, bundle.rustmail_version, schema
+        ));
+    }
+
+    let known: HashSet<String> = discord_ids(&bundle).into_iter().map(|u| u.id).collect();
+    remap_discord_ids(&mut bundle, mapping);
+
+    let config = match bundle.config.take() {
+        Some(_) if Path::new(config_path).exists() && !replace_config => {
+            (ConfigImportOutcome::Kept, None)
+        }
+        Some(config) => (
+            ConfigImportOutcome::Written,
+            Some(bundled_config(
+                config,
+                bundle.secrets_included,
+                config_path,
+            )?),
+        ),
+        None => (ConfigImportOutcome::Absent, None),
+    };
+
+    let mut secrets = Vec::new();
+    for row in bundle.tables.get_mut("api_keys").into_iter().flatten() {
+        let (api_key, key_hash) = generate_api_key()?;
+        if let Some(row) = row.as_object_mut() {
+            row.insert("key_hash".to_string(), Value::String(key_hash));
+        }
+        secrets.push(api_key);
+    }
+
+    let mut report = import_instance_tables(&bundle.tables, &secrets, dry_run, pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    report.config = config.0;
+    if let Some(content) = config.1.filter(|_| !dry_run) {
+        fs::write(config_path, content)
+            .map_err(|e| format!("Failed to write {}: {}", config_path, e))?;
+    }
+    report.remapped = mapping
+        .iter()
+        .filter(|(old, new)| known.contains(*old) && old != new)
+        .map(|(old, new)| (old.clone(), new.clone()))
+        .collect();
+    if dry_run {
+        report
+            .api_keys
+            .iter_mut()
+            .for_each(|key| key.api_key.clear());
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn discord_ids_are_listed_and_remapped_in_place() {
+        let mut bundle = InstanceBundle {
+            format: INSTANCE_BUNDLE_FORMAT,
+            rustmail_version: String::new(),
+            schema_version: 0,
+            exported_at: 0,
+            secrets_included: false,
+            history_included: false,
+            config: Some(json!({
+                "bot": { "mode": { "type": "single", "guild_id": 10 }, "panel_super_admin_roles": [30] },
+                "thread": { "inbox_category_id": 20, "board_channel_id": 0 },
+            })),
+            tables: BTreeMap::from([
+                (
+                    "panel_permissions".to_string(),
+                    vec![
+                        json!({ "subject_type": "role", "subject_id": "30" }),
+                        json!({ "subject_type": "user", "subject_id": "40" }),
+                    ],
+                ),
+                (
+                    "reminders".to_string(),
+                    vec![json!({ "guild_id": 10, "target_roles": "30, 31" })],
+                ),
+            ]),
+        };
+
+        let ids: Vec<(String, DiscordIdKind)> = discord_ids(&bundle)
+            .into_iter()
+            .map(|used| (used.id, used.kind))
+            .collect();
+        assert_eq!(
+            ids,
+            [
+                ("10".to_string(), DiscordIdKind::Guild),
+                ("20".to_string(), DiscordIdKind::Category),
+                ("30".to_string(), DiscordIdKind::Role),
+                ("31".to_string(), DiscordIdKind::Role),
+            ]
+        );
+
+        let mapping = BTreeMap::from([
+            ("10".to_string(), "11".to_string()),
+            ("30".to_string(), "32".to_string()),
+        ]);
+        remap_discord_ids(&mut bundle, &mapping);
+
+        let config = bundle.config.unwrap();
+        assert_eq!(config.pointer("/bot/mode/guild_id"), Some(&json!(11)));
+        assert_eq!(
+            config.pointer("/bot/panel_super_admin_roles"),
+            Some(&json!([32]))
+        );
+        assert_eq!(
+            bundle.tables["panel_permissions"],
+            [
+                json!({ "subject_type": "role", "subject_id": "32" }),
+                json!({ "subject_type": "user", "subject_id": "40" }),
+            ]
+        );
+        assert_eq!(
+            bundle.tables["reminders"],
+            [json!({ "guild_id": 11, "target_roles": "32, 31" })]
+        );
+    }
+}
@@ -0,0 +1,261 @@
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::features::*;
+use crate::prelude::modules::*;
+use chrono::Utc;
+use serenity::all::Context;
+use std::sync::{Arc, LazyLock};
+use std::time::{Duration, Instant};
+use tokio::select;
+use tokio::sync::watch::Receiver;
+use tokio::time::interval;
+use tracing::{Instrument, error, info, info_span};
+use uuid::Uuid;
+
+const JOB_POLL_INTERVAL: Duration = Duration::from_secs(1);
+const JOB_REQUEUE_INTERVAL: Duration = Duration::from_secs(60);
+const JOB_BATCH_SIZE: i64 = 20;
+const JOB_LEASE_SECS: i64 = 15 * 60;
+const JOB_LEASE_RENEW_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const JOB_BACKOFF_BASE_SECS: i64 = 30;
+const JOB_BACKOFF_MAX_SECS: i64 = 60 * 60;
+
+pub const THREAD_STATUS_REFRESH_INTERVAL_SECS: i64 = 10 * 60;
+pub const THREAD_STATUS_REFRESH_KEY: &str = "all";
+
+// Tells jobs left `running` by a previous process apart from ours.
+static WORKER_ID: LazyLock<String> = LazyLock::new(|| Uuid::new_v4().to_string());
+
+pub enum JobOutcome {
+    Done,
+    RunAt(i64),
+}
+
+pub type JobResult = Result<JobOutcome, String>;
+
+pub fn retry_backoff_secs(attempts: i64) -> i64 {
+    let exponent = attempts.saturating_sub(1).clamp(0, 16) as u32;
+    JOB_BACKOFF_BASE_SECS
+        .saturating_mul(2i64.saturating_pow(exponent))
+        .min(JOB_BACKOFF_MAX_SECS)
+}
+
+fn next_retry_at(job: &Job, now: i64) -> Option<i64> {
+    (job.attempts < job.max_attempts).then(|| now + retry_backoff_secs(job.attempts))
+}
+
+pub async fn run_job_scheduler(ctx: Context, config: ConfigHandle, mut shutdown: Receiver<bool>) {
+    let Some(pool) = config.load().db_pool.clone() else {
+        error!("Job scheduler not started: database pool is not set in config.");
+        return;
+    };
+
+    if let Err(e) = ensure_job(
+        JobKind::ThreadStatusRefresh,
+        THREAD_STATUS_REFRESH_KEY,
+        Utc::now().timestamp() + THREAD_STATUS_REFRESH_INTERVAL_SECS,
+        &pool,
+    )
+    .await
+    {
+        error!("Failed to schedule thread status refresh: {e:?}");
+    }
+
+    if let Err(e) = ensure_job(
+        JobKind::SessionMaintenance,
+        SESSION_MAINTENANCE_KEY,
+        Utc::now().timestamp() + SESSION_MAINTENANCE_INTERVAL_SECS,
+        &pool,
+    )
+    .await
+    {
+        error!("Failed to schedule panel session maintenance: {e:?}");
+    }
+
+    if let Err(e) = ensure_job(
+        JobKind::DatabaseBackup,
+        DATABASE_BACKUP_KEY,
+        Utc::now().timestamp(),
+        &pool,
+    )
+    .await
+    {
+        error!("Failed to schedule database backups: {e:?}");
+    }
+
+    if let Err(e) = ensure_job(
+        JobKind::Retention,
+        RETENTION_KEY,
+        next_retention_run(Utc::now(), config.load().bot.timezone),
+        &pool,
+    )
+    .await
+    {
+        error!("Failed to schedule data retention: {e:?}");
+    }
+
+    if let Err(e) = ensure_job(
+        JobKind::InactivityClose,
+        INACTIVITY_CLOSE_KEY,
+        Utc::now().timestamp() + INACTIVITY_CLOSE_INTERVAL_SECS,
+        &pool,
+    )
+    .await
+    {
+        error!("Failed to schedule inactivity closing: {e:?}");
+    }
+
+    if config.load().thread.board_channel_id.is_some()
+        && let Err(e) = ensure_job(
+            JobKind::TicketBoardRefresh,
+            TICKET_BOARD_KEY,
+            Utc::now().timestamp(),
+            &pool,
+        )
+        .await
+    {
+        error!("Failed to schedule ticket board refresh: {e:?}");
+    }
+
+    let mut ticker = interval(JOB_POLL_INTERVAL);
+    let mut last_requeue: Option<Instant> = None;
+
+    info!("Job scheduler started.");
+
+    loop {
+        select! {
+            _ = shutdown.changed() => break,
+            _ = ticker.tick() => {}
+        }
+
+        if last_requeue.is_none_or(|at| at.elapsed() >= JOB_REQUEUE_INTERVAL) {
+            let cutoff = Utc::now().timestamp() - JOB_LEASE_SECS;
+            match requeue_stale_jobs(&WORKER_ID, cutoff, &pool).await {
+                Ok(0) => {}
+                Ok(n) => info!("Requeued {} stale job(s)", n),
+                Err(e) => error!("Failed to requeue stale jobs: {e:?}"),
+            }
+            last_requeue = Some(Instant::now());
+        }
+
+        let jobs = match claim_due_jobs(&WORKER_ID, JOB_BATCH_SIZE, &pool).await {
+            Ok(jobs) => jobs,
+            Err(_) => continue,
+        };
+
+        for job in jobs {
+            let span = info_span!("job", job_id = job.id, kind = %job.kind, key = %job.job_key);
+            tokio::spawn(execute_job(ctx.clone(), config.load(), job).instrument(span));
+        }
+    }
+
+    info!("Job scheduler stopped.");
+}
+
+async fn execute_job(ctx: Context, config: Arc<Config>, job: Job) {
+    let Some(pool) = config.db_pool.as_ref() else {
+        return;
+    };
+
+    let run = async {
+        match JobKind::parse(&job.kind) {
+            Some(JobKind::ScheduledClosure) => {
+                run_scheduled_closure_job(&ctx, &config, &job.job_key).await
+            }
+            Some(JobKind::Reminder) => run_reminder_job(&ctx, &config, &job.job_key).await,
+            Some(JobKind::CategorySelectionTimeout) => {
+                run_category_timeout_job(&ctx, &config, &job.job_key).await
+            }
+            Some(JobKind::ThreadStatusRefresh) => run_thread_status_refresh_job(&ctx, pool).await,
+            Some(JobKind::SessionMaintenance) => run_session_maintenance_job(&config, pool).await,
+            Some(JobKind::PollClose) => run_poll_close_job(&ctx, &config, &job.job_key).await,
+            Some(JobKind::HoldExpiry) => run_hold_expiry_job(&ctx, &config, &job.job_key).await,
+            Some(JobKind::TicketBoardRefresh) => run_ticket_board_job(&ctx, &config).await,
+            Some(JobKind::DatabaseBackup) => run_backup_job(&config, pool).await,
+            Some(JobKind::Retention) => run_retention_job(&config, pool).await,
+            Some(JobKind::InactivityClose) => run_inactivity_close_job(&ctx, &config).await,
+            None => Err(format!("Unknown job kind '{}'", job.kind)),
+        }
+    };
+    tokio::pin!(run);
+
+    let mut renew = interval(JOB_LEASE_RENEW_INTERVAL);
+    renew.tick().await;
+    let result = loop {
+        select! {
+            result = &mut run => break result,
+            _ = renew.tick() => {
+                if let Err(e) = renew_job_lease(job.id, &WORKER_ID, pool).await {
+                    error!("Failed to renew the lease of job {}: {e:?}", job.id);
+                }
+            }
+        }
+    };
+
+    let update = match result {
+        Ok(JobOutcome::Done) => complete_job(job.id, pool).await,
+        Ok(JobOutcome::RunAt(run_at)) => reschedule_job(job.id, run_at, pool).await,
+        Err(e) => {
+            error!(
+                "Job {} ({} {}) failed on attempt {}/{}: {}",
+                job.id, job.kind, job.job_key, job.attempts, job.max_attempts, e
+            );
+            fail_job(
+                job.id,
+                &e,
+                next_retry_at(&job, Utc::now().timestamp()),
+                pool,
+            )
+            .await
+        }
+    };
+
+    if let Err(e) = update {
+        error!("Failed to record outcome of job {}: {e:?}", job.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_doubles_from_base() {
+        assert_eq!(retry_backoff_secs(1), 30);
+        assert_eq!(retry_backoff_secs(2), 60);
+        assert_eq!(retry_backoff_secs(3), 120);
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        assert_eq!(retry_backoff_secs(10), JOB_BACKOFF_MAX_SECS);
+        assert_eq!(retry_backoff_secs(i64::MAX), JOB_BACKOFF_MAX_SECS);
+    }
+
+    #[test]
+    fn backoff_handles_zero_attempts() {
+        assert_eq!(retry_backoff_secs(0), 30);
+    }
+
+    #[tokio::test]
+    async fn failing_jobs_are_retried_up_to_max_attempts() {
+        let pool = crate::db::operations::init::test_pool().await;
+        let id = enqueue_job(JobKind::Reminder, "1", "{}", 0, &pool)
+            .await
+            .unwrap();
+
+        let mut attempts = 0;
+        while let Some(job) = claim_due_jobs("a", 10, &pool).await.unwrap().pop() {
+            attempts += 1;
+            assert_eq!(job.attempts, attempts);
+            // Run the retry immediately instead of after the backoff.
+            let retry_at = next_retry_at(&job, 0).map(|_| 0);
+            fail_job(job.id, "boom", retry_at, &pool).await.unwrap();
+        }
+
+        let job = get_job(id, &pool).await.unwrap().unwrap();
+        assert_eq!(attempts, JOB_DEFAULT_MAX_ATTEMPTS);
+        assert_eq!(job.status, "failed");
+        assert_eq!(job.last_error.as_deref(), Some("boom"));
+    }
+}
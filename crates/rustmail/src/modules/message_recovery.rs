@@ -5,6 +5,7 @@ use crate::prelude::utils::*;
 use chrono::{DateTime, Utc};
 use serenity::all::{ChannelId, Context, GetMessages, Message, MessageId, UserId};
 use std::collections::HashMap;
+use tracing::{error, info};
 
 pub struct MessageRecoveryResult {
     pub thread_id: String,
@@ -20,7 +21,7 @@ pub async fn recover_missing_messages(
     let pool = match &config.db_pool {
         Some(pool) => pool,
         None => {
-            eprintln!("Database pool is not set in config.");
+            error!("Database pool is not set in config.");
             return vec![];
         }
     };
@@ -32,7 +33,7 @@ pub async fn recover_missing_messages(
         },
         Ok(None) => Utc::now() - chrono::Duration::hours(1),
         Err(e) => {
-            eprintln!("Failed to get last recovery timestamp: {}", e);
+            error!("Failed to get last recovery timestamp: {}", e);
             Utc::now() - chrono::Duration::hours(1)
         }
     };
@@ -47,7 +48,7 @@ pub async fn recover_missing_messages(
     }
 
     if let Err(e) = update_last_recovery_timestamp(pool).await {
-        eprintln!("Failed to update last recovery timestamp: {}", e);
+        error!("Failed to update last recovery timestamp: {}", e);
     }
 
     results
@@ -222,18 +223,18 @@ pub async fn send_recovery_summary(
     let summary_message =
         get_translated_message(config, "recovery.summary", Some(&params), None, None, None).await;
 
-    println!("=== Récupération des messages terminée ===");
-    println!("{}", summary_message);
+    info!("=== Récupération des messages terminée ===");
+    info!("{}", summary_message);
 
     for result in results {
         if result.recovered_count > 0 {
-            println!(
+            info!(
                 "Thread {}: {} messages récupérés",
                 result.thread_id, result.recovered_count
             );
         }
         if let Some(error) = &result.error_message {
-            println!("Thread {}: Erreur - {}", result.thread_id, error);
+            info!("Thread {}: Erreur - {}", result.thread_id, error);
         }
     }
 }
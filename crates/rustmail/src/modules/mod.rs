@@ -1,15 +1,39 @@
+pub mod backups;
+pub mod board;
 pub mod categories;
+pub mod category_access;
+pub mod command_permissions;
 pub mod commands;
+pub mod gdpr;
+pub mod imports;
+pub mod instance;
+pub mod jobs;
 pub mod message_recovery;
+pub mod priorities;
 pub mod reminders;
+pub mod retention;
 pub mod scheduled_closures;
+pub mod sessions;
 pub mod threads;
 pub mod threads_status;
+pub mod workflow;
 
+pub use backups::*;
+pub use board::*;
 pub use categories::*;
+pub use category_access::*;
+pub use command_permissions::*;
 pub use commands::*;
+pub use gdpr::*;
+pub use imports::*;
+pub use instance::*;
+pub use jobs::*;
 pub use message_recovery::*;
+pub use priorities::*;
 pub use reminders::*;
+pub use retention::*;
 pub use scheduled_closures::*;
+pub use sessions::*;
 pub use threads::*;
 pub use threads_status::*;
+pub use workflow::*;
@@ -0,0 +1,185 @@
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
+use serenity::all::{ChannelId, ChannelType, Context, GuildId, UserId};
+use std::cmp::Reverse;
+use tracing::error;
+
+pub fn matching_rule_priority(rules: &[PriorityRule], content: &str) -> Option<TicketPriority> {
+    let content = content.to_lowercase();
+    rules
+        .iter()
+        .filter(|rule| {
+            let keyword = rule.keyword.trim().to_lowercase();
+            !keyword.is_empty() && content.contains(&keyword)
+        })
+        .map(|rule| rule.priority)
+        .max()
+}
+
+pub async fn initial_priority(
+    pool: &sqlx::SqlitePool,
+    ticket_category_id: Option<&str>,
+    first_message: Option<&str>,
+) -> TicketPriority {
+    let mut priority = TicketPriority::Normal;
+
+    if let Some(cat_id) = ticket_category_id
+        && let Ok(Some(category)) = get_category_by_id(cat_id, pool).await
+        && let Some(default) = category.default_priority
+    {
+        priority = default;
+    }
+
+    if let Some(content) = first_message {
+        match list_priority_rules(pool).await {
+            Ok(rules) => {
+                if let Some(matched) = matching_rule_priority(&rules, content) {
+                    priority = priority.max(matched);
+                }
+            }
+            Err(e) => error!("Failed to load priority rules: {e:?}"),
+        }
+    }
+
+    priority
+}
+
+// Ticket channels sorted by priority below any other channel; ties keep their current order.
+pub fn priority_positions(
+    channels: &[(ChannelId, u16, Option<TicketPriority>)],
+) -> Vec<(ChannelId, u64)> {
+    let mut ordered = channels.to_vec();
+    ordered.sort_by_key(|(id, position, _)| (*position, *id));
+    let base = ordered.first().map(|(_, p, _)| *p as u64).unwrap_or(0);
+
+    ordered.sort_by_key(|(_, _, priority)| priority.map(Reverse));
+
+    ordered
+        .into_iter()
+        .enumerate()
+        .map(|(i, (id, position, _))| (id, position as u64, base + i as u64))
+        .filter(|(_, current, desired)| current != desired)
+        .map(|(id, _, desired)| (id, desired))
+        .collect()
+}
+
+pub async fn reorder_channels_by_priority(
+    ctx: &Context,
+    config: &Config,
+    parent_id: ChannelId,
+) -> ModmailResult<()> {
+    let pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+    let guild_id = GuildId::new(config.bot.get_staff_guild_id());
+
+    let priorities = list_open_channel_priorities(pool).await?;
+    let channels: Vec<_> = guild_id
+        .channels(&ctx.http)
+        .await?
+        .into_values()
+        .filter(|c| c.kind == ChannelType::Text && c.parent_id == Some(parent_id))
+        .map(|c| {
+            let priority = priorities.get(&c.id.to_string()).copied();
+            (c.id, c.position, priority)
+        })
+        .collect();
+
+    let positions = priority_positions(&channels);
+    if !positions.is_empty() {
+        guild_id.reorder_channels(&ctx.http, positions).await?;
+    }
+    Ok(())
+}
+
+pub async fn set_ticket_priority(
+    ctx: &Context,
+    config: &Config,
+    thread: &Thread,
+    priority: TicketPriority,
+) -> ModmailResult<bool> {
+    let pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+
+    set_thread_priority(&thread.id, priority, pool).await?;
+
+    let applied = match get_thread_status(&thread.id, pool).await {
+        Some(ticket) => update_thread_status_ui(ctx, &ticket).await.unwrap_or(true),
+        None => true,
+    };
+
+    let channel_id = ChannelId::new(thread.channel_id.parse::<u64>().unwrap_or(0));
+    if let Ok(channel) = channel_id.to_channel(&ctx.http).await
+        && let Some(parent_id) = channel.guild().and_then(|c| c.parent_id)
+        && let Err(e) = reorder_channels_by_priority(ctx, config, parent_id).await
+    {
+        error!("Failed to reorder ticket channels by priority: {e:?}");
+    }
+
+    Ok(applied)
+}
+
+pub async fn priority_label(
+    config: &Config,
+    priority: TicketPriority,
+    user_id: UserId,
+    guild_id: Option<GuildId>,
+) -> String {
+    get_translated_message(
+        config,
+        &format!("priority.{}", priority.as_str()),
+        None,
+        Some(user_id),
+        guild_id.map(|g| g.get()),
+        None,
+    )
+    .await
+}
+
+pub fn new_ticket_channel_name(priority: TicketPriority, username: &str) -> String {
+    format!("{}🔴・{}・0m", priority.channel_prefix(), username)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highest_matching_rule_wins() {
+        let rules = vec![
+            PriorityRule {
+                keyword: "refund".to_string(),
+                priority: TicketPriority::High,
+            },
+            PriorityRule {
+                keyword: "Hacked".to_string(),
+                priority: TicketPriority::Urgent,
+            },
+        ];
+
+        assert_eq!(
+            matching_rule_priority(&rules, "I got HACKED, need a refund"),
+            Some(TicketPriority::Urgent)
+        );
+        assert_eq!(matching_rule_priority(&rules, "hello"), None);
+    }
+
+    #[test]
+    fn priority_positions_sorts_tickets_below_other_channels() {
+        let id = ChannelId::new;
+        let channels = vec![
+            (id(1), 3, Some(TicketPriority::Normal)),
+            (id(2), 4, Some(TicketPriority::Urgent)),
+            (id(3), 2, None),
+            (id(4), 5, Some(TicketPriority::Low)),
+        ];
+
+        assert_eq!(priority_positions(&channels), vec![(id(2), 3), (id(1), 4)]);
+    }
+}
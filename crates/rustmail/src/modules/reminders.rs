@@ -1,23 +1,29 @@
 use crate::prelude::commands::*;
 use crate::prelude::config::*;
 use crate::prelude::db::*;
+use crate::prelude::modules::*;
+use chrono::Utc;
 use serenity::all::Context;
-use std::sync::Arc;
-use tokio::sync::watch::Receiver;
 
-pub async fn load_reminders(
-    ctx: &Context,
-    config: &Config,
-    pool: &sqlx::SqlitePool,
-    shutdown: Arc<Receiver<bool>>,
-) {
-    let reminders = get_all_pending_reminders(pool).await.unwrap_or_else(|e| {
-        eprintln!("Failed to fetch pending reminders: {:?}", e);
-        Vec::new()
-    });
+pub async fn run_reminder_job(ctx: &Context, config: &Config, key: &str) -> JobResult {
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Err("Database pool is not set in config".to_string());
+    };
 
-    for reminder in reminders {
-        spawn_reminder(&reminder, None, ctx, config, pool, shutdown.clone());
+    let reminder_id = key
+        .parse::<i64>()
+        .map_err(|_| format!("Invalid reminder id '{}'", key))?;
+
+    let reminder = match get_reminder_by_id(reminder_id, pool).await {
+        Ok(Some(r)) if !r.completed => r,
+        Ok(_) => return Ok(JobOutcome::Done),
+        Err(e) => return Err(e.to_string()),
+    };
+
+    if reminder.trigger_time > Utc::now().timestamp() {
+        return Ok(JobOutcome::RunAt(reminder.trigger_time));
     }
-    println!("All pending reminders have been scheduled.");
+
+    send_reminder(ctx, config, pool, &reminder).await?;
+    Ok(JobOutcome::Done)
 }
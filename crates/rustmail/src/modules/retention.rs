@@ -0,0 +1,96 @@
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::modules::*;
+use chrono::{DateTime, Days, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use sqlx::SqlitePool;
+use std::collections::BTreeMap;
+use tracing::{error, info};
+
+pub const RETENTION_KEY: &str = "nightly";
+
+const RETENTION_RUN_HOUR: u32 = 3;
+
+pub fn next_retention_run(now: DateTime<Utc>, tz: Tz) -> i64 {
+    let run_time = NaiveTime::from_hms_opt(RETENTION_RUN_HOUR, 0, 0).unwrap_or_default();
+    let today = now.with_timezone(&tz).date_naive();
+
+    [Some(today), today.checked_add_days(Days::new(1))]
+        .into_iter()
+        .flatten()
+        .filter_map(|day| tz.from_local_datetime(&day.and_time(run_time)).earliest())
+        .map(|local| local.timestamp())
+        .find(|run_at| *run_at > now.timestamp())
+        .unwrap_or(now.timestamp() + 24 * 60 * 60)
+}
+
+pub async fn run_retention_rule(
+    rule: &RetentionRule,
+    pool: &SqlitePool,
+) -> ModmailResult<BTreeMap<String, u64>> {
+    let now = Utc::now().timestamp();
+    let cutoff = retention_cutoff(rule.older_than_days, now);
+
+    let affected = apply_retention(
+        rule.target,
+        cutoff,
+        rule.ticket_category_id.as_deref(),
+        pool,
+    )
+    .await?;
+    record_retention_run(rule.id, affected.values().sum(), now, pool).await?;
+
+    Ok(affected)
+}
+
+pub async fn run_retention_job(config: &Config, pool: &SqlitePool) -> JobResult {
+    let next_run = next_retention_run(Utc::now(), config.bot.timezone);
+    let rules = list_retention_rules(pool)
+        .await
+        .map_err(|e| format!("{e:?}"))?;
+
+    for rule in rules.iter().filter(|rule| rule.enabled) {
+        match run_retention_rule(rule, pool).await {
+            Ok(affected) => {
+                let total: u64 = affected.values().sum();
+                if total > 0 {
+                    info!(
+                        "Retention rule {} ({}) pruned {} row(s)",
+                        rule.id,
+                        rule.target.as_str(),
+                        total
+                    );
+                }
+            }
+            Err(e) => error!("Failed to apply retention rule {}: {e:?}", rule.id),
+        }
+    }
+
+    Ok(JobOutcome::RunAt(next_run))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_run_is_the_coming_local_night() {
+        let paris: Tz = "Europe/Paris".parse().unwrap();
+        let before = Utc.with_ymd_and_hms(2026, 3, 10, 0, 30, 0).unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 3, 10, 5, 0, 0).unwrap();
+
+        assert_eq!(
+            next_retention_run(before, paris),
+            Utc.with_ymd_and_hms(2026, 3, 10, 2, 0, 0)
+                .unwrap()
+                .timestamp()
+        );
+        assert_eq!(
+            next_retention_run(after, paris),
+            Utc.with_ymd_and_hms(2026, 3, 11, 2, 0, 0)
+                .unwrap()
+                .timestamp()
+        );
+    }
+}
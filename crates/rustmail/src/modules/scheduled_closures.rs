@@ -1,155 +1,85 @@
 use crate::prelude::config::*;
 use crate::prelude::db::*;
+use crate::prelude::modules::*;
 use crate::prelude::utils::*;
 use chrono::Utc;
 use serenity::all::{ChannelId, Context, UserId};
-use tokio::time::{Duration, sleep};
 
-pub fn schedule_one(ctx: &Context, config: &Config, thread_id: String, close_at: i64) {
-    let now = Utc::now().timestamp();
-    let delay_secs = (close_at - now).max(0) as u64;
-    let ctx_clone = ctx.clone();
-    let config_clone = config.clone();
-
-    tokio::spawn(async move {
-        if delay_secs > 0 {
-            sleep(Duration::from_secs(delay_secs)).await;
-        }
-        if let Some(pool) = config_clone.db_pool.as_ref()
-            && let Ok(Some(current)) = get_scheduled_closure(&thread_id, pool).await
-        {
-            if current.close_at <= Utc::now().timestamp() {
-                if let Some(thread) = get_thread_by_id(&thread_id, pool).await {
-                    let channel_id = ChannelId::new(thread.channel_id.parse::<u64>().unwrap_or(0));
-                    let user_id = UserId::new(thread.user_id as u64);
-
-                    let _ = close_thread(
-                        &thread_id,
-                        &current.closed_by,
-                        &current.category_id,
-                        &current.category_name,
-                        current.required_permissions.parse::<u64>().unwrap_or(0),
-                        pool,
-                    )
-                    .await;
-                    let _ = delete_scheduled_closure(&thread_id, pool).await;
-
-                    if config_clone.bot.enable_rustmail_logs
-                        && let Some(logs_channel_id) = config_clone.bot.logs_channel_id
-                    {
-                        let base_url = config_clone
-                            .bot
-                            .redirect_url
-                            .trim_end_matches("/api/auth/callback")
-                            .trim_end_matches('/');
-
-                        let panel_url = format!("{}/panel/tickets/{}", base_url, thread.id);
-
-                        let mut params = std::collections::HashMap::new();
-                        params.insert("username".to_string(), thread.user_name.clone());
-                        params.insert("user_id".to_string(), thread.user_id.to_string());
-                        params.insert("panel_url".to_string(), panel_url);
-
-                        let _ = MessageBuilder::system_message(&ctx_clone, &config_clone)
-                            .translated_content("logs.ticket_closed", Some(&params), None, None)
-                            .await
-                            .to_channel(ChannelId::new(logs_channel_id))
-                            .send(true)
-                            .await;
-                    }
-
-                    let effective_silent =
-                        current.silent || is_thread_silent(&thread_id, pool).await;
-                    if !effective_silent {
-                        let _ = MessageBuilder::system_message(&ctx_clone, &config_clone)
-                            .content(&config_clone.bot.close_message)
-                            .to_user(user_id)
-                            .send(true)
-                            .await;
-                    }
-                    let _ = channel_id.delete(&ctx_clone.http).await;
-                } else {
-                    let _ = delete_scheduled_closure(&thread_id, pool).await;
-                }
-            } else {
-                schedule_one(
-                    &ctx_clone,
-                    &config_clone,
-                    thread_id.clone(),
-                    current.close_at,
-                );
-            }
-        }
-    });
-}
-
-pub async fn hydrate_scheduled_closures(ctx: &Context, config: &Config) {
+// A missing `scheduled_closures` row means the closure was cancelled.
+pub async fn run_scheduled_closure_job(
+    ctx: &Context,
+    config: &Config,
+    thread_id: &str,
+) -> JobResult {
     let Some(pool) = config.db_pool.as_ref() else {
-        return;
+        return Err("Database pool is not set in config".to_string());
     };
 
-    let list = match get_all_scheduled_closures(pool).await {
-        Ok(l) => l,
-        Err(e) => {
-            eprintln!("Failed to load scheduled closures: {e:?}");
-            return;
-        }
+    let current = match get_scheduled_closure(thread_id, pool).await {
+        Ok(Some(sc)) => sc,
+        Ok(None) => return Ok(JobOutcome::Done),
+        Err(e) => return Err(e.to_string()),
     };
 
-    for sc in list {
-        if let Some(thread) = get_thread_by_id(&sc.thread_id, pool).await {
-            if sc.close_at <= Utc::now().timestamp() {
-                let channel_id = ChannelId::new(thread.channel_id.parse::<u64>().unwrap_or(0));
-                let user_id = UserId::new(thread.user_id as u64);
-                let _ = close_thread(
-                    &thread.id,
-                    &sc.closed_by,
-                    &sc.category_id,
-                    &sc.category_name,
-                    sc.required_permissions.parse::<u64>().unwrap_or(0),
-                    pool,
-                )
-                .await;
-                let _ = delete_scheduled_closure(&thread.id, pool).await;
-
-                if config.bot.enable_rustmail_logs
-                    && let Some(logs_channel_id) = config.bot.logs_channel_id
-                {
-                    let base_url = config
-                        .bot
-                        .redirect_url
-                        .trim_end_matches("/api/auth/callback")
-                        .trim_end_matches('/');
-
-                    let panel_url = format!("{}/panel/tickets/{}", base_url, thread.id);
+    if current.close_at > Utc::now().timestamp() {
+        return Ok(JobOutcome::RunAt(current.close_at));
+    }
 
-                    let mut params = std::collections::HashMap::new();
-                    params.insert("username".to_string(), thread.user_name.clone());
-                    params.insert("user_id".to_string(), thread.user_id.to_string());
-                    params.insert("panel_url".to_string(), panel_url);
+    let Some(thread) = get_thread_by_id(thread_id, pool).await else {
+        let _ = delete_scheduled_closure(thread_id, pool).await;
+        return Ok(JobOutcome::Done);
+    };
 
-                    let _ = MessageBuilder::system_message(ctx, config)
-                        .translated_content("logs.ticket_closed", Some(&params), None, None)
-                        .await
-                        .to_channel(ChannelId::new(logs_channel_id))
-                        .send(true)
-                        .await;
-                }
+    let channel_id = ChannelId::new(thread.channel_id.parse::<u64>().unwrap_or(0));
+    let user_id = UserId::new(thread.user_id as u64);
+
+    close_thread(
+        &current.thread_id,
+        &current.closed_by,
+        &current.category_id,
+        &current.category_name,
+        current.required_permissions.parse::<u64>().unwrap_or(0),
+        pool,
+    )
+    .await
+    .map_err(|e| e.to_string())?;
+    let _ = delete_scheduled_closure(thread_id, pool).await;
+
+    if config.bot.enable_rustmail_logs
+        && let Some(logs_channel_id) = config.bot.logs_channel_id
+    {
+        let base_url = config
+            .bot
+            .redirect_url
+            .trim_end_matches("/api/auth/callback")
+            .trim_end_matches('/');
+
+        let panel_url = format!("{}/panel/tickets/{}", base_url, thread.id);
+
+        let mut params = std::collections::HashMap::new();
+        params.insert("username".to_string(), thread.user_name.clone());
+        params.insert("user_id".to_string(), thread.user_id.to_string());
+        params.insert("panel_url".to_string(), panel_url);
+
+        let _ = MessageBuilder::system_message(ctx, config)
+            .translated_content("logs.ticket_closed", Some(&params), None, None)
+            .await
+            .to_channel(ChannelId::new(logs_channel_id))
+            .send(true)
+            .await;
+    }
 
-                let effective_silent = sc.silent || is_thread_silent(&thread.id, pool).await;
-                if !effective_silent {
-                    let _ = MessageBuilder::system_message(ctx, config)
-                        .content(&config.bot.close_message)
-                        .to_user(user_id)
-                        .send(true)
-                        .await;
-                }
-                let _ = channel_id.delete(&ctx.http).await;
-            } else {
-                schedule_one(ctx, config, sc.thread_id.clone(), sc.close_at);
-            }
-        } else {
-            let _ = delete_scheduled_closure(&sc.thread_id, pool).await;
-        }
+    let effective_silent = current.silent || is_thread_silent(thread_id, pool).await;
+    if !effective_silent {
+        let overrides = category_messages_for_thread(config, thread_id, user_id).await;
+        let _ = overrides
+            .apply_color(MessageBuilder::system_message(ctx, config))
+            .content(overrides.close_message(config, user_id))
+            .to_user(user_id)
+            .send(true)
+            .await;
     }
+    let _ = channel_id.delete(&ctx.http).await;
+
+    Ok(JobOutcome::Done)
 }
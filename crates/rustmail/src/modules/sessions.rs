@@ -0,0 +1,103 @@
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::modules::*;
+use chrono::Utc;
+use reqwest::Client;
+use sqlx::SqlitePool;
+use tracing::{error, info, warn};
+
+pub const SESSION_MAINTENANCE_INTERVAL_SECS: i64 = 15 * 60;
+pub const SESSION_MAINTENANCE_KEY: &str = "all";
+
+pub const SESSION_MAX_AGE_SECS: i64 = 30 * 24 * 60 * 60;
+
+const SESSION_REFRESH_WINDOW_SECS: i64 = 24 * 60 * 60;
+
+enum RefreshOutcome {
+    Refreshed,
+    Rejected,
+}
+
+async fn refresh_session_token(
+    client: &Client,
+    config: &Config,
+    session_id: &str,
+    refresh_token: &str,
+    pool: &SqlitePool,
+) -> Result<RefreshOutcome, String> {
+    let response = client
+        .post("https://discord.com/api/oauth2/token")
+        .form(&[
+            ("client_id", config.bot.client_id.to_string().as_str()),
+            ("client_secret", config.bot.client_secret.as_str()),
+            ("grant_type", "refresh_token"),
+            ("refresh_token", refresh_token),
+        ])
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let status = response.status();
+    if status.is_client_error() {
+        return Ok(RefreshOutcome::Rejected);
+    }
+    if !status.is_success() {
+        return Err(format!("Discord answered {}", status));
+    }
+
+    let token_data: serde_json::Value = response.json().await.map_err(|e| e.to_string())?;
+    let Some(access_token) = token_data["access_token"].as_str() else {
+        return Err("Missing access_token in refresh response".to_string());
+    };
+    let new_refresh_token = token_data["refresh_token"]
+        .as_str()
+        .unwrap_or(refresh_token);
+    let expires_in = token_data["expires_in"].as_i64().unwrap_or(3600);
+
+    update_session_tokens(
+        session_id,
+        access_token,
+        new_refresh_token,
+        Utc::now().timestamp() + expires_in,
+        pool,
+    )
+    .await
+    .map_err(|e| format!("{e:?}"))?;
+
+    Ok(RefreshOutcome::Refreshed)
+}
+
+pub async fn run_session_maintenance_job(config: &Config, pool: &SqlitePool) -> JobResult {
+    let now = Utc::now().timestamp();
+
+    match delete_expired_sessions(now, now - SESSION_MAX_AGE_SECS, pool).await {
+        Ok(0) => {}
+        Ok(n) => info!("Deleted {} expired panel session(s)", n),
+        Err(e) => error!("Failed to delete expired panel sessions: {e:?}"),
+    }
+
+    let to_refresh = list_sessions_to_refresh(now + SESSION_REFRESH_WINDOW_SECS, pool)
+        .await
+        .map_err(|e| format!("{e:?}"))?;
+
+    let client = Client::new();
+    let mut refreshed = 0;
+    for (session_id, refresh_token) in to_refresh {
+        match refresh_session_token(&client, config, &session_id, &refresh_token, pool).await {
+            Ok(RefreshOutcome::Refreshed) => refreshed += 1,
+            Ok(RefreshOutcome::Rejected) => {
+                warn!("Discord rejected a panel session refresh token, revoking the session");
+                if let Err(e) = delete_session(&session_id, pool).await {
+                    error!("Failed to revoke panel session: {e:?}");
+                }
+            }
+            Err(e) => error!("Failed to refresh panel session token: {}", e),
+        }
+    }
+
+    if refreshed > 0 {
+        info!("Refreshed {} panel session token(s)", refreshed);
+    }
+
+    Ok(JobOutcome::RunAt(now + SESSION_MAINTENANCE_INTERVAL_SECS))
+}
@@ -1,3 +1,8 @@
+use crate::modules::categories::category_messages_for_user;
+use crate::modules::category_access::apply_category_access;
+use crate::modules::priorities::{
+    initial_priority, new_ticket_channel_name, reorder_channels_by_priority,
+};
 use crate::prelude::config::*;
 use crate::prelude::db::*;
 use crate::prelude::errors::*;
@@ -12,6 +17,7 @@ use std::collections::HashMap;
 use std::str::FromStr;
 use std::time::Duration;
 use tokio::time::sleep;
+use tracing::{error, info};
 
 pub async fn create_or_get_thread_for_user(
     ctx: &Context,
@@ -19,11 +25,12 @@ pub async fn create_or_get_thread_for_user(
     user_id: UserId,
     discord_category_override: Option<u64>,
     ticket_category_id: Option<&str>,
+    first_message: Option<&str>,
 ) -> Result<(ChannelId, bool), Box<dyn std::error::Error + Send + Sync>> {
     let pool = match &config.db_pool {
         Some(pool) => pool,
         None => {
-            eprintln!("Database pool is not set in config.");
+            error!("Database pool is not set in config.");
             return Err("Database pool not available".into());
         }
     };
@@ -33,7 +40,8 @@ pub async fn create_or_get_thread_for_user(
         Err(_) => user_id.get().to_string(),
     };
 
-    let thread_name = format!("🔴・{}・0m", username);
+    let priority = initial_priority(pool, ticket_category_id, first_message).await;
+    let thread_name = new_ticket_channel_name(priority, &username);
 
     let staff_guild_id = GuildId::new(config.bot.get_staff_guild_id());
     let parent_id = discord_category_override.unwrap_or(config.thread.inbox_category_id);
@@ -46,14 +54,23 @@ pub async fn create_or_get_thread_for_user(
     let thread_id = create_thread_for_user(&channel, user_id.get() as i64, &username, false, pool)
         .await
         .map_err(|e| {
-            eprintln!("Error creating thread: {}", e);
+            error!("Error creating thread: {}", e);
             e
         })?;
 
-    if let Some(cat_id) = ticket_category_id
-        && let Err(e) = set_thread_category(&thread_id, Some(cat_id), pool).await
+    if let Some(cat_id) = ticket_category_id {
+        if let Err(e) = set_thread_category(&thread_id, Some(cat_id), pool).await {
+            error!("Failed to set thread category for thread {thread_id}: {e}");
+        }
+        if let Err(e) = apply_category_access(ctx, config, channel.id, Some(cat_id)).await {
+            error!("Failed to apply category access for thread {thread_id}: {e}");
+        }
+    }
+
+    if priority != TicketPriority::Normal
+        && let Err(e) = set_thread_priority(&thread_id, priority, pool).await
     {
-        eprintln!("Failed to set thread category for thread {thread_id}: {e}");
+        error!("Failed to set priority for thread {thread_id}: {e}");
     }
 
     let canonical_channel_id_str = get_thread_channel_by_user_id(user_id, pool).await;
@@ -74,6 +91,10 @@ pub async fn create_or_get_thread_for_user(
         };
 
     if is_new_thread {
+        if let Err(e) = reorder_channels_by_priority(ctx, config, ChannelId::new(parent_id)).await {
+            error!("Failed to reorder ticket channels by priority: {e:?}");
+        }
+
         let community_guild_id = GuildId::new(config.bot.get_community_guild_id());
         let member_join_date = get_member_join_date_for_user(ctx, user_id, community_guild_id)
             .await
@@ -114,13 +135,29 @@ pub async fn create_or_get_thread_for_user(
             .send(true)
             .await;
 
-        let _ = MessageBuilder::system_message(ctx, config)
-            .content(&config.bot.welcome_message)
+        let overrides = category_messages_for_user(config, ticket_category_id, user_id).await;
+        let _ = overrides
+            .apply_color(MessageBuilder::system_message(ctx, config))
+            .content(overrides.welcome_message(config, user_id))
             .to_user(user_id)
             .send(true)
             .await;
 
-        println!("Thread created successfully");
+        if let Some(auto_reply) = overrides.auto_reply(user_id) {
+            let _ = overrides
+                .apply_color(MessageBuilder::system_message(ctx, config))
+                .content(auto_reply)
+                .to_user(user_id)
+                .send(true)
+                .await;
+        }
+
+        info!(
+            thread_id = %thread_id,
+            user_id = %user_id,
+            channel_id = %target_channel_id,
+            "Thread created"
+        );
     }
 
     Ok((target_channel_id, is_new_thread))
@@ -167,17 +204,25 @@ pub async fn create_channel(ctx: &Context, msg: &Message, config: &Config) {
         }
     }
 
-    let (target_channel_id, _is_new_thread) =
-        match create_or_get_thread_for_user(ctx, config, msg.author.id, None, None).await {
-            Ok(res) => res,
-            Err(e) => {
-                eprintln!("Failed to create or get thread: {}", e);
-                return;
-            }
-        };
+    let (target_channel_id, _is_new_thread) = match create_or_get_thread_for_user(
+        ctx,
+        config,
+        msg.author.id,
+        None,
+        None,
+        Some(&msg.content),
+    )
+    .await
+    {
+        Ok(res) => res,
+        Err(e) => {
+            error!("Failed to create or get thread: {}", e);
+            return;
+        }
+    };
 
     if let Err(e) = send_to_thread(ctx, target_channel_id, msg, config, false).await {
-        eprintln!("Failed to forward message to thread: {:?}", e);
+        error!("Failed to forward message to thread: {:?}", e);
     }
 }
 
@@ -250,7 +295,7 @@ pub async fn handle_thread_modal_interaction(
                 Some(id) => match UserId::from_str(id.as_str()) {
                     Ok(user_id) => user_id,
                     Err(_) => {
-                        eprintln!("Invalid user ID provided in modal interaction: {}", id);
+                        error!("Invalid user ID provided in modal interaction: {}", id);
                         let _ = interaction
                             .create_followup(
                                 &ctx.http,
@@ -279,7 +324,7 @@ pub async fn handle_thread_modal_interaction(
             let user = match user_id.to_user(&ctx.http).await {
                 Ok(user) => user,
                 Err(_) => {
-                    eprintln!("Failed to fetch user by ID: {}", user_id);
+                    error!("Failed to fetch user by ID: {}", user_id);
                     let _ = interaction
                         .create_followup(
                             &ctx.http,
@@ -297,7 +342,7 @@ pub async fn handle_thread_modal_interaction(
             };
 
             if user.bot {
-                eprintln!(
+                error!(
                     "Attempted to create thread for a rustmail user: {}",
                     user_id
                 );
@@ -411,7 +456,7 @@ pub async fn handle_thread_modal_interaction(
             )
             .await
             {
-                eprintln!("Failed to create thread record: {}", e);
+                error!("Failed to create thread record: {}", e);
                 let _ = interaction
                     .create_followup(
                         &ctx.http,
@@ -473,12 +518,12 @@ pub async fn handle_thread_modal_interaction(
                     let _ = message.delete(&ctx.http).await;
                 }
                 None => {
-                    eprintln!("Failed to delete interaction message!");
+                    error!("Failed to delete interaction message!");
                 }
             }
         }
         _ => {
-            eprintln!("Unknown thread modal interaction action: {}", parts);
+            error!("Unknown thread modal interaction action: {}", parts);
             let _ = interaction
                 .create_followup(
                     &ctx.http,
@@ -600,7 +645,7 @@ pub async fn handle_thread_component_interaction(
                 .await;
         }
         _ => {
-            eprintln!("Unknown thread component interaction action: {}", parts);
+            error!("Unknown thread component interaction action: {}", parts);
             let _ = interaction
                 .create_followup(
                     &ctx.http,
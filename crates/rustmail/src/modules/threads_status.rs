@@ -1,11 +1,15 @@
+use crate::prelude::db::*;
 use crate::prelude::errors::*;
+use crate::prelude::modules::*;
 use crate::prelude::types::*;
 use chrono::Utc;
 use serenity::all::{ChannelId, UserId};
 use serenity::builder::EditChannel;
 use serenity::client::Context;
+use sqlx::SqlitePool;
 use std::time::Duration;
 use tokio::time::timeout;
+use tracing::{error, info};
 
 /// Updates the Discord channel name to reflect the ticket state.
 ///
@@ -14,10 +18,11 @@ use tokio::time::timeout;
 pub async fn update_thread_status_ui(ctx: &Context, ticket: &TicketState) -> ModmailResult<bool> {
     let channel = ChannelId::new(ticket.channel_id as u64);
 
-    let color = match ticket.last_message_by {
-        TicketAuthor::Staff => "🔵",
-        TicketAuthor::User => "🔴",
-    };
+    let color = format!(
+        "{}{}",
+        ticket.priority.channel_prefix(),
+        ticket.workflow_state.channel_marker()
+    );
 
     let elapsed = Utc::now().timestamp() - ticket.last_message_at;
     let minutes = elapsed / 60;
@@ -44,7 +49,7 @@ pub async fn update_thread_status_ui(ctx: &Context, ticket: &TicketState) -> Mod
     }
 
     let name = if let Some(label) = &ticket.label {
-        // Fixed parts: color(1 emoji) + "・" + "・" + owner + staff + "・" + time
+        // Fixed parts: priority + state emoji + "・" + "・" + owner + staff + "・" + time
         // We truncate the label so the total stays <= 100 chars.
         let without_label = format!("{color}・・{owner_name}{staff_name_part}・{time_str}");
         let budget = 100usize.saturating_sub(without_label.chars().count());
@@ -67,7 +72,7 @@ pub async fn update_thread_status_ui(ctx: &Context, ticket: &TicketState) -> Mod
         match channel.edit(&http, EditChannel::new().name(&name)).await {
             Ok(_) => Ok(()),
             Err(e) => {
-                eprintln!("Failed to edit channel {}: {:?}", channel_id_log, e);
+                error!("Failed to edit channel {}: {:?}", channel_id_log, e);
                 Err(e)
             }
         }
@@ -79,14 +84,14 @@ pub async fn update_thread_status_ui(ctx: &Context, ticket: &TicketState) -> Mod
         Ok(Ok(Ok(_))) => Ok(true),
         Ok(Ok(Err(e))) => Err(e.into()),
         Ok(Err(e)) => {
-            eprintln!(
+            error!(
                 "Edit task panicked for channel {}: {:?}",
                 ticket.channel_id, e
             );
             Ok(true)
         }
         Err(_) => {
-            eprintln!(
+            error!(
                 "Timeout editing channel {} — continuing in background",
                 ticket.channel_id
             );
@@ -94,3 +99,24 @@ pub async fn update_thread_status_ui(ctx: &Context, ticket: &TicketState) -> Mod
         }
     }
 }
+
+pub async fn run_thread_status_refresh_job(ctx: &Context, pool: &SqlitePool) -> JobResult {
+    let tickets_status = get_all_thread_status(pool).await;
+
+    for ticket in tickets_status.iter() {
+        if let Err(e) = update_thread_status_ui(ctx, ticket).await {
+            error!(
+                "Failed to update thread status for channel {}: {:?}",
+                ticket.channel_id, e
+            );
+        }
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+
+    info!("Updated {} ticket statuses", tickets_status.len());
+
+    Ok(JobOutcome::RunAt(
+        Utc::now().timestamp() + THREAD_STATUS_REFRESH_INTERVAL_SECS,
+    ))
+}
@@ -0,0 +1,285 @@
+use crate::prelude::config::*;
+use crate::prelude::db::*;
+use crate::prelude::errors::*;
+use crate::prelude::i18n::*;
+use crate::prelude::modules::*;
+use crate::prelude::types::*;
+use crate::prelude::utils::*;
+use chrono::Utc;
+use serenity::all::{ChannelId, Context, GuildChannel, GuildId, UserId};
+use std::collections::HashMap;
+use tracing::{error, info};
+
+pub const INACTIVITY_CLOSE_KEY: &str = "all";
+pub const INACTIVITY_CLOSE_INTERVAL_SECS: i64 = 5 * 60;
+
+pub fn resumed_state(last_message_by: TicketAuthor) -> TicketWorkflowState {
+    match last_message_by {
+        TicketAuthor::Staff => TicketWorkflowState::AwaitingUser,
+        TicketAuthor::User => TicketWorkflowState::AwaitingStaff,
+    }
+}
+
+pub async fn workflow_state_label(
+    config: &Config,
+    state: TicketWorkflowState,
+    user_id: UserId,
+    guild_id: Option<GuildId>,
+) -> String {
+    get_translated_message(
+        config,
+        &format!("workflow.state.{}", state.as_str()),
+        None,
+        Some(user_id),
+        guild_id.map(|g| g.get()),
+        None,
+    )
+    .await
+}
+
+async fn refresh_channel_name(ctx: &Context, thread_id: &str, pool: &sqlx::SqlitePool) {
+    if let Some(ticket) = get_thread_status(thread_id, pool).await
+        && let Err(e) = update_thread_status_ui(ctx, &ticket).await
+    {
+        error!("Failed to refresh ticket channel name: {e:?}");
+    }
+}
+
+pub async fn hold_ticket(
+    ctx: &Context,
+    config: &Config,
+    thread: &Thread,
+    until: Option<i64>,
+    actor: UserId,
+) -> ModmailResult<()> {
+    let pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+
+    set_thread_workflow_state(
+        &thread.id,
+        TicketWorkflowState::OnHold,
+        until,
+        Some(&actor.to_string()),
+        pool,
+    )
+    .await?;
+
+    match until {
+        Some(run_at) => {
+            enqueue_job(JobKind::HoldExpiry, &thread.id, "{}", run_at, pool).await?;
+        }
+        None => {
+            cancel_job(JobKind::HoldExpiry, &thread.id, pool).await?;
+        }
+    }
+
+    refresh_channel_name(ctx, &thread.id, pool).await;
+    Ok(())
+}
+
+async fn schedule_closure(
+    ctx: &Context,
+    thread_id: &str,
+    channel: &GuildChannel,
+    close_at: i64,
+    closed_by: UserId,
+    pool: &sqlx::SqlitePool,
+) -> ModmailResult<()> {
+    let category_id = get_category_id_from_guild_channel(ctx, channel).await;
+    let category_name = get_category_name_from_guild_channel(ctx, channel).await;
+    let required_permissions =
+        get_required_permissions_channel_from_guild_channel(ctx, channel).await;
+
+    upsert_scheduled_closure(
+        thread_id,
+        close_at,
+        false,
+        &closed_by.to_string(),
+        &category_id,
+        &category_name,
+        &required_permissions.to_string(),
+        pool,
+    )
+    .await
+}
+
+pub async fn resolve_ticket(
+    ctx: &Context,
+    config: &Config,
+    thread: &Thread,
+    channel: &GuildChannel,
+    delay_secs: u64,
+    actor: UserId,
+) -> ModmailResult<Option<i64>> {
+    let pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+
+    let close_at = (delay_secs > 0).then(|| Utc::now().timestamp() + delay_secs as i64);
+
+    set_thread_workflow_state(
+        &thread.id,
+        TicketWorkflowState::Resolved,
+        close_at,
+        Some(&actor.to_string()),
+        pool,
+    )
+    .await?;
+    cancel_job(JobKind::HoldExpiry, &thread.id, pool).await?;
+
+    if let Some(close_at) = close_at {
+        schedule_closure(ctx, &thread.id, channel, close_at, actor, pool).await?;
+    }
+
+    refresh_channel_name(ctx, &thread.id, pool).await;
+    Ok(close_at)
+}
+
+pub async fn resume_ticket(
+    ctx: &Context,
+    config: &Config,
+    thread: &Thread,
+    actor: Option<UserId>,
+) -> ModmailResult<TicketWorkflowState> {
+    let pool = config
+        .db_pool
+        .as_ref()
+        .ok_or_else(database_connection_failed)?;
+
+    let last_message_by = get_thread_status(&thread.id, pool)
+        .await
+        .map(|t| t.last_message_by)
+        .unwrap_or(TicketAuthor::User);
+    let state = resumed_state(last_message_by);
+
+    let current = get_thread_workflow(&thread.id, pool).await?.map(|(s, _)| s);
+    if current == Some(TicketWorkflowState::Resolved) {
+        delete_scheduled_closure(&thread.id, pool).await?;
+    }
+
+    set_thread_workflow_state(
+        &thread.id,
+        state,
+        None,
+        actor.map(|a| a.to_string()).as_deref(),
+        pool,
+    )
+    .await?;
+    cancel_job(JobKind::HoldExpiry, &thread.id, pool).await?;
+
+    refresh_channel_name(ctx, &thread.id, pool).await;
+    Ok(state)
+}
+
+// `state_until` is the source of truth, so a hold lifted or extended meanwhile is left alone.
+pub async fn run_hold_expiry_job(ctx: &Context, config: &Config, thread_id: &str) -> JobResult {
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Err("Database pool is not set in config".to_string());
+    };
+
+    let (state, until) = match get_thread_workflow(thread_id, pool).await {
+        Ok(Some(workflow)) => workflow,
+        Ok(None) => return Ok(JobOutcome::Done),
+        Err(e) => return Err(e.to_string()),
+    };
+    if state != TicketWorkflowState::OnHold {
+        return Ok(JobOutcome::Done);
+    }
+    match until {
+        Some(until) if until > Utc::now().timestamp() => return Ok(JobOutcome::RunAt(until)),
+        Some(_) => {}
+        None => return Ok(JobOutcome::Done),
+    }
+
+    let Some(thread) = get_thread_by_id(thread_id, pool).await else {
+        return Ok(JobOutcome::Done);
+    };
+
+    let state = resume_ticket(ctx, config, &thread, None)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let channel_id = ChannelId::new(thread.channel_id.parse::<u64>().unwrap_or(0));
+    let mut params = HashMap::new();
+    params.insert(
+        "state".to_string(),
+        get_translated_message(
+            config,
+            &format!("workflow.state.{}", state.as_str()),
+            None,
+            None,
+            None,
+            None,
+        )
+        .await,
+    );
+    let _ = MessageBuilder::system_message(ctx, config)
+        .translated_content("hold.expired", Some(&params), None, None)
+        .await
+        .to_channel(channel_id)
+        .send(true)
+        .await;
+
+    Ok(JobOutcome::Done)
+}
+
+pub async fn run_inactivity_close_job(ctx: &Context, config: &Config) -> JobResult {
+    let Some(pool) = config.db_pool.as_ref() else {
+        return Err("Database pool is not set in config".to_string());
+    };
+    let now = Utc::now().timestamp();
+    let next_run = JobOutcome::RunAt(now + INACTIVITY_CLOSE_INTERVAL_SECS);
+    let delay = config.thread.inactivity_close_delay as i64;
+    if delay == 0 {
+        return Ok(next_run);
+    }
+
+    let thread_ids = list_inactive_threads(now - delay, pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    let bot_id = ctx.cache.current_user().id;
+    for thread_id in thread_ids {
+        let Some(thread) = get_thread_by_id(&thread_id, pool).await else {
+            continue;
+        };
+        let channel_id = ChannelId::new(thread.channel_id.parse::<u64>().unwrap_or(0));
+        let Some(channel) = channel_id
+            .to_channel(&ctx.http)
+            .await
+            .ok()
+            .and_then(|c| c.guild())
+        else {
+            continue;
+        };
+        match schedule_closure(ctx, &thread.id, &channel, now, bot_id, pool).await {
+            Ok(()) => info!("Closing ticket {} after inactivity", thread.id),
+            Err(e) => error!("Failed to close inactive ticket {}: {e:?}", thread.id),
+        }
+    }
+
+    Ok(next_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn messages_drive_the_workflow() {
+        use TicketWorkflowState::*;
+
+        assert_eq!(Open.after_message(TicketAuthor::User), AwaitingStaff);
+        assert_eq!(
+            AwaitingStaff.after_message(TicketAuthor::Staff),
+            AwaitingUser
+        );
+        assert_eq!(OnHold.after_message(TicketAuthor::Staff), OnHold);
+        assert_eq!(OnHold.after_message(TicketAuthor::User), AwaitingStaff);
+        assert_eq!(Resolved.after_message(TicketAuthor::User), AwaitingStaff);
+        assert_eq!(Closed.after_message(TicketAuthor::User), Closed);
+        assert_eq!(resumed_state(TicketAuthor::Staff), AwaitingUser);
+    }
+}
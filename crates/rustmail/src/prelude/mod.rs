@@ -6,6 +6,7 @@ pub mod errors;
 pub mod features;
 pub mod handlers;
 pub mod i18n;
+pub mod logging;
 pub mod modules;
 pub mod panel_commands;
 pub mod types;
@@ -5,8 +5,8 @@ use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use rustmail_types::{
-    BotConfig, CommandConfig, ErrorHandlingConfig, LanguageConfig, LogsConfig, NotificationsConfig,
-    ReminderConfig, ServerMode, ThreadConfig,
+    BackupConfig, BotConfig, CommandConfig, ErrorHandlingConfig, LanguageConfig, LoggingConfig,
+    LogsConfig, NotificationsConfig, ReminderConfig, ServerMode, ThreadConfig,
 };
 use serde::Deserialize;
 use std::sync::Arc;
@@ -156,6 +156,7 @@ pub async fn handle_setup_save(
             create_ticket_by_create_channel: payload.create_ticket_by_create_channel,
             close_on_leave: payload.close_on_leave,
             auto_archive_duration: payload.auto_archive_duration,
+            ..ThreadConfig::default()
         },
         language: LanguageConfig {
             default_language: payload.default_language.clone(),
@@ -166,6 +167,8 @@ pub async fn handle_setup_save(
         notifications: NotificationsConfig::default(),
         reminders: ReminderConfig::default(),
         logs: LogsConfig::default(),
+        logging: LoggingConfig::default(),
+        backups: BackupConfig::default(),
         db_pool: None,
         error_handler: None,
         thread_locks: Arc::new(std::sync::Mutex::new(std::collections::HashMap::new())),
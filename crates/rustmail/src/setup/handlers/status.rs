@@ -1,3 +1,4 @@
+use crate::config::{read_config_table, resolve_config_path};
 use crate::setup::state::{SetupStep, SharedSetupState};
 use axum::Json;
 use axum::extract::State;
@@ -30,7 +31,13 @@ pub async fn handle_setup_status(
         .is_some_and(|token| token == state.token);
 
     let token_prefill = has_valid_setup_token
-        .then(|| std::env::var("RUSTMAIL_BOT_TOKEN").ok())
+        .then(|| {
+            read_config_table(&resolve_config_path("config.toml"))
+                .get("bot")?
+                .get("token")?
+                .as_str()
+                .map(str::to_string)
+        })
         .flatten()
         .filter(|t| !t.is_empty());
 
@@ -70,257 +70,173 @@ pub struct ValidateChannelResponse {
     pub error: Option<String>,
 }
 
-pub async fn handle_validate_token(Json(payload): Json<ValidateTokenRequest>) -> impl IntoResponse {
-    let client = Client::new();
-
-    let resp = match client
+pub async fn check_token(token: &str) -> Result<BotInfo, String> {
+    let resp = Client::new()
         .get("https://discord.com/api/v10/users/@me")
-        .header("Authorization", format!("Bot {}", payload.token))
+        .header("Authorization", format!("Bot {}", token))
         .send()
         .await
-    {
-        Ok(r) => r,
-        Err(_) => {
-            return (
-                StatusCode::OK,
-                Json(ValidateTokenResponse {
-                    valid: false,
-                    bot: None,
-                    error: Some("Network error reaching Discord API".to_string()),
-                }),
-            );
-        }
-    };
+        .map_err(|_| "Network error reaching Discord API".to_string())?;
 
     if !resp.status().is_success() {
-        return (
-            StatusCode::OK,
-            Json(ValidateTokenResponse {
-                valid: false,
-                bot: None,
-                error: Some("Invalid token".to_string()),
-            }),
-        );
+        return Err("Invalid token".to_string());
     }
 
-    let data: serde_json::Value = match resp.json().await {
-        Ok(d) => d,
-        Err(_) => {
-            return (
-                StatusCode::OK,
-                Json(ValidateTokenResponse {
-                    valid: false,
-                    bot: None,
-                    error: Some("Failed to parse Discord response".to_string()),
-                }),
-            );
-        }
-    };
+    let data: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|_| "Failed to parse Discord response".to_string())?;
 
-    (
-        StatusCode::OK,
-        Json(ValidateTokenResponse {
-            valid: true,
-            bot: Some(BotInfo {
-                id: data["id"].as_str().unwrap_or("").to_string(),
-                username: data["username"].as_str().unwrap_or("").to_string(),
-                avatar: data["avatar"].as_str().map(|s| s.to_string()),
-            }),
-            error: None,
-        }),
-    )
+    Ok(BotInfo {
+        id: data["id"].as_str().unwrap_or("").to_string(),
+        username: data["username"].as_str().unwrap_or("").to_string(),
+        avatar: data["avatar"].as_str().map(|s| s.to_string()),
+    })
 }
 
-pub async fn handle_validate_guild(Json(payload): Json<ValidateGuildRequest>) -> impl IntoResponse {
-    let client = Client::new();
-
-    let resp = match client
-        .get(format!(
-            "https://discord.com/api/v10/guilds/{}",
-            payload.guild_id
-        ))
-        .header("Authorization", format!("Bot {}", payload.token))
+pub async fn check_guild(token: &str, guild_id: &str) -> Result<GuildInfo, String> {
+    let resp = Client::new()
+        .get(format!("https://discord.com/api/v10/guilds/{}", guild_id))
+        .header("Authorization", format!("Bot {}", token))
         .send()
         .await
-    {
-        Ok(r) => r,
-        Err(_) => {
-            return (
-                StatusCode::OK,
-                Json(ValidateGuildResponse {
-                    valid: false,
-                    guild: None,
-                    error: Some("Network error reaching Discord API".to_string()),
-                }),
-            );
-        }
-    };
+        .map_err(|_| "Network error reaching Discord API".to_string())?;
 
     if !resp.status().is_success() {
-        return (
-            StatusCode::OK,
-            Json(ValidateGuildResponse {
-                valid: false,
-                guild: None,
-                error: Some("Guild not found or bot is not a member".to_string()),
-            }),
-        );
+        return Err("Guild not found or bot is not a member".to_string());
     }
 
-    let data: serde_json::Value = match resp.json().await {
-        Ok(d) => d,
-        Err(_) => {
-            return (
-                StatusCode::OK,
-                Json(ValidateGuildResponse {
-                    valid: false,
-                    guild: None,
-                    error: Some("Failed to parse Discord response".to_string()),
-                }),
-            );
-        }
-    };
+    let data: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|_| "Failed to parse Discord response".to_string())?;
 
-    (
-        StatusCode::OK,
-        Json(ValidateGuildResponse {
-            valid: true,
-            guild: Some(GuildInfo {
-                id: data["id"].as_str().unwrap_or("").to_string(),
-                name: data["name"].as_str().unwrap_or("").to_string(),
-                icon: data["icon"].as_str().map(|s| s.to_string()),
-            }),
-            error: None,
-        }),
-    )
+    Ok(GuildInfo {
+        id: data["id"].as_str().unwrap_or("").to_string(),
+        name: data["name"].as_str().unwrap_or("").to_string(),
+        icon: data["icon"].as_str().map(|s| s.to_string()),
+    })
 }
 
-pub async fn handle_validate_channel(
-    Json(payload): Json<ValidateChannelRequest>,
-) -> impl IntoResponse {
-    let client = Client::new();
-
-    let resp = match client
+pub async fn check_channel(
+    token: &str,
+    guild_id: &str,
+    channel_id: &str,
+) -> Result<ChannelInfo, String> {
+    let resp = Client::new()
         .get(format!(
             "https://discord.com/api/v10/channels/{}",
-            payload.channel_id
+            channel_id
         ))
-        .header("Authorization", format!("Bot {}", payload.token))
+        .header("Authorization", format!("Bot {}", token))
         .send()
         .await
-    {
-        Ok(r) => r,
-        Err(_) => {
-            return (
-                StatusCode::OK,
-                Json(ValidateChannelResponse {
-                    valid: false,
-                    channel: None,
-                    error: Some("Network error reaching Discord API".to_string()),
-                }),
-            );
-        }
-    };
+        .map_err(|_| "Network error reaching Discord API".to_string())?;
 
     if !resp.status().is_success() {
-        return (
-            StatusCode::OK,
-            Json(ValidateChannelResponse {
-                valid: false,
-                channel: None,
-                error: Some("Channel not found or bot has no access".to_string()),
-            }),
-        );
+        return Err("Channel not found or bot has no access".to_string());
     }
 
-    let data: serde_json::Value = match resp.json().await {
-        Ok(d) => d,
-        Err(_) => {
-            return (
-                StatusCode::OK,
-                Json(ValidateChannelResponse {
-                    valid: false,
-                    channel: None,
-                    error: Some("Failed to parse Discord response".to_string()),
-                }),
-            );
-        }
-    };
+    let data: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|_| "Failed to parse Discord response".to_string())?;
 
-    let guild_id = data["guild_id"].as_str().unwrap_or("");
-    if guild_id != payload.guild_id {
-        return (
-            StatusCode::OK,
-            Json(ValidateChannelResponse {
-                valid: false,
-                channel: None,
-                error: Some("Channel does not belong to the specified guild".to_string()),
-            }),
-        );
+    if data["guild_id"].as_str().unwrap_or("") != guild_id {
+        return Err("Channel does not belong to the specified guild".to_string());
     }
 
-    (
-        StatusCode::OK,
-        Json(ValidateChannelResponse {
-            valid: true,
-            channel: Some(ChannelInfo {
-                id: data["id"].as_str().unwrap_or("").to_string(),
-                name: data["name"].as_str().unwrap_or("").to_string(),
-                kind: data["type"].as_u64().unwrap_or(0) as u8,
-            }),
-            error: None,
-        }),
-    )
+    Ok(ChannelInfo {
+        id: data["id"].as_str().unwrap_or("").to_string(),
+        name: data["name"].as_str().unwrap_or("").to_string(),
+        kind: data["type"].as_u64().unwrap_or(0) as u8,
+    })
 }
 
-pub async fn handle_validate_oauth2(
-    Json(payload): Json<ValidateOAuth2Request>,
-) -> impl IntoResponse {
-    let client = Client::new();
-
-    let res = client
+pub async fn check_oauth2(client_id: &str, client_secret: &str) -> Result<(), String> {
+    let resp = Client::new()
         .post("https://discord.com/api/v10/oauth2/token")
-        .basic_auth(&payload.client_id, Some(&payload.client_secret))
+        .basic_auth(client_id, Some(client_secret))
         .form(&[("grant_type", "client_credentials"), ("scope", "identify")])
         .send()
-        .await;
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
 
-    match res {
-        Ok(resp) => {
-            if resp.status().is_success() {
-                (
-                    StatusCode::OK,
-                    Json(serde_json::json!({ "valid": true, "error": None::<String> })),
-                )
-            } else {
-                let err_msg = match resp.json::<serde_json::Value>().await {
-                    Ok(json) => {
-                        if let Some(desc) = json.get("error_description").and_then(|d| d.as_str()) {
-                            desc.to_string()
-                        } else if let Some(err) = json.get("error").and_then(|e| e.as_str()) {
-                            err.to_string()
-                        } else {
-                            "Invalid credentials".to_string()
-                        }
-                    }
-                    Err(_) => "Invalid credentials".to_string(),
-                };
+    if resp.status().is_success() {
+        return Ok(());
+    }
 
-                (
-                    StatusCode::OK,
-                    Json(serde_json::json!({
-                        "valid": false,
-                        "error": err_msg
-                    })),
-                )
+    Err(match resp.json::<serde_json::Value>().await {
+        Ok(json) => {
+            if let Some(desc) = json.get("error_description").and_then(|d| d.as_str()) {
+                desc.to_string()
+            } else if let Some(err) = json.get("error").and_then(|e| e.as_str()) {
+                err.to_string()
+            } else {
+                "Invalid credentials".to_string()
             }
         }
-        Err(e) => (
-            StatusCode::OK,
-            Json(serde_json::json!({
-                "valid": false,
-                "error": format!("Network error: {}", e)
-            })),
-        ),
-    }
+        Err(_) => "Invalid credentials".to_string(),
+    })
+}
+
+pub async fn handle_validate_token(Json(payload): Json<ValidateTokenRequest>) -> impl IntoResponse {
+    let response = match check_token(&payload.token).await {
+        Ok(bot) => ValidateTokenResponse {
+            valid: true,
+            bot: Some(bot),
+            error: None,
+        },
+        Err(e) => ValidateTokenResponse {
+            valid: false,
+            bot: None,
+            error: Some(e),
+        },
+    };
+    (StatusCode::OK, Json(response))
+}
+
+pub async fn handle_validate_guild(Json(payload): Json<ValidateGuildRequest>) -> impl IntoResponse {
+    let response = match check_guild(&payload.token, &payload.guild_id).await {
+        Ok(guild) => ValidateGuildResponse {
+            valid: true,
+            guild: Some(guild),
+            error: None,
+        },
+        Err(e) => ValidateGuildResponse {
+            valid: false,
+            guild: None,
+            error: Some(e),
+        },
+    };
+    (StatusCode::OK, Json(response))
+}
+
+pub async fn handle_validate_channel(
+    Json(payload): Json<ValidateChannelRequest>,
+) -> impl IntoResponse {
+    let response = match check_channel(&payload.token, &payload.guild_id, &payload.channel_id).await
+    {
+        Ok(channel) => ValidateChannelResponse {
+            valid: true,
+            channel: Some(channel),
+            error: None,
+        },
+        Err(e) => ValidateChannelResponse {
+            valid: false,
+            channel: None,
+            error: Some(e),
+        },
+    };
+    (StatusCode::OK, Json(response))
+}
+
+pub async fn handle_validate_oauth2(
+    Json(payload): Json<ValidateOAuth2Request>,
+) -> impl IntoResponse {
+    let body = match check_oauth2(&payload.client_id, &payload.client_secret).await {
+        Ok(()) => serde_json::json!({ "valid": true, "error": None::<String> }),
+        Err(e) => serde_json::json!({ "valid": false, "error": e }),
+    };
+    (StatusCode::OK, Json(body))
 }
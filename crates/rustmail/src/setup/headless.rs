@@ -0,0 +1,94 @@
+use crate::config::{Config, validate_config};
+use crate::setup::handlers::{check_channel, check_guild, check_oauth2, check_token};
+use std::process;
+use tracing::{error, info};
+
+pub async fn verify_headless_config(config: &Config) -> Vec<String> {
+    let mut errors = Vec::new();
+    if let Err(e) = validate_config(config) {
+        errors.push(e);
+    }
+
+    let token = &config.bot.token;
+    if token.is_empty() {
+        errors.push("bot.token is required (RUSTMAIL_BOT_TOKEN)".to_string());
+        return errors;
+    }
+    match check_token(token).await {
+        Ok(bot) => info!("Headless setup: logged in as {}", bot.username),
+        Err(e) => {
+            errors.push(format!("bot.token: {}", e));
+            return errors;
+        }
+    }
+
+    let community = config.bot.get_community_guild_id();
+    let staff = config.bot.get_staff_guild_id();
+    let mut guilds = vec![("community", community)];
+    if staff != community {
+        guilds.push(("staff", staff));
+    }
+    for (label, guild_id) in guilds {
+        if guild_id == 0 {
+            errors.push(format!("bot.mode: the {} guild ID is required", label));
+        } else if let Err(e) = check_guild(token, &guild_id.to_string()).await {
+            errors.push(format!("bot.mode ({} guild {}): {}", label, guild_id, e));
+        }
+    }
+
+    let mut channels = vec![(
+        "thread.inbox_category_id",
+        Some(config.thread.inbox_category_id),
+    )];
+    channels.push(("bot.logs_channel_id", config.bot.logs_channel_id));
+    channels.push(("bot.features_channel_id", config.bot.features_channel_id));
+    channels.push(("thread.board_channel_id", config.thread.board_channel_id));
+    for (field, channel_id) in channels {
+        match channel_id {
+            Some(0) => errors.push(format!("{} is required", field)),
+            Some(channel_id) if staff != 0 => {
+                if let Err(e) =
+                    check_channel(token, &staff.to_string(), &channel_id.to_string()).await
+                {
+                    errors.push(format!("{}: {}", field, e));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if config.bot.enable_panel {
+        if config.bot.client_id == 0 || config.bot.client_secret.is_empty() {
+            errors.push(
+                "bot.client_id and bot.client_secret are required when bot.enable_panel is true"
+                    .to_string(),
+            );
+        } else if let Err(e) =
+            check_oauth2(&config.bot.client_id.to_string(), &config.bot.client_secret).await
+        {
+            errors.push(format!("bot.client_secret: {}", e));
+        }
+    }
+
+    errors
+}
+
+pub async fn run_headless_setup(config: Result<Config, String>) {
+    let errors = match config {
+        Ok(config) => verify_headless_config(&config).await,
+        Err(e) => vec![e],
+    };
+
+    if errors.is_empty() {
+        info!("No config.toml found, using the configuration from the environment");
+        return;
+    }
+
+    for e in &errors {
+        error!("Headless setup: {}", e);
+    }
+    error!(
+        "Fix the RUSTMAIL_* variables or the seed file, or remove them to use the setup wizard."
+    );
+    process::exit(1);
+}
@@ -1,4 +1,5 @@
 pub mod handlers;
+pub mod headless;
 pub mod middleware;
 pub mod router;
 pub mod state;
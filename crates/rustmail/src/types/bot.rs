@@ -18,10 +18,18 @@ pub enum BotCommand {
         user_id: u64,
         resp: tokio::sync::oneshot::Sender<bool>,
     },
+    ListCommands {
+        resp: tokio::sync::oneshot::Sender<Vec<String>>,
+    },
+    SyncCommands,
+    ConfigReloaded {
+        changes: Vec<ConfigChange>,
+    },
 }
 
 pub struct BotState {
-    pub config: Option<Config>,
+    pub config: Option<ConfigHandle>,
+    pub config_path: String,
     pub status: BotStatus,
     pub db_pool: Option<sqlx::SqlitePool>,
     pub command_tx: tokio::sync::mpsc::Sender<BotCommand>,
@@ -30,3 +38,9 @@ pub struct BotState {
     pub maintenance_mode: Arc<AtomicBool>,
     pub presence_status: Arc<RwLock<String>>,
 }
+
+impl BotState {
+    pub fn config(&self) -> Option<Arc<Config>> {
+        self.config.as_ref().map(ConfigHandle::load)
+    }
+}
@@ -1,3 +1,5 @@
+use crate::db::repr::{TicketPriority, TicketWorkflowState};
+
 #[derive(Debug, Clone)]
 pub struct TicketState {
     pub channel_id: i64,
@@ -6,6 +8,8 @@ pub struct TicketState {
     pub last_message_by: TicketAuthor,
     pub last_message_at: i64,
     pub label: Option<String>,
+    pub priority: TicketPriority,
+    pub workflow_state: TicketWorkflowState,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,89 @@
+use crate::prelude::errors::*;
+use serde_json::Value;
+use serenity::all::{
+    AutocompleteChoice, CommandInteraction, Context, CreateAutocompleteResponse,
+    CreateInteractionResponse,
+};
+
+pub const AUTOCOMPLETE_MAX_CHOICES: usize = 25;
+const AUTOCOMPLETE_NAME_MAX_LEN: usize = 100;
+
+pub fn filter_choices<V: Into<Value>>(
+    query: &str,
+    items: impl IntoIterator<Item = (String, V)>,
+) -> Vec<AutocompleteChoice> {
+    let query = query.trim().to_lowercase();
+    let mut prefixed = Vec::new();
+    let mut others = Vec::new();
+
+    for (label, value) in items {
+        let lower = label.to_lowercase();
+        if lower.starts_with(&query) {
+            prefixed.push((label, value));
+        } else if lower.contains(&query) {
+            others.push((label, value));
+        }
+    }
+
+    prefixed
+        .into_iter()
+        .chain(others)
+        .take(AUTOCOMPLETE_MAX_CHOICES)
+        .map(|(label, value)| {
+            let label: String = label.chars().take(AUTOCOMPLETE_NAME_MAX_LEN).collect();
+            AutocompleteChoice::new(label, value)
+        })
+        .collect()
+}
+
+pub async fn respond_autocomplete(
+    ctx: &Context,
+    command: &CommandInteraction,
+    choices: Vec<AutocompleteChoice>,
+) -> ModmailResult<()> {
+    command
+        .create_response(
+            &ctx.http,
+            CreateInteractionResponse::Autocomplete(
+                CreateAutocompleteResponse::new().set_choices(choices),
+            ),
+        )
+        .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(choices: &[AutocompleteChoice]) -> Vec<String> {
+        choices
+            .iter()
+            .map(|c| {
+                serde_json::to_value(c).unwrap()["name"]
+                    .as_str()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn prefix_matches_come_first() {
+        let items = vec![
+            ("Billing refunds".to_string(), "1"),
+            ("Refunds".to_string(), "2"),
+            ("Support".to_string(), "3"),
+        ];
+        assert_eq!(
+            names(&filter_choices("ref", items)),
+            vec!["Refunds", "Billing refunds"]
+        );
+    }
+
+    #[test]
+    fn choices_are_capped() {
+        let items = (0..40).map(|i| (format!("snippet{}", i), i));
+        assert_eq!(filter_choices("", items).len(), AUTOCOMPLETE_MAX_CHOICES);
+    }
+}
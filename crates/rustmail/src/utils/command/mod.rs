@@ -1,8 +1,10 @@
+pub mod autocomplete;
 pub mod category;
 pub mod defer_response;
 pub mod extract_reply_content;
 pub mod wrap_command;
 
+pub use autocomplete::*;
 pub use category::*;
 pub use defer_response::*;
 pub use extract_reply_content::*;
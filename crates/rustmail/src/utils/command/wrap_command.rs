@@ -1,6 +1,10 @@
+// The first name is checked against the permission matrix unless given as `"command" => [names]`.
 #[macro_export]
 macro_rules! wrap_command {
-    ($map:expr, [$($name:expr),+], $func:expr) => {{
+    ($map:expr, [$first:expr $(, $name:expr)*], $func:expr) => {{
+        wrap_command!($map, $first => [$first $(, $name)*], $func);
+    }};
+    ($map:expr, $permission:expr => [$($name:expr),+], $func:expr) => {{
         let command: std::sync::Arc<
             dyn for<'a> Fn(
                 serenity::prelude::Context,
@@ -18,7 +22,13 @@ macro_rules! wrap_command {
             })
         });
         $(
-            $map.insert($name.to_string(), std::sync::Arc::clone(&command));
+            $map.insert(
+                $name.to_string(),
+                TextCommand {
+                    command: $permission,
+                    run: std::sync::Arc::clone(&command),
+                },
+            );
         )+
     }};
     ($map:expr, $name:expr, $func:expr) => {{
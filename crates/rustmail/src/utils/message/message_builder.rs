@@ -11,6 +11,7 @@ use serenity::all::{
 use serenity::builder::{CreateActionRow, CreateInteractionResponseMessage};
 use sqlx::SqlitePool;
 use std::collections::HashMap;
+use tracing::error;
 
 #[derive(Debug, Clone)]
 pub enum MessageSender {
@@ -593,7 +594,7 @@ impl<'a> MessageBuilder<'a> {
 
     pub async fn send_and_forget(self) {
         if let Err(e) = self.send(true).await {
-            eprintln!("Failed to send message: {}", e);
+            error!("Failed to send message: {}", e);
         }
     }
 
@@ -776,16 +777,27 @@ impl<'a> StaffReply<'a> {
                 dm_builder = dm_builder.with_role(role_name.clone());
             }
 
+            let category_color = match &self.config.db_pool {
+                Some(pool) => get_thread_category_embed_color(&self.thread_id, pool)
+                    .await
+                    .ok()
+                    .flatten(),
+                None => None,
+            };
+            let color = category_color
+                .as_deref()
+                .unwrap_or(&self.config.thread.staff_message_color);
+
             dm_builder = dm_builder
                 .content(self.content.clone())
                 .add_attachments(self.attachments.clone())
-                .color(hex_string_to_int(&self.config.thread.staff_message_color) as u32)
+                .color(hex_string_to_int(color) as u32)
                 .to_user(dm_user);
 
             match dm_builder.send(true).await {
                 Ok(m) => Some(m),
                 Err(e) => {
-                    eprintln!("Failed to send DM to user: {}", e);
+                    error!("Failed to send DM to user: {}", e);
                     None
                 }
             }
@@ -849,7 +861,7 @@ impl<'a> StaffReply<'a> {
         )
         .await
         {
-            eprintln!("Error inserting staff message: {}", e);
+            error!("Error inserting staff message: {}", e);
         }
 
         Ok((thread_msg, dm_msg_opt))
@@ -896,7 +908,7 @@ impl<'a> StaffReply<'a> {
         {
             Ok(m) => m,
             Err(e) => {
-                println!("Failed to send follow-up message: {}", e);
+                error!("Failed to send follow-up message: {}", e);
                 return Err(ModmailError::from(e));
             }
         };
@@ -917,7 +929,7 @@ impl<'a> StaffReply<'a> {
         )
         .await
         {
-            eprintln!("Error inserting staff message: {}", e);
+            error!("Error inserting staff message: {}", e);
         }
 
         Ok((thread_msg, dm_msg_opt))
@@ -995,7 +1007,7 @@ impl<'a> UserIncoming<'a> {
         )
         .await
         {
-            eprintln!("Error inserting user message: {}", e);
+            error!("Error inserting user message: {}", e);
         }
         Ok(sent)
     }
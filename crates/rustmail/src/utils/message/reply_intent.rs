@@ -1,4 +1,5 @@
 use serenity::all::{Attachment, CreateAttachment};
+use tracing::error;
 
 pub enum ReplyIntent {
     Text(String),
@@ -32,12 +33,12 @@ pub async fn download_attachments(attachments: &[Attachment]) -> Vec<CreateAttac
                 Ok(response) => match response.bytes().await {
                     Ok(bytes) => Some(CreateAttachment::bytes(bytes, filename.clone())),
                     Err(_) => {
-                        eprintln!("Failed to read bytes from attachment: {}", filename);
+                        error!("Failed to read bytes from attachment: {}", filename);
                         None
                     }
                 },
                 Err(_) => {
-                    eprintln!("Failed to download attachment: {}", filename);
+                    error!("Failed to download attachment: {}", filename);
                     None
                 }
             }
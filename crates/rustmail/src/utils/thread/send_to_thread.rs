@@ -7,6 +7,7 @@ use crate::types::TicketAuthor;
 use chrono::Utc;
 use serenity::all::{ChannelId, Context, CreateAttachment, GuildId, Message, UserId};
 use std::collections::HashMap;
+use tracing::error;
 
 fn extract_message_content_with_media(msg: &Message) -> (String, Vec<String>) {
     let content = msg.content.clone();
@@ -74,7 +75,7 @@ pub async fn send_to_thread(
     let pool = match &config.db_pool {
         Some(pool) => pool,
         None => {
-            eprintln!("Database pool is not set in config.");
+            error!("Database pool is not set in config.");
             return Err(ModmailError::Database(DatabaseError::ConnectionFailed));
         }
     };
@@ -127,7 +128,7 @@ pub async fn send_to_thread(
     let thread_id = match get_thread_id_by_user_id(msg.author.id, pool).await {
         Some(thread_id) => thread_id,
         None => {
-            eprintln!("Failed to get thread ID");
+            error!("Failed to get thread ID");
             return Err(ModmailError::Thread(ThreadError::ThreadNotFound));
         }
     };
@@ -142,6 +143,13 @@ pub async fn send_to_thread(
     ticket_status.last_message_by = TicketAuthor::User;
     ticket_status.last_message_at = Utc::now().timestamp();
     update_thread_status_db(&thread_id.clone(), &ticket_status, &pool.clone()).await?;
+    record_message_workflow(
+        &thread_id,
+        TicketAuthor::User,
+        Some(&msg.author.id.to_string()),
+        pool,
+    )
+    .await?;
 
     let builder = MessageBuilder::begin_user_incoming(ctx, config, thread_id.clone(), msg)
         .to_thread(channel_id)
@@ -152,7 +160,7 @@ pub async fn send_to_thread(
     let sent_msg = match builder.send_and_record(pool).await {
         Ok(m) => m,
         Err(e) => {
-            eprintln!("Failed to send message: {}", e);
+            error!("Failed to send message: {}", e);
             return Err(e);
         }
     };
@@ -55,6 +55,14 @@ pub struct ApiKeyListItem {
     pub last_used_at: Option<i64>,
     pub is_active: bool,
     pub key_preview: String,
+    #[serde(default)]
+    pub rate_limit_per_minute: Option<i64>,
+    #[serde(default)]
+    pub rate_limit_burst: Option<i64>,
+    #[serde(default)]
+    pub allowed_cidrs: Vec<String>,
+    #[serde(default)]
+    pub allowed_category_ids: Vec<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -62,6 +70,29 @@ pub struct CreateApiKeyRequest {
     pub name: String,
     pub permissions: Vec<Permission>,
     pub expires_at: Option<i64>,
+    pub rate_limit_per_minute: Option<i64>,
+    pub rate_limit_burst: Option<i64>,
+    pub allowed_cidrs: Vec<String>,
+    pub allowed_category_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiKeyRequestLog {
+    pub id: i64,
+    pub method: String,
+    pub route: String,
+    pub status: i64,
+    pub latency_ms: i64,
+    pub ip: Option<String>,
+    pub created_at: i64,
+}
+
+fn split_list(value: &str) -> Vec<String> {
+    value
+        .split([',', '\n'])
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
+        .collect()
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -296,6 +327,45 @@ fn api_key_card(props: &ApiKeyCardProps) -> Html {
         .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
         .unwrap_or_else(|| i18n.t("panel.apikeys.never"));
 
+    let requests = use_state(|| None::<Vec<ApiKeyRequestLog>>);
+    let on_toggle_requests = {
+        let requests = requests.clone();
+        let key_id = key.id;
+        Callback::from(move |_: MouseEvent| {
+            if requests.is_some() {
+                requests.set(None);
+                return;
+            }
+            let requests = requests.clone();
+            spawn_local(async move {
+                let url = format!("/api/apikeys/{}/requests?limit=20", key_id);
+                if let Ok(resp) = Request::get(&url).send().await
+                    && resp.status() == 200
+                    && let Ok(logs) = resp.json::<Vec<ApiKeyRequestLog>>().await
+                {
+                    requests.set(Some(logs));
+                }
+            });
+        })
+    };
+
+    let rate_limit = match (key.rate_limit_per_minute, key.rate_limit_burst) {
+        (Some(per_minute), Some(burst)) => format!("{}/min (burst {})", per_minute, burst),
+        (Some(per_minute), None) => format!("{}/min", per_minute),
+        _ => i18n.t("panel.apikeys.unlimited"),
+    };
+    let any = i18n.t("panel.apikeys.any");
+    let allowed_cidrs = if key.allowed_cidrs.is_empty() {
+        any.clone()
+    } else {
+        key.allowed_cidrs.join(", ")
+    };
+    let allowed_categories = if key.allowed_category_ids.is_empty() {
+        any
+    } else {
+        key.allowed_category_ids.join(", ")
+    };
+
     html! {
         <div class="bg-slate-800 rounded-lg p-6 border border-slate-700">
             <div class="flex justify-between items-start mb-4">
@@ -344,6 +414,54 @@ fn api_key_card(props: &ApiKeyCardProps) -> Html {
                 </div>
                 <p class="text-gray-400">{i18n.t("panel.apikeys.created")}{" "}{created_date}</p>
                 <p class="text-gray-400">{i18n.t("panel.apikeys.last_used")}{" "}{last_used}</p>
+                <p class="text-gray-400">{i18n.t("panel.apikeys.rate_limit")}{" "}{rate_limit}</p>
+                <p class="text-gray-400">{i18n.t("panel.apikeys.allowed_cidrs")}{" "}{allowed_cidrs}</p>
+                <p class="text-gray-400">{i18n.t("panel.apikeys.allowed_categories")}{" "}{allowed_categories}</p>
+                <button
+                    onclick={on_toggle_requests}
+                    class="text-blue-400 hover:text-blue-300 text-sm transition"
+                >
+                    {
+                        if requests.is_some() {
+                            i18n.t("panel.apikeys.hide_requests")
+                        } else {
+                            i18n.t("panel.apikeys.show_requests")
+                        }
+                    }
+                </button>
+                {
+                    match (*requests).as_ref() {
+                        Some(logs) if logs.is_empty() => html! {
+                            <p class="text-gray-500">{i18n.t("panel.apikeys.no_requests")}</p>
+                        },
+                        Some(logs) => html! {
+                            <div class="bg-slate-900 rounded-md p-3 font-mono text-xs space-y-1 overflow-x-auto">
+                                {
+                                    logs.iter().map(|log| {
+                                        let time = chrono::DateTime::from_timestamp(log.created_at, 0)
+                                            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                                            .unwrap_or_default();
+                                        let status_class = if log.status >= 400 {
+                                            "text-red-400"
+                                        } else {
+                                            "text-green-400"
+                                        };
+                                        html! {
+                                            <div key={log.id} class="text-gray-300 whitespace-nowrap">
+                                                <span class="text-gray-500">{time}</span>
+                                                {" "}
+                                                <span class={status_class}>{log.status}</span>
+                                                {format!(" {} {} {}ms ", log.method, log.route, log.latency_ms)}
+                                                <span class="text-gray-500">{log.ip.clone().unwrap_or_default()}</span>
+                                            </div>
+                                        }
+                                    }).collect::<Html>()
+                                }
+                            </div>
+                        },
+                        None => html! {},
+                    }
+                }
             </div>
         </div>
     }
@@ -360,6 +478,10 @@ pub struct CreateApiKeyModalProps {
 fn create_api_key_modal(props: &CreateApiKeyModalProps) -> Html {
     let (i18n, _set_language) = use_translation();
     let name_ref = use_node_ref();
+    let rate_limit_ref = use_node_ref();
+    let burst_ref = use_node_ref();
+    let cidrs_ref = use_node_ref();
+    let categories_ref = use_node_ref();
     let selected_permissions = use_state(|| Vec::<Permission>::new());
     let creating = use_state(|| false);
     let error = use_state(|| None::<String>);
@@ -379,6 +501,10 @@ fn create_api_key_modal(props: &CreateApiKeyModalProps) -> Html {
 
     let on_create = {
         let name_ref = name_ref.clone();
+        let rate_limit_ref = rate_limit_ref.clone();
+        let burst_ref = burst_ref.clone();
+        let cidrs_ref = cidrs_ref.clone();
+        let categories_ref = categories_ref.clone();
         let selected_permissions = selected_permissions.clone();
         let creating = creating.clone();
         let error = error.clone();
@@ -405,10 +531,40 @@ fn create_api_key_modal(props: &CreateApiKeyModalProps) -> Html {
                 return;
             }
 
+            let input_value = |node: &NodeRef| {
+                node.cast::<HtmlInputElement>()
+                    .map(|input| input.value())
+                    .unwrap_or_default()
+            };
+            let parse_limit = |value: String| -> Result<Option<i64>, ()> {
+                let value = value.trim();
+                if value.is_empty() {
+                    return Ok(None);
+                }
+                match value.parse::<i64>() {
+                    Ok(v) if v > 0 => Ok(Some(v)),
+                    _ => Err(()),
+                }
+            };
+
+            let (Ok(rate_limit_per_minute), Ok(rate_limit_burst)) = (
+                parse_limit(input_value(&rate_limit_ref)),
+                parse_limit(input_value(&burst_ref)),
+            ) else {
+                error.set(Some(
+                    i18n_clone.t("panel.apikeys.modal.error_invalid_rate_limit"),
+                ));
+                return;
+            };
+
             let request = CreateApiKeyRequest {
                 name,
                 permissions: (*selected_permissions).clone(),
                 expires_at: None,
+                rate_limit_per_minute,
+                rate_limit_burst,
+                allowed_cidrs: split_list(&input_value(&cidrs_ref)),
+                allowed_category_ids: split_list(&input_value(&categories_ref)),
             };
 
             let creating = creating.clone();
@@ -531,6 +687,49 @@ fn create_api_key_modal(props: &CreateApiKeyModalProps) -> Html {
                                         </div>
                                     </div>
 
+                                    <div class="grid grid-cols-1 sm:grid-cols-2 gap-4">
+                                        <div>
+                                            <label class="block text-sm font-medium text-gray-300 mb-2">{i18n.t("panel.apikeys.modal.rate_limit")}</label>
+                                            <input
+                                                ref={rate_limit_ref}
+                                                type="number"
+                                                min="1"
+                                                placeholder={i18n.t("panel.apikeys.modal.rate_limit_placeholder")}
+                                                class="w-full px-4 py-2 bg-slate-900 border border-slate-700 rounded-md text-white focus:outline-none focus:ring-2 focus:ring-blue-500"
+                                            />
+                                        </div>
+                                        <div>
+                                            <label class="block text-sm font-medium text-gray-300 mb-2">{i18n.t("panel.apikeys.modal.burst")}</label>
+                                            <input
+                                                ref={burst_ref}
+                                                type="number"
+                                                min="1"
+                                                placeholder={i18n.t("panel.apikeys.modal.burst_placeholder")}
+                                                class="w-full px-4 py-2 bg-slate-900 border border-slate-700 rounded-md text-white focus:outline-none focus:ring-2 focus:ring-blue-500"
+                                            />
+                                        </div>
+                                    </div>
+
+                                    <div>
+                                        <label class="block text-sm font-medium text-gray-300 mb-2">{i18n.t("panel.apikeys.modal.allowed_cidrs")}</label>
+                                        <input
+                                            ref={cidrs_ref}
+                                            type="text"
+                                            placeholder="203.0.113.0/24, 2001:db8::/32"
+                                            class="w-full px-4 py-2 bg-slate-900 border border-slate-700 rounded-md text-white focus:outline-none focus:ring-2 focus:ring-blue-500"
+                                        />
+                                    </div>
+
+                                    <div>
+                                        <label class="block text-sm font-medium text-gray-300 mb-2">{i18n.t("panel.apikeys.modal.allowed_categories")}</label>
+                                        <input
+                                            ref={categories_ref}
+                                            type="text"
+                                            placeholder={i18n.t("panel.apikeys.modal.allowed_categories_placeholder")}
+                                            class="w-full px-4 py-2 bg-slate-900 border border-slate-700 rounded-md text-white focus:outline-none focus:ring-2 focus:ring-blue-500"
+                                        />
+                                    </div>
+
                                     <div class="flex gap-3">
                                         <button
                                             onclick={props.on_close.reform(|_| ())}
@@ -0,0 +1,326 @@
+use crate::components::forbidden::Forbidden403;
+use crate::i18n::yew::use_translation;
+use crate::types::PanelPermission;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupInfo {
+    pub name: String,
+    pub created_at: i64,
+    pub size_bytes: u64,
+    pub compressed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RestoreReport {
+    pub restored: String,
+    pub safety_backup: String,
+    pub bot_restarted: bool,
+}
+
+fn format_ts(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", bytes, UNITS[0])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
+
+#[function_component(BackupsPage)]
+pub fn backups_page() -> Html {
+    let (i18n, _set_language) = use_translation();
+
+    let permissions = use_state(|| None::<Vec<PanelPermission>>);
+    {
+        let permissions = permissions.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(resp) = Request::get("/api/user/permissions").send().await
+                    && let Ok(perms) = resp.json::<Vec<PanelPermission>>().await
+                {
+                    permissions.set(Some(perms));
+                }
+            });
+            || ()
+        });
+    }
+
+    if let Some(perms) = (*permissions).as_ref() {
+        if !perms.contains(&PanelPermission::ManageBot) {
+            return html! {
+                <Forbidden403 required_permission={i18n.t("navbar.backups")} />
+            };
+        }
+    } else {
+        return html! {
+            <div class="flex items-center justify-center min-h-[70vh]">
+                <div class="text-gray-400 animate-pulse">{i18n.t("panel.forbidden.checking_permissions")}</div>
+            </div>
+        };
+    }
+
+    let backups = use_state(|| None::<Vec<BackupInfo>>);
+    let busy = use_state(|| false);
+    let message = use_state(|| None::<(bool, String)>);
+
+    let reload = {
+        let backups = backups.clone();
+        let message = message.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let backups = backups.clone();
+            let message = message.clone();
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                match Request::get("/api/admin/backups").send().await {
+                    Ok(resp) if resp.ok() => match resp.json::<Vec<BackupInfo>>().await {
+                        Ok(list) => backups.set(Some(list)),
+                        Err(e) => message.set(Some((
+                            false,
+                            format!("{}: {}", i18n.t("panel.backups.error_load"), e),
+                        ))),
+                    },
+                    Ok(resp) => message.set(Some((
+                        false,
+                        format!("{}: {}", i18n.t("panel.backups.error_load"), resp.status()),
+                    ))),
+                    Err(e) => message.set(Some((
+                        false,
+                        format!("{}: {}", i18n.t("panel.backups.error_load"), e),
+                    ))),
+                }
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with((), move |_| {
+            reload.emit(());
+            || ()
+        });
+    }
+
+    let on_create = {
+        let reload = reload.clone();
+        let busy = busy.clone();
+        let message = message.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_: MouseEvent| {
+            let reload = reload.clone();
+            let busy = busy.clone();
+            let message = message.clone();
+            let i18n = i18n.clone();
+            busy.set(true);
+            spawn_local(async move {
+                match Request::post("/api/admin/backups").send().await {
+                    Ok(resp) if resp.ok() => match resp.json::<BackupInfo>().await {
+                        Ok(backup) => message.set(Some((
+                            true,
+                            i18n.t("panel.backups.created")
+                                .replace("{name}", &backup.name),
+                        ))),
+                        Err(e) => message.set(Some((false, e.to_string()))),
+                    },
+                    Ok(resp) => {
+                        let text = resp.text().await.unwrap_or_default();
+                        message.set(Some((false, text)));
+                    }
+                    Err(e) => message.set(Some((false, e.to_string()))),
+                }
+                busy.set(false);
+                reload.emit(());
+            });
+        })
+    };
+
+    let on_restore = {
+        let reload = reload.clone();
+        let busy = busy.clone();
+        let message = message.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |name: String| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(
+                        &i18n
+                            .t("panel.backups.restore_confirm")
+                            .replace("{name}", &name),
+                    )
+                    .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let reload = reload.clone();
+            let busy = busy.clone();
+            let message = message.clone();
+            let i18n = i18n.clone();
+            busy.set(true);
+            spawn_local(async move {
+                let url = format!("/api/admin/backups/{}/restore", urlencoding::encode(&name));
+                match Request::post(&url).send().await {
+                    Ok(resp) if resp.ok() => match resp.json::<RestoreReport>().await {
+                        Ok(report) => {
+                            let key = if report.bot_restarted {
+                                "panel.backups.restored_restarted"
+                            } else {
+                                "panel.backups.restored"
+                            };
+                            message.set(Some((
+                                true,
+                                i18n.t(key)
+                                    .replace("{name}", &report.restored)
+                                    .replace("{safety}", &report.safety_backup),
+                            )));
+                        }
+                        Err(e) => message.set(Some((false, e.to_string()))),
+                    },
+                    Ok(resp) => {
+                        let text = resp.text().await.unwrap_or_default();
+                        message.set(Some((false, text)));
+                    }
+                    Err(e) => message.set(Some((false, e.to_string()))),
+                }
+                busy.set(false);
+                reload.emit(());
+            });
+        })
+    };
+
+    let table = match (*backups).as_ref() {
+        None => html! {
+            <p class="text-gray-400 animate-pulse">{i18n.t("panel.backups.loading")}</p>
+        },
+        Some(list) if list.is_empty() => html! {
+            <div class="bg-slate-800 rounded-lg p-8 text-center border border-slate-700">
+                <p class="text-gray-400">{i18n.t("panel.backups.no_backups")}</p>
+            </div>
+        },
+        Some(list) => html! {
+            <div class="bg-slate-800 rounded-lg border border-slate-700 overflow-x-auto">
+                <table class="min-w-full text-sm text-left">
+                    <thead class="text-gray-400 border-b border-slate-700">
+                        <tr>
+                            <th class="px-4 py-3">{i18n.t("panel.backups.column_name")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.backups.column_created")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.backups.column_size")}</th>
+                            <th class="px-4 py-3"></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                    {
+                        list.iter().map(|backup| {
+                            let download_url = format!(
+                                "/api/admin/backups/{}/download",
+                                urlencoding::encode(&backup.name)
+                            );
+                            let on_restore_one = {
+                                let on_restore = on_restore.clone();
+                                let name = backup.name.clone();
+                                Callback::from(move |_| on_restore.emit(name.clone()))
+                            };
+                            html! {
+                                <tr key={backup.name.clone()} class="border-b border-slate-700/50 text-gray-300">
+                                    <td class="px-4 py-3 font-mono text-xs">
+                                        {&backup.name}
+                                        {
+                                            if backup.compressed {
+                                                html! {
+                                                    <span class="ml-2 px-2 py-0.5 bg-slate-700 text-gray-300 rounded-full text-xs">
+                                                        {i18n.t("panel.backups.compressed")}
+                                                    </span>
+                                                }
+                                            } else {
+                                                html! {}
+                                            }
+                                        }
+                                    </td>
+                                    <td class="px-4 py-3">{format_ts(backup.created_at)}</td>
+                                    <td class="px-4 py-3">{format_size(backup.size_bytes)}</td>
+                                    <td class="px-4 py-3 text-right whitespace-nowrap space-x-2">
+                                        <a
+                                            href={download_url}
+                                            download={backup.name.clone()}
+                                            class="px-3 py-1 bg-slate-700 hover:bg-slate-600 text-gray-200 rounded-md text-xs transition"
+                                        >
+                                            {i18n.t("panel.backups.download")}
+                                        </a>
+                                        <button
+                                            onclick={on_restore_one}
+                                            disabled={*busy}
+                                            class="px-3 py-1 bg-red-900/30 border border-red-500 text-red-200 hover:bg-red-900/50 rounded-md text-xs transition disabled:opacity-50"
+                                        >
+                                            {i18n.t("panel.backups.restore")}
+                                        </button>
+                                    </td>
+                                </tr>
+                            }
+                        }).collect::<Html>()
+                    }
+                    </tbody>
+                </table>
+            </div>
+        },
+    };
+
+    html! {
+        <div class="space-y-6">
+            <div class="flex justify-between items-center">
+                <div>
+                    <h1 class="text-3xl font-bold text-white">{i18n.t("panel.backups.title")}</h1>
+                    <p class="text-gray-400 text-sm mt-1">{i18n.t("panel.backups.description")}</p>
+                </div>
+                <div class="flex items-center gap-2">
+                    <button
+                        onclick={on_create}
+                        disabled={*busy}
+                        class="px-4 py-2 bg-blue-600 hover:bg-blue-700 text-white rounded-md text-sm transition disabled:opacity-50"
+                    >
+                        {i18n.t("panel.backups.create")}
+                    </button>
+                    <button
+                        onclick={reload.reform(|_| ())}
+                        class="p-2 bg-slate-800 hover:bg-slate-700 text-gray-300 rounded-full transition"
+                        title={i18n.t("panel.backups.reload")}
+                    >
+                        <svg class="h-5 w-5" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24">
+                            <path stroke-linecap="round" stroke-linejoin="round" d="M4 4v5h.582m15.356 2A8.001 8.001 0 004.582 9m0 0H9m11 11v-5h-.581m0 0a8.003 8.003 0 01-15.357-2m15.357 2H15" />
+                        </svg>
+                    </button>
+                </div>
+            </div>
+
+            {
+                match (*message).clone() {
+                    Some((true, text)) => html! {
+                        <div class="bg-green-900/20 border border-green-500 text-green-200 p-4 rounded-md">{text}</div>
+                    },
+                    Some((false, text)) => html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-4 rounded-md">{text}</div>
+                    },
+                    None => html! {},
+                }
+            }
+
+            {table}
+        </div>
+    }
+}
@@ -4,7 +4,7 @@ use crate::types::PanelPermission;
 use gloo_net::http::Request;
 use serde::{Deserialize, Serialize};
 use wasm_bindgen_futures::spawn_local;
-use web_sys::HtmlInputElement;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
 use yew::prelude::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -18,12 +18,24 @@ pub struct CategoryDto {
     pub enabled: bool,
     pub created_at: i64,
     pub updated_at: i64,
+    #[serde(default)]
+    pub parent_id: Option<String>,
+    #[serde(default)]
+    pub embed_color: Option<String>,
+    #[serde(default)]
+    pub default_priority: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CategorySettingsDto {
     pub enabled: bool,
     pub selection_timeout_s: i64,
+    #[serde(default = "default_selection_mode")]
+    pub selection_mode: String,
+}
+
+fn default_selection_mode() -> String {
+    "buttons".to_string()
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -32,6 +44,7 @@ struct CreateCategoryRequest {
     description: Option<String>,
     emoji: Option<String>,
     discord_category_id: String,
+    parent_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Default)]
@@ -40,6 +53,20 @@ struct UpdateCategoryRequest {
     name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parent_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embed_color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_priority: Option<String>,
+}
+
+const PRIORITIES: [&str; 4] = ["low", "normal", "high", "urgent"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PriorityRule {
+    pub keyword: String,
+    pub priority: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
@@ -52,6 +79,63 @@ struct CategoryRoleRequest {
     role_id: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct CategoryFormQuestion {
+    pub label: String,
+    #[serde(default)]
+    pub placeholder: Option<String>,
+    #[serde(default)]
+    pub paragraph: bool,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub max_length: Option<u16>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct CategoryFormDto {
+    pub title: String,
+    pub questions: Vec<CategoryFormQuestion>,
+}
+
+const FORM_MAX_QUESTIONS: usize = 5;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct CategoryMessageVariant {
+    #[serde(default)]
+    pub language: String,
+    pub welcome_message: Option<String>,
+    pub close_message: Option<String>,
+    pub auto_reply: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct CategoryMessagesDto {
+    pub variants: Vec<CategoryMessageVariant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct CategoryAccessList {
+    #[serde(default)]
+    pub role_ids: Vec<String>,
+    #[serde(default)]
+    pub user_ids: Vec<String>,
+}
+
+const MESSAGE_LANGUAGES: [(&str, &str); 11] = [
+    ("en", "English"),
+    ("fr", "Français"),
+    ("es", "Español"),
+    ("de", "Deutsch"),
+    ("it", "Italiano"),
+    ("pt", "Português"),
+    ("nl", "Nederlands"),
+    ("ru", "Русский"),
+    ("ja", "日本語"),
+    ("ko", "한국어"),
+    ("zh", "中文"),
+];
+
 async fn fetch_categories() -> Result<Vec<CategoryDto>, String> {
     let resp = Request::get("/api/categories")
         .send()
@@ -172,7 +256,7 @@ pub fn categories_page() -> Html {
             };
             let new_settings = CategorySettingsDto {
                 enabled: !current.enabled,
-                selection_timeout_s: current.selection_timeout_s,
+                ..current
             };
             let reload = reload.clone();
             spawn_local(async move {
@@ -201,8 +285,31 @@ pub fn categories_page() -> Html {
                 return;
             }
             let new_settings = CategorySettingsDto {
-                enabled: current.enabled,
                 selection_timeout_s: val,
+                ..current
+            };
+            let reload = reload.clone();
+            spawn_local(async move {
+                if let Ok(req) = Request::put("/api/categories/settings").json(&new_settings) {
+                    let _ = req.send().await;
+                }
+                reload.emit(());
+            });
+        })
+    };
+
+    let on_selection_mode_change = {
+        let settings = settings.clone();
+        let reload = reload.clone();
+        Callback::from(move |e: Event| {
+            let current = match (*settings).clone() {
+                Some(s) => s,
+                None => return,
+            };
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let new_settings = CategorySettingsDto {
+                selection_mode: select.value(),
+                ..current
             };
             let reload = reload.clone();
             spawn_local(async move {
@@ -232,6 +339,56 @@ pub fn categories_page() -> Html {
         })
     };
 
+    let on_parent_change = {
+        let reload = reload.clone();
+        let error = error.clone();
+        Callback::from(move |(id, parent_id): (String, String)| {
+            let body = UpdateCategoryRequest {
+                parent_id: Some(parent_id),
+                ..Default::default()
+            };
+            let reload = reload.clone();
+            let error = error.clone();
+            spawn_local(async move {
+                let url = format!("/api/categories/{}", id);
+                if let Ok(req) = Request::patch(&url).json(&body)
+                    && let Ok(resp) = req.send().await
+                    && resp.status() != 200
+                {
+                    let text = resp.text().await.unwrap_or_default();
+                    error.set(Some(text));
+                    return;
+                }
+                reload.emit(());
+            });
+        })
+    };
+
+    let on_priority_change = {
+        let reload = reload.clone();
+        let error = error.clone();
+        Callback::from(move |(id, priority): (String, String)| {
+            let body = UpdateCategoryRequest {
+                default_priority: Some(priority),
+                ..Default::default()
+            };
+            let reload = reload.clone();
+            let error = error.clone();
+            spawn_local(async move {
+                let url = format!("/api/categories/{}", id);
+                if let Ok(req) = Request::patch(&url).json(&body)
+                    && let Ok(resp) = req.send().await
+                    && resp.status() != 200
+                {
+                    let text = resp.text().await.unwrap_or_default();
+                    error.set(Some(text));
+                    return;
+                }
+                reload.emit(());
+            });
+        })
+    };
+
     let on_delete_category = {
         let reload = reload.clone();
         Callback::from(move |id: String| {
@@ -312,6 +469,23 @@ pub fn categories_page() -> Html {
                                 />
                                 <p class="text-xs text-gray-500 mt-1">{i18n.t("panel.categories.timeout_help")}</p>
                             </div>
+                            <div>
+                                <label class="block text-sm font-medium text-gray-300 mb-2">
+                                    {i18n.t("panel.categories.selection_mode_label")}
+                                </label>
+                                <select
+                                    onchange={on_selection_mode_change}
+                                    class="w-full px-4 py-2 bg-slate-900 border border-slate-700 rounded-md text-white focus:outline-none focus:ring-2 focus:ring-blue-500"
+                                >
+                                    <option value="buttons" selected={s.selection_mode == "buttons"}>
+                                        {i18n.t("panel.categories.selection_mode_buttons")}
+                                    </option>
+                                    <option value="select" selected={s.selection_mode == "select"}>
+                                        {i18n.t("panel.categories.selection_mode_select")}
+                                    </option>
+                                </select>
+                                <p class="text-xs text-gray-500 mt-1">{i18n.t("panel.categories.selection_mode_help")}</p>
+                            </div>
                         </div>
                     }
                 } else {
@@ -319,6 +493,8 @@ pub fn categories_page() -> Html {
                 }
             }
 
+            <PriorityRulesEditor />
+
             {
                 if *loading {
                     html! {
@@ -340,19 +516,35 @@ pub fn categories_page() -> Html {
                     html! {
                         <div class="space-y-4">
                             {
-                                categories.iter().map(|c| {
+                                ordered_categories(&categories).into_iter().map(|c| {
                                     let cat = c.clone();
                                     let cat_id = cat.id.clone();
                                     let toggle_id = cat.id.clone();
+                                    let parent_change_id = cat.id.clone();
+                                    let priority_change_id = cat.id.clone();
                                     let on_toggle_category = on_toggle_category.clone();
                                     let on_delete_category = on_delete_category.clone();
+                                    let on_parent_change = on_parent_change.clone();
+                                    let on_priority_change = on_priority_change.clone();
+                                    let parents: Vec<CategoryDto> = categories
+                                        .iter()
+                                        .filter(|p| p.parent_id.is_none() && p.id != cat.id)
+                                        .cloned()
+                                        .collect();
                                     html! {
                                         <CategoryCard
                                             key={cat_id}
                                             category={cat}
+                                            parents={parents}
                                             on_toggle={Callback::from(move |enabled: bool| {
                                                 on_toggle_category.emit((toggle_id.clone(), enabled));
                                             })}
+                                            on_parent_change={Callback::from(move |parent_id: String| {
+                                                on_parent_change.emit((parent_change_id.clone(), parent_id));
+                                            })}
+                                            on_priority_change={Callback::from(move |priority: String| {
+                                                on_priority_change.emit((priority_change_id.clone(), priority));
+                                            })}
                                             on_delete={on_delete_category.clone()}
                                         />
                                     }
@@ -367,6 +559,7 @@ pub fn categories_page() -> Html {
                 if *show_create_modal {
                     html! {
                         <CreateCategoryModal
+                            parents={categories.iter().filter(|c| c.parent_id.is_none()).cloned().collect::<Vec<_>>()}
                             on_close={on_close_modal}
                             on_created={on_created}
                         />
@@ -379,10 +572,32 @@ pub fn categories_page() -> Html {
     }
 }
 
+fn ordered_categories(categories: &[CategoryDto]) -> Vec<&CategoryDto> {
+    let mut ordered = Vec::with_capacity(categories.len());
+    for parent in categories.iter().filter(|c| c.parent_id.is_none()) {
+        ordered.push(parent);
+        ordered.extend(
+            categories
+                .iter()
+                .filter(|c| c.parent_id.as_deref() == Some(parent.id.as_str())),
+        );
+    }
+    // Orphans should not exist, but never hide a category because of one.
+    for c in categories {
+        if !ordered.iter().any(|o| o.id == c.id) {
+            ordered.push(c);
+        }
+    }
+    ordered
+}
+
 #[derive(Properties, PartialEq)]
 struct CategoryCardProps {
     category: CategoryDto,
+    parents: Vec<CategoryDto>,
     on_toggle: Callback<bool>,
+    on_parent_change: Callback<String>,
+    on_priority_change: Callback<String>,
     on_delete: Callback<String>,
 }
 
@@ -407,6 +622,20 @@ fn category_card(props: &CategoryCardProps) -> Html {
     let on_toggle = props.on_toggle.clone();
     let on_delete = props.on_delete.clone();
     let cat_id = c.id.clone();
+    let on_parent_select = {
+        let on_parent_change = props.on_parent_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            on_parent_change.emit(select.value());
+        })
+    };
+    let on_priority_select = {
+        let on_priority_change = props.on_priority_change.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            on_priority_change.emit(select.value());
+        })
+    };
 
     let roles = use_state(|| Vec::<String>::new());
     let roles_loaded = use_state(|| false);
@@ -526,7 +755,10 @@ fn category_card(props: &CategoryCardProps) -> Html {
     };
 
     html! {
-        <div class="bg-slate-800 rounded-lg p-6 border border-slate-700 space-y-4">
+        <div class={classes!(
+            "bg-slate-800", "rounded-lg", "p-6", "border", "border-slate-700", "space-y-4",
+            c.parent_id.is_some().then_some("ml-8")
+        )}>
             <div class="flex justify-between items-start">
                 <div class="space-y-2">
                     <h3 class="text-xl font-semibold text-white">
@@ -542,6 +774,45 @@ fn category_card(props: &CategoryCardProps) -> Html {
                     <p class="text-xs text-gray-500 font-mono">
                         {i18n.t("panel.categories.discord_category_id")}{": "}{&c.discord_category_id}
                     </p>
+                    <label class="flex items-center gap-2 text-xs text-gray-400">
+                        {i18n.t("panel.categories.parent_label")}
+                        <select
+                            onchange={on_parent_select}
+                            class="px-2 py-1 bg-slate-900 border border-slate-700 rounded-md text-white text-xs"
+                        >
+                            <option value="" selected={c.parent_id.is_none()}>
+                                {i18n.t("panel.categories.parent_none")}
+                            </option>
+                            {
+                                props.parents.iter().map(|p| html! {
+                                    <option
+                                        value={p.id.clone()}
+                                        selected={c.parent_id.as_deref() == Some(p.id.as_str())}
+                                    >
+                                        {&p.name}
+                                    </option>
+                                }).collect::<Html>()
+                            }
+                        </select>
+                    </label>
+                    <label class="flex items-center gap-2 text-xs text-gray-400">
+                        {i18n.t("panel.categories.priority.default_label")}
+                        <select
+                            onchange={on_priority_select}
+                            class="px-2 py-1 bg-slate-900 border border-slate-700 rounded-md text-white text-xs"
+                        >
+                            <option value="" selected={c.default_priority.is_none()}>
+                                {i18n.t("panel.categories.priority.default_none")}
+                            </option>
+                            {
+                                PRIORITIES.iter().map(|p| html! {
+                                    <option value={*p} selected={c.default_priority.as_deref() == Some(*p)}>
+                                        {i18n.t(&format!("panel.tickets.priority.{}", p))}
+                                    </option>
+                                }).collect::<Html>()
+                            }
+                        </select>
+                    </label>
                 </div>
                 <div class="flex gap-2">
                     <button
@@ -626,12 +897,787 @@ fn category_card(props: &CategoryCardProps) -> Html {
                     </button>
                 </div>
             </div>
+
+            <CategoryFormEditor category_id={c.id.clone()} />
+
+            <CategoryAccessEditor category_id={c.id.clone()} />
+
+            <CategoryMessagesEditor category_id={c.id.clone()} embed_color={c.embed_color.clone()} />
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CategoryFormEditorProps {
+    category_id: String,
+}
+
+type QuestionEdit = (usize, Box<dyn Fn(&mut CategoryFormQuestion)>);
+
+async fn fetch_category_form(id: &str) -> Result<Option<CategoryFormDto>, String> {
+    let url = format!("/api/categories/{}/form", id);
+    let resp = Request::get(&url).send().await.map_err(|e| e.to_string())?;
+    match resp.status() {
+        200 => resp
+            .json::<CategoryFormDto>()
+            .await
+            .map(Some)
+            .map_err(|e| e.to_string()),
+        404 => Ok(None),
+        status => {
+            let body = resp.text().await.unwrap_or_default();
+            Err(format!("HTTP {}: {}", status, body))
+        }
+    }
+}
+
+#[function_component(CategoryFormEditor)]
+fn category_form_editor(props: &CategoryFormEditorProps) -> Html {
+    let (i18n, _set_language) = use_translation();
+    let form = use_state(CategoryFormDto::default);
+    let has_form = use_state(|| false);
+    let expanded = use_state(|| false);
+    let status = use_state(|| None::<Result<String, String>>);
+
+    {
+        let form = form.clone();
+        let has_form = has_form.clone();
+        let status = status.clone();
+        use_effect_with(props.category_id.clone(), move |id| {
+            let id = id.clone();
+            spawn_local(async move {
+                match fetch_category_form(&id).await {
+                    Ok(Some(f)) => {
+                        form.set(f);
+                        has_form.set(true);
+                    }
+                    Ok(None) => has_form.set(false),
+                    Err(e) => status.set(Some(Err(e))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let update_question = {
+        let form = form.clone();
+        Callback::from(move |(index, apply): QuestionEdit| {
+            let mut f = (*form).clone();
+            if let Some(q) = f.questions.get_mut(index) {
+                apply(q);
+            }
+            form.set(f);
+        })
+    };
+
+    let on_title = {
+        let form = form.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut f = (*form).clone();
+            f.title = input.value();
+            form.set(f);
+        })
+    };
+
+    let on_add_question = {
+        let form = form.clone();
+        Callback::from(move |_| {
+            let mut f = (*form).clone();
+            if f.questions.len() < FORM_MAX_QUESTIONS {
+                f.questions.push(CategoryFormQuestion {
+                    required: true,
+                    ..Default::default()
+                });
+                form.set(f);
+            }
+        })
+    };
+
+    let on_save = {
+        let form = form.clone();
+        let has_form = has_form.clone();
+        let status = status.clone();
+        let id = props.category_id.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let body = (*form).clone();
+            let form = form.clone();
+            let has_form = has_form.clone();
+            let status = status.clone();
+            let url = format!("/api/categories/{}/form", id);
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                let result = match Request::put(&url).json(&body) {
+                    Ok(req) => req.send().await.map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                };
+                match result {
+                    Ok(resp) if resp.status() == 200 => {
+                        if let Ok(saved) = resp.json::<CategoryFormDto>().await {
+                            form.set(saved);
+                        }
+                        has_form.set(true);
+                        status.set(Some(Ok(i18n.t("panel.categories.form.saved"))));
+                    }
+                    Ok(resp) => {
+                        let text = resp.text().await.unwrap_or_default();
+                        status.set(Some(Err(text)));
+                    }
+                    Err(e) => status.set(Some(Err(e))),
+                }
+            });
+        })
+    };
+
+    let on_delete = {
+        let form = form.clone();
+        let has_form = has_form.clone();
+        let status = status.clone();
+        let id = props.category_id.clone();
+        Callback::from(move |_| {
+            let form = form.clone();
+            let has_form = has_form.clone();
+            let status = status.clone();
+            let url = format!("/api/categories/{}/form", id);
+            spawn_local(async move {
+                match Request::delete(&url).send().await {
+                    Ok(resp) if resp.status() == 204 => {
+                        form.set(CategoryFormDto::default());
+                        has_form.set(false);
+                        status.set(None);
+                    }
+                    Ok(resp) => {
+                        let text = resp.text().await.unwrap_or_default();
+                        status.set(Some(Err(text)));
+                    }
+                    Err(e) => status.set(Some(Err(e.to_string()))),
+                }
+            });
+        })
+    };
+
+    let input_class = "w-full px-3 py-1.5 bg-slate-900 border border-slate-700 rounded-md text-white text-sm focus:outline-none focus:ring-2 focus:ring-blue-500";
+
+    html! {
+        <div class="border-t border-slate-700 pt-4 space-y-3">
+            <div class="flex items-center justify-between">
+                <div>
+                    <p class="text-sm font-medium text-gray-300">{i18n.t("panel.categories.form.title")}</p>
+                    <p class="text-xs text-gray-500">
+                        { if *has_form { i18n.t("panel.categories.form.active") } else { i18n.t("panel.categories.form.none") } }
+                    </p>
+                </div>
+                <button
+                    onclick={{
+                        let expanded = expanded.clone();
+                        Callback::from(move |_| expanded.set(!*expanded))
+                    }}
+                    class="px-3 py-1 bg-slate-700 hover:bg-slate-600 text-white rounded-md text-sm transition"
+                >
+                    { if *expanded { i18n.t("panel.categories.form.collapse") } else { i18n.t("panel.categories.form.edit") } }
+                </button>
+            </div>
+            {
+                match (*status).clone() {
+                    Some(Ok(msg)) => html! { <p class="text-green-300 text-sm">{msg}</p> },
+                    Some(Err(err)) => html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-2 rounded-md text-sm">{err}</div>
+                    },
+                    None => html! {},
+                }
+            }
+            {
+                if *expanded {
+                    html! {
+                        <div class="space-y-3">
+                            <input
+                                type="text"
+                                maxlength="45"
+                                value={form.title.clone()}
+                                oninput={on_title}
+                                placeholder={i18n.t("panel.categories.form.title_placeholder")}
+                                class={input_class}
+                            />
+                            {
+                                form.questions.iter().enumerate().map(|(index, q)| {
+                                    let on_label = {
+                                        let update_question = update_question.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let value = e.target_unchecked_into::<HtmlInputElement>().value();
+                                            update_question.emit((index, Box::new(move |q: &mut CategoryFormQuestion| q.label = value.clone())));
+                                        })
+                                    };
+                                    let on_placeholder = {
+                                        let update_question = update_question.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let value = e.target_unchecked_into::<HtmlInputElement>().value();
+                                            update_question.emit((index, Box::new(move |q: &mut CategoryFormQuestion| {
+                                                q.placeholder = Some(value.clone()).filter(|v| !v.is_empty());
+                                            })));
+                                        })
+                                    };
+                                    let on_max_length = {
+                                        let update_question = update_question.clone();
+                                        Callback::from(move |e: InputEvent| {
+                                            let value = e.target_unchecked_into::<HtmlInputElement>().value();
+                                            update_question.emit((index, Box::new(move |q: &mut CategoryFormQuestion| {
+                                                q.max_length = value.parse::<u16>().ok();
+                                            })));
+                                        })
+                                    };
+                                    let on_paragraph = {
+                                        let update_question = update_question.clone();
+                                        Callback::from(move |e: Event| {
+                                            let checked = e.target_unchecked_into::<HtmlInputElement>().checked();
+                                            update_question.emit((index, Box::new(move |q: &mut CategoryFormQuestion| q.paragraph = checked)));
+                                        })
+                                    };
+                                    let on_required = {
+                                        let update_question = update_question.clone();
+                                        Callback::from(move |e: Event| {
+                                            let checked = e.target_unchecked_into::<HtmlInputElement>().checked();
+                                            update_question.emit((index, Box::new(move |q: &mut CategoryFormQuestion| q.required = checked)));
+                                        })
+                                    };
+                                    let on_remove = {
+                                        let form = form.clone();
+                                        Callback::from(move |_| {
+                                            let mut f = (*form).clone();
+                                            if index < f.questions.len() {
+                                                f.questions.remove(index);
+                                            }
+                                            form.set(f);
+                                        })
+                                    };
+                                    html! {
+                                        <div key={index} class="bg-slate-900/50 border border-slate-700 rounded-md p-3 space-y-2">
+                                            <div class="flex gap-2">
+                                                <input
+                                                    type="text"
+                                                    maxlength="45"
+                                                    value={q.label.clone()}
+                                                    oninput={on_label}
+                                                    placeholder={i18n.t("panel.categories.form.question_label")}
+                                                    class={input_class}
+                                                />
+                                                <button
+                                                    onclick={on_remove}
+                                                    class="px-3 py-1 text-red-400 hover:text-red-200"
+                                                    title={i18n.t("panel.categories.form.remove_question")}
+                                                >{"×"}</button>
+                                            </div>
+                                            <input
+                                                type="text"
+                                                maxlength="100"
+                                                value={q.placeholder.clone().unwrap_or_default()}
+                                                oninput={on_placeholder}
+                                                placeholder={i18n.t("panel.categories.form.question_placeholder")}
+                                                class={input_class}
+                                            />
+                                            <div class="flex flex-wrap items-center gap-4 text-xs text-gray-300">
+                                                <label class="flex items-center gap-2">
+                                                    <input type="checkbox" checked={q.paragraph} onchange={on_paragraph} />
+                                                    {i18n.t("panel.categories.form.paragraph")}
+                                                </label>
+                                                <label class="flex items-center gap-2">
+                                                    <input type="checkbox" checked={q.required} onchange={on_required} />
+                                                    {i18n.t("panel.categories.form.required")}
+                                                </label>
+                                                <label class="flex items-center gap-2">
+                                                    {i18n.t("panel.categories.form.max_length")}
+                                                    <input
+                                                        type="number"
+                                                        min="1"
+                                                        max="4000"
+                                                        value={q.max_length.map(|m| m.to_string()).unwrap_or_default()}
+                                                        oninput={on_max_length}
+                                                        class="w-24 px-2 py-1 bg-slate-900 border border-slate-700 rounded-md text-white"
+                                                    />
+                                                </label>
+                                            </div>
+                                        </div>
+                                    }
+                                }).collect::<Html>()
+                            }
+                            <div class="flex flex-wrap gap-2">
+                                <button
+                                    onclick={on_add_question}
+                                    disabled={form.questions.len() >= FORM_MAX_QUESTIONS}
+                                    class="px-3 py-1.5 bg-slate-700 hover:bg-slate-600 disabled:opacity-50 text-white rounded-md text-sm transition"
+                                >
+                                    {i18n.t("panel.categories.form.add_question")}
+                                </button>
+                                <button
+                                    onclick={on_save}
+                                    class="px-3 py-1.5 bg-blue-600 hover:bg-blue-700 text-white rounded-md text-sm transition"
+                                >
+                                    {i18n.t("panel.categories.form.save")}
+                                </button>
+                                {
+                                    if *has_form {
+                                        html! {
+                                            <button
+                                                onclick={on_delete}
+                                                class="px-3 py-1.5 bg-red-900/30 border border-red-500 text-red-200 hover:bg-red-900/50 rounded-md text-sm transition"
+                                            >
+                                                {i18n.t("panel.categories.form.delete")}
+                                            </button>
+                                        }
+                                    } else {
+                                        html! {}
+                                    }
+                                }
+                            </div>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CategoryAccessEditorProps {
+    category_id: String,
+}
+
+fn parse_id_list(raw: &str) -> Result<Vec<String>, String> {
+    raw.split(|c: char| c == ',' || c.is_whitespace())
+        .map(|part| {
+            part.trim_start_matches("<@&")
+                .trim_start_matches("<@")
+                .trim_end_matches('>')
+        })
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            part.parse::<u64>()
+                .map(|_| part.to_string())
+                .map_err(|_| part.to_string())
+        })
+        .collect()
+}
+
+#[function_component(CategoryAccessEditor)]
+fn category_access_editor(props: &CategoryAccessEditorProps) -> Html {
+    let (i18n, _set_language) = use_translation();
+    let roles = use_state(String::new);
+    let users = use_state(String::new);
+    let status = use_state(|| None::<Result<String, String>>);
+
+    {
+        let roles = roles.clone();
+        let users = users.clone();
+        let status = status.clone();
+        use_effect_with(props.category_id.clone(), move |id| {
+            let url = format!("/api/categories/{}/access", id);
+            spawn_local(async move {
+                match Request::get(&url).send().await {
+                    Ok(resp) if resp.status() == 200 => {
+                        if let Ok(dto) = resp.json::<CategoryAccessList>().await {
+                            roles.set(dto.role_ids.join(", "));
+                            users.set(dto.user_ids.join(", "));
+                        }
+                    }
+                    Ok(resp) => status.set(Some(Err(format!("HTTP {}", resp.status())))),
+                    Err(e) => status.set(Some(Err(e.to_string()))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_input = |state: UseStateHandle<String>| {
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            state.set(input.value());
+        })
+    };
+
+    let on_save = {
+        let roles = roles.clone();
+        let users = users.clone();
+        let status = status.clone();
+        let id = props.category_id.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let body = match (parse_id_list(&roles), parse_id_list(&users)) {
+                (Ok(role_ids), Ok(user_ids)) => CategoryAccessList { role_ids, user_ids },
+                (Err(bad), _) | (_, Err(bad)) => {
+                    status.set(Some(Err(format!(
+                        "{}: {}",
+                        i18n.t("panel.categories.access.invalid_id"),
+                        bad
+                    ))));
+                    return;
+                }
+            };
+            let roles = roles.clone();
+            let users = users.clone();
+            let status = status.clone();
+            let url = format!("/api/categories/{}/access", id);
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                let result = match Request::put(&url).json(&body) {
+                    Ok(req) => req.send().await.map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                };
+                match result {
+                    Ok(resp) if resp.status() == 200 => {
+                        if let Ok(dto) = resp.json::<CategoryAccessList>().await {
+                            roles.set(dto.role_ids.join(", "));
+                            users.set(dto.user_ids.join(", "));
+                        }
+                        status.set(Some(Ok(i18n.t("panel.categories.access.saved"))));
+                    }
+                    Ok(resp) => status.set(Some(Err(resp.text().await.unwrap_or_default()))),
+                    Err(e) => status.set(Some(Err(e))),
+                }
+            });
+        })
+    };
+
+    let input_class = "w-full px-3 py-1.5 bg-slate-900 border border-slate-700 rounded-md text-white text-sm font-mono focus:outline-none focus:ring-2 focus:ring-blue-500";
+
+    html! {
+        <div class="border-t border-slate-700 pt-4 space-y-3">
+            <div>
+                <p class="text-sm font-medium text-gray-300">{i18n.t("panel.categories.access.title")}</p>
+                <p class="text-xs text-gray-500">{i18n.t("panel.categories.access.help")}</p>
+            </div>
+            {
+                match (*status).clone() {
+                    Some(Ok(msg)) => html! { <p class="text-green-300 text-sm">{msg}</p> },
+                    Some(Err(err)) => html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-2 rounded-md text-sm">{err}</div>
+                    },
+                    None => html! {},
+                }
+            }
+            <label class="block text-xs text-gray-300">
+                <span class="block mb-1">{i18n.t("panel.categories.access.roles")}</span>
+                <input
+                    type="text"
+                    value={(*roles).clone()}
+                    oninput={on_input(roles.clone())}
+                    placeholder={i18n.t("panel.categories.access.ids_placeholder")}
+                    class={input_class}
+                />
+            </label>
+            <label class="block text-xs text-gray-300">
+                <span class="block mb-1">{i18n.t("panel.categories.access.users")}</span>
+                <input
+                    type="text"
+                    value={(*users).clone()}
+                    oninput={on_input(users.clone())}
+                    placeholder={i18n.t("panel.categories.access.ids_placeholder")}
+                    class={input_class}
+                />
+            </label>
+            <button
+                onclick={on_save}
+                class="px-3 py-1.5 bg-blue-600 hover:bg-blue-700 text-white rounded-md text-sm transition"
+            >
+                {i18n.t("panel.categories.access.save")}
+            </button>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct CategoryMessagesEditorProps {
+    category_id: String,
+    embed_color: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum MessageField {
+    Welcome,
+    Close,
+    AutoReply,
+}
+
+impl MessageField {
+    fn get(self, v: &CategoryMessageVariant) -> &Option<String> {
+        match self {
+            MessageField::Welcome => &v.welcome_message,
+            MessageField::Close => &v.close_message,
+            MessageField::AutoReply => &v.auto_reply,
+        }
+    }
+
+    fn get_mut(self, v: &mut CategoryMessageVariant) -> &mut Option<String> {
+        match self {
+            MessageField::Welcome => &mut v.welcome_message,
+            MessageField::Close => &mut v.close_message,
+            MessageField::AutoReply => &mut v.auto_reply,
+        }
+    }
+}
+
+#[function_component(CategoryMessagesEditor)]
+fn category_messages_editor(props: &CategoryMessagesEditorProps) -> Html {
+    let (i18n, _set_language) = use_translation();
+    let variants = use_state(Vec::<CategoryMessageVariant>::new);
+    let language = use_state(String::new);
+    let color = use_state(|| props.embed_color.clone().unwrap_or_default());
+    let expanded = use_state(|| false);
+    let status = use_state(|| None::<Result<String, String>>);
+
+    {
+        let variants = variants.clone();
+        let status = status.clone();
+        use_effect_with(props.category_id.clone(), move |id| {
+            let url = format!("/api/categories/{}/messages", id);
+            spawn_local(async move {
+                match Request::get(&url).send().await {
+                    Ok(resp) if resp.status() == 200 => {
+                        if let Ok(dto) = resp.json::<CategoryMessagesDto>().await {
+                            variants.set(dto.variants);
+                        }
+                    }
+                    Ok(resp) => status.set(Some(Err(format!("HTTP {}", resp.status())))),
+                    Err(e) => status.set(Some(Err(e.to_string()))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let current = variants
+        .iter()
+        .find(|v| v.language == *language)
+        .cloned()
+        .unwrap_or_default();
+
+    let on_field = {
+        let variants = variants.clone();
+        let language = language.clone();
+        Callback::from(move |(field, value): (MessageField, String)| {
+            let mut list = (*variants).clone();
+            let index = match list.iter().position(|v| v.language == *language) {
+                Some(i) => i,
+                None => {
+                    list.push(CategoryMessageVariant {
+                        language: (*language).clone(),
+                        ..Default::default()
+                    });
+                    list.len() - 1
+                }
+            };
+            *field.get_mut(&mut list[index]) = Some(value).filter(|v| !v.is_empty());
+            variants.set(list);
+        })
+    };
+
+    let on_language = {
+        let language = language.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            language.set(select.value());
+        })
+    };
+
+    let on_color = {
+        let color = color.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            color.set(input.value());
+        })
+    };
+
+    let on_save = {
+        let variants = variants.clone();
+        let color = color.clone();
+        let status = status.clone();
+        let id = props.category_id.clone();
+        let initial_color = props.embed_color.clone().unwrap_or_default();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let body = CategoryMessagesDto {
+                variants: (*variants).clone(),
+            };
+            let new_color = (*color).trim().to_string();
+            let color_body = (new_color != initial_color).then(|| UpdateCategoryRequest {
+                embed_color: Some(new_color),
+                ..Default::default()
+            });
+            let variants = variants.clone();
+            let status = status.clone();
+            let id = id.clone();
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                if let Some(color_body) = color_body {
+                    let url = format!("/api/categories/{}", id);
+                    let result = match Request::patch(&url).json(&color_body) {
+                        Ok(req) => req.send().await.map_err(|e| e.to_string()),
+                        Err(e) => Err(e.to_string()),
+                    };
+                    match result {
+                        Ok(resp) if resp.status() == 200 => {}
+                        Ok(resp) => {
+                            status.set(Some(Err(resp.text().await.unwrap_or_default())));
+                            return;
+                        }
+                        Err(e) => {
+                            status.set(Some(Err(e)));
+                            return;
+                        }
+                    }
+                }
+
+                let url = format!("/api/categories/{}/messages", id);
+                let result = match Request::put(&url).json(&body) {
+                    Ok(req) => req.send().await.map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                };
+                match result {
+                    Ok(resp) if resp.status() == 200 => {
+                        if let Ok(dto) = resp.json::<CategoryMessagesDto>().await {
+                            variants.set(dto.variants);
+                        }
+                        status.set(Some(Ok(i18n.t("panel.categories.messages.saved"))));
+                    }
+                    Ok(resp) => status.set(Some(Err(resp.text().await.unwrap_or_default()))),
+                    Err(e) => status.set(Some(Err(e))),
+                }
+            });
+        })
+    };
+
+    let input_class = "w-full px-3 py-1.5 bg-slate-900 border border-slate-700 rounded-md text-white text-sm focus:outline-none focus:ring-2 focus:ring-blue-500";
+
+    let textarea = |field: MessageField, label_key: &str, help_key: &str| -> Html {
+        let on_field = on_field.clone();
+        let oninput = Callback::from(move |e: InputEvent| {
+            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            on_field.emit((field, input.value()));
+        });
+        html! {
+            <div>
+                <label class="block text-xs font-medium text-gray-300 mb-1">{i18n.t(label_key)}</label>
+                <textarea
+                    rows="3"
+                    maxlength="2000"
+                    value={field.get(&current).clone().unwrap_or_default()}
+                    {oninput}
+                    placeholder={i18n.t("panel.categories.messages.inherit_placeholder")}
+                    class={input_class}
+                />
+                <p class="text-xs text-gray-500 mt-1">{i18n.t(help_key)}</p>
+            </div>
+        }
+    };
+
+    let configured: Vec<String> = variants
+        .iter()
+        .map(|v| {
+            if v.language.is_empty() {
+                i18n.t("panel.categories.messages.language_default")
+            } else {
+                v.language.clone()
+            }
+        })
+        .collect();
+
+    html! {
+        <div class="border-t border-slate-700 pt-4 space-y-3">
+            <div class="flex items-center justify-between">
+                <div>
+                    <p class="text-sm font-medium text-gray-300">{i18n.t("panel.categories.messages.title")}</p>
+                    <p class="text-xs text-gray-500">
+                        {
+                            if configured.is_empty() {
+                                i18n.t("panel.categories.messages.none")
+                            } else {
+                                format!("{}: {}", i18n.t("panel.categories.messages.configured"), configured.join(", "))
+                            }
+                        }
+                    </p>
+                </div>
+                <button
+                    onclick={{
+                        let expanded = expanded.clone();
+                        Callback::from(move |_| expanded.set(!*expanded))
+                    }}
+                    class="px-3 py-1 bg-slate-700 hover:bg-slate-600 text-white rounded-md text-sm transition"
+                >
+                    { if *expanded { i18n.t("panel.categories.messages.collapse") } else { i18n.t("panel.categories.messages.edit") } }
+                </button>
+            </div>
+            {
+                match (*status).clone() {
+                    Some(Ok(msg)) => html! { <p class="text-green-300 text-sm">{msg}</p> },
+                    Some(Err(err)) => html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-2 rounded-md text-sm">{err}</div>
+                    },
+                    None => html! {},
+                }
+            }
+            {
+                if *expanded {
+                    html! {
+                        <div class="space-y-3">
+                            <div class="flex flex-wrap items-end gap-4">
+                                <label class="text-xs text-gray-300">
+                                    <span class="block mb-1">{i18n.t("panel.categories.messages.color")}</span>
+                                    <div class="flex items-center gap-2">
+                                        <span
+                                            class="inline-block w-6 h-6 rounded border border-slate-600"
+                                            style={format!("background-color: #{}", color.trim_start_matches('#'))}
+                                        />
+                                        <input
+                                            type="text"
+                                            maxlength="7"
+                                            value={(*color).clone()}
+                                            oninput={on_color}
+                                            placeholder="5865F2"
+                                            class="w-28 px-2 py-1 bg-slate-900 border border-slate-700 rounded-md text-white text-sm font-mono"
+                                        />
+                                    </div>
+                                </label>
+                                <label class="text-xs text-gray-300">
+                                    <span class="block mb-1">{i18n.t("panel.categories.messages.language")}</span>
+                                    <select
+                                        onchange={on_language}
+                                        class="px-2 py-1 bg-slate-900 border border-slate-700 rounded-md text-white text-sm"
+                                    >
+                                        <option value="" selected={language.is_empty()}>
+                                            {i18n.t("panel.categories.messages.language_default")}
+                                        </option>
+                                        {
+                                            MESSAGE_LANGUAGES.iter().map(|(code, name)| html! {
+                                                <option value={*code} selected={*language == *code}>{*name}</option>
+                                            }).collect::<Html>()
+                                        }
+                                    </select>
+                                </label>
+                            </div>
+                            <p class="text-xs text-gray-500">{i18n.t("panel.categories.messages.help")}</p>
+                            { textarea(MessageField::Welcome, "panel.categories.messages.welcome", "panel.categories.messages.welcome_help") }
+                            { textarea(MessageField::AutoReply, "panel.categories.messages.auto_reply", "panel.categories.messages.auto_reply_help") }
+                            { textarea(MessageField::Close, "panel.categories.messages.close", "panel.categories.messages.close_help") }
+                            <button
+                                onclick={on_save}
+                                class="px-3 py-1.5 bg-blue-600 hover:bg-blue-700 text-white rounded-md text-sm transition"
+                            >
+                                {i18n.t("panel.categories.messages.save")}
+                            </button>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
         </div>
     }
 }
 
 #[derive(Properties, PartialEq)]
 struct CreateCategoryModalProps {
+    parents: Vec<CategoryDto>,
     on_close: Callback<()>,
     on_created: Callback<()>,
 }
@@ -644,6 +1690,7 @@ fn create_category_modal(props: &CreateCategoryModalProps) -> Html {
     let emoji_ref = use_node_ref();
     let discord_id_ref = use_node_ref();
     let roles_ref = use_node_ref();
+    let parent_ref = use_node_ref();
     let creating = use_state(|| false);
     let error = use_state(|| None::<String>);
 
@@ -653,6 +1700,7 @@ fn create_category_modal(props: &CreateCategoryModalProps) -> Html {
         let emoji_ref = emoji_ref.clone();
         let discord_id_ref = discord_id_ref.clone();
         let roles_ref = roles_ref.clone();
+        let parent_ref = parent_ref.clone();
         let creating = creating.clone();
         let error = error.clone();
         let on_created = props.on_created.clone();
@@ -679,6 +1727,10 @@ fn create_category_modal(props: &CreateCategoryModalProps) -> Html {
                 .cast::<HtmlInputElement>()
                 .map(|i| i.value())
                 .unwrap_or_default();
+            let parent_id = parent_ref
+                .cast::<HtmlSelectElement>()
+                .map(|s| s.value())
+                .filter(|s| !s.is_empty());
 
             if name.trim().is_empty() {
                 error.set(Some(i18n_clone.t("panel.categories.error_name_required")));
@@ -709,6 +1761,7 @@ fn create_category_modal(props: &CreateCategoryModalProps) -> Html {
                 description: desc,
                 emoji,
                 discord_category_id: discord_id,
+                parent_id,
             };
 
             let creating = creating.clone();
@@ -825,6 +1878,21 @@ fn create_category_modal(props: &CreateCategoryModalProps) -> Html {
                         <p class="text-xs text-gray-500 mt-1">{i18n.t("panel.categories.modal.roles_help")}</p>
                     </div>
 
+                    <div>
+                        <label class="block text-sm font-medium text-gray-300 mb-2">{i18n.t("panel.categories.parent_label")}</label>
+                        <select
+                            ref={parent_ref}
+                            class="w-full px-4 py-2 bg-slate-900 border border-slate-700 rounded-md text-white focus:outline-none focus:ring-2 focus:ring-blue-500"
+                        >
+                            <option value="" selected=true>{i18n.t("panel.categories.parent_none")}</option>
+                            {
+                                props.parents.iter().map(|p| html! {
+                                    <option value={p.id.clone()}>{&p.name}</option>
+                                }).collect::<Html>()
+                            }
+                        </select>
+                    </div>
+
                     <div class="flex gap-3">
                         <button
                             onclick={props.on_close.reform(|_| ())}
@@ -846,3 +1914,160 @@ fn create_category_modal(props: &CreateCategoryModalProps) -> Html {
         </div>
     }
 }
+
+#[function_component(PriorityRulesEditor)]
+fn priority_rules_editor() -> Html {
+    let (i18n, _set_language) = use_translation();
+    let rules = use_state(|| None::<Vec<PriorityRule>>);
+    let status = use_state(|| None::<Result<String, String>>);
+    let keyword_ref = use_node_ref();
+    let priority_ref = use_node_ref();
+
+    {
+        let rules = rules.clone();
+        let status = status.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                match Request::get("/api/categories/priority-rules").send().await {
+                    Ok(resp) if resp.status() == 200 => {
+                        rules.set(Some(
+                            resp.json::<Vec<PriorityRule>>().await.unwrap_or_default(),
+                        ));
+                    }
+                    Ok(resp) => status.set(Some(Err(resp.text().await.unwrap_or_default()))),
+                    Err(e) => status.set(Some(Err(e.to_string()))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let save = {
+        let rules = rules.clone();
+        let status = status.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |updated: Vec<PriorityRule>| {
+            let rules = rules.clone();
+            let status = status.clone();
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                let resp = match Request::put("/api/categories/priority-rules").json(&updated) {
+                    Ok(req) => req.send().await.map_err(|e| e.to_string()),
+                    Err(e) => Err(e.to_string()),
+                };
+                match resp {
+                    Ok(resp) if resp.status() == 200 => {
+                        rules.set(Some(
+                            resp.json::<Vec<PriorityRule>>().await.unwrap_or(updated),
+                        ));
+                        status.set(Some(Ok(i18n.t("panel.categories.priority.rules_saved"))));
+                    }
+                    Ok(resp) => status.set(Some(Err(resp.text().await.unwrap_or_default()))),
+                    Err(e) => status.set(Some(Err(e))),
+                }
+            });
+        })
+    };
+
+    let on_add = {
+        let rules = rules.clone();
+        let save = save.clone();
+        let keyword_ref = keyword_ref.clone();
+        let priority_ref = priority_ref.clone();
+        Callback::from(move |_| {
+            let (Some(keyword), Some(priority)) = (
+                keyword_ref.cast::<HtmlInputElement>(),
+                priority_ref.cast::<HtmlSelectElement>(),
+            ) else {
+                return;
+            };
+            let value = keyword.value().trim().to_string();
+            if value.is_empty() {
+                return;
+            }
+            let mut updated = (*rules).clone().unwrap_or_default();
+            updated.push(PriorityRule {
+                keyword: value,
+                priority: priority.value(),
+            });
+            keyword.set_value("");
+            save.emit(updated);
+        })
+    };
+
+    html! {
+        <div class="bg-slate-800 rounded-lg p-6 border border-slate-700 space-y-4">
+            <div>
+                <h2 class="text-xl font-semibold text-white">{i18n.t("panel.categories.priority.rules_title")}</h2>
+                <p class="text-xs text-gray-500">{i18n.t("panel.categories.priority.rules_help")}</p>
+            </div>
+            {
+                match (*status).clone() {
+                    Some(Ok(msg)) => html! {
+                        <div class="bg-green-900/20 border border-green-500 text-green-200 p-2 rounded-md text-sm">{msg}</div>
+                    },
+                    Some(Err(err)) => html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-2 rounded-md text-sm">{err}</div>
+                    },
+                    None => html! {},
+                }
+            }
+            {
+                match (*rules).as_ref() {
+                    None => html! {
+                        <p class="text-xs text-gray-500 animate-pulse">{i18n.t("panel.categories.loading")}</p>
+                    },
+                    Some(list) if list.is_empty() => html! {
+                        <p class="text-xs text-gray-500 italic">{i18n.t("panel.categories.priority.rules_empty")}</p>
+                    },
+                    Some(list) => html! {
+                        <div class="flex flex-wrap gap-2">
+                            {
+                                list.iter().enumerate().map(|(index, rule)| {
+                                    let save = save.clone();
+                                    let mut remaining = list.clone();
+                                    remaining.remove(index);
+                                    html! {
+                                        <span class="inline-flex items-center gap-2 bg-slate-900 border border-slate-700 text-gray-200 text-xs px-3 py-1 rounded-full">
+                                            <span class="font-mono">{&rule.keyword}</span>
+                                            {"→"}
+                                            {i18n.t(&format!("panel.tickets.priority.{}", rule.priority))}
+                                            <button
+                                                onclick={Callback::from(move |_| save.emit(remaining.clone()))}
+                                                class="text-red-400 hover:text-red-200"
+                                            >{"×"}</button>
+                                        </span>
+                                    }
+                                }).collect::<Html>()
+                            }
+                        </div>
+                    },
+                }
+            }
+            <div class="flex gap-2">
+                <input
+                    ref={keyword_ref}
+                    type="text"
+                    placeholder={i18n.t("panel.categories.priority.keyword_placeholder")}
+                    class="flex-1 px-3 py-1.5 bg-slate-900 border border-slate-700 rounded-md text-white text-sm focus:outline-none focus:ring-2 focus:ring-blue-500"
+                />
+                <select
+                    ref={priority_ref}
+                    class="px-2 py-1.5 bg-slate-900 border border-slate-700 rounded-md text-white text-sm"
+                >
+                    {
+                        PRIORITIES.iter().rev().map(|p| html! {
+                            <option value={*p}>{i18n.t(&format!("panel.tickets.priority.{}", p))}</option>
+                        }).collect::<Html>()
+                    }
+                </select>
+                <button
+                    onclick={on_add}
+                    class="px-3 py-1.5 bg-blue-600 hover:bg-blue-700 text-white rounded-md text-sm transition"
+                >
+                    {i18n.t("panel.categories.priority.rule_add")}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,272 @@
+use crate::i18n::yew::use_translation;
+use crate::pages::administration::Role;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommandPermission {
+    pub command: String,
+    pub role_ids: Vec<String>,
+    pub user_ids: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct CommandAccessRequest {
+    role_ids: Vec<String>,
+    user_ids: Vec<String>,
+}
+
+async fn save_command_permission(entry: &CommandPermission) -> Result<CommandPermission, String> {
+    let body = CommandAccessRequest {
+        role_ids: entry.role_ids.clone(),
+        user_ids: entry.user_ids.clone(),
+    };
+    let resp = Request::put(&format!("/api/admin/commands/{}", entry.command))
+        .json(&body)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if resp.status() != 200 {
+        return Err(resp
+            .text()
+            .await
+            .unwrap_or_else(|_| resp.status().to_string()));
+    }
+    resp.json::<CommandPermission>()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[derive(Properties, PartialEq)]
+pub struct CommandPermissionsProps {
+    pub roles: Vec<Role>,
+}
+
+#[function_component(CommandPermissions)]
+pub fn command_permissions(props: &CommandPermissionsProps) -> Html {
+    let (i18n, _set_language) = use_translation();
+    let entries = use_state(|| None::<Vec<CommandPermission>>);
+    let error = use_state(|| None::<String>);
+
+    {
+        let entries = entries.clone();
+        let error = error.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                match Request::get("/api/admin/commands").send().await {
+                    Ok(resp) if resp.status() == 200 => {
+                        if let Ok(list) = resp.json::<Vec<CommandPermission>>().await {
+                            entries.set(Some(list));
+                        }
+                    }
+                    Ok(resp) => error.set(Some(
+                        resp.text()
+                            .await
+                            .unwrap_or_else(|_| resp.status().to_string()),
+                    )),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_change = {
+        let entries = entries.clone();
+        let error = error.clone();
+        Callback::from(move |entry: CommandPermission| {
+            let entries = entries.clone();
+            let error = error.clone();
+            spawn_local(async move {
+                match save_command_permission(&entry).await {
+                    Ok(saved) => {
+                        if let Some(list) = (*entries).as_ref() {
+                            let list = list
+                                .iter()
+                                .map(|e| {
+                                    if e.command == saved.command {
+                                        saved.clone()
+                                    } else {
+                                        e.clone()
+                                    }
+                                })
+                                .collect();
+                            entries.set(Some(list));
+                        }
+                        error.set(None);
+                    }
+                    Err(e) => error.set(Some(e)),
+                }
+            });
+        })
+    };
+
+    let role_name = |role_id: &str| -> String {
+        props
+            .roles
+            .iter()
+            .find(|r| r.role_id == role_id)
+            .map(|r| r.name.clone())
+            .unwrap_or_else(|| role_id.to_string())
+    };
+
+    let chip = |label: String, on_remove: Callback<MouseEvent>| -> Html {
+        html! {
+            <span class="inline-flex items-center gap-2 bg-slate-900 border border-slate-700 text-gray-200 text-xs px-3 py-1 rounded-full">
+                {label}
+                <button onclick={on_remove} class="text-red-400 hover:text-red-200">{"×"}</button>
+            </span>
+        }
+    };
+
+    let render_row = |entry: &CommandPermission| -> Html {
+        let on_add_role = {
+            let on_change = on_change.clone();
+            let entry = entry.clone();
+            Callback::from(move |e: Event| {
+                let select: HtmlSelectElement = e.target_unchecked_into();
+                let role_id = select.value();
+                select.set_value("");
+                if role_id.is_empty() || entry.role_ids.contains(&role_id) {
+                    return;
+                }
+                let mut updated = entry.clone();
+                updated.role_ids.push(role_id);
+                on_change.emit(updated);
+            })
+        };
+        let on_add_user = {
+            let on_change = on_change.clone();
+            let error = error.clone();
+            let entry = entry.clone();
+            let i18n = i18n.clone();
+            Callback::from(move |e: KeyboardEvent| {
+                if e.key() != "Enter" {
+                    return;
+                }
+                let input: HtmlInputElement = e.target_unchecked_into();
+                let raw = input.value();
+                let user_id = raw.trim().trim_start_matches("<@").trim_end_matches('>');
+                if user_id.parse::<u64>().is_err() {
+                    error.set(Some(i18n.t("panel.administration.commands.invalid_user")));
+                    return;
+                }
+                input.set_value("");
+                if entry.user_ids.iter().any(|u| u == user_id) {
+                    return;
+                }
+                let mut updated = entry.clone();
+                updated.user_ids.push(user_id.to_string());
+                on_change.emit(updated);
+            })
+        };
+
+        let restricted = !entry.role_ids.is_empty() || !entry.user_ids.is_empty();
+
+        html! {
+            <tr key={entry.command.clone()} class="border-t border-slate-700 align-top">
+                <td class="py-3 pr-4 font-mono text-sm text-white">{&entry.command}</td>
+                <td class="py-3 pr-4">
+                    <div class="flex flex-wrap gap-2">
+                        {
+                            if !restricted {
+                                html! {
+                                    <span class="text-xs text-gray-500 italic">{i18n.t("panel.administration.commands.everyone")}</span>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                        {
+                            entry.role_ids.iter().map(|role_id| {
+                                let on_change = on_change.clone();
+                                let mut updated = entry.clone();
+                                updated.role_ids.retain(|r| r != role_id);
+                                chip(
+                                    format!("@{}", role_name(role_id)),
+                                    Callback::from(move |_| on_change.emit(updated.clone())),
+                                )
+                            }).collect::<Html>()
+                        }
+                        {
+                            entry.user_ids.iter().map(|user_id| {
+                                let on_change = on_change.clone();
+                                let mut updated = entry.clone();
+                                updated.user_ids.retain(|u| u != user_id);
+                                chip(
+                                    user_id.clone(),
+                                    Callback::from(move |_| on_change.emit(updated.clone())),
+                                )
+                            }).collect::<Html>()
+                        }
+                    </div>
+                </td>
+                <td class="py-3">
+                    <div class="flex flex-col gap-2">
+                        <select
+                            onchange={on_add_role}
+                            class="px-2 py-1 bg-slate-900 border border-slate-700 rounded-md text-white text-xs"
+                        >
+                            <option value="" selected=true>{i18n.t("panel.administration.commands.add_role")}</option>
+                            {
+                                props.roles.iter()
+                                    .filter(|r| !entry.role_ids.contains(&r.role_id))
+                                    .map(|r| html! { <option value={r.role_id.clone()}>{&r.name}</option> })
+                                    .collect::<Html>()
+                            }
+                        </select>
+                        <input
+                            type="text"
+                            onkeydown={on_add_user}
+                            placeholder={i18n.t("panel.administration.commands.add_user")}
+                            class="px-2 py-1 bg-slate-900 border border-slate-700 rounded-md text-white text-xs font-mono"
+                        />
+                    </div>
+                </td>
+            </tr>
+        }
+    };
+
+    html! {
+        <div class="bg-gradient-to-br from-slate-800 to-slate-900 rounded-xl shadow-2xl p-8 border border-slate-700 mb-8">
+            <div class="mb-6">
+                <h2 class="text-2xl font-bold text-white">{i18n.t("panel.administration.commands.title")}</h2>
+                <p class="text-gray-400 text-sm">{i18n.t("panel.administration.commands.description")}</p>
+            </div>
+            {
+                if let Some(err) = (*error).clone() {
+                    html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-3 rounded-md text-sm mb-4">{err}</div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+            {
+                match (*entries).as_ref() {
+                    None => html! {
+                        <p class="text-gray-400 animate-pulse">{i18n.t("panel.administration.loading")}</p>
+                    },
+                    Some(list) => html! {
+                        <table class="w-full text-left">
+                            <thead>
+                                <tr class="text-xs uppercase text-gray-400">
+                                    <th class="pb-2 pr-4">{i18n.t("panel.administration.commands.command")}</th>
+                                    <th class="pb-2 pr-4">{i18n.t("panel.administration.commands.allowed")}</th>
+                                    <th class="pb-2"></th>
+                                </tr>
+                            </thead>
+                            <tbody>
+                                { for list.iter().map(render_row) }
+                            </tbody>
+                        </table>
+                    },
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,450 @@
+use crate::components::forbidden::Forbidden403;
+use crate::i18n::yew::use_translation;
+use crate::pages::panel::PanelRoute;
+use crate::types::PanelPermission;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+use yew_router::hooks::use_navigator;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigRevisionDto {
+    pub id: i64,
+    pub source: String,
+    pub author_id: Option<String>,
+    pub rollback_of: Option<i64>,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaginatedConfigRevisions {
+    pub revisions: Vec<ConfigRevisionDto>,
+    pub total: i64,
+    pub page: i64,
+    pub page_size: i64,
+    pub total_pages: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigFieldDiff {
+    pub field: String,
+    pub from: Option<serde_json::Value>,
+    pub to: Option<serde_json::Value>,
+    pub impact: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConfigRevisionDiff {
+    pub from: Option<i64>,
+    pub to: i64,
+    pub changes: Vec<ConfigFieldDiff>,
+}
+
+fn display_value(value: &Option<serde_json::Value>) -> String {
+    match value {
+        Some(serde_json::Value::String(s)) => s.clone(),
+        Some(serde_json::Value::Null) | None => "—".to_string(),
+        Some(other) => other.to_string(),
+    }
+}
+
+#[function_component(ConfigHistoryPage)]
+pub fn config_history_page() -> Html {
+    let (i18n, _set_language) = use_translation();
+    let navigator = use_navigator();
+
+    let permissions = use_state(|| None::<Vec<PanelPermission>>);
+    {
+        let permissions = permissions.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(resp) = Request::get("/api/user/permissions").send().await
+                    && let Ok(perms) = resp.json::<Vec<PanelPermission>>().await
+                {
+                    permissions.set(Some(perms));
+                }
+            });
+            || ()
+        });
+    }
+
+    if let Some(perms) = (*permissions).as_ref() {
+        if !perms.contains(&PanelPermission::ManageConfig) {
+            return html! {
+                <Forbidden403 required_permission={i18n.t("panel.configuration.title")} />
+            };
+        }
+    } else {
+        return html! {
+            <div class="flex items-center justify-center min-h-[70vh]">
+                <div class="text-gray-400 animate-pulse">{i18n.t("panel.forbidden.checking_permissions")}</div>
+            </div>
+        };
+    }
+
+    let revisions = use_state(|| None::<PaginatedConfigRevisions>);
+    let error = use_state(|| None::<String>);
+    let message = use_state(|| None::<(bool, String)>);
+    let page = use_state(|| 1i64);
+    let selected = use_state(|| None::<i64>);
+    let against = use_state(|| None::<i64>);
+    let diff = use_state(|| None::<ConfigRevisionDiff>);
+
+    let reload = {
+        let revisions = revisions.clone();
+        let error = error.clone();
+        let selected = selected.clone();
+        let page = page.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let revisions = revisions.clone();
+            let error = error.clone();
+            let selected = selected.clone();
+            let i18n = i18n.clone();
+            let url = format!("/api/bot/config/revisions?page={}", *page);
+            spawn_local(async move {
+                match Request::get(&url).send().await {
+                    Ok(resp) if resp.ok() => match resp.json::<PaginatedConfigRevisions>().await {
+                        Ok(data) => {
+                            if selected.is_none() {
+                                selected.set(data.revisions.first().map(|r| r.id));
+                            }
+                            revisions.set(Some(data));
+                            error.set(None);
+                        }
+                        Err(_) => error.set(Some(i18n.t("panel.config_history.error_load"))),
+                    },
+                    _ => error.set(Some(i18n.t("panel.config_history.error_load"))),
+                }
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(*page, move |_| {
+            reload.emit(());
+            || ()
+        });
+    }
+
+    {
+        let diff = diff.clone();
+        use_effect_with((*selected, *against), move |(selected, against)| {
+            let selected = *selected;
+            let against = *against;
+            spawn_local(async move {
+                let Some(id) = selected else {
+                    diff.set(None);
+                    return;
+                };
+                let mut url = format!("/api/bot/config/revisions/{}/diff", id);
+                if let Some(against) = against {
+                    url.push_str(&format!("?against={}", against));
+                }
+                if let Ok(resp) = Request::get(&url).send().await
+                    && resp.ok()
+                {
+                    diff.set(resp.json::<ConfigRevisionDiff>().await.ok());
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_rollback = {
+        let reload = reload.clone();
+        let message = message.clone();
+        let selected = selected.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |id: i64| {
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(
+                        &i18n
+                            .t("panel.config_history.rollback_confirm")
+                            .replace("{id}", &id.to_string()),
+                    )
+                    .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let reload = reload.clone();
+            let message = message.clone();
+            let selected = selected.clone();
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                match Request::post(&format!("/api/bot/config/revisions/{}/rollback", id))
+                    .send()
+                    .await
+                {
+                    Ok(resp) if resp.ok() => {
+                        message.set(Some((
+                            true,
+                            i18n.t("panel.config_history.rollback_done")
+                                .replace("{id}", &id.to_string()),
+                        )));
+                        selected.set(None);
+                        reload.emit(());
+                    }
+                    Ok(resp) => {
+                        let text = resp.text().await.unwrap_or_default();
+                        message.set(Some((false, text)));
+                    }
+                    Err(e) => message.set(Some((false, e.to_string()))),
+                }
+            });
+        })
+    };
+
+    let on_against_change = {
+        let against = against.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            against.set(select.value().parse::<i64>().ok());
+        })
+    };
+
+    let format_ts = |ts: i64| {
+        chrono::DateTime::from_timestamp(ts, 0)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| ts.to_string())
+    };
+
+    let total_pages = revisions
+        .as_ref()
+        .map(|r| r.total_pages.max(1))
+        .unwrap_or(1);
+    let rows = revisions
+        .as_ref()
+        .map(|r| r.revisions.clone())
+        .unwrap_or_default();
+
+    html! {
+        <div class="space-y-6">
+            <div class="flex justify-between items-center">
+                <div>
+                    <h1 class="text-3xl font-bold text-white">{i18n.t("panel.config_history.title")}</h1>
+                    <p class="text-gray-400">{i18n.t("panel.config_history.description")}</p>
+                </div>
+                <button
+                    onclick={{
+                        let navigator = navigator.clone();
+                        move |_| if let Some(nav) = &navigator {
+                            nav.push(&PanelRoute::Configuration);
+                        }
+                    }}
+                    class="px-4 py-2 bg-slate-800 hover:bg-slate-700 text-gray-200 rounded-md text-sm transition"
+                >
+                    {i18n.t("panel.config_history.back")}
+                </button>
+            </div>
+
+            {
+                if let Some((ok, text)) = (*message).clone() {
+                    html! {
+                        <div class={classes!(
+                            "p-4", "rounded-md", "border",
+                            if ok { "bg-green-900/20 border-green-500 text-green-200" } else { "bg-red-900/20 border-red-500 text-red-200" }
+                        )}>{text}</div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+
+            {
+                if let Some(err) = (*error).clone() {
+                    html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-4 rounded-md">{err}</div>
+                    }
+                } else if revisions.is_none() {
+                    html! {
+                        <div class="text-center text-gray-400 py-8">
+                            <p class="animate-pulse">{i18n.t("panel.config_history.loading")}</p>
+                        </div>
+                    }
+                } else if rows.is_empty() {
+                    html! {
+                        <div class="bg-slate-800 rounded-lg p-8 text-center border border-slate-700">
+                            <p class="text-gray-400">{i18n.t("panel.config_history.empty")}</p>
+                        </div>
+                    }
+                } else {
+                    html! {
+                        <div class="bg-slate-800 rounded-lg border border-slate-700 overflow-x-auto">
+                            <table class="min-w-full text-sm text-left">
+                                <thead class="text-gray-400 border-b border-slate-700">
+                                    <tr>
+                                        <th class="px-4 py-3">{i18n.t("panel.config_history.column_revision")}</th>
+                                        <th class="px-4 py-3">{i18n.t("panel.config_history.column_date")}</th>
+                                        <th class="px-4 py-3">{i18n.t("panel.config_history.column_source")}</th>
+                                        <th class="px-4 py-3">{i18n.t("panel.config_history.column_author")}</th>
+                                        <th class="px-4 py-3"></th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                {
+                                    rows.iter().enumerate().map(|(index, revision)| {
+                                        let id = revision.id;
+                                        let is_current = *page == 1 && index == 0;
+                                        let is_selected = *selected == Some(id);
+                                        let source = match revision.rollback_of {
+                                            Some(of) => i18n.t("panel.config_history.rollback_of")
+                                                .replace("{id}", &of.to_string()),
+                                            None => i18n.t(&format!("panel.config_history.source_{}", revision.source)),
+                                        };
+                                        let on_select = {
+                                            let selected = selected.clone();
+                                            Callback::from(move |_| selected.set(Some(id)))
+                                        };
+                                        html! {
+                                            <tr
+                                                key={id}
+                                                onclick={on_select}
+                                                class={classes!(
+                                                    "border-b", "border-slate-700/50", "text-gray-300", "cursor-pointer",
+                                                    if is_selected { "bg-white/10" } else { "hover:bg-white/5" }
+                                                )}
+                                            >
+                                                <td class="px-4 py-3 font-mono">
+                                                    {format!("#{}", id)}
+                                                    {
+                                                        if is_current {
+                                                            html! { <span class="ml-2 text-xs text-green-300">{i18n.t("panel.config_history.current")}</span> }
+                                                        } else {
+                                                            html! {}
+                                                        }
+                                                    }
+                                                </td>
+                                                <td class="px-4 py-3">{format_ts(revision.created_at)}</td>
+                                                <td class="px-4 py-3">{source}</td>
+                                                <td class="px-4 py-3 font-mono text-xs">{revision.author_id.clone().unwrap_or_else(|| "—".to_string())}</td>
+                                                <td class="px-4 py-3 text-right">
+                                                {
+                                                    if is_current {
+                                                        html! {}
+                                                    } else {
+                                                        let on_rollback = on_rollback.clone();
+                                                        html! {
+                                                            <button
+                                                                onclick={Callback::from(move |e: MouseEvent| {
+                                                                    e.stop_propagation();
+                                                                    on_rollback.emit(id);
+                                                                })}
+                                                                class="px-3 py-1 bg-slate-700 hover:bg-slate-600 text-gray-200 rounded-md text-xs transition"
+                                                            >
+                                                                {i18n.t("panel.config_history.rollback")}
+                                                            </button>
+                                                        }
+                                                    }
+                                                }
+                                                </td>
+                                            </tr>
+                                        }
+                                    }).collect::<Html>()
+                                }
+                                </tbody>
+                            </table>
+                        </div>
+                    }
+                }
+            }
+
+            <div class="flex justify-center items-center gap-4 text-gray-300 text-sm">
+                <button
+                    disabled={*page <= 1}
+                    onclick={{
+                        let page = page.clone();
+                        Callback::from(move |_| page.set((*page - 1).max(1)))
+                    }}
+                    class="px-3 py-1 bg-slate-800 hover:bg-slate-700 rounded-md disabled:opacity-50"
+                >
+                    {"<"}
+                </button>
+                <span>{format!("{} / {}", *page, total_pages)}</span>
+                <button
+                    disabled={*page >= total_pages}
+                    onclick={{
+                        let page = page.clone();
+                        Callback::from(move |_| page.set(*page + 1))
+                    }}
+                    class="px-3 py-1 bg-slate-800 hover:bg-slate-700 rounded-md disabled:opacity-50"
+                >
+                    {">"}
+                </button>
+            </div>
+
+            {
+                if let Some(id) = *selected {
+                    html! {
+                        <div class="bg-slate-800 rounded-lg border border-slate-700 p-4 space-y-4">
+                            <div class="flex flex-wrap items-center gap-3">
+                                <h2 class="text-xl font-semibold text-white">
+                                    {i18n.t("panel.config_history.diff_title").replace("{id}", &id.to_string())}
+                                </h2>
+                                <span class="text-gray-400 text-sm">{i18n.t("panel.config_history.compare_with")}</span>
+                                <select
+                                    onchange={on_against_change}
+                                    class="bg-slate-900 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2"
+                                >
+                                    <option value="" selected={against.is_none()}>{i18n.t("panel.config_history.previous")}</option>
+                                    {
+                                        rows.iter().filter(|r| r.id != id).map(|r| html! {
+                                            <option value={r.id.to_string()} selected={*against == Some(r.id)}>
+                                                {format!("#{} — {}", r.id, format_ts(r.created_at))}
+                                            </option>
+                                        }).collect::<Html>()
+                                    }
+                                </select>
+                            </div>
+                            {
+                                match (*diff).clone() {
+                                    Some(d) if d.from.is_none() => html! {
+                                        <p class="text-gray-400">{i18n.t("panel.config_history.first_revision")}</p>
+                                    },
+                                    Some(d) if d.changes.is_empty() => html! {
+                                        <p class="text-gray-400">{i18n.t("panel.config_history.no_changes")}</p>
+                                    },
+                                    Some(d) => html! {
+                                        <table class="min-w-full text-sm text-left">
+                                            <thead class="text-gray-400 border-b border-slate-700">
+                                                <tr>
+                                                    <th class="px-4 py-2">{i18n.t("panel.config_history.field")}</th>
+                                                    <th class="px-4 py-2">{format!("#{}", d.from.unwrap_or_default())}</th>
+                                                    <th class="px-4 py-2">{format!("#{}", d.to)}</th>
+                                                    <th class="px-4 py-2">{i18n.t("panel.config_history.applies")}</th>
+                                                </tr>
+                                            </thead>
+                                            <tbody>
+                                            {
+                                                d.changes.iter().map(|change| html! {
+                                                    <tr key={change.field.clone()} class="border-b border-slate-700/50 text-gray-300 align-top">
+                                                        <td class="px-4 py-2 font-mono text-xs">{&change.field}</td>
+                                                        <td class="px-4 py-2 text-red-300 break-all">{display_value(&change.from)}</td>
+                                                        <td class="px-4 py-2 text-green-300 break-all">{display_value(&change.to)}</td>
+                                                        <td class="px-4 py-2 text-xs">{i18n.t(&format!("panel.config_history.impact_{}", change.impact))}</td>
+                                                    </tr>
+                                                }).collect::<Html>()
+                                            }
+                                            </tbody>
+                                        </table>
+                                    },
+                                    None => html! {
+                                        <p class="text-gray-400 animate-pulse">{i18n.t("panel.config_history.loading")}</p>
+                                    },
+                                }
+                            }
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
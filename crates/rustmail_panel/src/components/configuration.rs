@@ -1,16 +1,19 @@
 use crate::components::forbidden::Forbidden403;
 use crate::i18n::yew::use_translation;
+use crate::pages::panel::PanelRoute;
 use crate::types::PanelPermission;
 use gloo_net::http::Request;
 use wasm_bindgen::JsCast;
 use wasm_bindgen_futures::spawn_local;
 use yew::prelude::*;
+use yew_router::hooks::use_navigator;
 
 use rustmail_types::*;
 
 #[function_component(ConfigurationPage)]
 pub fn configuration_page() -> Html {
     let (i18n, _set_language) = use_translation();
+    let navigator = use_navigator();
 
     let bot_status = use_state(|| "running".to_string());
     let presence_status = use_state(|| "online".to_string());
@@ -164,9 +167,35 @@ pub fn configuration_page() -> Html {
                     Ok(req) => match req.send().await {
                         Ok(resp) => {
                             if resp.ok() {
-                                save_message
-                                    .set(Some((true, i18n.t("panel.configuration.save_success"))));
-                                show_restart_modal.set(true);
+                                let result =
+                                    resp.json::<serde_json::Value>().await.unwrap_or_default();
+                                let fields = |key: &str| -> Vec<String> {
+                                    result[key]
+                                        .as_array()
+                                        .map(|a| {
+                                            a.iter()
+                                                .filter_map(|v| v.as_str().map(String::from))
+                                                .collect()
+                                        })
+                                        .unwrap_or_default()
+                                };
+                                let restart_fields = fields("restart_required");
+                                if restart_fields.is_empty() {
+                                    save_message.set(Some((
+                                        true,
+                                        i18n.t("panel.configuration.save_success"),
+                                    )));
+                                } else {
+                                    save_message.set(Some((
+                                        true,
+                                        i18n.t("panel.configuration.save_needs_process_restart")
+                                            .replace("{fields}", &restart_fields.join(", ")),
+                                    )));
+                                }
+                                let reconnect_fields = fields("reconnect_required");
+                                if !reconnect_fields.is_empty() {
+                                    show_restart_modal.set(true);
+                                }
                                 if let Ok(resp) = Request::get("/api/bot/config").send().await {
                                     if resp.ok() {
                                         if let Ok(config_data) = resp.json::<ConfigResponse>().await
@@ -220,9 +249,19 @@ pub fn configuration_page() -> Html {
         <div class="min-h-screen px-4 sm:px-6 lg:px-8 py-8 text-white">
             <div class="max-w-4xl mx-auto">
 
-                <div class="mb-8">
-                    <h1 class="text-3xl text-white mb-2 font-bold">{i18n.t("panel.configuration.title")}</h1>
-                    <p class="text-gray-400">{i18n.t("panel.configuration.description")}</p>
+                <div class="mb-8 flex items-start justify-between gap-4">
+                    <div>
+                        <h1 class="text-3xl text-white mb-2 font-bold">{i18n.t("panel.configuration.title")}</h1>
+                        <p class="text-gray-400">{i18n.t("panel.configuration.description")}</p>
+                    </div>
+                    <button
+                        onclick={move |_| if let Some(nav) = &navigator {
+                            nav.push(&PanelRoute::ConfigHistory);
+                        }}
+                        class="px-4 py-2 bg-slate-800 hover:bg-slate-700 border border-slate-700 text-gray-200 rounded-md text-sm transition"
+                    >
+                        {i18n.t("panel.configuration.history_button")}
+                    </button>
                 </div>
 
                 <div class="mb-6 bg-slate-800/50 border border-slate-700 rounded-lg p-6">
@@ -1259,6 +1298,19 @@ fn thread_section(props: &ThreadSectionProps) -> Html {
                 }}
             />
 
+            <TextInput
+                label={i18n.t("panel.configuration.threads.board_channel_id")}
+                value={config.thread.board_channel_id.map(|id| id.to_string()).unwrap_or_default()}
+                on_change={{
+                    let config = config.clone();
+                    Callback::from(move |val: String| {
+                        let mut cfg = (*config).clone();
+                        cfg.thread.board_channel_id = val.parse::<u64>().ok();
+                        config.set(cfg);
+                    })
+                }}
+            />
+
             <CheckboxInput
                 label={i18n.t("panel.configuration.threads.embedded_message")}
                 checked={config.thread.embedded_message}
@@ -1342,6 +1394,22 @@ fn thread_section(props: &ThreadSectionProps) -> Html {
                 }}
             />
 
+            <TextInput
+                label={i18n.t("panel.configuration.threads.resolved_close_delay")}
+                value={config.thread.resolved_close_delay.to_string()}
+                input_type={Some("number".to_string())}
+                on_change={{
+                    let config = config.clone();
+                    Callback::from(move |val: String| {
+                        if let Ok(delay) = val.parse::<u64>() {
+                            let mut cfg = (*config).clone();
+                            cfg.thread.resolved_close_delay = delay;
+                            config.set(cfg);
+                        }
+                    })
+                }}
+            />
+
             <CheckboxInput
                 label={i18n.t("panel.configuration.threads.create_by_channel")}
                 checked={config.thread.create_ticket_by_create_channel}
@@ -0,0 +1,462 @@
+use crate::components::forbidden::Forbidden403;
+use crate::i18n::yew::use_translation;
+use crate::types::PanelPermission;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DataSubjectSummary {
+    pub user_id: String,
+    pub counts: BTreeMap<String, u64>,
+    pub open_ticket: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct DataSubjectRequest {
+    pub id: i64,
+    pub user_id: String,
+    pub action: String,
+    pub mode: Option<String>,
+    pub source: String,
+    pub requested_by: String,
+    pub summary: serde_json::Value,
+    pub created_at: i64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct EraseUserDataRequest {
+    mode: String,
+    confirm: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct EraseUserDataResponse {
+    user_id: String,
+    counts: BTreeMap<String, u64>,
+}
+
+fn format_ts(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+fn total_rows(summary: &serde_json::Value) -> u64 {
+    summary
+        .as_object()
+        .map(|counts| counts.values().filter_map(|v| v.as_u64()).sum())
+        .unwrap_or(0)
+}
+
+#[function_component(DataRequestsPage)]
+pub fn data_requests_page() -> Html {
+    let (i18n, _set_language) = use_translation();
+
+    let permissions = use_state(|| None::<Vec<PanelPermission>>);
+    {
+        let permissions = permissions.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(resp) = Request::get("/api/user/permissions").send().await
+                    && let Ok(perms) = resp.json::<Vec<PanelPermission>>().await
+                {
+                    permissions.set(Some(perms));
+                }
+            });
+            || ()
+        });
+    }
+
+    if let Some(perms) = (*permissions).as_ref() {
+        if !perms.contains(&PanelPermission::ManageBot) {
+            return html! {
+                <Forbidden403 required_permission={i18n.t("navbar.gdpr")} />
+            };
+        }
+    } else {
+        return html! {
+            <div class="flex items-center justify-center min-h-[70vh]">
+                <div class="text-gray-400 animate-pulse">{i18n.t("panel.forbidden.checking_permissions")}</div>
+            </div>
+        };
+    }
+
+    let user_input = use_state(String::new);
+    let summary = use_state(|| None::<DataSubjectSummary>);
+    let mode = use_state(|| "anonymize".to_string());
+    let confirm_input = use_state(String::new);
+    let history = use_state(|| None::<Vec<DataSubjectRequest>>);
+    let busy = use_state(|| false);
+    let message = use_state(|| None::<(bool, String)>);
+
+    let reload_history = {
+        let history = history.clone();
+        let message = message.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let history = history.clone();
+            let message = message.clone();
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                match Request::get("/api/admin/gdpr/requests").send().await {
+                    Ok(resp) if resp.ok() => match resp.json::<Vec<DataSubjectRequest>>().await {
+                        Ok(list) => history.set(Some(list)),
+                        Err(e) => message.set(Some((
+                            false,
+                            format!("{}: {}", i18n.t("panel.gdpr.error_load"), e),
+                        ))),
+                    },
+                    Ok(resp) => message.set(Some((
+                        false,
+                        format!("{}: {}", i18n.t("panel.gdpr.error_load"), resp.status()),
+                    ))),
+                    Err(e) => message.set(Some((
+                        false,
+                        format!("{}: {}", i18n.t("panel.gdpr.error_load"), e),
+                    ))),
+                }
+            });
+        })
+    };
+
+    {
+        let reload_history = reload_history.clone();
+        use_effect_with((), move |_| {
+            reload_history.emit(());
+            || ()
+        });
+    }
+
+    let lookup = {
+        let user_input = user_input.clone();
+        let summary = summary.clone();
+        let confirm_input = confirm_input.clone();
+        let message = message.clone();
+        Callback::from(move |_| {
+            let user_id = user_input.trim().to_string();
+            if user_id.is_empty() {
+                return;
+            }
+            let summary = summary.clone();
+            let confirm_input = confirm_input.clone();
+            let message = message.clone();
+            spawn_local(async move {
+                let url = format!("/api/admin/gdpr/{}/summary", urlencoding::encode(&user_id));
+                match Request::get(&url).send().await {
+                    Ok(resp) if resp.ok() => match resp.json::<DataSubjectSummary>().await {
+                        Ok(found) => {
+                            summary.set(Some(found));
+                            confirm_input.set(String::new());
+                            message.set(None);
+                        }
+                        Err(e) => message.set(Some((false, e.to_string()))),
+                    },
+                    Ok(resp) => {
+                        let text = resp.text().await.unwrap_or_default();
+                        summary.set(None);
+                        message.set(Some((false, text)));
+                    }
+                    Err(e) => message.set(Some((false, e.to_string()))),
+                }
+            });
+        })
+    };
+
+    let on_user_input = {
+        let user_input = user_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            user_input.set(input.value());
+        })
+    };
+
+    let on_user_keydown = {
+        let lookup = lookup.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() == "Enter" {
+                lookup.emit(());
+            }
+        })
+    };
+
+    let on_mode_change = {
+        let mode = mode.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            mode.set(select.value());
+        })
+    };
+
+    let on_confirm_input = {
+        let confirm_input = confirm_input.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            confirm_input.set(input.value());
+        })
+    };
+
+    let on_export = {
+        let reload_history = reload_history.clone();
+        Callback::from(move |_: MouseEvent| reload_history.emit(()))
+    };
+
+    let on_erase = {
+        let summary = summary.clone();
+        let mode = mode.clone();
+        let confirm_input = confirm_input.clone();
+        let busy = busy.clone();
+        let message = message.clone();
+        let reload_history = reload_history.clone();
+        let lookup = lookup.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_: MouseEvent| {
+            let Some(current) = (*summary).clone() else {
+                return;
+            };
+            let confirmed = web_sys::window()
+                .and_then(|w| {
+                    w.confirm_with_message(
+                        &i18n
+                            .t("panel.gdpr.erase_confirm")
+                            .replace("{user_id}", &current.user_id),
+                    )
+                    .ok()
+                })
+                .unwrap_or(false);
+            if !confirmed {
+                return;
+            }
+            let body = EraseUserDataRequest {
+                mode: (*mode).clone(),
+                confirm: (*confirm_input).clone(),
+            };
+            let busy = busy.clone();
+            let message = message.clone();
+            let reload_history = reload_history.clone();
+            let lookup = lookup.clone();
+            let i18n = i18n.clone();
+            busy.set(true);
+            spawn_local(async move {
+                let url = format!(
+                    "/api/admin/gdpr/{}/erase",
+                    urlencoding::encode(&current.user_id)
+                );
+                match Request::post(&url).json(&body) {
+                    Ok(req) => match req.send().await {
+                        Ok(resp) if resp.ok() => match resp.json::<EraseUserDataResponse>().await {
+                            Ok(result) => message.set(Some((
+                                true,
+                                i18n.t("panel.gdpr.erased")
+                                    .replace("{user_id}", &result.user_id)
+                                    .replace(
+                                        "{rows}",
+                                        &result.counts.values().sum::<u64>().to_string(),
+                                    ),
+                            ))),
+                            Err(e) => message.set(Some((false, e.to_string()))),
+                        },
+                        Ok(resp) => {
+                            let text = resp.text().await.unwrap_or_default();
+                            message.set(Some((false, text)));
+                        }
+                        Err(e) => message.set(Some((false, e.to_string()))),
+                    },
+                    Err(e) => message.set(Some((false, format!("{:?}", e)))),
+                }
+                busy.set(false);
+                reload_history.emit(());
+                lookup.emit(());
+            });
+        })
+    };
+
+    let details = match (*summary).as_ref() {
+        None => html! {},
+        Some(found) => {
+            let export_url = format!(
+                "/api/admin/gdpr/{}/export",
+                urlencoding::encode(&found.user_id)
+            );
+            let can_erase =
+                !found.open_ticket && !found.counts.is_empty() && *confirm_input == found.user_id;
+            html! {
+                <div class="bg-slate-800 rounded-lg border border-slate-700 p-6 space-y-6">
+                    <div class="flex justify-between items-center">
+                        <h2 class="text-xl font-semibold text-white font-mono">{&found.user_id}</h2>
+                        <a
+                            href={export_url}
+                            download={format!("rustmail-user-{}.json", found.user_id)}
+                            onclick={on_export}
+                            class="px-4 py-2 bg-blue-600 hover:bg-blue-700 text-white rounded-md text-sm transition"
+                        >
+                            {i18n.t("panel.gdpr.export")}
+                        </a>
+                    </div>
+
+                    {
+                        if found.counts.is_empty() {
+                            html! { <p class="text-gray-400">{i18n.t("panel.gdpr.no_data")}</p> }
+                        } else {
+                            html! {
+                                <table class="min-w-full text-sm text-left">
+                                    <thead class="text-gray-400 border-b border-slate-700">
+                                        <tr>
+                                            <th class="px-4 py-2">{i18n.t("panel.gdpr.column_table")}</th>
+                                            <th class="px-4 py-2">{i18n.t("panel.gdpr.column_rows")}</th>
+                                        </tr>
+                                    </thead>
+                                    <tbody>
+                                    {
+                                        found.counts.iter().map(|(table, count)| html! {
+                                            <tr key={table.clone()} class="border-b border-slate-700/50 text-gray-300">
+                                                <td class="px-4 py-2 font-mono text-xs">{table}</td>
+                                                <td class="px-4 py-2">{count}</td>
+                                            </tr>
+                                        }).collect::<Html>()
+                                    }
+                                    </tbody>
+                                </table>
+                            }
+                        }
+                    }
+
+                    <div class="border-t border-slate-700 pt-6 space-y-3">
+                        <h3 class="text-lg font-semibold text-red-200">{i18n.t("panel.gdpr.erase_title")}</h3>
+                        <p class="text-gray-400 text-sm">{i18n.t("panel.gdpr.erase_description")}</p>
+                        {
+                            if found.open_ticket {
+                                html! {
+                                    <div class="bg-yellow-900/20 border border-yellow-500 text-yellow-200 p-3 rounded-md text-sm">
+                                        {i18n.t("panel.gdpr.open_ticket")}
+                                    </div>
+                                }
+                            } else {
+                                html! {}
+                            }
+                        }
+                        <div class="flex flex-wrap items-center gap-2">
+                            <select
+                                onchange={on_mode_change}
+                                class="bg-slate-900 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2"
+                            >
+                                <option value="anonymize" selected={*mode == "anonymize"}>{i18n.t("panel.gdpr.mode_anonymize")}</option>
+                                <option value="delete" selected={*mode == "delete"}>{i18n.t("panel.gdpr.mode_delete")}</option>
+                            </select>
+                            <input
+                                type="text"
+                                value={(*confirm_input).clone()}
+                                oninput={on_confirm_input}
+                                placeholder={i18n.t("panel.gdpr.confirm_placeholder")}
+                                class="bg-slate-900 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2 font-mono"
+                            />
+                            <button
+                                onclick={on_erase}
+                                disabled={*busy || !can_erase}
+                                class="px-4 py-2 bg-red-900/30 border border-red-500 text-red-200 hover:bg-red-900/50 rounded-md text-sm transition disabled:opacity-50"
+                            >
+                                {i18n.t("panel.gdpr.erase")}
+                            </button>
+                        </div>
+                    </div>
+                </div>
+            }
+        }
+    };
+
+    let history_table = match (*history).as_ref() {
+        None => html! {
+            <p class="text-gray-400 animate-pulse">{i18n.t("panel.gdpr.loading")}</p>
+        },
+        Some(list) if list.is_empty() => html! {
+            <div class="bg-slate-800 rounded-lg p-8 text-center border border-slate-700">
+                <p class="text-gray-400">{i18n.t("panel.gdpr.no_requests")}</p>
+            </div>
+        },
+        Some(list) => html! {
+            <div class="bg-slate-800 rounded-lg border border-slate-700 overflow-x-auto">
+                <table class="min-w-full text-sm text-left">
+                    <thead class="text-gray-400 border-b border-slate-700">
+                        <tr>
+                            <th class="px-4 py-3">{i18n.t("panel.gdpr.column_date")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.gdpr.column_user")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.gdpr.column_action")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.gdpr.column_source")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.gdpr.column_requested_by")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.gdpr.column_rows")}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                    {
+                        list.iter().map(|request| {
+                            let action = match (request.action.as_str(), request.mode.as_deref()) {
+                                ("export", _) => i18n.t("panel.gdpr.action_export"),
+                                (_, Some("delete")) => i18n.t("panel.gdpr.action_delete"),
+                                _ => i18n.t("panel.gdpr.action_anonymize"),
+                            };
+                            html! {
+                                <tr key={request.id} class="border-b border-slate-700/50 text-gray-300">
+                                    <td class="px-4 py-3">{format_ts(request.created_at)}</td>
+                                    <td class="px-4 py-3 font-mono text-xs">{&request.user_id}</td>
+                                    <td class="px-4 py-3">{action}</td>
+                                    <td class="px-4 py-3">{&request.source}</td>
+                                    <td class="px-4 py-3 font-mono text-xs">{&request.requested_by}</td>
+                                    <td class="px-4 py-3">{total_rows(&request.summary)}</td>
+                                </tr>
+                            }
+                        }).collect::<Html>()
+                    }
+                    </tbody>
+                </table>
+            </div>
+        },
+    };
+
+    html! {
+        <div class="space-y-6">
+            <div>
+                <h1 class="text-3xl font-bold text-white">{i18n.t("panel.gdpr.title")}</h1>
+                <p class="text-gray-400 text-sm mt-1">{i18n.t("panel.gdpr.description")}</p>
+            </div>
+
+            <div class="flex items-center gap-2">
+                <input
+                    type="text"
+                    value={(*user_input).clone()}
+                    oninput={on_user_input}
+                    onkeydown={on_user_keydown}
+                    placeholder={i18n.t("panel.gdpr.user_placeholder")}
+                    class="bg-slate-800 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2 font-mono w-72"
+                />
+                <button
+                    onclick={lookup.reform(|_: MouseEvent| ())}
+                    class="px-4 py-2 bg-slate-700 hover:bg-slate-600 text-gray-200 rounded-md text-sm transition"
+                >
+                    {i18n.t("panel.gdpr.lookup")}
+                </button>
+            </div>
+
+            {
+                match (*message).clone() {
+                    Some((true, text)) => html! {
+                        <div class="bg-green-900/20 border border-green-500 text-green-200 p-4 rounded-md">{text}</div>
+                    },
+                    Some((false, text)) => html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-4 rounded-md">{text}</div>
+                    },
+                    None => html! {},
+                }
+            }
+
+            {details}
+
+            <div class="space-y-3">
+                <h2 class="text-xl font-semibold text-white">{i18n.t("panel.gdpr.history")}</h2>
+                {history_table}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,338 @@
+use crate::components::forbidden::Forbidden403;
+use crate::i18n::yew::use_translation;
+use crate::types::PanelPermission;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use yew::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureField {
+    pub key: String,
+    pub kind: String,
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    pub max_length: Option<usize>,
+    pub default: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeatureDto {
+    pub key: String,
+    pub enabled: bool,
+    pub settings: Map<String, Value>,
+    pub fields: Vec<FeatureField>,
+}
+
+async fn update_feature(key: &str, body: Value) -> Result<FeatureDto, String> {
+    let resp = Request::put(&format!("/api/features/{}", key))
+        .json(&body)
+        .map_err(|e| e.to_string())?
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if resp.status() != 200 {
+        return Err(resp
+            .text()
+            .await
+            .unwrap_or_else(|_| resp.status().to_string()));
+    }
+    resp.json::<FeatureDto>().await.map_err(|e| e.to_string())
+}
+
+#[function_component(FeaturesPage)]
+pub fn features_page() -> Html {
+    let (i18n, _set_language) = use_translation();
+
+    let permissions = use_state(|| None::<Vec<PanelPermission>>);
+    {
+        let permissions = permissions.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(resp) = Request::get("/api/user/permissions").send().await
+                    && let Ok(perms) = resp.json::<Vec<PanelPermission>>().await
+                {
+                    permissions.set(Some(perms));
+                }
+            });
+            || ()
+        });
+    }
+
+    if let Some(perms) = (*permissions).as_ref() {
+        if !perms.contains(&PanelPermission::ManageBot) {
+            return html! {
+                <Forbidden403 required_permission={i18n.t("navbar.features")} />
+            };
+        }
+    } else {
+        return html! {
+            <div class="flex items-center justify-center min-h-[70vh]">
+                <div class="text-gray-400 animate-pulse">{i18n.t("panel.forbidden.checking_permissions")}</div>
+            </div>
+        };
+    }
+
+    let features = use_state(|| None::<Vec<FeatureDto>>);
+    // Unsaved settings edits, keyed by feature.
+    let drafts = use_state(HashMap::<String, Map<String, Value>>::new);
+    let error = use_state(|| None::<String>);
+    let saved = use_state(|| None::<String>);
+
+    {
+        let features = features.clone();
+        let error = error.clone();
+        let i18n = i18n.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let result = match Request::get("/api/features").send().await {
+                    Ok(resp) if resp.status() == 200 => resp
+                        .json::<Vec<FeatureDto>>()
+                        .await
+                        .map_err(|e| e.to_string()),
+                    Ok(resp) => Err(resp.status().to_string()),
+                    Err(e) => Err(e.to_string()),
+                };
+                match result {
+                    Ok(list) => features.set(Some(list)),
+                    Err(e) => error.set(Some(format!(
+                        "{}: {}",
+                        i18n.t("panel.features.error_load"),
+                        e
+                    ))),
+                }
+            });
+            || ()
+        });
+    }
+
+    let on_update = {
+        let features = features.clone();
+        let drafts = drafts.clone();
+        let error = error.clone();
+        let saved = saved.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |(key, body): (String, Value)| {
+            let features = features.clone();
+            let drafts = drafts.clone();
+            let error = error.clone();
+            let saved = saved.clone();
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                match update_feature(&key, body).await {
+                    Ok(updated) => {
+                        if let Some(list) = (*features).as_ref() {
+                            let list = list
+                                .iter()
+                                .map(|f| {
+                                    if f.key == key {
+                                        updated.clone()
+                                    } else {
+                                        f.clone()
+                                    }
+                                })
+                                .collect();
+                            features.set(Some(list));
+                        }
+                        let mut d = (*drafts).clone();
+                        d.remove(&key);
+                        drafts.set(d);
+                        error.set(None);
+                        saved.set(Some(key));
+                    }
+                    Err(e) => {
+                        saved.set(None);
+                        error.set(Some(format!(
+                            "{}: {}",
+                            i18n.t("panel.features.error_save"),
+                            e
+                        )));
+                    }
+                }
+            });
+        })
+    };
+
+    let set_draft = {
+        let drafts = drafts.clone();
+        Callback::from(move |(feature, key, value): (String, String, Value)| {
+            let mut d = (*drafts).clone();
+            d.entry(feature).or_default().insert(key, value);
+            drafts.set(d);
+        })
+    };
+
+    let render_field = |feature: &FeatureDto, field: &FeatureField| -> Html {
+        let draft = drafts.get(&feature.key).and_then(|d| d.get(&field.key));
+        let value = draft
+            .or_else(|| feature.settings.get(&field.key))
+            .cloned()
+            .unwrap_or_else(|| field.default.clone());
+        let label = i18n.t(&format!("panel.features.{}.{}", feature.key, field.key));
+
+        let input = match field.kind.as_str() {
+            "boolean" => {
+                let set_draft = set_draft.clone();
+                let (fk, key) = (feature.key.clone(), field.key.clone());
+                let onchange = Callback::from(move |e: Event| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    set_draft.emit((fk.clone(), key.clone(), Value::Bool(input.checked())));
+                });
+                html! {
+                    <input type="checkbox" class="h-4 w-4" checked={value.as_bool().unwrap_or(false)} {onchange} />
+                }
+            }
+            "integer" => {
+                let set_draft = set_draft.clone();
+                let (fk, key) = (feature.key.clone(), field.key.clone());
+                let oninput = Callback::from(move |e: InputEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    if let Ok(v) = input.value().parse::<i64>() {
+                        set_draft.emit((fk.clone(), key.clone(), json!(v)));
+                    }
+                });
+                html! {
+                    <input
+                        type="number"
+                        class="w-32 bg-slate-900 border border-slate-600 rounded-md px-2 py-1 text-gray-200"
+                        min={field.min.map(|m| m.to_string())}
+                        max={field.max.map(|m| m.to_string())}
+                        value={value.as_i64().map(|v| v.to_string()).unwrap_or_default()}
+                        {oninput}
+                    />
+                }
+            }
+            _ => {
+                let set_draft = set_draft.clone();
+                let (fk, key) = (feature.key.clone(), field.key.clone());
+                let oninput = Callback::from(move |e: InputEvent| {
+                    let input: HtmlInputElement = e.target_unchecked_into();
+                    set_draft.emit((fk.clone(), key.clone(), Value::String(input.value())));
+                });
+                html! {
+                    <input
+                        type="text"
+                        class="w-64 bg-slate-900 border border-slate-600 rounded-md px-2 py-1 text-gray-200"
+                        maxlength={field.max_length.map(|m| m.to_string())}
+                        value={value.as_str().unwrap_or_default().to_string()}
+                        {oninput}
+                    />
+                }
+            }
+        };
+
+        html! {
+            <label key={field.key.clone()} class="flex items-center justify-between gap-4 py-2">
+                <span class="text-gray-300 text-sm">{label}</span>
+                {input}
+            </label>
+        }
+    };
+
+    html! {
+        <div class="space-y-6">
+            <div>
+                <h1 class="text-3xl font-bold text-white">{i18n.t("panel.features.title")}</h1>
+                <p class="text-gray-400 mt-1">{i18n.t("panel.features.description")}</p>
+            </div>
+
+            {
+                if let Some(err) = (*error).clone() {
+                    html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-4 rounded-md">{err}</div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+
+            {
+                match (*features).as_ref() {
+                    None => html! {
+                        <p class="text-gray-400 animate-pulse">{i18n.t("panel.features.loading")}</p>
+                    },
+                    Some(list) => list.iter().map(|feature| {
+                        let on_toggle = {
+                            let on_update = on_update.clone();
+                            let key = feature.key.clone();
+                            let enabled = feature.enabled;
+                            Callback::from(move |_| on_update.emit((key.clone(), json!({ "enabled": !enabled }))))
+                        };
+                        let draft = drafts.get(&feature.key).cloned();
+                        let on_save = {
+                            let on_update = on_update.clone();
+                            let key = feature.key.clone();
+                            let draft = draft.clone().unwrap_or_default();
+                            Callback::from(move |_| on_update.emit((key.clone(), json!({ "settings": draft.clone() }))))
+                        };
+
+                        html! {
+                            <section key={feature.key.clone()} class="bg-slate-800 rounded-lg border border-slate-700 p-6 space-y-4">
+                                <div class="flex items-start justify-between gap-4">
+                                    <div>
+                                        <h2 class="text-xl font-semibold text-white">
+                                            {i18n.t(&format!("panel.features.{}.name", feature.key))}
+                                        </h2>
+                                        <p class="text-gray-400 text-sm">
+                                            {i18n.t(&format!("panel.features.{}.description", feature.key))}
+                                        </p>
+                                    </div>
+                                    <button
+                                        onclick={on_toggle}
+                                        class={if feature.enabled {
+                                            "px-3 py-1 bg-green-900/30 border border-green-500 text-green-200 rounded-md text-sm transition"
+                                        } else {
+                                            "px-3 py-1 bg-slate-700 border border-slate-500 text-gray-300 rounded-md text-sm transition"
+                                        }}
+                                    >
+                                        {if feature.enabled { i18n.t("panel.features.enabled") } else { i18n.t("panel.features.disabled") }}
+                                    </button>
+                                </div>
+
+                                {
+                                    if feature.fields.is_empty() {
+                                        html! {}
+                                    } else {
+                                        html! {
+                                            <div class="divide-y divide-slate-700">
+                                                { for feature.fields.iter().map(|field| render_field(feature, field)) }
+                                            </div>
+                                        }
+                                    }
+                                }
+
+                                <div class="flex items-center justify-end gap-3">
+                                    {
+                                        if (*saved).as_deref() == Some(feature.key.as_str()) {
+                                            html! { <span class="text-green-300 text-sm">{i18n.t("panel.features.saved")}</span> }
+                                        } else {
+                                            html! {}
+                                        }
+                                    }
+                                    {
+                                        if feature.fields.is_empty() {
+                                            html! {}
+                                        } else {
+                                            html! {
+                                                <button
+                                                    onclick={on_save}
+                                                    disabled={draft.is_none()}
+                                                    class="px-4 py-2 bg-blue-600 hover:bg-blue-500 disabled:opacity-50 text-white rounded-md text-sm transition"
+                                                >
+                                                    {i18n.t("panel.features.save")}
+                                                </button>
+                                            }
+                                        }
+                                    }
+                                </div>
+                            </section>
+                        }
+                    }).collect::<Html>(),
+                }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,300 @@
+use crate::components::forbidden::Forbidden403;
+use crate::i18n::yew::use_translation;
+use crate::types::PanelPermission;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlSelectElement;
+use yew::prelude::*;
+
+const JOB_STATUSES: [&str; 5] = ["pending", "running", "completed", "failed", "cancelled"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobDto {
+    pub id: i64,
+    pub kind: String,
+    pub job_key: String,
+    pub status: String,
+    pub run_at: i64,
+    pub attempts: i64,
+    pub max_attempts: i64,
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PaginatedJobsResponse {
+    pub jobs: Vec<JobDto>,
+    pub total: i64,
+    pub page: i64,
+    pub page_size: i64,
+    pub total_pages: i64,
+}
+
+#[function_component(JobsPage)]
+pub fn jobs_page() -> Html {
+    let (i18n, _set_language) = use_translation();
+
+    let permissions = use_state(|| None::<Vec<PanelPermission>>);
+    {
+        let permissions = permissions.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(resp) = Request::get("/api/user/permissions").send().await
+                    && let Ok(perms) = resp.json::<Vec<PanelPermission>>().await
+                {
+                    permissions.set(Some(perms));
+                }
+            });
+            || ()
+        });
+    }
+
+    if let Some(perms) = (*permissions).as_ref() {
+        if !perms.contains(&PanelPermission::ManageBot) {
+            return html! {
+                <Forbidden403 required_permission={i18n.t("navbar.jobs")} />
+            };
+        }
+    } else {
+        return html! {
+            <div class="flex items-center justify-center min-h-[70vh]">
+                <div class="text-gray-400 animate-pulse">{i18n.t("panel.forbidden.checking_permissions")}</div>
+            </div>
+        };
+    }
+
+    let jobs = use_state(|| None::<PaginatedJobsResponse>);
+    let loading = use_state(|| true);
+    let error = use_state(|| None::<String>);
+    let status_filter = use_state(String::new);
+    let page = use_state(|| 1i64);
+
+    let reload = {
+        let jobs = jobs.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let status_filter = status_filter.clone();
+        let page = page.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let jobs = jobs.clone();
+            let loading = loading.clone();
+            let error = error.clone();
+            let i18n = i18n.clone();
+            let mut url = format!("/api/jobs?page={}", *page);
+            if !status_filter.is_empty() {
+                url.push_str(&format!("&status={}", *status_filter));
+            }
+            spawn_local(async move {
+                loading.set(true);
+                match Request::get(&url).send().await {
+                    Ok(resp) => {
+                        if resp.status() == 200 {
+                            if let Ok(data) = resp.json::<PaginatedJobsResponse>().await {
+                                jobs.set(Some(data));
+                                error.set(None);
+                            } else {
+                                error.set(Some(i18n.t("panel.jobs.error_parse")));
+                            }
+                        } else {
+                            error.set(Some(format!(
+                                "{}: {}",
+                                i18n.t("panel.jobs.error_load"),
+                                resp.status()
+                            )));
+                        }
+                    }
+                    Err(e) => {
+                        error.set(Some(format!("{}: {}", i18n.t("panel.jobs.error_load"), e)));
+                    }
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with(((*status_filter).clone(), *page), move |_| {
+            reload.emit(());
+            || ()
+        });
+    }
+
+    let on_status_change = {
+        let status_filter = status_filter.clone();
+        let page = page.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            status_filter.set(select.value());
+            page.set(1);
+        })
+    };
+
+    let on_action = {
+        let reload = reload.clone();
+        Callback::from(move |(id, action): (i64, &'static str)| {
+            let reload = reload.clone();
+            spawn_local(async move {
+                let _ = Request::post(&format!("/api/jobs/{}/{}", id, action))
+                    .send()
+                    .await;
+                reload.emit(());
+            });
+        })
+    };
+
+    let format_ts = |ts: i64| {
+        chrono::DateTime::from_timestamp(ts, 0)
+            .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+            .unwrap_or_else(|| ts.to_string())
+    };
+
+    let total_pages = jobs.as_ref().map(|j| j.total_pages.max(1)).unwrap_or(1);
+
+    html! {
+        <div class="space-y-6">
+            <div class="flex justify-between items-center">
+                <h1 class="text-3xl font-bold text-white">{i18n.t("panel.jobs.title")}</h1>
+                <div class="flex items-center gap-3">
+                    <select
+                        onchange={on_status_change}
+                        class="bg-slate-800 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2"
+                    >
+                        <option value="" selected={status_filter.is_empty()}>{i18n.t("panel.jobs.all_statuses")}</option>
+                        {
+                            JOB_STATUSES.iter().map(|s| html! {
+                                <option value={*s} selected={*status_filter == *s}>
+                                    {i18n.t(&format!("panel.jobs.status_{}", s))}
+                                </option>
+                            }).collect::<Html>()
+                        }
+                    </select>
+                    <button
+                        onclick={reload.clone().reform(|_| ())}
+                        class="p-2 bg-slate-800 hover:bg-slate-700 text-gray-300 rounded-full transition"
+                        title={i18n.t("panel.jobs.reload")}
+                    >
+                        <svg class="h-5 w-5" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24">
+                            <path stroke-linecap="round" stroke-linejoin="round" d="M4 4v5h.582m15.356 2A8.001 8.001 0 004.582 9m0 0H9m11 11v-5h-.581m0 0a8.003 8.003 0 01-15.357-2m15.357 2H15" />
+                        </svg>
+                    </button>
+                </div>
+            </div>
+
+            {
+                if *loading && jobs.is_none() {
+                    html! {
+                        <div class="text-center text-gray-400 py-8">
+                            <p class="animate-pulse">{i18n.t("panel.jobs.loading")}</p>
+                        </div>
+                    }
+                } else if let Some(err) = (*error).clone() {
+                    html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-4 rounded-md">{err}</div>
+                    }
+                } else if jobs.as_ref().map(|j| j.jobs.is_empty()).unwrap_or(true) {
+                    html! {
+                        <div class="bg-slate-800 rounded-lg p-8 text-center border border-slate-700">
+                            <p class="text-gray-400">{i18n.t("panel.jobs.no_jobs")}</p>
+                        </div>
+                    }
+                } else {
+                    html! {
+                        <div class="bg-slate-800 rounded-lg border border-slate-700 overflow-x-auto">
+                            <table class="min-w-full text-sm text-left">
+                                <thead class="text-gray-400 border-b border-slate-700">
+                                    <tr>
+                                        <th class="px-4 py-3">{i18n.t("panel.jobs.column_kind")}</th>
+                                        <th class="px-4 py-3">{i18n.t("panel.jobs.column_key")}</th>
+                                        <th class="px-4 py-3">{i18n.t("panel.jobs.column_status")}</th>
+                                        <th class="px-4 py-3">{i18n.t("panel.jobs.column_run_at")}</th>
+                                        <th class="px-4 py-3">{i18n.t("panel.jobs.column_attempts")}</th>
+                                        <th class="px-4 py-3">{i18n.t("panel.jobs.column_last_error")}</th>
+                                        <th class="px-4 py-3"></th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                {
+                                    jobs.as_ref().map(|j| j.jobs.iter().map(|job| {
+                                        let id = job.id;
+                                        let action = match job.status.as_str() {
+                                            "pending" => Some(("cancel", "panel.jobs.cancel")),
+                                            "failed" | "cancelled" => Some(("retry", "panel.jobs.retry")),
+                                            _ => None,
+                                        };
+                                        let status_class = match job.status.as_str() {
+                                            "completed" => "text-green-300",
+                                            "failed" => "text-red-300",
+                                            "running" => "text-blue-300",
+                                            "cancelled" => "text-gray-400",
+                                            _ => "text-yellow-300",
+                                        };
+                                        html! {
+                                            <tr key={job.id} class="border-b border-slate-700/50 text-gray-300">
+                                                <td class="px-4 py-3">{i18n.t(&format!("panel.jobs.kind_{}", job.kind))}</td>
+                                                <td class="px-4 py-3 font-mono text-xs">{&job.job_key}</td>
+                                                <td class={classes!("px-4", "py-3", status_class)}>
+                                                    {i18n.t(&format!("panel.jobs.status_{}", job.status))}
+                                                </td>
+                                                <td class="px-4 py-3">{format_ts(job.run_at)}</td>
+                                                <td class="px-4 py-3">{format!("{}/{}", job.attempts, job.max_attempts)}</td>
+                                                <td class="px-4 py-3 text-xs text-red-300 max-w-xs truncate" title={job.last_error.clone().unwrap_or_default()}>
+                                                    {job.last_error.clone().unwrap_or_default()}
+                                                </td>
+                                                <td class="px-4 py-3 text-right">
+                                                {
+                                                    if let Some((action, label)) = action {
+                                                        let on_action = on_action.clone();
+                                                        html! {
+                                                            <button
+                                                                onclick={Callback::from(move |_| on_action.emit((id, action)))}
+                                                                class="px-3 py-1 bg-slate-700 hover:bg-slate-600 text-gray-200 rounded-md text-xs transition"
+                                                            >
+                                                                {i18n.t(label)}
+                                                            </button>
+                                                        }
+                                                    } else {
+                                                        html! {}
+                                                    }
+                                                }
+                                                </td>
+                                            </tr>
+                                        }
+                                    }).collect::<Html>()).unwrap_or_default()
+                                }
+                                </tbody>
+                            </table>
+                        </div>
+                    }
+                }
+            }
+
+            <div class="flex justify-center items-center gap-4 text-gray-300 text-sm">
+                <button
+                    disabled={*page <= 1}
+                    onclick={{
+                        let page = page.clone();
+                        Callback::from(move |_| page.set((*page - 1).max(1)))
+                    }}
+                    class="px-3 py-1 bg-slate-800 hover:bg-slate-700 rounded-md disabled:opacity-50"
+                >
+                    {"<"}
+                </button>
+                <span>{format!("{} / {}", *page, total_pages)}</span>
+                <button
+                    disabled={*page >= total_pages}
+                    onclick={{
+                        let page = page.clone();
+                        Callback::from(move |_| page.set(*page + 1))
+                    }}
+                    class="px-3 py-1 bg-slate-800 hover:bg-slate-700 rounded-md disabled:opacity-50"
+                >
+                    {">"}
+                </button>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,240 @@
+use crate::components::forbidden::Forbidden403;
+use crate::i18n::yew::use_translation;
+use crate::types::PanelPermission;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+const LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogEntry {
+    pub id: u64,
+    pub timestamp: i64,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+    pub fields: BTreeMap<String, String>,
+}
+
+#[function_component(LogsPage)]
+pub fn logs_page() -> Html {
+    let (i18n, _set_language) = use_translation();
+
+    let permissions = use_state(|| None::<Vec<PanelPermission>>);
+    {
+        let permissions = permissions.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(resp) = Request::get("/api/user/permissions").send().await
+                    && let Ok(perms) = resp.json::<Vec<PanelPermission>>().await
+                {
+                    permissions.set(Some(perms));
+                }
+            });
+            || ()
+        });
+    }
+
+    if let Some(perms) = (*permissions).as_ref() {
+        if !perms.contains(&PanelPermission::ManageBot) {
+            return html! {
+                <Forbidden403 required_permission={i18n.t("navbar.logs")} />
+            };
+        }
+    } else {
+        return html! {
+            <div class="flex items-center justify-center min-h-[70vh]">
+                <div class="text-gray-400 animate-pulse">{i18n.t("panel.forbidden.checking_permissions")}</div>
+            </div>
+        };
+    }
+
+    let entries = use_state(Vec::<LogEntry>::new);
+    let loading = use_state(|| true);
+    let error = use_state(|| None::<String>);
+    let level = use_state(|| "info".to_string());
+    let search = use_state(String::new);
+
+    let reload = {
+        let entries = entries.clone();
+        let loading = loading.clone();
+        let error = error.clone();
+        let level = level.clone();
+        let search = search.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let entries = entries.clone();
+            let loading = loading.clone();
+            let error = error.clone();
+            let i18n = i18n.clone();
+            let mut url = format!("/api/admin/logs?limit=500&level={}", *level);
+            if !search.is_empty() {
+                url.push_str(&format!("&search={}", urlencoding::encode(&search)));
+            }
+            spawn_local(async move {
+                loading.set(true);
+                match Request::get(&url).send().await {
+                    Ok(resp) => {
+                        if resp.status() == 200 {
+                            if let Ok(data) = resp.json::<Vec<LogEntry>>().await {
+                                entries.set(data);
+                                error.set(None);
+                            } else {
+                                error.set(Some(i18n.t("panel.logs.error_parse")));
+                            }
+                        } else {
+                            error.set(Some(format!(
+                                "{}: {}",
+                                i18n.t("panel.logs.error_load"),
+                                resp.status()
+                            )));
+                        }
+                    }
+                    Err(e) => {
+                        error.set(Some(format!("{}: {}", i18n.t("panel.logs.error_load"), e)));
+                    }
+                }
+                loading.set(false);
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with((*level).clone(), move |_| {
+            reload.emit(());
+            || ()
+        });
+    }
+
+    let on_level_change = {
+        let level = level.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            level.set(select.value());
+        })
+    };
+
+    let on_search_input = {
+        let search = search.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            search.set(input.value());
+        })
+    };
+
+    let on_search_keydown = {
+        let reload = reload.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() == "Enter" {
+                reload.emit(());
+            }
+        })
+    };
+
+    html! {
+        <div class="space-y-6">
+            <div class="flex flex-wrap justify-between items-center gap-3">
+                <h1 class="text-3xl font-bold text-white">{i18n.t("panel.logs.title")}</h1>
+                <div class="flex items-center gap-3">
+                    <input
+                        type="text"
+                        value={(*search).clone()}
+                        oninput={on_search_input}
+                        onkeydown={on_search_keydown}
+                        placeholder={i18n.t("panel.logs.search_placeholder")}
+                        class="bg-slate-800 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2"
+                    />
+                    <select
+                        onchange={on_level_change}
+                        class="bg-slate-800 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2"
+                    >
+                        {
+                            LOG_LEVELS.iter().map(|l| html! {
+                                <option value={*l} selected={*level == *l}>
+                                    {i18n.t(&format!("panel.logs.level_{}", l))}
+                                </option>
+                            }).collect::<Html>()
+                        }
+                    </select>
+                    <button
+                        onclick={reload.clone().reform(|_| ())}
+                        class="p-2 bg-slate-800 hover:bg-slate-700 text-gray-300 rounded-full transition"
+                        title={i18n.t("panel.logs.reload")}
+                    >
+                        <svg class="h-5 w-5" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24">
+                            <path stroke-linecap="round" stroke-linejoin="round" d="M4 4v5h.582m15.356 2A8.001 8.001 0 004.582 9m0 0H9m11 11v-5h-.581m0 0a8.003 8.003 0 01-15.357-2m15.357 2H15" />
+                        </svg>
+                    </button>
+                </div>
+            </div>
+
+            {
+                if *loading && entries.is_empty() {
+                    html! {
+                        <div class="text-center text-gray-400 py-8">
+                            <p class="animate-pulse">{i18n.t("panel.logs.loading")}</p>
+                        </div>
+                    }
+                } else if let Some(err) = (*error).clone() {
+                    html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-4 rounded-md">{err}</div>
+                    }
+                } else if entries.is_empty() {
+                    html! {
+                        <div class="bg-slate-800 rounded-lg p-8 text-center border border-slate-700">
+                            <p class="text-gray-400">{i18n.t("panel.logs.no_logs")}</p>
+                        </div>
+                    }
+                } else {
+                    html! {
+                        <div class="bg-slate-900 rounded-lg border border-slate-700 p-4 font-mono text-xs overflow-x-auto space-y-1">
+                            {
+                                entries.iter().rev().map(|entry| {
+                                    let time = chrono::DateTime::from_timestamp_millis(entry.timestamp)
+                                        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+                                        .unwrap_or_default();
+                                    let level_class = match entry.level.as_str() {
+                                        "ERROR" => "text-red-400",
+                                        "WARN" => "text-yellow-400",
+                                        "INFO" => "text-green-400",
+                                        "DEBUG" => "text-blue-400",
+                                        _ => "text-gray-500",
+                                    };
+                                    let fields = entry
+                                        .fields
+                                        .iter()
+                                        .map(|(k, v)| format!("{}={}", k, v))
+                                        .collect::<Vec<_>>()
+                                        .join(" ");
+                                    html! {
+                                        <div key={entry.id} class="whitespace-pre-wrap break-all text-gray-300">
+                                            <span class="text-gray-500">{time}</span>
+                                            {" "}
+                                            <span class={classes!("font-semibold", level_class)}>{format!("{:>5}", entry.level)}</span>
+                                            {" "}
+                                            <span class="text-slate-400">{&entry.target}</span>
+                                            {": "}
+                                            <span>{&entry.message}</span>
+                                            {
+                                                if fields.is_empty() {
+                                                    html! {}
+                                                } else {
+                                                    html! { <span class="text-cyan-300">{format!(" {}", fields)}</span> }
+                                                }
+                                            }
+                                        </div>
+                                    }
+                                }).collect::<Html>()
+                            }
+                        </div>
+                    }
+                }
+            }
+        </div>
+    }
+}
@@ -1,12 +1,21 @@
 pub mod api_keys;
+pub mod backups;
 pub mod bans;
 pub mod categories;
+pub mod command_permissions;
+pub mod config_history;
 pub mod configuration;
+pub mod data_requests;
+pub mod features;
 pub mod forbidden;
 pub mod home;
+pub mod jobs;
 pub mod language_switcher;
 pub mod logout_button;
+pub mod logs;
 pub mod navbar;
+pub mod retention;
+pub mod sessions;
 pub mod setup_detector;
 pub mod statistics;
 pub mod ticket;
@@ -33,10 +33,17 @@ pub fn rustmail_navbar(props: &RustmailNavbarProps) -> Html {
     };
 
     let home_active = current_path == "/panel";
-    let config_active = current_path == "/panel/configuration";
+    let config_active = current_path.starts_with("/panel/configuration");
     let apikeys_active = current_path == "/panel/apikeys";
     let categories_active = current_path == "/panel/categories";
     let bans_active = current_path == "/panel/bans";
+    let jobs_active = current_path == "/panel/jobs";
+    let logs_active = current_path == "/panel/logs";
+    let backups_active = current_path == "/panel/backups";
+    let gdpr_active = current_path == "/panel/gdpr";
+    let retention_active = current_path == "/panel/retention";
+    let sessions_active = current_path == "/panel/sessions";
+    let features_active = current_path == "/panel/features";
     let tickets_active = current_path.starts_with("/panel/tickets");
     let admin_active = current_path == "/admin";
 
@@ -51,6 +58,7 @@ pub fn rustmail_navbar(props: &RustmailNavbarProps) -> Html {
         .permissions
         .contains(&PanelPermission::ManageCategories);
     let has_view_bans = props.permissions.contains(&PanelPermission::ViewBans);
+    let has_manage_bot = props.permissions.contains(&PanelPermission::ManageBot);
 
     html! {
         <nav class="fixed top-0 left-0 w-full z-50 bg-gradient-to-r from-slate-900 to-black border-b border-slate-800">
@@ -218,6 +226,181 @@ pub fn rustmail_navbar(props: &RustmailNavbarProps) -> Html {
                                 html! {}
                             }}
 
+                            { if has_manage_bot {
+                                html! {
+                                    <button
+                                        onclick={{
+                                            let navigator = navigator.clone();
+                                            move |_| if let Some(nav) = &navigator {
+                                                nav.push(&PanelRoute::Jobs);
+                                            }
+                                        }}
+                                        class={classes!(
+                                            "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                            if jobs_active {
+                                                "bg-white/10 text-white"
+                                            } else {
+                                                "text-gray-300 hover:bg-white/10 hover:text-white"
+                                            }
+                                        )}
+                                    >
+                                        {i18n.t("navbar.jobs")}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+
+                            { if has_manage_bot {
+                                html! {
+                                    <button
+                                        onclick={{
+                                            let navigator = navigator.clone();
+                                            move |_| if let Some(nav) = &navigator {
+                                                nav.push(&PanelRoute::Features);
+                                            }
+                                        }}
+                                        class={classes!(
+                                            "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                            if features_active {
+                                                "bg-white/10 text-white"
+                                            } else {
+                                                "text-gray-300 hover:bg-white/10 hover:text-white"
+                                            }
+                                        )}
+                                    >
+                                        {i18n.t("navbar.features")}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+
+                            { if has_manage_bot {
+                                html! {
+                                    <button
+                                        onclick={{
+                                            let navigator = navigator.clone();
+                                            move |_| if let Some(nav) = &navigator {
+                                                nav.push(&PanelRoute::Logs);
+                                            }
+                                        }}
+                                        class={classes!(
+                                            "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                            if logs_active {
+                                                "bg-white/10 text-white"
+                                            } else {
+                                                "text-gray-300 hover:bg-white/10 hover:text-white"
+                                            }
+                                        )}
+                                    >
+                                        {i18n.t("navbar.logs")}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+
+                            { if has_manage_bot {
+                                html! {
+                                    <button
+                                        onclick={{
+                                            let navigator = navigator.clone();
+                                            move |_| if let Some(nav) = &navigator {
+                                                nav.push(&PanelRoute::Backups);
+                                            }
+                                        }}
+                                        class={classes!(
+                                            "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                            if backups_active {
+                                                "bg-white/10 text-white"
+                                            } else {
+                                                "text-gray-300 hover:bg-white/10 hover:text-white"
+                                            }
+                                        )}
+                                    >
+                                        {i18n.t("navbar.backups")}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+
+                            { if has_manage_bot {
+                                html! {
+                                    <button
+                                        onclick={{
+                                            let navigator = navigator.clone();
+                                            move |_| if let Some(nav) = &navigator {
+                                                nav.push(&PanelRoute::DataRequests);
+                                            }
+                                        }}
+                                        class={classes!(
+                                            "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                            if gdpr_active {
+                                                "bg-white/10 text-white"
+                                            } else {
+                                                "text-gray-300 hover:bg-white/10 hover:text-white"
+                                            }
+                                        )}
+                                    >
+                                        {i18n.t("navbar.gdpr")}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+
+                            { if has_manage_bot {
+                                html! {
+                                    <button
+                                        onclick={{
+                                            let navigator = navigator.clone();
+                                            move |_| if let Some(nav) = &navigator {
+                                                nav.push(&PanelRoute::Retention);
+                                            }
+                                        }}
+                                        class={classes!(
+                                            "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                            if retention_active {
+                                                "bg-white/10 text-white"
+                                            } else {
+                                                "text-gray-300 hover:bg-white/10 hover:text-white"
+                                            }
+                                        )}
+                                    >
+                                        {i18n.t("navbar.retention")}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+
+                            { if has_view_panel {
+                                html! {
+                                    <button
+                                        onclick={{
+                                            let navigator = navigator.clone();
+                                            move |_| if let Some(nav) = &navigator {
+                                                nav.push(&PanelRoute::Sessions);
+                                            }
+                                        }}
+                                        class={classes!(
+                                            "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                            if sessions_active {
+                                                "bg-white/10 text-white"
+                                            } else {
+                                                "text-gray-300 hover:bg-white/10 hover:text-white"
+                                            }
+                                        )}
+                                    >
+                                        {i18n.t("navbar.sessions")}
+                                    </button>
+                                }
+                            } else {
+                                html! {}
+                            }}
+
                             { if has_manage_permissions {
                                 html! {
                                     <a
@@ -453,6 +636,209 @@ pub fn rustmail_navbar(props: &RustmailNavbarProps) -> Html {
                         html! {}
                     }}
 
+                    { if has_manage_bot {
+                        html! {
+                            <button
+                                onclick={{
+                                    let navigator = navigator.clone();
+                                    let mobile_menu_open = mobile_menu_open.clone();
+                                    move |_| {
+                                        if let Some(nav) = &navigator {
+                                            nav.push(&PanelRoute::Jobs);
+                                        }
+                                        mobile_menu_open.set(false);
+                                    }
+                                }}
+                                class={classes!(
+                                    "block", "w-full", "text-left", "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                    if jobs_active {
+                                        "bg-white/10 text-white"
+                                    } else {
+                                        "text-gray-300 hover:bg-white/10 hover:text-white"
+                                    }
+                                )}
+                            >
+                                {i18n.t("navbar.jobs")}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    { if has_manage_bot {
+                        html! {
+                            <button
+                                onclick={{
+                                    let navigator = navigator.clone();
+                                    let mobile_menu_open = mobile_menu_open.clone();
+                                    move |_| {
+                                        if let Some(nav) = &navigator {
+                                            nav.push(&PanelRoute::Features);
+                                        }
+                                        mobile_menu_open.set(false);
+                                    }
+                                }}
+                                class={classes!(
+                                    "block", "w-full", "text-left", "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                    if features_active {
+                                        "bg-white/10 text-white"
+                                    } else {
+                                        "text-gray-300 hover:bg-white/10 hover:text-white"
+                                    }
+                                )}
+                            >
+                                {i18n.t("navbar.features")}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    { if has_manage_bot {
+                        html! {
+                            <button
+                                onclick={{
+                                    let navigator = navigator.clone();
+                                    let mobile_menu_open = mobile_menu_open.clone();
+                                    move |_| {
+                                        if let Some(nav) = &navigator {
+                                            nav.push(&PanelRoute::Logs);
+                                        }
+                                        mobile_menu_open.set(false);
+                                    }
+                                }}
+                                class={classes!(
+                                    "block", "w-full", "text-left", "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                    if logs_active {
+                                        "bg-white/10 text-white"
+                                    } else {
+                                        "text-gray-300 hover:bg-white/10 hover:text-white"
+                                    }
+                                )}
+                            >
+                                {i18n.t("navbar.logs")}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    { if has_manage_bot {
+                        html! {
+                            <button
+                                onclick={{
+                                    let navigator = navigator.clone();
+                                    let mobile_menu_open = mobile_menu_open.clone();
+                                    move |_| {
+                                        if let Some(nav) = &navigator {
+                                            nav.push(&PanelRoute::Backups);
+                                        }
+                                        mobile_menu_open.set(false);
+                                    }
+                                }}
+                                class={classes!(
+                                    "block", "w-full", "text-left", "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                    if backups_active {
+                                        "bg-white/10 text-white"
+                                    } else {
+                                        "text-gray-300 hover:bg-white/10 hover:text-white"
+                                    }
+                                )}
+                            >
+                                {i18n.t("navbar.backups")}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    { if has_manage_bot {
+                        html! {
+                            <button
+                                onclick={{
+                                    let navigator = navigator.clone();
+                                    let mobile_menu_open = mobile_menu_open.clone();
+                                    move |_| {
+                                        if let Some(nav) = &navigator {
+                                            nav.push(&PanelRoute::DataRequests);
+                                        }
+                                        mobile_menu_open.set(false);
+                                    }
+                                }}
+                                class={classes!(
+                                    "block", "w-full", "text-left", "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                    if gdpr_active {
+                                        "bg-white/10 text-white"
+                                    } else {
+                                        "text-gray-300 hover:bg-white/10 hover:text-white"
+                                    }
+                                )}
+                            >
+                                {i18n.t("navbar.gdpr")}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    { if has_manage_bot {
+                        html! {
+                            <button
+                                onclick={{
+                                    let navigator = navigator.clone();
+                                    let mobile_menu_open = mobile_menu_open.clone();
+                                    move |_| {
+                                        if let Some(nav) = &navigator {
+                                            nav.push(&PanelRoute::Retention);
+                                        }
+                                        mobile_menu_open.set(false);
+                                    }
+                                }}
+                                class={classes!(
+                                    "block", "w-full", "text-left", "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                    if retention_active {
+                                        "bg-white/10 text-white"
+                                    } else {
+                                        "text-gray-300 hover:bg-white/10 hover:text-white"
+                                    }
+                                )}
+                            >
+                                {i18n.t("navbar.retention")}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }}
+
+                    { if has_view_panel {
+                        html! {
+                            <button
+                                onclick={{
+                                    let navigator = navigator.clone();
+                                    let mobile_menu_open = mobile_menu_open.clone();
+                                    move |_| {
+                                        if let Some(nav) = &navigator {
+                                            nav.push(&PanelRoute::Sessions);
+                                        }
+                                        mobile_menu_open.set(false);
+                                    }
+                                }}
+                                class={classes!(
+                                    "block", "w-full", "text-left", "rounded-md", "px-3", "py-2", "text-sm", "transition",
+                                    if sessions_active {
+                                        "bg-white/10 text-white"
+                                    } else {
+                                        "text-gray-300 hover:bg-white/10 hover:text-white"
+                                    }
+                                )}
+                            >
+                                {i18n.t("navbar.sessions")}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }}
+
                     { if has_manage_permissions {
                         html! {
                             <a
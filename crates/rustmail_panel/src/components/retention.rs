@@ -0,0 +1,644 @@
+use crate::components::categories::CategoryDto;
+use crate::components::forbidden::Forbidden403;
+use crate::i18n::yew::use_translation;
+use crate::types::PanelPermission;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+const TARGETS: [&str; 4] = [
+    "message_content",
+    "closed_tickets",
+    "tracked_members",
+    "expired_api_keys",
+];
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RetentionRule {
+    pub id: i64,
+    pub target: String,
+    pub older_than_days: i64,
+    pub ticket_category_id: Option<String>,
+    pub enabled: bool,
+    pub last_run_at: Option<i64>,
+    pub last_affected: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct RetentionRuleRequest {
+    target: String,
+    older_than_days: i64,
+    ticket_category_id: Option<String>,
+    enabled: bool,
+}
+
+impl RetentionRuleRequest {
+    fn from_rule(rule: &RetentionRule) -> Self {
+        Self {
+            target: rule.target.clone(),
+            older_than_days: rule.older_than_days,
+            ticket_category_id: rule.ticket_category_id.clone(),
+            enabled: rule.enabled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct RetentionPreview {
+    cutoff: i64,
+    counts: BTreeMap<String, u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct RetentionRunResponse {
+    counts: BTreeMap<String, u64>,
+}
+
+fn format_ts(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M:%S").to_string())
+        .unwrap_or_default()
+}
+
+fn is_ticket_target(target: &str) -> bool {
+    matches!(target, "message_content" | "closed_tickets")
+}
+
+async fn response_error(resp: gloo_net::http::Response) -> String {
+    let status = resp.status();
+    let text = resp.text().await.unwrap_or_default();
+    if text.is_empty() {
+        format!("HTTP {}", status)
+    } else {
+        text
+    }
+}
+
+#[function_component(RetentionPage)]
+pub fn retention_page() -> Html {
+    let (i18n, _set_language) = use_translation();
+
+    let permissions = use_state(|| None::<Vec<PanelPermission>>);
+    {
+        let permissions = permissions.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(resp) = Request::get("/api/user/permissions").send().await
+                    && let Ok(perms) = resp.json::<Vec<PanelPermission>>().await
+                {
+                    permissions.set(Some(perms));
+                }
+            });
+            || ()
+        });
+    }
+
+    if let Some(perms) = (*permissions).as_ref() {
+        if !perms.contains(&PanelPermission::ManageBot) {
+            return html! {
+                <Forbidden403 required_permission={i18n.t("navbar.retention")} />
+            };
+        }
+    } else {
+        return html! {
+            <div class="flex items-center justify-center min-h-[70vh]">
+                <div class="text-gray-400 animate-pulse">{i18n.t("panel.forbidden.checking_permissions")}</div>
+            </div>
+        };
+    }
+
+    let rules = use_state(|| None::<Vec<RetentionRule>>);
+    let categories = use_state(Vec::<CategoryDto>::new);
+    let editing = use_state(|| None::<i64>);
+    let form = use_state(|| RetentionRuleRequest {
+        target: "message_content".to_string(),
+        older_than_days: 365,
+        ticket_category_id: None,
+        enabled: false,
+    });
+    let preview = use_state(|| None::<RetentionPreview>);
+    let busy = use_state(|| false);
+    let message = use_state(|| None::<(bool, String)>);
+
+    let reload = {
+        let rules = rules.clone();
+        let message = message.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let rules = rules.clone();
+            let message = message.clone();
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                match Request::get("/api/admin/retention/rules").send().await {
+                    Ok(resp) if resp.ok() => match resp.json::<Vec<RetentionRule>>().await {
+                        Ok(list) => rules.set(Some(list)),
+                        Err(e) => message.set(Some((
+                            false,
+                            format!("{}: {}", i18n.t("panel.retention.error_load"), e),
+                        ))),
+                    },
+                    Ok(resp) => message.set(Some((
+                        false,
+                        format!(
+                            "{}: {}",
+                            i18n.t("panel.retention.error_load"),
+                            response_error(resp).await
+                        ),
+                    ))),
+                    Err(e) => message.set(Some((
+                        false,
+                        format!("{}: {}", i18n.t("panel.retention.error_load"), e),
+                    ))),
+                }
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        let categories = categories.clone();
+        use_effect_with((), move |_| {
+            reload.emit(());
+            spawn_local(async move {
+                if let Ok(resp) = Request::get("/api/categories").send().await
+                    && let Ok(list) = resp.json::<Vec<CategoryDto>>().await
+                {
+                    categories.set(list);
+                }
+            });
+            || ()
+        });
+    }
+
+    let run_preview = {
+        let preview = preview.clone();
+        let message = message.clone();
+        Callback::from(move |request: RetentionRuleRequest| {
+            let preview = preview.clone();
+            let message = message.clone();
+            spawn_local(async move {
+                match Request::post("/api/admin/retention/preview").json(&request) {
+                    Ok(req) => match req.send().await {
+                        Ok(resp) if resp.ok() => match resp.json::<RetentionPreview>().await {
+                            Ok(result) => {
+                                preview.set(Some(result));
+                                message.set(None);
+                            }
+                            Err(e) => message.set(Some((false, e.to_string()))),
+                        },
+                        Ok(resp) => {
+                            preview.set(None);
+                            message.set(Some((false, response_error(resp).await)));
+                        }
+                        Err(e) => message.set(Some((false, e.to_string()))),
+                    },
+                    Err(e) => message.set(Some((false, format!("{:?}", e)))),
+                }
+            });
+        })
+    };
+
+    let save_rule = {
+        let busy = busy.clone();
+        let message = message.clone();
+        let reload = reload.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |(id, request): (Option<i64>, RetentionRuleRequest)| {
+            let busy = busy.clone();
+            let message = message.clone();
+            let reload = reload.clone();
+            let i18n = i18n.clone();
+            busy.set(true);
+            spawn_local(async move {
+                let builder = match id {
+                    Some(id) => Request::put(&format!("/api/admin/retention/rules/{}", id)),
+                    None => Request::post("/api/admin/retention/rules"),
+                };
+                match builder.json(&request) {
+                    Ok(req) => match req.send().await {
+                        Ok(resp) if resp.ok() => {
+                            message.set(Some((true, i18n.t("panel.retention.saved"))))
+                        }
+                        Ok(resp) => message.set(Some((false, response_error(resp).await))),
+                        Err(e) => message.set(Some((false, e.to_string()))),
+                    },
+                    Err(e) => message.set(Some((false, format!("{:?}", e)))),
+                }
+                busy.set(false);
+                reload.emit(());
+            });
+        })
+    };
+
+    let on_target_change = {
+        let form = form.clone();
+        let preview = preview.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*form).clone();
+            next.target = select.value();
+            if !is_ticket_target(&next.target) {
+                next.ticket_category_id = None;
+            }
+            form.set(next);
+            preview.set(None);
+        })
+    };
+
+    let on_days_input = {
+        let form = form.clone();
+        let preview = preview.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*form).clone();
+            next.older_than_days = input.value().parse().unwrap_or(0);
+            form.set(next);
+            preview.set(None);
+        })
+    };
+
+    let on_category_change = {
+        let form = form.clone();
+        let preview = preview.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let mut next = (*form).clone();
+            next.ticket_category_id = Some(select.value()).filter(|id| !id.is_empty());
+            form.set(next);
+            preview.set(None);
+        })
+    };
+
+    let on_enabled_change = {
+        let form = form.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let mut next = (*form).clone();
+            next.enabled = input.checked();
+            form.set(next);
+        })
+    };
+
+    let on_preview = {
+        let form = form.clone();
+        let run_preview = run_preview.clone();
+        Callback::from(move |_: MouseEvent| run_preview.emit((*form).clone()))
+    };
+
+    let on_save = {
+        let form = form.clone();
+        let editing = editing.clone();
+        let save_rule = save_rule.clone();
+        Callback::from(move |_: MouseEvent| save_rule.emit((*editing, (*form).clone())))
+    };
+
+    let on_cancel_edit = {
+        let editing = editing.clone();
+        let preview = preview.clone();
+        Callback::from(move |_: MouseEvent| {
+            editing.set(None);
+            preview.set(None);
+        })
+    };
+
+    let target_label = {
+        let i18n = i18n.clone();
+        move |target: &str| i18n.t(&format!("panel.retention.target_{}", target))
+    };
+
+    let category_name = {
+        let categories = (*categories).clone();
+        let i18n = i18n.clone();
+        move |id: Option<&str>| match id {
+            None => i18n.t("panel.retention.all_categories"),
+            Some(id) => categories
+                .iter()
+                .find(|c| c.id == id)
+                .map(|c| c.name.clone())
+                .unwrap_or_else(|| id.to_string()),
+        }
+    };
+
+    let preview_panel = match (*preview).as_ref() {
+        None => html! {},
+        Some(result) => html! {
+            <div class="bg-slate-900 border border-slate-700 rounded-md p-4 space-y-2">
+                <h3 class="text-sm font-semibold text-gray-200">
+                    {i18n.t("panel.retention.preview_title").replace("{cutoff}", &format_ts(result.cutoff))}
+                </h3>
+                {
+                    if result.counts.is_empty() {
+                        html! { <p class="text-gray-400 text-sm">{i18n.t("panel.retention.preview_empty")}</p> }
+                    } else {
+                        html! {
+                            <ul class="text-sm text-gray-300 space-y-1">
+                            {
+                                result.counts.iter().map(|(table, count)| html! {
+                                    <li key={table.clone()}>
+                                        <span class="font-mono text-xs">{table}</span>{": "}{count}
+                                    </li>
+                                }).collect::<Html>()
+                            }
+                            </ul>
+                        }
+                    }
+                }
+            </div>
+        },
+    };
+
+    let form_view = html! {
+        <div class="bg-slate-800 rounded-lg border border-slate-700 p-6 space-y-4">
+            <h2 class="text-xl font-semibold text-white">
+                {
+                    if editing.is_some() {
+                        i18n.t("panel.retention.edit_rule")
+                    } else {
+                        i18n.t("panel.retention.new_rule")
+                    }
+                }
+            </h2>
+            <div class="flex flex-wrap items-end gap-4">
+                <label class="flex flex-col gap-1 text-sm text-gray-400">
+                    {i18n.t("panel.retention.field_target")}
+                    <select
+                        onchange={on_target_change}
+                        class="bg-slate-900 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2"
+                    >
+                    {
+                        TARGETS.iter().map(|target| html! {
+                            <option value={*target} selected={form.target == *target}>{target_label(target)}</option>
+                        }).collect::<Html>()
+                    }
+                    </select>
+                </label>
+                <label class="flex flex-col gap-1 text-sm text-gray-400">
+                    {i18n.t("panel.retention.field_days")}
+                    <input
+                        type="number"
+                        min="1"
+                        value={form.older_than_days.to_string()}
+                        oninput={on_days_input}
+                        class="bg-slate-900 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2 w-32"
+                    />
+                </label>
+                {
+                    if is_ticket_target(&form.target) {
+                        html! {
+                            <label class="flex flex-col gap-1 text-sm text-gray-400">
+                                {i18n.t("panel.retention.field_category")}
+                                <select
+                                    onchange={on_category_change}
+                                    class="bg-slate-900 border border-slate-700 text-gray-200 text-sm rounded-md px-3 py-2"
+                                >
+                                    <option value="" selected={form.ticket_category_id.is_none()}>
+                                        {i18n.t("panel.retention.all_categories")}
+                                    </option>
+                                    {
+                                        categories.iter().map(|category| html! {
+                                            <option
+                                                value={category.id.clone()}
+                                                selected={form.ticket_category_id.as_deref() == Some(category.id.as_str())}
+                                            >
+                                                {&category.name}
+                                            </option>
+                                        }).collect::<Html>()
+                                    }
+                                </select>
+                            </label>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+                <label class="flex items-center gap-2 text-sm text-gray-300 py-2">
+                    <input type="checkbox" checked={form.enabled} onchange={on_enabled_change} />
+                    {i18n.t("panel.retention.field_enabled")}
+                </label>
+            </div>
+            <p class="text-gray-400 text-xs">{i18n.t(&format!("panel.retention.hint_{}", form.target))}</p>
+            {preview_panel}
+            <div class="flex gap-2">
+                <button
+                    onclick={on_preview}
+                    class="px-4 py-2 bg-slate-700 hover:bg-slate-600 text-gray-200 rounded-md text-sm transition"
+                >
+                    {i18n.t("panel.retention.dry_run")}
+                </button>
+                <button
+                    onclick={on_save}
+                    disabled={*busy || form.older_than_days < 1}
+                    class="px-4 py-2 bg-blue-600 hover:bg-blue-700 text-white rounded-md text-sm transition disabled:opacity-50"
+                >
+                    {i18n.t("panel.retention.save")}
+                </button>
+                {
+                    if editing.is_some() {
+                        html! {
+                            <button
+                                onclick={on_cancel_edit}
+                                class="px-4 py-2 text-gray-300 hover:text-white text-sm transition"
+                            >
+                                {i18n.t("panel.retention.cancel")}
+                            </button>
+                        }
+                    } else {
+                        html! {}
+                    }
+                }
+            </div>
+        </div>
+    };
+
+    let rules_table = match (*rules).as_ref() {
+        None => html! {
+            <p class="text-gray-400 animate-pulse">{i18n.t("panel.retention.loading")}</p>
+        },
+        Some(list) if list.is_empty() => html! {
+            <div class="bg-slate-800 rounded-lg p-8 text-center border border-slate-700">
+                <p class="text-gray-400">{i18n.t("panel.retention.no_rules")}</p>
+            </div>
+        },
+        Some(list) => html! {
+            <div class="bg-slate-800 rounded-lg border border-slate-700 overflow-x-auto">
+                <table class="min-w-full text-sm text-left">
+                    <thead class="text-gray-400 border-b border-slate-700">
+                        <tr>
+                            <th class="px-4 py-3">{i18n.t("panel.retention.column_target")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.retention.column_days")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.retention.column_category")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.retention.column_enabled")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.retention.column_last_run")}</th>
+                            <th class="px-4 py-3"></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                    {
+                        list.iter().map(|rule| {
+                            let on_toggle = {
+                                let save_rule = save_rule.clone();
+                                let rule = rule.clone();
+                                Callback::from(move |_: Event| {
+                                    let mut request = RetentionRuleRequest::from_rule(&rule);
+                                    request.enabled = !rule.enabled;
+                                    save_rule.emit((Some(rule.id), request));
+                                })
+                            };
+                            let on_edit = {
+                                let form = form.clone();
+                                let editing = editing.clone();
+                                let run_preview = run_preview.clone();
+                                let rule = rule.clone();
+                                Callback::from(move |_: MouseEvent| {
+                                    let request = RetentionRuleRequest::from_rule(&rule);
+                                    form.set(request.clone());
+                                    editing.set(Some(rule.id));
+                                    run_preview.emit(request);
+                                })
+                            };
+                            let on_run = {
+                                let busy = busy.clone();
+                                let message = message.clone();
+                                let reload = reload.clone();
+                                let i18n = i18n.clone();
+                                let id = rule.id;
+                                Callback::from(move |_: MouseEvent| {
+                                    let confirmed = web_sys::window()
+                                        .and_then(|w| w.confirm_with_message(&i18n.t("panel.retention.run_confirm")).ok())
+                                        .unwrap_or(false);
+                                    if !confirmed {
+                                        return;
+                                    }
+                                    let busy = busy.clone();
+                                    let message = message.clone();
+                                    let reload = reload.clone();
+                                    let i18n = i18n.clone();
+                                    busy.set(true);
+                                    spawn_local(async move {
+                                        let url = format!("/api/admin/retention/rules/{}/run", id);
+                                        match Request::post(&url).send().await {
+                                            Ok(resp) if resp.ok() => match resp.json::<RetentionRunResponse>().await {
+                                                Ok(result) => message.set(Some((
+                                                    true,
+                                                    i18n.t("panel.retention.ran").replace(
+                                                        "{rows}",
+                                                        &result.counts.values().sum::<u64>().to_string(),
+                                                    ),
+                                                ))),
+                                                Err(e) => message.set(Some((false, e.to_string()))),
+                                            },
+                                            Ok(resp) => message.set(Some((false, response_error(resp).await))),
+                                            Err(e) => message.set(Some((false, e.to_string()))),
+                                        }
+                                        busy.set(false);
+                                        reload.emit(());
+                                    });
+                                })
+                            };
+                            let on_delete = {
+                                let message = message.clone();
+                                let reload = reload.clone();
+                                let editing = editing.clone();
+                                let i18n = i18n.clone();
+                                let id = rule.id;
+                                Callback::from(move |_: MouseEvent| {
+                                    let confirmed = web_sys::window()
+                                        .and_then(|w| w.confirm_with_message(&i18n.t("panel.retention.delete_confirm")).ok())
+                                        .unwrap_or(false);
+                                    if !confirmed {
+                                        return;
+                                    }
+                                    if *editing == Some(id) {
+                                        editing.set(None);
+                                    }
+                                    let message = message.clone();
+                                    let reload = reload.clone();
+                                    spawn_local(async move {
+                                        let url = format!("/api/admin/retention/rules/{}", id);
+                                        match Request::delete(&url).send().await {
+                                            Ok(resp) if resp.ok() => {}
+                                            Ok(resp) => message.set(Some((false, response_error(resp).await))),
+                                            Err(e) => message.set(Some((false, e.to_string()))),
+                                        }
+                                        reload.emit(());
+                                    });
+                                })
+                            };
+                            let last_run = match (rule.last_run_at, rule.last_affected) {
+                                (Some(at), Some(rows)) => i18n
+                                    .t("panel.retention.last_run")
+                                    .replace("{date}", &format_ts(at))
+                                    .replace("{rows}", &rows.to_string()),
+                                _ => i18n.t("panel.retention.never_run"),
+                            };
+                            html! {
+                                <tr key={rule.id} class="border-b border-slate-700/50 text-gray-300">
+                                    <td class="px-4 py-3">{target_label(&rule.target)}</td>
+                                    <td class="px-4 py-3">{rule.older_than_days}</td>
+                                    <td class="px-4 py-3">
+                                        {
+                                            if is_ticket_target(&rule.target) {
+                                                category_name(rule.ticket_category_id.as_deref())
+                                            } else {
+                                                "-".to_string()
+                                            }
+                                        }
+                                    </td>
+                                    <td class="px-4 py-3">
+                                        <input type="checkbox" checked={rule.enabled} disabled={*busy} onchange={on_toggle} />
+                                    </td>
+                                    <td class="px-4 py-3 text-xs">{last_run}</td>
+                                    <td class="px-4 py-3 text-right whitespace-nowrap space-x-2">
+                                        <button onclick={on_edit} class="text-blue-400 hover:text-blue-300 text-sm">
+                                            {i18n.t("panel.retention.edit")}
+                                        </button>
+                                        <button onclick={on_run} disabled={*busy} class="text-yellow-400 hover:text-yellow-300 text-sm disabled:opacity-50">
+                                            {i18n.t("panel.retention.run_now")}
+                                        </button>
+                                        <button onclick={on_delete} class="text-red-400 hover:text-red-300 text-sm">
+                                            {i18n.t("panel.retention.delete")}
+                                        </button>
+                                    </td>
+                                </tr>
+                            }
+                        }).collect::<Html>()
+                    }
+                    </tbody>
+                </table>
+            </div>
+        },
+    };
+
+    html! {
+        <div class="space-y-6">
+            <div>
+                <h1 class="text-3xl font-bold text-white">{i18n.t("panel.retention.title")}</h1>
+                <p class="text-gray-400 text-sm mt-1">{i18n.t("panel.retention.description")}</p>
+            </div>
+
+            {
+                match (*message).clone() {
+                    Some((true, text)) => html! {
+                        <div class="bg-green-900/20 border border-green-500 text-green-200 p-4 rounded-md">{text}</div>
+                    },
+                    Some((false, text)) => html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-4 rounded-md">{text}</div>
+                    },
+                    None => html! {},
+                }
+            }
+
+            {form_view}
+
+            <div class="space-y-3">
+                <h2 class="text-xl font-semibold text-white">{i18n.t("panel.retention.rules")}</h2>
+                {rules_table}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,299 @@
+use crate::components::forbidden::Forbidden403;
+use crate::i18n::yew::use_translation;
+use crate::types::PanelPermission;
+use gloo_net::http::Request;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_futures::spawn_local;
+use yew::prelude::*;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PanelSession {
+    pub id: String,
+    pub user_id: String,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub last_seen_at: Option<i64>,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+    #[serde(default)]
+    pub current: bool,
+}
+
+fn format_ts(ts: i64) -> String {
+    chrono::DateTime::from_timestamp(ts, 0)
+        .map(|dt| dt.format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_default()
+}
+
+async fn fetch_sessions(url: &str) -> Result<Vec<PanelSession>, String> {
+    let resp = Request::get(url).send().await.map_err(|e| e.to_string())?;
+    if resp.status() != 200 {
+        return Err(resp.status().to_string());
+    }
+    resp.json::<Vec<PanelSession>>()
+        .await
+        .map_err(|e| e.to_string())
+}
+
+#[function_component(SessionsPage)]
+pub fn sessions_page() -> Html {
+    let (i18n, _set_language) = use_translation();
+
+    let permissions = use_state(|| None::<Vec<PanelPermission>>);
+    {
+        let permissions = permissions.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(resp) = Request::get("/api/user/permissions").send().await
+                    && let Ok(perms) = resp.json::<Vec<PanelPermission>>().await
+                {
+                    permissions.set(Some(perms));
+                }
+            });
+            || ()
+        });
+    }
+
+    let can_manage = if let Some(perms) = (*permissions).as_ref() {
+        if !perms.contains(&PanelPermission::ViewPanel) {
+            return html! {
+                <Forbidden403 required_permission={i18n.t("navbar.sessions")} />
+            };
+        }
+        perms.contains(&PanelPermission::ManagePermissions)
+    } else {
+        return html! {
+            <div class="flex items-center justify-center min-h-[70vh]">
+                <div class="text-gray-400 animate-pulse">{i18n.t("panel.forbidden.checking_permissions")}</div>
+            </div>
+        };
+    };
+
+    let own_sessions = use_state(|| None::<Vec<PanelSession>>);
+    let all_sessions = use_state(|| None::<Vec<PanelSession>>);
+    let error = use_state(|| None::<String>);
+
+    let reload = {
+        let own_sessions = own_sessions.clone();
+        let all_sessions = all_sessions.clone();
+        let error = error.clone();
+        let i18n = i18n.clone();
+        Callback::from(move |_| {
+            let own_sessions = own_sessions.clone();
+            let all_sessions = all_sessions.clone();
+            let error = error.clone();
+            let i18n = i18n.clone();
+            spawn_local(async move {
+                match fetch_sessions("/api/user/sessions").await {
+                    Ok(sessions) => {
+                        own_sessions.set(Some(sessions));
+                        error.set(None);
+                    }
+                    Err(e) => {
+                        error.set(Some(format!(
+                            "{}: {}",
+                            i18n.t("panel.sessions.error_load"),
+                            e
+                        )));
+                    }
+                }
+                if can_manage && let Ok(sessions) = fetch_sessions("/api/admin/sessions").await {
+                    all_sessions.set(Some(sessions));
+                }
+            });
+        })
+    };
+
+    {
+        let reload = reload.clone();
+        use_effect_with((), move |_| {
+            reload.emit(());
+            || ()
+        });
+    }
+
+    let on_revoke = {
+        let reload = reload.clone();
+        Callback::from(move |(url, current): (String, bool)| {
+            let reload = reload.clone();
+            spawn_local(async move {
+                let Ok(resp) = Request::delete(&url).send().await else {
+                    return;
+                };
+                if resp.status() != 204 {
+                    return;
+                }
+                if !current {
+                    reload.emit(());
+                } else if let Some(window) = web_sys::window() {
+                    let _ = window.location().set_href("/");
+                }
+            });
+        })
+    };
+
+    let render_table = |sessions: &Vec<PanelSession>, admin: bool| -> Html {
+        if sessions.is_empty() {
+            return html! {
+                <div class="bg-slate-800 rounded-lg p-8 text-center border border-slate-700">
+                    <p class="text-gray-400">{i18n.t("panel.sessions.no_sessions")}</p>
+                </div>
+            };
+        }
+
+        html! {
+            <div class="bg-slate-800 rounded-lg border border-slate-700 overflow-x-auto">
+                <table class="min-w-full text-sm text-left">
+                    <thead class="text-gray-400 border-b border-slate-700">
+                        <tr>
+                            {
+                                if admin {
+                                    html! { <th class="px-4 py-3">{i18n.t("panel.sessions.column_user")}</th> }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                            <th class="px-4 py-3">{i18n.t("panel.sessions.column_device")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.sessions.column_ip")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.sessions.column_created")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.sessions.column_last_seen")}</th>
+                            <th class="px-4 py-3">{i18n.t("panel.sessions.column_expires")}</th>
+                            <th class="px-4 py-3"></th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                    {
+                        sessions.iter().map(|session| {
+                            let revoke_url = if admin {
+                                format!("/api/admin/sessions/{}", session.id)
+                            } else {
+                                format!("/api/user/sessions/{}", session.id)
+                            };
+                            let current = session.current;
+                            let on_revoke_one = {
+                                let on_revoke = on_revoke.clone();
+                                Callback::from(move |_| on_revoke.emit((revoke_url.clone(), current)))
+                            };
+                            let on_revoke_user = {
+                                let on_revoke = on_revoke.clone();
+                                let url = format!("/api/admin/sessions/user/{}", session.user_id);
+                                Callback::from(move |_| on_revoke.emit((url.clone(), false)))
+                            };
+                            html! {
+                                <tr key={session.id.clone()} class="border-b border-slate-700/50 text-gray-300">
+                                    {
+                                        if admin {
+                                            html! { <td class="px-4 py-3 font-mono text-xs">{&session.user_id}</td> }
+                                        } else {
+                                            html! {}
+                                        }
+                                    }
+                                    <td class="px-4 py-3 text-xs max-w-xs truncate" title={session.user_agent.clone().unwrap_or_default()}>
+                                        {session.user_agent.clone().unwrap_or_else(|| i18n.t("panel.sessions.unknown"))}
+                                        {
+                                            if current {
+                                                html! {
+                                                    <span class="ml-2 px-2 py-0.5 bg-green-900/30 border border-green-500 text-green-200 rounded-full text-xs">
+                                                        {i18n.t("panel.sessions.current")}
+                                                    </span>
+                                                }
+                                            } else {
+                                                html! {}
+                                            }
+                                        }
+                                    </td>
+                                    <td class="px-4 py-3 font-mono text-xs">{session.ip.clone().unwrap_or_default()}</td>
+                                    <td class="px-4 py-3">{format_ts(session.created_at)}</td>
+                                    <td class="px-4 py-3">{session.last_seen_at.map(format_ts).unwrap_or_default()}</td>
+                                    <td class="px-4 py-3">{format_ts(session.expires_at)}</td>
+                                    <td class="px-4 py-3 text-right whitespace-nowrap space-x-2">
+                                        <button
+                                            onclick={on_revoke_one}
+                                            class="px-3 py-1 bg-red-900/30 border border-red-500 text-red-200 hover:bg-red-900/50 rounded-md text-xs transition"
+                                        >
+                                            {i18n.t("panel.sessions.revoke")}
+                                        </button>
+                                        {
+                                            if admin {
+                                                html! {
+                                                    <button
+                                                        onclick={on_revoke_user}
+                                                        class="px-3 py-1 bg-slate-700 hover:bg-slate-600 text-gray-200 rounded-md text-xs transition"
+                                                    >
+                                                        {i18n.t("panel.sessions.revoke_all_user")}
+                                                    </button>
+                                                }
+                                            } else {
+                                                html! {}
+                                            }
+                                        }
+                                    </td>
+                                </tr>
+                            }
+                        }).collect::<Html>()
+                    }
+                    </tbody>
+                </table>
+            </div>
+        }
+    };
+
+    html! {
+        <div class="space-y-6">
+            <div class="flex justify-between items-center">
+                <h1 class="text-3xl font-bold text-white">{i18n.t("panel.sessions.title")}</h1>
+                <button
+                    onclick={reload.reform(|_| ())}
+                    class="p-2 bg-slate-800 hover:bg-slate-700 text-gray-300 rounded-full transition"
+                    title={i18n.t("panel.sessions.reload")}
+                >
+                    <svg class="h-5 w-5" fill="none" stroke="currentColor" stroke-width="2" viewBox="0 0 24 24">
+                        <path stroke-linecap="round" stroke-linejoin="round" d="M4 4v5h.582m15.356 2A8.001 8.001 0 004.582 9m0 0H9m11 11v-5h-.581m0 0a8.003 8.003 0 01-15.357-2m15.357 2H15" />
+                    </svg>
+                </button>
+            </div>
+
+            {
+                if let Some(err) = (*error).clone() {
+                    html! {
+                        <div class="bg-red-900/20 border border-red-500 text-red-200 p-4 rounded-md">{err}</div>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+
+            <section class="space-y-3">
+                <h2 class="text-xl font-semibold text-white">{i18n.t("panel.sessions.my_sessions")}</h2>
+                {
+                    match (*own_sessions).as_ref() {
+                        Some(sessions) => render_table(sessions, false),
+                        None => html! {
+                            <p class="text-gray-400 animate-pulse">{i18n.t("panel.sessions.loading")}</p>
+                        },
+                    }
+                }
+            </section>
+
+            {
+                if can_manage {
+                    html! {
+                        <section class="space-y-3">
+                            <h2 class="text-xl font-semibold text-white">{i18n.t("panel.sessions.all_sessions")}</h2>
+                            {
+                                match (*all_sessions).as_ref() {
+                                    Some(sessions) => render_table(sessions, true),
+                                    None => html! {
+                                        <p class="text-gray-400 animate-pulse">{i18n.t("panel.sessions.loading")}</p>
+                                    },
+                                }
+                            }
+                        </section>
+                    }
+                } else {
+                    html! {}
+                }
+            }
+        </div>
+    }
+}
@@ -48,6 +48,34 @@ enum MessageType {
     Internal,
 }
 
+fn default_priority() -> String {
+    "normal".to_string()
+}
+
+fn priority_badge_class(priority: &str) -> Option<&'static str> {
+    match priority {
+        "urgent" => Some("bg-red-900/40 text-red-300 border-red-700"),
+        "high" => Some("bg-orange-900/40 text-orange-300 border-orange-700"),
+        "low" => Some("bg-slate-700/40 text-gray-400 border-slate-600"),
+        _ => None,
+    }
+}
+
+fn default_workflow_state() -> String {
+    "open".to_string()
+}
+
+fn workflow_badge_class(state: &str) -> Option<&'static str> {
+    match state {
+        "awaiting_staff" => Some("bg-red-900/40 text-red-300 border-red-700"),
+        "awaiting_user" => Some("bg-blue-900/40 text-blue-300 border-blue-700"),
+        "on_hold" => Some("bg-yellow-900/40 text-yellow-300 border-yellow-700"),
+        "resolved" => Some("bg-green-900/40 text-green-300 border-green-700"),
+        "open" => Some("bg-slate-700/40 text-gray-300 border-slate-600"),
+        _ => None,
+    }
+}
+
 #[derive(Clone, PartialEq, Deserialize, Debug)]
 pub struct CompleteThread {
     pub id: String,
@@ -63,7 +91,31 @@ pub struct CompleteThread {
     pub category_id: Option<String>,
     pub category_name: Option<String>,
     pub required_permissions: Option<String>,
+    #[serde(default = "default_priority")]
+    pub priority: String,
+    #[serde(default = "default_workflow_state")]
+    pub workflow_state: String,
+    #[serde(default)]
+    pub state_until: Option<i64>,
     pub messages: Vec<ThreadMessage>,
+    #[serde(default)]
+    pub form_answers: Vec<FormAnswer>,
+    #[serde(default)]
+    pub state_history: Vec<StateChange>,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Debug)]
+pub struct StateChange {
+    pub from_state: Option<String>,
+    pub to_state: String,
+    pub changed_by: Option<String>,
+    pub created_at: i64,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Debug)]
+pub struct FormAnswer {
+    pub question: String,
+    pub answer: String,
 }
 
 #[derive(Clone, PartialEq, Deserialize, Debug)]
@@ -125,6 +177,8 @@ pub fn tickets_list() -> Html {
     let tickets = use_state(|| Vec::<CompleteThread>::new());
     let loading = use_state(|| true);
     let selected_category = use_state(|| "all".to_string());
+    let selected_priority = use_state(|| "all".to_string());
+    let sort_by = use_state(|| "created_at".to_string());
     let search_query = use_state(|| String::new());
     let navigator = use_navigator().unwrap();
     let location = use_location().unwrap();
@@ -227,10 +281,18 @@ pub fn tickets_list() -> Html {
         let total_pages = total_pages.clone();
         let total_tickets = total_tickets.clone();
         let selected_category = selected_category.clone();
+        let selected_priority = selected_priority.clone();
+        let sort_by = sort_by.clone();
         let update_url = update_url.clone();
 
         use_effect_with(
-            (*current_page, *page_size, (*selected_category).clone()),
+            (
+                *current_page,
+                *page_size,
+                (*selected_category).clone(),
+                (*selected_priority).clone(),
+                (*sort_by).clone(),
+            ),
             move |_| {
                 let tickets_clone = tickets.clone();
                 let loading_clone = loading.clone();
@@ -239,6 +301,8 @@ pub fn tickets_list() -> Html {
                 let page = *current_page;
                 let size = *page_size;
                 let category = (*selected_category).clone();
+                let priority = (*selected_priority).clone();
+                let sort = (*sort_by).clone();
 
                 update_url.emit(());
 
@@ -249,6 +313,10 @@ pub fn tickets_list() -> Html {
                     if category != "all" {
                         url.push_str(&format!("&category_id={}", urlencoding::encode(&category)));
                     }
+                    if priority != "all" {
+                        url.push_str(&format!("&priority={}", priority));
+                    }
+                    url.push_str(&format!("&sort_by={}", sort));
 
                     if let Ok(resp) = Request::get(&url).send().await {
                         if let Ok(data) = resp.json::<PaginatedThreadsResponse>().await {
@@ -351,6 +419,55 @@ pub fn tickets_list() -> Html {
                         </select>
                     </div>
 
+                    <div class="grid grid-cols-1 md:grid-cols-2 gap-4">
+                        <div>
+                            <label class="block text-sm text-gray-300 mb-2">
+                                <i class="bi bi-flag mr-2"></i>
+                                {i18n.t("panel.tickets.priority.filter")}
+                            </label>
+                            <select
+                                value={(*selected_priority).clone()}
+                                onchange={{
+                                    let selected_priority = selected_priority.clone();
+                                    let current_page = current_page.clone();
+                                    move |e: Event| {
+                                        let value = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+                                        selected_priority.set(value);
+                                        current_page.set(1);
+                                    }
+                                }}
+                                class="w-full px-3 py-2 bg-slate-900/50 border border-slate-600 rounded-md text-white focus:outline-none focus:ring-2 focus:ring-blue-500"
+                            >
+                                <option value="all">{i18n.t("panel.tickets.priority.all")}</option>
+                                { for ["urgent", "high", "normal", "low"].iter().map(|p| html! {
+                                    <option value={*p}>{i18n.t(&format!("panel.tickets.priority.{}", p))}</option>
+                                }) }
+                            </select>
+                        </div>
+                        <div>
+                            <label class="block text-sm text-gray-300 mb-2">
+                                <i class="bi bi-sort-down mr-2"></i>
+                                {i18n.t("panel.tickets.sort.label")}
+                            </label>
+                            <select
+                                value={(*sort_by).clone()}
+                                onchange={{
+                                    let sort_by = sort_by.clone();
+                                    let current_page = current_page.clone();
+                                    move |e: Event| {
+                                        let value = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+                                        sort_by.set(value);
+                                        current_page.set(1);
+                                    }
+                                }}
+                                class="w-full px-3 py-2 bg-slate-900/50 border border-slate-600 rounded-md text-white focus:outline-none focus:ring-2 focus:ring-blue-500"
+                            >
+                                <option value="created_at">{i18n.t("panel.tickets.sort.created_at")}</option>
+                                <option value="priority">{i18n.t("panel.tickets.sort.priority")}</option>
+                            </select>
+                        </div>
+                    </div>
+
                     <div class="flex items-center justify-between text-sm">
                         <div class="flex items-center gap-2 text-gray-400">
                             <i class="bi bi-info-circle"></i>
@@ -433,7 +550,31 @@ pub fn tickets_list() -> Html {
                                         >
                                             <div class="flex-1 min-w-0">
                                                 <div class="flex items-center justify-between mb-2">
-                                                    <h3 class="text-white font-medium">{ format!("Ticket #{}", &ticket.id) }</h3>
+                                                    <h3 class="text-white font-medium flex items-center gap-2">
+                                                        { format!("Ticket #{}", &ticket.id) }
+                                                        {
+                                                            if let Some(class) = priority_badge_class(&ticket.priority) {
+                                                                html! {
+                                                                    <span class={format!("text-xs px-2 py-0.5 rounded-full border {}", class)}>
+                                                                        { i18n.t(&format!("panel.tickets.priority.{}", ticket.priority)) }
+                                                                    </span>
+                                                                }
+                                                            } else {
+                                                                html! {}
+                                                            }
+                                                        }
+                                                        {
+                                                            if let Some(class) = workflow_badge_class(&ticket.workflow_state) {
+                                                                html! {
+                                                                    <span class={format!("text-xs px-2 py-0.5 rounded-full border {}", class)}>
+                                                                        { i18n.t(&format!("panel.tickets.state.{}", ticket.workflow_state)) }
+                                                                    </span>
+                                                                }
+                                                            } else {
+                                                                html! {}
+                                                            }
+                                                        }
+                                                    </h3>
                                                     <span class="text-xs text-gray-500">
                                                         { format_date(ticket.created_at) }
                                                     </span>
@@ -687,6 +828,68 @@ pub fn ticket_details(props: &TicketDetailsProps) -> Html {
                                 </div>
                             </div>
 
+                            {
+                                if ticket.form_answers.is_empty() {
+                                    html! {}
+                                } else {
+                                    html! {
+                                        <div class="bg-slate-800/50 border border-slate-700 rounded-lg p-4 mb-4">
+                                            <h2 class="text-lg font-semibold text-white flex items-center gap-2 mb-3">
+                                                <i class="bi bi-ui-checks"></i>
+                                                {i18n.t("panel.tickets.form_answers")}
+                                            </h2>
+                                            <dl class="space-y-2">
+                                                { for ticket.form_answers.iter().map(|a| html! {
+                                                    <div>
+                                                        <dt class="text-sm text-gray-400">{&a.question}</dt>
+                                                        <dd class="text-gray-200 whitespace-pre-wrap">{&a.answer}</dd>
+                                                    </div>
+                                                }) }
+                                            </dl>
+                                        </div>
+                                    }
+                                }
+                            }
+                            {
+                                if ticket.state_history.is_empty() {
+                                    html! {}
+                                } else {
+                                    html! {
+                                        <div class="bg-slate-800/50 border border-slate-700 rounded-lg p-4 mb-4">
+                                            <h2 class="text-lg font-semibold text-white flex items-center gap-2 mb-3">
+                                                <i class="bi bi-clock-history"></i>
+                                                {i18n.t("panel.tickets.state.history")}
+                                                {
+                                                    if let Some(until) = ticket.state_until {
+                                                        html! {
+                                                            <span class="text-xs font-normal text-gray-400">
+                                                                {format!("{} {}", i18n.t("panel.tickets.state.until"), format_date(until))}
+                                                            </span>
+                                                        }
+                                                    } else {
+                                                        html! {}
+                                                    }
+                                                }
+                                            </h2>
+                                            <ol class="space-y-1 text-sm">
+                                                { for ticket.state_history.iter().map(|change| html! {
+                                                    <li class="flex items-center gap-2 text-gray-300">
+                                                        <span class="text-xs text-gray-500">{ format_date(change.created_at) }</span>
+                                                        <span>{ i18n.t(&format!("panel.tickets.state.{}", change.to_state)) }</span>
+                                                        {
+                                                            if let Some(by) = &change.changed_by {
+                                                                html! { <span class="text-xs text-gray-500 font-mono">{by}</span> }
+                                                            } else {
+                                                                html! {}
+                                                            }
+                                                        }
+                                                    </li>
+                                                }) }
+                                            </ol>
+                                        </div>
+                                    }
+                                }
+                            }
                             <div class="bg-slate-800/50 border border-slate-700 rounded-lg p-4 mb-4">
                                 <div class="flex items-center justify-between mb-3">
                                     <h2 class="text-lg font-semibold text-white flex items-center gap-2">
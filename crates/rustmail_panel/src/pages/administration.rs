@@ -1,3 +1,4 @@
+use crate::components::command_permissions::CommandPermissions;
 use crate::components::forbidden::Forbidden403;
 use crate::components::navbar::RustmailNavbar;
 use crate::i18n::yew::use_translation;
@@ -733,6 +734,8 @@ pub fn administration() -> Html {
                     }}
                 </div>
             </div>
+
+            <CommandPermissions roles={(*roles).clone()} />
                         </div>
                     }
                 }}
@@ -1,9 +1,17 @@
 use crate::components::api_keys::ApiKeysPage;
+use crate::components::backups::BackupsPage;
 use crate::components::bans::BansPage;
 use crate::components::categories::CategoriesPage;
+use crate::components::config_history::ConfigHistoryPage;
 use crate::components::configuration::ConfigurationPage;
+use crate::components::data_requests::DataRequestsPage;
+use crate::components::features::FeaturesPage;
 use crate::components::home::Home;
+use crate::components::jobs::JobsPage;
+use crate::components::logs::LogsPage;
 use crate::components::navbar::RustmailNavbar;
+use crate::components::retention::RetentionPage;
+use crate::components::sessions::SessionsPage;
 use crate::components::ticket::{TicketDetails, TicketsList};
 use crate::i18n::yew::use_translation;
 use crate::types::PanelPermission;
@@ -26,12 +34,28 @@ pub enum PanelRoute {
     Home,
     #[at("/panel/configuration")]
     Configuration,
+    #[at("/panel/configuration/history")]
+    ConfigHistory,
     #[at("/panel/apikeys")]
     ApiKeys,
     #[at("/panel/categories")]
     Categories,
     #[at("/panel/bans")]
     Bans,
+    #[at("/panel/jobs")]
+    Jobs,
+    #[at("/panel/features")]
+    Features,
+    #[at("/panel/logs")]
+    Logs,
+    #[at("/panel/backups")]
+    Backups,
+    #[at("/panel/gdpr")]
+    DataRequests,
+    #[at("/panel/retention")]
+    Retention,
+    #[at("/panel/sessions")]
+    Sessions,
     #[at("/panel/tickets")]
     TicketsList,
     #[at("/panel/tickets/:id")]
@@ -139,9 +163,17 @@ fn switch(route: PanelRoute, navigator: Option<Navigator>) -> Html {
     match route {
         PanelRoute::Home => html! { <Home /> },
         PanelRoute::Configuration => html! { <ConfigurationPage /> },
+        PanelRoute::ConfigHistory => html! { <ConfigHistoryPage /> },
         PanelRoute::ApiKeys => html! { <ApiKeysPage /> },
         PanelRoute::Categories => html! { <CategoriesPage /> },
         PanelRoute::Bans => html! { <BansPage /> },
+        PanelRoute::Jobs => html! { <JobsPage /> },
+        PanelRoute::Features => html! { <FeaturesPage /> },
+        PanelRoute::Logs => html! { <LogsPage /> },
+        PanelRoute::Backups => html! { <BackupsPage /> },
+        PanelRoute::DataRequests => html! { <DataRequestsPage /> },
+        PanelRoute::Retention => html! { <RetentionPage /> },
+        PanelRoute::Sessions => html! { <SessionsPage /> },
         PanelRoute::TicketsList => html! { <TicketsList /> },
         PanelRoute::TicketDetails { id } => {
             let nav = navigator.clone();
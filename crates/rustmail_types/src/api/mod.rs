@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 pub use panel_permissions::*;
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ConfigResponse {
     pub bot: BotConfig,
     pub command: CommandConfig,
@@ -15,15 +16,23 @@ pub struct ConfigResponse {
     pub notifications: NotificationsConfig,
     pub reminders: ReminderConfig,
     pub logs: LogsConfig,
+    #[serde(default)]
+    pub logging: LoggingConfig,
+    #[serde(default)]
+    pub backups: BackupConfig,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CreateTicket {
     pub discord_id: String,
     pub staff_discord_id: Option<String>,
+    #[serde(default)]
+    pub category_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Snippet {
     pub id: i64,
     pub key: String,
@@ -34,6 +43,7 @@ pub struct Snippet {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct StatisticsOverview {
     pub open_tickets: i64,
     pub total_closed: i64,
@@ -45,6 +55,7 @@ pub struct StatisticsOverview {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct DailyActivity {
     pub date: String,
     pub created: i64,
@@ -52,6 +63,7 @@ pub struct DailyActivity {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct CategoryStats {
     pub name: String,
     pub count: i64,
@@ -59,6 +71,7 @@ pub struct CategoryStats {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct StaffMember {
     pub user_id: String,
     pub username: String,
@@ -68,6 +81,7 @@ pub struct StaffMember {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TopPerformer {
     pub user_id: String,
     pub username: String,
@@ -75,6 +89,7 @@ pub struct TopPerformer {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct TopPerformers {
     pub fastest_responder: Option<TopPerformer>,
     pub most_messages: Option<TopPerformer>,
@@ -82,6 +97,7 @@ pub struct TopPerformers {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct Statistics {
     pub overview: StatisticsOverview,
     pub activity: Vec<DailyActivity>,
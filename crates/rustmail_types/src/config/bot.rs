@@ -2,6 +2,7 @@ use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct BotConfig {
     pub token: String,
     pub mode: ServerMode,
@@ -22,6 +23,7 @@ pub struct BotConfig {
         deserialize_with = "deserialize_timezone",
         serialize_with = "serialize_timezone"
     )]
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
     pub timezone: Tz,
     #[serde(default)]
     pub logs_channel_id: Option<u64>,
@@ -38,6 +40,7 @@ pub struct BotConfig {
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 #[serde(tag = "type", rename_all = "lowercase")]
 pub enum ServerMode {
     Single {
@@ -1,16 +1,20 @@
+mod backups;
 mod bot;
 mod commands;
 mod error_handling;
 mod languages;
+mod logging;
 mod logs;
 mod notifications;
 mod reminders;
 mod threads;
 
+pub use backups::BackupConfig;
 pub use bot::{BotConfig, ServerMode};
 pub use commands::CommandConfig;
 pub use error_handling::ErrorHandlingConfig;
 pub use languages::LanguageConfig;
+pub use logging::{LogFormat, LogRotation, LoggingConfig};
 pub use logs::LogsConfig;
 pub use notifications::NotificationsConfig;
 pub use reminders::ReminderConfig;
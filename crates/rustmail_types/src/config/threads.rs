@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
 pub struct ThreadConfig {
     pub inbox_category_id: u64,
     pub embedded_message: bool,
@@ -14,6 +15,14 @@ pub struct ThreadConfig {
     pub close_on_leave: bool,
     #[serde(default = "default_auto_archive_duration")]
     pub auto_archive_duration: u16,
+    #[serde(default = "default_resolved_close_delay")]
+    pub resolved_close_delay: u64,
+    #[serde(default)]
+    pub board_channel_id: Option<u64>,
+    #[serde(default)]
+    pub inactivity_close_delay: u64,
+    #[serde(default)]
+    pub response_sla: u64,
 }
 
 fn default_close_on_leave() -> bool {
@@ -24,6 +33,10 @@ fn default_auto_archive_duration() -> u16 {
     10080
 }
 
+fn default_resolved_close_delay() -> u64 {
+    86400
+}
+
 impl Default for ThreadConfig {
     fn default() -> Self {
         Self {
@@ -37,6 +50,10 @@ impl Default for ThreadConfig {
             create_ticket_by_create_channel: false,
             close_on_leave: false,
             auto_archive_duration: 10080,
+            resolved_close_delay: default_resolved_close_delay(),
+            board_channel_id: None,
+            inactivity_close_delay: 0,
+            response_sla: 0,
         }
     }
 }
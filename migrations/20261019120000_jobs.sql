@@ -0,0 +1,39 @@
+-- Unified persistent job scheduler: one row per (kind, job_key)
+
+CREATE TABLE IF NOT EXISTS jobs (
+    id           INTEGER PRIMARY KEY AUTOINCREMENT,
+    kind         TEXT NOT NULL,
+    job_key      TEXT NOT NULL,
+    payload      TEXT NOT NULL DEFAULT '{}',
+    status       TEXT NOT NULL DEFAULT 'pending',
+    run_at       INTEGER NOT NULL,
+    attempts     INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL DEFAULT 5,
+    last_error   TEXT,
+    locked_by    TEXT,
+    locked_at    INTEGER,
+    created_at   INTEGER NOT NULL,
+    updated_at   INTEGER NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_jobs_kind_key ON jobs(kind, job_key);
+
+CREATE INDEX IF NOT EXISTS idx_jobs_status_run_at ON jobs(status, run_at);
+
+-- Carry over timers that used to be hydrated in memory on ready
+
+INSERT OR IGNORE INTO jobs (kind, job_key, run_at, created_at, updated_at)
+SELECT 'scheduled_closure', thread_id, close_at,
+       CAST(strftime('%s', 'now') AS INTEGER), CAST(strftime('%s', 'now') AS INTEGER)
+FROM scheduled_closures;
+
+INSERT OR IGNORE INTO jobs (kind, job_key, run_at, created_at, updated_at)
+SELECT 'reminder', CAST(id AS TEXT), trigger_time,
+       CAST(strftime('%s', 'now') AS INTEGER), CAST(strftime('%s', 'now') AS INTEGER)
+FROM reminders
+WHERE completed = 0;
+
+INSERT OR IGNORE INTO jobs (kind, job_key, run_at, created_at, updated_at)
+SELECT 'category_selection_timeout', CAST(user_id AS TEXT), expires_at,
+       CAST(strftime('%s', 'now') AS INTEGER), CAST(strftime('%s', 'now') AS INTEGER)
+FROM pending_category_selections;
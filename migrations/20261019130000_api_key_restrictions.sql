@@ -0,0 +1,18 @@
+-- Per-key rate limits, IP allowlists, category scoping and request logs
+ALTER TABLE api_keys ADD COLUMN rate_limit_per_minute INTEGER;
+ALTER TABLE api_keys ADD COLUMN rate_limit_burst INTEGER;
+ALTER TABLE api_keys ADD COLUMN allowed_cidrs TEXT NOT NULL DEFAULT '[]';
+ALTER TABLE api_keys ADD COLUMN allowed_category_ids TEXT NOT NULL DEFAULT '[]';
+
+CREATE TABLE IF NOT EXISTS api_key_requests (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    api_key_id INTEGER NOT NULL REFERENCES api_keys(id) ON DELETE CASCADE,
+    method TEXT NOT NULL,
+    route TEXT NOT NULL,
+    status INTEGER NOT NULL,
+    latency_ms INTEGER NOT NULL,
+    ip TEXT,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_api_key_requests_key ON api_key_requests(api_key_id, id);
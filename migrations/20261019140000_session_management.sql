@@ -0,0 +1,13 @@
+-- Session metadata for listing and revoking panel sessions
+ALTER TABLE sessions_panel ADD COLUMN public_id TEXT;
+ALTER TABLE sessions_panel ADD COLUMN created_at INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE sessions_panel ADD COLUMN last_seen_at INTEGER;
+ALTER TABLE sessions_panel ADD COLUMN ip TEXT;
+ALTER TABLE sessions_panel ADD COLUMN user_agent TEXT;
+
+UPDATE sessions_panel SET public_id = lower(hex(randomblob(16))) WHERE public_id IS NULL;
+UPDATE sessions_panel SET created_at = strftime('%s', 'now') WHERE created_at = 0;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_sessions_panel_public_id ON sessions_panel(public_id);
+CREATE INDEX IF NOT EXISTS idx_sessions_panel_user ON sessions_panel(user_id);
+CREATE INDEX IF NOT EXISTS idx_sessions_panel_expires ON sessions_panel(expires_at);
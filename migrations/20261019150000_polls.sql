@@ -0,0 +1,21 @@
+-- Staff polls posted from the features channel, with one changeable vote per member
+
+CREATE TABLE IF NOT EXISTS polls (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    channel_id TEXT NOT NULL,
+    message_id TEXT,
+    question   TEXT NOT NULL,
+    options    TEXT NOT NULL,
+    created_by TEXT NOT NULL,
+    created_at INTEGER NOT NULL,
+    closes_at  INTEGER,
+    closed_at  INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS poll_votes (
+    poll_id      INTEGER NOT NULL REFERENCES polls(id) ON DELETE CASCADE,
+    user_id      TEXT NOT NULL,
+    option_index INTEGER NOT NULL,
+    voted_at     INTEGER NOT NULL,
+    PRIMARY KEY (poll_id, user_id)
+);
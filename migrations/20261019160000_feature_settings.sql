@@ -0,0 +1,8 @@
+-- Per-feature toggle and settings managed from the panel
+
+CREATE TABLE IF NOT EXISTS feature_settings (
+    feature_key TEXT PRIMARY KEY,
+    enabled     INTEGER NOT NULL,
+    settings    TEXT NOT NULL DEFAULT '{}',
+    updated_at  INTEGER NOT NULL
+);
@@ -0,0 +1,22 @@
+-- Category selection as a select menu, parent/child categories and intake forms
+
+ALTER TABLE ticket_category_settings ADD COLUMN selection_mode TEXT NOT NULL DEFAULT 'buttons';
+
+ALTER TABLE ticket_categories ADD COLUMN parent_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_ticket_categories_parent
+    ON ticket_categories(parent_id);
+
+CREATE TABLE IF NOT EXISTS ticket_category_forms (
+    category_id TEXT PRIMARY KEY,
+    title       TEXT NOT NULL,
+    questions   TEXT NOT NULL DEFAULT '[]',
+    updated_at  INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS thread_form_answers (
+    thread_id    TEXT PRIMARY KEY,
+    category_id  TEXT NOT NULL,
+    answers      TEXT NOT NULL DEFAULT '[]',
+    submitted_at INTEGER NOT NULL
+);
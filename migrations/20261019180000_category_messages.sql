@@ -0,0 +1,14 @@
+-- Per-category welcome, close and auto-reply messages with language variants
+
+ALTER TABLE ticket_categories ADD COLUMN embed_color TEXT;
+
+CREATE TABLE IF NOT EXISTS ticket_category_messages (
+    category_id     TEXT NOT NULL,
+    -- Language code (e.g. 'fr'); '' holds the variant used for every other language
+    language        TEXT NOT NULL DEFAULT '',
+    welcome_message TEXT,
+    close_message   TEXT,
+    auto_reply      TEXT,
+    updated_at      INTEGER NOT NULL,
+    PRIMARY KEY (category_id, language)
+);
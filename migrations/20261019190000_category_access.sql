@@ -0,0 +1,9 @@
+-- Per-category access lists restricting which staff can see and act on tickets
+
+CREATE TABLE IF NOT EXISTS ticket_category_access (
+    category_id TEXT NOT NULL,
+    -- 'role' or 'user'
+    target_type TEXT NOT NULL,
+    target_id   TEXT NOT NULL,
+    PRIMARY KEY (category_id, target_type, target_id)
+);
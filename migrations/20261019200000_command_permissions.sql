@@ -0,0 +1,9 @@
+-- Per-command permission matrix: which staff roles and users may run each command
+
+CREATE TABLE IF NOT EXISTS command_permissions (
+    command_name TEXT NOT NULL,
+    -- 'role' or 'user'
+    target_type  TEXT NOT NULL,
+    target_id    TEXT NOT NULL,
+    PRIMARY KEY (command_name, target_type, target_id)
+);
@@ -0,0 +1,14 @@
+-- Ticket priorities: per-ticket level, per-category default and keyword rules
+
+ALTER TABLE threads ADD COLUMN priority TEXT NOT NULL DEFAULT 'normal';
+ALTER TABLE ticket_categories ADD COLUMN default_priority TEXT;
+
+CREATE TABLE IF NOT EXISTS priority_rules (
+    id         INTEGER PRIMARY KEY AUTOINCREMENT,
+    -- Matched case-insensitively against the first message of a ticket
+    keyword    TEXT NOT NULL,
+    priority   TEXT NOT NULL,
+    created_at INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_threads_priority ON threads(status, priority);
@@ -0,0 +1,33 @@
+-- Explicit ticket workflow states (open, awaiting user/staff, on hold,
+-- resolved, closed) with a history of every transition for reporting.
+ALTER TABLE "threads" ADD COLUMN "workflow_state" TEXT NOT NULL DEFAULT 'open';
+-- End of a hold, or when a resolved ticket closes; NULL otherwise.
+ALTER TABLE "threads" ADD COLUMN "state_until" INTEGER;
+ALTER TABLE "threads" ADD COLUMN "state_changed_at" INTEGER;
+
+CREATE TABLE IF NOT EXISTS "thread_state_history" (
+    "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+    "thread_id" TEXT NOT NULL,
+    "from_state" TEXT,
+    "to_state" TEXT NOT NULL,
+    -- Discord ID of whoever caused the transition; NULL for timers.
+    "changed_by" TEXT,
+    "created_at" INTEGER NOT NULL,
+    CONSTRAINT "thread_state_history_thread_id_fkey" FOREIGN KEY ("thread_id") REFERENCES "threads" ("id") ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS "idx_thread_state_history_thread" ON "thread_state_history" ("thread_id", "id");
+CREATE INDEX IF NOT EXISTS "idx_threads_workflow_state" ON "threads" ("status", "workflow_state");
+
+UPDATE "threads" SET "workflow_state" = 'closed' WHERE "status" = 0;
+
+UPDATE "threads"
+SET "workflow_state" = CASE
+        WHEN EXISTS (
+            SELECT 1 FROM "thread_status" ts
+            WHERE ts."thread_id" = "threads"."id" AND ts."last_message_by" = 'staff'
+        ) THEN 'awaiting_user'
+        ELSE 'awaiting_staff'
+    END,
+    "state_changed_at" = CAST(strftime('%s', 'now') AS INTEGER)
+WHERE "status" = 1;
@@ -0,0 +1,7 @@
+-- Messages of the live open-tickets board, one per page, so they can be
+-- edited in place instead of reposted.
+CREATE TABLE IF NOT EXISTS "ticket_board_messages" (
+    "page" INTEGER PRIMARY KEY,
+    "channel_id" TEXT NOT NULL,
+    "message_id" TEXT NOT NULL
+);
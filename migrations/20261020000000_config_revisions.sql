@@ -0,0 +1,11 @@
+-- Every accepted configuration, with the bot token and client secret masked.
+CREATE TABLE IF NOT EXISTS "config_revisions" (
+    "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+    "snapshot" TEXT NOT NULL,
+    "source" TEXT NOT NULL,
+    "author_id" TEXT,
+    "rollback_of" INTEGER REFERENCES "config_revisions"("id") ON DELETE SET NULL,
+    "created_at" INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS "idx_config_revisions_created_at" ON "config_revisions"("created_at");
@@ -0,0 +1,18 @@
+-- Audit trail of data-subject exports and erasures.
+CREATE TABLE IF NOT EXISTS "data_subject_requests" (
+    "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+    -- Discord ID of the user whose data was exported or erased
+    "user_id" TEXT NOT NULL,
+    -- 'export' or 'erase'
+    "action" TEXT NOT NULL,
+    -- 'anonymize' or 'delete' for erasures
+    "mode" TEXT,
+    -- 'command' or 'panel'
+    "source" TEXT NOT NULL,
+    "requested_by" TEXT NOT NULL,
+    -- JSON object of row counts per table
+    "summary" TEXT NOT NULL DEFAULT '{}',
+    "created_at" INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS "idx_data_subject_requests_user_id" ON "data_subject_requests"("user_id");
@@ -0,0 +1,39 @@
+-- Retention rules applied nightly, and the statistics of pruned tickets
+
+CREATE TABLE IF NOT EXISTS retention_rules (
+    id                 INTEGER PRIMARY KEY AUTOINCREMENT,
+    -- 'message_content', 'closed_tickets', 'tracked_members' or 'expired_api_keys'
+    target             TEXT NOT NULL,
+    older_than_days    INTEGER NOT NULL,
+    -- Limits ticket rules to one ticket category
+    ticket_category_id TEXT NULL,
+    enabled            INTEGER NOT NULL DEFAULT 0,
+    last_run_at        INTEGER NULL,
+    last_affected      INTEGER NULL,
+    created_at         INTEGER NOT NULL,
+    updated_at         INTEGER NOT NULL
+);
+
+-- Per-day aggregates of deleted tickets, added to the live statistics.
+-- `created` is counted on the creation day, the other columns on the closing day.
+CREATE TABLE IF NOT EXISTS archived_ticket_stats (
+    day                TEXT NOT NULL,
+    category_name      TEXT NOT NULL DEFAULT '',
+    created            INTEGER NOT NULL DEFAULT 0,
+    closed             INTEGER NOT NULL DEFAULT 0,
+    resolution_seconds INTEGER NOT NULL DEFAULT 0,
+    response_seconds   INTEGER NOT NULL DEFAULT 0,
+    responded          INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (day, category_name)
+);
+
+CREATE TABLE IF NOT EXISTS archived_staff_stats (
+    day              TEXT NOT NULL,
+    user_id          TEXT NOT NULL,
+    user_name        TEXT NOT NULL DEFAULT '',
+    messages         INTEGER NOT NULL DEFAULT 0,
+    tickets_closed   INTEGER NOT NULL DEFAULT 0,
+    response_seconds INTEGER NOT NULL DEFAULT 0,
+    responded        INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (day, user_id)
+);
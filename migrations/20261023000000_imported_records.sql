@@ -0,0 +1,11 @@
+-- Threads imported from other modmail bots, so that an import can be run again safely
+
+CREATE TABLE IF NOT EXISTS imported_records (
+    -- 'modmail' (Python bot) or 'modmailbot' (Node bot)
+    source      TEXT NOT NULL,
+    -- ID of the thread in the source export
+    external_id TEXT NOT NULL,
+    thread_id   TEXT NOT NULL,
+    imported_at INTEGER NOT NULL,
+    PRIMARY KEY (source, external_id)
+);
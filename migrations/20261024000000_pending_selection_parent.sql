@@ -0,0 +1,3 @@
+-- Parent category picked in the category picker, so that only its subcategories can be chosen next
+
+ALTER TABLE pending_category_selections ADD COLUMN picked_parent_id TEXT;